@@ -2,6 +2,8 @@
 //
 // Benchmark groups:
 //   - skeleton: Time until skeleton appears (1, 4, 8 worktrees; warm + cold)
+//   - time_to_skeleton: Process start to "Skeleton rendered" trace event (typical-4/8/16, warm)
+//       - fails if the median exceeds WORKTRUNK_BENCH_SKELETON_BUDGET_MS (default 300)
 //   - complete: Full execution time (1, 4, 8 worktrees; warm + cold)
 //   - worktree_scaling: Worktree count scaling (1, 4, 8 worktrees; warm + cold)
 //   - real_repo: rust-lang/rust clone (1, 4, 8 worktrees; warm + cold)
@@ -16,15 +18,21 @@
 // Run examples:
 //   cargo bench --bench list                         # All benchmarks
 //   cargo bench --bench list skeleton                # Progressive rendering
+//   cargo bench --bench list time_to_skeleton        # Perceived latency with budget guard
 //   cargo bench --bench list real_repo_many_branches # GH #461 scenario (large repo + many branches)
 //   cargo bench --bench list timeout_effect          # Test timeout fix for GH #461
 //   cargo bench --bench list -- --skip cold          # Skip cold cache variants
 //   cargo bench --bench list -- --skip real          # Skip rust repo clone
 
 use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use std::cell::RefCell;
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use wt_perf::{RepoConfig, create_repo, ensure_rust_repo, invalidate_caches, setup_fake_remote};
+use std::time::Duration;
+use wt_perf::{
+    RepoConfig, create_repo, ensure_rust_repo, invalidate_caches, setup_fake_remote,
+    time_to_trace_event,
+};
 
 /// Benchmark configuration wrapping RepoConfig with cache state.
 #[derive(Clone)]
@@ -144,6 +152,60 @@ fn bench_skeleton(c: &mut Criterion) {
     group.finish();
 }
 
+/// Median time-to-skeleton allowed before the bench fails, in milliseconds.
+fn skeleton_budget() -> Duration {
+    let ms = std::env::var("WORKTRUNK_BENCH_SKELETON_BUDGET_MS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(300);
+    Duration::from_millis(ms)
+}
+
+fn bench_time_to_skeleton(c: &mut Criterion) {
+    let mut group = c.benchmark_group("time_to_skeleton");
+    let binary = get_release_binary();
+    let budget = skeleton_budget();
+
+    for worktrees in [4, 8, 16] {
+        let config = BenchConfig::typical(worktrees, false);
+        let temp = create_repo(&config.repo);
+        let repo_path = temp.path().join("repo");
+        setup_fake_remote(&repo_path);
+
+        let samples = RefCell::new(Vec::new());
+        group.bench_function(BenchmarkId::new("typical", worktrees), |b| {
+            b.iter_custom(|iters| {
+                (0..iters)
+                    .map(|_| {
+                        let mut cmd = Command::new(binary);
+                        cmd.args(["list", "--progressive"])
+                            .current_dir(&repo_path)
+                            .env("WORKTRUNK_SKELETON_ONLY", "1");
+                        let elapsed = time_to_trace_event(&mut cmd, "Skeleton rendered");
+                        samples.borrow_mut().push(elapsed);
+                        elapsed
+                    })
+                    .sum()
+            });
+        });
+
+        // Regression guard: criterion only reports, so fail loudly on a blown budget
+        let mut samples = samples.into_inner();
+        if samples.is_empty() {
+            continue; // Filtered out
+        }
+        samples.sort();
+        let median = samples[samples.len() / 2];
+        assert!(
+            median <= budget,
+            "typical-{worktrees}: median time to skeleton {median:?} exceeds budget {budget:?} \
+             (set WORKTRUNK_BENCH_SKELETON_BUDGET_MS to adjust)"
+        );
+    }
+
+    group.finish();
+}
+
 fn bench_complete(c: &mut Criterion) {
     let mut group = c.benchmark_group("complete");
     let binary = get_release_binary();
@@ -526,6 +588,6 @@ criterion_group! {
         .sample_size(30)
         .measurement_time(std::time::Duration::from_secs(15))
         .warm_up_time(std::time::Duration::from_secs(3));
-    targets = bench_skeleton, bench_complete, bench_worktree_scaling, bench_real_repo, bench_many_branches, bench_divergent_branches, bench_real_repo_many_branches, bench_timeout_effect
+    targets = bench_skeleton, bench_time_to_skeleton, bench_complete, bench_worktree_scaling, bench_real_repo, bench_many_branches, bench_divergent_branches, bench_real_repo_many_branches, bench_timeout_effect
 }
 criterion_main!(benches);
@@ -0,0 +1,318 @@
+//! Library facade for embedding worktrunk in other tools.
+//!
+//! [`Worktrunk`] exposes the repository-level parts of `wt list`, `wt switch`
+//! and `wt remove`: worktree paths follow the user's `worktree-path` template
+//! and new branches get the project's `[branch] prefix`, exactly as with the
+//! CLI. Nothing here prints, prompts or runs hooks — those stay in the `wt`
+//! binary, which layers its output and approvals on top. The checks that keep
+//! work safe (`[safety]` protection, the integration check before deleting a
+//! branch, path computation) are the library functions the CLI calls too, so
+//! the two can't drift apart.
+//!
+//! ```no_run
+//! use worktrunk::api::{ListOptions, RemoveOptions, Worktrunk};
+//!
+//! let wt = Worktrunk::open(".")?;
+//! let path = wt.switch("feature", true)?;
+//! for item in wt.list(ListOptions::default())? {
+//!     println!("{:?} {:?}", item.branch, item.path);
+//! }
+//! wt.remove("feature", RemoveOptions::default())?;
+//! # assert!(path.is_absolute());
+//! # Ok::<(), anyhow::Error>(())
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use color_print::cformat;
+use normalize_path::NormalizePath;
+use serde::Serialize;
+
+use crate::config::{WorktrunkConfig, format_worktree_path};
+use crate::git::{GitError, Repository, delete_branch_if_safe};
+use crate::path::format_path_for_display;
+
+/// A repository opened for worktree management.
+pub struct Worktrunk {
+    repo: Repository,
+    config: WorktrunkConfig,
+}
+
+/// Options for [`Worktrunk::list`].
+#[derive(Debug, Clone, Default)]
+pub struct ListOptions {
+    /// Also include local branches that have no worktree.
+    pub branches: bool,
+}
+
+/// Options for [`Worktrunk::remove`].
+#[derive(Debug, Clone)]
+pub struct RemoveOptions {
+    /// Remove the worktree even if it has uncommitted changes.
+    pub force: bool,
+    /// Delete the branch after removing its worktree, if it's integrated.
+    pub delete_branch: bool,
+}
+
+impl Default for RemoveOptions {
+    fn default() -> Self {
+        Self {
+            force: false,
+            delete_branch: true,
+        }
+    }
+}
+
+/// One row of [`Worktrunk::list`]: a worktree, or a branch without one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ListItem {
+    /// Branch name, or `None` for a detached HEAD.
+    pub branch: Option<String>,
+    /// Worktree path, or `None` for a branch without a worktree.
+    pub path: Option<PathBuf>,
+    /// Commit SHA the branch or worktree points at.
+    pub head: String,
+    /// Whether this is the default branch.
+    pub is_main: bool,
+    /// Commits ahead of the default branch.
+    pub ahead: usize,
+    /// Commits behind the default branch.
+    pub behind: usize,
+    /// Whether the worktree has uncommitted changes (`None` without a worktree).
+    pub dirty: Option<bool>,
+}
+
+impl Worktrunk {
+    /// Open the repository containing `path`, loading the user config.
+    pub fn open(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let config = WorktrunkConfig::load()?;
+        Self::with_config(path, config)
+    }
+
+    /// Open the repository containing `path` with an explicit user config.
+    pub fn with_config(path: impl AsRef<Path>, config: WorktrunkConfig) -> anyhow::Result<Self> {
+        Ok(Self {
+            repo: Repository::at(path.as_ref())?,
+            config,
+        })
+    }
+
+    /// The underlying repository.
+    pub fn repository(&self) -> &Repository {
+        &self.repo
+    }
+
+    /// List worktrees, and optionally branches without one.
+    pub fn list(&self, options: ListOptions) -> anyhow::Result<Vec<ListItem>> {
+        let default_branch = self.repo.default_branch();
+        let mut items = Vec::new();
+
+        for wt in self.repo.list_worktrees()? {
+            if wt.bare {
+                continue;
+            }
+            let dirty = self.repo.worktree_at(&wt.path).is_dirty()?;
+            items.push(self.item(
+                wt.branch,
+                Some(wt.path),
+                wt.head,
+                Some(dirty),
+                default_branch.as_deref(),
+            )?);
+        }
+
+        if options.branches {
+            let branches = self.repo.run_command(&[
+                "for-each-ref",
+                "--format=%(refname:lstrip=2) %(objectname)",
+                "refs/heads/",
+            ])?;
+            for (branch, head) in branches.lines().filter_map(|line| line.split_once(' ')) {
+                if items
+                    .iter()
+                    .any(|item| item.branch.as_deref() == Some(branch))
+                {
+                    continue;
+                }
+                items.push(self.item(
+                    Some(branch.to_string()),
+                    None,
+                    head.to_string(),
+                    None,
+                    default_branch.as_deref(),
+                )?);
+            }
+        }
+
+        Ok(items)
+    }
+
+    fn item(
+        &self,
+        branch: Option<String>,
+        path: Option<PathBuf>,
+        head: String,
+        dirty: Option<bool>,
+        default_branch: Option<&str>,
+    ) -> anyhow::Result<ListItem> {
+        let is_main = branch.is_some() && branch.as_deref() == default_branch;
+        let (ahead, behind) = match default_branch {
+            Some(base) if !is_main => self.repo.ahead_behind(base, &head)?,
+            _ => (0, 0),
+        };
+        Ok(ListItem {
+            branch,
+            path,
+            head,
+            is_main,
+            ahead,
+            behind,
+            dirty,
+        })
+    }
+
+    /// Return the worktree for `branch`, creating it if needed.
+    ///
+    /// With `create`, a new branch is created from the default branch (with the
+    /// project's branch prefix applied). Unlike `wt switch`, no hooks run and
+    /// the current directory doesn't change.
+    pub fn switch(&self, branch: &str, create: bool) -> anyhow::Result<PathBuf> {
+        let branch = if create {
+            self.repo.with_branch_prefix(branch)
+        } else {
+            self.repo.resolve_branch_prefix(branch)?
+        };
+        if let Some(path) = self.repo.worktree_for_branch(&branch)? {
+            return Ok(path);
+        }
+
+        let exists = self.repo.branch_exists(&branch)?;
+        if create && exists {
+            return Err(GitError::BranchAlreadyExists { branch }.into());
+        }
+        if !create && !exists {
+            return Err(GitError::InvalidReference { reference: branch }.into());
+        }
+
+        let path = compute_worktree_path(&self.repo, &branch, &self.config)?;
+        let path_str = path.to_string_lossy();
+        let mut args = vec!["worktree", "add", path_str.as_ref()];
+        let default_branch = self.repo.default_branch();
+        if create {
+            args.extend(["-b", branch.as_str()]);
+            args.extend(default_branch.as_deref());
+        } else {
+            args.push(&branch);
+        }
+        self.repo
+            .run_command(&args)
+            .map_err(|e| GitError::WorktreeCreationFailed {
+                branch: branch.clone(),
+                base_branch: create.then(|| default_branch.clone()).flatten(),
+                error: e.to_string(),
+            })?;
+        Ok(path)
+    }
+
+    /// Remove the worktree for `branch`.
+    ///
+    /// Applies the same checks as `wt remove`: the main worktree can't be
+    /// removed, a dirty worktree needs `force`, and a branch listed in
+    /// `[safety] protected-branches` isn't deleted. The branch is only deleted
+    /// once it's integrated into the default branch.
+    pub fn remove(&self, branch: &str, options: RemoveOptions) -> anyhow::Result<()> {
+        let branch = self.repo.resolve_branch_prefix(branch)?;
+        let Some(path) = self.repo.worktree_for_branch(&branch)? else {
+            return Err(GitError::NoWorktreeFound { branch }.into());
+        };
+        let worktree = self.repo.worktree_at(&path);
+        if !worktree.is_linked()? {
+            return Err(GitError::CannotRemoveMainWorktree.into());
+        }
+        if options.delete_branch {
+            self.repo
+                .ensure_not_protected(&branch, "delete branch", None)?;
+        }
+        if !options.force {
+            worktree.ensure_clean("remove worktree", Some(&branch), false)?;
+        }
+
+        self.repo.remove_worktree(&path, options.force)?;
+        if options.delete_branch {
+            // Removing the default branch's worktree checks against HEAD, as `wt remove` does
+            let default_branch = self.repo.default_branch().filter(|db| *db != branch);
+            let target = default_branch.as_deref().unwrap_or("HEAD");
+            delete_branch_if_safe(&self.repo, &branch, target, false)?;
+        }
+        Ok(())
+    }
+}
+
+/// Compute the expected worktree path for a branch name.
+///
+/// For the default branch, returns the repo root (main worktree location).
+/// For other branches, applies the `worktree-path` template from config, or
+/// from the branch's `[[rules]]` entry in the project config. A rule's path
+/// must stay in the directory the user's template puts worktrees in.
+///
+/// Uses cached values from Repository for `default_branch` and `is_bare`.
+pub fn compute_worktree_path(
+    repo: &Repository,
+    branch: &str,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<PathBuf> {
+    let repo_root = repo.repo_path()?;
+    let default_branch = repo.default_branch().unwrap_or_default();
+    let is_bare = repo.is_bare()?;
+
+    // Default branch lives at repo root (main worktree), not a templated path.
+    // Exception: bare repos have no main worktree, so all branches use templated paths.
+    if !is_bare && branch == default_branch {
+        return Ok(repo_root);
+    }
+
+    let repo_name = repo_root
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("Repository path has no filename: {}", repo_root.display()))?
+        .to_str()
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Repository path contains invalid UTF-8: {}",
+                repo_root.display()
+            )
+        })?;
+
+    let format_error = |e: String| anyhow::anyhow!("Failed to format worktree path: {e}");
+    let user_path = repo_root
+        .join(
+            config
+                .format_path(repo_name, branch, repo)
+                .map_err(format_error)?,
+        )
+        .normalize();
+
+    // A matching `[[rules]]` entry overrides the user's template, but the
+    // project config comes with the repository, so its path has to stay in
+    // the directory the user's template puts worktrees in
+    let rule_template = repo
+        .load_project_config()?
+        .and_then(|project| project.rule_for(branch)?.worktree_path.clone());
+    let Some(template) = rule_template else {
+        return Ok(user_path);
+    };
+    let rule_path = repo_root
+        .join(format_worktree_path(&template, repo_name, branch, repo).map_err(format_error)?)
+        .normalize();
+    let root = user_path.parent().unwrap_or(&user_path);
+    if !rule_path.starts_with(root) || rule_path == root {
+        return Err(GitError::Other {
+            message: cformat!(
+                "The <bright-black>[[rules]] worktree-path</> for <bold>{branch}</> resolves to <bold>{}</>, outside <bold>{}</> where the user's <bright-black>worktree-path</> puts worktrees",
+                format_path_for_display(&rule_path),
+                format_path_for_display(root)
+            ),
+        }
+        .into());
+    }
+    Ok(rule_path)
+}
@@ -0,0 +1,11 @@
+use clap::Subcommand;
+
+/// Subcommands for `wt alias`
+#[derive(Subcommand)]
+pub enum AliasCommand {
+    /// List configured aliases
+    ///
+    /// Prints each alias and its expansion, one per line. Aliases that share a
+    /// name with a built-in command are reported and skipped.
+    List,
+}
@@ -41,10 +41,50 @@ eval "$(wt config shell init zsh)"
         cmd: Option<String>,
     },
 
+    /// Generate a completion script
+    #[command(
+        after_long_help = r#"Prints a completion script for the shell, or with `--install`, writes it to the shell's conventional completion directory and shows the path. For setups that don't eval `wt config shell init`, which already includes completions.
+
+| Shell | Installed to |
+|-------|--------------|
+| bash | `~/.local/share/bash-completion/completions/wt` |
+| zsh | `~/.zfunc/_wt` (must be in `fpath` before `compinit`) |
+| fish | `~/.config/fish/completions/wt.fish` |
+
+PowerShell completions are only available through `wt config shell install powershell`.
+
+## Examples
+
+Install zsh completions:
+```console
+wt config shell completions zsh --install
+```
+
+Generate completions for a package (e.g. a Homebrew formula):
+```console
+wt config shell completions fish > wt.fish
+```"#
+    )]
+    Completions {
+        /// Shell to generate completions for
+        #[arg(value_enum)]
+        shell: Shell,
+
+        /// Write to the shell's completion directory instead of stdout
+        #[arg(long)]
+        install: bool,
+
+        /// Command name for completions (defaults to binary name)
+        #[arg(long)]
+        cmd: Option<String>,
+    },
+
     /// Write shell integration to config files
     #[command(
         after_long_help = r#"Detects existing shell config files and adds the integration line.
 
+For bash, zsh, and PowerShell, the line is wrapped in `# >>> wt shell integration >>>` marker comments. Installing again updates that block (or a line from an older version) in place rather than adding another.
+
 ## Examples
 
 Install for all detected shells:
@@ -84,7 +124,7 @@ Use --yes to skip confirmation."#
 
     /// Remove shell integration from config files
     #[command(
-        after_long_help = r#"Removes shell integration lines from config files.
+        after_long_help = r#"Removes shell integration from config files: the marker block written by `wt config shell install`, plus integration lines from older versions.
 
 ## Examples
 
@@ -107,7 +147,8 @@ wt config shell uninstall --yes
 
 Detects various forms of the integration pattern regardless of:
 - Command prefix (wt, worktree, etc.)
-- Minor syntax variations between versions"#
+- Minor syntax variations between versions
+- Whether it was written inside marker comments"#
     )]
     Uninstall {
         /// Shell to uninstall (default: all)
@@ -151,6 +192,30 @@ including previously approved ones."#
         all: bool,
     },
 
+    /// List approved commands
+    #[command(
+        after_long_help = r#"Shows the approved commands in user config, grouped by project. When run
+inside a repository, the current project is marked."#
+    )]
+    List,
+
+    /// Revoke approvals for a project
+    #[command(
+        after_long_help = r#"Removes saved approvals for a project, requiring re-approval on its next
+command run. Project identifiers are shown by `wt hook approvals list`.
+
+Without a command, revokes all of the project's approvals. A command is matched
+by its exact text or by the name it was approved under: `test` or
+`pre-merge:test` for a pre-merge command named `test`."#
+    )]
+    Revoke {
+        /// Project identifier, as shown by list
+        project: String,
+
+        /// Command text or name [default: all]
+        command: Option<String>,
+    },
+
     /// Clear approved commands from config
     #[command(
         after_long_help = r#"Removes saved approvals, requiring re-approval on next command run.
@@ -217,6 +282,55 @@ This tests:
         full: bool,
     },
 
+    /// Show settings that differ from the defaults
+    #[command(
+        after_long_help = r#"Prints each setting whose effective value differs from the built-in default, annotated with where it came from: user config (noting `--config` or `WORKTRUNK_CONFIG_PATH` when they moved it), a `WORKTRUNK_*` environment variable, or project config. Defaults are shown alongside when they aren't empty.
+
+Useful for working out why worktrunk behaves differently on one machine. Approved commands aren't listed; see `wt hook approvals list`. Secrets — `gitea.token`, and any key ending in `token`, `password` or `secret` — print as `<redacted>`, here and in `wt config show`.
+
+## Examples
+
+```console
+$ wt config diff
+worktree-path = ".worktrees/{{ branch | sanitize }}"  # user config; default "../{{ repo }}.{{ branch | sanitize }}"
+list.full = true  # env WORKTRUNK_LIST__FULL
+ci.platform = "gitlab"  # project config
+```"#
+    )]
+    Diff,
+
+    /// Update config files to the current format
+    #[command(
+        after_long_help = r#"Rewrites a config file written for an older version of worktrunk, such as one using renamed template variables. Comments and formatting are kept, the original is saved alongside as `config.toml.bak` (or `wt.toml.bak`), and the file records its format in `config-version`.
+
+Files already up to date are left alone.
+
+## Examples
+
+Preview the changes to user config:
+```console
+wt config migrate --dry-run
+```
+
+Migrate project config without prompting:
+```console
+wt config migrate --project --yes
+```"#
+    )]
+    Migrate {
+        /// Migrate project config (`.config/wt.toml`) instead of user config
+        #[arg(long)]
+        project: bool,
+
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Show what would be changed
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Manage internal data and cache
     #[command(
         after_long_help = r#"State is stored in `.git/` (config entries and log files), separate from configuration files.
@@ -642,6 +756,7 @@ wt config state marker set "✅ ready" --branch=feature
 ```"#)]
     Set {
         /// Marker text (shown in `wt list` output)
+        #[arg(add = crate::completion::marker_completer())]
         value: String,
 
         /// Target branch (defaults to current)
@@ -729,6 +844,7 @@ wt config state hints clear worktree-path
     )]
     Clear {
         /// Specific hint to clear (clears all if not specified)
+        #[arg(add = crate::completion::hint_name_completer())]
         name: Option<String>,
     },
 }
@@ -177,6 +177,16 @@ Pre-approve all commands for current project:
 wt hook approvals add
 ```
 
+List saved approvals across projects:
+```console
+wt hook approvals list
+```
+
+Revoke one command's approval:
+```console
+wt hook approvals revoke github.com/user/repo pre-merge:test
+```
+
 Clear approvals for current project:
 ```console
 wt hook approvals clear
@@ -189,7 +199,9 @@ wt hook approvals clear --global
 
 ## How approvals work
 
-Approved commands are saved to user config. Re-approval is required when the command template changes or the project moves. Use `--yes` to bypass prompts in CI."#
+Approved commands are saved to user config. Re-approval is required when the command template changes or the project moves. Use `--yes` to bypass prompts in CI.
+
+Each approval also records a hash of the command under its hook type and name. When a previously-approved command's text changes, the prompt marks it `(changed since approval)`, and approving it replaces the old text."#
     )]
     Approvals {
         #[command(subcommand)]
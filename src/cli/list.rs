@@ -6,7 +6,17 @@ pub enum ListSubcommand {
     /// Single-line status for shell prompts
     ///
     /// For shell prompts, starship, or editor integrations.
-    #[command(after_long_help = "Format: `branch  status  ±working  commits  upstream  ci`")]
+    #[command(
+        after_long_help = r##"Format: `branch  status  ±working  commits  upstream  ci`
+
+For terminal multiplexer status bars, pass `--format`:
+
+- tmux: `set -g status-right '#(cd "#{pane_current_path}" && wt list statusline --format=tmux)'`
+- zellij (zjstatus): `command_wt_command "wt list statusline --format=zellij"` with `command_wt_rendermode "dynamic"`
+- wezterm: run `wt list statusline --format=wezterm` in the pane's directory from an `update-status` handler and pass its output to `window:set_right_status()`
+
+These print the last cached status and refresh it in the background, so they return in a few milliseconds."##
+    )]
     Statusline {
         /// Claude Code mode: read context from stdin, add directory and model
         ///
@@ -14,5 +24,29 @@ pub enum ListSubcommand {
         /// Output: `dir  branch  status  ±working  commits  upstream  ci  | model`
         #[arg(long)]
         claude_code: bool,
+
+        /// Output syntax (ansi, tmux, zellij, wezterm)
+        ///
+        /// Multiplexer formats convert colors to the status bar's own syntax.
+        #[arg(long, value_enum, default_value_t, conflicts_with = "claude_code")]
+        format: StatuslineFormat,
+
+        /// Recompute the cached multiplexer status without printing it
+        #[arg(long, hide = true)]
+        refresh_cache: bool,
     },
 }
+
+/// Output syntax for `wt list statusline`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum StatuslineFormat {
+    /// ANSI escapes, for shell prompts and editors
+    #[default]
+    Ansi,
+    /// tmux `#[fg=...]` style sequences
+    Tmux,
+    /// zellij (zjstatus) `#[fg=...]` style sequences
+    Zellij,
+    /// ANSI escapes without hyperlinks, for `window:set_right_status`
+    Wezterm,
+}
@@ -1,14 +1,16 @@
+mod alias;
 mod config;
 mod hook;
 mod list;
 mod step;
 
+pub(crate) use alias::AliasCommand;
 pub(crate) use config::{
     ApprovalsCommand, CiStatusAction, ConfigCommand, ConfigShellCommand, DefaultBranchAction,
     HintsAction, LogsAction, MarkerAction, PreviousBranchAction, StateCommand,
 };
 pub(crate) use hook::HookCommand;
-pub(crate) use list::ListSubcommand;
+pub(crate) use list::{ListSubcommand, StatuslineFormat};
 pub(crate) use step::StepCommand;
 
 use clap::builder::styling::{AnsiColor, Color, Styles};
@@ -39,6 +41,28 @@ pub(super) fn parse_key_val(s: &str) -> Result<(String, String), String> {
     Ok((key.to_string(), value.to_string()))
 }
 
+/// Parse an age like `30m`, `12h`, `7d` or `2w`.
+///
+/// Used by `wt history --since` and `wt report --stale-after`.
+fn parse_age(s: &str) -> Result<std::time::Duration, String> {
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (count, unit) = s.split_at(split);
+    let count: u64 = count
+        .parse()
+        .map_err(|_| format!("invalid age `{s}`: expected a number and unit, like 7d"))?;
+    let unit_secs = match unit {
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(format!("invalid age `{s}`: unit must be m, h, d or w")),
+    };
+    count
+        .checked_mul(unit_secs)
+        .map(std::time::Duration::from_secs)
+        .ok_or_else(|| format!("invalid age `{s}`: too large"))
+}
+
 /// Custom styles for help output - matches worktrunk's color scheme
 fn help_styles() -> Styles {
     Styles::styled()
@@ -78,6 +102,12 @@ fn help_styles() -> Styles {
 /// Default command name for worktrunk
 const DEFAULT_COMMAND_NAME: &str = "wt";
 
+/// `--wait` help, shared by the commands that take the operation lock
+const WAIT_HELP: &str = "Wait for another wt operation to finish instead of failing";
+const WAIT_LONG_HELP: &str = "Wait for another wt operation to finish instead of failing
+
+Worktree creation and removal hold a lock in the common git directory; without this flag, wt fails while another process holds it.";
+
 /// Help template for commands
 const HELP_TEMPLATE: &str = "\
 {before-help}{name} - {about-with-newline}
@@ -181,6 +211,24 @@ pub(crate) enum OutputFormat {
     Json,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum LogFormat {
+    /// Thread-prefixed lines for reading
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ListLayout {
+    /// Records on narrow terminals, otherwise a table
+    Auto,
+    /// One row per item
+    Table,
+    /// One labeled line per field, items separated by blank lines
+    Record,
+}
+
 #[derive(Parser)]
 #[command(name = "wt")]
 #[command(about = "Git worktree management for parallel AI agent workflows", long_about = None)]
@@ -210,10 +258,11 @@ pub(crate) struct Cli {
         short = 'C',
         global = true,
         value_name = "path",
+        action = clap::ArgAction::Append,
         display_order = 100,
         help_heading = "Global Options"
     )]
-    pub directory: Option<std::path::PathBuf>,
+    pub directory: Vec<std::path::PathBuf>,
 
     /// User config file path
     #[arg(
@@ -236,6 +285,33 @@ pub(crate) struct Cli {
     )]
     pub verbose: u8,
 
+    /// Answer no to all prompts
+    #[arg(
+        long,
+        global = true,
+        display_order = 103,
+        help_heading = "Global Options"
+    )]
+    pub no: bool,
+
+    /// Answer yes to all prompts
+    #[arg(
+        short,
+        long,
+        global = true,
+        display_order = 104,
+        help_heading = "Global Options"
+    )]
+    pub yes: bool,
+
+    /// Debug log format (also `WORKTRUNK_LOG_FORMAT`)
+    #[arg(long, global = true, value_name = "format", hide = true)]
+    pub log_format: Option<LogFormat>,
+
+    /// Print a breakdown of time spent in git, CI, and other commands at exit
+    #[arg(long, global = true, hide = true)]
+    pub timings: bool,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -263,6 +339,8 @@ wt switch pr:123                 # Switch to PR #123's branch
 
 The `--create` flag creates a new branch from the `--base` branch (defaults to default branch). Without `--create`, the branch must already exist.
 
+`--from-template <name>` starts the new branch with a commit of the `[templates.<name>]` directory from `.config/wt.toml` — useful for branches that always begin from the same boilerplate, like docs or experiments.
+
 ## Creating worktrees
 
 If the branch already has a worktree, `wt switch` changes directories to it. Otherwise, it creates one, running [hooks](@/hook.md).
@@ -279,6 +357,7 @@ wt switch feature                        # Existing branch → creates worktree
 wt switch --create feature               # New branch and worktree
 wt switch --create fix --base release    # New branch from release
 wt switch --create temp --no-verify      # Skip hooks
+wt switch --create-all 'review/*'        # Worktrees for every review/ branch
 ```
 
 ## Shortcuts
@@ -288,10 +367,12 @@ wt switch --create temp --no-verify      # Skip hooks
 | `^` | Default branch (`main`/`master`) |
 | `@` | Current branch/worktree |
 | `-` | Previous worktree (like `cd -`) |
+| `-N` | Nth previous worktree (`--history` to pick) |
 | `pr:{N}` | GitHub PR #N's branch |
 
 ```console
 wt switch -                      # Back to previous
+wt switch -2                     # Two worktrees back
 wt switch ^                      # Default branch worktree
 wt switch --create fix --base=@  # Branch from current HEAD
 wt switch pr:123                 # PR #123's branch
@@ -311,7 +392,7 @@ Requires `gh` CLI to be installed and authenticated. The `--create` flag cannot
 
 ## When wt switch fails
 
-- **Branch doesn't exist** — Use `--create`, or check `wt list --branches`
+- **Branch doesn't exist** — A branch uniquely containing the name is used instead; otherwise similar names are suggested. Use `--create`, or check `wt list --branches`
 - **Path occupied** — Another worktree is at the target path; switch to it or remove it
 - **Stale directory** — Use `--clobber` to remove a non-worktree directory at the target path
 
@@ -328,9 +409,13 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
     Switch {
         /// Branch name or shortcut
         ///
-        /// Shortcuts: '^' (default branch), '-' (previous), '@' (current), 'pr:{N}' (GitHub PR, experimental)
-        #[arg(add = crate::completion::worktree_branch_completer())]
-        branch: String,
+        /// Shortcuts: '^' (default branch), '-' (previous), '-N' (Nth previous), '@' (current), 'pr:{N}' (GitHub PR, experimental)
+        #[arg(
+            required_unless_present_any = ["history", "create_all"],
+            allow_negative_numbers = true,
+            add = crate::completion::worktree_branch_completer()
+        )]
+        branch: Option<String>,
 
         /// Create a new branch
         #[arg(short = 'c', long)]
@@ -342,6 +427,14 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         #[arg(short = 'b', long, add = crate::completion::branch_value_completer())]
         base: Option<String>,
 
+        /// Scaffold the new branch from a template
+        ///
+        /// Copies the `[templates.<NAME>]` directory from the project config
+        /// into the new worktree and commits it as the branch's first commit,
+        /// before post-create hooks run.
+        #[arg(long, value_name = "NAME", requires = "create")]
+        from_template: Option<String>,
+
         /// Command to run after switch
         ///
         /// Replaces the wt process with the command after switching, giving
@@ -385,11 +478,157 @@ To change which branch a worktree is on, use `git switch` inside that worktree.
         #[arg(long)]
         clobber: bool,
 
+        /// Pick from recently visited branches
+        #[arg(long, conflicts_with_all = ["branch", "create", "base"])]
+        history: bool,
+
+        /// Create worktrees for all branches matching a pattern
+        ///
+        /// `*` matches any characters, e.g. `'feature/*'`. Covers local
+        /// branches and remote branches without a local copy; branches that
+        /// already have a worktree are left alone. Post-create hooks run for
+        /// the new worktrees in parallel, then a table lists each branch's
+        /// result. Post-start and post-switch hooks don't run, and the shell
+        /// stays in the current directory.
+        #[arg(
+            long,
+            value_name = "PATTERN",
+            conflicts_with_all = ["branch", "create", "base", "history", "execute", "from_template"]
+        )]
+        create_all: Option<String>,
+
+        /// Put the current detached worktree on BRANCH
+        ///
+        /// Creates BRANCH at HEAD if it doesn't exist, and fast-forwards it if
+        /// HEAD is ahead. Fails if they've diverged.
+        #[arg(
+            long,
+            requires = "branch",
+            conflicts_with_all = ["create", "base", "history", "execute", "from_template", "create_all", "clobber"]
+        )]
+        reattach: bool,
+
+        /// Switch away despite a rebase or merge in progress
+        #[arg(long)]
+        force: bool,
+
+        /// Skip hooks
+        #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        verify: bool,
+
+        /// Skip hooks of one type (repeatable)
+        #[arg(long, value_name = "HOOK", hide_possible_values = true)]
+        skip_hook: Vec<worktrunk::HookType>,
+
+        #[arg(long, help = WAIT_HELP, long_help = WAIT_LONG_HELP)]
+        wait: bool,
+    },
+
+    /// Print a worktree's path
+    ///
+    /// Resolves the branch like `wt switch`, without changing directory.
+    #[command(
+        after_long_help = r#"Only the path goes to stdout, so the output can be used directly in any shell, script or editor integration. Without a branch, prints the current worktree's path.
+
+A branch without a worktree is an error, unless `--create` creates the worktree — from the local branch, or from a remote branch of that name — running `post-create` and `post-start` hooks as `wt switch` does. `wt path` never creates branches; use `wt switch --create` for that.
+
+## Examples
+
+Change directory without shell integration:
+
+```console
+cd "$(wt path feature)"
+```
+
+Open a branch in an editor, creating its worktree if needed:
+
+```console
+code "$(wt path feature --create)"
+```
+
+Path of the default branch's worktree:
+
+```console
+wt path ^
+```
+
+## See also
+
+- [`wt switch`](@/switch.md) — Switch to the worktree, with shell integration
+"#
+    )]
+    Path {
+        /// Branch name or shortcut [default: current worktree]
+        ///
+        /// Shortcuts: '^' (default branch), '-' (previous), '-N' (Nth previous), '@' (current), 'pr:{N}' (GitHub PR, experimental)
+        #[arg(
+            allow_negative_numbers = true,
+            add = crate::completion::worktree_branch_completer()
+        )]
+        branch: Option<String>,
+
+        /// Create the worktree if the branch doesn't have one
+        #[arg(long)]
+        create: bool,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+
         /// Skip hooks
         #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
         verify: bool,
     },
 
+    /// Go to the primary worktree
+    ///
+    /// Prints its path; with shell integration, also changes to it.
+    #[command(
+        after_long_help = r#"The primary worktree is where the repository was cloned — the main worktree, or in a bare repository, the default branch's worktree. It's where ignored files and local config usually live, and what `{{ primary_worktree_path }}` refers to in hooks.
+
+## Examples
+
+Return to the primary worktree from anywhere in the repository:
+
+```console
+wt main
+```
+
+Copy a local config file from it, without shell integration:
+
+```console
+cp "$(wt main)/.env" .
+```
+
+## See also
+
+- [`wt root`](@/root.md) — The common git directory
+- [`wt switch ^`](@/switch.md) — Switch to the default branch's worktree
+"#
+    )]
+    Main,
+
+    /// Go to the common git directory
+    ///
+    /// Prints its path; with shell integration, also changes to it.
+    #[command(
+        after_long_help = r#"The common git directory holds the data shared by all worktrees: objects, refs, config, and worktrunk's logs (`wt-logs/`) and caches. For a normal repository it's the main worktree's `.git`; for a bare repository, the repository itself.
+
+## Examples
+
+Follow the background logs of a post-start hook:
+
+```console
+tail -f "$(wt root)/wt-logs/"*
+```
+
+## See also
+
+- [`wt main`](@/main.md) — The primary worktree
+"#
+    )]
+    Root,
+
     /// List worktrees and their status
     #[command(
         after_long_help = r#"Shows uncommitted changes, divergence from the default branch and remote, and optional CI status.
@@ -421,6 +660,12 @@ Include branches that don't have worktrees:
 $ wt list --branches --full
 ```
 
+Add remote branches that have no local branch of the same name, to spot work worth checking out:
+
+```console
+$ wt list --branches --remotes
+```
+
 Output as JSON for scripting:
 
 ```console
@@ -431,24 +676,29 @@ $ wt list --format=json
 
 | Column | Shows |
 |--------|-------|
+| # | Row number for `wt switch N` (`--index`) |
 | Branch | Branch name |
 | Status | Compact symbols (see below) |
 | HEAD± | Uncommitted changes: +added -deleted lines |
 | main↕ | Commits ahead/behind default branch |
+| Graph | Divergence from default branch, a dot per commit (`--graph`) |
 | main…± | Line diffs since the merge-base with the default branch (`--full`) |
 | Path | Worktree directory |
 | Remote⇅ | Commits ahead/behind tracking branch |
 | URL | Dev server URL from project config (dimmed if port not listening) |
+| Issue | Issue key from branch name (`[integrations.issues]`) |
 | CI | Pipeline status (`--full`) |
+| Size | Disk usage, excluding `.git` (`--du`) |
+| Owner | `WT_OWNER` marker or last commit author (`--owner`) |
 | Commit | Short hash (8 chars) |
-| Age | Time since last commit |
-| Message | Last commit message (truncated) |
+| Age | Time since last commit, or its date with `--absolute-time` |
+| Message | Last commit message, or branch description with `--full` (truncated) |
 
 Note: `main↕` and `main…±` refer to the default branch (header label stays `main` for compactness). `main…±` uses a merge-base (three-dot) diff.
 
 ### CI status
 
-The CI column shows GitHub/GitLab pipeline status:
+The CI column shows GitHub/GitLab/Gitea/Bitbucket pipeline status:
 
 | Indicator | Meaning |
 |-----------|---------|
@@ -460,8 +710,37 @@ The CI column shows GitHub/GitLab pipeline status:
 | `⚠` yellow | Fetch error (rate limit, network) |
 | (blank) | No upstream or no PR/MR |
 
+An open PR/MR also shows its review state after the CI dot:
+
+| Indicator | Meaning |
+|-----------|---------|
+| `✓` green | Approved |
+| `✎` red | Changes requested |
+| `○` yellow | Waiting on review |
+| `◌` dim | Draft |
+
 CI indicators are clickable links to the PR or pipeline page. Any CI dot appears dimmed when there are unpushed local changes (stale status). PRs/MRs are checked first, then branch workflows/pipelines for branches with an upstream. Local-only branches show blank. Results are cached for 30-60 seconds; use `wt config state` to view or clear.
 
+### Disk usage
+
+`--du` adds a Size column with each worktree's disk usage, excluding `.git`. Sizes are computed in parallel and cached per top-level directory in `.git/wt-cache/disk-usage/`; a directory is re-measured when its modification time changes, so installing dependencies or clearing build output is picked up without re-walking unchanged trees.
+
+### Ownership
+
+`--owner` adds an Owner column for shared checkouts. It shows the contents of a `WT_OWNER` file in the worktree's git directory (`git rev-parse --git-dir`) when present, and otherwise the author of the latest commit. `--mine` keeps only branches whose latest commit was made with the configured `user.email`; the main worktree is always shown.
+
+### Graph
+
+`--graph` adds a Graph column drawing each branch against the default branch, like a one-line `git log --graph`: `○` for each default-branch commit the branch lacks, `◆` for the merge-base, and `●` for each of the branch's own commits. The merge-base sits at the same position in every row, so the column reads as a timeline; up to five commits show on each side, and `…` marks more. It uses the same counts as main↕, so it costs nothing extra.
+
+### Row numbers
+
+`--index` numbers each row. For the next hour, until the next `wt list --index`, `wt switch` and `wt remove` also accept a row number in place of a branch: `wt switch 3`, `wt remove 2 5`. A branch whose name is a number takes precedence, and `--create` and `--base` always take a branch name. Set `index = true` under `[list]` in user config to always number rows.
+
+### Column layout
+
+`--tune` opens the table in an interactive editor: ←/→ pick a column, ↑/↓ widen or narrow it, space hides or shows it, `r` resets it, and enter saves. The layout is stored per repository in git config (`worktrunk.list-columns`) and used by every later `wt list` in place of automatic widths; `--no-truncate` still shows full values. Diff columns can be hidden but not resized.
+
 ## Status symbols
 
 The Status column has multiple subcolumns. Within each, only the first matching symbol is shown (listed in priority order):
@@ -491,6 +770,8 @@ The Status column has multiple subcolumns. Within each, only the first matching
 | | `⇅` | Diverged from remote |
 | | `⇡` | Ahead of remote |
 | | `⇣` | Behind remote |
+| | `⇈` | Tracks a remote branch not pushed yet |
+| | `⊘` | Remote branch deleted (upstream gone) |
 
 Rows are dimmed when [safe to delete](@/remove.md#branch-cleanup) (`_` same commit with clean working tree or `⊂` content integrated).
 
@@ -524,8 +805,16 @@ wt list --format=json | jq '.[] | select(.remote.ahead > 0) | {branch, ahead: .r
 
 # Stale CI (local changes not reflected in CI)
 wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
+
+# PRs/MRs waiting on review
+wt list --format=json --full | jq '.[] | select(.ci.review_state == "review-required") | .branch'
+
+# Largest worktrees first
+wt list --format=json --du | jq -r 'sort_by(-.worktree.disk_usage) | .[] | .path'
 ```
 
+Prompts and status bars that call `wt list --format=json` every few seconds can set `cache = true` under `[list]` in user config. A repeated call then prints the previous result immediately when refs and staged changes are unchanged, and a background process recomputes it.
+
 **Fields:**
 
 | Field | Type | Description |
@@ -547,6 +836,9 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `ci` | object | CI status (see below, absent when no CI) |
 | `url` | string | Dev server URL from project config (absent when not configured) |
 | `url_active` | boolean | Whether the URL's port is listening (absent when not configured) |
+| `description` | string | Branch description, set with `wt describe` (`--full` only, absent when unset) |
+| `owner` | string | `WT_OWNER` marker or last commit author (only with `--owner`) |
+| `issue` | object | Issue from the branch name: `{key, id, url}` (absent when not configured or no match) |
 | `statusline` | string | Pre-formatted status with ANSI colors |
 | `symbols` | string | Raw status symbols without colors (e.g., `"!?↓"`) |
 
@@ -558,6 +850,7 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `short_sha` | string | Short commit SHA (7 chars) |
 | `message` | string | Commit message (first line) |
 | `timestamp` | number | Unix timestamp |
+| `time` | string/null | Commit time, ISO 8601 UTC |
 
 ### working_tree object
 
@@ -594,6 +887,7 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `state` | string | `"no_worktree"`, `"branch_worktree_mismatch"`, `"prunable"`, `"locked"` (absent when normal) |
 | `reason` | string | Reason for locked/prunable state |
 | `detached` | boolean | HEAD is detached |
+| `disk_usage` | number | Disk usage in bytes, excluding `.git` (only with `--du`) |
 
 ### ci object
 
@@ -603,6 +897,7 @@ wt list --format=json --full | jq '.[] | select(.ci.stale) | .branch'
 | `source` | string | `"pr"` (PR/MR) or `"branch"` (branch workflow) |
 | `stale` | boolean | Local HEAD differs from remote (unpushed changes) |
 | `url` | string | URL to the PR/MR page |
+| `review_state` | string | PR/MR review state (see below) |
 
 ### main_state values
 
@@ -618,6 +913,10 @@ When `main_state == "integrated"`: `"ancestor"` `"trees_match"` `"no_added_chang
 
 `"passed"` `"running"` `"failed"` `"conflicts"` `"no-ci"` `"error"`
 
+### ci.review_state values
+
+`"draft"` `"review-required"` `"changes-requested"` `"approved"`
+
 Missing a field that would be generally useful? Open an issue at https://github.com/max-sixty/worktrunk.
 
 ## See also
@@ -638,18 +937,71 @@ Missing a field that would be generally useful? Open an issue at https://github.
         #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
         format: OutputFormat,
 
+        /// Table or per-item records (auto, table, record)
+        ///
+        /// auto shows records when the terminal is narrower than record-below
+        /// under [list] in user config (default 60 columns).
+        #[arg(long, value_enum, default_value = "auto", hide_possible_values = true)]
+        layout: ListLayout,
+
         /// Include branches without worktrees
         #[arg(long)]
         branches: bool,
 
         /// Include remote branches
-        #[arg(long)]
+        #[arg(long, alias = "remote")]
         remotes: bool,
 
         /// Include CI status and diff analysis (slower)
         #[arg(long)]
         full: bool,
 
+        /// Include disk usage per worktree (slower)
+        #[arg(long)]
+        du: bool,
+
+        /// Include an Owner column
+        ///
+        /// Shows the worktree's WT_OWNER marker, else the last commit's author.
+        #[arg(long)]
+        owner: bool,
+
+        /// Only branches last committed by user.email
+        #[arg(long)]
+        mine: bool,
+
+        /// Number rows, addressable as wt switch N
+        #[arg(long)]
+        index: bool,
+
+        /// Draw each branch's divergence from main
+        #[arg(long)]
+        graph: bool,
+
+        /// Show commit dates instead of relative ages
+        #[arg(long)]
+        absolute_time: bool,
+
+        /// Show full values instead of truncating to the terminal
+        ///
+        /// Lines longer than the terminal wrap. Per-column limits are set
+        /// with max-width under [list] in user config.
+        #[arg(long)]
+        no_truncate: bool,
+
+        /// Adjust column widths interactively
+        ///
+        /// Arrow keys pick a column and change its width, space hides it, and
+        /// enter saves the layout for this repository.
+        #[arg(long, conflicts_with = "format")]
+        tune: bool,
+
+        /// Omit the summary line after the table
+        ///
+        /// Metrics are configured with summary under [list] in user config.
+        #[arg(long)]
+        no_summary: bool,
+
         /// Show fast info immediately, update with slow info
         ///
         /// Displays local data (branches, paths, status) first, then updates
@@ -660,6 +1012,10 @@ Missing a field that would be generally useful? Open an issue at https://github.
         /// Force buffered rendering
         #[arg(long = "no-progressive", overrides_with = "progressive", hide = true)]
         no_progressive: bool,
+
+        /// Recompute the cached JSON output without printing it
+        #[arg(long, hide = true)]
+        refresh_cache: bool,
     },
 
     /// Remove worktree; delete branch if merged
@@ -725,9 +1081,17 @@ wt remove feature --force -D    # Both
 
 Without `--force`, removal fails if the worktree contains untracked files. Without `-D`, removal keeps branches with unmerged changes. Use `--no-delete-branch` to keep the branch regardless of merge status.
 
+### Removing dirty worktrees
+
+`--force-dirty` removes a worktree with uncommitted changes after saving them. Staged, unstaged, and untracked changes are committed (without touching the worktree or index) to `refs/wt-backup/<branch>`, the ref `wt merge` uses for its backups, and the commit is printed. To restore, run `git checkout <commit> -- .` in any worktree. Earlier backups stay in the ref's reflog: `git reflog show refs/wt-backup/<branch>`.
+
+### Locked worktrees
+
+Worktrees locked with `git worktree lock` are refused, and the error shows the lock reason. Interactive sessions are prompted to unlock and remove; `--unlock` does the same without prompting. The lock is released only after all other checks pass.
+
 ## Background removal
 
-Removal runs in the background by default (returns immediately). Logs are written to `.git/wt-logs/{branch}-remove.log`. Use `--foreground` to run in the foreground.
+Removal runs in the background by default (returns immediately). The worktree is first moved into `.git/wt-trash/`, so it disappears right away; its files are then deleted by a detached process. `wt ps` shows deletions still in progress. Logs are written to `.git/wt-logs/{branch}-remove.log`. Use `--foreground` to wait for deletion, with a progress spinner.
 
 ## See also
 
@@ -769,204 +1133,982 @@ Removal runs in the background by default (returns immediately). Logs are writte
         /// artifacts). Without this flag, removal fails if untracked files exist.
         #[arg(short, long)]
         force: bool,
+
+        /// Back up uncommitted changes, then remove
+        ///
+        /// Saves staged, unstaged, and untracked changes to refs/wt-backup/<branch>
+        /// before removing a dirty worktree. Implies --force.
+        #[arg(long = "force-dirty")]
+        force_dirty: bool,
+
+        /// Unlock locked worktrees before removing
+        ///
+        /// Without this flag, locked worktrees are refused (interactive sessions
+        /// are prompted to unlock instead).
+        #[arg(long)]
+        unlock: bool,
+
+        #[arg(long, help = WAIT_HELP, long_help = WAIT_LONG_HELP)]
+        wait: bool,
     },
 
-    /// Merge current branch into target
+    /// Show background removals in progress
     ///
-    /// Squash & rebase, fast-forward target, remove the worktree.
+    /// Lists removed worktrees whose files are still being deleted.
     #[command(
-        after_long_help = r#"Unlike `git merge`, this merges current into target (not target into current). Similar to clicking "Merge pull request" on GitHub, but locally. Target defaults to the default branch.
+        after_long_help = r#"`wt remove` moves a worktree into `.git/wt-trash/` and deletes its files in a detached process. Large directories like `node_modules/` can take a while; `wt ps` shows each pending deletion with the space it still occupies and when it started.
 
-<!-- demo: wt-merge.gif 1600x900 -->
+Entries that stay listed indicate a deletion that was interrupted. Check the log in `.git/wt-logs/{branch}-remove.log`, then delete the directory under `.git/wt-trash/` by hand.
+
+## See also
+
+- [`wt remove`](@/remove.md) — Remove worktrees
+"#
+    )]
+    Ps,
+
+    /// Show recent worktree activity
+    ///
+    /// Lists switches, creations, removals and merges across all worktrees, oldest first.
+    #[command(
+        after_long_help = r#"Every `wt switch`, `wt remove` and `wt merge` appends an entry — time, operation, branch, worktree path, and whether it succeeded — to `.git/wt-history.jsonl` in the repository's common git directory, so activity from all worktrees ends up in one log.
 
 ## Examples
 
-Merge to the default branch:
+What did I work on this week?
 
 ```console
-wt merge
+wt history --since 7d
 ```
 
-Merge to a different branch:
+Branches touched today, as JSON:
 
 ```console
-wt merge develop
+wt history --since 1d --format=json | jq -r '.[].branch' | sort -u
 ```
 
-Keep the worktree after merging:
+`--since` takes a number followed by `m` (minutes), `h`, `d` or `w`.
 
-```console
-wt merge --no-remove
-```
+## See also
 
-Preserve commit history (no squash):
+- [`wt switch`](@/switch.md) — `--history` picks from recently visited branches
+"#
+    )]
+    History {
+        /// Only show entries newer than this (e.g. 12h, 7d, 2w)
+        #[arg(long, value_name = "AGE", value_parser = parse_age)]
+        since: Option<std::time::Duration>,
+
+        /// Output format (table, json)
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
+
+    /// Summarize worktrees that need cleanup
+    ///
+    /// Stale and integrated worktrees, branches far behind the default branch, and disk usage.
+    #[command(
+        after_long_help = r#"Prints a short report suited to a shell login message or a scheduled CI job:
+
+- **No recent commits** — worktrees whose last commit is older than `--stale-after`
+- **Integrated** — worktrees whose branch is already in the default branch (safe to `wt remove`)
+- **Behind** — local branches more than `--behind` commits behind the default branch
+- **Disk usage** — per worktree, largest first (cached like `wt list --du`)
+
+## Examples
+
+Show the report when opening a shell in a repository:
 
 ```console
-wt merge --no-squash
+wt report
 ```
 
-Skip committing/squashing (rebase still runs unless --no-rebase):
+Weekly cron job posting integrated worktrees somewhere:
 
 ```console
-wt merge --no-commit
+wt report --format=json | jq -r '.integrated[].branch'
 ```
 
-## Pipeline
+## See also
 
-`wt merge` runs these steps:
+- [`wt list`](@/list.md) — Full status of every worktree
+- [`wt remove`](@/remove.md) — Remove the worktrees it lists
+"#
+    )]
+    Report {
+        /// Worktrees without commits for this long count as stale (e.g. 2w, 30d)
+        #[arg(long, value_name = "AGE", value_parser = parse_age, default_value = "30d")]
+        stale_after: std::time::Duration,
 
-1. **Squash** — Stages uncommitted changes, then combines all commits since target into one (like GitHub's "Squash and merge"). Use `--stage` to control what gets staged: `all` (default), `tracked`, or `none`. A backup ref is saved to `refs/wt-backup/<branch>`. With `--no-squash`, uncommitted changes become a separate commit and individual commits are preserved.
-2. **Rebase** — Rebases onto target if behind. Skipped if already up-to-date. Conflicts abort immediately.
-3. **Pre-merge hooks** — Hooks run after rebase, before merge. Failures abort. See [`wt hook`](@/hook.md).
-4. **Merge** — Fast-forward merge to the target branch. Non-fast-forward merges are rejected.
-5. **Pre-remove hooks** — Hooks run before removing worktree. Failures abort.
-6. **Cleanup** — Removes the worktree and branch. Use `--no-remove` to keep the worktree. When already on the target branch or in the main worktree, the worktree is preserved.
-7. **Post-merge hooks** — Hooks run after cleanup. Failures are logged but don't abort.
+        /// Branches more than this many commits behind count as behind
+        #[arg(long, value_name = "N", default_value_t = 50)]
+        behind: usize,
 
-Use `--no-commit` to skip committing uncommitted changes and squashing; rebase still runs by default and can rewrite commits unless `--no-rebase` is passed. Useful after preparing commits manually with `wt step`. Requires a clean working tree.
+        /// Output format (table, json)
+        #[arg(long, value_enum, default_value = "table", hide_possible_values = true)]
+        format: OutputFormat,
+    },
 
-## Local CI
+    /// Clear build output across worktrees
+    ///
+    /// Previews `git clean -Xd` in each worktree with sizes; `--force` deletes.
+    #[command(
+        after_long_help = r#"Build output, dependency directories and logs pile up in every worktree. `wt clean` runs `git clean -Xd` in each one — or only in the worktrees named — and lists what it would delete with the space each path takes, largest first. Nothing is deleted without `--force`, and cleaning every worktree asks for confirmation first; `--yes` skips the prompt.
 
-For personal projects, pre-merge hooks open up the possibility of a workflow with much faster iteration — an order of magnitude more small changes instead of fewer large ones.
+Only ignored files such as `target/` and `node_modules/` are deleted, including files like `.env` that are ignored but not regenerated, so check the preview. `--untracked` also deletes untracked files that aren't ignored (`git clean -xd`), such as new files that haven't been committed yet.
 
-Historically, ensuring tests ran before merging was difficult to enforce locally. Remote CI was valuable for the process as much as the checks: it guaranteed validation happened. `wt merge` brings that guarantee local.
+Worktrees that fail are reported at the end, as with `wt step for-each`; the others are still cleaned.
 
-The full workflow: start an agent (one of many) on a task, work elsewhere, return when it's ready. Review the diff, run `wt merge`, move on. Pre-merge hooks validate before merging — if they pass, the branch goes to the default branch and the worktree cleans up.
+## Examples
 
-```toml
-[pre-merge]
-test = "cargo test"
-lint = "cargo clippy"
+See how much space clean builds would free:
+
+```console
+wt clean
+```
+
+Delete build output in two worktrees:
+
+```console
+wt clean feature-a feature-b --force
 ```
 
 ## See also
 
-- [`wt step`](@/step.md) — Run individual operations (commit, squash, rebase, push)
-- [`wt remove`](@/remove.md) — Remove worktrees without merging
-- [`wt switch`](@/switch.md) — Navigate to other worktrees
+- [`wt report`](@/report.md) — Disk usage per worktree
+- [`wt step for-each`](@/step.md#wt-step-for-each) — Run any command in every worktree
 "#
     )]
-    Merge {
-        /// Target branch
-        ///
-        /// Defaults to default branch.
-        #[arg(add = crate::completion::branch_value_completer())]
-        target: Option<String>,
+    Clean {
+        /// Worktrees to clean [default: all]
+        #[arg(add = crate::completion::worktree_branch_completer())]
+        branches: Vec<String>,
 
-        /// Force commit squashing
-        #[arg(long, overrides_with = "no_squash", hide = true)]
-        squash: bool,
+        /// Delete the files instead of listing them
+        #[arg(short, long)]
+        force: bool,
 
-        /// Skip commit squashing
-        #[arg(long = "no-squash", overrides_with = "squash")]
-        no_squash: bool,
+        /// Also delete untracked files that aren't ignored (`git clean -x`)
+        #[arg(long)]
+        untracked: bool,
 
-        /// Force commit and squash
-        #[arg(long, overrides_with = "no_commit", hide = true)]
-        commit: bool,
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Update branch from the default branch
+    ///
+    /// Fetches, then rebases onto (or merges) the remote default branch.
+    #[command(
+        after_long_help = r#"Keeps a worktree's branch current with the default branch in one step: fetch the remote, then rebase the branch onto the default branch as the remote has it (e.g. `origin/main`). Without an upstream for the default branch, the local branch is used. The local default branch itself isn't moved.
+
+`--strategy merge` (or `strategy = "merge"` under `[update]` in user config) merges the default branch in instead of rebasing, for branches that are already shared.
+
+Uncommitted changes are stashed for the update and restored afterwards (git's `--autostash`). If restoring them conflicts, they stay in `git stash` and a warning says so.
+
+With `fast-forward-clean = true` under `[fetch]` in user config, the fetch also fast-forwards every other worktree that is strictly behind the default branch — no commits of its own, no uncommitted changes — and lists what moved. The default branch, and branches that track a different upstream, stay put. Long-lived worktrees for docs or test runs stay current without being visited.
+
+A conflict stops the update with the rebase or merge left in progress; resolve it and run `git rebase --continue` or `git merge --continue`, or undo it with `--abort`. Afterwards, the branch's new ahead/behind counts against the default branch are shown.
+
+## Examples
+
+Update the current worktree:
+
+```console
+wt update
+```
+
+Update another worktree by merging:
+
+```console
+wt update feature --strategy merge
+```
+
+## See also
+
+- [`wt step rebase`](@/step.md#wt-step-rebase) — Rebase onto a local branch without fetching
+- [`wt list`](@/list.md) — The `main↕` column shows how far each branch is behind
+"#
+    )]
+    Update {
+        /// Branch whose worktree to update [default: current]
+        #[arg(add = crate::completion::worktree_branch_completer())]
+        branch: Option<String>,
+
+        /// Rebase onto the default branch or merge it in [default: rebase]
+        #[arg(long)]
+        strategy: Option<worktrunk::config::UpdateStrategy>,
+    },
+
+    /// Merge current branch into target
+    ///
+    /// Squash & rebase, fast-forward target, remove the worktree.
+    #[command(
+        after_long_help = r#"Unlike `git merge`, this merges current into target (not target into current). Similar to clicking "Merge pull request" on GitHub, but locally. Target defaults to the default branch.
+
+<!-- demo: wt-merge.gif 1600x900 -->
+
+## Examples
+
+Merge to the default branch:
+
+```console
+wt merge
+```
+
+Merge to a different branch:
+
+```console
+wt merge develop
+```
+
+Keep the worktree after merging:
+
+```console
+wt merge --no-remove
+```
+
+Preserve commit history (no squash):
+
+```console
+wt merge --no-squash
+```
+
+Fold fixup commits but otherwise keep history:
+
+```console
+wt merge --no-squash --autosquash
+```
+
+Skip committing/squashing (rebase still runs unless --no-rebase):
+
+```console
+wt merge --no-commit
+```
+
+## Pipeline
+
+`wt merge` runs these steps:
+
+1. **Squash** — Stages uncommitted changes, then combines all commits since target into one (like GitHub's "Squash and merge"). Use `--stage` to control what gets staged: `all` (default), `tracked`, or `none`. A backup ref is saved to `refs/wt-backup/<branch>`. With `--no-squash`, uncommitted changes become a separate commit and individual commits are preserved.
+2. **Rebase** — Rebases onto target if behind. Skipped if already up-to-date. Conflicts abort immediately.
+3. **Pre-merge hooks** — Hooks run after rebase, before merge. Failures abort. See [`wt hook`](@/hook.md).
+4. **Merge** — Fast-forward merge to the target branch. Non-fast-forward merges are rejected. With `--push`, the updated target is then pushed to its remote.
+5. **Pre-remove hooks** — Hooks run before removing worktree. Failures abort.
+6. **Cleanup** — Removes the worktree and branch. Use `--no-remove` to keep the worktree. When already on the target branch or in the main worktree, the worktree is preserved.
+7. **Post-merge hooks** — Hooks run after cleanup. Failures are logged but don't abort.
+
+Use `--no-commit` to skip committing uncommitted changes and squashing; rebase still runs by default and can rewrite commits unless `--no-rebase` is passed. Useful after preparing commits manually with `wt step`. Requires a clean working tree.
+
+Projects can set the pipeline in `.config/wt.toml`. `[merge] steps` lists the built-in steps in order — `commit`, `squash`, `rebase`, `hooks` (pre-merge), `push` (the fast-forward), `cleanup` — and can insert `[profiles]` commands between them:
+
+```toml
+[merge]
+steps = ["commit", "rebase", "lint", "hooks", "push", "cleanup"]
+
+[profiles.lint]
+command = "cargo clippy -- -D warnings"
+```
+
+Built-in steps left out are off unless a flag or the user's `[merge]` config turns them on — here `squash`, so history is kept. Profiles run where they're listed; like hooks, they need approval and are skipped with `--no-verify`. The list is checked before anything runs: built-in steps keep the order above, `push` is required, `squash` needs `commit`, and nothing can follow `cleanup`.
+
+## Pushing to a remote
+
+`--push` (or `push = true` under `[merge]` in user config) pushes the target branch to its remote after the fast-forward. In fork workflows, set `[repo] upstream-remote` in the project config so merge targets go to the canonical repository while feature branches keep pushing to `origin`; without it, the primary remote is used.
+
+The remote's copy of the target (as of the last fetch) must be an ancestor of the local target. This is checked before anything is committed or rebased, so a push that would be rejected fails without updating the local branch.
+
+## Waiting for CI
+
+`--wait-ci` (or `wait-ci = true` under `[merge]` in user config) pushes the branch after the pre-merge hooks and waits for its CI before the fast-forward, so the target only moves once the checks pass on what will land. The push uses `--force-with-lease`, since squashing and rebasing rewrite the branch. Status comes from the same forge CLI or API as `wt list`'s CI column, checked every `ci-interval` seconds (default 15) for up to `ci-timeout` seconds (default 1800).
+
+Failed checks, a conflict with the base, a timeout, or no checks within a minute (or `ci-timeout`, if shorter) stop the merge before the target is updated; fix the branch and run `wt merge --continue`.
+
+## Interrupted merges
+
+If a merge stops after committing, squashing, or rebasing but before the fast-forward — a rebase conflict, a failing pre-merge hook, Ctrl+C — the next `wt merge` on that branch reports it rather than starting over:
+
+- `wt merge --continue` finishes a rebase left in progress (after conflicts are resolved and staged), then runs the remaining steps toward the original target. Flags such as `--no-remove` or `--push` apply to those steps; flags for committing, squashing, and rebasing are refused, since those steps have already run
+- `wt merge --abort` rolls the branch back to where the merge started; changes the merge committed or squashed stay staged
+
+The starting point is recorded in `worktrunk.state.<branch>.merge` once a step changes the branch, and cleared once the target branch is updated or when the merge stops without having changed the branch.
+
+## Local CI
+
+For personal projects, pre-merge hooks open up the possibility of a workflow with much faster iteration — an order of magnitude more small changes instead of fewer large ones.
+
+Historically, ensuring tests ran before merging was difficult to enforce locally. Remote CI was valuable for the process as much as the checks: it guaranteed validation happened. `wt merge` brings that guarantee local.
+
+The full workflow: start an agent (one of many) on a task, work elsewhere, return when it's ready. Review the diff, run `wt merge`, move on. Pre-merge hooks validate before merging — if they pass, the branch goes to the default branch and the worktree cleans up.
+
+```toml
+[pre-merge]
+test = "cargo test"
+lint = "cargo clippy"
+```
+
+## See also
+
+- [`wt step`](@/step.md) — Run individual operations (commit, squash, rebase, push)
+- [`wt remove`](@/remove.md) — Remove worktrees without merging
+- [`wt switch`](@/switch.md) — Navigate to other worktrees
+"#,
+        // `--abort` refuses every step flag, `--continue` those of steps already run
+        group = clap::ArgGroup::new("steps").multiple(true),
+        group = clap::ArgGroup::new("rewrite").multiple(true)
+    )]
+    Merge {
+        /// Target branch
+        ///
+        /// Defaults to default branch.
+        #[arg(add = crate::completion::branch_value_completer())]
+        target: Option<String>,
+
+        /// Force commit squashing
+        #[arg(
+            long,
+            overrides_with = "no_squash",
+            hide = true,
+            group = "steps",
+            group = "rewrite"
+        )]
+        squash: bool,
+
+        /// Skip commit squashing
+        #[arg(
+            long = "no-squash",
+            overrides_with = "squash",
+            group = "steps",
+            group = "rewrite"
+        )]
+        no_squash: bool,
+
+        /// Force commit and squash
+        #[arg(
+            long,
+            overrides_with = "no_commit",
+            hide = true,
+            group = "steps",
+            group = "rewrite"
+        )]
+        commit: bool,
+
+        /// Skip commit and squash
+        #[arg(
+            long = "no-commit",
+            overrides_with = "commit",
+            group = "steps",
+            group = "rewrite"
+        )]
+        no_commit: bool,
+
+        /// Force rebasing onto target
+        #[arg(
+            long,
+            overrides_with = "no_rebase",
+            hide = true,
+            group = "steps",
+            group = "rewrite"
+        )]
+        rebase: bool,
+
+        /// Skip rebase (fail if not already rebased)
+        #[arg(
+            long = "no-rebase",
+            overrides_with = "rebase",
+            group = "steps",
+            group = "rewrite"
+        )]
+        no_rebase: bool,
+
+        /// Force worktree removal after merge
+        #[arg(long, overrides_with = "no_remove", hide = true, group = "steps")]
+        remove: bool,
+
+        /// Keep worktree after merge
+        #[arg(long = "no-remove", overrides_with = "remove", group = "steps")]
+        no_remove: bool,
+
+        /// Force running hooks
+        #[arg(long, overrides_with = "no_verify", hide = true, group = "steps")]
+        verify: bool,
+
+        /// Skip hooks
+        #[arg(long = "no-verify", overrides_with = "verify", group = "steps")]
+        no_verify: bool,
+
+        /// Skip hooks of one type (repeatable)
+        #[arg(
+            long,
+            value_name = "HOOK",
+            hide_possible_values = true,
+            group = "steps"
+        )]
+        skip_hook: Vec<worktrunk::HookType>,
+
+        /// Push target branch to its remote after merging
+        ///
+        /// Uses [repo] upstream-remote if present, else the primary remote.
+        #[arg(long, overrides_with = "no_push", group = "steps")]
+        push: bool,
+
+        /// Don't push target branch to its remote
+        #[arg(
+            long = "no-push",
+            overrides_with = "push",
+            hide = true,
+            group = "steps"
+        )]
+        no_push: bool,
+
+        /// Push the branch and wait for CI before merging
+        ///
+        /// Fails the merge if the checks fail or time out; see [merge]
+        /// ci-timeout and ci-interval.
+        #[arg(long, overrides_with = "no_wait_ci", group = "steps")]
+        wait_ci: bool,
+
+        /// Don't wait for CI before merging
+        #[arg(
+            long = "no-wait-ci",
+            overrides_with = "wait_ci",
+            hide = true,
+            group = "steps"
+        )]
+        no_wait_ci: bool,
+
+        /// Skip approval prompts
+        #[arg(short, long, group = "steps")]
+        yes: bool,
+
+        /// What to stage before committing [default: all]
+        #[arg(long, group = "steps", group = "rewrite")]
+        stage: Option<crate::commands::commit::StageMode>,
+
+        /// Fold fixup!/squash! commits into their targets
+        ///
+        /// Runs git rebase --autosquash before squashing. With --no-squash,
+        /// the remaining commits are kept.
+        #[arg(long, group = "steps", group = "rewrite")]
+        autosquash: bool,
+
+        /// Resume an interrupted merge
+        #[arg(long = "continue", conflicts_with_all = ["target", "abort", "rewrite"])]
+        resume: bool,
+
+        /// Roll back an interrupted merge
+        #[arg(long, conflicts_with_all = ["target", "steps"])]
+        abort: bool,
+
+        /// Merge despite a rebase or merge in progress
+        #[arg(long, group = "steps")]
+        force: bool,
+
+        #[arg(long, help = WAIT_HELP, long_help = WAIT_LONG_HELP, group = "steps")]
+        wait: bool,
+    },
+
+    /// Push current branch to its remote
+    ///
+    /// Pushes to the branch's upstream, creating it on the first push.
+    #[command(
+        after_long_help = r#"The first push of a branch goes to a same-named branch on the primary remote and records it as the upstream, like `git push -u`. Later pushes, and branches with `[switch] set-upstream`, go to the upstream already configured.
+
+A push that would drop commits from the remote branch — because it was rebased, amended, or someone else pushed — is refused, with the commits listed. Pull them in with `git pull --rebase`, or replace them with `--force-with-lease`, which still refuses if the remote moved since it was last fetched. Branches in `[safety] protected-branches` are never force-pushed.
+
+After pushing, the branch's cached CI status is dropped, so `wt list` fetches it again instead of showing the status from before the push.
+
+## Examples
+
+Push the current branch:
+
+```console
+wt push
+```
+
+Push after rebasing onto main:
+
+```console
+wt push --force-with-lease
+```
+
+## See also
+
+- [`wt merge`](@/merge.md) — Merge locally, with `--push` to publish the target
+- [`wt list`](@/list.md) — The `Remote⇅` column shows what's unpushed
+"#
+    )]
+    Push {
+        /// Replace commits on the remote branch, unless it moved since the last fetch
+        #[arg(long)]
+        force_with_lease: bool,
+    },
+
+    /// Tag a release
+    ///
+    /// Tags the merged commit, pushes the tag, and removes the merged worktree.
+    #[command(
+        after_long_help = r#"Run from the worktree of a release branch once it's merged into the target (the default branch unless `--target` names another), or from the target's own worktree. The tag goes on the commit that brought the branch into the target — the branch tip after a fast-forward, the merge or squash commit otherwise — so it names what shipped, not the release branch's pre-merge history or later commits on the target. From the target's own worktree, it goes on the target's tip.
+
+Tags are annotated, with the version as the message unless `--message` gives one. `--sign` signs the tag with the configured GPG or SSH key; `git config tag.gpgSign true` signs every tag without the flag. The tag is pushed to `[repo] upstream-remote` if configured, else the primary remote; if the push fails, the local tag is deleted again so the command can be rerun.
+
+Afterwards the release branch's worktree is removed and its branch deleted, as with `wt remove`. The target's worktree and the main worktree are never removed.
+
+## Examples
+
+Tag a merged release branch:
+
+```console
+wt merge
+wt tag v1.4.0
+```
+
+Sign the tag and keep the worktree:
+
+```console
+wt tag v1.4.0 --sign --no-remove
+```
+
+## See also
+
+- [`wt merge`](@/merge.md) — Merge the release branch first
+- [`wt remove`](@/remove.md) — What happens to the worktree afterwards
+"#
+    )]
+    Tag {
+        /// Tag name, e.g. v1.4.0
+        version: String,
+
+        /// Tag message [default: the version]
+        #[arg(short, long)]
+        message: Option<String>,
+
+        /// Sign the tag
+        #[arg(short, long)]
+        sign: bool,
+
+        /// Branch to tag [default: default branch]
+        #[arg(long, add = crate::completion::branch_value_completer())]
+        target: Option<String>,
+
+        /// Don't push the tag
+        #[arg(long = "no-push")]
+        no_push: bool,
+
+        /// Keep the release worktree
+        #[arg(long = "no-remove")]
+        no_remove: bool,
+
+        /// Skip hooks
+        #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        verify: bool,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Cherry-pick commits from another branch
+    ///
+    /// Picks commits from another worktree's branch into the current worktree.
+    #[command(
+        after_long_help = r#"Without commits, lists the commits on the branch that neither the default branch nor the current worktree has — patches picked earlier are recognized by content — and prompts for which to pick. Worktrees share one object database, so the other worktree's commits are available directly; its uncommitted changes aren't picked.
+
+Picked commits apply oldest first. When one doesn't apply cleanly, the cherry-pick stops with the conflicted files listed; resolve them and run `git cherry-pick --continue`, or `git cherry-pick --abort` to undo.
+
+## Examples
+
+Choose commits from the `feature` worktree:
+
+```console
+wt cherry feature
+```
+
+Pick specific commits:
+
+```console
+wt cherry feature a1b2c3d e4f5a6b
+```
+
+Pick everything `feature` has that this worktree doesn't:
+
+```console
+wt cherry feature --all
+```
+
+Without a terminal, `wt cherry <branch>` prints the candidates, one tab-separated hash and subject per line, instead of prompting. The global `--yes` picks them all, like `--all`; `--no` picks none.
+
+## See also
+
+- [`wt list`](@/list.md) — See how far branches have diverged
+- [`wt merge`](@/merge.md) — Bring a whole branch in instead
+"#
+    )]
+    Cherry {
+        /// Branch to pick from
+        #[arg(add = crate::completion::branch_value_completer())]
+        branch: String,
+
+        /// Commits to pick (default: prompt)
+        commits: Vec<String>,
+
+        /// Pick every commit the current worktree doesn't have
+        #[arg(long, conflicts_with = "commits")]
+        all: bool,
+    },
+
+    /// Check out a branch for review
+    ///
+    /// Creates a worktree that commands won't commit to, and removes it once the branch is merged.
+    #[command(
+        after_long_help = r#"Accepts anything `wt switch` does, including `pr:<number>` for a pull request's branch. The worktree is recorded in `worktrunk.state.<branch>.review`; `wt merge`, `wt step commit`, and `wt step squash` refuse to run there, and `wt list` marks it with 👀 unless the branch has its own marker. With `--read-only`, tracked files also lose write permission, so editors warn before changing them.
+
+Each `wt review` first removes review worktrees whose branch has been merged into the default branch, along with the branch. Worktrees with uncommitted changes, and the current worktree, are kept.
+
+## Examples
+
+Review a pull request:
+
+```console
+wt review pr:101
+```
+
+Review a branch with its files made read-only:
+
+```console
+wt review feature --read-only
+```
+
+Keep working on a reviewed branch as your own:
+
+```console
+wt review --release feature
+```
+
+## See also
+
+- [`wt switch`](@/switch.md) — Check out a branch to work on
+- [`wt list`](@/list.md) — See review worktrees alongside the rest
+"#
+    )]
+    Review {
+        /// Branch or `pr:<number>` to review
+        #[arg(add = crate::completion::branch_value_completer())]
+        branch: String,
+
+        /// Make tracked files read-only
+        #[arg(long, conflicts_with = "release")]
+        read_only: bool,
+
+        /// Stop treating the branch as a review checkout
+        #[arg(long)]
+        release: bool,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Skip hooks
+        #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
+        verify: bool,
+    },
+
+    /// Interactive worktree selector
+    ///
+    /// Browse and switch worktrees with live preview.
+    #[cfg_attr(not(unix), command(hide = true))]
+    #[command(after_long_help = r#"<!-- demo: wt-select.gif 1600x800 -->
+
+## Examples
+
+Open the selector:
+
+```console
+wt select
+```
+
+## Preview tabs
+
+Toggle between views with number keys:
+
+1. **HEAD±** — Diff of uncommitted changes
+2. **log** — Recent commits; commits already on the default branch have dimmed hashes
+3. **main…±** — Diff of changes since the merge-base with the default branch
+4. **remote⇅** — Diff vs upstream tracking branch (ahead/behind)
+
+## Keybindings
+
+| Key | Action |
+|-----|--------|
+| `↑`/`↓` | Navigate worktree list |
+| `Enter` | Switch to selected worktree |
+| `Tab` | Mark entry |
+| `Alt-x` | Remove marked entries, after confirming |
+| `Alt-r` | Rebase marked worktrees onto the default branch |
+| `Esc` | Cancel |
+| (type) | Filter worktrees |
+| `1`/`2`/`3`/`4` | Switch preview tab |
+| `Alt-p` | Toggle preview panel |
+| `Ctrl-u`/`Ctrl-d` | Scroll preview up/down |
+
+With `--branches`, branches without worktrees are included — selecting one creates a worktree. This matches `wt list --branches`.
+
+Removing and rebasing act on every marked entry, or the highlighted one if none are marked. Removal works like `wt remove --no-verify`; worktrees with uncommitted changes are skipped by rebase, and a rebase that conflicts is aborted.
+
+## Configuration
+
+### Pager
+
+The preview panel pipes diff output through git's pager (typically `less` or `delta`). Override pager behavior in user config:
+
+```toml
+[select]
+pager = "delta --paging=never"
+```
+
+This is useful when the default pager doesn't render correctly in the embedded preview panel.
+
+## See also
+
+- [`wt list`](@/list.md) — Static table view with all worktree metadata
+- [`wt switch`](@/switch.md) — Direct switching to a known target branch
+
+Available on Unix only (macOS, Linux). On Windows, use `wt list` or `wt switch` directly.
+"#)]
+    Select {
+        /// Include branches without worktrees
+        #[arg(long)]
+        branches: bool,
+
+        /// Include remote branches
+        #[arg(long)]
+        remotes: bool,
+    },
+
+    /// JSON-RPC server for editor integrations
+    ///
+    /// Answers `list`, `switch` and `remove` requests on stdin and pushes change notifications to stdout.
+    #[cfg(feature = "serve")]
+    #[command(after_long_help = r#"## Examples
+
+Start the server from an editor extension, in the repository:
+
+```console
+wt serve --stdio
+```
+
+Then write one JSON-RPC 2.0 request per line to its stdin:
+
+```json
+{"jsonrpc": "2.0", "id": 1, "method": "switch", "params": {"branch": "feature", "create": true}}
+```
+
+Each response is one line on stdout:
+
+```json
+{"jsonrpc": "2.0", "id": 1, "result": {"path": "/code/myproject.feature"}}
+```
+
+## Methods
+
+| Method | Params | Result |
+|--------|--------|--------|
+| `list` | `branches` | Array of worktrees |
+| `switch` | `branch`, `create` | `{"path": ...}` |
+| `remove` | `branch`, `force`, `delete_branch` (default `true`) | `null` |
+| `subscribe` | `branches` | Array of worktrees |
+
+Each worktree has `branch`, `path`, `head`, `is_main`, `ahead`, `behind` (relative to the default branch) and `dirty`. Branches without a worktree have a `null` path.
+
+`remove` applies the checks of `wt remove`: branches in `[safety] protected-branches` are never deleted, and other branches only once they are integrated into the default branch.
+
+After `subscribe`, the server checks the worktree list every second and sends a `changed` notification with the new `items` whenever it differs — including after the editor's own `switch` and `remove` calls.
+
+`switch` and `remove` don't run hooks, ask for approval, or change directory; use `wt switch` and `wt remove` in a terminal for those. The server exits when stdin closes. Requires building with `--features serve`.
+"#)]
+    Serve {
+        /// Speak JSON-RPC over stdin and stdout
+        #[arg(long, required = true)]
+        stdio: bool,
+    },
+
+    /// Local worktree dashboard in the browser
+    ///
+    /// Serves the `wt list` data on a local web page that refreshes itself.
+    #[cfg(feature = "web")]
+    #[command(after_long_help = r#"## Examples
+
+Serve the dashboard on the default port:
+
+```console
+wt web
+```
+
+Then open `http://127.0.0.1:7717` — on a second monitor, say. The page reloads the worktree list every few seconds.
+
+## Actions
+
+The page doesn't change anything itself. Each row has buttons that copy a command — `wt switch`, `wt merge`, `wt remove` — to the clipboard, to paste into a terminal.
+
+## Endpoints
+
+| Path | Content |
+|------|---------|
+| `/` | Dashboard page |
+| `/api/list` | Same JSON as `wt list --format=json` |
+
+The server listens on `127.0.0.1` only, and refuses requests addressed to any other host. Requires building with `--features web`.
+"#)]
+    Web {
+        /// Port to listen on
+        #[arg(long, default_value_t = 7717)]
+        port: u16,
+
+        /// Include branches without worktrees
+        #[arg(long)]
+        branches: bool,
+    },
+
+    /// Show or set a branch description
+    ///
+    /// Descriptions carry human context beyond the last commit subject. They're stored in `branch.<name>.description`, the same key `git branch --edit-description` uses.
+    #[command(after_long_help = r#"## Examples
+
+Describe a branch:
+
+```console
+wt describe feature-auth "OAuth login; blocked on API keys"
+```
+
+Describe the current branch:
 
-        /// Skip commit and squash
-        #[arg(long = "no-commit", overrides_with = "commit")]
-        no_commit: bool,
+```console
+wt describe @ "Spike: try the new parser"
+```
 
-        /// Force rebasing onto target
-        #[arg(long, overrides_with = "no_rebase", hide = true)]
-        rebase: bool,
+Print a description:
 
-        /// Skip rebase (fail if not already rebased)
-        #[arg(long = "no-rebase", overrides_with = "rebase")]
-        no_rebase: bool,
+```console
+wt describe feature-auth
+```
 
-        /// Force worktree removal after merge
-        #[arg(long, overrides_with = "no_remove", hide = true)]
-        remove: bool,
+Clear a description:
 
-        /// Keep worktree after merge
-        #[arg(long = "no-remove", overrides_with = "remove")]
-        no_remove: bool,
+```console
+wt describe feature-auth --clear
+```
 
-        /// Force running hooks
-        #[arg(long, overrides_with = "no_verify", hide = true)]
-        verify: bool,
+`wt list --full` shows descriptions in the Message column in place of the commit subject, and includes them as `description` in JSON output.
 
-        /// Skip hooks
-        #[arg(long = "no-verify", overrides_with = "verify")]
-        no_verify: bool,
+## See also
 
-        /// Skip approval prompts
-        #[arg(short, long)]
-        yes: bool,
+- [`wt list`](@/list.md) — View all worktrees
+"#)]
+    Describe {
+        /// Branch name (`@` for current)
+        #[arg(add = crate::completion::local_branches_completer())]
+        branch: String,
 
-        /// What to stage before committing [default: all]
-        #[arg(long)]
-        stage: Option<crate::commands::commit::StageMode>,
+        /// Description text [default: print the current description]
+        text: Option<String>,
+
+        /// Remove the description
+        #[arg(long, conflicts_with = "text")]
+        clear: bool,
     },
-    /// Interactive worktree selector
+
+    /// Clone a repository for worktree use
     ///
-    /// Browse and switch worktrees with live preview.
-    #[cfg_attr(not(unix), command(hide = true))]
-    #[command(after_long_help = r#"<!-- demo: wt-select.gif 1600x800 -->
+    /// Clones into the layout the `worktree-path` template expects, sets `origin/HEAD`, and changes into the main worktree — or, with `--switch`, into a new worktree.
+    #[command(after_long_help = r#"## Examples
 
-## Examples
+Clone into `./worktrunk`:
 
-Open the selector:
+```console
+wt clone https://github.com/max-sixty/worktrunk.git
+```
+
+Clone into a chosen directory:
 
 ```console
-wt select
+wt clone git@github.com:max-sixty/worktrunk.git ~/code/wt
 ```
 
-## Preview tabs
+Clone and start work on a new branch:
 
-Toggle between views with number keys:
+```console
+wt clone https://github.com/max-sixty/worktrunk.git --switch fix-typo
+```
 
-1. **HEAD±** — Diff of uncommitted changes
-2. **log** — Recent commits; commits already on the default branch have dimmed hashes
-3. **main…±** — Diff of changes since the merge-base with the default branch
-4. **remote⇅** — Diff vs upstream tracking branch (ahead/behind)
+## Layout
 
-## Keybindings
+With the default template (`../{{ repo }}.{{ branch | sanitize }}`), worktrees sit beside the clone, so `wt clone` is a plain `git clone`.
 
-| Key | Action |
-|-----|--------|
-| `↑`/`↓` | Navigate worktree list |
-| `Enter` | Switch to selected worktree |
-| `Esc` | Cancel |
-| (type) | Filter worktrees |
-| `1`/`2`/`3`/`4` | Switch preview tab |
-| `Alt-p` | Toggle preview panel |
-| `Ctrl-u`/`Ctrl-d` | Scroll preview up/down |
+When the template places worktrees beside the main worktree without the repo name — such as `../{{ branch | sanitize }}` — the main worktree goes in a subdirectory named after the default branch:
 
-With `--branches`, branches without worktrees are included — selecting one creates a worktree. This matches `wt list --branches`.
+```
+worktrunk/
+├── main/          ← main worktree
+└── feature-auth/  ← wt switch --create feature-auth
+```
 
-## Configuration
+## See also
 
-### Pager
+- [`wt switch`](@/switch.md) — Create the first worktree
+- [`wt config`](@/config.md) — Set the worktree path template
+"#)]
+    Clone {
+        /// Repository URL or path
+        url: String,
 
-The preview panel pipes diff output through git's pager (typically `less` or `delta`). Override pager behavior in user config:
+        /// Directory to clone into [default: repository name]
+        directory: Option<std::path::PathBuf>,
 
-```toml
-[select]
-pager = "delta --paging=never"
-```
+        /// Also create a worktree for this branch and switch to it
+        ///
+        /// An existing branch is checked out; any other name becomes a new branch from the default branch.
+        #[arg(long, value_name = "BRANCH")]
+        switch: Option<String>,
+    },
 
-This is useful when the default pager doesn't render correctly in the embedded preview panel.
+    /// Guided first-run configuration
+    ///
+    /// Installs shell integration for the detected shell, picks a worktree path template, and configures an LLM for commit messages.
+    #[command(
+        after_long_help = r#"Each step can be skipped. Answers are written to the user config; a new file starts from the commented example that `wt config create` writes, and an existing file keeps its other settings and comments.
+
+Runs in a terminal only. For scripted setup, use `wt config shell install` and `wt config create`.
+
+## Steps
+
+1. **Shell integration** — detects the shell from `$SHELL` and offers `wt config shell install` for it
+2. **Worktree path** — chooses where `wt switch --create` puts worktrees: beside the repo, inside it under `.worktrees/`, or nested next to the main worktree (the layout `wt clone` creates)
+3. **LLM commits** — if `llm` or `aichat` is installed, sets `[commit-generation]` to use it
+
+Until a user config exists, `wt switch`, `wt list` and `wt select` suggest `wt setup` once per repository when run in a terminal.
 
 ## See also
 
-- [`wt list`](@/list.md) — Static table view with all worktree metadata
-- [`wt switch`](@/switch.md) — Direct switching to a known target branch
+- [`wt config`](@/config.md) — Manage config files and shell integration
+"#
+    )]
+    Setup,
 
-Available on Unix only (macOS, Linux). On Windows, use `wt list` or `wt switch` directly.
-"#)]
-    Select {
-        /// Include branches without worktrees
-        #[arg(long)]
-        branches: bool,
+    /// Check the configuration for problems
+    ///
+    /// Reports whether diffs can leave this machine for an LLM command.
+    #[command(
+        after_long_help = r#"With `[llm] enabled = false`, or no `[commit-generation]` command, confirms that diffs are never sent to a command. Otherwise names the command that receives them.
 
-        /// Include remote branches
-        #[arg(long)]
-        remotes: bool,
-    },
+`wt config show --full` goes further: it runs the command once to check that commit generation works.
+
+## See also
+
+- [`wt config`](@/config.md) — Turn LLM features off with `[llm] enabled = false`
+"#
+    )]
+    Doctor,
 
     /// Run individual operations
     ///
@@ -997,6 +2139,7 @@ wt step push
 - `rebase` — Rebase onto target branch
 - `push` — Fast-forward target to current branch
 - `copy-ignored` — Copy gitignored files between worktrees
+- `prune` — Clean up stale worktree metadata and orphaned directories
 - `for-each` — [experimental] Run a command in every worktree
 
 ## See also
@@ -1013,6 +2156,83 @@ wt step push
         action: StepCommand,
     },
 
+    /// Run a project command profile
+    ///
+    /// Runs a named `[profiles.<name>]` command from the project config in the current worktree.
+    #[command(
+        after_long_help = r#"Profiles are ad-hoc commands a project names once and everyone runs the same way — test suites, linters, dev servers — without tying them to a lifecycle hook.
+
+```toml
+# .config/wt.toml
+[profiles.test]
+command = "cargo nextest run"
+
+[profiles.serve]
+command = "npm run dev -- --port {{ branch | hash_port }}"
+```
+
+Commands take the same [template variables](@/hook.md#template-variables) as hooks and receive the same JSON context on stdin. Like project hooks, a profile's command needs approval the first time it runs, and again when its text changes; `wt hook approvals add` approves profiles along with hooks.
+
+## Examples
+
+Run the `test` profile in the current worktree:
+
+```console
+wt run test
+```
+
+Run it in every worktree:
+
+```console
+wt step for-each --profile test
+```
+
+## See also
+
+- [`wt hook`](@/hook.md) — Lifecycle hooks and template variables
+- [`wt step for-each`](@/step.md#wt-step-for-each) — Run a command in every worktree
+"#
+    )]
+    Run {
+        /// Profile name
+        profile: String,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Override built-in template variable (KEY=VALUE)
+        #[arg(long = "var", value_name = "KEY=VALUE", value_parser = parse_key_val, action = clap::ArgAction::Append)]
+        vars: Vec<(String, String)>,
+    },
+
+    /// Manage command aliases
+    ///
+    /// Aliases are shorthands for commands, defined in the `[alias]` section of user config.
+    #[command(
+        after_long_help = r#"Each alias names a command line to run in its place. Arguments after the alias are appended to the expansion:
+
+```toml
+[alias]
+ls = "list --branches --full"
+sw = "switch --create"
+```
+
+```console
+wt ls                # runs wt list --branches --full
+wt sw feature-auth   # runs wt switch --create feature-auth
+```
+
+An alias may expand to another alias, but not back to itself. Built-in commands can't be redefined; an alias with a built-in's name is ignored.
+
+Aliases are only read from user config. Project config can't define them.
+"#
+    )]
+    Alias {
+        #[command(subcommand)]
+        action: AliasCommand,
+    },
+
     /// Run configured hooks
     #[command(
         name = "hook",
@@ -1114,11 +2334,11 @@ Project commands require approval on first run:
 ```
 
 - Approvals are saved to user config (`~/.config/worktrunk/config.toml`)
-- If a command changes, new approval is required
+- If a command changes, new approval is required; the prompt marks it as changed
 - Use `--yes` to bypass prompts (useful for CI/automation)
-- Use `--no-verify` to skip hooks
+- Use `--no-verify` to skip hooks, or `--skip-hook <type>` to skip one hook type (e.g. `--skip-hook pre-merge`)
 
-Manage approvals with `wt hook approvals add` and `wt hook approvals clear`.
+Manage approvals with `wt hook approvals add`, `wt hook approvals list`, `wt hook approvals revoke`, and `wt hook approvals clear`.
 
 ## Configuration
 
@@ -1138,6 +2358,13 @@ test = "cargo test"
 build = "cargo build --release"
 ```
 
+A named command with `paths` only runs when the branch changes a matching file — compared with the merge target for merge and commit hooks, the base branch for post-create, and otherwise the default branch. `*` matches any characters, including `/`:
+
+```toml
+[post-create]
+install = { command = "npm install", paths = ["web/*", "package-lock.json"] }
+```
+
 ### User hooks
 
 Define hooks in `~/.config/worktrunk/config.toml` to run for all repositories. User hooks run before project hooks and don't require approval.
@@ -1205,6 +2432,8 @@ Hooks can use template variables that expand at runtime:
 | `{{ remote }}` | Primary remote name |
 | `{{ remote_url }}` | Remote URL |
 | `{{ upstream }}` | Upstream tracking branch |
+| `{{ compose_project }}` | Docker Compose project name: `{{ repo }}-{{ branch }}`, lowercased, with other characters as `-` |
+| `{{ flake }}` | Worktree path, when it contains a `flake.nix` |
 | `{{ target }}` | Target branch (merge hooks only) |
 | `{{ base }}` | Base branch (creation hooks only) |
 | `{{ base_worktree_path }}` | Base branch worktree (creation hooks only) |
@@ -1215,11 +2444,11 @@ Templates support Jinja2 filters for transforming values:
 
 | Filter | Example | Description |
 |--------|---------|-------------|
-| `sanitize` | `{{ branch \| sanitize }}` | Replace `/` and `\` with `-` |
+| `sanitize` | `{{ branch \| sanitize }}` | Replace `/`, `\` and characters invalid in filenames with `-` |
 | `sanitize_db` | `{{ branch \| sanitize_db }}` | Database-safe identifier with hash suffix (`[a-z0-9_]`, max 63 chars) |
 | `hash_port` | `{{ branch \| hash_port }}` | Hash to port 10000-19999 |
 
-The `sanitize` filter makes branch names safe for filesystem paths. The `sanitize_db` filter produces database-safe identifiers (lowercase alphanumeric and underscores, no leading digits, with a 3-character hash suffix to avoid collisions and reserved words). The `hash_port` filter is useful for running dev servers on unique ports per worktree:
+The `sanitize` filter makes branch names safe for filesystem paths. Earlier releases only replaced `/` and `\`, so a worktree made then for a branch with other such characters, invisible characters or a name over 200 bytes keeps its old path, shown as `⚑` in `wt list`; move it with `git worktree move`. The `sanitize_db` filter produces database-safe identifiers (lowercase alphanumeric and underscores, no leading digits, with a 3-character hash suffix to avoid collisions and reserved words). The `hash_port` filter is useful for running dev servers on unique ports per worktree:
 
 ```toml
 [post-start]
@@ -1249,6 +2478,33 @@ The `worktree_path_of_branch` function returns the filesystem path of a worktree
 setup = "cp {{ worktree_path_of_branch('main') }}/config.local {{ worktree_path }}"
 ```
 
+### Docker Compose
+
+Worktrees of the same project share Compose's default project name when their directories do, so `docker compose up` in one worktree can replace another's containers and reuse its volumes. Pass `-p {{ compose_project }}` to give each worktree its own, or have Worktrunk set `COMPOSE_PROJECT_NAME` for every hook:
+
+```toml
+[integrations]
+compose = true
+
+[post-start]
+db = "docker compose up -d"
+
+[pre-remove]
+db = "docker compose down -v"
+```
+
+### Nix
+
+A new worktree's dev shell is built the first time something enters it. Set `nix` to build it during post-create instead, ahead of the project's own post-create hooks and without needing approval:
+
+```toml
+[integrations]
+nix = "develop"   # runs `nix develop --command true`
+# nix = "devenv"  # runs `devenv shell true`
+```
+
+Hooks that need the shell can reference the worktree's flake with `{{ flake }}`, e.g. `nix develop {{ flake }} --command make`.
+
 ### JSON context
 
 Hooks also receive context as JSON on stdin, enabling hooks in any language:
@@ -1474,7 +2730,7 @@ Controls where new worktrees are created. Paths are relative to the repository r
 
 - `{{ repo }}` — repository directory name
 - `{{ branch }}` — raw branch name (e.g., `feature/auth`)
-- `{{ branch | sanitize }}` — filesystem-safe: `/` and `\` become `-` (e.g., `feature-auth`)
+- `{{ branch | sanitize }}` — filesystem-safe: `/`, `\` and characters invalid in filenames become `-` (e.g., `feature-auth`)
 - `{{ branch | sanitize_db }}` — database-safe: lowercase, underscores, hash suffix (e.g., `feature_auth_x7k`)
 
 **Examples** for repo at `~/code/myproject`, branch `feature/auth`:
@@ -1519,6 +2775,28 @@ args = ["-m", "claude:claude-haiku-4.5"]
 
 See [Custom prompt templates](#custom-prompt-templates) for inline template options.
 
+To keep diffs on this machine, turn LLM features off. Commits and squashes then use the fallback message, LLM hints are hidden, and `wt doctor` confirms nothing is sent to a command, even when `[commit-generation]` is set:
+
+```toml
+[llm]
+enabled = false
+```
+
+Diffs over `max-diff-bytes` (default 400000) are trimmed before they reach the prompt. Lock files go first, then `diff-truncation` decides the rest: `per-file` (default) caps each file at 50 lines, and `file-list` keeps whole files while they fit and names the others. A warning lists what was trimmed, and so does the prompt:
+
+```toml
+[llm]
+max-diff-bytes = 100000
+diff-truncation = "file-list"
+```
+
+Secrets are scrubbed before that: `.env` files are left out, and AWS keys, GitHub, GitLab and Slack tokens, API keys, private keys, and quoted values of password or token keys become `[REDACTED]`. Add patterns with `redact`; with a capture group, only the group is replaced. `wt step commit --show-prompt` prints exactly what would be sent:
+
+```toml
+[llm]
+redact = ['internal\.example\.com', 'customer_id=(\d+)']
+```
+
 ## Commands
 
 ### List
@@ -1530,6 +2808,14 @@ Persistent flag values for `wt list`. Override on command line as needed.
 full = false       # Show CI status and main…± diffstat columns (--full)
 branches = false   # Include branches without worktrees (--branches)
 remotes = false    # Include remote-only branches (--remotes)
+index = false      # Number rows for wt switch N (--index)
+summary = ["dirty", "ahead"]  # Summary metrics; also "stale", "integrated", "disk"
+max-width = { branch = 40, message = 60 }  # Column width caps: branch, path, url, message
+record-below = 60  # Terminal width under which --layout auto shows records
+cache = false      # Serve repeated --format=json calls from a cache, refreshed in the background
+absolute-time = false  # Show commit dates in the Age column (--absolute-time)
+time-format = "%Y-%m-%d"  # strftime pattern for those dates
+time-units = { now = "jetzt", month = "M" }  # Labels for relative ages: now, future, minute, hour, day, week, month, year
 ```
 
 ### Commit
@@ -1539,6 +2825,9 @@ Shared by `wt step commit`, `wt step squash`, and `wt merge`.
 ```toml
 [commit]
 stage = "all"      # What to stage before commit: "all", "tracked", or "none"
+signoff = false    # Add Signed-off-by trailer to generated commits
+git-hooks = true   # Run native git hooks (core.hooksPath); skipped by --no-verify
+review = false     # Accept, edit, or regenerate LLM messages before committing
 ```
 
 ### Merge
@@ -1552,6 +2841,39 @@ commit = true      # Commit uncommitted changes first (--no-commit to skip)
 rebase = true      # Rebase onto target before merge (--no-rebase to skip)
 remove = true      # Remove worktree after merge (--no-remove to keep)
 verify = true      # Run project hooks (--no-verify to skip)
+push = false       # Push target to its remote after merge (--push to enable)
+wait-ci = false    # Wait for CI to pass before merging (--wait-ci to enable)
+ci-timeout = 1800  # Seconds to wait for CI before giving up
+ci-interval = 15   # Seconds between CI status checks
+```
+
+### Update
+
+`wt update` rebases onto the default branch by default; `merge` merges it in instead, which keeps history intact for branches others have pulled.
+
+```toml
+[update]
+strategy = "rebase"  # "rebase" or "merge" (--strategy to override)
+```
+
+### Fetch
+
+After `wt update` fetches, `fast-forward-clean` also moves other worktrees that are strictly behind the default branch: no commits of their own and no uncommitted changes. The default branch, and branches that track a different upstream, stay put. Each one that moves is listed.
+
+```toml
+[fetch]
+fast-forward-clean = false  # Fast-forward clean worktrees behind the default branch
+```
+
+### Remove
+
+Shared by `wt remove` and the cleanup step of `wt merge`. With `auto-integrated`, `wt list` and `wt switch` also offer to remove worktrees whose branch has been merged into the default branch (`prompt`), or remove them without asking (`auto`). Merges are detected from the last fetch, such as the one `wt update` runs.
+
+```toml
+[remove]
+use-trash = false          # Move removed worktrees to the OS trash instead of deleting
+trash-max-mb = 1024        # Larger worktrees are deleted directly, even with use-trash
+auto-integrated = "never"  # Remove merged worktrees in wt list/switch: "prompt", "auto"
 ```
 
 ### Select
@@ -1566,9 +2888,53 @@ Pager behavior for `wt select` diff previews.
 # pager = "delta --paging=never"
 ```
 
+### Switch
+
+Branches made by `wt switch --create` start without an upstream, so `Remote⇅` in `wt list` stays blank until the first push. With `set-upstream`, they track a same-named branch on the primary remote from the start, shown as `⇈` until it's pushed.
+
+`exclude` lists ignore patterns to add to `.git/info/exclude` when a worktree is created, for files that hooks generate in each worktree, such as logs. The file is shared by all worktrees of the repository and isn't committed, so `git status` and `HEAD±` in `wt list` stay clean without touching `.gitignore`.
+
+Shell completion for branch names offers worktrees first, then local and remote-only branches by recency. Remote-only branches come from the primary remote only, since that is where `wt switch` looks them up. `completion-limit` caps how many are offered, so repositories with thousands of remote branches stay usable; typing more of a name still reaches older ones. `0` offers all.
+
+```toml
+[switch]
+set-upstream = false  # Track <remote>/<branch> from creation, so git push needs no -u
+exclude = ["*.log", ".wt-state"]  # Patterns added to .git/info/exclude
+completion-limit = 100  # Branches offered by shell completion (0 = all)
+```
+
+### Integrations
+
+Keep other tools in step as worktrees come and go.
+
+```toml
+[integrations]
+zoxide = false   # Add created worktrees to zoxide and drop removed ones, so `z feature` jumps there
+direnv = "warn"  # New worktree has an .envrc: "allow" runs direnv allow, "warn" suggests it, "ignore"
+```
+
+### Gitea and Forgejo
+
+Self-hosted instances show PR and CI status in `wt list --full` through the Gitea API. Remotes on `host` use it; hosts named `gitea`, `forgejo`, or `codeberg.org` are recognized without it.
+
+```toml
+[gitea]
+host = "git.example.com"  # Prefix with "http://" for instances without TLS
+token = "..."             # API token for private repositories, only sent to host
+```
+
+### Aliases
+
+Shorthands for commands: `wt ls` below runs `wt list --branches --full`, followed by any further arguments. List them with `wt alias list`.
+
+```toml
+[alias]
+ls = "list --branches --full"
+```
+
 ### Approved commands
 
-Commands approved for project hooks. Auto-populated when approving hooks on first run, or via `wt hook approvals add`.
+Commands approved for project hooks. Auto-populated when approving hooks on first run, or via `wt hook approvals add`; review them with `wt hook approvals list`. The `approved-hashes` table alongside records which hook command each approval was for, so a changed command is flagged at the next prompt.
 
 ```toml
 [projects."github.com/user/repo"]
@@ -1706,11 +3072,82 @@ The `[ci]` section overrides CI platform detection for GitHub Enterprise or self
 
 ```toml
 [ci]
-platform = "github"  # or "gitlab"
+platform = "github"  # or "gitlab", "gitea", "bitbucket"
 ```
 
 By default, the platform is detected from the remote URL. Use this when URL detection fails (e.g., `git.mycompany.com` instead of `github.mycompany.com`).
 
+### Protected branches
+
+The `[safety]` section lists branches that shouldn't be deleted or updated casually. Patterns support `*` wildcards:
+
+```toml
+[safety]
+protected-branches = ["main", "release/*"]
+```
+
+`wt remove` refuses to delete a protected branch (`--no-delete-branch` removes only the worktree), and `wt step push` requires `--force` to update one. `wt switch --create --base` warns when the base is neither the default branch nor a protected branch.
+
+### Fork remotes
+
+In a fork workflow, where `origin` is the fork and another remote holds the original repository, the `[repo]` section names that remote:
+
+```toml
+[repo]
+upstream-remote = "upstream"
+```
+
+The default branch is detected from that remote, and the `main↕` and `main…±` columns in `wt list` compare against its copy (e.g. `upstream/main`). `Remote⇅` still compares each branch with the remote it tracks, typically `origin`. Clones without a remote by that name ignore the setting.
+
+### Branch prefix
+
+Teams that namespace branches per person can set a prefix that `wt switch --create` applies automatically:
+
+```toml
+[branch]
+prefix = "{user}/"
+```
+
+`{user}` is the part of `git config user.email` before the `@` (falling back to the login name). With this, `wt switch --create login-fix` creates `alice/login-fix`; a name that already has the prefix is used as-is. Commands that take a branch accept the short name, and completions and `wt list` show it. Set `strip-prefix = false` to show full names.
+
+### Issue tracker links
+
+The `[integrations.issues]` section extracts issue keys from branch names and shows them in an Issue column in `wt list`, linked to the tracker:
+
+```toml
+[integrations.issues]
+pattern = "PROJ-(\\d+)"
+url = "https://example.atlassian.net/browse/PROJ-{id}"
+```
+
+`pattern` is a regex matched against each branch name; the first capture group becomes `{id}` in `url` (without a capture group, the whole match is used). A branch named `feature/PROJ-123-login` shows `PROJ-123`, linking to `…/browse/PROJ-123`. JSON output includes the match as `issue: {key, id, url}`.
+
+### Commit trailers
+
+The `[commit]` section appends trailers to messages generated by `wt step commit`, `wt step squash`, and `wt merge`:
+
+```toml
+[commit]
+trailers = ["Refs: {{ issue }}", "Co-authored-by: Pair Bot <bot@example.com>"]
+```
+
+Trailers are templates with the same variables as hooks, plus `{{ issue }}`, `{{ issue_id }}`, and `{{ issue_url }}` from `[integrations.issues]`. A trailer whose value expands to nothing is left out, so `Refs:` is skipped on branches without an issue; a trailer already in the message isn't repeated.
+
+### Branch rules
+
+`[[rules]]` entries set policies for branches matching a pattern, so conventions like "hotfixes start from `release`" don't rely on everyone remembering them:
+
+```toml
+[[rules]]
+pattern = "hotfix/*"
+base = "release"
+worktree-path = "../hotfixes/{{ branch | sanitize }}"
+post-create = "make check-release"
+ci = true
+```
+
+Patterns match the full branch name (including any `[branch] prefix`) with `*` wildcards; the first matching rule applies. `base` is used by `wt switch --create` when no `--base` is given. `worktree-path` replaces the user's template for matching branches, but has to stay in the directory that template puts worktrees in. `post-create` and `post-start` commands run after the project's own hooks of the same type and are approved like them. `ci = true` fetches CI status for matching branches in `wt list` without `--full`.
+
 ---
 
 ## Shell integration
@@ -1736,6 +3173,10 @@ wt config shell init fish | source
 
 Without shell integration, `wt switch` prints the target directory but cannot `cd` into it.
 
+After a switch, shell integration also sets `WT_BRANCH` to the branch and `WT_PORT` to its `hash_port` — the same port a hook's `{{ branch | hash_port }}` gets — so commands in the new worktree can reach a dev server a hook started.
+
+For completions without shell integration (e.g. when packaging), see `wt config shell completions --help`.
+
 ### Skip first-run prompt
 
 On first run without shell integration, Worktrunk offers to install it. Suppress this prompt in CI or automated environments:
@@ -1795,6 +3236,11 @@ WORKTRUNK_COMMIT_GENERATION__ARGS="test: automated commit" \
 | `WORKTRUNK_DIRECTIVE_FILE` | Internal: set by shell wrappers to enable directory changes |
 | `WORKTRUNK_SHELL` | Internal: set by shell wrappers to indicate shell type (e.g., `powershell`) |
 | `WORKTRUNK_MAX_CONCURRENT_COMMANDS` | Max parallel git commands (default: 32). Lower if hitting file descriptor limits. |
+| `WORKTRUNK_LOG_FORMAT` | Debug log format: `text` (default) or `json`, one object per line. Same as `--log-format`. |
+| `WORKTRUNK_ASSUME_YES` | Answer yes to all prompts, like `--yes` on every command. `--no` takes precedence. |
+| `BITBUCKET_TOKEN` | Bitbucket Cloud access token for CI status in `wt list --full` |
+| `BITBUCKET_USERNAME` | Bitbucket Cloud username, with `BITBUCKET_APP_PASSWORD` instead of a token |
+| `BITBUCKET_APP_PASSWORD` | Bitbucket Cloud app password for `BITBUCKET_USERNAME` |
 | `NO_COLOR` | Disable colored output ([standard](https://no-color.org/)) |
 | `CLICOLOR_FORCE` | Force colored output even when not a TTY |
 <!-- subdoc: show -->
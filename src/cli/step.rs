@@ -34,7 +34,7 @@ stage = "tracked"
 
 ### `--show-prompt`
 
-Output the rendered LLM prompt to stdout without running the command. Useful for inspecting prompt templates or piping to other tools:
+Output the rendered LLM prompt to stdout without running the command. This is exactly what the command would receive, with secrets already redacted. Useful for inspecting prompt templates or piping to other tools:
 
 ```console
 # Inspect the rendered prompt
@@ -43,6 +43,15 @@ wt step commit --show-prompt | less
 # Pipe to a different LLM
 wt step commit --show-prompt | llm -m gpt-5-nano
 ```
+
+### `--interactive`
+
+Split the changes into several commits instead of one. Changes are grouped by top-level directory — or by the LLM with `--llm-groups` — and each group is shown for confirmation: `y` (or Enter) commits it with its own generated message, `n` skips it, `q` stops. Pre-commit hooks run once, before the first group. Skipped groups stay staged.
+
+```console
+wt step commit --interactive
+wt step commit --interactive --llm-groups
+```
 "#
     )]
     Commit {
@@ -54,6 +63,10 @@ wt step commit --show-prompt | llm -m gpt-5-nano
         #[arg(long = "no-verify", action = clap::ArgAction::SetFalse, default_value_t = true)]
         verify: bool,
 
+        /// Skip hooks of one type (repeatable)
+        #[arg(long, value_name = "HOOK", hide_possible_values = true)]
+        skip_hook: Vec<worktrunk::HookType>,
+
         /// What to stage before committing [default: all]
         #[arg(long)]
         stage: Option<crate::commands::commit::StageMode>,
@@ -63,6 +76,16 @@ wt step commit --show-prompt | llm -m gpt-5-nano
         /// Outputs the rendered prompt to stdout for debugging or manual piping.
         #[arg(long)]
         show_prompt: bool,
+
+        /// Split changes into several commits, confirming each
+        ///
+        /// Groups changes by top-level directory and asks about each group.
+        #[arg(long, conflicts_with = "show_prompt")]
+        interactive: bool,
+
+        /// Group changes with the LLM instead of by directory
+        #[arg(long, requires = "interactive")]
+        llm_groups: bool,
     },
 
     /// Squash commits since branching
@@ -96,11 +119,19 @@ stage = "tracked"
 
 ### `--show-prompt`
 
-Output the rendered LLM prompt to stdout without running the command. Useful for inspecting prompt templates or piping to other tools:
+Output the rendered LLM prompt to stdout without running the command. This is exactly what the command would receive, with secrets already redacted. Useful for inspecting prompt templates or piping to other tools:
 
 ```console
 wt step squash --show-prompt | less
 ```
+
+### `--autosquash`
+
+Fold `fixup!`, `squash!`, and `amend!` commits into the commits they name before squashing, as `git rebase --autosquash` does:
+
+```console
+wt step squash --autosquash
+```
 "#
     )]
     Squash {
@@ -127,6 +158,12 @@ wt step squash --show-prompt | less
         /// Outputs the rendered prompt to stdout for debugging or manual piping.
         #[arg(long)]
         show_prompt: bool,
+
+        /// Fold fixup!/squash! commits first
+        ///
+        /// Runs git rebase --autosquash before squashing what remains.
+        #[arg(long)]
+        autosquash: bool,
     },
 
     /// Fast-forward target to current branch
@@ -140,6 +177,12 @@ wt step squash --show-prompt | less
         /// Defaults to default branch.
         #[arg(add = crate::completion::branch_value_completer())]
         target: Option<String>,
+
+        /// Allow pushing to a protected branch
+        ///
+        /// Required when the target matches `[safety] protected-branches`.
+        #[arg(short, long)]
+        force: bool,
     },
 
     /// Rebase onto target
@@ -258,6 +301,23 @@ The `.worktreeinclude` pattern is shared with [Claude Code on desktop](https://c
         dry_run: bool,
     },
 
+    /// Clean up stale worktree metadata
+    ///
+    /// Prunes git's records of worktrees whose directory is gone. With `--orphans`, also removes directories at worktree-path locations that git no longer tracks but that were worktrees of this repository. Other directories there are only deleted after asking, even with `--yes`.
+    Prune {
+        /// Also remove orphaned worktree directories
+        #[arg(long)]
+        orphans: bool,
+
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+
+        /// Show what would be pruned
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// \[experimental\] Run command in each worktree
     #[command(
         after_long_help = r#"Executes a command sequentially in every worktree with real-time output. Continues on failure and shows a summary at the end.
@@ -294,12 +354,36 @@ Pull updates in worktrees with upstreams (skips others):
 git fetch --prune && wt step for-each -- '[ "$(git rev-parse @{u} 2>/dev/null)" ] || exit 0; git pull --autostash'
 ```
 
+Run the project's `test` profile (see `wt run`) in every worktree:
+
+```console
+wt step for-each --profile test
+```
+
 Note: This command is experimental and may change in future versions.
 "#
     )]
     ForEach {
         /// Command template (see --help for all variables)
-        #[arg(required = true, last = true, num_args = 1..)]
+        #[arg(required_unless_present = "profile", last = true, num_args = 1..)]
         args: Vec<String>,
+
+        /// Run a project command profile instead of ARGS
+        #[arg(long, value_name = "NAME", conflicts_with = "args")]
+        profile: Option<String>,
+
+        /// Skip approval prompts
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Finish deleting a removed worktree
+    ///
+    /// Run by background removals: moves an entry of `.git/wt-trash/` to the
+    /// OS trash when `[remove] use-trash` allows, and deletes it otherwise.
+    #[command(hide = true)]
+    EmptyTrash {
+        /// Entry in the trash directory
+        path: std::path::PathBuf,
     },
 }
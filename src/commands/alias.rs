@@ -0,0 +1,207 @@
+//! Command aliases from `[alias]` in user config.
+//!
+//! `ls = "list --branches --full"` makes `wt ls` run `wt list --branches --full`,
+//! with any further arguments appended, like git aliases. Aliases expand before
+//! clap parses the command line. Built-in commands can't be shadowed, and an
+//! alias may expand to another alias but not back into one already expanded.
+
+use std::collections::HashSet;
+use std::ffi::OsString;
+use std::path::PathBuf;
+
+use color_print::cformat;
+use worktrunk::config::{WorktrunkConfig, set_config_path};
+use worktrunk::git::GitError;
+use worktrunk::styling::{hint_message, info_message, visual_width, warning_message};
+
+use crate::cli;
+use crate::display::pad_to_width;
+use crate::output;
+
+/// Global options whose value is a separate argument (`-C <path>`)
+const GLOBAL_OPTIONS_WITH_VALUE: &[&str] = &["-C", "--config", "--log-format"];
+
+/// Replace an alias in the command position of `args` with its expansion.
+///
+/// Returns `args` untouched when the command is built in or isn't an alias.
+/// User config is only loaded for commands clap wouldn't recognize.
+pub fn expand_aliases(mut args: Vec<OsString>) -> anyhow::Result<Vec<OsString>> {
+    let Some(position) = command_position(&args) else {
+        return Ok(args);
+    };
+    let builtins = builtin_commands();
+    if args[position]
+        .to_str()
+        .is_none_or(|name| builtins.contains(name))
+    {
+        return Ok(args);
+    }
+
+    // `--config` has to take effect before the config is loaded
+    if let Some(path) = config_path_arg(&args, position) {
+        set_config_path(path);
+    }
+    // A config that fails to load gets reported once the command runs
+    let Ok(config) = WorktrunkConfig::load() else {
+        return Ok(args);
+    };
+
+    let mut expanded: Vec<String> = Vec::new();
+    while let Some(name) = args[position].to_str().map(str::to_string) {
+        if builtins.contains(&name) {
+            break;
+        }
+        let Some(expansion) = config.alias.get(&name) else {
+            break;
+        };
+        if expanded.contains(&name) {
+            expanded.push(name.clone());
+            return Err(GitError::Other {
+                message: cformat!(
+                    "Alias <bold>{name}</> expands to itself: {}",
+                    expanded.join(" → ")
+                ),
+            }
+            .into());
+        }
+        let words = match shlex::split(expansion) {
+            Some(words) if !words.is_empty() => words,
+            _ => {
+                return Err(GitError::Other {
+                    message: cformat!(
+                        "Alias <bold>{name}</> has an invalid expansion: {expansion}"
+                    ),
+                }
+                .into());
+            }
+        };
+        log::debug!("Expanding alias {name} to {expansion}");
+        expanded.push(name);
+        args.splice(position..=position, words.into_iter().map(OsString::from));
+    }
+    Ok(args)
+}
+
+/// Index of the first argument that isn't a global option or its value
+fn command_position(args: &[OsString]) -> Option<usize> {
+    let mut iter = args.iter().enumerate().skip(1);
+    while let Some((i, arg)) = iter.next() {
+        let arg = arg.to_str()?;
+        if GLOBAL_OPTIONS_WITH_VALUE.contains(&arg) {
+            iter.next();
+        } else if arg == "--" {
+            return None;
+        } else if !arg.starts_with('-') {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// The value of `--config <path>` or `--config=<path>` before the command at `position`
+fn config_path_arg(args: &[OsString], position: usize) -> Option<PathBuf> {
+    let globals = &args[1..position];
+    globals.iter().enumerate().find_map(|(i, arg)| {
+        let arg = arg.to_str()?;
+        if arg == "--config" {
+            globals.get(i + 1).map(PathBuf::from)
+        } else {
+            arg.strip_prefix("--config=").map(PathBuf::from)
+        }
+    })
+}
+
+/// Names and aliases of the top-level commands, plus `help`
+fn builtin_commands() -> HashSet<String> {
+    let cmd = cli::build_command();
+    cmd.get_subcommands()
+        .flat_map(|sub| std::iter::once(sub.get_name()).chain(sub.get_all_aliases()))
+        .chain(["help"])
+        .map(str::to_string)
+        .collect()
+}
+
+/// Handle `wt alias list`
+pub fn handle_alias_list(config: &WorktrunkConfig) -> anyhow::Result<()> {
+    if config.alias.is_empty() {
+        output::print(info_message("No aliases configured"))?;
+        output::print(hint_message(cformat!(
+            "To add one, set <bright-black>[alias] ls = \"list --full\"</> in user config"
+        )))?;
+        return Ok(());
+    }
+
+    let builtins = builtin_commands();
+    let width = config
+        .alias
+        .keys()
+        .map(|name| visual_width(name))
+        .max()
+        .unwrap_or(0);
+    let mut lines = Vec::new();
+    for (name, expansion) in &config.alias {
+        if builtins.contains(name) {
+            output::print(warning_message(cformat!(
+                "Alias <bold>{name}</> is ignored; <bold>wt {name}</> is a built-in command"
+            )))?;
+            continue;
+        }
+        lines.push(format!("{}  {expansion}", pad_to_width(name, width)));
+    }
+    if !lines.is_empty() {
+        output::stdout(lines.join("\n"))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<OsString> {
+        args.iter().map(OsString::from).collect()
+    }
+
+    #[test]
+    fn test_command_position() {
+        assert_eq!(command_position(&args(&["wt", "ls"])), Some(1));
+        assert_eq!(command_position(&args(&["wt", "-C", "dir", "ls"])), Some(3));
+        assert_eq!(
+            command_position(&args(&["wt", "-v", "--no", "ls"])),
+            Some(3)
+        );
+        assert_eq!(
+            command_position(&args(&["wt", "--config=c.toml", "ls"])),
+            Some(2)
+        );
+        assert_eq!(command_position(&args(&["wt", "-v"])), None);
+    }
+
+    #[test]
+    fn test_config_path_arg() {
+        let with_flag = args(&["wt", "--config", "c.toml", "ls"]);
+        assert_eq!(
+            config_path_arg(&with_flag, 3),
+            Some(PathBuf::from("c.toml"))
+        );
+        let with_equals = args(&["wt", "-C", "dir", "--config=c.toml", "ls"]);
+        assert_eq!(
+            config_path_arg(&with_equals, 4),
+            Some(PathBuf::from("c.toml"))
+        );
+        // After the command, `--config` belongs to the expansion's arguments
+        assert_eq!(
+            config_path_arg(&args(&["wt", "ls", "--config", "c"]), 1),
+            None
+        );
+    }
+
+    #[test]
+    fn test_builtin_commands() {
+        let builtins = builtin_commands();
+        assert!(builtins.contains("list"));
+        assert!(builtins.contains("alias"));
+        assert!(builtins.contains("help"));
+        assert!(!builtins.contains("ls"));
+    }
+}
@@ -0,0 +1,217 @@
+//! `wt cherry`: cherry-pick commits from another worktree's branch.
+//!
+//! Worktrees share one object database, so commits on any local branch can be
+//! picked into the current worktree directly. Only commits are picked; the
+//! other worktree's uncommitted changes stay where they are.
+
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{eprintln, info_message, success_message};
+
+use crate::output;
+use crate::output::prompt::{Input, Response, assumed_answer, is_interactive};
+
+/// A commit offered for picking.
+struct Candidate {
+    sha: String,
+    short: String,
+    subject: String,
+}
+
+/// Handle `wt cherry`.
+///
+/// With `commits`, picks exactly those. Otherwise offers the commits on
+/// `branch` that neither the default branch nor the current worktree has
+/// (patches picked earlier are recognized by content), all of them with `all`.
+pub fn handle_cherry(branch: &str, commits: &[String], all: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    if !repo.branch_exists(branch)? {
+        return Err(GitError::InvalidReference {
+            reference: branch.to_string(),
+        }
+        .into());
+    }
+    let worktree = repo.current_worktree();
+    worktree.ensure_clean("cherry-pick", None, false)?;
+
+    let picks = if commits.is_empty() {
+        let candidates = pickable_commits(&repo, branch)?;
+        if candidates.is_empty() {
+            output::print(info_message(cformat!(
+                "No commits on <bold>{branch}</> that this worktree doesn't have"
+            )))?;
+            return Ok(());
+        }
+        if all || assumed_answer() == Some(true) {
+            candidates
+        } else if assumed_answer() == Some(false) {
+            return Ok(());
+        } else if !is_interactive() {
+            // Without a terminal, list the candidates so they can be passed back as arguments
+            let lines: Vec<String> = candidates
+                .iter()
+                .map(|c| format!("{}\t{}", c.short, c.subject))
+                .collect();
+            output::stdout(lines.join("\n"))?;
+            return Ok(());
+        } else {
+            match choose_commits(branch, candidates)? {
+                Some(picks) => picks,
+                None => return Ok(()),
+            }
+        }
+    } else {
+        let mut args = vec!["log", "--no-walk=unsorted", "--format=%H%x00%h%x00%s"];
+        args.extend(commits.iter().map(String::as_str));
+        parse_commits(&repo.run_command(&args)?)
+    };
+
+    let mut args = vec!["cherry-pick"];
+    args.extend(picks.iter().map(|c| c.sha.as_str()));
+    if let Err(e) = worktree.run_command(&args) {
+        if repo.worktree_state()?.as_deref() != Some("CHERRY-PICKING") {
+            return Err(e);
+        }
+        let files = worktree
+            .run_command(&["diff", "--name-only", "--diff-filter=U"])?
+            .lines()
+            .map(str::to_string)
+            .collect();
+        let commit = worktree
+            .run_command(&["log", "-1", "--format=%h %s", "CHERRY_PICK_HEAD"])
+            .map(|line| line.trim().to_string())
+            .unwrap_or_default();
+        return Err(GitError::CherryPickConflict {
+            source_branch: branch.to_string(),
+            commit,
+            files,
+        }
+        .into());
+    }
+
+    let count = picks.len();
+    let suffix = if count == 1 { "" } else { "s" };
+    output::print(success_message(cformat!(
+        "Cherry-picked {count} commit{suffix} from <bold>{branch}</>"
+    )))?;
+    Ok(())
+}
+
+/// Commits on `branch` missing from both HEAD and the default branch, oldest first.
+fn pickable_commits(repo: &Repository, branch: &str) -> anyhow::Result<Vec<Candidate>> {
+    let range = format!("HEAD...{branch}");
+    let exclude_base = repo.default_branch().map(|base| format!("^{base}"));
+    let mut args = vec![
+        "log",
+        "--reverse",
+        "--no-merges",
+        "--right-only",
+        "--cherry-pick",
+        "--format=%H%x00%h%x00%s",
+        range.as_str(),
+    ];
+    args.extend(exclude_base.as_deref());
+    Ok(parse_commits(&repo.run_command(&args)?))
+}
+
+/// Parse `%H%x00%h%x00%s` log lines.
+fn parse_commits(output: &str) -> Vec<Candidate> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\0');
+            Some(Candidate {
+                sha: fields.next()?.to_string(),
+                short: fields.next()?.to_string(),
+                subject: fields.next().unwrap_or_default().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Prompt for commits to pick by number. Returns `None` if the user cancels.
+fn choose_commits(
+    branch: &str,
+    candidates: Vec<Candidate>,
+) -> anyhow::Result<Option<Vec<Candidate>>> {
+    output::print(info_message(cformat!(
+        "Commits on <bold>{branch}</> not in this worktree:"
+    )))?;
+    output::flush()?;
+    for (i, c) in candidates.iter().enumerate() {
+        eprintln!(
+            "{}",
+            cformat!(
+                "  <bold>{}</> <bright-black>{}</> {}",
+                i + 1,
+                c.short,
+                c.subject
+            )
+        );
+    }
+    let question = cformat!(
+        "Cherry-pick <bold>[1-{}]</> (e.g. 1,3 or 2-4), or Enter to cancel:",
+        candidates.len()
+    );
+    let response = match Input::new(question).ask(false)? {
+        Response::Text(text) => text,
+        Response::Yes => return Ok(Some(candidates)),
+        Response::No | Response::NotInteractive => return Ok(None),
+    };
+    if response.is_empty() {
+        return Ok(None);
+    }
+    let Some(indices) = parse_selection(&response, candidates.len()) else {
+        return Err(GitError::Other {
+            message: format!("Invalid selection: {response}"),
+        }
+        .into());
+    };
+    let mut candidates: Vec<Option<Candidate>> = candidates.into_iter().map(Some).collect();
+    Ok(Some(
+        indices
+            .into_iter()
+            .filter_map(|i| candidates[i].take())
+            .collect(),
+    ))
+}
+
+/// Parse a selection like `1,3` or `2-4` into zero-based indices below `count`,
+/// in the order given. Returns `None` if any part is out of range or malformed.
+fn parse_selection(input: &str, count: usize) -> Option<Vec<usize>> {
+    let mut indices = Vec::new();
+    for part in input
+        .split([',', ' '])
+        .filter(|part| !part.trim().is_empty())
+    {
+        let (start, end) = match part.trim().split_once('-') {
+            Some((start, end)) => (start.trim().parse().ok()?, end.trim().parse().ok()?),
+            None => {
+                let n: usize = part.trim().parse().ok()?;
+                (n, n)
+            }
+        };
+        if start == 0 || start > end || end > count {
+            return None;
+        }
+        indices.extend((start - 1)..end);
+    }
+    Some(indices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_selection() {
+        assert_eq!(parse_selection("1", 3), Some(vec![0]));
+        assert_eq!(parse_selection("1,3\n", 3), Some(vec![0, 2]));
+        assert_eq!(parse_selection("2-3", 3), Some(vec![1, 2]));
+        assert_eq!(parse_selection("3 1", 3), Some(vec![2, 0]));
+        assert_eq!(parse_selection("0", 3), None);
+        assert_eq!(parse_selection("4", 3), None);
+        assert_eq!(parse_selection("3-2", 3), None);
+        assert_eq!(parse_selection("x", 3), None);
+    }
+}
@@ -0,0 +1,138 @@
+//! `wt merge --wait-ci`: push the branch and hold the merge until its CI passes.
+//!
+//! The branch is pushed after the local pipeline has run, so CI sees exactly
+//! what will land. Its status is then polled through the forge backend until
+//! the checks pass, fail, or `[merge] ci-timeout` runs out. Only a pass lets
+//! the merge go on to update the target.
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{Stream, progress_message, success_message, supports_hyperlinks};
+
+use super::forge::forge_for;
+use super::list::ci_status::{CiStatus, PrStatus, get_platform_for_repo};
+use super::worktree::push_branch;
+use crate::output;
+
+/// How long a branch may go without any checks before it counts as having no CI,
+/// unless `[merge] ci-timeout` is shorter.
+const NO_CHECKS_GRACE: Duration = Duration::from_secs(60);
+
+/// Push `branch` and wait for its CI to pass.
+///
+/// The push uses `--force-with-lease`, since squashing and rebasing rewrite
+/// the branch. Errors when the checks fail, the branch conflicts with its
+/// base, or the checks don't finish within the timeout.
+pub(crate) fn push_and_wait_for_ci(
+    repo: &Repository,
+    branch: &str,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let project_config = repo.load_project_config()?;
+    let platform_override = project_config.as_ref().and_then(|c| c.ci_platform());
+    let Some(platform) = get_platform_for_repo(repo, platform_override) else {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Can't wait for CI: no supported host for this repository; set <bright-black>[ci] platform</> if it's hosted on GitHub, GitLab, Gitea, or Bitbucket"
+            ),
+        }
+        .into());
+    };
+    let forge = forge_for(platform);
+
+    push_branch(repo, branch, true)?;
+    let head = repo.run_command(&["rev-parse", branch])?.trim().to_string();
+
+    let (timeout, interval) = config.merge_ci_timing();
+    let no_checks_grace = NO_CHECKS_GRACE.min(timeout);
+    let started = Instant::now();
+    let mut last_state: Option<String> = None;
+    loop {
+        // Statuses for the previous push say nothing about this one
+        let status = forge
+            .status(repo, branch, &head, true)
+            .filter(|status| !status.is_stale);
+        let ci_status = status.as_ref().map(|status| status.ci_status);
+        let elapsed = started.elapsed();
+
+        match ci_status {
+            Some(CiStatus::Passed) => {
+                output::print(success_message(cformat!(
+                    "CI passed for <bold>{branch}</> ({})",
+                    format_elapsed(elapsed)
+                )))?;
+                return Ok(());
+            }
+            Some(CiStatus::Failed) => {
+                return Err(ci_error(branch, status.as_ref(), "CI failed"));
+            }
+            Some(CiStatus::Conflicts) => {
+                return Err(ci_error(
+                    branch,
+                    status.as_ref(),
+                    "The branch conflicts with its base",
+                ));
+            }
+            None | Some(CiStatus::NoCI) if elapsed >= no_checks_grace => {
+                return Err(GitError::Other {
+                    message: cformat!(
+                        "No CI checks reported for <bold>{branch}</> after {}",
+                        format_elapsed(elapsed)
+                    ),
+                }
+                .into());
+            }
+            _ => {}
+        }
+
+        if elapsed >= timeout {
+            return Err(GitError::Other {
+                message: cformat!(
+                    "Timed out waiting for CI on <bold>{branch}</> after {}; raise <bright-black>[merge] ci-timeout</> to wait longer",
+                    format_elapsed(elapsed)
+                ),
+            }
+            .into());
+        }
+
+        // Report changes only, not every poll
+        let state = match &status {
+            Some(status) if status.ci_status == CiStatus::Running => format!(
+                "{} running",
+                status.format_indicator(supports_hyperlinks(Stream::Stderr))
+            ),
+            _ => "waiting for checks to start".to_string(),
+        };
+        if last_state.as_ref() != Some(&state) {
+            output::print(progress_message(cformat!(
+                "Waiting for CI on <bold>{branch}</>: {state}..."
+            )))?;
+            last_state = Some(state);
+        }
+        thread::sleep(interval.min(timeout.saturating_sub(elapsed)));
+    }
+}
+
+fn ci_error(branch: &str, status: Option<&PrStatus>, what: &str) -> anyhow::Error {
+    let link = status
+        .and_then(|status| status.url.as_deref())
+        .map(|url| format!(": {url}"))
+        .unwrap_or_default();
+    GitError::Other {
+        message: cformat!("{what} for <bold>{branch}</>, not merging{link}"),
+    }
+    .into()
+}
+
+fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    if secs < 60 {
+        format!("{secs}s")
+    } else {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    }
+}
@@ -0,0 +1,196 @@
+//! `wt clean`: clear build output and other ignored files across worktrees.
+//!
+//! Runs `git clean -Xd` in each selected worktree, like `wt step for-each`
+//! but aggregated: the default is a dry run (`-n`) listing what would go and
+//! how much space it takes, and `--force` deletes (`-f`). Only ignored files
+//! go unless `--untracked` asks for untracked ones too (`-x`), so new files
+//! that aren't committed yet stay by default. Cleaning every worktree asks
+//! first.
+
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{GitError, Repository, ResolvedWorktree, WorktreeInfo};
+use worktrunk::styling::{
+    error_message, format_with_gutter, hint_message, info_message, success_message,
+};
+
+use crate::output::prompt::{self, Confirm};
+
+use super::for_each::report_failures;
+use super::list::disk_usage::{format_size, path_size};
+use super::worktree_display_name;
+use crate::output;
+
+/// A path `git clean` would remove, relative to its worktree.
+struct CleanPath {
+    path: String,
+    bytes: u64,
+}
+
+/// Handle `wt clean`.
+pub fn handle_clean(
+    branches: &[String],
+    force: bool,
+    untracked: bool,
+    yes: bool,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let config = WorktrunkConfig::load()?;
+    let worktrees = select_worktrees(&repo, branches)?;
+
+    let mode = if untracked { "-x" } else { "-X" };
+    if force && branches.is_empty() {
+        let what = if untracked {
+            "ignored and untracked files"
+        } else {
+            "ignored files"
+        };
+        let count = worktrees.len();
+        let worktrees_word = if count == 1 { "worktree" } else { "worktrees" };
+        let question = cformat!("Delete {what} in all <bold>{count}</> {worktrees_word}?");
+        if !Confirm::new(question, false).ask(yes)? {
+            if prompt::assumed_answer().is_none() && !prompt::is_interactive() {
+                output::print(hint_message(cformat!(
+                    "To clean every worktree without prompting, add <bright-black>--yes</>"
+                )))?;
+            } else {
+                output::print(info_message("Clean cancelled"))?;
+            }
+            return Ok(());
+        }
+    }
+    let mut failed: Vec<String> = Vec::new();
+    let mut total_bytes = 0;
+    let mut touched = 0;
+
+    for wt in &worktrees {
+        let display_name = worktree_display_name(wt, &repo, &config);
+        let preview = match dry_run(&repo, wt, mode) {
+            Ok(paths) => paths,
+            Err(e) => {
+                output::print(error_message(cformat!("Failed in {display_name}")))?;
+                output::print(format_with_gutter(&e.to_string(), None))?;
+                failed.push(display_name);
+                continue;
+            }
+        };
+        if preview.is_empty() {
+            continue;
+        }
+
+        let bytes: u64 = preview.iter().map(|p| p.bytes).sum();
+        let count = preview.len();
+        let paths = if count == 1 { "path" } else { "paths" };
+        if force {
+            let args = ["clean", "-d", mode, "-f", "--quiet"];
+            if let Err(e) = repo.worktree_at(&wt.path).run_command(&args) {
+                output::print(error_message(cformat!("Failed in {display_name}")))?;
+                output::print(format_with_gutter(&e.to_string(), None))?;
+                failed.push(display_name);
+                continue;
+            }
+            output::print(success_message(cformat!(
+                "Cleaned {display_name}: {count} {paths}, {}",
+                format_size(bytes)
+            )))?;
+        } else {
+            output::print(info_message(cformat!(
+                "{display_name}: {count} {paths}, {}",
+                format_size(bytes)
+            )))?;
+            let listing = preview
+                .iter()
+                .map(|p| format!("{:>6}  {}", format_size(p.bytes), p.path))
+                .collect::<Vec<_>>()
+                .join("\n");
+            output::print(format_with_gutter(&listing, None))?;
+        }
+        total_bytes += bytes;
+        touched += 1;
+    }
+
+    let worktrees_label = |n: usize| if n == 1 { "worktree" } else { "worktrees" };
+    if touched == 0 && failed.is_empty() {
+        output::print(info_message("Nothing to clean"))?;
+    } else if touched > 0 && force {
+        output::print(success_message(format!(
+            "Freed {} across {touched} {}",
+            format_size(total_bytes),
+            worktrees_label(touched)
+        )))?;
+    } else if touched > 0 {
+        output::print(info_message(format!(
+            "Would free {} across {touched} {}",
+            format_size(total_bytes),
+            worktrees_label(touched)
+        )))?;
+        let flag = if untracked { " --untracked" } else { "" };
+        output::print(hint_message(cformat!(
+            "To delete, run <bright-black>wt clean{flag} --force</>"
+        )))?;
+    }
+
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        report_failures(&failed, worktrees.len())
+    }
+}
+
+/// The worktrees named by `branches`, or every worktree with files on disk.
+fn select_worktrees(repo: &Repository, branches: &[String]) -> anyhow::Result<Vec<WorktreeInfo>> {
+    let worktrees: Vec<WorktreeInfo> = repo
+        .list_worktrees()?
+        .into_iter()
+        .filter(|wt| !wt.bare && !wt.is_prunable())
+        .collect();
+    if branches.is_empty() {
+        return Ok(worktrees);
+    }
+
+    let mut selected: Vec<WorktreeInfo> = Vec::new();
+    for name in branches {
+        let path = match repo.resolve_worktree(name)? {
+            ResolvedWorktree::Worktree { path, .. } => path,
+            ResolvedWorktree::BranchOnly { branch } => {
+                return Err(GitError::NoWorktreeFound { branch }.into());
+            }
+        };
+        let Some(wt) = worktrees.iter().find(|wt| same_path(&wt.path, &path)) else {
+            return Err(GitError::WorktreeMissing {
+                branch: name.clone(),
+            }
+            .into());
+        };
+        if !selected.iter().any(|s| s.path == wt.path) {
+            selected.push(wt.clone());
+        }
+    }
+    Ok(selected)
+}
+
+fn same_path(a: &std::path::Path, b: &std::path::Path) -> bool {
+    a == b || dunce::canonicalize(a).ok() == dunce::canonicalize(b).ok()
+}
+
+/// What `git clean -n` would remove in a worktree, largest first.
+fn dry_run(repo: &Repository, wt: &WorktreeInfo, mode: &str) -> anyhow::Result<Vec<CleanPath>> {
+    let stdout = repo.worktree_at(&wt.path).run_command(&[
+        "-c",
+        "core.quotePath=false",
+        "clean",
+        "-d",
+        mode,
+        "-n",
+    ])?;
+    let mut paths: Vec<CleanPath> = stdout
+        .lines()
+        .filter_map(|line| line.strip_prefix("Would remove "))
+        .map(|path| CleanPath {
+            bytes: path_size(&wt.path.join(path)),
+            path: path.to_string(),
+        })
+        .collect();
+    paths.sort_by(|a, b| b.bytes.cmp(&a.bytes).then(a.path.cmp(&b.path)));
+    Ok(paths)
+}
@@ -0,0 +1,226 @@
+//! Clone a repository into the layout the worktree-path template expects.
+//!
+//! With the default template (`../{{ repo }}.{{ branch | sanitize }}`),
+//! worktrees are siblings of the main worktree, so a plain clone is already
+//! the right shape. Templates that place worktrees next to the main worktree
+//! without the repo name (e.g. `../{{ branch | sanitize }}`) only work when
+//! the main worktree lives in its own directory, so the clone goes into
+//! `<directory>/<default-branch>` instead. The default branch is only known
+//! once the clone exists, so the clone lands in a staging directory first and
+//! is renamed into place.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::config::{WorktrunkConfig, normalize_template_vars, sanitize_branch_name};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{
+    hint_message, info_message, progress_message, success_message, warning_message,
+};
+
+use super::approve_hooks;
+use super::command_executor::CommandContext;
+use super::worktree::{execute_switch, plan_switch};
+use crate::output;
+use crate::output::handle_switch_output;
+
+/// Staging directory for a nested clone, inside the target directory.
+const STAGING_DIR: &str = ".wt-clone";
+
+/// Handle `wt clone`: clone `url`, set `origin/HEAD`, and cd into the main
+/// worktree, or into a worktree for `switch` when given.
+pub fn handle_clone(
+    url: &str,
+    directory: Option<&Path>,
+    switch: Option<&str>,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let directory = match directory {
+        Some(directory) => directory.to_path_buf(),
+        None => PathBuf::from(repo_name_from_url(url).ok_or_else(|| {
+            GitError::CloneDirectoryUnknown {
+                url: url.to_string(),
+            }
+        })?),
+    };
+
+    let nested = template_nests_worktrees(&config.worktree_path());
+    // A nested layout owns the whole directory; a plain clone only needs its own
+    let clone_path = if nested {
+        directory.join(STAGING_DIR)
+    } else {
+        directory.clone()
+    };
+    if directory
+        .read_dir()
+        .is_ok_and(|mut entries| entries.next().is_some())
+    {
+        return Err(GitError::CloneTargetNotEmpty { path: directory }.into());
+    }
+
+    output::print(progress_message(cformat!(
+        "Cloning <bold>{url}</> into <bold>{}</>...",
+        format_path_for_display(&directory)
+    )))?;
+
+    Cmd::new("git")
+        .args(["clone", url])
+        .arg(clone_path.to_string_lossy())
+        .stdout(std::process::Stdio::from(std::io::stderr()))
+        .context("clone")
+        .stream()?;
+
+    // `git clone` sets origin/HEAD, but only when the remote advertises it;
+    // worktrunk relies on it to find the default branch without network calls.
+    let repo = Repository::at(&clone_path)?;
+    if repo
+        .run_command(&["remote", "set-head", "origin", "--auto"])
+        .is_err()
+    {
+        output::print(warning_message(
+            "Could not set origin/HEAD; the default branch will be detected on demand",
+        ))?;
+    }
+
+    let target = if nested {
+        // An empty clone has no default branch yet; name its directory after HEAD
+        let default_branch = match repo.default_branch() {
+            Some(branch) => branch,
+            None => repo
+                .run_command(&["symbolic-ref", "--short", "HEAD"])?
+                .trim()
+                .to_string(),
+        };
+        let target = directory.join(sanitize_branch_name(&default_branch));
+        std::fs::rename(&clone_path, &target).with_context(|| {
+            format!(
+                "Failed to move the clone to {}",
+                format_path_for_display(&target)
+            )
+        })?;
+        target
+    } else {
+        clone_path
+    };
+
+    let target = dunce::canonicalize(&target).unwrap_or(target);
+    output::print(success_message(cformat!(
+        "Cloned into <bold>{}</>",
+        format_path_for_display(&target)
+    )))?;
+
+    match switch {
+        Some(branch) => switch_to_worktree(&Repository::at(&target)?, branch, config)?,
+        None => {
+            output::print(hint_message(cformat!(
+                "To start a worktree, run <bright-black>wt switch --create BRANCH</>"
+            )))?;
+            output::change_directory(&target)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Create the worktree for `branch` in the new clone and cd into it.
+///
+/// An existing branch (usually a remote one) is checked out; any other name
+/// becomes a new branch from the default branch. Post-create hooks run after
+/// approval, as with `wt switch --create`.
+fn switch_to_worktree(
+    repo: &Repository,
+    branch: &str,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let create = !repo.branch_exists(branch)?;
+    let plan = plan_switch(repo, branch, create, None, false, None, config)?;
+
+    let repo_root = repo.repo_path()?;
+    let ctx = CommandContext::new(
+        repo,
+        config,
+        plan.branch(),
+        plan.worktree_path(),
+        &repo_root,
+        false,
+    );
+    let approved = approve_hooks(&ctx, &[HookType::PostCreate])?;
+    if !approved {
+        output::print(info_message(
+            "Commands declined, continuing worktree creation",
+        ))?;
+    }
+
+    let (result, branch_info) = execute_switch(repo, plan, config, false, !approved, false)?;
+    handle_switch_output(&result, &branch_info, None)?;
+    Ok(())
+}
+
+/// Derive the directory name `git clone` would use for `url`.
+///
+/// Takes the last path component (after `/` or `:`), minus a `.git` suffix.
+fn repo_name_from_url(url: &str) -> Option<&str> {
+    let url = url.trim_end_matches('/');
+    let name = url.rsplit(['/', ':']).next()?;
+    let name = name.strip_suffix(".git").unwrap_or(name);
+    (!name.is_empty()).then_some(name)
+}
+
+/// Whether the template puts worktrees beside the main worktree in a shared
+/// parent directory that isn't named after the repo.
+///
+/// `../{{ branch | sanitize }}` from `repo/main` gives `repo/feature`; from a
+/// plain `repo` clone it would scatter worktrees into the clone's parent.
+/// Templates that don't parse are left to fail when a worktree is created.
+fn template_nests_worktrees(template: &str) -> bool {
+    if !template.starts_with("../") {
+        return false;
+    }
+    // `main_worktree` is the deprecated name for `repo`
+    let template = normalize_template_vars(template);
+    let env = minijinja::Environment::new();
+    env.template_from_str(&template)
+        .is_ok_and(|parsed| !parsed.undeclared_variables(false).contains("repo"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repo_name_from_url() {
+        assert_eq!(
+            repo_name_from_url("https://github.com/max-sixty/worktrunk.git"),
+            Some("worktrunk")
+        );
+        assert_eq!(
+            repo_name_from_url("git@github.com:max-sixty/worktrunk.git"),
+            Some("worktrunk")
+        );
+        assert_eq!(repo_name_from_url("git@host:worktrunk"), Some("worktrunk"));
+        assert_eq!(repo_name_from_url("/srv/git/worktrunk/"), Some("worktrunk"));
+        assert_eq!(repo_name_from_url(".git"), None);
+    }
+
+    #[test]
+    fn test_template_nests_worktrees() {
+        assert!(template_nests_worktrees("../{{ branch | sanitize }}"));
+        assert!(!template_nests_worktrees(
+            "../{{ repo }}.{{ branch | sanitize }}"
+        ));
+        assert!(!template_nests_worktrees(
+            "../{{ main_worktree }}.{{ branch }}"
+        ));
+        assert!(!template_nests_worktrees("../{{repo}}-{{branch}}"));
+        assert!(!template_nests_worktrees(
+            "../{{ repo | lower }}/{{ branch | sanitize }}"
+        ));
+        assert!(!template_nests_worktrees(
+            ".worktrees/{{ branch | sanitize }}"
+        ));
+    }
+}
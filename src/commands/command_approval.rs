@@ -18,15 +18,15 @@
 //! eliminating the need to thread `auto_trust` through execution layers.
 
 use super::hook_filter::{HookSource, ParsedFilter};
-use super::project_config::{HookCommand, collect_commands_for_hooks};
+use super::project_config::{HookCommand, collect_commands_for_hooks, collect_rule_commands};
 use crate::output;
+use crate::output::prompt::{self, Confirm, NonInteractive};
 use anyhow::Context;
 use color_print::cformat;
 use worktrunk::config::WorktrunkConfig;
-use worktrunk::git::{GitError, HookType};
+use worktrunk::git::HookType;
 use worktrunk::styling::{
-    INFO_SYMBOL, PROMPT_SYMBOL, WARNING_SYMBOL, eprint, format_bash_with_gutter, hint_message,
-    stderr, warning_message,
+    INFO_SYMBOL, WARNING_SYMBOL, format_bash_with_gutter, hint_message, warning_message,
 };
 
 /// Batch approval helper used when multiple commands are queued for execution.
@@ -57,18 +57,19 @@ pub fn approve_command_batch(
         return Ok(true);
     }
 
-    let approved = if yes {
-        true
-    } else {
-        prompt_for_batch_approval(&needs_approval, project_id)?
+    // `--no`, `--yes` and `WORKTRUNK_ASSUME_YES` answer without listing the commands
+    let assumed = prompt::assumed_answer().or(yes.then_some(true));
+    let approved = match assumed {
+        Some(answer) => answer,
+        None => prompt_for_batch_approval(&needs_approval, project_id, config)?,
     };
 
     if !approved {
         return Ok(false);
     }
 
-    // Only save approvals when interactively approved, not when using --yes
-    if !yes {
+    // Only save approvals when interactively approved, not when assumed
+    if assumed.is_none() {
         let mut fresh_config = WorktrunkConfig::load().context("Failed to reload config")?;
 
         let project_entry = fresh_config
@@ -76,18 +77,11 @@ pub fn approve_command_batch(
             .entry(project_id.to_string())
             .or_default();
 
-        let mut updated = false;
+        let before = project_entry.clone();
         for cmd in &needs_approval {
-            if !project_entry
-                .approved_commands
-                .contains(&cmd.command.template)
-            {
-                project_entry
-                    .approved_commands
-                    .push(cmd.command.template.clone());
-                updated = true;
-            }
+            project_entry.record_approval(&cmd.approval_key(), &cmd.command.template);
         }
+        let updated = *project_entry != before;
 
         if updated && let Err(e) = fresh_config.save() {
             let _ = output::print(warning_message(format!(
@@ -100,9 +94,11 @@ pub fn approve_command_batch(
     Ok(true)
 }
 
-fn prompt_for_batch_approval(commands: &[&HookCommand], project_id: &str) -> anyhow::Result<bool> {
-    use std::io::{self, IsTerminal, Write};
-
+fn prompt_for_batch_approval(
+    commands: &[&HookCommand],
+    project_id: &str,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<bool> {
     let project_name = project_id.split('/').next_back().unwrap_or(project_id);
     let count = commands.len();
     let plural = if count == 1 { "" } else { "s" };
@@ -118,39 +114,25 @@ fn prompt_for_batch_approval(commands: &[&HookCommand], project_id: &str) -> any
 
     for cmd in commands {
         // Format as: {phase} {bold}{name}{bold:#}:
-        // Phase comes from the hook type (e.g., "pre-commit", "pre-merge")
+        // Phase is the hook type (e.g., "pre-commit", "pre-merge") or "run" for profiles
         // Uses INFO_SYMBOL (○) since this is a preview, not active execution
-        let phase = cmd.hook_type.to_string();
-        let label = match &cmd.command.name {
+        let phase = cmd.phase.to_string();
+        let mut label = match &cmd.command.name {
             Some(name) => cformat!("{INFO_SYMBOL} {phase} <bold>{name}</>:"),
             None => format!("{INFO_SYMBOL} {phase}:"),
         };
+        if config.approval_changed(project_id, &cmd.approval_key(), &cmd.command.template) {
+            label.push_str(&cformat!(" <yellow>(changed since approval)</>"));
+        }
         output::print(label)?;
         output::print(format_bash_with_gutter(&cmd.command.template))?;
     }
 
-    // Check if stdin is a TTY before attempting to prompt
-    // This happens AFTER showing the commands so they appear in CI/CD logs
-    // even when the prompt cannot be displayed (fail-fast principle)
-    if !io::stdin().is_terminal() {
-        return Err(GitError::NotInteractive.into());
-    }
-
-    // Flush stderr before showing prompt to ensure all output is visible
-    stderr().flush()?;
-
-    eprint!(
-        "{}",
-        cformat!("{PROMPT_SYMBOL} Allow and remember? <bold>[y/N]</> ")
-    );
-    stderr().flush()?;
-
-    let mut response = String::new();
-    io::stdin().read_line(&mut response)?;
-
-    output::blank()?;
-
-    Ok(response.trim().eq_ignore_ascii_case("y"))
+    // Fails without a terminal only AFTER showing the commands, so they appear
+    // in CI/CD logs even when the prompt cannot be displayed (fail-fast principle)
+    Confirm::new("Allow and remember?", false)
+        .non_interactive(NonInteractive::Fail)
+        .ask(false)
 }
 
 /// Collect project commands for hooks and request batch approval.
@@ -177,6 +159,40 @@ pub fn approve_hooks(
     approve_hooks_filtered(ctx, hook_types, None)
 }
 
+/// Like `approve_hooks`, for hooks about to run in a new worktree for each of
+/// `branches`.
+///
+/// `ctx.branch` is ignored: the `[[rules]]` commands of every branch join the
+/// batch, so one prompt covers all the worktrees.
+pub fn approve_hooks_for_branches(
+    ctx: &super::command_executor::CommandContext<'_>,
+    hook_types: &[HookType],
+    branches: &[String],
+) -> anyhow::Result<bool> {
+    let Some(project_config) = ctx.repo.load_project_config()? else {
+        return Ok(true);
+    };
+
+    let mut commands = collect_commands_for_hooks(&project_config, hook_types);
+    for branch in branches {
+        for command in collect_rule_commands(&project_config, branch, hook_types) {
+            if !commands
+                .iter()
+                .any(|known| known.command.template == command.command.template)
+            {
+                commands.push(command);
+            }
+        }
+    }
+
+    if commands.is_empty() {
+        return Ok(true);
+    }
+
+    let project_id = ctx.repo.project_identifier()?;
+    approve_command_batch(&commands, &project_id, ctx.config, ctx.yes, false)
+}
+
 /// Like `approve_hooks` but with optional name filter for targeted hook approval.
 ///
 /// When `name_filter` is provided, only commands matching that name are shown
@@ -210,6 +226,9 @@ pub fn approve_hooks_filtered(
     };
 
     let mut commands = collect_commands_for_hooks(&project_config, hook_types);
+    if let Some(branch) = ctx.branch {
+        commands.extend(collect_rule_commands(&project_config, branch, hook_types));
+    }
 
     // Apply name filter before approval to only prompt for targeted commands
     // Use the parsed name (not raw filter) for matching
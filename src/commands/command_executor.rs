@@ -1,9 +1,14 @@
 use std::collections::HashMap;
 use std::path::Path;
+
+use color_print::cformat;
 use worktrunk::HookType;
-use worktrunk::config::{Command, CommandConfig, WorktrunkConfig, expand_template};
+use worktrunk::config::{
+    Command, CommandConfig, WorktrunkConfig, compose_project_name, expand_template,
+};
 use worktrunk::git::Repository;
 use worktrunk::path::to_posix_path;
+use worktrunk::styling::info_message;
 
 #[derive(Debug)]
 pub struct PreparedCommand {
@@ -46,6 +51,34 @@ impl<'a> CommandContext<'a> {
     pub fn branch_or_head(&self) -> &str {
         self.branch.unwrap_or("HEAD")
     }
+
+    /// Repository directory name, as `{{ repo }}` in templates.
+    pub fn repo_name(&self) -> &str {
+        self.repo_root
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+    }
+
+    /// Extra environment variables for hook commands.
+    ///
+    /// With `[integrations] compose` in the project config, sets
+    /// `COMPOSE_PROJECT_NAME` to the worktree's `{{ compose_project }}`.
+    pub fn hook_env(&self) -> Vec<(String, String)> {
+        let compose = self
+            .repo
+            .load_project_config()
+            .ok()
+            .flatten()
+            .is_some_and(|config| config.compose_integration());
+        if !compose {
+            return Vec::new();
+        }
+        vec![(
+            "COMPOSE_PROJECT_NAME".to_string(),
+            compose_project_name(self.repo_name(), self.branch_or_head()),
+        )]
+    }
 }
 
 /// Build hook context as a HashMap for JSON serialization and template expansion.
@@ -57,10 +90,7 @@ pub fn build_hook_context(
     extra_vars: &[(&str, &str)],
 ) -> HashMap<String, String> {
     let repo_root = ctx.repo_root;
-    let repo_name = repo_root
-        .file_name()
-        .and_then(|n| n.to_str())
-        .unwrap_or("unknown");
+    let repo_name = ctx.repo_name();
 
     // Convert paths to POSIX format for Git Bash compatibility on Windows.
     // This avoids shell escaping of `:` and `\` characters in Windows paths.
@@ -77,6 +107,10 @@ pub fn build_hook_context(
     map.insert("repo".into(), repo_name.into());
     map.insert("branch".into(), ctx.branch_or_head().into());
     map.insert("worktree_name".into(), worktree_name.into());
+    map.insert(
+        "compose_project".into(),
+        compose_project_name(repo_name, ctx.branch_or_head()),
+    );
 
     // Canonical path variables
     map.insert("repo_path".into(), repo_path.clone());
@@ -121,6 +155,14 @@ pub fn build_hook_context(
         }
     }
 
+    // Flake reference for the worktree, when it has a flake.nix
+    if ctx.worktree_path.join("flake.nix").is_file() {
+        map.insert(
+            "flake".into(),
+            to_posix_path(&ctx.worktree_path.to_string_lossy()),
+        );
+    }
+
     // Add extra vars (e.g., target branch for merge)
     for (k, v) in extra_vars {
         map.insert((*k).into(), (*v).into());
@@ -194,12 +236,12 @@ pub fn prepare_commands(
     extra_vars: &[(&str, &str)],
     hook_type: HookType,
 ) -> anyhow::Result<Vec<PreparedCommand>> {
-    let commands = command_config.commands();
+    let commands = filter_by_changed_paths(command_config.commands(), ctx, extra_vars, hook_type)?;
     if commands.is_empty() {
         return Ok(Vec::new());
     }
 
-    let expanded_with_json = expand_commands(commands, ctx, extra_vars, hook_type)?;
+    let expanded_with_json = expand_commands(&commands, ctx, extra_vars, hook_type)?;
 
     Ok(expanded_with_json
         .into_iter()
@@ -210,3 +252,49 @@ pub fn prepare_commands(
         })
         .collect())
 }
+
+/// Drop commands whose `paths` filter matches none of the branch's changes.
+///
+/// Changes are diffed against `{{ target }}` (merge and commit hooks), then
+/// `{{ base }}` (post-create), then the default branch.
+fn filter_by_changed_paths(
+    commands: &[Command],
+    ctx: &CommandContext<'_>,
+    extra_vars: &[(&str, &str)],
+    hook_type: HookType,
+) -> anyhow::Result<Vec<Command>> {
+    if commands.iter().all(|cmd| cmd.paths.is_empty()) {
+        return Ok(commands.to_vec());
+    }
+
+    let var = |name: &str| {
+        extra_vars
+            .iter()
+            .find(|(key, _)| *key == name)
+            .map(|(_, value)| value.to_string())
+    };
+    let changed = match var("target")
+        .or_else(|| var("base"))
+        .or_else(|| ctx.repo.default_branch())
+    {
+        Some(base) => ctx
+            .repo
+            .worktree_at(ctx.worktree_path)
+            .changed_files_since(&base)?,
+        None => None,
+    };
+
+    let mut kept = Vec::new();
+    for cmd in commands {
+        if cmd.matches_changed_files(changed.as_deref()) {
+            kept.push(cmd.clone());
+        } else {
+            crate::output::print(info_message(cformat!(
+                "Skipping {hook_type} <bold>{}</>: no changes match {}",
+                cmd.name.as_deref().unwrap_or_default(),
+                cmd.paths.join(", ")
+            )))?;
+        }
+    }
+    Ok(kept)
+}
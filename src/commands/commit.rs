@@ -1,13 +1,14 @@
 use anyhow::Context;
 use color_print::cformat;
 use worktrunk::HookType;
-use worktrunk::config::CommitGenerationConfig;
-use worktrunk::git::Repository;
+use worktrunk::config::{CommitGenerationConfig, IssueMatcher, ProjectConfig, expand_template};
+use worktrunk::git::{GitError, Repository};
 use worktrunk::styling::{
     format_with_gutter, hint_message, info_message, progress_message, success_message,
 };
 
-use super::command_executor::CommandContext;
+use super::command_executor::{CommandContext, build_hook_context};
+use super::commit_review::{Review, edit_message, prompt_review};
 use super::hooks::HookFailureStrategy;
 use super::repository_ext::RepositoryCliExt;
 
@@ -22,6 +23,10 @@ pub struct CommitOptions<'a> {
     pub stage_mode: StageMode,
     pub warn_about_untracked: bool,
     pub show_no_squash_note: bool,
+    /// Offer the changes as several commits (`wt step commit --interactive`)
+    pub interactive: bool,
+    /// Let the LLM group changes for `interactive` instead of by directory
+    pub llm_groups: bool,
 }
 
 impl<'a> CommitOptions<'a> {
@@ -34,17 +39,100 @@ impl<'a> CommitOptions<'a> {
             stage_mode: StageMode::All,
             warn_about_untracked: true,
             show_no_squash_note: false,
+            interactive: false,
+            llm_groups: false,
         }
     }
 }
 
 pub(crate) struct CommitGenerator<'a> {
     config: &'a CommitGenerationConfig,
+    signoff: bool,
+    verify: bool,
+    review: bool,
+    trailers: Vec<String>,
 }
 
 impl<'a> CommitGenerator<'a> {
     pub fn new(config: &'a CommitGenerationConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            signoff: false,
+            verify: true,
+            review: false,
+            trailers: Vec::new(),
+        }
+    }
+
+    /// Add a `Signed-off-by` trailer to commits (from `[commit] signoff`)
+    pub fn with_signoff(mut self, signoff: bool) -> Self {
+        self.signoff = signoff;
+        self
+    }
+
+    /// Run native git hooks on commits (from `[commit] git-hooks`, off with `--no-verify`)
+    pub fn with_git_hooks(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    /// Offer LLM messages for review before committing (from `[commit] review`,
+    /// off with `--yes`)
+    pub fn with_review(mut self, review: bool) -> Self {
+        self.review = review;
+        self
+    }
+
+    /// Append expanded `[commit] trailers` to generated messages
+    pub fn with_trailers(mut self, trailers: Vec<String>) -> Self {
+        self.trailers = trailers;
+        self
+    }
+
+    /// Add the configured trailers to a generated message
+    pub fn finalize_message(&self, repo: &Repository, message: &str) -> anyhow::Result<String> {
+        if self.trailers.is_empty() {
+            return Ok(message.to_string());
+        }
+        repo.current_worktree()
+            .append_trailers(message, &self.trailers)
+    }
+
+    /// Generate a message with `generate`, add trailers, and show it.
+    ///
+    /// With `[commit] review` on a terminal, the user then accepts it, edits
+    /// it, regenerates it, or aborts the commit; `generate` is called again with the rejected
+    /// message and their guidance in `feedback`.
+    pub fn generate_message(
+        &self,
+        repo: &Repository,
+        generate: impl Fn(&CommitGenerationConfig) -> anyhow::Result<String>,
+    ) -> anyhow::Result<String> {
+        let review = self.review && self.config.is_configured();
+        let mut config = self.config.clone();
+        loop {
+            let message = self.finalize_message(repo, &generate(&config)?)?;
+            let formatted_message = self.format_message_for_display(&message);
+            crate::output::print(format_with_gutter(&formatted_message, None))?;
+            if !review {
+                return Ok(message);
+            }
+
+            match prompt_review()? {
+                Review::Accept => return Ok(message),
+                Review::Edit => return edit_message(repo, &message),
+                Review::Regenerate(guidance) => {
+                    config.feedback = Some(crate::llm::regeneration_feedback(&message, &guidance));
+                    crate::output::print(progress_message("Regenerating commit message..."))?;
+                }
+            }
+        }
+    }
+
+    /// Create a commit from the index, honoring signoff, native hooks, and `commit.gpgsign`
+    pub fn commit(&self, repo: &Repository, message: &str) -> anyhow::Result<()> {
+        repo.current_worktree()
+            .commit(message, self.signoff, self.verify)
     }
 
     pub fn format_message_for_display(&self, message: &str) -> String {
@@ -67,7 +155,8 @@ impl<'a> CommitGenerator<'a> {
     }
 
     pub fn emit_hint_if_needed(&self) -> anyhow::Result<()> {
-        if !self.config.is_configured() {
+        // With LLM features disabled, the fallback message is the intended one
+        if !self.config.is_configured() && !self.config.disabled {
             crate::output::print(hint_message(cformat!(
                 "Using fallback commit message. For LLM setup guide, run <bright-black>wt config --help</>"
             )))?;
@@ -85,7 +174,7 @@ impl<'a> CommitGenerator<'a> {
 
         // Fail early if nothing is staged (avoids confusing LLM prompt with empty diff)
         if !wt.has_staged_changes()? {
-            anyhow::bail!("Nothing to commit");
+            return Err(GitError::NothingToCommit.into());
         }
 
         let stats_parts = repo.diff_stats_summary(&["diff", "--staged", "--shortstat"]);
@@ -121,12 +210,9 @@ impl<'a> CommitGenerator<'a> {
         crate::output::print(progress_message(full_progress_msg))?;
 
         self.emit_hint_if_needed()?;
-        let commit_message = crate::llm::generate_commit_message(self.config)?;
-
-        let formatted_message = self.format_message_for_display(&commit_message);
-        crate::output::print(format_with_gutter(&formatted_message, None))?;
+        let commit_message = self.generate_message(&repo, crate::llm::generate_commit_message)?;
 
-        repo.run_command(&["commit", "-m", &commit_message])
+        self.commit(&repo, &commit_message)
             .context("Failed to commit")?;
 
         let commit_hash = repo
@@ -142,9 +228,66 @@ impl<'a> CommitGenerator<'a> {
     }
 }
 
+/// Expand the project's `[commit] trailers` for the current branch.
+///
+/// Templates see the hook variables (plus `extra_vars`) and the branch's issue
+/// from `[integrations.issues]`. Trailers whose value expands to nothing are dropped.
+pub(crate) fn expand_trailers(
+    ctx: &CommandContext<'_>,
+    project_config: Option<&ProjectConfig>,
+    extra_vars: &[(&str, &str)],
+) -> anyhow::Result<Vec<String>> {
+    let Some(project_config) = project_config else {
+        return Ok(Vec::new());
+    };
+    let templates = project_config.commit_trailers();
+    if templates.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut context = build_hook_context(ctx, extra_vars);
+    let issue = project_config
+        .issue_tracker()
+        .and_then(|tracker| IssueMatcher::new(tracker).ok())
+        .and_then(|matcher| matcher.issue_for_branch(ctx.branch_or_head()));
+    if let Some(issue) = issue {
+        context.insert("issue".into(), issue.key);
+        context.insert("issue_id".into(), issue.id);
+        if let Some(url) = issue.url {
+            context.insert("issue_url".into(), url);
+        }
+    }
+    let vars = context
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+
+    templates
+        .iter()
+        .map(|template| {
+            expand_template(template, &vars, false, ctx.repo)
+                .map_err(|e| anyhow::anyhow!("Failed to expand commit trailer '{template}': {e}"))
+        })
+        .filter(|trailer| !matches!(trailer, Ok(t) if is_empty_trailer(t)))
+        .collect()
+}
+
+/// A trailer with no value, e.g. `Refs: {{ issue }}` on a branch without an issue.
+fn is_empty_trailer(trailer: &str) -> bool {
+    trailer
+        .split_once(':')
+        .map_or(trailer, |(_, value)| value)
+        .trim()
+        .is_empty()
+}
+
 /// Commit uncommitted changes with the shared commit pipeline.
 impl CommitOptions<'_> {
     pub fn commit(self) -> anyhow::Result<()> {
+        if self.interactive {
+            super::commit_split::ensure_terminal()?;
+        }
+
         let project_config = self.ctx.repo.load_project_config()?;
         let user_hooks_exist = self.ctx.config.hooks.pre_commit.is_some();
         let project_hooks_exist = project_config
@@ -158,13 +301,13 @@ impl CommitOptions<'_> {
             crate::output::print(info_message("Skipping pre-commit hooks (--no-verify)"))?;
         }
 
-        if !self.no_verify {
-            let extra_vars: Vec<(&str, &str)> = self
-                .target_branch
-                .into_iter()
-                .map(|target| ("target", target))
-                .collect();
+        let extra_vars: Vec<(&str, &str)> = self
+            .target_branch
+            .into_iter()
+            .map(|target| ("target", target))
+            .collect();
 
+        if !self.no_verify {
             // Run pre-commit hooks (user first, then project)
             super::hooks::run_hook_with_filter(
                 self.ctx,
@@ -206,8 +349,24 @@ impl CommitOptions<'_> {
             }
         }
 
-        CommitGenerator::new(&self.ctx.config.commit_generation)
-            .commit_staged_changes(self.show_no_squash_note, self.stage_mode)
+        let generator = CommitGenerator::new(&self.ctx.config.commit_generation)
+            .with_signoff(self.ctx.config.commit_signoff())
+            .with_git_hooks(!self.no_verify && self.ctx.config.commit_git_hooks())
+            .with_review(self.ctx.config.commit_review() && !self.ctx.yes)
+            .with_trailers(expand_trailers(
+                self.ctx,
+                project_config.as_ref(),
+                &extra_vars,
+            )?);
+        if self.interactive {
+            return super::commit_split::commit_in_groups(
+                &generator,
+                &self.ctx.config.commit_generation,
+                self.llm_groups,
+                self.stage_mode,
+            );
+        }
+        generator.commit_staged_changes(self.show_no_squash_note, self.stage_mode)
     }
 }
 
@@ -245,6 +404,15 @@ mod tests {
         assert_eq!(result, "");
     }
 
+    #[test]
+    fn test_is_empty_trailer() {
+        assert!(is_empty_trailer(""));
+        assert!(is_empty_trailer("Refs: "));
+        assert!(is_empty_trailer("  "));
+        assert!(!is_empty_trailer("Refs: PROJ-1"));
+        assert!(!is_empty_trailer("Reviewed-by: Team <team@example.com>"));
+    }
+
     #[test]
     fn test_commit_options_new() {
         // CommitOptions::new requires a CommandContext, which requires a Repository.
@@ -0,0 +1,93 @@
+//! `[commit] review`: look over a generated message before it's committed.
+//!
+//! The message can be accepted, edited in the git editor, or regenerated with
+//! optional guidance that goes into the next prompt. Asked through
+//! [`crate::output::prompt`], so with `--yes` or without a terminal the
+//! generated message is used as is, and `--no` aborts the commit.
+
+use std::fs;
+use std::process::Stdio;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::shell_exec::Cmd;
+
+use crate::output::prompt::{Input, Response};
+
+/// Appended to the message file opened in the editor, as comments.
+const EDIT_HELP: &str = "Edit the commit message. Comment lines are ignored;\n\
+an empty message aborts the commit.\n";
+
+/// What to do with a generated message.
+pub(crate) enum Review {
+    Accept,
+    Edit,
+    /// Generate another, with the user's guidance (possibly empty)
+    Regenerate(String),
+}
+
+/// Ask what to do with the message shown above.
+///
+/// Quitting (or `--no`) fails with [`GitError::CommitAborted`].
+pub(crate) fn prompt_review() -> anyhow::Result<Review> {
+    let question = cformat!("Use this message? <bold>[a]ccept/[e]dit/[r]egenerate/[q]uit</>");
+    loop {
+        let response = match Input::new(question.as_str()).ask(false)? {
+            Response::Text(text) => text,
+            Response::Yes | Response::NotInteractive => return Ok(Review::Accept),
+            Response::No => return Err(GitError::CommitAborted { empty: false }.into()),
+        };
+        match response.to_ascii_lowercase().as_str() {
+            "" | "a" | "accept" | "y" | "yes" => return Ok(Review::Accept),
+            "e" | "edit" => return Ok(Review::Edit),
+            "r" | "regenerate" => {
+                let guidance = match Input::new(cformat!(
+                    "Guidance for the LLM <bright-black>(optional)</>:"
+                ))
+                .ask(false)?
+                {
+                    Response::Text(text) => text,
+                    _ => String::new(),
+                };
+                return Ok(Review::Regenerate(guidance));
+            }
+            "q" | "quit" => return Err(GitError::CommitAborted { empty: false }.into()),
+            _ => continue,
+        }
+    }
+}
+
+/// Open the message in the git editor (`GIT_EDITOR`, `core.editor`, `$VISUAL`,
+/// `$EDITOR`) and return what the user saved.
+///
+/// Comment lines are stripped by `git stripspace`, so `core.commentChar` is
+/// respected as in `git commit`.
+pub(crate) fn edit_message(repo: &Repository, message: &str) -> anyhow::Result<String> {
+    let worktree = repo.current_worktree();
+    let path = worktree.git_dir()?.join("WT_EDITMSG");
+    let help = worktree.stripspace(EDIT_HELP, &["--comment-lines"])?;
+    fs::write(&path, format!("{message}\n\n{help}"))
+        .context("Failed to write commit message file")?;
+
+    let editor = repo.run_command(&["var", "GIT_EDITOR"])?;
+    let result = Cmd::shell(format!(
+        "{} {}",
+        editor.trim(),
+        shell_escape::escape(path.to_string_lossy())
+    ))
+    .stdin(Stdio::inherit())
+    .stream()
+    .context("Editor failed")
+    .and_then(|()| fs::read_to_string(&path).context("Failed to read edited commit message"));
+    let _ = fs::remove_file(&path);
+
+    let edited = worktree
+        .stripspace(&result?, &["--strip-comments"])?
+        .trim()
+        .to_string();
+    if edited.is_empty() {
+        return Err(GitError::CommitAborted { empty: true }.into());
+    }
+    Ok(edited)
+}
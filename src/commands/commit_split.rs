@@ -0,0 +1,259 @@
+//! `wt step commit --interactive`: split the working tree into several commits.
+//!
+//! Staged files are grouped by top-level directory, or by the LLM with
+//! `--llm-groups`. Each group is offered in turn and committed with its own
+//! generated message; groups that aren't committed stay staged.
+
+use std::collections::BTreeMap;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::CommitGenerationConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{format_with_gutter, info_message};
+
+use super::commit::{CommitGenerator, StageMode};
+use crate::output;
+use crate::output::prompt::{Input, Response, is_interactive};
+
+/// Files committed together.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct CommitGroup {
+    /// Shown when offering the group
+    pub label: String,
+    pub paths: Vec<String>,
+}
+
+/// Group paths by their top-level directory; files at the root form one group.
+pub(crate) fn group_by_directory(paths: &[String]) -> Vec<CommitGroup> {
+    let mut groups: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for path in paths {
+        let label = match path.split_once('/') {
+            Some((dir, _)) => format!("{dir}/"),
+            None => "./".to_string(),
+        };
+        groups.entry(label).or_default().push(path.clone());
+    }
+    groups
+        .into_iter()
+        .map(|(label, paths)| CommitGroup { label, paths })
+        .collect()
+}
+
+/// Parse an LLM grouping: one line per group, listing paths separated by spaces.
+///
+/// Unknown and repeated paths are dropped. Files the response leaves out are
+/// collected into a final group, so every staged file is offered once.
+pub(crate) fn parse_llm_groups(response: &str, paths: &[String]) -> Vec<CommitGroup> {
+    let mut remaining: Vec<&String> = paths.iter().collect();
+    let mut groups = Vec::new();
+    for line in response.lines() {
+        let mut group = Vec::new();
+        for token in line.split_whitespace() {
+            let token = token.trim_matches(|c| c == '`' || c == ',');
+            if let Some(pos) = remaining.iter().position(|p| p.as_str() == token) {
+                group.push(remaining.remove(pos).clone());
+            }
+        }
+        if !group.is_empty() {
+            groups.push(CommitGroup {
+                label: format!("group {}", groups.len() + 1),
+                paths: group,
+            });
+        }
+    }
+    if !remaining.is_empty() {
+        groups.push(CommitGroup {
+            label: "remaining files".to_string(),
+            paths: remaining.into_iter().cloned().collect(),
+        });
+    }
+    groups
+}
+
+/// What to do with an offered group.
+enum GroupChoice {
+    Commit,
+    Skip,
+    Quit,
+}
+
+/// `--yes` commits every group; `--no` leaves them all staged.
+fn prompt_group() -> anyhow::Result<GroupChoice> {
+    Ok(
+        match Input::new(cformat!("Commit this group? <bold>[Y/n/q]</>")).ask(false)? {
+            Response::Text(text) => match text.to_ascii_lowercase().as_str() {
+                "" | "y" | "yes" => GroupChoice::Commit,
+                "q" | "quit" => GroupChoice::Quit,
+                _ => GroupChoice::Skip,
+            },
+            Response::Yes => GroupChoice::Commit,
+            Response::No | Response::NotInteractive => GroupChoice::Quit,
+        },
+    )
+}
+
+/// Fail before staging or running hooks when groups can't be offered.
+pub(crate) fn ensure_terminal() -> anyhow::Result<()> {
+    if !is_interactive() {
+        return Err(GitError::NeedsTerminal {
+            command: "wt step commit --interactive",
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Commit the staged changes as a series of groups the user accepts.
+pub(crate) fn commit_in_groups(
+    generator: &CommitGenerator,
+    config: &CommitGenerationConfig,
+    llm_groups: bool,
+    stage_mode: StageMode,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let paths: Vec<String> = repo
+        .run_command(&["diff", "--staged", "--name-only", "--no-renames", "-z"])?
+        .split('\0')
+        .filter(|path| !path.is_empty())
+        .map(String::from)
+        .collect();
+    if paths.is_empty() {
+        return Err(GitError::NothingToCommit.into());
+    }
+
+    let groups = if llm_groups {
+        parse_llm_groups(&crate::llm::generate_commit_groups(config)?, &paths)
+    } else {
+        group_by_directory(&paths)
+    };
+
+    // Snapshot the staged tree, then stage each group back from it. Only the
+    // index changes; the working tree is never touched. Paths are relative to
+    // the worktree root, so restore runs from there.
+    let staged_tree = repo.run_command(&["write-tree"])?.trim().to_string();
+    repo.run_command(&["reset", "--quiet"])
+        .context("Failed to unstage changes")?;
+    let wt = repo.worktree_at(repo.current_worktree().root()?);
+    let source = format!("--source={staged_tree}");
+    let stage = |paths: &[&String]| -> anyhow::Result<()> {
+        let mut args = vec!["restore", "--staged", source.as_str(), "--"];
+        args.extend(paths.iter().map(|path| path.as_str()));
+        wt.run_command(&args).context("Failed to stage files")?;
+        Ok(())
+    };
+
+    let left = match offer_groups(&groups, generator, stage_mode, &stage) {
+        Ok(left) => left,
+        Err(e) => {
+            // Put back everything that was staged. Groups already committed
+            // match the snapshot, so they don't show up as staged again.
+            if let Err(restore_err) = wt.run_command(&["read-tree", &staged_tree]) {
+                log::warn!("Failed to restore staged changes from {staged_tree}: {restore_err:#}");
+            }
+            return Err(e);
+        }
+    };
+
+    if !left.is_empty() {
+        stage(&left)?;
+        let plural = if left.len() == 1 { "" } else { "s" };
+        output::print(info_message(format!(
+            "Left {} file{plural} staged and uncommitted",
+            left.len()
+        )))?;
+    }
+    Ok(())
+}
+
+/// Offer each group in turn, returning the paths that weren't committed.
+fn offer_groups<'a>(
+    groups: &'a [CommitGroup],
+    generator: &CommitGenerator,
+    stage_mode: StageMode,
+    stage: &impl Fn(&[&String]) -> anyhow::Result<()>,
+) -> anyhow::Result<Vec<&'a String>> {
+    let total = groups.len();
+    let mut left: Vec<&String> = Vec::new();
+    for (i, group) in groups.iter().enumerate() {
+        output::print(info_message(cformat!(
+            "Group {}/{total}: <bold>{}</>",
+            i + 1,
+            group.label
+        )))?;
+        output::print(format_with_gutter(&group.paths.join("\n"), None))?;
+
+        match prompt_group()? {
+            GroupChoice::Commit => {
+                stage(&group.paths.iter().collect::<Vec<_>>())?;
+                generator.commit_staged_changes(false, stage_mode)?;
+            }
+            GroupChoice::Skip => left.extend(&group.paths),
+            GroupChoice::Quit => {
+                left.extend(groups[i..].iter().flat_map(|g| &g.paths));
+                break;
+            }
+        }
+    }
+    Ok(left)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn paths(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_group_by_directory() {
+        let groups = group_by_directory(&paths(&[
+            "src/main.rs",
+            "README.md",
+            "docs/guide.md",
+            "src/lib.rs",
+            "Cargo.toml",
+        ]));
+        assert_eq!(
+            groups,
+            vec![
+                CommitGroup {
+                    label: "./".into(),
+                    paths: paths(&["README.md", "Cargo.toml"]),
+                },
+                CommitGroup {
+                    label: "docs/".into(),
+                    paths: paths(&["docs/guide.md"]),
+                },
+                CommitGroup {
+                    label: "src/".into(),
+                    paths: paths(&["src/main.rs", "src/lib.rs"]),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_llm_groups() {
+        let staged = paths(&["src/a.rs", "src/b.rs", "docs/a.md", "Cargo.lock"]);
+        let response = "`src/a.rs`, docs/a.md\n\nsrc/b.rs src/a.rs unknown.rs\n";
+        assert_eq!(
+            parse_llm_groups(response, &staged),
+            vec![
+                CommitGroup {
+                    label: "group 1".into(),
+                    paths: paths(&["src/a.rs", "docs/a.md"]),
+                },
+                CommitGroup {
+                    label: "group 2".into(),
+                    paths: paths(&["src/b.rs"]),
+                },
+                CommitGroup {
+                    label: "remaining files".into(),
+                    paths: paths(&["Cargo.lock"]),
+                },
+            ]
+        );
+    }
+}
@@ -41,6 +41,11 @@ pub(super) fn comment_out_config(content: &str) -> String {
     }
 }
 
+/// The example user config with every setting commented out, as `wt config create` writes it
+pub(crate) fn user_config_template() -> String {
+    comment_out_config(USER_CONFIG_EXAMPLE)
+}
+
 /// Handle the config create command
 pub fn handle_config_create(project: bool) -> anyhow::Result<()> {
     if project {
@@ -0,0 +1,217 @@
+//! Config diff command.
+//!
+//! Lists the settings whose effective value differs from the built-in default,
+//! with where each one came from.
+
+use std::collections::HashMap;
+
+use anyhow::Context;
+use worktrunk::config::{CONFIG_VERSION_KEY, ProjectConfig, WorktrunkConfig, config_path_override};
+use worktrunk::git::Repository;
+use worktrunk::styling::info_message;
+
+use super::redact::redact_value;
+use crate::output;
+
+/// A setting whose effective value isn't the default
+#[derive(Debug, PartialEq)]
+struct Changed {
+    /// Dotted key, e.g. `commit-generation.command`
+    key: String,
+    value: toml::Value,
+    /// The default, when it isn't empty
+    default: Option<toml::Value>,
+}
+
+/// Handle the config diff command
+pub fn handle_config_diff() -> anyhow::Result<()> {
+    let mut lines = Vec::new();
+
+    let user = WorktrunkConfig::load().context("Failed to load user config")?;
+    let user_source = match config_path_override() {
+        Some(flag) => format!("user config, via {flag}"),
+        None => "user config".to_string(),
+    };
+    let defaults = WorktrunkConfig::default();
+    let mut default_table = toml::Table::try_from(&defaults)?;
+    // Unset in the struct so an explicit setting can be told apart
+    default_table.insert("worktree-path".into(), defaults.worktree_path().into());
+    for setting in changed_settings(&toml::Table::try_from(&user)?, &default_table) {
+        // Approvals and the schema version aren't behavior
+        if setting.key == CONFIG_VERSION_KEY || setting.key.starts_with("projects.") {
+            continue;
+        }
+        let env_var = env_var_for(&setting.key);
+        let source = if std::env::var_os(&env_var).is_some() {
+            format!("env {env_var}")
+        } else {
+            user_source.clone()
+        };
+        lines.push(format_setting(&setting, &source));
+    }
+
+    if let Ok(repo) = Repository::current()
+        && let Some(project) = repo.load_project_config()?
+    {
+        let changed = changed_settings(
+            &toml::Table::try_from(&project)?,
+            &toml::Table::try_from(ProjectConfig::default())?,
+        );
+        for setting in changed {
+            if setting.key != CONFIG_VERSION_KEY {
+                lines.push(format_setting(&setting, "project config"));
+            }
+        }
+    }
+
+    if lines.is_empty() {
+        output::print(info_message("All settings are at their defaults"))?;
+        return Ok(());
+    }
+    output::stdout(lines.join("\n"))?;
+    Ok(())
+}
+
+/// `key = value  # source`, plus the default when there is one
+fn format_setting(setting: &Changed, source: &str) -> String {
+    let value = redact_value(&setting.key, &setting.value);
+    match &setting.default {
+        Some(default) => format!("{} = {value}  # {source}; default {default}", setting.key),
+        None => format!("{} = {value}  # {source}", setting.key),
+    }
+}
+
+/// The environment variable overriding `key`, as the config loader maps them:
+/// `commit-generation.command` -> `WORKTRUNK_COMMIT_GENERATION__COMMAND`
+fn env_var_for(key: &str) -> String {
+    format!(
+        "WORKTRUNK_{}",
+        key.to_uppercase().replace('-', "_").replace('.', "__")
+    )
+}
+
+/// Settings in `effective` that differ from `defaults`, in `effective` order.
+///
+/// A setting without a default counts as changed unless it's empty: loading
+/// fills in empty strings and lists where the default is unset.
+fn changed_settings(effective: &toml::Table, defaults: &toml::Table) -> Vec<Changed> {
+    let mut default_values = Vec::new();
+    flatten("", defaults, &mut default_values);
+    let default_values: HashMap<_, _> = default_values.into_iter().collect();
+
+    let mut values = Vec::new();
+    flatten("", effective, &mut values);
+    values
+        .into_iter()
+        .filter_map(|(key, value)| {
+            let default = default_values.get(&key);
+            let unchanged = match default {
+                Some(default) => *default == value,
+                None => is_empty(&value),
+            };
+            (!unchanged).then(|| Changed {
+                default: default.filter(|d| !is_empty(d)).cloned(),
+                key,
+                value,
+            })
+        })
+        .collect()
+}
+
+/// Collect the leaves of `table` as dotted keys
+fn flatten(prefix: &str, table: &toml::Table, out: &mut Vec<(String, toml::Value)>) {
+    for (key, value) in table {
+        let key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{prefix}.{key}")
+        };
+        match value {
+            toml::Value::Table(table) => flatten(&key, table, out),
+            value => out.push((key, value.clone())),
+        }
+    }
+}
+
+fn is_empty(value: &toml::Value) -> bool {
+    match value {
+        toml::Value::String(s) => s.is_empty(),
+        toml::Value::Array(items) => items.is_empty(),
+        toml::Value::Table(table) => table.is_empty(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(content: &str) -> toml::Table {
+        content.parse().unwrap()
+    }
+
+    #[test]
+    fn test_changed_settings() {
+        let defaults = table(
+            r#"
+worktree-path = "../{{ repo }}"
+[commit-generation]
+args = []
+"#,
+        );
+        let effective = table(
+            r#"
+worktree-path = "../{{ repo }}"
+[commit-generation]
+command = "llm"
+args = []
+[list]
+full = true
+"#,
+        );
+        let changed = changed_settings(&effective, &defaults);
+        let keys: Vec<_> = changed.iter().map(|s| s.key.as_str()).collect();
+        assert_eq!(keys, ["commit-generation.command", "list.full"]);
+    }
+
+    #[test]
+    fn test_changed_settings_ignores_empty_values_without_default() {
+        let effective = table(
+            r#"
+[commit-generation]
+command = ""
+"#,
+        );
+        assert!(changed_settings(&effective, &toml::Table::new()).is_empty());
+    }
+
+    #[test]
+    fn test_changed_settings_keeps_default() {
+        let defaults = table(r#"worktree-path = "../{{ repo }}""#);
+        let effective = table(r#"worktree-path = ".worktrees/{{ branch }}""#);
+        let changed = changed_settings(&effective, &defaults);
+        assert_eq!(
+            format_setting(&changed[0], "user config"),
+            r#"worktree-path = ".worktrees/{{ branch }}"  # user config; default "../{{ repo }}""#
+        );
+    }
+
+    #[test]
+    fn test_format_setting_redacts_secrets() {
+        let changed =
+            changed_settings(&table("[gitea]\ntoken = \"abc123\"\n"), &toml::Table::new());
+        assert_eq!(
+            format_setting(&changed[0], "user config"),
+            r#"gitea.token = "<redacted>"  # user config"#
+        );
+    }
+
+    #[test]
+    fn test_env_var_for() {
+        assert_eq!(
+            env_var_for("commit-generation.command"),
+            "WORKTRUNK_COMMIT_GENERATION__COMMAND"
+        );
+        assert_eq!(env_var_for("worktree-path"), "WORKTRUNK_WORKTREE_PATH");
+    }
+}
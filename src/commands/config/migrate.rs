@@ -0,0 +1,82 @@
+//! Config file migration.
+//!
+//! Brings a user or project config file up to the current schema version,
+//! keeping a backup of the original.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{CONFIG_VERSION, migrate_config, write_migrated};
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    format_toml, format_with_gutter, hint_message, info_message, success_message,
+};
+
+use super::state::require_user_config_path;
+use crate::output;
+use crate::output::prompt::{self, Confirm};
+
+/// Handle the config migrate command
+pub fn handle_config_migrate(project: bool, yes: bool, dry_run: bool) -> anyhow::Result<()> {
+    let (path, config_type) = if project {
+        let repo = Repository::current()?;
+        let path = repo.current_worktree().root()?.join(".config/wt.toml");
+        (path, "Project config")
+    } else {
+        (require_user_config_path()?, "User config")
+    };
+    let display_path = format_path_for_display(&path);
+
+    if !path.exists() {
+        output::print(info_message(cformat!(
+            "{config_type} not found: <bold>{display_path}</>"
+        )))?;
+        return Ok(());
+    }
+
+    let content = std::fs::read_to_string(&path).context("Failed to read config file")?;
+    let Some(migrated) = migrate_config(&content)? else {
+        output::print(info_message(cformat!(
+            "{config_type} is up to date: <bold>{display_path}</>"
+        )))?;
+        return Ok(());
+    };
+
+    let changes = format_with_gutter(&migrated.applied.join("\n"), None);
+    let versions = cformat!(
+        "version <bold>{}</> → <bold>{CONFIG_VERSION}</>",
+        migrated.from
+    );
+
+    if dry_run {
+        output::print(info_message(cformat!(
+            "Would migrate <bold>{display_path}</> ({versions}):\n{changes}"
+        )))?;
+        output::print(format_toml(&migrated.content).trim_end())?;
+        return Ok(());
+    }
+
+    output::print(info_message(cformat!(
+        "Migrating <bold>{display_path}</> ({versions}):\n{changes}"
+    )))?;
+
+    if !Confirm::new("Rewrite config?", false).ask(yes)? {
+        if prompt::assumed_answer().is_none() && !prompt::is_interactive() {
+            output::print(hint_message(cformat!(
+                "To migrate without prompting, add <bright-black>--yes</>"
+            )))?;
+        } else {
+            output::print(info_message("Migration cancelled"))?;
+        }
+        return Ok(());
+    }
+
+    let backup = write_migrated(&path, &content, &migrated)?;
+    output::print(success_message(cformat!(
+        "Migrated {}; original saved to <bold>{}</>",
+        config_type.to_lowercase(),
+        format_path_for_display(&backup)
+    )))?;
+
+    Ok(())
+}
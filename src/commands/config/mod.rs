@@ -3,13 +3,19 @@
 //! Commands for managing user config, project config, state, and hints.
 
 mod create;
+mod diff;
 mod hints;
+mod migrate;
+mod redact;
 mod show;
 mod state;
 
 // Re-export public functions
 pub use create::handle_config_create;
+pub(crate) use create::user_config_template;
+pub use diff::handle_config_diff;
 pub use hints::{handle_hints_clear, handle_hints_get};
+pub use migrate::handle_config_migrate;
 pub use show::handle_config_show;
 pub use state::{
     handle_state_clear, handle_state_clear_all, handle_state_get, handle_state_set,
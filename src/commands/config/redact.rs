@@ -0,0 +1,116 @@
+//! Hiding secrets when printing config.
+//!
+//! `wt config show` and `wt config diff` print config values, and people paste
+//! their output into issues. Values of keys that name a secret — `gitea.token`,
+//! or any key ending in `token`, `password` or `secret` — are replaced.
+
+const REDACTED: &str = "<redacted>";
+
+/// Whether the (dotted) key holds a secret.
+pub(super) fn is_secret_key(key: &str) -> bool {
+    let last = key.rsplit('.').next().unwrap_or(key).to_ascii_lowercase();
+    ["token", "password", "secret"]
+        .iter()
+        .any(|suffix| last.ends_with(suffix))
+}
+
+/// The value to print for `key`.
+pub(super) fn redact_value(key: &str, value: &toml::Value) -> toml::Value {
+    if is_secret_key(key) {
+        REDACTED.into()
+    } else {
+        value.clone()
+    }
+}
+
+/// Config file contents with secret values replaced, keeping comments and layout.
+///
+/// Contents that don't parse are redacted line by line, so a broken config
+/// doesn't leak its secrets either.
+pub(super) fn redact_config(contents: &str) -> String {
+    match contents.parse::<toml_edit::DocumentMut>() {
+        Ok(mut doc) => {
+            redact_table(doc.as_table_mut());
+            doc.to_string()
+        }
+        Err(_) => contents.split_inclusive('\n').map(redact_line).collect(),
+    }
+}
+
+fn redact_table(table: &mut dyn toml_edit::TableLike) {
+    for (key, item) in table.iter_mut() {
+        if is_secret_key(key.get())
+            && let Some(value) = item.as_value_mut()
+        {
+            let decor = value.decor().clone();
+            *value = REDACTED.into();
+            *value.decor_mut() = decor;
+        } else if let Some(tables) = item.as_array_of_tables_mut() {
+            for table in tables.iter_mut() {
+                redact_table(table);
+            }
+        } else if let Some(table) = item.as_table_like_mut() {
+            redact_table(table);
+        }
+    }
+}
+
+/// `key = value` with a secret key becomes `key = "<redacted>"`.
+fn redact_line(line: &str) -> String {
+    let Some((key, _)) = line.split_once('=') else {
+        return line.to_string();
+    };
+    let name = key.trim().trim_matches(['"', '\'']);
+    if line.trim_start().starts_with('#') || !is_secret_key(name) {
+        return line.to_string();
+    }
+    let newline = if line.ends_with('\n') { "\n" } else { "" };
+    format!("{key}= \"{REDACTED}\"{newline}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_secret_key() {
+        assert!(is_secret_key("gitea.token"));
+        assert!(is_secret_key("forge.api-token"));
+        assert!(is_secret_key("smtp.PASSWORD"));
+        assert!(is_secret_key("client_secret"));
+        assert!(!is_secret_key("commit-generation.command"));
+        assert!(!is_secret_key("token.host"));
+    }
+
+    #[test]
+    fn test_redact_config_keeps_layout() {
+        let contents = r#"# Forge access
+[gitea]
+host = "git.example.com"
+token = "abc123"  # personal token
+
+[other]
+inline = { password = "hunter2", user = "me" }
+"#;
+        assert_eq!(
+            redact_config(contents),
+            r#"# Forge access
+[gitea]
+host = "git.example.com"
+token = "<redacted>"  # personal token
+
+[other]
+inline = { password = "<redacted>", user = "me" }
+"#
+        );
+    }
+
+    #[test]
+    fn test_redact_config_invalid_toml() {
+        let contents = "[gitea\ntoken = \"abc123\"\nhost = \"x\"\n";
+        assert_eq!(
+            redact_config(contents),
+            "[gitea\ntoken = \"<redacted>\"\nhost = \"x\"\n"
+        );
+    }
+}
@@ -10,6 +10,7 @@ use anyhow::Context;
 use color_print::cformat;
 use worktrunk::config::{
     ProjectConfig, WorktrunkConfig, find_unknown_project_keys, find_unknown_user_keys,
+    migrate_config,
 };
 use worktrunk::git::Repository;
 use worktrunk::path::format_path_for_display;
@@ -19,6 +20,7 @@ use worktrunk::styling::{
     hint_message, info_message, success_message, warning_message,
 };
 
+use super::redact::redact_config;
 use super::state::require_user_config_path;
 use crate::cli::version_str;
 use crate::commands::configure_shell::{ConfigAction, scan_shell_configs};
@@ -247,19 +249,71 @@ fn render_diagnostics(out: &mut String) -> anyhow::Result<()> {
                 ci_tools.glab_authenticated,
             )?;
         }
+        Some(CiPlatform::Gitea) => {
+            let has_token = WorktrunkConfig::load()
+                .ok()
+                .and_then(|config| config.gitea)
+                .is_some_and(|gitea| gitea.token.is_some());
+            if has_token {
+                writeln!(out, "{}", success_message("Gitea API token configured"))?;
+            } else {
+                writeln!(
+                    out,
+                    "{}",
+                    hint_message(cformat!(
+                        "No <bright-black>[gitea] token</> (CI status for public repositories only)"
+                    ))
+                )?;
+            }
+        }
+        Some(CiPlatform::Bitbucket) => {
+            let has_credentials = |vars: &[&str]| {
+                vars.iter()
+                    .all(|var| std::env::var(var).is_ok_and(|value| !value.is_empty()))
+            };
+            if has_credentials(&["BITBUCKET_TOKEN"]) {
+                writeln!(out, "{}", success_message("Bitbucket token configured"))?;
+            } else if has_credentials(&["BITBUCKET_USERNAME", "BITBUCKET_APP_PASSWORD"]) {
+                writeln!(
+                    out,
+                    "{}",
+                    success_message("Bitbucket app password configured")
+                )?;
+            } else {
+                writeln!(
+                    out,
+                    "{}",
+                    hint_message(cformat!(
+                        "No <bright-black>BITBUCKET_TOKEN</> (CI status for public repositories only)"
+                    ))
+                )?;
+            }
+        }
         None => {
             writeln!(
                 out,
                 "{}",
-                hint_message("CI status requires GitHub or GitLab remote")
+                hint_message("CI status requires GitHub, GitLab, Gitea, or Bitbucket remote")
             )?;
         }
     }
 
+    render_clone_status(out, &repo)?;
+
     // Test commit generation
     let config = WorktrunkConfig::load()?;
     let commit_config = &config.commit_generation;
 
+    if commit_config.disabled {
+        writeln!(
+            out,
+            "{}",
+            hint_message(cformat!(
+                "Commit generation disabled (<bright-black>[llm] enabled = false</>)"
+            ))
+        )?;
+        return Ok(());
+    }
     if !commit_config.is_configured() {
         writeln!(out, "{}", hint_message("Commit generation not configured"))?;
         return Ok(());
@@ -338,10 +392,11 @@ fn render_user_config(out: &mut String) -> anyhow::Result<()> {
     } else {
         // Only check for unknown keys if config is valid
         warn_unknown_keys(out, &find_unknown_user_keys(&contents))?;
+        hint_pending_migration(out, &contents, false)?;
     }
 
     // Display TOML with syntax highlighting (gutter at column 0)
-    write!(out, "{}", format_toml(&contents))?;
+    write!(out, "{}", format_toml(&redact_config(&contents)))?;
 
     Ok(())
 }
@@ -358,6 +413,21 @@ pub(super) fn warn_unknown_keys(out: &mut String, unknown_keys: &[String]) -> an
     Ok(())
 }
 
+/// Suggest `wt config migrate` when a config file predates the current format
+fn hint_pending_migration(out: &mut String, contents: &str, project: bool) -> anyhow::Result<()> {
+    if let Ok(Some(_)) = migrate_config(contents) {
+        let flag = if project { " --project" } else { "" };
+        writeln!(
+            out,
+            "{}",
+            hint_message(cformat!(
+                "Written for an older version; to update, run <bright-black>wt config migrate{flag}</>"
+            ))
+        )?;
+    }
+    Ok(())
+}
+
 fn render_project_config(out: &mut String) -> anyhow::Result<()> {
     // Try to get current repository root
     let repo_root = match Repository::current().and_then(|repo| repo.current_worktree().root()) {
@@ -407,10 +477,11 @@ fn render_project_config(out: &mut String) -> anyhow::Result<()> {
     } else {
         // Only check for unknown keys if config is valid
         warn_unknown_keys(out, &find_unknown_project_keys(&contents))?;
+        hint_pending_migration(out, &contents, true)?;
     }
 
     // Display TOML with syntax highlighting (gutter at column 0)
-    write!(out, "{}", format_toml(&contents))?;
+    write!(out, "{}", format_toml(&redact_config(&contents)))?;
 
     Ok(())
 }
@@ -616,7 +687,22 @@ fn render_shell_status(out: &mut String) -> anyhow::Result<()> {
                     )?;
                 }
             }
-            _ => {} // Added/Created won't appear in dry_run mode
+            ConfigAction::WouldUpdate => {
+                // Configured, but by an older install; still works until updated
+                writeln!(
+                    out,
+                    "{}",
+                    info_message(cformat!("Outdated {what} for <bold>{shell}</> @ {path}"))
+                )?;
+                writeln!(
+                    out,
+                    "{}",
+                    hint_message(cformat!(
+                        "To update, run <bright-black>{cmd} config shell install {shell}</>"
+                    ))
+                )?;
+            }
+            _ => {} // Added/Created/Updated won't appear in dry_run mode
         }
     }
 
@@ -790,6 +876,44 @@ fn render_shell_status(out: &mut String) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Report shallow/partial clones, which limit what `wt list` can compute.
+fn render_clone_status(out: &mut String, repo: &Repository) -> anyhow::Result<()> {
+    let clone_kind = repo.clone_kind();
+    if clone_kind.shallow {
+        writeln!(
+            out,
+            "{}",
+            warning_message(
+                "Shallow clone; wt list counts ahead/behind only to the shallow boundary and skips branch diffs and integration checks"
+            )
+        )?;
+        writeln!(
+            out,
+            "{}",
+            hint_message(cformat!(
+                "To fetch full history, run <bright-black>git fetch --unshallow</>"
+            ))
+        )?;
+    }
+    if let Some((remote, filter)) = &clone_kind.partial {
+        writeln!(
+            out,
+            "{}",
+            warning_message(cformat!(
+                "Partial clone (<bold>{filter}</> from <bold>{remote}</>); wt list skips branch diffs and conflict checks, which would fetch missing objects"
+            ))
+        )?;
+        writeln!(
+            out,
+            "{}",
+            hint_message(cformat!(
+                "To fetch all objects, run <bright-black>git config --unset remote.{remote}.partialclonefilter && git fetch --refetch {remote}</>"
+            ))
+        )?;
+    }
+    Ok(())
+}
+
 pub(super) fn render_ci_tool_status(
     out: &mut String,
     tool: &str,
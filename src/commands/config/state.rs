@@ -303,10 +303,7 @@ pub fn handle_state_clear(key: &str, branch: Option<String>, all: bool) -> anyho
             }
         }
         "previous-branch" => {
-            if repo
-                .run_command(&["config", "--unset", "worktrunk.history"])
-                .is_ok()
-            {
+            if repo.clear_switch_history() {
                 output::print(success_message("Cleared previous branch"))?;
             } else {
                 output::print(info_message("No previous branch to clear"))?;
@@ -419,10 +416,7 @@ pub fn handle_state_clear_all() -> anyhow::Result<()> {
     }
 
     // Clear previous branch
-    if repo
-        .run_command(&["config", "--unset", "worktrunk.history"])
-        .is_ok()
-    {
+    if repo.clear_switch_history() {
         cleared_any = true;
     }
 
@@ -437,12 +431,36 @@ pub fn handle_state_clear_all() -> anyhow::Result<()> {
         }
     }
 
+    // Clear interrupted merge records
+    let merges_output = repo
+        .run_command(&["config", "--get-regexp", r"^worktrunk\.state\..+\.merge$"])
+        .unwrap_or_default();
+    for line in merges_output.lines() {
+        if let Some(config_key) = line.split_whitespace().next() {
+            let _ = repo.run_command(&["config", "--unset", config_key]);
+            cleared_any = true;
+        }
+    }
+
+    // Clear the column layout saved by `wt list --tune`
+    if repo
+        .run_command(&["config", "--unset", "worktrunk.list-columns"])
+        .is_ok()
+    {
+        cleared_any = true;
+    }
+
     // Clear all CI status cache
     let ci_cleared = CachedCiStatus::clear_all(&repo);
     if ci_cleared > 0 {
         cleared_any = true;
     }
 
+    // Clear cached LLM commit messages
+    if crate::llm::clear_cache(&repo) > 0 {
+        cleared_any = true;
+    }
+
     // Clear all logs
     let logs_cleared = clear_logs(&repo)?;
     if logs_cleared > 0 {
@@ -1,5 +1,4 @@
-use std::fs::{self, OpenOptions};
-use std::io::{self, BufRead, BufReader, Write};
+use std::fs;
 use std::path::{Path, PathBuf};
 use worktrunk::path::format_path_for_display;
 use worktrunk::shell::{self, Shell};
@@ -9,6 +8,7 @@ use worktrunk::styling::{
 };
 
 use crate::output;
+use crate::output::prompt::{Confirm, Input, Response};
 
 pub struct ConfigureResult {
     pub shell: Shell,
@@ -83,8 +83,11 @@ pub enum ConfigAction {
     Added,
     AlreadyExists,
     Created,
+    /// Replaced an older integration line or managed block in place
+    Updated,
     WouldAdd,
     WouldCreate,
+    WouldUpdate,
 }
 
 impl ConfigAction {
@@ -93,21 +96,59 @@ impl ConfigAction {
             ConfigAction::Added => "Added",
             ConfigAction::AlreadyExists => "Already configured",
             ConfigAction::Created => "Created",
+            ConfigAction::Updated => "Updated",
             ConfigAction::WouldAdd => "Will add",
             ConfigAction::WouldCreate => "Will create",
+            ConfigAction::WouldUpdate => "Will update",
         }
     }
 
     /// Returns the appropriate symbol for this action
     pub fn symbol(&self) -> &'static str {
         match self {
-            ConfigAction::Added | ConfigAction::Created => SUCCESS_SYMBOL,
+            ConfigAction::Added | ConfigAction::Created | ConfigAction::Updated => SUCCESS_SYMBOL,
             ConfigAction::AlreadyExists => INFO_SYMBOL,
-            ConfigAction::WouldAdd | ConfigAction::WouldCreate => INFO_SYMBOL,
+            ConfigAction::WouldAdd | ConfigAction::WouldCreate | ConfigAction::WouldUpdate => {
+                INFO_SYMBOL
+            }
         }
     }
 }
 
+/// Wrap the config line in marker comments so later installs and uninstalls can
+/// find exactly what we wrote.
+fn managed_block(config_line: &str, cmd: &str) -> String {
+    format!("# >>> {cmd} shell integration >>>\n{config_line}\n# <<< {cmd} shell integration <<<")
+}
+
+/// Find the first managed block, returning the line indices of its start and end
+/// markers. A start marker without a matching end marker isn't treated as a block.
+fn find_managed_block(lines: &[&str], cmd: &str) -> Option<(usize, usize)> {
+    let start_marker = format!("# >>> {cmd} shell integration >>>");
+    let end_marker = format!("# <<< {cmd} shell integration <<<");
+    let start = lines.iter().position(|l| l.trim() == start_marker)?;
+    let end = start + lines[start..].iter().position(|l| l.trim() == end_marker)?;
+    Some((start, end))
+}
+
+/// Replace a range of lines with `replacement`, keeping the rest of the file intact.
+fn replace_lines(
+    content: &str,
+    lines: &[&str],
+    range: std::ops::RangeInclusive<usize>,
+    replacement: &str,
+) -> String {
+    let mut new_lines: Vec<&str> = lines[..*range.start()].to_vec();
+    new_lines.push(replacement);
+    new_lines.extend_from_slice(&lines[range.end() + 1..]);
+    let new_content = new_lines.join("\n");
+    if content.ends_with('\n') {
+        format!("{new_content}\n")
+    } else {
+        new_content
+    }
+}
+
 /// Check if file content appears to be worktrunk-managed (contains our markers)
 ///
 /// Used to identify files safe to delete during migration/uninstall.
@@ -400,67 +441,63 @@ fn configure_shell_file(
         );
     }
 
-    // For other shells, check if file exists
+    // For other shells, the line lives in a managed block in the rc file
+    let block = managed_block(&config_line, cmd);
     if path.exists() {
-        // Read the file and check if our integration already exists
-        let file = fs::File::open(path)
+        let content = fs::read_to_string(path)
             .map_err(|e| format!("Failed to read {}: {}", format_path_for_display(path), e))?;
+        let lines: Vec<&str> = content.lines().collect();
+
+        // An existing block is upgraded in place; a bare line matching exactly what
+        // we write (from before managed blocks) is left alone since it's current
+        let (action, new_content) = if let Some((start, end)) = find_managed_block(&lines, cmd) {
+            if lines[start + 1..end]
+                .iter()
+                .map(|l| l.trim())
+                .eq([config_line.as_str()])
+            {
+                (ConfigAction::AlreadyExists, None)
+            } else {
+                (
+                    ConfigAction::Updated,
+                    Some(replace_lines(&content, &lines, start..=end, &block)),
+                )
+            }
+        } else if lines.iter().any(|line| line.trim() == config_line) {
+            (ConfigAction::AlreadyExists, None)
+        } else if let Some(i) = lines
+            .iter()
+            .position(|line| shell::is_shell_integration_line(line, cmd))
+        {
+            // Older integration line (e.g. unconditional `eval`): replace it where it is
+            (
+                ConfigAction::Updated,
+                Some(replace_lines(&content, &lines, i..=i, &block)),
+            )
+        } else {
+            // Add blank line before the block, then the block with its own newline
+            (ConfigAction::Added, Some(format!("{content}\n{block}\n")))
+        };
 
-        let reader = BufReader::new(file);
-
-        // Check for the exact conditional wrapper we would write
-        for line in reader.lines() {
-            let line = line.map_err(|e| {
+        let action = match (action, dry_run) {
+            (ConfigAction::Added, true) => ConfigAction::WouldAdd,
+            (ConfigAction::Updated, true) => ConfigAction::WouldUpdate,
+            (action, _) => action,
+        };
+        if !dry_run && let Some(new_content) = new_content {
+            fs::write(path, new_content).map_err(|e| {
                 format!(
-                    "Failed to read line from {}: {}",
+                    "Failed to write to {}: {}",
                     format_path_for_display(path),
                     e
                 )
             })?;
-
-            // Canonical detection: check if the line matches exactly what we write
-            if line.trim() == config_line {
-                return Ok(Some(ConfigureResult {
-                    shell,
-                    path: path.to_path_buf(),
-                    action: ConfigAction::AlreadyExists,
-                    config_line: config_line.clone(),
-                }));
-            }
         }
 
-        // Line doesn't exist, add it
-        if dry_run {
-            return Ok(Some(ConfigureResult {
-                shell,
-                path: path.to_path_buf(),
-                action: ConfigAction::WouldAdd,
-                config_line: config_line.clone(),
-            }));
-        }
-
-        // Append the line with proper spacing
-        let mut file = OpenOptions::new().append(true).open(path).map_err(|e| {
-            format!(
-                "Failed to open {} for writing: {}",
-                format_path_for_display(path),
-                e
-            )
-        })?;
-
-        // Add blank line before config, then the config line with its own newline
-        write!(file, "\n{}\n", config_line).map_err(|e| {
-            format!(
-                "Failed to write to {}: {}",
-                format_path_for_display(path),
-                e
-            )
-        })?;
-
         Ok(Some(ConfigureResult {
             shell,
             path: path.to_path_buf(),
-            action: ConfigAction::Added,
+            action,
             config_line: config_line.clone(),
         }))
     } else {
@@ -484,7 +521,7 @@ fn configure_shell_file(
             }
 
             // Write the config content
-            fs::write(path, format!("{}\n", config_line)).map_err(|e| {
+            fs::write(path, format!("{}\n", block)).map_err(|e| {
                 format!(
                     "Failed to write to {}: {}",
                     format_path_for_display(path),
@@ -710,6 +747,7 @@ pub fn show_uninstall_preview(
 
 /// Prompt for install with [y/N/?] options
 ///
+/// `--no` and `WORKTRUNK_ASSUME_YES` answer without prompting.
 /// - `y` or `yes`: Accept and return true
 /// - `n`, `no`, or empty: Decline and return false
 /// - `?`: Show preview (via show_install_preview) and re-prompt
@@ -719,64 +757,25 @@ pub fn prompt_for_install(
     cmd: &str,
     prompt_text: &str,
 ) -> Result<bool, String> {
-    use std::io::Write;
-
-    // Flush before interactive prompt
-    crate::output::flush().map_err(|e| e.to_string())?;
-
+    let question = color_print::cformat!("{prompt_text} <bold>[y/N/?]</>");
     loop {
-        eprint!(
-            "{}",
-            color_print::cformat!("{} {} <bold>[y/N/?]</> ", PROMPT_SYMBOL, prompt_text)
-        );
-        io::stderr().flush().map_err(|e| e.to_string())?;
-
-        let mut input = String::new();
-        io::stdin()
-            .read_line(&mut input)
-            .map_err(|e| e.to_string())?;
-
-        let response = input.trim().to_lowercase();
-        match response.as_str() {
-            "y" | "yes" => {
-                eprintln!();
-                return Ok(true);
-            }
-            "?" => {
-                eprintln!();
-                show_install_preview(results, completion_results, cmd);
-                // Loop back to prompt again
-            }
-            _ => {
+        match Input::new(question.as_str())
+            .ask(false)
+            .map_err(|e| e.to_string())?
+        {
+            Response::Text(text) => match text.to_lowercase().as_str() {
+                "y" | "yes" => return Ok(true),
+                // Show the preview, then ask again
+                "?" => show_install_preview(results, completion_results, cmd),
                 // Empty, "n", "no", or anything else is decline
-                eprintln!();
-                return Ok(false);
-            }
+                _ => return Ok(false),
+            },
+            Response::Yes => return Ok(true),
+            Response::No | Response::NotInteractive => return Ok(false),
         }
     }
 }
 
-/// Prompt user for yes/no confirmation (simple [y/N] prompt)
-fn prompt_yes_no() -> Result<bool, String> {
-    use anstyle::Style;
-    use std::io::Write;
-    use worktrunk::styling::eprint;
-
-    let bold = Style::new().bold();
-    eprint!("{PROMPT_SYMBOL} Proceed? {bold}[y/N]{bold:#} ");
-    io::stderr().flush().map_err(|e| e.to_string())?;
-
-    let mut input = String::new();
-    io::stdin()
-        .read_line(&mut input)
-        .map_err(|e| e.to_string())?;
-
-    crate::output::blank().map_err(|e| e.to_string())?;
-
-    let response = input.trim().to_lowercase();
-    Ok(response == "y" || response == "yes")
-}
-
 /// Fish completion content - finds command in PATH, with WORKTRUNK_BIN as optional override
 fn fish_completion_content(cmd: &str) -> String {
     format!(
@@ -786,6 +785,40 @@ complete --keep-order --exclusive --command {cmd} --arguments "(test -n \"\$WORK
     )
 }
 
+/// Contents of a standalone completion file for `shell`.
+///
+/// Bash and Zsh normally get completions inline from `wt config shell init`; these
+/// files are for setups that don't eval the init script (e.g. package managers that
+/// install completions). Each one generates clap's completer lazily on first TAB.
+///
+/// Returns `None` for PowerShell, which only registers completions in the profile.
+pub fn completion_content(shell: Shell, cmd: &str) -> Option<String> {
+    match shell {
+        Shell::Bash => Some(format!(
+            r#"# worktrunk completions for bash
+_{cmd}_lazy_complete() {{
+    if ! declare -F _clap_complete_{cmd} >/dev/null; then
+        eval "$(COMPLETE=bash command "${{WORKTRUNK_BIN:-{cmd}}}" 2>/dev/null)" || return
+    fi
+    _clap_complete_{cmd} "$@"
+}}
+complete -o nospace -o bashdefault -F _{cmd}_lazy_complete {cmd}
+"#
+        )),
+        Shell::Zsh => Some(format!(
+            r#"#compdef {cmd}
+# worktrunk completions for zsh
+if ! (( $+functions[_clap_dynamic_completer_{cmd}] )); then
+    eval "$(COMPLETE=zsh command "${{WORKTRUNK_BIN:-{cmd}}}" 2>/dev/null | sed "s/_describe 'values'/_describe -V 'values'/")" || return
+fi
+_clap_dynamic_completer_{cmd} "$@"
+"#
+        )),
+        Shell::Fish => Some(fish_completion_content(cmd)),
+        Shell::PowerShell => None,
+    }
+}
+
 /// Process shell completions - either preview or write based on dry_run flag
 ///
 /// Note: Bash and Zsh use inline lazy completions in the init script.
@@ -1013,12 +1046,13 @@ fn scan_for_uninstall(
         }
     }
 
-    // Fish has a separate completion file that needs to be removed
+    // Fish has a separate completion file that needs to be removed. Bash and zsh
+    // only have one if `config shell completions --install` wrote it.
     let mut completion_results = Vec::new();
     let mut completion_not_found = Vec::new();
 
     for &shell in &shells {
-        if shell != Shell::Fish {
+        if shell == Shell::PowerShell {
             continue;
         }
 
@@ -1026,6 +1060,13 @@ fn scan_for_uninstall(
             .completion_path(cmd)
             .map_err(|e| format!("Failed to get completion path for {}: {}", shell, e))?;
 
+        if shell != Shell::Fish
+            && !fs::read_to_string(&completion_path)
+                .is_ok_and(|content| content.contains("# worktrunk completions for"))
+        {
+            continue;
+        }
+
         if completion_path.exists() {
             if dry_run {
                 completion_results.push(CompletionUninstallResult {
@@ -1066,14 +1107,25 @@ fn uninstall_from_file(
         .map_err(|e| format!("Failed to read {}: {}", format_path_for_display(path), e))?;
 
     let lines: Vec<&str> = content.lines().collect();
-    let integration_lines: Vec<(usize, &str)> = lines
+
+    // Managed blocks are removed whole, markers included
+    let mut managed: Vec<std::ops::RangeInclusive<usize>> = Vec::new();
+    let mut offset = 0;
+    while let Some((start, end)) = find_managed_block(&lines[offset..], cmd) {
+        managed.push(offset + start..=offset + end);
+        offset += end + 1;
+    }
+    // Integration lines outside any block (installed before managed blocks)
+    let integration_lines: Vec<usize> = lines
         .iter()
         .enumerate()
-        .filter(|(_, line)| shell::is_shell_integration_line(line, cmd))
-        .map(|(i, line)| (i, *line))
+        .filter(|(i, line)| {
+            shell::is_shell_integration_line(line, cmd) && !managed.iter().any(|r| r.contains(i))
+        })
+        .map(|(i, _)| i)
         .collect();
 
-    if integration_lines.is_empty() {
+    if managed.is_empty() && integration_lines.is_empty() {
         return Ok(None);
     }
 
@@ -1086,11 +1138,13 @@ fn uninstall_from_file(
         }));
     }
 
-    // Remove matching lines and any immediately preceding blank line
-    // (install adds "\n{line}\n", so we remove both the blank and the integration line)
+    // Remove blocks and matching lines, plus any immediately preceding blank line
+    // (install adds "\n{block}\n", so we remove both the blank and the block)
     let mut indices_to_remove: std::collections::HashSet<usize> =
-        integration_lines.iter().map(|(i, _)| *i).collect();
-    for &(i, _) in &integration_lines {
+        managed.iter().flat_map(|r| r.clone()).collect();
+    indices_to_remove.extend(&integration_lines);
+    let starts = managed.iter().map(|r| *r.start()).chain(integration_lines);
+    for i in starts {
         if i > 0 && lines[i - 1].trim().is_empty() {
             indices_to_remove.insert(i - 1);
         }
@@ -1161,7 +1215,9 @@ fn prompt_for_uninstall_confirmation(
         .map_err(|e| e.to_string())?;
     }
 
-    prompt_yes_no()
+    Confirm::new("Proceed?", false)
+        .ask(false)
+        .map_err(|e| e.to_string())
 }
 
 /// Show samples of all output message types
@@ -1259,6 +1315,8 @@ mod tests {
         assert_eq!(ConfigAction::Created.description(), "Created");
         assert_eq!(ConfigAction::WouldAdd.description(), "Will add");
         assert_eq!(ConfigAction::WouldCreate.description(), "Will create");
+        assert_eq!(ConfigAction::Updated.description(), "Updated");
+        assert_eq!(ConfigAction::WouldUpdate.description(), "Will update");
     }
 
     #[test]
@@ -1268,6 +1326,110 @@ mod tests {
         assert_eq!(ConfigAction::AlreadyExists.symbol(), INFO_SYMBOL);
         assert_eq!(ConfigAction::WouldAdd.symbol(), INFO_SYMBOL);
         assert_eq!(ConfigAction::WouldCreate.symbol(), INFO_SYMBOL);
+        assert_eq!(ConfigAction::Updated.symbol(), SUCCESS_SYMBOL);
+        assert_eq!(ConfigAction::WouldUpdate.symbol(), INFO_SYMBOL);
+    }
+
+    #[test]
+    fn test_find_managed_block() {
+        let lines = [
+            "export A=1",
+            "# >>> wt shell integration >>>",
+            "eval \"$(wt config shell init bash)\"",
+            "# <<< wt shell integration <<<",
+        ];
+        assert_eq!(find_managed_block(&lines, "wt"), Some((1, 3)));
+        // Another command's block doesn't count
+        assert_eq!(find_managed_block(&lines, "git-wt"), None);
+        // Unterminated block isn't a block
+        assert_eq!(find_managed_block(&lines[..3], "wt"), None);
+    }
+
+    #[test]
+    fn test_install_writes_managed_block_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let rc = dir.path().join(".bashrc");
+        fs::write(&rc, "export A=1\n").unwrap();
+
+        let first = configure_shell_file(Shell::Bash, &rc, false, false, "wt")
+            .unwrap()
+            .unwrap();
+        assert_eq!(first.action, ConfigAction::Added);
+        let installed = fs::read_to_string(&rc).unwrap();
+        assert_eq!(
+            installed,
+            format!(
+                "export A=1\n\n{}\n",
+                managed_block(&Shell::Bash.config_line("wt"), "wt")
+            )
+        );
+
+        let second = configure_shell_file(Shell::Bash, &rc, false, false, "wt")
+            .unwrap()
+            .unwrap();
+        assert_eq!(second.action, ConfigAction::AlreadyExists);
+        assert_eq!(fs::read_to_string(&rc).unwrap(), installed);
+    }
+
+    #[test]
+    fn test_install_upgrades_in_place() {
+        let dir = tempfile::tempdir().unwrap();
+        let rc = dir.path().join(".bashrc");
+        let block = managed_block(&Shell::Bash.config_line("wt"), "wt");
+
+        // Outdated managed block
+        fs::write(
+            &rc,
+            "export A=1\n# >>> wt shell integration >>>\neval \"$(wt config shell init bash)\"\n# <<< wt shell integration <<<\nexport B=2\n",
+        )
+        .unwrap();
+        let result = configure_shell_file(Shell::Bash, &rc, true, false, "wt")
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.action, ConfigAction::WouldUpdate);
+        let result = configure_shell_file(Shell::Bash, &rc, false, false, "wt")
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.action, ConfigAction::Updated);
+        assert_eq!(
+            fs::read_to_string(&rc).unwrap(),
+            format!("export A=1\n{block}\nexport B=2\n")
+        );
+
+        // Older bare integration line
+        fs::write(&rc, "eval \"$(wt config shell init bash)\"\nexport B=2\n").unwrap();
+        let result = configure_shell_file(Shell::Bash, &rc, false, false, "wt")
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.action, ConfigAction::Updated);
+        assert_eq!(
+            fs::read_to_string(&rc).unwrap(),
+            format!("{block}\nexport B=2\n")
+        );
+    }
+
+    #[test]
+    fn test_uninstall_removes_managed_block() {
+        let dir = tempfile::tempdir().unwrap();
+        let rc = dir.path().join(".zshrc");
+        let original = "export A=1\n";
+        fs::write(&rc, original).unwrap();
+        configure_shell_file(Shell::Zsh, &rc, false, false, "wt").unwrap();
+        // A bare line from before managed blocks is removed too
+        let mut content = fs::read_to_string(&rc).unwrap();
+        content.push_str("eval \"$(wt config shell init zsh)\"\n");
+        fs::write(&rc, content).unwrap();
+
+        let result = uninstall_from_file(Shell::Zsh, &rc, false, "wt")
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.action, UninstallAction::Removed);
+        assert_eq!(fs::read_to_string(&rc).unwrap(), original);
+        assert!(
+            uninstall_from_file(Shell::Zsh, &rc, false, "wt")
+                .unwrap()
+                .is_none()
+        );
     }
 
     #[test]
@@ -1,7 +1,7 @@
 use anyhow::Context;
 use std::path::PathBuf;
 use worktrunk::config::WorktrunkConfig;
-use worktrunk::git::Repository;
+use worktrunk::git::{Repository, base_dir};
 
 use super::command_executor::CommandContext;
 
@@ -28,11 +28,20 @@ impl CommandEnv {
     /// Load the command environment for a specific action.
     ///
     /// `action` describes what command is running (e.g., "merge", "squash").
-    /// Used in error messages when the environment can't be loaded.
+    /// Used in error messages when the environment can't be loaded. Fails on a
+    /// branch checked out with `wt review`, since every action writes to it.
     pub fn for_action(action: &str) -> anyhow::Result<Self> {
         let repo = Repository::current()?;
-        let worktree_path = std::env::current_dir().context("Failed to get current directory")?;
+        let worktree_path = base_dir().context("Failed to get current directory")?;
         let branch = repo.require_current_branch(action)?;
+        // Review checkouts (`wt review`) aren't for committing to
+        if repo.review_state(&branch).is_some() {
+            return Err(worktrunk::git::GitError::ReviewWorktree {
+                branch,
+                action: action.into(),
+            }
+            .into());
+        }
         let config = WorktrunkConfig::load().context("Failed to load config")?;
         let repo_root = repo
             .repo_path()
@@ -53,7 +62,7 @@ impl CommandEnv {
     /// such as running hooks (where `{{ branch }}` expands to "HEAD" if detached).
     pub fn for_action_branchless() -> anyhow::Result<Self> {
         let repo = Repository::current()?;
-        let worktree_path = std::env::current_dir().context("Failed to get current directory")?;
+        let worktree_path = base_dir().context("Failed to get current directory")?;
         // Propagate git errors (broken repo, missing git) but allow None for detached HEAD
         let branch = repo
             .current_worktree()
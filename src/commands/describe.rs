@@ -0,0 +1,58 @@
+//! Branch descriptions.
+//!
+//! Reads and writes `branch.<name>.description`, the same key git uses for
+//! `git branch --edit-description`. `wt list --full` shows descriptions in
+//! place of the commit subject.
+
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{hint_message, info_message, success_message};
+
+use crate::output;
+
+/// Handle `wt describe`: show, set, or clear a branch description.
+pub fn handle_describe(branch: &str, text: Option<String>, clear: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = repo.resolve_worktree_name(branch)?;
+
+    if !repo.local_branch_exists(&branch)? {
+        return Err(GitError::InvalidReference { reference: branch }.into());
+    }
+
+    if clear {
+        repo.set_branch_description(&branch, None)?;
+        output::print(success_message(cformat!(
+            "Cleared description for <bold>{branch}</>"
+        )))?;
+        return Ok(());
+    }
+
+    match text.map(|t| t.trim().to_string()) {
+        // Empty text clears, matching an emptied `git branch --edit-description`
+        Some(text) if text.is_empty() => {
+            repo.set_branch_description(&branch, None)?;
+            output::print(success_message(cformat!(
+                "Cleared description for <bold>{branch}</>"
+            )))?;
+        }
+        Some(text) => {
+            repo.set_branch_description(&branch, Some(&text))?;
+            output::print(success_message(cformat!(
+                "Set description for <bold>{branch}</>"
+            )))?;
+        }
+        None => match repo.branch_description(&branch) {
+            Some(description) => output::stdout(description)?,
+            None => {
+                output::print(info_message(cformat!(
+                    "No description for <bold>{branch}</>"
+                )))?;
+                output::print(hint_message(cformat!(
+                    "To add one, run <bright-black>wt describe {branch} \"TEXT\"</>"
+                )))?;
+            }
+        },
+    }
+
+    Ok(())
+}
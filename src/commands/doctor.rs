@@ -0,0 +1,36 @@
+//! `wt doctor`: check the configuration for problems.
+//!
+//! Reports where diff content can go: with `[llm] enabled = false` nothing is
+//! ever handed to a command, which security-conscious setups want confirmed.
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::styling::{hint_message, info_message, success_message};
+
+use crate::output;
+
+/// Handle `wt doctor`.
+pub fn handle_doctor() -> anyhow::Result<()> {
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+    let commit_config = &config.commit_generation;
+
+    if commit_config.disabled {
+        return output::print(success_message(cformat!(
+            "LLM features disabled (<bold>[llm] enabled = false</>); diffs are never sent to a command"
+        )));
+    }
+    if !commit_config.is_configured() {
+        return output::print(success_message(
+            "No LLM command configured; diffs are never sent to a command",
+        ));
+    }
+
+    let command = commit_config.command.as_deref().unwrap_or_default();
+    output::print(info_message(cformat!(
+        "Diffs are sent to <bold>{command}</> to write commit messages"
+    )))?;
+    output::print(hint_message(cformat!(
+        "To keep them on this machine, set <bright-black>[llm] enabled = false</>"
+    )))
+}
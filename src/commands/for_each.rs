@@ -31,10 +31,12 @@ use worktrunk::git::Repository;
 use worktrunk::git::WorktrunkError;
 use worktrunk::shell_exec::ShellConfig;
 use worktrunk::styling::{
-    error_message, format_with_gutter, progress_message, success_message, warning_message,
+    error_message, format_with_gutter, info_message, progress_message, success_message,
+    warning_message,
 };
 
 use crate::commands::command_executor::{CommandContext, build_hook_context};
+use crate::commands::run::approve_profile;
 use crate::commands::worktree_display_name;
 use crate::output;
 
@@ -44,7 +46,10 @@ use crate::output;
 /// in real-time. Continues on errors and reports a summary at the end.
 ///
 /// All template variables from hooks are available, and context JSON is piped to stdin.
-pub fn step_for_each(args: Vec<String>) -> anyhow::Result<()> {
+///
+/// With `profile`, runs that `[profiles.<name>]` command instead of `args`,
+/// after approving it once up front.
+pub fn step_for_each(args: Vec<String>, profile: Option<&str>, yes: bool) -> anyhow::Result<()> {
     let repo = Repository::current()?;
     // Filter out prunable worktrees (directory deleted) - can't run commands there
     let worktrees: Vec<_> = repo
@@ -54,12 +59,21 @@ pub fn step_for_each(args: Vec<String>) -> anyhow::Result<()> {
         .collect();
     let config = WorktrunkConfig::load()?;
 
+    // Profile template, or args joined into one (either is expanded per-worktree)
+    let command_template = match profile {
+        Some(name) => match approve_profile(&repo, &config, name, yes)? {
+            Some(template) => template,
+            None => {
+                output::print(info_message("Commands declined"))?;
+                return Ok(());
+            }
+        },
+        None => args.join(" "),
+    };
+
     let mut failed: Vec<String> = Vec::new();
     let total = worktrees.len();
 
-    // Join args into a template string (will be expanded per-worktree)
-    let command_template = args.join(" ");
-
     // Get repo root for context
     let repo_root = repo.repo_path()?;
 
@@ -129,18 +143,23 @@ pub fn step_for_each(args: Vec<String>) -> anyhow::Result<()> {
         )))?;
         Ok(())
     } else {
-        output::print(warning_message(format!(
-            "{} of {total} worktree{} failed",
-            failed.len(),
-            if total == 1 { "" } else { "s" }
-        )))?;
-        let failed_list = failed.join("\n");
-        output::print(format_with_gutter(&failed_list, None))?;
-        // Return silent error so main exits with code 1 without duplicate message
-        Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into())
+        report_failures(&failed, total)
     }
 }
 
+/// Summarize worktrees where a multi-worktree command failed.
+///
+/// Returns a silent error so main exits with code 1 without a duplicate message.
+pub(super) fn report_failures(failed: &[String], total: usize) -> anyhow::Result<()> {
+    output::print(warning_message(format!(
+        "{} of {total} worktree{} failed",
+        failed.len(),
+        if total == 1 { "" } else { "s" }
+    )))?;
+    output::print(format_with_gutter(&failed.join("\n"), None))?;
+    Err(WorktrunkError::AlreadyDisplayed { exit_code: 1 }.into())
+}
+
 /// Error from running a command in a worktree
 enum CommandError {
     /// Command failed to spawn (e.g., command not found, permission denied)
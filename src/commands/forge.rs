@@ -0,0 +1,101 @@
+//! Forge operations behind one interface.
+//!
+//! A forge is the code host a repository's remote points at. Each backend
+//! wraps one CLI or API (`gh` for GitHub, `glab` for GitLab, the REST API for
+//! Gitea, Forgejo, and Bitbucket Cloud) and owns the parsing of its output; [`GitOnly`] answers
+//! for hosts without one. Commands ask [`forge_for_repo`] instead of shelling
+//! out themselves, so CI status, review state, and PR lookups share one
+//! implementation per host.
+//!
+//! The backend implementations live next to their CI parsing in
+//! `list::ci_status`.
+
+use worktrunk::git::Repository;
+
+use super::list::ci_status::{CiPlatform, CiQuery, PrStatus, get_platform_for_repo};
+
+/// Queries against a code host.
+pub(crate) trait Forge {
+    /// Status of the open PR/MR for `branch`, including its checks and
+    /// review state. `local_head` decides whether the status is stale.
+    fn pr_for_branch(&self, repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus>;
+
+    /// CI status of `local_head` itself, for pushed branches without a PR/MR.
+    fn ci_status(&self, repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus>;
+
+    /// Statuses for many branches at once, in `queries` order.
+    ///
+    /// `None` means the forge can't batch (or the batch failed) and each
+    /// branch should go through [`Forge::status`].
+    fn batch_status(
+        &self,
+        _repo: &Repository,
+        _queries: &[&CiQuery],
+    ) -> Option<Vec<Option<PrStatus>>> {
+        None
+    }
+
+    /// The PR/MR status, falling back to the branch's own CI when it has an
+    /// upstream (unpushed branches can't have run any).
+    fn status(
+        &self,
+        repo: &Repository,
+        branch: &str,
+        local_head: &str,
+        has_upstream: bool,
+    ) -> Option<PrStatus> {
+        if let Some(status) = self.pr_for_branch(repo, branch, local_head) {
+            return Some(status);
+        }
+        if has_upstream {
+            return self.ci_status(repo, branch, local_head);
+        }
+        None
+    }
+}
+
+/// GitHub, through `gh`.
+pub(crate) struct GitHub;
+
+/// GitLab, through `glab`.
+pub(crate) struct GitLab;
+
+/// Gitea or Forgejo, through the REST API.
+pub(crate) struct Gitea;
+
+/// Bitbucket Cloud, through the REST API.
+pub(crate) struct Bitbucket;
+
+/// A host without a supported CLI: no PRs or CI.
+pub(crate) struct GitOnly;
+
+impl Forge for GitOnly {
+    fn pr_for_branch(&self, _: &Repository, _: &str, _: &str) -> Option<PrStatus> {
+        None
+    }
+
+    fn ci_status(&self, _: &Repository, _: &str, _: &str) -> Option<PrStatus> {
+        None
+    }
+}
+
+/// The backend for `platform`.
+pub(crate) fn forge_for(platform: CiPlatform) -> &'static dyn Forge {
+    match platform {
+        CiPlatform::GitHub => &GitHub,
+        CiPlatform::GitLab => &GitLab,
+        CiPlatform::Gitea => &Gitea,
+        CiPlatform::Bitbucket => &Bitbucket,
+    }
+}
+
+/// The backend for a repository, from project config `[ci] platform` or the
+/// remote URL; [`GitOnly`] when neither names a supported host.
+pub(crate) fn forge_for_repo(repo: &Repository) -> &'static dyn Forge {
+    let project_config = repo.load_project_config().ok().flatten();
+    let platform_override = project_config.as_ref().and_then(|c| c.ci_platform());
+    match get_platform_for_repo(repo, platform_override) {
+        Some(platform) => forge_for(platform),
+        None => &GitOnly,
+    }
+}
@@ -0,0 +1,66 @@
+//! `wt history`: the activity log written by switch, remove and merge.
+//!
+//! See [`worktrunk::git::ActivityEntry`] for the log format.
+
+use std::time::Duration;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::info_message;
+use worktrunk::utils::get_now;
+
+use crate::OutputFormat;
+use crate::output;
+
+/// Handle `wt history`: print activity newer than `since`, oldest first.
+pub fn handle_history(since: Option<Duration>, format: OutputFormat) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let cutoff = since.map_or(0, |age| get_now().saturating_sub(age.as_secs()));
+    let entries = repo.activity_log(cutoff)?;
+
+    if let OutputFormat::Json = format {
+        let json = serde_json::to_string_pretty(&entries).context("Failed to serialize to JSON")?;
+        output::stdout(json)?;
+        return Ok(());
+    }
+
+    if entries.is_empty() {
+        output::print(info_message("No activity recorded"))?;
+        return Ok(());
+    }
+
+    let width = entries
+        .iter()
+        .map(|entry| entry.operation.to_string().len())
+        .max()
+        .unwrap_or(0);
+    let lines: Vec<String> = entries
+        .iter()
+        .map(|entry| {
+            let time = chrono::DateTime::from_timestamp(entry.timestamp as i64, 0)
+                .map(|time| {
+                    time.with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M")
+                        .to_string()
+                })
+                .unwrap_or_default();
+            let operation = format!("{:width$}", entry.operation.to_string());
+            let branch = entry.branch.as_deref().unwrap_or("HEAD");
+            let path = entry
+                .path
+                .as_deref()
+                .map(|path| cformat!(" <bright-black>{}</>", format_path_for_display(path)))
+                .unwrap_or_default();
+            let failed = if entry.success {
+                String::new()
+            } else {
+                cformat!(" <red>(failed)</>")
+            };
+            cformat!("<dim>{time}</>  {operation}  <bold>{branch}</>{path}{failed}")
+        })
+        .collect();
+    output::stdout(lines.join("\n"))?;
+    Ok(())
+}
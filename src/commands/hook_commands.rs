@@ -3,6 +3,8 @@
 //! This module contains:
 //! - `run_hook` - Execute a specific hook type
 //! - `add_approvals` - Approve all project commands
+//! - `list_approvals` - Show saved approvals
+//! - `revoke_approvals` - Revoke a project's approvals
 //! - `clear_approvals` - Clear approved commands
 //! - `handle_hook_show` - Display configured hooks
 
@@ -28,7 +30,7 @@ use super::hooks::{
 use super::merge::{
     execute_post_merge_commands, execute_pre_remove_commands, run_pre_merge_commands,
 };
-use super::project_config::collect_commands_for_hooks;
+use super::project_config::{collect_commands_for_hooks, collect_profile_commands};
 
 /// Handle `wt hook` command
 ///
@@ -103,9 +105,10 @@ pub fn run_hook(
     match hook_type {
         HookType::PostCreate => {
             let user_config = user_hook!(post_create);
-            let project_config = project_config
+            let project_commands = project_config
                 .as_ref()
-                .and_then(|c| c.hooks.post_create.as_ref());
+                .and_then(|c| c.post_create_commands(ctx.branch));
+            let project_config = project_commands.as_ref();
             require_hooks(user_config, project_config, hook_type)?;
             // Manual wt hook: user stays at cwd (no cd happens)
             run_hook_with_filter(
@@ -278,9 +281,10 @@ pub fn add_approvals(show_all: bool) -> anyhow::Result<()> {
         .load_project_config()?
         .ok_or(GitError::ProjectConfigNotFound { config_path })?;
 
-    // Collect all commands from the project config
+    // Collect all commands from the project config, including `wt run` profiles
     let all_hooks: Vec<_> = HookType::iter().collect();
-    let commands = collect_commands_for_hooks(&project_config, &all_hooks);
+    let mut commands = collect_commands_for_hooks(&project_config, &all_hooks);
+    commands.extend(collect_profile_commands(&project_config, None));
 
     if commands.is_empty() {
         crate::output::print(info_message("No commands configured in project"))?;
@@ -320,6 +324,100 @@ pub fn add_approvals(show_all: bool) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Handle `wt hook approvals list` command - show saved approvals
+pub fn list_approvals() -> anyhow::Result<()> {
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+
+    if config.projects.is_empty() {
+        crate::output::print(info_message("No approvals saved"))?;
+        return Ok(());
+    }
+
+    // Outside a repository there's no current project to mark
+    let current = Repository::current()
+        .ok()
+        .and_then(|repo| repo.project_identifier().ok());
+
+    for (project_id, project) in &config.projects {
+        let count = project.approved_commands.len();
+        let plural = if count == 1 { "" } else { "s" };
+        let marker = if current.as_deref() == Some(project_id.as_str()) {
+            " (current project)"
+        } else {
+            ""
+        };
+        crate::output::print(info_message(cformat!(
+            "<bold>{project_id}</>{marker}: {count} approved command{plural}"
+        )))?;
+        for command in &project.approved_commands {
+            crate::output::print(format_bash_with_gutter(command))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Handle `wt hook approvals revoke` command - revoke one or all of a project's approvals
+pub fn revoke_approvals(project: &str, command: Option<&str>) -> anyhow::Result<()> {
+    let mut config = WorktrunkConfig::load().context("Failed to load config")?;
+
+    let Some(project_config) = config.projects.get(project) else {
+        crate::output::print(info_message(cformat!(
+            "No approvals for <bold>{project}</>"
+        )))?;
+        crate::output::print(hint_message(cformat!(
+            "To see saved approvals, run <bright-black>wt hook approvals list</>"
+        )))?;
+        return Ok(());
+    };
+
+    let Some(command) = command else {
+        let count = project_config.approved_commands.len();
+        config
+            .revoke_project(project, None)
+            .context("Failed to revoke project approvals")?;
+        crate::output::print(success_message(cformat!(
+            "Revoked {count} approval{} for <bold>{project}</>",
+            if count == 1 { "" } else { "s" }
+        )))?;
+        return Ok(());
+    };
+
+    // Exact command text first, then the name it was approved under
+    let targets: Vec<String> = if project_config
+        .approved_commands
+        .iter()
+        .any(|c| c == command)
+    {
+        vec![command.to_string()]
+    } else {
+        project_config
+            .commands_named(command)
+            .into_iter()
+            .cloned()
+            .collect()
+    };
+    if targets.is_empty() {
+        return Err(GitError::ApprovalNotFound {
+            project: project.to_string(),
+            command: command.to_string(),
+        }
+        .into());
+    }
+
+    for target in &targets {
+        config
+            .revoke_command(project, target, None)
+            .context("Failed to revoke approval")?;
+    }
+    crate::output::print(success_message(cformat!(
+        "Revoked approval for <bold>{project}</>:"
+    )))?;
+    crate::output::print(format_bash_with_gutter(&targets.join("\n")))?;
+
+    Ok(())
+}
+
 /// Handle `wt hook approvals clear` command - clear approved commands
 pub fn clear_approvals(global: bool) -> anyhow::Result<()> {
     use worktrunk::config::WorktrunkConfig;
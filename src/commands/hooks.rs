@@ -1,18 +1,39 @@
+use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::process::Stdio;
+use std::sync::OnceLock;
 
 use color_print::cformat;
 use worktrunk::HookType;
 use worktrunk::config::CommandConfig;
 use worktrunk::git::WorktrunkError;
 use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::Cmd;
 use worktrunk::styling::{
-    error_message, format_bash_with_gutter, progress_message, warning_message,
+    FormattedMessage, error_message, format_bash_with_gutter, info_message, progress_message,
+    warning_message,
 };
 
 use super::command_executor::{CommandContext, PreparedCommand, prepare_commands};
 use crate::commands::process::spawn_detached;
 use crate::output::execute_command_in_worktree;
 
+/// Hook types skipped for this invocation (`--skip-hook`).
+static SKIPPED_HOOKS: OnceLock<Vec<HookType>> = OnceLock::new();
+
+/// Skip hooks of these types wherever the command runs or approves them (`--skip-hook`).
+pub fn set_skipped_hooks(hook_types: Vec<HookType>) {
+    let _ = SKIPPED_HOOKS.set(hook_types);
+}
+
+/// Whether `--skip-hook` skips `hook_type`.
+pub fn is_hook_skipped(hook_type: HookType) -> bool {
+    SKIPPED_HOOKS
+        .get()
+        .is_some_and(|skipped| skipped.contains(&hook_type))
+}
+
 /// A prepared command with its source information.
 pub struct SourcedCommand {
     pub prepared: PreparedCommand,
@@ -25,10 +46,16 @@ pub struct SourcedCommand {
 
 impl SourcedCommand {
     /// Announce this command before execution.
+    fn announce(&self) -> anyhow::Result<()> {
+        crate::output::print(self.announcement())?;
+        Ok(())
+    }
+
+    /// The announcement: a progress line and the command in a gutter.
     ///
     /// Format: "Running pre-merge user:foo:" for named, "Running post-create user hook:" for unnamed
     /// When display_path is set, appends "@ path" to show where the command runs.
-    fn announce(&self) -> anyhow::Result<()> {
+    fn announcement(&self) -> String {
         // Named: "Running post-switch user:foo" with "user:foo" bold
         // Unnamed: "Running post-switch user hook" with no bold
         let full_label = match &self.prepared.name {
@@ -48,9 +75,11 @@ impl SourcedCommand {
             }
             None => format!("{full_label}:"),
         };
-        crate::output::print(progress_message(message))?;
-        crate::output::print(format_bash_with_gutter(&self.prepared.expanded))?;
-        Ok(())
+        format!(
+            "{}\n{}",
+            progress_message(message),
+            format_bash_with_gutter(&self.prepared.expanded)
+        )
     }
 }
 
@@ -85,6 +114,15 @@ pub fn prepare_hook_commands(
     name_filter: Option<&str>,
     display_path: Option<&Path>,
 ) -> anyhow::Result<Vec<SourcedCommand>> {
+    if is_hook_skipped(hook_type) {
+        if user_config.is_some() || project_config.is_some() {
+            crate::output::print(info_message(format!(
+                "Skipping {hook_type} hooks (--skip-hook)"
+            )))?;
+        }
+        return Ok(Vec::new());
+    }
+
     let parsed_filter = name_filter.map(ParsedFilter::parse);
     let mut commands = Vec::new();
 
@@ -152,6 +190,7 @@ pub fn spawn_hook_commands_background(
 
     // Track index for unnamed commands to prevent log collisions
     let mut unnamed_index = 0usize;
+    let env = ctx.hook_env();
 
     for cmd in commands {
         cmd.announce()?;
@@ -175,6 +214,7 @@ pub fn spawn_hook_commands_background(
             ctx.branch_or_head(),
             &operation,
             Some(&cmd.prepared.context_json),
+            &env,
         ) {
             let err_msg = err.to_string();
             let message = match &cmd.prepared.name {
@@ -267,6 +307,7 @@ pub fn run_hook_with_filter(
 
     // Track first failure's exit code for Warn strategy (to propagate after all commands run)
     let mut first_failure_exit_code: Option<i32> = None;
+    let env = ctx.hook_env();
 
     for cmd in commands {
         cmd.announce()?;
@@ -275,18 +316,9 @@ pub fn run_hook_with_filter(
             ctx.worktree_path,
             &cmd.prepared.expanded,
             Some(&cmd.prepared.context_json),
+            &env,
         ) {
-            // Extract raw message and exit code from error
-            let (err_msg, exit_code) = if let Some(wt_err) = err.downcast_ref::<WorktrunkError>() {
-                match wt_err {
-                    WorktrunkError::ChildProcessExited { message, code } => {
-                        (message.clone(), Some(*code))
-                    }
-                    _ => (err.to_string(), None),
-                }
-            } else {
-                (err.to_string(), None)
-            };
+            let (err_msg, exit_code) = failure_details(&err);
 
             match &failure_strategy {
                 HookFailureStrategy::FailFast => {
@@ -300,11 +332,7 @@ pub fn run_hook_with_filter(
                     .into());
                 }
                 HookFailureStrategy::Warn => {
-                    let message = match &cmd.prepared.name {
-                        Some(name) => cformat!("Command <bold>{name}</> failed: {err_msg}"),
-                        None => format!("Command failed: {err_msg}"),
-                    };
-                    crate::output::print(error_message(message))?;
+                    crate::output::print(failure_message(&cmd, &err_msg))?;
 
                     // Track first failure to propagate exit code later (only for PostMerge)
                     if first_failure_exit_code.is_none() && hook_type == HookType::PostMerge {
@@ -327,6 +355,72 @@ pub fn run_hook_with_filter(
     Ok(())
 }
 
+/// Run hooks like [`run_hook_with_filter`] with the `Warn` strategy, writing
+/// the announcements, command output and failures to `log` instead of the terminal.
+///
+/// For hooks of several worktrees running at once: the caller prints each
+/// worktree's log as one block, so their output doesn't interleave.
+pub fn run_hook_to_log(
+    ctx: &CommandContext,
+    user_config: Option<&CommandConfig>,
+    project_config: Option<&CommandConfig>,
+    hook_type: HookType,
+    extra_vars: &[(&str, &str)],
+    display_path: Option<&Path>,
+    log: &mut fs::File,
+) -> anyhow::Result<()> {
+    let commands = prepare_hook_commands(
+        ctx,
+        user_config,
+        project_config,
+        hook_type,
+        extra_vars,
+        None,
+        display_path,
+    )?;
+    let env = ctx.hook_env();
+
+    for cmd in commands {
+        writeln!(log, "{}", cmd.announcement())?;
+
+        let mut command = Cmd::shell(&cmd.prepared.expanded)
+            .current_dir(ctx.worktree_path)
+            .stdin_bytes(cmd.prepared.context_json.as_str())
+            .stdout(Stdio::from(log.try_clone()?))
+            .stderr(Stdio::from(log.try_clone()?))
+            .forward_signals();
+        for (key, value) in &env {
+            command = command.env(key, value);
+        }
+
+        if let Err(err) = command.stream() {
+            let (err_msg, _) = failure_details(&err);
+            writeln!(log, "{}", failure_message(&cmd, &err_msg))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The raw message and exit code of a failed hook command.
+fn failure_details(err: &anyhow::Error) -> (String, Option<i32>) {
+    match err.downcast_ref::<WorktrunkError>() {
+        Some(WorktrunkError::ChildProcessExited { message, code }) => {
+            (message.clone(), Some(*code))
+        }
+        _ => (err.to_string(), None),
+    }
+}
+
+/// The error shown when a hook command fails under the `Warn` strategy.
+fn failure_message(cmd: &SourcedCommand, err_msg: &str) -> FormattedMessage {
+    let message = match &cmd.prepared.name {
+        Some(name) => cformat!("Command <bold>{name}</> failed: {err_msg}"),
+        None => format!("Command failed: {err_msg}"),
+    };
+    error_message(message)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,5 +1,11 @@
+use color_print::cformat;
+use std::fs;
+use worktrunk::path::format_path_for_display;
 use worktrunk::shell;
-use worktrunk::styling::println;
+use worktrunk::styling::{hint_message, print, println, success_message};
+
+use super::configure_shell::completion_content;
+use crate::output;
 
 pub fn handle_init(shell: shell::Shell, cmd: String) -> Result<(), String> {
     let init = shell::ShellInit::with_prefix(shell, cmd);
@@ -13,3 +19,47 @@ pub fn handle_init(shell: shell::Shell, cmd: String) -> Result<(), String> {
 
     Ok(())
 }
+
+/// Print a completion script, or with `install`, write it to the shell's
+/// conventional completion directory.
+pub fn handle_completions(shell: shell::Shell, install: bool, cmd: String) -> Result<(), String> {
+    let Some(content) = completion_content(shell, &cmd) else {
+        return Err(format!(
+            "{shell} completions are registered by the shell integration; run `{cmd} config shell install {shell}`"
+        ));
+    };
+
+    if !install {
+        print!("{content}");
+        return Ok(());
+    }
+
+    let path = shell
+        .completion_path(&cmd)
+        .map_err(|e| format!("Failed to get completion path for {shell}: {e}"))?;
+    let display = format_path_for_display(&path);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|e| format!("Failed to create directory {}: {e}", parent.display()))?;
+    }
+    fs::write(&path, content).map_err(|e| format!("Failed to write {display}: {e}"))?;
+
+    output::print(success_message(cformat!(
+        "Wrote completions for <bold>{shell}</> @ <bold>{display}</>"
+    )))
+    .map_err(|e| e.to_string())?;
+    // Bash (bash-completion) and fish load these directories automatically;
+    // ~/.zfunc needs to be on fpath before compinit runs
+    if matches!(shell, shell::Shell::Zsh) {
+        let dir = path
+            .parent()
+            .map(format_path_for_display)
+            .unwrap_or_default();
+        output::print(hint_message(cformat!(
+            "Ensure <bright-black>{dir}</> is in fpath before compinit: <bright-black>fpath=({dir} $fpath)</>"
+        )))
+        .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
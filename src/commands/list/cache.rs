@@ -0,0 +1,164 @@
+//! Cached `wt list --format=json` output.
+//!
+//! Prompt integrations call `wt list --format=json` every few seconds. With
+//! `[list] cache = true` the output is stored in `.git/wt-list-cache/`, keyed on
+//! the repository's refs and each worktree's index mtime. A matching entry is
+//! printed immediately and, once it's a couple of seconds old, recomputed in a
+//! detached `wt list ... --refresh-cache` (stale-while-revalidate), so edits the
+//! key can't see — unstaged changes — show up on a later call.
+
+use std::fs;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use anyhow::Context;
+use worktrunk::git::Repository;
+
+/// Entries older than this are recomputed in the background when served.
+const REFRESH_AFTER: Duration = Duration::from_secs(2);
+
+/// Entries older than this are ignored and recomputed in the foreground.
+const MAX_AGE: Duration = Duration::from_secs(30);
+
+/// A cache slot for one combination of flags and current worktree.
+pub(super) struct ListCache {
+    path: PathBuf,
+    key: String,
+}
+
+impl ListCache {
+    /// Compute the cache slot and key for the repository's current state.
+    ///
+    /// `variant` identifies everything besides repository state that changes the
+    /// output (flags, current directory).
+    pub(super) fn new(repo: &Repository, variant: &str) -> anyhow::Result<Self> {
+        let mut state = repo.run_command(&["for-each-ref", "--format=%(objectname) %(refname)"])?;
+        for wt in repo.list_worktrees()? {
+            let index_mtime = index_path(&wt.path)
+                .and_then(|index| fs::metadata(index).and_then(|m| m.modified()).ok());
+            state.push_str(&format!(
+                "{} {} {index_mtime:?}\n",
+                wt.path.display(),
+                wt.head
+            ));
+        }
+
+        Ok(Self {
+            path: repo
+                .wt_list_cache_dir()
+                .join(format!("{}.json", hash(&variant))),
+            key: hash(&(variant, state)),
+        })
+    }
+
+    /// The cached output, if it was computed for the same state recently enough.
+    ///
+    /// Returns whether the entry should also be refreshed in the background.
+    pub(super) fn read(&self) -> Option<(String, bool)> {
+        let age = fs::metadata(&self.path)
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| SystemTime::now().duration_since(modified).ok())?;
+        if age > MAX_AGE {
+            return None;
+        }
+        let contents = fs::read_to_string(&self.path).ok()?;
+        let (key, json) = contents.split_once('\n')?;
+        (key == self.key).then(|| (json.to_string(), age > REFRESH_AFTER))
+    }
+
+    /// Store freshly computed output.
+    pub(super) fn write(&self, json: &str) -> anyhow::Result<()> {
+        let dir = self.path.parent().expect("cache path has a parent");
+        fs::create_dir_all(dir).context("Failed to create list cache directory")?;
+        // Write-then-rename so a concurrent reader never sees a partial entry
+        let tmp_path = self
+            .path
+            .with_extension(format!("{}.tmp", std::process::id()));
+        fs::write(&tmp_path, format!("{}\n{json}", self.key))
+            .and_then(|()| fs::rename(&tmp_path, &self.path))
+            .context("Failed to write list cache")
+    }
+
+    /// Recompute the entry in a detached process, rerunning this invocation
+    /// with `--refresh-cache`.
+    pub(super) fn refresh_in_background(&self, repo: &Repository) -> anyhow::Result<()> {
+        // Bump the mtime first so calls during the refresh don't spawn more
+        let _ = fs::File::options()
+            .write(true)
+            .open(&self.path)
+            .and_then(|file| file.set_modified(SystemTime::now()));
+
+        let exe = std::env::current_exe().context("Failed to locate wt executable")?;
+        let command = std::iter::once(exe.to_string_lossy().into_owned())
+            .chain(
+                std::env::args_os()
+                    .skip(1)
+                    .map(|arg| arg.to_string_lossy().into_owned()),
+            )
+            .chain(["--refresh-cache".to_string()])
+            .map(|arg| shell_escape::escape(arg.into()).into_owned())
+            .collect::<Vec<_>>()
+            .join(" ");
+        // The rerun repeats any `-C`, so it starts where this process did
+        // rather than in `base_dir()`
+        let cwd = std::env::current_dir().context("Failed to get current directory")?;
+        crate::commands::process::spawn_detached(
+            repo,
+            &cwd,
+            &command,
+            "wt",
+            "list-cache",
+            None,
+            &[],
+        )?;
+        Ok(())
+    }
+}
+
+/// The index file of the worktree at `path`, found without running git.
+fn index_path(path: &Path) -> Option<PathBuf> {
+    let dot_git = path.join(".git");
+    if dot_git.is_dir() {
+        return Some(dot_git.join("index"));
+    }
+    // Linked worktrees have a `.git` file pointing at their git dir
+    let contents = fs::read_to_string(&dot_git).ok()?;
+    let git_dir = Path::new(contents.strip_prefix("gitdir:")?.trim());
+    Some(path.join(git_dir).join("index"))
+}
+
+fn hash(value: &impl Hash) -> String {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index_path_linked_worktree() {
+        let dir = tempfile::tempdir().unwrap();
+        let git_dir = dir
+            .path()
+            .join("repo.git")
+            .join("worktrees")
+            .join("feature");
+        fs::write(
+            dir.path().join(".git"),
+            format!("gitdir: {}\n", git_dir.display()),
+        )
+        .unwrap();
+        assert_eq!(index_path(dir.path()), Some(git_dir.join("index")));
+
+        fs::remove_file(dir.path().join(".git")).unwrap();
+        fs::create_dir(dir.path().join(".git")).unwrap();
+        assert_eq!(
+            index_path(dir.path()),
+            Some(dir.path().join(".git").join("index"))
+        );
+    }
+}
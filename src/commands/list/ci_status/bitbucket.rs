@@ -0,0 +1,365 @@
+//! Bitbucket Cloud CI status detection.
+//!
+//! Detects CI status from pull requests and commit build statuses (which
+//! Pipelines reports like any other CI) through the REST API, called with
+//! `curl` (see [`super::rest`]). Credentials come from the environment:
+//! `BITBUCKET_TOKEN` for an access token, or `BITBUCKET_USERNAME` with
+//! `BITBUCKET_APP_PASSWORD`.
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use worktrunk::git::{Repository, parse_owner_repo};
+
+use super::rest::{self, Auth};
+use super::{CiQuery, CiSource, CiStatus, PrStatus, ReviewState};
+use crate::commands::forge::{Bitbucket, Forge};
+
+/// Bitbucket Cloud API root.
+const API_URL: &str = "https://api.bitbucket.org/2.0";
+
+/// Open pull requests fetched when checking many branches at once (the API's
+/// maximum page size).
+const PRS_PER_PAGE: u8 = 50;
+
+/// Credentials from the environment.
+struct Credentials {
+    token: Option<String>,
+    user: Option<String>,
+    password: Option<String>,
+}
+
+impl Credentials {
+    fn from_env() -> Self {
+        let var = |name| std::env::var(name).ok().filter(|value| !value.is_empty());
+        Self {
+            token: var("BITBUCKET_TOKEN"),
+            user: var("BITBUCKET_USERNAME"),
+            password: var("BITBUCKET_APP_PASSWORD"),
+        }
+    }
+
+    fn auth(&self) -> Auth<'_> {
+        match (&self.token, &self.user, &self.password) {
+            (Some(token), _, _) => Auth::Token {
+                scheme: "Bearer",
+                token,
+            },
+            (None, Some(user), Some(password)) => Auth::Basic { user, password },
+            _ => Auth::Anonymous,
+        }
+    }
+}
+
+/// A repository on Bitbucket Cloud.
+struct BitbucketRepo {
+    /// `<api>/repositories/<workspace>/<slug>`
+    api: String,
+    /// `<workspace>/<slug>`, to tell our PRs from those of forks
+    full_name: String,
+    credentials: Credentials,
+}
+
+impl BitbucketRepo {
+    fn for_repo(repo: &Repository) -> Option<Self> {
+        let url = repo.primary_remote_url()?;
+        let (workspace, slug) = parse_owner_repo(&url)?;
+        Some(Self {
+            api: format!("{API_URL}/repositories/{workspace}/{slug}"),
+            full_name: format!("{workspace}/{slug}"),
+            credentials: Credentials::from_env(),
+        })
+    }
+
+    /// GET `path` under the repository endpoint, as in [`rest::get_json`].
+    fn get<T: DeserializeOwned>(&self, path: &str, branch: &str) -> Result<T, Option<PrStatus>> {
+        rest::get_json(
+            &format!("{}{path}", self.api),
+            &self.credentials.auth(),
+            branch,
+        )
+    }
+
+    /// Open PRs from `branch`, or from any branch when `branch` is `None`.
+    fn open_prs(&self, branch: Option<&str>) -> Result<Vec<BitbucketPr>, Option<PrStatus>> {
+        // Participants carry the review state, but aren't in listings by default
+        let mut path = format!(
+            "/pullrequests?state=OPEN&pagelen={PRS_PER_PAGE}&fields=%2Bvalues.participants"
+        );
+        if let Some(branch) = branch {
+            let filter = format!("source.branch.name = {}", serde_json::Value::from(branch));
+            path.push_str(&format!("&q={}", urlencoding::encode(&filter)));
+        }
+        let page: Page<BitbucketPr> = self.get(&path, branch.unwrap_or("batch"))?;
+        Ok(page.values)
+    }
+
+    /// Aggregate build status, `None` when there are no builds.
+    fn checks(&self, path: &str, branch: &str) -> Result<Option<CiStatus>, Option<PrStatus>> {
+        let page: Page<BuildStatus> = self.get(&format!("{path}?pagelen=100"), branch)?;
+        Ok(aggregate_build_statuses(&page.values))
+    }
+
+    fn pr_status(&self, pr: &BitbucketPr, branch: &str, local_head: &str) -> PrStatus {
+        let ci_status = match self.checks(&format!("/pullrequests/{}/statuses", pr.id), branch) {
+            Ok(status) => status.unwrap_or(CiStatus::NoCI),
+            Err(Some(error)) => return error,
+            Err(None) => CiStatus::NoCI,
+        };
+        // PR listings carry abbreviated commit hashes
+        let is_stale = pr
+            .source
+            .commit
+            .as_ref()
+            .is_none_or(|commit| !local_head.starts_with(&commit.hash));
+        PrStatus {
+            ci_status,
+            source: CiSource::PullRequest,
+            is_stale,
+            url: pr.links.html.as_ref().map(|link| link.href.clone()),
+            review_state: pr.review_state(),
+        }
+    }
+
+    fn commit_status(&self, branch: &str, local_head: &str) -> Option<PrStatus> {
+        let ci_status = match self.checks(&format!("/commit/{local_head}/statuses"), branch) {
+            Ok(status) => status?,
+            Err(status) => return status,
+        };
+        Some(PrStatus {
+            ci_status,
+            source: CiSource::Branch,
+            is_stale: false, // We're querying by SHA, so always current
+            url: None,
+            review_state: None,
+        })
+    }
+
+    /// The PR from `branch` in this repository rather than a fork.
+    fn select_pr<'a>(&self, prs: &'a [BitbucketPr], branch: &str) -> Option<&'a BitbucketPr> {
+        prs.iter().find(|pr| {
+            pr.source.branch.name == branch
+                && pr
+                    .source
+                    .repository
+                    .as_ref()
+                    .is_none_or(|repo| repo.full_name.eq_ignore_ascii_case(&self.full_name))
+        })
+    }
+}
+
+impl Forge for Bitbucket {
+    fn pr_for_branch(&self, repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
+        let bitbucket = BitbucketRepo::for_repo(repo)?;
+        let prs = match bitbucket.open_prs(Some(branch)) {
+            Ok(prs) => prs,
+            Err(status) => return status,
+        };
+        let pr = bitbucket.select_pr(&prs, branch)?;
+        Some(bitbucket.pr_status(pr, branch, local_head))
+    }
+
+    fn ci_status(&self, repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
+        BitbucketRepo::for_repo(repo)?.commit_status(branch, local_head)
+    }
+
+    /// One PR listing for all branches, then each matching PR's builds.
+    fn batch_status(
+        &self,
+        repo: &Repository,
+        queries: &[&CiQuery],
+    ) -> Option<Vec<Option<PrStatus>>> {
+        let bitbucket = BitbucketRepo::for_repo(repo)?;
+        let prs = match bitbucket.open_prs(None) {
+            Ok(prs) => prs,
+            Err(Some(error)) => return Some(queries.iter().map(|_| Some(error.clone())).collect()),
+            Err(None) => return None,
+        };
+        let statuses = queries
+            .iter()
+            .map(|query| match bitbucket.select_pr(&prs, &query.branch) {
+                Some(pr) => Some(bitbucket.pr_status(pr, &query.branch, &query.head)),
+                None if query.has_upstream => bitbucket.commit_status(&query.branch, &query.head),
+                None => None,
+            })
+            .collect();
+        Some(statuses)
+    }
+}
+
+/// One page of a paginated response.
+#[derive(Debug, Deserialize)]
+struct Page<T> {
+    values: Vec<T>,
+}
+
+/// Pull request from `GET /repositories/{workspace}/{slug}/pullrequests`
+#[derive(Debug, Deserialize)]
+struct BitbucketPr {
+    #[serde(default)]
+    id: u64,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    links: PrLinks,
+    #[serde(default)]
+    source: PrSource,
+    #[serde(default)]
+    participants: Vec<Participant>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PrLinks {
+    html: Option<Link>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Link {
+    href: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct PrSource {
+    branch: BranchName,
+    commit: Option<CommitHash>,
+    /// Missing when the source repository was deleted
+    repository: Option<SourceRepository>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BranchName {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct CommitHash {
+    hash: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct SourceRepository {
+    full_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Participant {
+    role: Option<String>,
+    #[serde(default)]
+    approved: bool,
+    /// `approved`, `changes_requested`, or null
+    state: Option<String>,
+}
+
+impl BitbucketPr {
+    fn review_state(&self) -> Option<ReviewState> {
+        if self.draft {
+            return Some(ReviewState::Draft);
+        }
+        let participants = &self.participants;
+        if participants
+            .iter()
+            .any(|p| p.state.as_deref() == Some("changes_requested"))
+        {
+            Some(ReviewState::ChangesRequested)
+        } else if participants.iter().any(|p| p.approved) {
+            Some(ReviewState::Approved)
+        } else if participants
+            .iter()
+            .any(|p| p.role.as_deref() == Some("REVIEWER"))
+        {
+            Some(ReviewState::ReviewRequired)
+        } else {
+            None
+        }
+    }
+}
+
+/// Build status from `GET .../statuses`
+#[derive(Debug, Deserialize)]
+struct BuildStatus {
+    /// `SUCCESSFUL`, `FAILED`, `INPROGRESS`, or `STOPPED`
+    state: String,
+}
+
+/// Aggregate build statuses: any failed → Failed, any running → Running, else Passed.
+fn aggregate_build_statuses(statuses: &[BuildStatus]) -> Option<CiStatus> {
+    if statuses.is_empty() {
+        return None;
+    }
+    let any = |states: &[&str]| statuses.iter().any(|s| states.contains(&s.state.as_str()));
+    Some(if any(&["FAILED", "STOPPED"]) {
+        CiStatus::Failed
+    } else if any(&["INPROGRESS"]) {
+        CiStatus::Running
+    } else {
+        CiStatus::Passed
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn statuses(states: &[&str]) -> Vec<BuildStatus> {
+        states
+            .iter()
+            .map(|state| BuildStatus {
+                state: state.to_string(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_aggregate_build_statuses() {
+        assert_eq!(aggregate_build_statuses(&[]), None);
+        assert_eq!(
+            aggregate_build_statuses(&statuses(&["SUCCESSFUL", "SUCCESSFUL"])),
+            Some(CiStatus::Passed)
+        );
+        assert_eq!(
+            aggregate_build_statuses(&statuses(&["SUCCESSFUL", "INPROGRESS"])),
+            Some(CiStatus::Running)
+        );
+        assert_eq!(
+            aggregate_build_statuses(&statuses(&["INPROGRESS", "STOPPED"])),
+            Some(CiStatus::Failed)
+        );
+    }
+
+    #[test]
+    fn test_review_state() {
+        let pr = |draft, participants: &str| -> BitbucketPr {
+            serde_json::from_str(&format!(
+                r#"{{"id": 1, "draft": {draft}, "participants": {participants},
+                    "source": {{"branch": {{"name": "feature"}}}}}}"#
+            ))
+            .unwrap()
+        };
+        assert_eq!(pr(true, "[]").review_state(), Some(ReviewState::Draft));
+        assert_eq!(pr(false, "[]").review_state(), None);
+        assert_eq!(
+            pr(
+                false,
+                r#"[{"role": "REVIEWER", "approved": false, "state": null}]"#
+            )
+            .review_state(),
+            Some(ReviewState::ReviewRequired)
+        );
+        assert_eq!(
+            pr(
+                false,
+                r#"[{"role": "REVIEWER", "approved": true, "state": "approved"}]"#
+            )
+            .review_state(),
+            Some(ReviewState::Approved)
+        );
+        assert_eq!(
+            pr(
+                false,
+                r#"[{"role": "REVIEWER", "approved": true, "state": "approved"},
+                    {"role": "REVIEWER", "approved": false, "state": "changes_requested"}]"#
+            )
+            .review_state(),
+            Some(ReviewState::ChangesRequested)
+        );
+    }
+}
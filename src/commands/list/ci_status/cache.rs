@@ -114,6 +114,11 @@ impl CachedCiStatus {
         }
     }
 
+    /// Drop the cached CI status for a branch, so the next read fetches it fresh.
+    pub(crate) fn remove(repo: &Repository, branch: &str) {
+        let _ = fs::remove_file(Self::cache_file(repo, branch));
+    }
+
     /// List all cached CI statuses as (branch_name, cached_status) pairs.
     pub(crate) fn list_all(repo: &Repository) -> Vec<(String, Self)> {
         let cache_dir = Self::cache_dir(repo);
@@ -0,0 +1,361 @@
+//! Gitea and Forgejo CI status detection.
+//!
+//! Detects CI status from pull requests and commit statuses through the
+//! REST API (`/api/v1`), called with `curl` (see [`super::rest`]): `tea` has
+//! no JSON output for commit statuses. The host and token come from the user
+//! config `[gitea]` section; the token is only sent to that host.
+
+use std::sync::OnceLock;
+
+use rayon::prelude::*;
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use worktrunk::config::{GiteaConfig, WorktrunkConfig};
+use worktrunk::git::{Repository, parse_owner_repo, parse_remote_host};
+
+use super::rest::{self, Auth};
+use super::{CiQuery, CiSource, CiStatus, PrStatus, ReviewState};
+use crate::commands::forge::{Forge, Gitea};
+
+/// Open pull requests fetched per repository (Gitea's default maximum page size).
+///
+/// The API can't filter by head branch, so the branch is matched client-side
+/// among the most recently updated open PRs.
+const PRS_PER_PAGE: u8 = 50;
+
+/// The `[gitea]` user config, loaded once per process.
+fn config() -> Option<&'static GiteaConfig> {
+    static CONFIG: OnceLock<Option<GiteaConfig>> = OnceLock::new();
+    CONFIG
+        .get_or_init(|| WorktrunkConfig::load().ok().and_then(|config| config.gitea))
+        .as_ref()
+}
+
+/// Split a configured host into its scheme-qualified base URL and bare host.
+fn split_host(host: &str) -> (String, &str) {
+    let host = host.trim_end_matches('/');
+    match host.split_once("://") {
+        Some((_, bare)) => (host.to_string(), bare),
+        None => (format!("https://{host}"), host),
+    }
+}
+
+/// Whether `remote_url` points at the configured `[gitea] host`.
+pub(super) fn is_configured_host(remote_url: &str) -> bool {
+    let Some(host) = config().and_then(|config| config.host.as_deref()) else {
+        return false;
+    };
+    let (_, host) = split_host(host);
+    parse_remote_host(remote_url).is_some_and(|remote| remote.eq_ignore_ascii_case(host))
+}
+
+/// Base URL and credentials for API requests to `host`.
+///
+/// The remote URL and `[ci] platform` come from the repository, so the token
+/// only goes to the host the user configured it for; any other host is
+/// queried anonymously over `https://`.
+fn endpoint<'a>(config: Option<&'a GiteaConfig>, host: &str) -> (String, Auth<'a>) {
+    let Some(config) = config else {
+        return (format!("https://{host}"), Auth::Anonymous);
+    };
+    // The configured host may name a scheme, e.g. `http://` for a local instance
+    match config.host.as_deref().map(split_host) {
+        Some((base, configured)) if configured.eq_ignore_ascii_case(host) => {
+            let auth = match config.token.as_deref() {
+                Some(token) => Auth::Token {
+                    scheme: "token",
+                    token,
+                },
+                None => Auth::Anonymous,
+            };
+            (base, auth)
+        }
+        _ => (format!("https://{host}"), Auth::Anonymous),
+    }
+}
+
+/// A repository on a Gitea instance.
+struct GiteaRepo {
+    /// `<base>/api/v1/repos/<owner>/<name>`
+    api: String,
+    owner: String,
+    auth: Auth<'static>,
+}
+
+impl GiteaRepo {
+    fn for_repo(repo: &Repository) -> Option<Self> {
+        let url = repo.primary_remote_url()?;
+        let host = parse_remote_host(&url)?;
+        let (owner, name) = parse_owner_repo(&url)?;
+        let (base, auth) = endpoint(config(), &host);
+        Some(Self {
+            api: format!("{base}/api/v1/repos/{owner}/{name}"),
+            owner,
+            auth,
+        })
+    }
+
+    /// GET `path` under the repository endpoint, as in [`rest::get_json`].
+    fn get<T: DeserializeOwned>(&self, path: &str, branch: &str) -> Result<T, Option<PrStatus>> {
+        rest::get_json(&format!("{}{path}", self.api), &self.auth, branch)
+    }
+
+    fn open_prs(&self, branch: &str) -> Result<Vec<GiteaPr>, Option<PrStatus>> {
+        self.get(
+            &format!("/pulls?state=open&sort=recentupdate&limit={PRS_PER_PAGE}"),
+            branch,
+        )
+    }
+
+    /// Combined status of a commit's checks, `None` when it has none.
+    fn checks(&self, branch: &str, sha: &str) -> Result<Option<CiStatus>, Option<PrStatus>> {
+        let status: GiteaCombinedStatus = self.get(&format!("/commits/{sha}/status"), branch)?;
+        Ok(status.ci_status())
+    }
+
+    fn pr_status(&self, pr: &GiteaPr, branch: &str, local_head: &str) -> PrStatus {
+        let ci_status = if pr.mergeable == Some(false) {
+            CiStatus::Conflicts
+        } else {
+            match self.checks(branch, &pr.head.sha) {
+                Ok(status) => status.unwrap_or(CiStatus::NoCI),
+                Err(Some(error)) => return error,
+                Err(None) => CiStatus::NoCI,
+            }
+        };
+        PrStatus {
+            ci_status,
+            source: CiSource::PullRequest,
+            is_stale: pr.head.sha != local_head,
+            url: pr.html_url.clone(),
+            review_state: pr.review_state(),
+        }
+    }
+
+    fn commit_status(&self, branch: &str, local_head: &str) -> Option<PrStatus> {
+        let ci_status = match self.checks(branch, local_head) {
+            Ok(status) => status?,
+            Err(status) => return status,
+        };
+        Some(PrStatus {
+            ci_status,
+            source: CiSource::Branch,
+            is_stale: false, // We're querying by SHA, so always current
+            url: None,
+            review_state: None,
+        })
+    }
+}
+
+/// The PR for `branch` whose head is in our origin's repository.
+///
+/// A PR without head repository info (a deleted fork) counts as a match.
+fn select_pr<'a>(prs: &'a [GiteaPr], owner: &str, branch: &str) -> Option<&'a GiteaPr> {
+    prs.iter().find(|pr| {
+        pr.head.ref_name == branch
+            && pr
+                .head
+                .repo
+                .as_ref()
+                .is_none_or(|repo| repo.owner.login.eq_ignore_ascii_case(owner))
+    })
+}
+
+impl Forge for Gitea {
+    fn pr_for_branch(&self, repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
+        let gitea = GiteaRepo::for_repo(repo)?;
+        let prs = match gitea.open_prs(branch) {
+            Ok(prs) => prs,
+            Err(status) => return status,
+        };
+        let pr = select_pr(&prs, &gitea.owner, branch)?;
+        Some(gitea.pr_status(pr, branch, local_head))
+    }
+
+    fn ci_status(&self, repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
+        GiteaRepo::for_repo(repo)?.commit_status(branch, local_head)
+    }
+
+    /// One PR listing for all branches, then each matching PR's checks in parallel.
+    fn batch_status(
+        &self,
+        repo: &Repository,
+        queries: &[&CiQuery],
+    ) -> Option<Vec<Option<PrStatus>>> {
+        let gitea = GiteaRepo::for_repo(repo)?;
+        let prs = match gitea.open_prs("batch") {
+            Ok(prs) => prs,
+            Err(Some(error)) => return Some(queries.iter().map(|_| Some(error.clone())).collect()),
+            Err(None) => return None,
+        };
+        let statuses = queries
+            .par_iter()
+            .map(|query| match select_pr(&prs, &gitea.owner, &query.branch) {
+                Some(pr) => Some(gitea.pr_status(pr, &query.branch, &query.head)),
+                None if query.has_upstream => gitea.commit_status(&query.branch, &query.head),
+                None => None,
+            })
+            .collect();
+        Some(statuses)
+    }
+}
+
+/// Pull request from `GET /repos/{owner}/{repo}/pulls`
+#[derive(Debug, Deserialize)]
+struct GiteaPr {
+    #[serde(default)]
+    title: String,
+    html_url: Option<String>,
+    #[serde(default)]
+    draft: bool,
+    /// `false` when the PR has conflicts with its base
+    mergeable: Option<bool>,
+    head: GiteaPrHead,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaPrHead {
+    #[serde(rename = "ref")]
+    ref_name: String,
+    sha: String,
+    /// Missing when the head repository was deleted
+    repo: Option<GiteaPrRepo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaPrRepo {
+    owner: GiteaUser,
+}
+
+#[derive(Debug, Deserialize)]
+struct GiteaUser {
+    login: String,
+}
+
+impl GiteaPr {
+    /// Drafts are marked by the `draft` flag on newer servers and a `WIP:`
+    /// title prefix on all of them. Reviews need a request per PR, so other
+    /// review states aren't shown.
+    fn review_state(&self) -> Option<ReviewState> {
+        let title = self.title.trim_start().to_ascii_lowercase();
+        (self.draft || title.starts_with("wip:") || title.starts_with("[wip]"))
+            .then_some(ReviewState::Draft)
+    }
+}
+
+/// Combined commit status from `GET /repos/{owner}/{repo}/commits/{sha}/status`
+#[derive(Debug, Deserialize)]
+struct GiteaCombinedStatus {
+    state: Option<String>,
+    #[serde(default)]
+    total_count: u64,
+}
+
+impl GiteaCombinedStatus {
+    fn ci_status(&self) -> Option<CiStatus> {
+        if self.total_count == 0 {
+            return None;
+        }
+        Some(match self.state.as_deref() {
+            Some("success" | "warning") => CiStatus::Passed,
+            Some("failure" | "error") => CiStatus::Failed,
+            _ => CiStatus::Running,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_host() {
+        assert_eq!(
+            split_host("git.example.com"),
+            ("https://git.example.com".to_string(), "git.example.com")
+        );
+        assert_eq!(
+            split_host("http://localhost:3000/"),
+            ("http://localhost:3000".to_string(), "localhost:3000")
+        );
+    }
+
+    #[test]
+    fn test_endpoint_sends_token_only_to_configured_host() {
+        let config = GiteaConfig {
+            host: Some("http://git.example.com".into()),
+            token: Some("secret".into()),
+        };
+        let (base, auth) = endpoint(Some(&config), "git.example.com");
+        assert_eq!(base, "http://git.example.com");
+        assert!(matches!(
+            auth,
+            Auth::Token {
+                token: "secret",
+                ..
+            }
+        ));
+
+        let (base, auth) = endpoint(Some(&config), "gitea.attacker.example");
+        assert_eq!(base, "https://gitea.attacker.example");
+        assert!(matches!(auth, Auth::Anonymous));
+
+        let (_, auth) = endpoint(None, "git.example.com");
+        assert!(matches!(auth, Auth::Anonymous));
+    }
+
+    #[test]
+    fn test_select_pr() {
+        let prs: Vec<GiteaPr> = serde_json::from_str(
+            r#"[
+                {"html_url": "https://git.example.com/fork/repo/pulls/2", "mergeable": true,
+                 "head": {"ref": "feature", "sha": "bbb", "repo": {"owner": {"login": "fork"}}}},
+                {"html_url": "https://git.example.com/owner/repo/pulls/1", "mergeable": true,
+                 "head": {"ref": "feature", "sha": "aaa", "repo": {"owner": {"login": "Owner"}}}}
+            ]"#,
+        )
+        .unwrap();
+        let pr = select_pr(&prs, "owner", "feature").unwrap();
+        assert_eq!(pr.head.sha, "aaa");
+        assert!(select_pr(&prs, "owner", "other").is_none());
+    }
+
+    #[test]
+    fn test_combined_status() {
+        let status = |state: &str, total_count| GiteaCombinedStatus {
+            state: Some(state.into()),
+            total_count,
+        };
+        assert_eq!(status("success", 2).ci_status(), Some(CiStatus::Passed));
+        assert_eq!(status("warning", 1).ci_status(), Some(CiStatus::Passed));
+        assert_eq!(status("failure", 1).ci_status(), Some(CiStatus::Failed));
+        assert_eq!(status("error", 1).ci_status(), Some(CiStatus::Failed));
+        assert_eq!(status("pending", 1).ci_status(), Some(CiStatus::Running));
+        assert_eq!(status("", 0).ci_status(), None);
+    }
+
+    #[test]
+    fn test_review_state() {
+        let pr = |title: &str, draft| GiteaPr {
+            title: title.into(),
+            html_url: None,
+            draft,
+            mergeable: None,
+            head: GiteaPrHead {
+                ref_name: "feature".into(),
+                sha: "aaa".into(),
+                repo: None,
+            },
+        };
+        assert_eq!(
+            pr("WIP: login", false).review_state(),
+            Some(ReviewState::Draft)
+        );
+        assert_eq!(
+            pr("[WIP] login", false).review_state(),
+            Some(ReviewState::Draft)
+        );
+        assert_eq!(pr("login", true).review_state(), Some(ReviewState::Draft));
+        assert_eq!(pr("login", false).review_state(), None);
+    }
+}
@@ -2,13 +2,21 @@
 //!
 //! Detects CI status from GitHub PRs and workflow runs using the `gh` CLI.
 
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::{Context, bail};
+
 use serde::Deserialize;
+use worktrunk::git::pr_ref::PrInfo;
 use worktrunk::git::{Repository, parse_owner_repo, parse_remote_owner};
+use worktrunk::shell_exec::Cmd;
 
 use super::{
-    CiSource, CiStatus, MAX_PRS_TO_FETCH, PrStatus, is_retriable_error, non_interactive_cmd,
-    parse_json,
+    CiQuery, CiSource, CiStatus, MAX_PRS_TO_FETCH, PrStatus, ReviewState, is_retriable_error,
+    non_interactive_cmd, parse_json, run_ci_query,
 };
+use crate::commands::forge::{Forge, GitHub};
 
 /// Get the owner of the origin remote (for GitHub fork detection).
 ///
@@ -44,7 +52,7 @@ fn get_owner_repo(repo: &Repository) -> Option<(String, String)> {
 /// - Fork workflows (PRs from your fork to upstream)
 /// - Organization repos (PRs from org branches)
 /// - Multiple users with same branch name
-pub(super) fn detect_github(repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
+fn detect_github(repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
     let repo_root = repo.current_worktree().root().ok()?;
 
     // Get origin owner for filtering (see parse_remote_owner docs for why)
@@ -59,7 +67,7 @@ pub(super) fn detect_github(repo: &Repository, branch: &str, local_head: &str) -
     //
     // We fetch up to MAX_PRS_TO_FETCH PRs to handle branch name collisions, then filter
     // client-side by headRepositoryOwner to find PRs from our fork.
-    let output = match non_interactive_cmd("gh")
+    let cmd = non_interactive_cmd("gh")
         .args([
             "pr",
             "list",
@@ -70,11 +78,10 @@ pub(super) fn detect_github(repo: &Repository, branch: &str, local_head: &str) -
             "--limit",
             &MAX_PRS_TO_FETCH.to_string(),
             "--json",
-            "headRefOid,mergeStateStatus,statusCheckRollup,url,headRepositoryOwner",
+            "headRefOid,mergeStateStatus,statusCheckRollup,url,headRepositoryOwner,isDraft,reviewDecision",
         ])
-        .current_dir(&repo_root)
-        .run()
-    {
+        .current_dir(&repo_root);
+    let output = match run_ci_query(cmd) {
         Ok(output) => output,
         Err(e) => {
             log::warn!("gh pr list failed to execute for branch {}: {}", branch, e);
@@ -92,56 +99,41 @@ pub(super) fn detect_github(repo: &Repository, branch: &str, local_head: &str) -
 
     // gh pr list returns an array - find the first PR from our origin
     let pr_list: Vec<GitHubPrInfo> = parse_json(&output.stdout, "gh pr list", branch)?;
+    let pr_info = select_pr(&pr_list, origin_owner.as_deref(), branch)?;
+    Some(pr_info.pr_status(local_head))
+}
 
-    // Filter to PRs from our origin (case-insensitive comparison for GitHub usernames).
-    // If headRepositoryOwner is missing (older GH CLI, Enterprise, or permissions),
-    // treat it as a potential match to avoid false negatives.
-    let pr_info = if let Some(ref owner) = origin_owner {
-        let matched = pr_list.iter().find(|pr| {
-            pr.head_repository_owner
-                .as_ref()
-                .map(|h| h.login.eq_ignore_ascii_case(owner))
-                .unwrap_or(true) // Missing owner field = potential match
-        });
-        if matched.is_none() && !pr_list.is_empty() {
-            log::debug!(
-                "Found {} PRs for branch {} but none from origin owner {}",
-                pr_list.len(),
-                branch,
-                owner
-            );
-        }
-        matched
-    } else {
+/// Pick the PR whose head branch comes from our origin.
+///
+/// Compares `headRepositoryOwner` case-insensitively, since GitHub usernames
+/// are. If the owner field is missing (older GH CLI, Enterprise, or
+/// permissions), the PR counts as a match to avoid false negatives.
+fn select_pr<'a>(
+    pr_list: &'a [GitHubPrInfo],
+    origin_owner: Option<&str>,
+    branch: &str,
+) -> Option<&'a GitHubPrInfo> {
+    let Some(owner) = origin_owner else {
         // If we can't determine origin owner, fall back to first open PR
         // This is less accurate but better than nothing
+        log::debug!("No origin owner, using first open PR for branch {}", branch);
+        return pr_list.first();
+    };
+    let matched = pr_list.iter().find(|pr| {
+        pr.head_repository_owner
+            .as_ref()
+            .map(|h| h.login.eq_ignore_ascii_case(owner))
+            .unwrap_or(true) // Missing owner field = potential match
+    });
+    if matched.is_none() && !pr_list.is_empty() {
         log::debug!(
-            "No origin owner for {}, using first open PR for branch {}",
-            repo_root.display(),
-            branch
+            "Found {} PRs for branch {} but none from origin owner {}",
+            pr_list.len(),
+            branch,
+            owner
         );
-        pr_list.first()
-    }?;
-
-    // Determine CI status using priority: conflicts > running > failed > passed > no_ci
-    let ci_status = if pr_info.merge_state_status.as_deref() == Some("DIRTY") {
-        CiStatus::Conflicts
-    } else {
-        pr_info.ci_status()
-    };
-
-    let is_stale = pr_info
-        .head_ref_oid
-        .as_ref()
-        .map(|pr_head| pr_head != local_head)
-        .unwrap_or(false);
-
-    Some(PrStatus {
-        ci_status,
-        source: CiSource::PullRequest,
-        is_stale,
-        url: pr_info.url.clone(),
-    })
+    }
+    matched
 }
 
 /// Detect CI status for a commit using GitHub's check-runs API.
@@ -149,21 +141,21 @@ pub(super) fn detect_github(repo: &Repository, branch: &str, local_head: &str) -
 /// This queries all check runs for the commit SHA, giving us the same data
 /// that `statusCheckRollup` provides for PRs. This correctly aggregates
 /// status across multiple workflows (e.g., `ci` and `publish-docs`).
-pub(super) fn detect_github_commit_checks(repo: &Repository, local_head: &str) -> Option<PrStatus> {
+fn detect_github_commit_checks(repo: &Repository, local_head: &str) -> Option<PrStatus> {
     let repo_root = repo.current_worktree().root().ok()?;
     let (owner, repo_name) = get_owner_repo(repo)?;
 
     // Use GitHub's check-runs API to get all checks for this commit
-    let output = match non_interactive_cmd("gh")
-        .args([
-            "api",
-            &format!("repos/{owner}/{repo_name}/commits/{local_head}/check-runs"),
-            "--jq",
-            ".check_runs | map({status, conclusion})",
-        ])
-        .current_dir(&repo_root)
-        .run()
-    {
+    let output = match run_ci_query(
+        non_interactive_cmd("gh")
+            .args([
+                "api",
+                &format!("repos/{owner}/{repo_name}/commits/{local_head}/check-runs"),
+                "--jq",
+                ".check_runs | map({status, conclusion})",
+            ])
+            .current_dir(&repo_root),
+    ) {
         Ok(output) => output,
         Err(e) => {
             log::warn!(
@@ -197,9 +189,349 @@ pub(super) fn detect_github_commit_checks(repo: &Repository, local_head: &str) -
         source: CiSource::Branch,
         is_stale: false, // We're querying by SHA, so always current
         url: None,
+        review_state: None,
     })
 }
 
+impl Forge for GitHub {
+    fn pr_for_branch(&self, repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
+        detect_github(repo, branch, local_head)
+    }
+
+    fn ci_status(&self, repo: &Repository, _branch: &str, local_head: &str) -> Option<PrStatus> {
+        detect_github_commit_checks(repo, local_head)
+    }
+
+    fn batch_status(
+        &self,
+        repo: &Repository,
+        queries: &[&CiQuery],
+    ) -> Option<Vec<Option<PrStatus>>> {
+        detect_github_batch(repo, queries)
+    }
+}
+
+impl GitHub {
+    /// Look up a PR by number, for `pr:<number>` (see [`worktrunk::git::pr_ref`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - `gh` is not installed or not authenticated
+    /// - The PR doesn't exist
+    /// - The JSON response is malformed
+    pub(crate) fn pr_info(&self, pr_number: u32, repo_root: &Path) -> anyhow::Result<PrInfo> {
+        let output = match Cmd::new("gh")
+            .args([
+                "pr",
+                "view",
+                &pr_number.to_string(),
+                "--json",
+                "headRefName,headRepository,headRepositoryOwner,isCrossRepository,url",
+            ])
+            .current_dir(repo_root)
+            .env("GH_PROMPT_DISABLED", "1")
+            .run()
+        {
+            Ok(output) => output,
+            Err(e) => {
+                // Check if gh is not installed (OS error for command not found)
+                let error_str = e.to_string();
+                if error_str.contains("No such file")
+                    || error_str.contains("not found")
+                    || error_str.contains("cannot find")
+                {
+                    bail!("GitHub CLI (gh) not installed; install from https://cli.github.com/");
+                }
+                return Err(anyhow::Error::from(e).context("Failed to run gh pr view"));
+            }
+        };
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let stderr_lower = stderr.to_lowercase();
+
+            // PR not found (various phrasings across gh versions)
+            if stderr_lower.contains("could not resolve")
+                || stderr_lower.contains("not found")
+                || stderr_lower.contains("no pull request")
+            {
+                bail!("PR #{} not found", pr_number);
+            }
+
+            // Authentication errors
+            if stderr_lower.contains("authentication")
+                || stderr_lower.contains("logged in")
+                || stderr_lower.contains("auth login")
+                || stderr_lower.contains("not logged")
+            {
+                bail!("GitHub CLI not authenticated; run gh auth login");
+            }
+
+            // Rate limiting
+            if stderr_lower.contains("rate limit") || stderr_lower.contains("api rate") {
+                bail!("GitHub API rate limit exceeded; wait a few minutes and retry");
+            }
+
+            // Network errors
+            if stderr_lower.contains("network")
+                || stderr_lower.contains("connection")
+                || stderr_lower.contains("timeout")
+            {
+                bail!("Network error connecting to GitHub; check your internet connection");
+            }
+
+            bail!("gh pr view failed: {}", stderr.trim());
+        }
+
+        let response: GhPrResponse = serde_json::from_slice(&output.stdout).with_context(|| {
+            format!(
+                "Failed to parse gh pr view JSON for PR #{}. \
+                 This may indicate a gh version incompatibility or GitHub API change.",
+                pr_number
+            )
+        })?;
+
+        // Validate required fields are not empty
+        if response.head_ref_name.is_empty() {
+            bail!(
+                "PR #{} has empty branch name; the PR may be in an invalid state",
+                pr_number
+            );
+        }
+
+        Ok(PrInfo {
+            number: pr_number,
+            head_ref_name: response.head_ref_name,
+            head_owner: response.head_repository_owner.login,
+            head_repo: response.head_repository.name,
+            is_cross_repository: response.is_cross_repository,
+            url: response.url,
+        })
+    }
+}
+
+/// Raw JSON response from `gh pr view`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GhPrResponse {
+    head_ref_name: String,
+    head_repository: GhRepository,
+    head_repository_owner: GhOwner,
+    is_cross_repository: bool,
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhRepository {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GhOwner {
+    login: String,
+}
+
+/// Branches per GraphQL query.
+///
+/// Each branch asks for up to `MAX_PRS_TO_FETCH` PRs with their checks;
+/// larger batches risk GitHub's per-query node limit.
+pub(super) const GRAPHQL_BATCH_SIZE: usize = 25;
+
+/// Fields requested for each PR and commit in [`detect_github_batch`].
+///
+/// Mirrors the `gh pr list --json` fields in [`detect_github`]; check
+/// contexts have the same `status`/`conclusion`/`state` fields as
+/// `statusCheckRollup` there.
+const GRAPHQL_FRAGMENTS: &str = r#"
+fragment checks on Commit {
+  statusCheckRollup {
+    contexts(first: 100) {
+      nodes {
+        ... on CheckRun { status conclusion }
+        ... on StatusContext { state }
+      }
+    }
+  }
+}
+fragment pr on PullRequest {
+  headRefOid
+  mergeStateStatus
+  url
+  isDraft
+  reviewDecision
+  headRepositoryOwner { login }
+  commits(last: 1) { nodes { commit { ...checks } } }
+}
+"#;
+
+/// Detect CI status for many branches with one `gh api graphql` call.
+///
+/// Each branch gets the same answer as [`detect_github`], falling back to
+/// its commit's checks when it has no PR and `has_upstream` is set (like
+/// [`detect_github_commit_checks`]). `{owner}` and `{repo}` are filled in by
+/// `gh`, so forks resolve to the same repository `gh pr list` uses.
+///
+/// Returns `None` if the query fails for a reason retrying per branch might
+/// avoid (an old `gh`, an unexpected response). Retriable failures (rate
+/// limits, network) mark every branch as an error instead, since per-branch
+/// queries would only hit the same limit once per branch.
+fn detect_github_batch(repo: &Repository, queries: &[&CiQuery]) -> Option<Vec<Option<PrStatus>>> {
+    let repo_root = repo.current_worktree().root().ok()?;
+    let query = graphql_query(queries);
+    let cmd = non_interactive_cmd("gh")
+        .args([
+            "api",
+            "graphql",
+            "-F",
+            "owner={owner}",
+            "-F",
+            "name={repo}",
+            "-f",
+            &format!("query={query}"),
+        ])
+        .current_dir(&repo_root);
+    let output = match run_ci_query(cmd) {
+        Ok(output) => output,
+        Err(e) => {
+            log::warn!("gh api graphql failed to execute: {}", e);
+            return None;
+        }
+    };
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if is_retriable_error(&stderr) {
+            return Some(queries.iter().map(|_| Some(PrStatus::error())).collect());
+        }
+        log::debug!("gh api graphql failed: {}", stderr.trim());
+        return None;
+    }
+
+    let response: GraphQlResponse = parse_json(&output.stdout, "gh api graphql", "batch")?;
+    let mut repository = response.data?.repository?;
+    let origin_owner = get_origin_owner(repo);
+
+    let statuses = queries
+        .iter()
+        .enumerate()
+        .map(|(i, query)| {
+            let prs: Vec<GitHubPrInfo> = repository
+                .remove(&format!("pr{i}"))
+                .and_then(|value| serde_json::from_value::<GraphQlNodes<GraphQlPr>>(value).ok())
+                .map(|prs| prs.nodes.into_iter().map(GitHubPrInfo::from).collect())
+                .unwrap_or_default();
+            if let Some(pr_info) = select_pr(&prs, origin_owner.as_deref(), &query.branch) {
+                return Some(pr_info.pr_status(&query.head));
+            }
+
+            let commit: GraphQlCommit = repository
+                .remove(&format!("commit{i}"))
+                .and_then(|value| serde_json::from_value(value).ok())?;
+            let checks = commit.status_check_rollup?.contexts.nodes;
+            if checks.is_empty() {
+                return None;
+            }
+            Some(PrStatus {
+                ci_status: aggregate_github_checks(&checks),
+                source: CiSource::Branch,
+                is_stale: false,
+                url: None,
+                review_state: None,
+            })
+        })
+        .collect();
+    Some(statuses)
+}
+
+/// One aliased field per branch: `pr<i>` for its open PRs, and `commit<i>`
+/// for its head commit when the branch has an upstream.
+fn graphql_query(queries: &[&CiQuery]) -> String {
+    let mut fields = String::new();
+    for (i, query) in queries.iter().enumerate() {
+        // A JSON string literal is also a valid GraphQL one
+        let branch = serde_json::Value::from(query.branch.as_str());
+        fields.push_str(&format!(
+            "    pr{i}: pullRequests(headRefName: {branch}, states: OPEN, first: {MAX_PRS_TO_FETCH}) {{ nodes {{ ...pr }} }}\n"
+        ));
+        if query.has_upstream {
+            fields.push_str(&format!(
+                "    commit{i}: object(oid: \"{}\") {{ ... on Commit {{ ...checks }} }}\n",
+                query.head
+            ));
+        }
+    }
+    format!(
+        "query($owner: String!, $name: String!) {{\n  repository(owner: $owner, name: $name) {{\n{fields}  }}\n}}\n{GRAPHQL_FRAGMENTS}"
+    )
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse {
+    data: Option<GraphQlData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlData {
+    /// Aliased fields from [`graphql_query`]
+    repository: Option<HashMap<String, serde_json::Value>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlNodes<T> {
+    nodes: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlPr {
+    head_ref_oid: Option<String>,
+    merge_state_status: Option<String>,
+    url: Option<String>,
+    #[serde(default)]
+    is_draft: bool,
+    review_decision: Option<String>,
+    head_repository_owner: Option<HeadRepositoryOwner>,
+    commits: GraphQlNodes<GraphQlCommitNode>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlCommitNode {
+    commit: GraphQlCommit,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct GraphQlCommit {
+    status_check_rollup: Option<GraphQlRollup>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlRollup {
+    contexts: GraphQlNodes<GitHubCheck>,
+}
+
+impl From<GraphQlPr> for GitHubPrInfo {
+    fn from(pr: GraphQlPr) -> Self {
+        let status_check_rollup = pr
+            .commits
+            .nodes
+            .into_iter()
+            .next()
+            .and_then(|node| node.commit.status_check_rollup)
+            .map(|rollup| rollup.contexts.nodes);
+        Self {
+            head_ref_oid: pr.head_ref_oid,
+            merge_state_status: pr.merge_state_status,
+            status_check_rollup,
+            url: pr.url,
+            head_repository_owner: pr.head_repository_owner,
+            is_draft: pr.is_draft,
+            review_decision: pr.review_decision,
+        }
+    }
+}
+
 /// GitHub PR info from `gh pr list --json ...`
 ///
 /// Note: We include `headRepositoryOwner` for client-side filtering by source fork.
@@ -219,6 +551,12 @@ pub(super) struct GitHubPrInfo {
     /// Used to filter PRs by source fork (see [`parse_remote_owner`]).
     #[serde(rename = "headRepositoryOwner")]
     pub head_repository_owner: Option<HeadRepositoryOwner>,
+    #[serde(rename = "isDraft", default)]
+    pub is_draft: bool,
+    /// "APPROVED", "CHANGES_REQUESTED", "REVIEW_REQUIRED", or empty when
+    /// the repo doesn't require reviews
+    #[serde(rename = "reviewDecision", default)]
+    pub review_decision: Option<String>,
 }
 
 /// Owner info for the head repository of a PR.
@@ -252,6 +590,44 @@ pub(super) struct GitHubCheck {
 }
 
 impl GitHubPrInfo {
+    /// Status for the PR, stale if its head isn't `local_head`.
+    ///
+    /// CI status priority: conflicts > running > failed > passed > no_ci.
+    fn pr_status(&self, local_head: &str) -> PrStatus {
+        let ci_status = if self.merge_state_status.as_deref() == Some("DIRTY") {
+            CiStatus::Conflicts
+        } else {
+            self.ci_status()
+        };
+        let is_stale = self
+            .head_ref_oid
+            .as_ref()
+            .is_some_and(|pr_head| pr_head != local_head);
+
+        PrStatus {
+            ci_status,
+            source: CiSource::PullRequest,
+            is_stale,
+            url: self.url.clone(),
+            review_state: Some(self.review_state()),
+        }
+    }
+
+    /// Review state: draft wins, then the review decision.
+    ///
+    /// Repos without required reviews report no decision; an open PR there
+    /// is still waiting on review until someone approves it.
+    pub fn review_state(&self) -> ReviewState {
+        if self.is_draft {
+            return ReviewState::Draft;
+        }
+        match self.review_decision.as_deref() {
+            Some("APPROVED") => ReviewState::Approved,
+            Some("CHANGES_REQUESTED") => ReviewState::ChangesRequested,
+            _ => ReviewState::ReviewRequired,
+        }
+    }
+
     pub fn ci_status(&self) -> CiStatus {
         match &self.status_check_rollup {
             None => CiStatus::NoCI,
@@ -335,6 +711,8 @@ mod tests {
             status_check_rollup: None,
             url: None,
             head_repository_owner: None,
+            is_draft: false,
+            review_decision: None,
         };
         assert_eq!(pr.ci_status(), CiStatus::NoCI);
 
@@ -345,6 +723,8 @@ mod tests {
             status_check_rollup: Some(vec![]),
             url: None,
             head_repository_owner: None,
+            is_draft: false,
+            review_decision: None,
         };
         assert_eq!(pr.ci_status(), CiStatus::NoCI);
 
@@ -360,6 +740,8 @@ mod tests {
                 }]),
                 url: None,
                 head_repository_owner: None,
+                is_draft: false,
+                review_decision: None,
             };
             assert_eq!(pr.ci_status(), CiStatus::Running, "status={status}");
         }
@@ -375,6 +757,8 @@ mod tests {
             }]),
             url: None,
             head_repository_owner: None,
+            is_draft: false,
+            review_decision: None,
         };
         assert_eq!(pr.ci_status(), CiStatus::Running);
 
@@ -390,6 +774,8 @@ mod tests {
                 }]),
                 url: None,
                 head_repository_owner: None,
+                is_draft: false,
+                review_decision: None,
             };
             assert_eq!(pr.ci_status(), CiStatus::Failed, "conclusion={conclusion}");
         }
@@ -406,6 +792,8 @@ mod tests {
                 }]),
                 url: None,
                 head_repository_owner: None,
+                is_draft: false,
+                review_decision: None,
             };
             assert_eq!(pr.ci_status(), CiStatus::Failed, "state={state}");
         }
@@ -421,10 +809,31 @@ mod tests {
             }]),
             url: None,
             head_repository_owner: None,
+            is_draft: false,
+            review_decision: None,
         };
         assert_eq!(pr.ci_status(), CiStatus::Passed);
     }
 
+    #[test]
+    fn test_github_pr_info_review_state() {
+        let pr: GitHubPrInfo = serde_json::from_str(r#"{"reviewDecision":"APPROVED"}"#).unwrap();
+        assert_eq!(pr.review_state(), ReviewState::Approved);
+
+        let pr: GitHubPrInfo =
+            serde_json::from_str(r#"{"reviewDecision":"CHANGES_REQUESTED"}"#).unwrap();
+        assert_eq!(pr.review_state(), ReviewState::ChangesRequested);
+
+        // No decision (reviews not required) still waits on review
+        let pr: GitHubPrInfo = serde_json::from_str(r#"{"reviewDecision":""}"#).unwrap();
+        assert_eq!(pr.review_state(), ReviewState::ReviewRequired);
+
+        // Drafts aren't ready for review, whatever the decision
+        let pr: GitHubPrInfo =
+            serde_json::from_str(r#"{"isDraft":true,"reviewDecision":"APPROVED"}"#).unwrap();
+        assert_eq!(pr.review_state(), ReviewState::Draft);
+    }
+
     #[test]
     fn test_aggregate_github_checks() {
         // Helper to create a check without state field (like check-runs API)
@@ -509,4 +918,47 @@ mod tests {
         }];
         assert_eq!(aggregate_github_checks(&checks), CiStatus::Failed);
     }
+
+    #[test]
+    fn test_graphql_query() {
+        let queries = [
+            CiQuery {
+                branch: "feature".into(),
+                head: "abc123".into(),
+                has_upstream: true,
+            },
+            CiQuery {
+                branch: r#"odd"name"#.into(),
+                head: "def456".into(),
+                has_upstream: false,
+            },
+        ];
+        let query = graphql_query(&queries.iter().collect::<Vec<_>>());
+        assert!(query.contains(r#"pr0: pullRequests(headRefName: "feature", states: OPEN"#));
+        assert!(query.contains(r#"commit0: object(oid: "abc123")"#));
+        assert!(query.contains(r#"pr1: pullRequests(headRefName: "odd\"name", states: OPEN"#));
+        assert!(!query.contains("commit1"));
+        assert!(query.contains("fragment pr on PullRequest"));
+    }
+
+    #[test]
+    fn test_graphql_pr_into_pr_info() {
+        let json = r#"{"nodes": [{
+            "headRefOid": "abc123",
+            "mergeStateStatus": "CLEAN",
+            "url": "https://github.com/owner/repo/pull/1",
+            "isDraft": false,
+            "reviewDecision": "APPROVED",
+            "headRepositoryOwner": {"login": "owner"},
+            "commits": {"nodes": [{"commit": {"statusCheckRollup": {"contexts": {"nodes": [
+                {"status": "COMPLETED", "conclusion": "SUCCESS"},
+                {"state": "PENDING"}
+            ]}}}}]}
+        }]}"#;
+        let prs: GraphQlNodes<GraphQlPr> = serde_json::from_str(json).unwrap();
+        let pr = GitHubPrInfo::from(prs.nodes.into_iter().next().unwrap());
+        assert_eq!(pr.ci_status(), CiStatus::Running);
+        assert_eq!(pr.head_ref_oid.as_deref(), Some("abc123"));
+        assert_eq!(pr.review_decision.as_deref(), Some("APPROVED"));
+    }
 }
@@ -7,9 +7,10 @@ use worktrunk::git::Repository;
 use worktrunk::shell_exec::Cmd;
 
 use super::{
-    CiSource, CiStatus, MAX_PRS_TO_FETCH, PrStatus, is_retriable_error, non_interactive_cmd,
-    parse_json, tool_available,
+    CiSource, CiStatus, MAX_PRS_TO_FETCH, PrStatus, ReviewState, is_retriable_error,
+    non_interactive_cmd, parse_json, run_ci_query, tool_available,
 };
+use crate::commands::forge::{Forge, GitLab};
 
 /// Get the GitLab project ID for a repository.
 ///
@@ -62,7 +63,7 @@ fn get_gitlab_project_id(repo: &Repository) -> Option<u64> {
 /// 1. Get the current project ID via `glab repo view`
 /// 2. Fetch all open MRs with matching branch name (up to 20)
 /// 3. Filter client-side by comparing `source_project_id` to our project ID
-pub(super) fn detect_gitlab(repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
+fn detect_gitlab(repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
     if !tool_available("glab", &["--version"]) {
         return None;
     }
@@ -77,20 +78,20 @@ pub(super) fn detect_gitlab(repo: &Repository, branch: &str, local_head: &str) -
 
     // Fetch MRs with matching source branch.
     // We filter client-side by source_project_id (numeric project ID comparison).
-    let output = match Cmd::new("glab")
-        .args([
-            "mr",
-            "list",
-            "--source-branch",
-            branch,
-            "--state=opened",
-            &format!("--per-page={}", MAX_PRS_TO_FETCH),
-            "--output",
-            "json",
-        ])
-        .current_dir(&repo_root)
-        .run()
-    {
+    let output = match run_ci_query(
+        Cmd::new("glab")
+            .args([
+                "mr",
+                "list",
+                "--source-branch",
+                branch,
+                "--state=opened",
+                &format!("--per-page={}", MAX_PRS_TO_FETCH),
+                "--output",
+                "json",
+            ])
+            .current_dir(&repo_root),
+    ) {
         Ok(output) => output,
         Err(e) => {
             log::warn!(
@@ -158,21 +159,22 @@ pub(super) fn detect_gitlab(repo: &Repository, branch: &str, local_head: &str) -
         source: CiSource::PullRequest,
         is_stale,
         url: mr_info.web_url.clone(),
+        review_state: mr_info.review_state(),
     })
 }
 
 /// Detect GitLab pipeline status for a branch (when no MR exists).
-pub(super) fn detect_gitlab_pipeline(branch: &str, local_head: &str) -> Option<PrStatus> {
+fn detect_gitlab_pipeline(branch: &str, local_head: &str) -> Option<PrStatus> {
     if !tool_available("glab", &["--version"]) {
         return None;
     }
 
     // Get most recent pipeline for the branch using JSON output
-    let output = match Cmd::new("glab")
-        .args(["ci", "list", "--per-page", "1", "--output", "json"])
-        .env("BRANCH", branch) // glab ci list uses BRANCH env var
-        .run()
-    {
+    let output = match run_ci_query(
+        Cmd::new("glab")
+            .args(["ci", "list", "--per-page", "1", "--output", "json"])
+            .env("BRANCH", branch), // glab ci list uses BRANCH env var
+    ) {
         Ok(output) => output,
         Err(e) => {
             log::warn!(
@@ -205,9 +207,20 @@ pub(super) fn detect_gitlab_pipeline(branch: &str, local_head: &str) -> Option<P
         source: CiSource::Branch,
         is_stale,
         url: pipeline.web_url.clone(),
+        review_state: None,
     })
 }
 
+impl Forge for GitLab {
+    fn pr_for_branch(&self, repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
+        detect_gitlab(repo, branch, local_head)
+    }
+
+    fn ci_status(&self, _repo: &Repository, branch: &str, local_head: &str) -> Option<PrStatus> {
+        detect_gitlab_pipeline(branch, local_head)
+    }
+}
+
 /// GitLab MR info from `glab mr list --output json`
 ///
 /// Note: We include `source_project_id` for client-side filtering by source project.
@@ -224,9 +237,28 @@ pub(super) struct GitLabMrInfo {
     pub source_project_id: Option<u64>,
     /// URL to the MR page for clickable links
     pub web_url: Option<String>,
+    #[serde(default)]
+    pub draft: bool,
 }
 
 impl GitLabMrInfo {
+    /// Review state from the draft flag and `detailed_merge_status`.
+    ///
+    /// The MR list doesn't include approvals, so an approved MR is only
+    /// recognizable once nothing else blocks it (`mergeable`).
+    pub fn review_state(&self) -> Option<ReviewState> {
+        if self.draft {
+            return Some(ReviewState::Draft);
+        }
+        match self.detailed_merge_status.as_deref()? {
+            "draft_status" => Some(ReviewState::Draft),
+            "not_approved" => Some(ReviewState::ReviewRequired),
+            "requested_changes" => Some(ReviewState::ChangesRequested),
+            "mergeable" => Some(ReviewState::Approved),
+            _ => None,
+        }
+    }
+
     pub fn ci_status(&self) -> CiStatus {
         self.head_pipeline
             .as_ref()
@@ -305,6 +337,38 @@ mod tests {
         assert_eq!(parse_gitlab_status(Some("unknown")), CiStatus::NoCI);
     }
 
+    #[test]
+    fn test_gitlab_mr_info_review_state() {
+        let mr = |draft: bool, status: Option<&str>| GitLabMrInfo {
+            sha: "abc".into(),
+            has_conflicts: false,
+            detailed_merge_status: status.map(Into::into),
+            head_pipeline: None,
+            pipeline: None,
+            source_project_id: None,
+            web_url: None,
+            draft,
+        };
+        assert_eq!(
+            mr(true, Some("mergeable")).review_state(),
+            Some(ReviewState::Draft)
+        );
+        assert_eq!(
+            mr(false, Some("not_approved")).review_state(),
+            Some(ReviewState::ReviewRequired)
+        );
+        assert_eq!(
+            mr(false, Some("requested_changes")).review_state(),
+            Some(ReviewState::ChangesRequested)
+        );
+        assert_eq!(
+            mr(false, Some("mergeable")).review_state(),
+            Some(ReviewState::Approved)
+        );
+        assert_eq!(mr(false, Some("ci_still_running")).review_state(), None);
+        assert_eq!(mr(false, None).review_state(), None);
+    }
+
     #[test]
     fn test_gitlab_mr_info_ci_status() {
         // No pipeline = NoCI
@@ -316,6 +380,7 @@ mod tests {
             pipeline: None,
             source_project_id: None,
             web_url: None,
+            draft: false,
         };
         assert_eq!(mr.ci_status(), CiStatus::NoCI);
 
@@ -336,6 +401,7 @@ mod tests {
             }),
             source_project_id: None,
             web_url: None,
+            draft: false,
         };
         assert_eq!(mr.ci_status(), CiStatus::Passed);
 
@@ -352,6 +418,7 @@ mod tests {
             }),
             source_project_id: None,
             web_url: None,
+            draft: false,
         };
         assert_eq!(mr.ci_status(), CiStatus::Running);
     }
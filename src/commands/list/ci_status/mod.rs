@@ -1,18 +1,27 @@
-//! CI status detection for GitHub and GitLab.
+//! CI status detection for GitHub, GitLab, Gitea, and Bitbucket.
 //!
 //! This module provides CI status detection by querying GitHub PRs/workflows
-//! and GitLab MRs/pipelines using their respective CLI tools (`gh` and `glab`).
+//! and GitLab MRs/pipelines using their respective CLI tools (`gh` and `glab`),
+//! and Gitea/Forgejo and Bitbucket Cloud PRs and commit statuses through their
+//! REST APIs.
 
+mod bitbucket;
 mod cache;
+mod gitea;
 mod github;
 mod gitlab;
 mod platform;
+mod rest;
+
+use std::process::{ExitStatus, Output};
 
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
 use worktrunk::git::Repository;
 use worktrunk::shell_exec::Cmd;
 use worktrunk::utils::get_now;
 
+use crate::commands::forge::{Forge, GitHub, GitLab, forge_for, forge_for_repo};
+
 // Re-export public types
 pub(crate) use cache::CachedCiStatus;
 pub use platform::{CiPlatform, get_platform_for_repo};
@@ -46,6 +55,71 @@ fn non_interactive_cmd(program: &str) -> Cmd {
         .env("GH_PROMPT_DISABLED", "1")
 }
 
+/// Simulated CI fetch failure, selected by `WORKTRUNK_TEST_FAULT`.
+///
+/// Lets integration tests exercise the degradation paths users hit in practice
+/// without a real network: `ci-timeout`, `ci-rate-limit`, `ci-malformed-json`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CiFault {
+    Timeout,
+    RateLimit,
+    MalformedJson,
+}
+
+impl CiFault {
+    fn from_env() -> Option<Self> {
+        Self::parse(&std::env::var("WORKTRUNK_TEST_FAULT").ok()?)
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "ci-timeout" => Some(Self::Timeout),
+            "ci-rate-limit" => Some(Self::RateLimit),
+            "ci-malformed-json" => Some(Self::MalformedJson),
+            _ => None,
+        }
+    }
+
+    /// The output `gh`/`glab` produce for this failure.
+    fn output(self) -> Output {
+        let (code, stdout, stderr): (i32, &str, &str) = match self {
+            Self::Timeout => (
+                1,
+                "",
+                "Post \"https://api.github.com/graphql\": net/http: request canceled \
+                 (Client.Timeout exceeded while awaiting headers)\n",
+            ),
+            Self::RateLimit => (1, "", "HTTP 403: API rate limit exceeded for user ID 1.\n"),
+            Self::MalformedJson => (0, "[{\"headRefOid\": ", ""),
+        };
+        Output {
+            status: exit_status(code),
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: stderr.as_bytes().to_vec(),
+        }
+    }
+}
+
+#[cfg(unix)]
+fn exit_status(code: i32) -> ExitStatus {
+    std::os::unix::process::ExitStatusExt::from_raw(code << 8)
+}
+
+#[cfg(windows)]
+fn exit_status(code: i32) -> ExitStatus {
+    std::os::windows::process::ExitStatusExt::from_raw(code as u32)
+}
+
+/// Run a CI status query, substituting a simulated failure when
+/// `WORKTRUNK_TEST_FAULT` selects one.
+fn run_ci_query(cmd: Cmd) -> std::io::Result<Output> {
+    if let Some(fault) = CiFault::from_env() {
+        log::debug!("Simulating CI fault {:?}", fault);
+        return Ok(fault.output());
+    }
+    cmd.run()
+}
+
 /// Check if a CLI tool is available
 ///
 /// On Windows, CreateProcessW (via Cmd) searches PATH for .exe files.
@@ -157,6 +231,47 @@ pub enum CiSource {
     Branch,
 }
 
+/// Review state of an open PR/MR
+///
+/// Shown after the CI indicator:
+/// - Draft: ◌ dimmed (not ready for review)
+/// - ReviewRequired: ○ yellow (waiting on reviewers)
+/// - ChangesRequested: ✎ red (waiting on the author)
+/// - Approved: ✓ green
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::IntoStaticStr)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum ReviewState {
+    Draft,
+    ReviewRequired,
+    ChangesRequested,
+    Approved,
+}
+
+impl ReviewState {
+    /// Symbol and style shown after the CI indicator.
+    pub fn indicator(&self) -> (&'static str, anstyle::Style) {
+        use anstyle::{AnsiColor, Color, Style};
+        let fg = |color| Style::new().fg_color(Some(Color::Ansi(color)));
+        match self {
+            Self::Draft => ("◌", Style::new().dimmed()),
+            Self::ReviewRequired => ("○", fg(AnsiColor::Yellow)),
+            Self::ChangesRequested => ("✎", fg(AnsiColor::Red)),
+            Self::Approved => ("✓", fg(AnsiColor::Green)),
+        }
+    }
+}
+
+/// A branch to fetch CI status for with [`PrStatus::prefetch`].
+#[derive(Debug, Clone)]
+pub struct CiQuery {
+    pub branch: String,
+    /// Local HEAD commit of the branch
+    pub head: String,
+    /// Whether to fall back to the commit's checks when there's no PR
+    pub has_upstream: bool,
+}
+
 /// CI status from PR/MR or branch workflow
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PrStatus {
@@ -168,6 +283,9 @@ pub struct PrStatus {
     /// URL to the PR/MR (if available)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+    /// Review state of the PR/MR (absent for branch CI)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub review_state: Option<ReviewState>,
 }
 
 impl CiStatus {
@@ -220,9 +338,10 @@ impl PrStatus {
     ///
     /// When `include_link` is false, the indicator is colored but not clickable.
     /// Used for environments that don't support OSC 8 hyperlinks (e.g., Claude Code).
+    /// The review state, if any, follows the CI indicator.
     pub fn format_indicator(&self, include_link: bool) -> String {
         let indicator = self.indicator();
-        if include_link && self.url.is_some() {
+        let mut formatted = if include_link && self.url.is_some() {
             let url = self.url.as_ref().unwrap();
             let style = self.style().underline();
             format!(
@@ -236,7 +355,12 @@ impl PrStatus {
         } else {
             let style = self.style();
             format!("{style}{indicator}{style:#}")
+        };
+        if let Some(review) = self.review_state {
+            let (symbol, style) = review.indicator();
+            formatted.push_str(&format!("{style}{symbol}{style:#}"));
         }
+        formatted
     }
 
     /// Create an error status for retriable failures (rate limit, network errors)
@@ -246,6 +370,7 @@ impl PrStatus {
             source: CiSource::Branch,
             is_stale: false,
             url: None,
+            review_state: None,
         }
     }
 
@@ -312,6 +437,46 @@ impl PrStatus {
         status
     }
 
+    /// Fetch CI status for many branches at once, filling the cache that
+    /// [`PrStatus::detect`] reads.
+    ///
+    /// Branches without a fresh cache entry go to [`Forge::batch_status`] a
+    /// [`github::GRAPHQL_BATCH_SIZE`] at a time; on GitHub that is one GraphQL
+    /// request instead of one or two per branch. Forges that can't batch keep
+    /// per-branch queries, as do branches of a batch that fails.
+    pub fn prefetch(repo: &Repository, queries: &[CiQuery]) {
+        let Ok(repo_path) = repo.current_worktree().root() else {
+            return;
+        };
+        let now_secs = get_now();
+        let uncached: Vec<&CiQuery> = queries
+            .iter()
+            .filter(|query| {
+                !CachedCiStatus::read(repo, &query.branch)
+                    .is_some_and(|cached| cached.is_valid(&query.head, now_secs, &repo_path))
+            })
+            .collect();
+        // A single branch costs the same either way
+        if uncached.len() < 2 {
+            return;
+        }
+
+        let forge = forge_for_repo(repo);
+        for batch in uncached.chunks(github::GRAPHQL_BATCH_SIZE) {
+            let Some(statuses) = forge.batch_status(repo, batch) else {
+                return;
+            };
+            for (query, status) in batch.iter().zip(statuses) {
+                let cached = CachedCiStatus {
+                    status,
+                    checked_at: now_secs,
+                    head: query.head.clone(),
+                };
+                cached.write(repo, &query.branch);
+            }
+        }
+    }
+
     /// Detect CI status without caching (internal implementation)
     ///
     /// Platform is determined by project config override or remote URL detection.
@@ -332,53 +497,17 @@ impl PrStatus {
         let platform = get_platform_for_repo(repo, platform_override);
 
         match platform {
-            Some(CiPlatform::GitHub) => {
-                Self::detect_github_ci(repo, branch, local_head, has_upstream)
-            }
-            Some(CiPlatform::GitLab) => {
-                Self::detect_gitlab_ci(repo, branch, local_head, has_upstream)
-            }
+            Some(platform) => forge_for(platform).status(repo, branch, local_head, has_upstream),
             None => {
                 // Unknown platform (e.g., GitHub Enterprise, self-hosted GitLab with custom domain)
                 // Fall back to trying both platforms
                 log::debug!("Could not determine CI platform, trying both");
-                Self::detect_github_ci(repo, branch, local_head, has_upstream)
-                    .or_else(|| Self::detect_gitlab_ci(repo, branch, local_head, has_upstream))
+                GitHub
+                    .status(repo, branch, local_head, has_upstream)
+                    .or_else(|| GitLab.status(repo, branch, local_head, has_upstream))
             }
         }
     }
-
-    /// Detect GitHub CI status (PR first, then workflow if has_upstream)
-    fn detect_github_ci(
-        repo: &Repository,
-        branch: &str,
-        local_head: &str,
-        has_upstream: bool,
-    ) -> Option<Self> {
-        if let Some(status) = github::detect_github(repo, branch, local_head) {
-            return Some(status);
-        }
-        if has_upstream {
-            return github::detect_github_commit_checks(repo, local_head);
-        }
-        None
-    }
-
-    /// Detect GitLab CI status (MR first, then pipeline if has_upstream)
-    fn detect_gitlab_ci(
-        repo: &Repository,
-        branch: &str,
-        local_head: &str,
-        has_upstream: bool,
-    ) -> Option<Self> {
-        if let Some(status) = gitlab::detect_gitlab(repo, branch, local_head) {
-            return Some(status);
-        }
-        if has_upstream {
-            return gitlab::detect_gitlab_pipeline(branch, local_head);
-        }
-        None
-    }
 }
 
 #[cfg(test)]
@@ -409,6 +538,29 @@ mod tests {
         assert!(!is_retriable_error(""));
     }
 
+    #[test]
+    fn test_ci_fault_outputs() {
+        assert_eq!(CiFault::parse("ci-timeout"), Some(CiFault::Timeout));
+        assert_eq!(CiFault::parse("ci-rate-limit"), Some(CiFault::RateLimit));
+        assert_eq!(
+            CiFault::parse("ci-malformed-json"),
+            Some(CiFault::MalformedJson)
+        );
+        assert_eq!(CiFault::parse("nonsense"), None);
+
+        for fault in [CiFault::Timeout, CiFault::RateLimit] {
+            let output = fault.output();
+            assert!(!output.status.success());
+            assert!(is_retriable_error(&String::from_utf8_lossy(&output.stderr)));
+        }
+
+        let output = CiFault::MalformedJson.output();
+        assert!(output.status.success());
+        assert!(
+            parse_json::<Vec<serde_json::Value>>(&output.stdout, "gh pr list", "main").is_none()
+        );
+    }
+
     #[test]
     fn test_ci_status_color() {
         use anstyle::AnsiColor;
@@ -428,6 +580,7 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: None,
+            review_state: None,
         };
         assert_eq!(pr_passed.indicator(), "●");
 
@@ -436,6 +589,7 @@ mod tests {
             source: CiSource::Branch,
             is_stale: false,
             url: None,
+            review_state: None,
         };
         assert_eq!(branch_running.indicator(), "●");
 
@@ -444,6 +598,7 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: None,
+            review_state: None,
         };
         assert_eq!(error_status.indicator(), "⚠");
     }
@@ -455,6 +610,7 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: Some("https://github.com/owner/repo/pull/123".to_string()),
+            review_state: None,
         };
 
         // Call format_indicator(true) directly
@@ -472,6 +628,7 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: None,
+            review_state: None,
         };
 
         // Call format_indicator(true) directly
@@ -492,6 +649,7 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: Some("https://github.com/owner/repo/pull/123".to_string()),
+            review_state: None,
         };
 
         let with_link = pr_with_url.format_indicator(true);
@@ -514,6 +672,27 @@ mod tests {
         assert!(without_link.contains("●"), "Should contain indicator");
     }
 
+    #[test]
+    fn test_format_indicator_with_review_state() {
+        let approved = PrStatus {
+            ci_status: CiStatus::Passed,
+            source: CiSource::PullRequest,
+            is_stale: false,
+            url: None,
+            review_state: Some(ReviewState::Approved),
+        };
+        let formatted = approved.format_indicator(false);
+        assert!(formatted.contains("●"));
+        assert!(formatted.contains("✓"));
+        assert!(formatted.find("●") < formatted.find("✓"));
+
+        // Older cache entries have no review state
+        let cached: PrStatus =
+            serde_json::from_str(r#"{"ci_status":"passed","source":"pr","is_stale":false}"#)
+                .unwrap();
+        assert_eq!(cached.review_state, None);
+    }
+
     #[test]
     fn test_pr_status_error_constructor() {
         let error = PrStatus::error();
@@ -530,6 +709,7 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: None,
+            review_state: None,
         };
         // Call format_indicator directly
         let formatted = status.format_indicator(false);
@@ -541,6 +721,7 @@ mod tests {
             source: CiSource::Branch,
             is_stale: true,
             url: None,
+            review_state: None,
         };
         let style = stale.style();
         // Just verify it doesn't panic and returns a style
@@ -1,29 +1,45 @@
 //! CI platform detection.
 //!
-//! Determines whether a repository uses GitHub or GitLab based on
+//! Determines whether a repository uses GitHub, GitLab, Gitea, or Bitbucket based on
 //! project config override or remote URL detection.
 
 use worktrunk::git::Repository;
 
+use super::gitea;
+
 /// CI platform detected from project config override or remote URL.
 ///
 /// Platform is determined by:
-/// 1. Project config `[ci] platform = "github" | "gitlab"` (takes precedence)
-/// 2. Remote URL detection (searches for "github" or "gitlab" in URL)
+/// 1. Project config `[ci] platform = "github" | "gitlab" | "gitea" | "bitbucket"` (takes precedence)
+/// 2. The user config `[gitea] host`
+/// 3. Remote URL detection (searches for "github", "gitlab", "gitea", "bitbucket.org", and similar)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString)]
 #[strum(serialize_all = "lowercase")]
 pub enum CiPlatform {
     GitHub,
     GitLab,
+    /// Gitea and its fork Forgejo, which share an API
+    #[strum(to_string = "gitea", serialize = "forgejo")]
+    Gitea,
+    /// Bitbucket Cloud
+    Bitbucket,
 }
 
-/// Detect the CI platform from a remote URL by searching for "github" or "gitlab".
+/// Detect the CI platform from a remote URL by searching for "github",
+/// "gitlab", "bitbucket.org", or a Gitea/Forgejo host name.
 pub fn detect_platform_from_url(url: &str) -> Option<CiPlatform> {
     let url_lower = url.to_ascii_lowercase();
     if url_lower.contains("github") {
         Some(CiPlatform::GitHub)
     } else if url_lower.contains("gitlab") {
         Some(CiPlatform::GitLab)
+    } else if ["gitea", "forgejo", "codeberg.org"]
+        .iter()
+        .any(|name| url_lower.contains(name))
+    {
+        Some(CiPlatform::Gitea)
+    } else if url_lower.contains("bitbucket.org") {
+        Some(CiPlatform::Bitbucket)
     } else {
         None
     }
@@ -32,8 +48,8 @@ pub fn detect_platform_from_url(url: &str) -> Option<CiPlatform> {
 /// Get the CI platform for a repository.
 ///
 /// If `platform_override` is provided (from project config `[ci] platform`),
-/// uses that value directly. Otherwise, detects platform from the primary
-/// remote URL.
+/// uses that value directly. Otherwise, a remote on the configured `[gitea]
+/// host` is Gitea, and anything else is detected from the primary remote URL.
 pub fn get_platform_for_repo(
     repo: &Repository,
     platform_override: Option<&str>,
@@ -45,13 +61,16 @@ pub fn get_platform_for_repo(
             return Some(platform);
         }
         log::warn!(
-            "Invalid CI platform in config: '{}'. Expected 'github' or 'gitlab'.",
+            "Invalid CI platform in config: '{}'. Expected 'github', 'gitlab', 'gitea', or 'bitbucket'.",
             platform_str
         );
     }
 
     // Fall back to URL detection
     let url = repo.primary_remote_url()?;
+    if gitea::is_configured_host(&url) {
+        return Some(CiPlatform::Gitea);
+    }
     detect_platform_from_url(&url)
 }
 
@@ -115,13 +134,33 @@ mod tests {
             Some(CiPlatform::GitLab)
         );
 
-        // Unknown platforms
+        // Gitea and Forgejo
+        assert_eq!(
+            detect_platform_from_url("https://gitea.example.com/owner/repo.git"),
+            Some(CiPlatform::Gitea)
+        );
+        assert_eq!(
+            detect_platform_from_url("git@codeberg.org:owner/repo.git"),
+            Some(CiPlatform::Gitea)
+        );
+
+        // Bitbucket Cloud
         assert_eq!(
             detect_platform_from_url("https://bitbucket.org/owner/repo.git"),
+            Some(CiPlatform::Bitbucket)
+        );
+        assert_eq!(
+            detect_platform_from_url("git@bitbucket.org:owner/repo.git"),
+            Some(CiPlatform::Bitbucket)
+        );
+
+        // Unknown platforms (including self-hosted Bitbucket Data Center)
+        assert_eq!(
+            detect_platform_from_url("https://bitbucket.example.com/scm/owner/repo.git"),
             None
         );
         assert_eq!(
-            detect_platform_from_url("https://codeberg.org/owner/repo.git"),
+            detect_platform_from_url("https://git.example.com/owner/repo.git"),
             None
         );
     }
@@ -144,6 +183,24 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_platform_override_gitea() {
+        assert_eq!("gitea".parse::<CiPlatform>().ok(), Some(CiPlatform::Gitea));
+        assert_eq!(
+            "forgejo".parse::<CiPlatform>().ok(),
+            Some(CiPlatform::Gitea)
+        );
+        assert_eq!(CiPlatform::Gitea.to_string(), "gitea");
+    }
+
+    #[test]
+    fn test_platform_override_bitbucket() {
+        assert_eq!(
+            "bitbucket".parse::<CiPlatform>().ok(),
+            Some(CiPlatform::Bitbucket)
+        );
+    }
+
     #[test]
     fn test_platform_override_invalid() {
         // Invalid platform strings should not parse
@@ -0,0 +1,92 @@
+//! REST API requests through `curl`, for forges without a usable CLI.
+//!
+//! Options go to curl through stdin (`--config -`), so tokens and passwords
+//! don't show up in process listings. Commands fed through stdin don't get the
+//! command timeout, so curl enforces its own.
+
+use serde::de::DeserializeOwned;
+use worktrunk::shell_exec::Cmd;
+
+use super::{PrStatus, is_retriable_error, non_interactive_cmd, parse_json, run_ci_query};
+
+/// Upper bound on each request, in seconds.
+const API_TIMEOUT_SECS: &str = "30";
+
+/// Credentials sent with a request.
+pub(super) enum Auth<'a> {
+    Anonymous,
+    /// `Authorization: <scheme> <token>`
+    Token {
+        scheme: &'a str,
+        token: &'a str,
+    },
+    /// HTTP basic auth
+    Basic {
+        user: &'a str,
+        password: &'a str,
+    },
+}
+
+/// `curl` for a request to `url`.
+fn curl(url: &str, auth: &Auth) -> Cmd {
+    let mut config = String::from("header = \"Accept: application/json\"\n");
+    match auth {
+        Auth::Anonymous => {}
+        Auth::Token { scheme, token } => {
+            config.push_str(&format!(
+                "header = {}\n",
+                quote(&format!("Authorization: {scheme} {token}"))
+            ));
+        }
+        Auth::Basic { user, password } => {
+            config.push_str(&format!(
+                "user = {}\n",
+                quote(&format!("{user}:{password}"))
+            ));
+        }
+    }
+    non_interactive_cmd("curl")
+        .args([url, "--silent", "--show-error", "--fail"])
+        .args(["--max-time", API_TIMEOUT_SECS, "--config", "-"])
+        .stdin_bytes(config)
+}
+
+/// Quote a value for a curl config file.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// GET `url` and parse the JSON response.
+///
+/// `Err` holds the status to report instead: an error for failures worth
+/// retrying later (rate limits, network), `None` for anything else.
+pub(super) fn get_json<T: DeserializeOwned>(
+    url: &str,
+    auth: &Auth,
+    branch: &str,
+) -> Result<T, Option<PrStatus>> {
+    let output = run_ci_query(curl(url, auth)).map_err(|e| {
+        log::warn!("curl failed to execute for {}: {}", url, e);
+        None
+    })?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        if is_retriable_error(&stderr) {
+            return Err(Some(PrStatus::error()));
+        }
+        log::debug!("API request {} failed: {}", url, stderr.trim());
+        return Err(None);
+    }
+    parse_json(&output.stdout, "API response", branch).ok_or(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("token abc"), r#""token abc""#);
+        assert_eq!(quote(r#"a"b\c"#), r#""a\"b\\c""#);
+    }
+}
@@ -9,14 +9,14 @@
 use std::sync::Arc;
 
 use crossbeam_channel as chan;
-use worktrunk::git::{BranchRef, Repository, WorktreeInfo};
+use worktrunk::git::{BranchRef, CloneKind, Repository, WorktreeInfo};
 
 use super::CollectOptions;
 use super::tasks::{
-    AheadBehindTask, BranchDiffTask, CiStatusTask, CommitDetailsTask, CommittedTreesMatchTask,
-    GitOperationTask, HasFileChangesTask, IsAncestorTask, MergeTreeConflictsTask, Task,
-    TaskContext, UpstreamTask, UrlStatusTask, UserMarkerTask, WorkingTreeConflictsTask,
-    WorkingTreeDiffTask, WouldMergeAddTask,
+    AheadBehindTask, BranchDescriptionTask, BranchDiffTask, CiStatusTask, CommitDetailsTask,
+    CommittedTreesMatchTask, DiskUsageTask, GitOperationTask, HasFileChangesTask, IsAncestorTask,
+    MergeTreeConflictsTask, OwnerTask, Task, TaskContext, UpstreamTask, UrlStatusTask,
+    UserMarkerTask, WorkingTreeConflictsTask, WorkingTreeDiffTask, WouldMergeAddTask,
 };
 use super::types::{TaskError, TaskKind, TaskResult};
 
@@ -32,6 +32,30 @@ const EXPENSIVE_TASKS: &[TaskKind] = &[
     TaskKind::MergeTreeConflicts, // git merge-tree simulation
 ];
 
+// Tasks that read file contents. A partial clone fetches each missing blob from
+// its remote, turning these into network round trips, so they're skipped there.
+const BLOB_TASKS: &[TaskKind] = &[
+    TaskKind::WouldMergeAdd,
+    TaskKind::BranchDiff,
+    TaskKind::MergeTreeConflicts,
+];
+
+/// Whether a shallow or partial clone makes `kind` wrong or slow.
+///
+/// Shallow history can cut off the merge-base every expensive task relies on.
+fn limited_by_clone(kind: TaskKind, clone_kind: &CloneKind) -> bool {
+    (clone_kind.shallow && EXPENSIVE_TASKS.contains(&kind))
+        || (clone_kind.partial.is_some() && BLOB_TASKS.contains(&kind))
+}
+
+/// Whether CI status is fetched for a branch (see `CollectOptions::ci_branches`).
+fn ci_enabled(options: &CollectOptions, branch: Option<&str>) -> bool {
+    options
+        .ci_branches
+        .as_ref()
+        .is_none_or(|branches| branch.is_some_and(|b| branches.contains(b)))
+}
+
 // ============================================================================
 // Work Item Dispatch (for flat parallelism)
 // ============================================================================
@@ -73,9 +97,12 @@ fn dispatch_task(kind: TaskKind, ctx: TaskContext) -> Result<TaskResult, TaskErr
         TaskKind::WorkingTreeConflicts => WorkingTreeConflictsTask::compute(ctx),
         TaskKind::GitOperation => GitOperationTask::compute(ctx),
         TaskKind::UserMarker => UserMarkerTask::compute(ctx),
+        TaskKind::BranchDescription => BranchDescriptionTask::compute(ctx),
+        TaskKind::Owner => OwnerTask::compute(ctx),
         TaskKind::Upstream => UpstreamTask::compute(ctx),
         TaskKind::CiStatus => CiStatusTask::compute(ctx),
         TaskKind::UrlStatus => UrlStatusTask::compute(ctx),
+        TaskKind::DiskUsage => DiskUsageTask::compute(ctx),
     }
 }
 
@@ -178,7 +205,7 @@ pub fn work_items_for_worktree(
         .as_deref()
         .is_some_and(|b| options.stale_branches.contains(b));
 
-    let mut items = Vec::with_capacity(15);
+    let mut items = Vec::with_capacity(17);
 
     // Helper to add a work item and register the expected result
     let mut add_item = |kind: TaskKind| {
@@ -199,11 +226,14 @@ pub fn work_items_for_worktree(
         TaskKind::WorkingTreeDiff,
         TaskKind::GitOperation,
         TaskKind::UserMarker,
+        TaskKind::BranchDescription,
+        TaskKind::Owner,
         TaskKind::WorkingTreeConflicts,
         TaskKind::BranchDiff,
         TaskKind::MergeTreeConflicts,
         TaskKind::CiStatus,
         TaskKind::WouldMergeAdd,
+        TaskKind::DiskUsage,
     ] {
         if skip.contains(&kind) {
             continue;
@@ -212,6 +242,12 @@ pub fn work_items_for_worktree(
         if is_stale && EXPENSIVE_TASKS.contains(&kind) {
             continue;
         }
+        if limited_by_clone(kind, &options.clone_kind) {
+            continue;
+        }
+        if kind == TaskKind::CiStatus && !ci_enabled(options, wt.branch.as_deref()) {
+            continue;
+        }
         add_item(kind);
     }
     // URL status health check task (if we have a URL).
@@ -255,7 +291,7 @@ pub fn work_items_for_branch(
     // Check if this branch is stale and should skip expensive tasks.
     let is_stale = options.stale_branches.contains(branch_name);
 
-    let mut items = Vec::with_capacity(11);
+    let mut items = Vec::with_capacity(12);
 
     // Helper to add a work item and register the expected result
     let mut add_item = |kind: TaskKind| {
@@ -273,6 +309,8 @@ pub fn work_items_for_branch(
         TaskKind::HasFileChanges,
         TaskKind::IsAncestor,
         TaskKind::Upstream,
+        TaskKind::BranchDescription,
+        TaskKind::Owner,
         TaskKind::BranchDiff,
         TaskKind::MergeTreeConflicts,
         TaskKind::CiStatus,
@@ -285,6 +323,12 @@ pub fn work_items_for_branch(
         if is_stale && EXPENSIVE_TASKS.contains(&kind) {
             continue;
         }
+        if limited_by_clone(kind, &options.clone_kind) {
+            continue;
+        }
+        if kind == TaskKind::CiStatus && !ci_enabled(options, Some(branch_name)) {
+            continue;
+        }
         add_item(kind);
     }
 
@@ -103,11 +103,15 @@ use dunce::canonicalize;
 use once_cell::sync::OnceCell;
 use rayon::prelude::*;
 use std::sync::Arc;
-use worktrunk::git::{Repository, WorktreeInfo};
-use worktrunk::styling::{INFO_SYMBOL, format_with_gutter, hint_message, warning_message};
+use worktrunk::config::{IssueMatcher, IssueTrackerConfig, SummaryPart};
+use worktrunk::git::{GitError, Repository, WorktreeInfo};
+use worktrunk::styling::{
+    INFO_SYMBOL, format_with_gutter, hint_message, info_message, warning_message,
+};
 
 use crate::commands::is_worktree_at_expected_path;
 
+use super::ci_status::{CiQuery, PrStatus};
 use super::model::{DisplayFields, ItemKind, ListItem, WorktreeData};
 
 // Re-exports for sibling modules (columns.rs, render.rs, layout.rs)
@@ -116,7 +120,7 @@ pub(crate) use types::TaskKind;
 
 // Internal imports
 pub(crate) use execution::ExpectedResults;
-use execution::{work_items_for_branch, work_items_for_worktree};
+use execution::{WorkItem, work_items_for_branch, work_items_for_worktree};
 use results::drain_results;
 use types::{DrainOutcome, StatusContext};
 use types::{TaskError, TaskResult};
@@ -179,6 +183,16 @@ pub struct CollectOptions {
     /// TODO: Consider adding a visible indicator in Status column when integration
     /// checks are skipped, so users know the `⊂` symbol may be incomplete.
     pub stale_branches: std::collections::HashSet<String>,
+
+    /// Shallow/partial clone detection, populated post-skeleton.
+    ///
+    /// Shallow clones skip the merge-base tasks and partial clones skip the
+    /// tasks that read blobs; skipped cells show `…` and the summary notes why.
+    pub clone_kind: worktrunk::git::CloneKind,
+
+    /// Branches that get CI status without `--full`, from `[[rules]]` entries
+    /// with `ci = true`. `None` when CI status isn't limited to some branches.
+    pub ci_branches: Option<std::collections::HashSet<String>>,
 }
 
 fn worktree_branch_set(worktrees: &[WorktreeInfo]) -> std::collections::HashSet<&str> {
@@ -204,6 +218,23 @@ fn worktree_branch_set(worktrees: &[WorktreeInfo]) -> std::collections::HashSet<
 /// The `skip_expensive_for_stale` parameter enables batch-fetching ahead/behind counts and
 /// skipping expensive merge-base operations for branches far behind the default branch.
 /// This dramatically improves performance for repos with many stale branches.
+///
+/// The `show_index` parameter numbers rows in display order (`wt list --index`).
+///
+/// The `show_summary` parameter controls the summary line after the table
+/// (`wt list --no-summary`); its metrics come from `[list] summary`.
+///
+/// The `no_truncate` parameter shows full values, letting lines overflow
+/// (`wt list --no-truncate`); otherwise `[list.max-width]` caps columns.
+///
+/// The `record_layout` parameter renders each item as a stanza of labeled
+/// fields instead of a table row (`wt list --layout record`).
+///
+/// The `mine` parameter keeps only branches whose last committer email is
+/// `user.email` (`wt list --mine`). The main worktree always stays.
+///
+/// The `display` parameter adds the Graph column (`wt list --graph`) and sets
+/// how the Age column shows commit times.
 #[allow(clippy::too_many_arguments)]
 pub fn collect(
     repo: &Repository,
@@ -215,6 +246,12 @@ pub fn collect(
     config: &worktrunk::config::WorktrunkConfig,
     command_timeout: Option<std::time::Duration>,
     skip_expensive_for_stale: bool,
+    show_index: bool,
+    show_summary: bool,
+    no_truncate: bool,
+    record_layout: bool,
+    mine: bool,
+    display: &super::layout::DisplayOptions,
 ) -> anyhow::Result<Option<super::model::ListData>> {
     use super::progressive_table::ProgressiveTable;
     worktrunk::shell_exec::trace_instant("List collect started");
@@ -228,6 +265,7 @@ pub fn collect(
     // - default_branch: independent (git config + verify)
     // - is_bare: independent (git config, cached for later use)
     // - url_template: independent (loads project config via show-toplevel)
+    // - issue_tracker: independent (loads project config via show-toplevel)
     // - local_branches: independent (for-each-ref, but filtering needs worktrees)
     // - remote_branches: independent (for-each-ref)
     //
@@ -235,6 +273,7 @@ pub fn collect(
     let worktrees_cell: OnceCell<anyhow::Result<Vec<WorktreeInfo>>> = OnceCell::new();
     let default_branch_cell: OnceCell<Option<String>> = OnceCell::new();
     let url_template_cell: OnceCell<Option<String>> = OnceCell::new();
+    let issue_tracker_cell: OnceCell<Option<IssueTrackerConfig>> = OnceCell::new();
     let local_branches_cell: OnceCell<anyhow::Result<Vec<(String, String)>>> = OnceCell::new();
     let remote_branches_cell: OnceCell<anyhow::Result<Vec<(String, String)>>> = OnceCell::new();
 
@@ -252,6 +291,9 @@ pub fn collect(
         s.spawn(|_| {
             let _ = url_template_cell.set(repo.url_template());
         });
+        s.spawn(|_| {
+            let _ = issue_tracker_cell.set(repo.issue_tracker());
+        });
         s.spawn(|_| {
             if show_branches {
                 let _ = local_branches_cell.set(repo.list_local_branches());
@@ -274,6 +316,7 @@ pub fn collect(
     }
     let default_branch = default_branch_cell.into_inner().unwrap();
     let url_template = url_template_cell.into_inner().unwrap();
+    let issue_tracker = issue_tracker_cell.into_inner().unwrap();
 
     // Filter local branches to those without worktrees (CPU-only, no git commands)
     let branches_without_worktrees = if show_branches {
@@ -333,6 +376,11 @@ pub fn collect(
         .chain(remote_branches.iter().map(|(_, sha)| sha.as_str()))
         .collect();
     let timestamps = repo.commit_timestamps(&all_shas).unwrap_or_default();
+    let committer_emails = if mine {
+        repo.commit_committer_emails(&all_shas).unwrap_or_default()
+    } else {
+        std::collections::HashMap::new()
+    };
 
     // Sort worktrees: current first, main second, then by timestamp descending
     let sorted_worktrees = sort_worktrees_with_cache(
@@ -350,6 +398,36 @@ pub fn collect(
     let remote_branches =
         sort_by_timestamp_desc_with_cache(remote_branches, &timestamps, |(_, sha)| sha.as_str());
 
+    // --mine: drop branches last committed by someone else
+    let (sorted_worktrees, branches_without_worktrees, remote_branches) = if mine {
+        let Some(email) = repo.get_config("user.email")? else {
+            return Err(GitError::UserEmailNotSet.into());
+        };
+        let is_mine = |sha: &str| {
+            committer_emails
+                .get(sha)
+                .is_some_and(|e| e.eq_ignore_ascii_case(&email))
+        };
+        let is_mine_branch = |(_, sha): &(String, String)| is_mine(sha);
+        (
+            sorted_worktrees
+                .into_iter()
+                .filter(|wt| wt.path == main_worktree.path || is_mine(&wt.head))
+                .collect(),
+            branches_without_worktrees
+                .into_iter()
+                .filter(is_mine_branch)
+                .collect(),
+            remote_branches.into_iter().filter(is_mine_branch).collect(),
+        )
+    } else {
+        (
+            sorted_worktrees,
+            branches_without_worktrees,
+            remote_branches,
+        )
+    };
+
     // Pre-canonicalize main_worktree.path for is_main comparison
     // (paths from git worktree list may differ based on symlinks or working directory)
     let main_worktree_canonical = canonicalize(&main_worktree.path).ok();
@@ -379,6 +457,11 @@ pub fn collect(
             let mut worktree_data =
                 WorktreeData::from_worktree(wt, is_main, is_current, is_previous);
             worktree_data.branch_worktree_mismatch = branch_worktree_mismatch;
+            // One git command per detached worktree, which are rare; the Branch
+            // column's width depends on it, so it can't wait for the skeleton
+            if wt.detached {
+                worktree_data.detached_at = repo.describe_detached(&wt.head);
+            }
 
             // URL expanded post-skeleton to minimize time-to-skeleton
             ListItem {
@@ -396,6 +479,10 @@ pub fn collect(
                 pr_status: None,
                 url: None,
                 url_active: None,
+                description: None,
+                owner: None,
+                issue: None,
+                index: None,
                 status_symbols: None,
                 display: DisplayFields::default(),
                 kind: ItemKind::Worktree(Box::new(worktree_data)),
@@ -418,18 +505,77 @@ pub fn collect(
             .map(|(name, sha)| ListItem::new_branch(sha.clone(), name.clone())),
     );
 
+    // Row numbers follow display order, so `wt switch N` matches what was shown
+    if show_index {
+        for (i, item) in all_items.iter_mut().enumerate() {
+            item.index = Some(i + 1);
+        }
+    }
+
+    // Issue references are pure string matching, so fill them in before layout
+    // (the Issue column is sized from the extracted keys)
+    if let Some(tracker) = issue_tracker {
+        match IssueMatcher::new(&tracker) {
+            Ok(matcher) => {
+                for item in &mut all_items {
+                    item.issue = item
+                        .branch
+                        .as_deref()
+                        .and_then(|branch| matcher.issue_for_branch(branch));
+                }
+            }
+            Err(e) => {
+                crate::output::print(warning_message(cformat!(
+                    "Invalid <bright-black>[integrations.issues]</> pattern <bold>{}</>: {e}",
+                    tracker.pattern
+                )))?;
+            }
+        }
+    }
+
     // If no URL template configured, add UrlStatus to skip_tasks
     let mut effective_skip_tasks = skip_tasks.clone();
     if url_template.is_none() {
         effective_skip_tasks.insert(TaskKind::UrlStatus);
     }
 
+    // Without --full, branches matching a `[[rules]]` entry with `ci = true`
+    // still get CI status
+    let mut ci_branches = None;
+    if effective_skip_tasks.contains(&TaskKind::CiStatus)
+        && let Some(project_config) = repo.load_project_config().ok().flatten()
+    {
+        let matching: std::collections::HashSet<String> = all_items
+            .iter()
+            .filter_map(|item| item.branch.as_deref())
+            .filter(|branch| project_config.rule_for(branch).is_some_and(|rule| rule.ci))
+            .map(str::to_string)
+            .collect();
+        if !matching.is_empty() {
+            effective_skip_tasks.remove(&TaskKind::CiStatus);
+            ci_branches = Some(matching);
+        }
+    }
+
     // Calculate layout from items (worktrees, local branches, and remote branches)
+    // Records have a line per field, so every column fits
+    let width_limits = super::layout::WidthLimits {
+        no_truncate: no_truncate || record_layout,
+        max_widths: config
+            .list
+            .as_ref()
+            .and_then(|l| l.max_width)
+            .unwrap_or_default(),
+        tuning: super::tune::ColumnTuning::load(repo),
+    };
     let layout = super::layout::calculate_layout_from_basics(
         &all_items,
         &effective_skip_tasks,
         &main_worktree.path,
         url_template.as_deref(),
+        repo.branch_display_prefix().as_deref(),
+        &width_limits,
+        display,
     );
 
     // Single-line invariant: use safe width to prevent line wrapping
@@ -439,6 +585,7 @@ pub fn collect(
     let mut options = CollectOptions {
         skip_tasks: effective_skip_tasks,
         url_template: url_template.clone(),
+        ci_branches,
         ..Default::default()
     };
 
@@ -565,8 +712,12 @@ pub fn collect(
     //
     // Uses `git for-each-ref --format='%(ahead-behind:...)'` (git 2.36+) which gets all
     // counts in a single command. On older git versions, returns empty and all tasks run.
-    // Skip if default_branch is unknown.
-    if skip_expensive_for_stale && let Some(ref db) = default_branch {
+    // Skip if default_branch is unknown. Counts are against the same base as the
+    // `main↕` column, so AheadBehindTask can reuse them from the cache.
+    if skip_expensive_for_stale
+        && default_branch.is_some()
+        && let Some(ref base) = repo.ahead_behind_base()
+    {
         // Branches more than 50 commits behind skip expensive operations.
         // 50 is low enough to catch truly stale branches while keeping info for
         // recently-diverged ones.
@@ -578,9 +729,9 @@ pub fn collect(
         let threshold: usize = std::env::var("WORKTRUNK_TEST_SKIP_EXPENSIVE_THRESHOLD")
             .ok()
             .and_then(|s| s.parse().ok())
-            .unwrap_or(50);
+            .unwrap_or(super::STALE_BEHIND_THRESHOLD);
         // batch_ahead_behind populates the Repository cache with all counts
-        let ahead_behind = repo.batch_ahead_behind(db);
+        let ahead_behind = repo.batch_ahead_behind(base);
         // Filter to stale branches (behind > threshold). The set indicates which
         // branches should skip expensive tasks; counts come from the cache.
         options.stale_branches = ahead_behind
@@ -589,6 +740,10 @@ pub fn collect(
             .collect();
     }
 
+    // Shallow and partial clones can't answer some tasks cheaply or correctly
+    let clone_kind = repo.clone_kind();
+    options.clone_kind = clone_kind.clone();
+
     // Note: URL template expansion is deferred to task spawning (in collect_worktree_progressive
     // and collect_branch_progressive). This parallelizes the work and minimizes time-to-skeleton.
 
@@ -666,16 +821,42 @@ pub fn collect(
             ));
         }
 
-        // Sort work items: network tasks last to avoid blocking local operations
-        all_work_items.sort_by_key(|item| item.kind.is_network());
-
-        // Phase 2: Execute all work items in parallel
-        worktrunk::shell_exec::trace_instant("Parallel execution started");
-        all_work_items.into_par_iter().for_each(|item| {
+        // Network tasks run alongside local ones rather than competing with them
+        let (network_items, local_items): (Vec<_>, Vec<_>) = all_work_items
+            .into_iter()
+            .partition(|item| item.kind.is_network());
+        let run = |item: WorkItem| {
             worktrunk::shell_exec::set_command_timeout(command_timeout);
             let result = item.execute();
             let _ = tx_worker.send(result);
-        });
+        };
+
+        // Phase 2: Execute all work items in parallel
+        worktrunk::shell_exec::trace_instant("Parallel execution started");
+        rayon::join(
+            || local_items.into_par_iter().for_each(run),
+            || {
+                // Fetch CI status for all branches in batches first, so the CI
+                // tasks find it cached instead of each querying gh
+                worktrunk::shell_exec::set_command_timeout(command_timeout);
+                let ci_queries: Vec<CiQuery> = network_items
+                    .iter()
+                    .filter(|item| item.kind == TaskKind::CiStatus)
+                    .filter_map(|item| {
+                        let branch = item.ctx.branch_ref.branch.clone()?;
+                        let has_upstream =
+                            repo_clone.upstream_branch(&branch).ok().flatten().is_some();
+                        Some(CiQuery {
+                            branch,
+                            head: item.ctx.branch_ref.commit_sha.clone(),
+                            has_upstream,
+                        })
+                    })
+                    .collect();
+                PrStatus::prefetch(&repo_clone, &ci_queries);
+                network_items.into_par_iter().for_each(run);
+            },
+        );
     });
 
     // Drop the original sender so drain_results knows when all spawned threads are done
@@ -802,49 +983,70 @@ pub fn collect(
     let timed_out_count = errors.iter().filter(|e| e.is_timeout()).count();
 
     // Finalize progressive table or render buffered output
-    if let Some(mut table) = progressive_table {
-        // Build final summary string
-        let final_msg = super::format_summary_message(
+    // Build final summary string (`--no-summary` omits it)
+    let summary_metrics = config
+        .list
+        .as_ref()
+        .and_then(|l| l.summary.as_deref())
+        .unwrap_or(SummaryPart::DEFAULT);
+    let final_msg = show_summary.then(|| {
+        super::format_summary_message(
             &all_items,
             show_branches || show_remotes,
             layout.hidden_column_count,
+            summary_metrics,
             error_count,
             timed_out_count,
-        );
+        )
+    });
 
+    // Final (non-progressive) output: a table, or a record per item
+    let print_items = |items: &[ListItem]| -> anyhow::Result<()> {
+        if record_layout {
+            for (idx, item) in items.iter().enumerate() {
+                if idx > 0 {
+                    crate::output::stdout("")?;
+                }
+                for line in layout.format_record(item, max_width) {
+                    crate::output::stdout(line)?;
+                }
+            }
+        } else {
+            crate::output::stdout(layout.format_header_line())?;
+            for item in items {
+                crate::output::stdout(layout.format_list_item_line(item))?;
+            }
+        }
+        Ok(())
+    };
+
+    if let Some(mut table) = progressive_table {
         if table.is_tty() {
             // Interactive: do final render pass and update footer to summary
             for (item_idx, item) in all_items.iter().enumerate() {
                 let rendered = layout.format_list_item_line(item);
                 table.update_row(item_idx, rendered);
             }
-            table.finalize(final_msg)?;
+            match final_msg {
+                Some(msg) => table.finalize(msg)?,
+                None => table.finalize_without_footer()?,
+            }
         } else {
             // Non-TTY: output to stdout (same as buffered mode)
             // Progressive skeleton was suppressed; now output the final table
-            crate::output::stdout(layout.format_header_line())?;
-            for item in &all_items {
-                crate::output::stdout(layout.format_list_item_line(item))?;
+            print_items(&all_items)?;
+            if let Some(msg) = final_msg {
+                crate::output::stdout("")?;
+                crate::output::stdout(msg)?;
             }
-            crate::output::stdout("")?;
-            crate::output::stdout(final_msg)?;
         }
     } else if render_table {
         // Buffered mode: render final table
-        let final_msg = super::format_summary_message(
-            &all_items,
-            show_branches || show_remotes,
-            layout.hidden_column_count,
-            error_count,
-            timed_out_count,
-        );
-
-        crate::output::stdout(layout.format_header_line())?;
-        for item in &all_items {
-            crate::output::stdout(layout.format_list_item_line(item))?;
+        print_items(&all_items)?;
+        if let Some(msg) = final_msg {
+            crate::output::stdout("")?;
+            crate::output::stdout(msg)?;
         }
-        crate::output::stdout("")?;
-        crate::output::stdout(final_msg)?;
     }
 
     // Status symbols are now computed during data collection (both modes), no fallback needed
@@ -889,6 +1091,18 @@ pub fn collect(
         crate::output::print(hint_message(crate::diagnostic::issue_hint()))?;
     }
 
+    if render_table && let Some(label) = clone_kind.label() {
+        let skipped = if clone_kind.shallow {
+            "ahead/behind counts stop at the shallow boundary; branch diffs and integration checks skipped"
+        } else {
+            "branch diffs and conflict checks skipped"
+        };
+        crate::output::print(info_message(cformat!("<bold>{label}</>: {skipped}")))?;
+        crate::output::print(hint_message(cformat!(
+            "For guidance, run <bright-black>wt config show --full</>"
+        )))?;
+    }
+
     // Populate display fields for all items (used by JSON output and statusline)
     for item in &mut all_items {
         item.finalize_display();
@@ -992,6 +1206,10 @@ pub fn build_worktree_item(
         pr_status: None,
         url: None,
         url_active: None,
+        description: None,
+        owner: None,
+        issue: None,
+        index: None,
         status_symbols: None,
         display: DisplayFields::default(),
         kind: ItemKind::Worktree(Box::new(WorktreeData::from_worktree(
@@ -73,6 +73,12 @@ pub(super) fn apply_default(
             // Already defaults to None
             status_contexts[idx].user_marker = None;
         }
+        TaskKind::BranchDescription => {
+            // Already defaults to None
+        }
+        TaskKind::Owner => {
+            // Leave as None — UI shows nothing for unknown owner
+        }
         TaskKind::Upstream => {
             items[idx].upstream = Some(UpstreamStatus::default());
         }
@@ -85,6 +91,9 @@ pub(super) fn apply_default(
             // URL is set at item creation, only default url_active
             items[idx].url_active = None;
         }
+        TaskKind::DiskUsage => {
+            // Leave as None — UI shows nothing for unknown size
+        }
     }
 }
 
@@ -271,6 +280,12 @@ pub(super) fn drain_results(
                 // Store for status_symbols computation
                 status_ctx.user_marker = user_marker;
             }
+            TaskResult::BranchDescription { description, .. } => {
+                item.description = description;
+            }
+            TaskResult::Owner { owner, .. } => {
+                item.owner = Some(owner);
+            }
             TaskResult::Upstream { upstream, .. } => {
                 item.upstream = Some(upstream);
             }
@@ -290,6 +305,13 @@ pub(super) fn drain_results(
                     item.url_active = active;
                 }
             }
+            TaskResult::DiskUsage { bytes, .. } => {
+                if let ItemKind::Worktree(data) = &mut item.kind {
+                    data.disk_usage = Some(bytes);
+                } else {
+                    debug_assert!(false, "DiskUsage result for non-worktree item");
+                }
+            }
         }
 
         // Invoke callback (progressive mode re-renders rows, buffered mode does nothing)
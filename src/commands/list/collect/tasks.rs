@@ -1,6 +1,6 @@
 //! Task trait and implementations.
 //!
-//! Contains the `Task` trait interface and all 15 task implementations that
+//! Contains the `Task` trait interface and all 17 task implementations that
 //! compute various git operations for worktrees and branches.
 
 use worktrunk::git::{LineDiff, Repository};
@@ -11,6 +11,7 @@ use super::super::model::{
     WorkingTreeStatus,
 };
 use super::types::{ErrorCause, TaskError, TaskKind, TaskResult};
+use crate::commands::review::REVIEW_MARKER;
 
 // ============================================================================
 // Task Context
@@ -67,6 +68,14 @@ impl TaskContext {
         self.repo.default_branch()
     }
 
+    /// Get the ref ahead/behind stats compare against (cached in Repository).
+    ///
+    /// The default branch, or its upstream remote copy with `[repo] upstream-remote`.
+    /// Used for the `main↕` and `main…±` columns.
+    pub(super) fn ahead_behind_base(&self) -> Option<String> {
+        self.repo.ahead_behind_base()
+    }
+
     /// Get the integration target (cached in Repository).
     ///
     /// Used for integration checks (status symbols, safe deletion).
@@ -122,7 +131,7 @@ impl Task for CommitDetailsTask {
     }
 }
 
-/// Task 2: Ahead/behind counts vs local default branch (informational stats)
+/// Task 2: Ahead/behind counts vs default branch (informational stats)
 pub struct AheadBehindTask;
 
 impl Task for AheadBehindTask {
@@ -130,7 +139,7 @@ impl Task for AheadBehindTask {
 
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
         // When default_branch is None, return zero counts (cells show empty)
-        let Some(base) = ctx.default_branch() else {
+        let Some(base) = ctx.ahead_behind_base() else {
             return Ok(TaskResult::AheadBehind {
                 item_idx: ctx.item_idx,
                 counts: AheadBehind::default(),
@@ -141,10 +150,13 @@ impl Task for AheadBehindTask {
 
         // Check for orphan branch (no common ancestor with default branch).
         // merge_base() is cached, so this is cheap after first call.
+        // In a shallow clone the common ancestor may just be past the shallow
+        // boundary, so a missing merge-base doesn't make the branch an orphan.
         let is_orphan = repo
             .merge_base(&base, &ctx.branch_ref.commit_sha)
             .map_err(|e| ctx.error(Self::KIND, &e))?
-            .is_none();
+            .is_none()
+            && !repo.clone_kind().shallow;
 
         if is_orphan {
             return Ok(TaskResult::AheadBehind {
@@ -324,7 +336,7 @@ impl Task for IsAncestorTask {
     }
 }
 
-/// Task 4: Branch diff stats vs local default branch (informational stats)
+/// Task 4: Branch diff stats vs default branch (informational stats)
 pub struct BranchDiffTask;
 
 impl Task for BranchDiffTask {
@@ -332,7 +344,7 @@ impl Task for BranchDiffTask {
 
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
         // When default_branch is None, return empty diff (cells show empty)
-        let Some(base) = ctx.default_branch() else {
+        let Some(base) = ctx.ahead_behind_base() else {
             return Ok(TaskResult::BranchDiff {
                 item_idx: ctx.item_idx,
                 branch_diff: BranchDiffTotals::default(),
@@ -520,7 +532,7 @@ impl Task for GitOperationTask {
     }
 }
 
-/// Task 8 (worktree only): User-defined status from git config
+/// Task 8 (worktree only): User-defined status from git config, or the review marker
 pub struct UserMarkerTask;
 
 impl Task for UserMarkerTask {
@@ -528,7 +540,12 @@ impl Task for UserMarkerTask {
 
     fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
         let repo = &ctx.repo;
-        let user_marker = repo.user_marker(ctx.branch_ref.branch.as_deref());
+        let branch = ctx.branch_ref.branch.as_deref();
+        let user_marker = repo.user_marker(branch).or_else(|| {
+            branch
+                .and_then(|branch| repo.review_state(branch))
+                .map(|_| REVIEW_MARKER.to_string())
+        });
         Ok(TaskResult::UserMarker {
             item_idx: ctx.item_idx,
             user_marker,
@@ -536,6 +553,53 @@ impl Task for UserMarkerTask {
     }
 }
 
+/// Task 8b: Branch description from git config (--full only)
+pub struct BranchDescriptionTask;
+
+impl Task for BranchDescriptionTask {
+    const KIND: TaskKind = TaskKind::BranchDescription;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let description = ctx
+            .branch_ref
+            .branch
+            .as_deref()
+            .and_then(|branch| ctx.repo.branch_description(branch));
+        Ok(TaskResult::BranchDescription {
+            item_idx: ctx.item_idx,
+            description,
+        })
+    }
+}
+
+/// Task 8c: Owner of the branch (--owner only)
+///
+/// The worktree's `WT_OWNER` marker wins over the last commit's author,
+/// which is often a pairing partner or a bot on shared checkouts.
+pub struct OwnerTask;
+
+impl Task for OwnerTask {
+    const KIND: TaskKind = TaskKind::Owner;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let marker = ctx
+            .branch_ref
+            .working_tree(&ctx.repo)
+            .and_then(|wt| wt.owner_marker());
+        let owner = match marker {
+            Some(owner) => owner,
+            None => ctx
+                .repo
+                .commit_author(&ctx.branch_ref.commit_sha)
+                .map_err(|e| ctx.error(Self::KIND, &e))?,
+        };
+        Ok(TaskResult::Owner {
+            item_idx: ctx.item_idx,
+            owner,
+        })
+    }
+}
+
 /// Task 9: Upstream tracking status
 pub struct UpstreamTask;
 
@@ -558,9 +622,20 @@ impl Task for UpstreamTask {
             .upstream_branch(branch)
             .map_err(|e| ctx.error(Self::KIND, &e))?;
         let Some(upstream_branch) = upstream_branch else {
+            // Tracking config without a remote-tracking ref: either set up ahead of
+            // the first push (`[switch] set-upstream`), or deleted on the remote
+            let remote = repo
+                .configured_upstream_remote(branch)
+                .map_err(|e| ctx.error(Self::KIND, &e))?;
+            let unpushed = remote.is_some() && repo.tracks_unpushed(branch);
             return Ok(TaskResult::Upstream {
                 item_idx: ctx.item_idx,
-                upstream: UpstreamStatus::default(),
+                upstream: UpstreamStatus {
+                    gone: remote.is_some() && !unpushed,
+                    unpushed,
+                    remote,
+                    ..Default::default()
+                },
             });
         };
 
@@ -575,6 +650,8 @@ impl Task for UpstreamTask {
                 remote,
                 ahead,
                 behind,
+                unpushed: false,
+                gone: false,
             },
         })
     }
@@ -648,6 +725,28 @@ impl Task for UrlStatusTask {
     }
 }
 
+/// Task 14 (worktree only): Disk usage of the worktree directory (--du only)
+pub struct DiskUsageTask;
+
+impl Task for DiskUsageTask {
+    const KIND: TaskKind = TaskKind::DiskUsage;
+
+    fn compute(ctx: TaskContext) -> Result<TaskResult, TaskError> {
+        let Some(path) = ctx.branch_ref.worktree_path.as_deref() else {
+            return Ok(TaskResult::DiskUsage {
+                item_idx: ctx.item_idx,
+                bytes: 0,
+            });
+        };
+        let bytes = super::super::disk_usage::worktree_disk_usage(&ctx.repo, path)
+            .map_err(|e| ctx.error(Self::KIND, &e))?;
+        Ok(TaskResult::DiskUsage {
+            item_idx: ctx.item_idx,
+            bytes,
+        })
+    }
+}
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -136,6 +136,13 @@ pub(crate) enum TaskResult {
         item_idx: usize,
         user_marker: Option<String>,
     },
+    /// Branch description from `branch.<name>.description`
+    BranchDescription {
+        item_idx: usize,
+        description: Option<String>,
+    },
+    /// Owner marker or last commit author (--owner only)
+    Owner { item_idx: usize, owner: String },
     /// Upstream tracking status
     Upstream {
         item_idx: usize,
@@ -154,6 +161,8 @@ pub(crate) enum TaskResult {
         /// Whether the port is listening (None if no URL or couldn't parse port)
         active: Option<bool>,
     },
+    /// Disk usage of the worktree directory in bytes (--du only)
+    DiskUsage { item_idx: usize, bytes: u64 },
 }
 
 impl TaskResult {
@@ -172,9 +181,12 @@ impl TaskResult {
             | TaskResult::WorkingTreeConflicts { item_idx, .. }
             | TaskResult::GitOperation { item_idx, .. }
             | TaskResult::UserMarker { item_idx, .. }
+            | TaskResult::BranchDescription { item_idx, .. }
+            | TaskResult::Owner { item_idx, .. }
             | TaskResult::Upstream { item_idx, .. }
             | TaskResult::CiStatus { item_idx, .. }
-            | TaskResult::UrlStatus { item_idx, .. } => *item_idx,
+            | TaskResult::UrlStatus { item_idx, .. }
+            | TaskResult::DiskUsage { item_idx, .. } => *item_idx,
         }
     }
 }
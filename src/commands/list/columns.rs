@@ -1,18 +1,28 @@
+use serde::{Deserialize, Serialize};
+
 use super::collect::TaskKind;
 
 /// Logical identifier for each column rendered by `wt list`.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+///
+/// Serialized names key the widths saved by `wt list --tune`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum ColumnKind {
     Gutter, // Type indicator: `@` (current), `^` (main), `+` (worktree), space (branch-only)
+    Index,  // Row number for `wt switch N`, opt-in via `--index`
     Branch,
     Status, // Includes both git status symbols and user-defined status
     WorkingDiff,
     AheadBehind,
+    Graph, // Divergence from main drawn as commits, opt-in via `--graph`
     BranchDiff,
     Path,
     Upstream,
-    Url, // Dev server URL from project config template
+    Url,   // Dev server URL from project config template
+    Issue, // Issue key extracted from branch name via `[integrations.issues]`
     CiStatus,
+    DiskUsage, // Opt-in via `--du`
+    Owner,     // Opt-in via `--owner`
     Commit,
     Time,
     Message,
@@ -22,16 +32,21 @@ impl ColumnKind {
     pub const fn header(self) -> &'static str {
         match self {
             ColumnKind::Gutter => "",
+            ColumnKind::Index => "#",
             ColumnKind::Branch => "Branch",
             ColumnKind::Status => "Status",
             ColumnKind::WorkingDiff => "HEAD±",
             ColumnKind::AheadBehind => "main↕",
+            ColumnKind::Graph => "Graph",
             ColumnKind::BranchDiff => "main…±",
             ColumnKind::Path => "Path",
             ColumnKind::Upstream => "Remote⇅",
             ColumnKind::Url => "URL",
+            ColumnKind::Issue => "Issue",
             ColumnKind::Time => "Age",
             ColumnKind::CiStatus => "CI",
+            ColumnKind::DiskUsage => "Size",
+            ColumnKind::Owner => "Owner",
             ColumnKind::Commit => "Commit",
             ColumnKind::Message => "Message",
         }
@@ -82,15 +97,20 @@ impl ColumnSpec {
 /// Static registry of all possible columns in display order.
 pub const COLUMN_SPECS: &[ColumnSpec] = &[
     ColumnSpec::new(ColumnKind::Gutter, 0, None),
+    ColumnSpec::new(ColumnKind::Index, 15, None),
     ColumnSpec::new(ColumnKind::Branch, 1, None),
     ColumnSpec::new(ColumnKind::Status, 2, None),
     ColumnSpec::new(ColumnKind::WorkingDiff, 3, None),
     ColumnSpec::new(ColumnKind::AheadBehind, 4, None),
+    ColumnSpec::new(ColumnKind::Graph, 17, None),
     ColumnSpec::new(ColumnKind::BranchDiff, 5, Some(TaskKind::BranchDiff)),
     ColumnSpec::new(ColumnKind::Path, 6, None),
     ColumnSpec::new(ColumnKind::Upstream, 7, None),
     ColumnSpec::new(ColumnKind::Url, 8, Some(TaskKind::UrlStatus)),
+    ColumnSpec::new(ColumnKind::Issue, 14, None),
     ColumnSpec::new(ColumnKind::CiStatus, 9, Some(TaskKind::CiStatus)),
+    ColumnSpec::new(ColumnKind::DiskUsage, 13, Some(TaskKind::DiskUsage)),
+    ColumnSpec::new(ColumnKind::Owner, 16, Some(TaskKind::Owner)),
     ColumnSpec::new(ColumnKind::Commit, 10, None),
     ColumnSpec::new(ColumnKind::Time, 11, None),
     ColumnSpec::new(ColumnKind::Message, 12, None),
@@ -113,15 +133,20 @@ mod tests {
         let kinds: Vec<ColumnKind> = COLUMN_SPECS.iter().map(|c| c.kind).collect();
         let expected = vec![
             ColumnKind::Gutter,
+            ColumnKind::Index,
             ColumnKind::Branch,
             ColumnKind::Status,
             ColumnKind::WorkingDiff,
             ColumnKind::AheadBehind,
+            ColumnKind::Graph,
             ColumnKind::BranchDiff,
             ColumnKind::Path,
             ColumnKind::Upstream,
             ColumnKind::Url,
+            ColumnKind::Issue,
             ColumnKind::CiStatus,
+            ColumnKind::DiskUsage,
+            ColumnKind::Owner,
             ColumnKind::Commit,
             ColumnKind::Time,
             ColumnKind::Message,
@@ -149,11 +174,25 @@ mod tests {
             .unwrap();
         assert_eq!(ci_status.requires_task, Some(TaskKind::CiStatus));
 
+        let disk_usage = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::DiskUsage)
+            .unwrap();
+        assert_eq!(disk_usage.requires_task, Some(TaskKind::DiskUsage));
+
+        let owner = COLUMN_SPECS
+            .iter()
+            .find(|c| c.kind == ColumnKind::Owner)
+            .unwrap();
+        assert_eq!(owner.requires_task, Some(TaskKind::Owner));
+
         // All other columns should not require a background task to render
         for spec in COLUMN_SPECS {
             if spec.kind != ColumnKind::BranchDiff
                 && spec.kind != ColumnKind::Url
                 && spec.kind != ColumnKind::CiStatus
+                && spec.kind != ColumnKind::DiskUsage
+                && spec.kind != ColumnKind::Owner
             {
                 assert!(
                     spec.requires_task.is_none(),
@@ -211,15 +250,20 @@ mod tests {
         // If this fails, a new variant was added but not registered in COLUMN_SPECS.
         let all_kinds = [
             ColumnKind::Gutter,
+            ColumnKind::Index,
             ColumnKind::Branch,
             ColumnKind::Status,
             ColumnKind::WorkingDiff,
             ColumnKind::AheadBehind,
+            ColumnKind::Graph,
             ColumnKind::BranchDiff,
             ColumnKind::Path,
             ColumnKind::Upstream,
             ColumnKind::Url,
+            ColumnKind::Issue,
             ColumnKind::CiStatus,
+            ColumnKind::DiskUsage,
+            ColumnKind::Owner,
             ColumnKind::Commit,
             ColumnKind::Time,
             ColumnKind::Message,
@@ -0,0 +1,219 @@
+//! Per-worktree disk usage for `wt list --du`.
+//!
+//! Walking a worktree with a large `node_modules/` or `target/` takes seconds,
+//! so sizes are cached in `.git/wt-cache/disk-usage/`, in one file per worktree
+//! path with one entry per top-level directory. An entry is reused while that
+//! directory's mtime is unchanged and the entry is younger than
+//! [`CACHE_TTL_SECS`]. The mtime catches the common cases (installing
+//! dependencies, clearing build output) immediately; edits deep inside an
+//! otherwise unchanged directory don't touch it, so the TTL bounds how stale
+//! those sizes get.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+use worktrunk::git::Repository;
+use worktrunk::path::sanitize_for_filename;
+use worktrunk::utils::get_now;
+
+/// How long a directory's cached size is trusted while its mtime is unchanged
+const CACHE_TTL_SECS: u64 = 10 * 60;
+
+/// Cached size of one top-level directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedEntry {
+    /// Directory mtime (nanoseconds since the epoch) when the size was computed
+    mtime: u128,
+    bytes: u64,
+    /// Unix timestamp when the size was computed
+    #[serde(default)]
+    computed_at: u64,
+}
+
+/// Cached disk usage stored in `.git/wt-cache/disk-usage/<worktree>-<hash>.json`
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CachedDiskUsage {
+    /// Top-level directory name → cached size
+    entries: BTreeMap<String, CachedEntry>,
+}
+
+impl CachedDiskUsage {
+    /// Get the cache file path for a worktree.
+    ///
+    /// Keyed on the canonical path, so worktrees that share a directory name
+    /// (`~/a/app` and `~/b/app`) get separate files; the name is kept for
+    /// readability.
+    fn cache_file(repo: &Repository, worktree_path: &Path) -> PathBuf {
+        let canonical =
+            dunce::canonicalize(worktree_path).unwrap_or_else(|_| worktree_path.to_path_buf());
+        let name = canonical
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        // FNV-1a rather than `DefaultHasher`, whose output may change between
+        // Rust releases and would orphan the cache files
+        let hash = canonical
+            .to_string_lossy()
+            .bytes()
+            .fold(0xcbf29ce484222325_u64, |h, b| {
+                (h ^ u64::from(b)).wrapping_mul(0x100000001b3)
+            });
+        repo.git_common_dir()
+            .join("wt-cache")
+            .join("disk-usage")
+            .join(format!("{}-{hash:016x}.json", sanitize_for_filename(&name)))
+    }
+
+    fn read(repo: &Repository, worktree_path: &Path) -> Self {
+        fs::read_to_string(Self::cache_file(repo, worktree_path))
+            .ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    /// Write the cache, ignoring failures (the cache is an optimization only).
+    fn write(&self, repo: &Repository, worktree_path: &Path) {
+        let path = Self::cache_file(repo, worktree_path);
+        if let Some(parent) = path.parent()
+            && let Err(e) = fs::create_dir_all(parent)
+        {
+            log::debug!("Failed to create disk usage cache dir: {}", e);
+            return;
+        }
+        let Ok(json) = serde_json::to_string(self) else {
+            return;
+        };
+        // Write to temp file first, then rename for atomic update
+        let temp_path = path.with_extension("json.tmp");
+        if fs::write(&temp_path, json).is_err() || fs::rename(&temp_path, &path).is_err() {
+            log::debug!("Failed to write disk usage cache {}", path.display());
+            let _ = fs::remove_file(&temp_path);
+        }
+    }
+}
+
+/// Compute the disk usage of a worktree in bytes, excluding `.git`.
+///
+/// `.git` is skipped so the main worktree (which holds the object store) is
+/// comparable with linked worktrees.
+pub(crate) fn worktree_disk_usage(repo: &Repository, worktree_path: &Path) -> anyhow::Result<u64> {
+    let cached = CachedDiskUsage::read(repo, worktree_path);
+    let mut updated = CachedDiskUsage::default();
+    let mut total = 0;
+    let now = get_now();
+
+    for entry in fs::read_dir(worktree_path)? {
+        let entry = entry?;
+        let name = entry.file_name().to_string_lossy().into_owned();
+        if name == ".git" {
+            continue;
+        }
+        let metadata = entry.path().symlink_metadata()?;
+        if !metadata.is_dir() {
+            total += allocated_size(&metadata);
+            continue;
+        }
+
+        let mtime = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+            .map(|d| d.as_nanos())
+            .unwrap_or_default();
+        let size = match cached.entries.get(&name) {
+            Some(hit)
+                if hit.mtime == mtime && now.saturating_sub(hit.computed_at) < CACHE_TTL_SECS =>
+            {
+                hit.clone()
+            }
+            _ => CachedEntry {
+                mtime,
+                bytes: dir_size(&entry.path()),
+                computed_at: now,
+            },
+        };
+        total += size.bytes;
+        updated.entries.insert(name, size);
+    }
+
+    updated.write(repo, worktree_path);
+    Ok(total)
+}
+
+/// Recursively sum allocated sizes under a directory, without following symlinks.
+///
+/// Unreadable entries are skipped rather than failing the whole walk.
+pub(crate) fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+    let mut total = 0;
+    for entry in entries.flatten() {
+        let Ok(metadata) = entry.path().symlink_metadata() else {
+            continue;
+        };
+        total += if metadata.is_dir() {
+            allocated_size(&metadata) + dir_size(&entry.path())
+        } else {
+            allocated_size(&metadata)
+        };
+    }
+    total
+}
+
+/// Size of a file or directory tree, without following symlinks.
+pub(crate) fn path_size(path: &Path) -> u64 {
+    match path.symlink_metadata() {
+        Ok(metadata) if metadata.is_dir() => allocated_size(&metadata) + dir_size(path),
+        Ok(metadata) => allocated_size(&metadata),
+        Err(_) => 0,
+    }
+}
+
+/// Bytes allocated on disk (like `du`), falling back to the apparent size.
+#[cfg(unix)]
+fn allocated_size(metadata: &fs::Metadata) -> u64 {
+    use std::os::unix::fs::MetadataExt;
+    metadata.blocks() * 512
+}
+
+#[cfg(not(unix))]
+fn allocated_size(metadata: &fs::Metadata) -> u64 {
+    metadata.len()
+}
+
+/// Format a byte count compactly, like `du -h` (e.g. `0B`, `12K`, `8.2G`).
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["K", "M", "G", "T", "P"];
+    if bytes < 1024 {
+        return format!("{bytes}B");
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if value < 10.0 {
+        format!("{value:.1}{}", UNITS[unit])
+    } else {
+        format!("{value:.0}{}", UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0B");
+        assert_eq!(format_size(1023), "1023B");
+        assert_eq!(format_size(1024), "1.0K");
+        assert_eq!(format_size(12 * 1024), "12K");
+        assert_eq!(format_size(512 * 1024 * 1024), "512M");
+        assert_eq!(format_size(8_800_000_000), "8.2G");
+    }
+}
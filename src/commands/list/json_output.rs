@@ -18,7 +18,9 @@
 use std::path::PathBuf;
 
 use serde::Serialize;
+use worktrunk::config::IssueRef;
 use worktrunk::git::LineDiff;
+use worktrunk::utils::format_timestamp_iso8601;
 
 use super::ci_status::{CiSource, PrStatus};
 use super::model::{ItemKind, ListItem, UpstreamStatus};
@@ -90,6 +92,18 @@ pub struct JsonItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url_active: Option<bool>,
 
+    /// Branch description from `branch.<name>.description` (only with --full)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// `WT_OWNER` marker or last commit author (only with --owner)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+
+    /// Issue reference extracted from the branch name via `[integrations.issues]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue: Option<IssueRef>,
+
     /// Pre-formatted statusline for statusline tools (tmux, starship)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub statusline: Option<String>,
@@ -113,6 +127,11 @@ pub struct JsonCommit {
 
     /// Unix timestamp of commit
     pub timestamp: i64,
+
+    /// Commit time as ISO 8601 UTC (e.g., "2025-01-01T00:00:00Z"), whatever
+    /// `--absolute-time` and `[list]` time settings the table uses (null
+    /// without a commit)
+    pub time: Option<String>,
 }
 
 /// Working tree state
@@ -197,6 +216,10 @@ pub struct JsonWorktree {
 
     /// HEAD is detached (not on a branch)
     pub detached: bool,
+
+    /// Disk usage in bytes, excluding `.git` (only with `--du`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk_usage: Option<u64>,
 }
 
 /// CI status from PR or branch workflow
@@ -214,6 +237,10 @@ pub struct JsonCi {
     /// URL to the PR/MR (if available)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
+
+    /// PR/MR review state: "draft", "review-required", "changes-requested", "approved"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review_state: Option<&'static str>,
 }
 
 impl JsonItem {
@@ -235,6 +262,7 @@ impl JsonItem {
         } else {
             sha.clone()
         };
+        let timestamp = item.commit.as_ref().map(|c| c.timestamp);
         let commit = JsonCommit {
             sha,
             short_sha,
@@ -243,7 +271,8 @@ impl JsonItem {
                 .as_ref()
                 .map(|c| c.commit_message.clone())
                 .unwrap_or_default(),
-            timestamp: item.commit.as_ref().map(|c| c.timestamp).unwrap_or(0),
+            timestamp: timestamp.unwrap_or(0),
+            time: timestamp.map(|ts| format_timestamp_iso8601(ts.max(0) as u64)),
         };
 
         // Working tree (only for worktrees with status symbols)
@@ -302,6 +331,7 @@ impl JsonItem {
                 state,
                 reason,
                 detached: data.detached,
+                disk_usage: data.disk_usage,
             }
         });
 
@@ -341,6 +371,9 @@ impl JsonItem {
             ci,
             url: item.url.clone(),
             url_active: item.url_active,
+            description: item.description.clone(),
+            owner: item.owner.clone(),
+            issue: item.issue.clone(),
             statusline,
             symbols,
         }
@@ -400,6 +433,7 @@ impl From<&PrStatus> for JsonCi {
             source: pr.source,
             stale: pr.is_stale,
             url: pr.url.clone(),
+            review_state: pr.review_state.map(Into::into),
         }
     }
 }
@@ -453,7 +487,7 @@ pub fn to_json_items(items: &[ListItem]) -> Vec<JsonItem> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::commands::list::ci_status::{CiSource, CiStatus};
+    use crate::commands::list::ci_status::{CiSource, CiStatus, ReviewState};
     use crate::commands::list::model::{
         Divergence, GitOperationState, MainState, OperationState, StatusSymbols, WorkingTreeStatus,
         WorktreeData, WorktreeState,
@@ -497,6 +531,7 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: Some("https://github.com/org/repo/pull/123".to_string()),
+            review_state: None,
         };
         let json = JsonCi::from(&pr);
         assert_eq!(json.status, "passed");
@@ -515,6 +550,7 @@ mod tests {
             source: CiSource::Branch,
             is_stale: true,
             url: None,
+            review_state: None,
         };
         let json = JsonCi::from(&pr);
         assert_eq!(json.status, "failed");
@@ -530,6 +566,7 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: None,
+            review_state: None,
         };
         let json = JsonCi::from(&pr);
         assert_eq!(json.status, "running");
@@ -542,6 +579,7 @@ mod tests {
             source: CiSource::PullRequest,
             is_stale: false,
             url: None,
+            review_state: None,
         };
         let json = JsonCi::from(&pr);
         assert_eq!(json.status, "conflicts");
@@ -554,11 +592,25 @@ mod tests {
             source: CiSource::Branch,
             is_stale: false,
             url: None,
+            review_state: None,
         };
         let json = JsonCi::from(&pr);
         assert_eq!(json.status, "no-ci");
     }
 
+    #[test]
+    fn test_json_ci_review_state() {
+        let pr = PrStatus {
+            ci_status: CiStatus::Passed,
+            source: CiSource::PullRequest,
+            is_stale: false,
+            url: None,
+            review_state: Some(ReviewState::ChangesRequested),
+        };
+        let json = JsonCi::from(&pr);
+        assert_eq!(json.review_state, Some("changes-requested"));
+    }
+
     #[test]
     fn test_json_ci_from_error() {
         let pr = PrStatus {
@@ -566,6 +618,7 @@ mod tests {
             source: CiSource::Branch,
             is_stale: false,
             url: None,
+            review_state: None,
         };
         let json = JsonCi::from(&pr);
         assert_eq!(json.status, "error");
@@ -581,6 +634,8 @@ mod tests {
             remote: Some("origin".to_string()),
             ahead: 3,
             behind: 2,
+            unpushed: false,
+            gone: false,
         };
         let branch = Some("feature".to_string());
         let json = upstream_to_json(&upstream, &branch);
@@ -598,6 +653,8 @@ mod tests {
             remote: None,
             ahead: 0,
             behind: 0,
+            unpushed: false,
+            gone: false,
         };
         let branch = Some("feature".to_string());
         let json = upstream_to_json(&upstream, &branch);
@@ -610,6 +667,8 @@ mod tests {
             remote: Some("origin".to_string()),
             ahead: 1,
             behind: 0,
+            unpushed: false,
+            gone: false,
         };
         let branch = None;
         let json = upstream_to_json(&upstream, &branch);
@@ -629,12 +688,14 @@ mod tests {
             is_current: false,
             is_previous: false,
             detached: false,
+            detached_at: None,
             locked: None,
             prunable: None,
             working_tree_diff: None,
             git_operation: GitOperationState::None,
             branch_worktree_mismatch: false,
             working_diff_display: None,
+            disk_usage: None,
         }
     }
 
@@ -814,6 +875,7 @@ mod tests {
             short_sha: "abc123d".to_string(),
             message: "Fix bug".to_string(),
             timestamp: 1700000000,
+            time: Some("2023-11-14T22:13:20Z".to_string()),
         };
         let json = serde_json::to_string(&commit).unwrap();
         assert!(json.contains("abc123def456"));
@@ -821,6 +883,19 @@ mod tests {
         assert!(json.contains("1700000000"));
     }
 
+    #[test]
+    fn test_json_commit_without_time_serializes_null() {
+        let commit = JsonCommit {
+            sha: String::new(),
+            short_sha: String::new(),
+            message: String::new(),
+            timestamp: 0,
+            time: None,
+        };
+        let json = serde_json::to_string(&commit).unwrap();
+        assert!(json.contains("\"time\":null"), "{json}");
+    }
+
     #[test]
     fn test_json_working_tree_serialization() {
         let wt = JsonWorkingTree {
@@ -874,6 +949,7 @@ mod tests {
             state: Some("locked"),
             reason: Some("manual".to_string()),
             detached: false,
+            disk_usage: None,
         };
         let json = serde_json::to_string(&wt).unwrap();
         assert!(json.contains("\"state\":\"locked\""));
@@ -54,7 +54,7 @@
 //! final_priority = base_priority + empty_penalty
 //! ```
 //!
-//! **Base priorities** (1-11) are determined by **user need hierarchy** - what questions users need
+//! **Base priorities** (1-17) are determined by **user need hierarchy** - what questions users need
 //! answered when scanning worktrees:
 //! - 1: Branch (identity - "what is this?")
 //! - 2: Working diff (critical - "do I need to commit?")
@@ -62,16 +62,17 @@
 //! - 4-10: Context (work volume, states, path, time, CI, etc.)
 //! - 11: Message (nice-to-have, space-hungry)
 //!
-//! **Empty penalty**: +10 if column has no data (only header)
-//! - Empty working_diff: 2 + 10 = priority 12
-//! - Empty ahead/behind: 3 + 10 = priority 13
+//! **Empty penalty**: +20 if column has no data (only header)
+//! - Empty working_diff: 2 + 20 = priority 22
+//! - Empty ahead/behind: 3 + 20 = priority 23
 //! - etc.
 //!
 //! This creates two effective priority tiers:
-//! - **Tier 1 (priorities 1-11)**: Columns with actual data
-//! - **Tier 2 (priorities 12-21)**: Empty columns (visual consistency)
+//! - **Tier 1 (priorities 1-17)**: Columns with actual data
+//! - **Tier 2 (priorities 21-37)**: Empty columns (visual consistency)
 //!
-//! The empty penalty is large (+10) but not infinite, so empty columns maintain their relative
+//! The penalty exceeds every base priority, so any column with data outranks any empty
+//! one. It is large (+20) but not infinite, so empty columns maintain their relative
 //! ordering (empty working_diff still ranks higher than empty ci_status) for visual consistency.
 //!
 //! ## Why This Design?
@@ -112,7 +113,7 @@
 //! 2. **CiStatus** - Visibility gate (`fetch_ci` flag)
 //!    - Only shown when `fetch_ci=true` (when CI data was requested)
 //!    - Bypasses the tier system entirely when `fetch_ci=false`
-//!    - Within the visibility gate, follows normal two-tier priority (priority 9 with data, 19 when empty)
+//!    - Within the visibility gate, follows normal two-tier priority (priority 9 with data, 29 when empty)
 //!
 //! 3. **Message** - Flexible sizing with post-allocation expansion
 //!    - Allocated at priority 11 with flexible width (min 20, preferred 50)
@@ -170,15 +171,18 @@
 //! - `fit_header()`: Ensures column width ≥ header width to prevent overflow
 //! - `try_allocate()`: Attempts to allocate space, returns 0 if insufficient
 
-use crate::display::get_terminal_width;
+use crate::display::{TimeFormat, get_terminal_width};
 use anstyle::Style;
+use std::borrow::Cow;
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use unicode_width::UnicodeWidthStr;
-use worktrunk::styling::{ADDITION, DELETION, Stream, supports_hyperlinks};
+use worktrunk::config::ColumnMaxWidths;
+use worktrunk::styling::{ADDITION, DELETION, Stream, sanitize_for_display, supports_hyperlinks};
 
 use super::collect::{TaskKind, parse_port_from_url};
 use super::columns::{COLUMN_SPECS, ColumnKind, ColumnSpec, column_display_index};
+use super::tune::ColumnTuning;
 
 // Re-export DiffVariant for external use (e.g., select command)
 pub use super::columns::DiffVariant;
@@ -186,6 +190,51 @@ pub use super::columns::DiffVariant;
 /// Width of short commit hash display (first 8 hex characters)
 const COMMIT_HASH_WIDTH: usize = 8;
 
+/// Commits drawn on each side of the merge-base in the Graph column
+const GRAPH_DEPTH: usize = 5;
+
+/// How values render, independent of the space they get.
+#[derive(Clone, Debug, Default)]
+pub struct DisplayOptions {
+    /// Draw the Graph column (`--graph`)
+    pub graph: bool,
+    /// Ages or dates in the Age column (`--absolute-time`, `[list]` time settings)
+    pub time: TimeFormat,
+}
+
+/// Limits on how wide values render, from `--no-truncate`, `[list.max-width]`
+/// and `wt list --tune`.
+#[derive(Clone, Debug, Default)]
+pub struct WidthLimits {
+    /// Show full values: lay out as if the terminal were unbounded, letting
+    /// lines overflow (the terminal wraps them)
+    pub no_truncate: bool,
+    /// Per-column caps; ignored with `no_truncate`
+    pub max_widths: ColumnMaxWidths,
+    /// Saved widths and hidden columns; widths are ignored with `no_truncate`
+    pub tuning: ColumnTuning,
+}
+
+impl WidthLimits {
+    /// The tuned width for a column, which replaces its estimate.
+    fn tuned_width(&self, kind: ColumnKind) -> Option<usize> {
+        if self.no_truncate {
+            return None;
+        }
+        self.tuning
+            .width(kind)
+            .map(|width| fit_header(kind.header(), width))
+    }
+
+    /// Cap `width` at the configured maximum, but never below the header.
+    fn cap(&self, width: usize, max: Option<usize>, header: &str) -> usize {
+        match max {
+            Some(max) if !self.no_truncate => width.min(fit_header(header, max)),
+            _ => width,
+        }
+    }
+}
+
 /// Ensures a column width is at least as wide as its header.
 ///
 /// This is the general solution for preventing header overflow: pass the header
@@ -236,11 +285,16 @@ pub struct DiffWidths {
 
 #[derive(Clone, Debug)]
 pub struct ColumnWidths {
+    pub index: usize,
     pub branch: usize,
     pub status: usize, // Includes both git status symbols and user-defined status
     pub time: usize,
     pub url: usize,
+    pub issue: usize,
     pub ci_status: usize,
+    pub disk_usage: usize,
+    pub owner: usize,
+    pub graph: usize,
     pub message: usize,
     pub ahead_behind: DiffWidths,
     pub working_diff: DiffWidths,
@@ -257,7 +311,12 @@ pub struct ColumnDataFlags {
     pub branch_diff: bool,
     pub upstream: bool,
     pub url: bool,
+    pub issue: bool,
+    pub index: bool,
     pub ci_status: bool,
+    pub disk_usage: bool,
+    pub owner: bool,
+    pub graph: bool,
     pub path: bool, // True if any worktree has branch_worktree_mismatch
 }
 
@@ -269,7 +328,8 @@ pub struct LayoutMetadata {
     pub status_position_mask: super::model::PositionMask,
 }
 
-const EMPTY_PENALTY: u8 = 10;
+/// Larger than every base priority, so columns with data always outrank empty ones.
+const EMPTY_PENALTY: u8 = 20;
 
 #[derive(Clone, Copy, Debug)]
 pub struct DiffDisplayConfig {
@@ -400,16 +460,21 @@ impl ColumnKind {
     pub fn has_data(self, flags: &ColumnDataFlags) -> bool {
         match self {
             ColumnKind::Gutter => true, // Always present (shows @ ^ + or space)
+            ColumnKind::Index => flags.index,
             ColumnKind::Branch => true,
             ColumnKind::Status => flags.status,
             ColumnKind::WorkingDiff => flags.working_diff,
             ColumnKind::AheadBehind => flags.ahead_behind,
+            ColumnKind::Graph => flags.graph,
             ColumnKind::BranchDiff => flags.branch_diff,
             ColumnKind::Path => flags.path,
             ColumnKind::Upstream => flags.upstream,
             ColumnKind::Url => flags.url,
+            ColumnKind::Issue => flags.issue,
             ColumnKind::Time => true,
             ColumnKind::CiStatus => flags.ci_status,
+            ColumnKind::DiskUsage => flags.disk_usage,
+            ColumnKind::Owner => flags.owner,
             ColumnKind::Commit => true,
             ColumnKind::Message => true,
         }
@@ -441,12 +506,17 @@ impl ColumnKind {
 
         match self {
             ColumnKind::Gutter => text(2), // Fixed width: symbol (1 char) + space (1 char)
+            ColumnKind::Index => text(widths.index),
             ColumnKind::Branch => text(widths.branch),
             ColumnKind::Status => text(widths.status),
             ColumnKind::Path => text(max_path_width),
             ColumnKind::Time => text(widths.time),
             ColumnKind::Url => text(widths.url),
+            ColumnKind::Issue => text(widths.issue),
             ColumnKind::CiStatus => text(widths.ci_status),
+            ColumnKind::DiskUsage => text(widths.disk_usage),
+            ColumnKind::Owner => text(widths.owner),
+            ColumnKind::Graph => text(widths.graph),
             ColumnKind::Commit => text(commit_width),
             ColumnKind::Message => None,
             ColumnKind::WorkingDiff => diff(widths.working_diff),
@@ -486,6 +556,19 @@ pub struct LayoutConfig {
     pub max_message_len: usize,
     pub hidden_column_count: usize,
     pub status_position_mask: super::model::PositionMask,
+    /// `[branch] prefix` hidden from the Branch column
+    pub branch_prefix: Option<String>,
+    pub time_format: TimeFormat,
+}
+
+/// Branch name as shown in the Branch column, without the `[branch] prefix`
+/// and made [safe to print](sanitize_for_display).
+pub(super) fn display_branch<'a>(branch: &'a str, prefix: Option<&str>) -> Cow<'a, str> {
+    let short = prefix
+        .and_then(|prefix| branch.strip_prefix(prefix))
+        .filter(|short| !short.is_empty())
+        .unwrap_or(branch);
+    sanitize_for_display(short)
 }
 
 #[derive(Clone, Copy)]
@@ -527,7 +610,7 @@ fn estimate_url_width(url_template: Option<&str>, hyperlinks_supported: bool) ->
     template
         .replace("{{ branch | hash_port }}", "12345")
         .replace("{{ branch }}", "feature-xx")
-        .len()
+        .width()
 }
 
 /// Build pre-allocated column width estimates.
@@ -540,6 +623,9 @@ fn build_estimated_widths(
     skip_tasks: &HashSet<TaskKind>,
     has_branch_worktree_mismatch: bool,
     url_width: usize,
+    issue_width: usize,
+    index_width: usize,
+    display: &DisplayOptions,
 ) -> LayoutMetadata {
     // Fixed widths for slow columns (require expensive git operations)
     // Values exceeding these widths use compact notation (K suffix)
@@ -551,8 +637,10 @@ fn build_estimated_widths(
     let ahead_behind_fixed = fit_header(ColumnKind::AheadBehind.header(), 7); // "↑99 ↓99"
     let branch_diff_fixed = fit_header(ColumnKind::BranchDiff.header(), 9); // "+999 -999"
     let upstream_fixed = fit_header(ColumnKind::Upstream.header(), 7); // "↑99 ↓99"
-    let age_estimate = 4; // "11mo" (short format)
+    let age_estimate = display.time.width_estimate(); // "11mo" (short format) by default
     let ci_estimate = fit_header(ColumnKind::CiStatus.header(), 1); // Single indicator symbol
+    let disk_usage_estimate = fit_header(ColumnKind::DiskUsage.header(), 5); // "1023M"
+    let owner_estimate = fit_header(ColumnKind::Owner.header(), 12); // Names truncate
 
     // Assume columns will have data (better to show and hide than to not show).
     // This is a limitation of progressive mode - we can't know which columns have data
@@ -568,7 +656,12 @@ fn build_estimated_widths(
         branch_diff: !skip_tasks.contains(&TaskKind::BranchDiff),
         upstream: true,
         url: !skip_tasks.contains(&TaskKind::UrlStatus),
+        issue: issue_width > 0,
+        index: index_width > 0,
         ci_status: !skip_tasks.contains(&TaskKind::CiStatus),
+        disk_usage: !skip_tasks.contains(&TaskKind::DiskUsage),
+        owner: !skip_tasks.contains(&TaskKind::Owner),
+        graph: display.graph,
         path: has_branch_worktree_mismatch,
     };
 
@@ -580,12 +673,38 @@ fn build_estimated_widths(
         0
     };
 
+    // Issue keys are extracted before layout, so the width is exact
+    let issue_estimate = if issue_width > 0 {
+        fit_header(ColumnKind::Issue.header(), issue_width)
+    } else {
+        0
+    };
+
+    // Row numbers are assigned before layout, so the width is exact
+    let index_estimate = if index_width > 0 {
+        fit_header(ColumnKind::Index.header(), index_width)
+    } else {
+        0
+    };
+
+    // Behind commits, the merge-base, then ahead commits
+    let graph_estimate = if display.graph {
+        fit_header(ColumnKind::Graph.header(), 2 * GRAPH_DEPTH + 1)
+    } else {
+        0
+    };
+
     let widths = ColumnWidths {
+        index: index_estimate,
         branch: max_branch,
         status: status_fixed,
         time: age_estimate,
         url: url_estimate,
+        issue: issue_estimate,
         ci_status: ci_estimate,
+        disk_usage: disk_usage_estimate,
+        owner: owner_estimate,
+        graph: graph_estimate,
         message: 50, // Will be flexible during allocation
         // Commit counts (Arrows): compact notation, 2 digits covers up to 99
         ahead_behind: DiffWidths {
@@ -619,20 +738,35 @@ fn build_estimated_widths(
     }
 }
 
+/// Columns chosen by [`allocate_columns_with_priority`], before they're bound
+/// to a repo in [`LayoutConfig`].
+#[derive(Clone, Debug)]
+struct ColumnAllocation {
+    columns: Vec<ColumnLayout>,
+    max_message_len: usize,
+    hidden_column_count: usize,
+}
+
 /// Allocate columns using priority-based allocation logic.
 ///
 /// This is the core allocation algorithm used by `calculate_layout_from_basics()`
-/// with pre-allocated width estimates for expensive-to-compute columns.
+/// with pre-allocated width estimates for expensive-to-compute columns. It is a
+/// pure function of its inputs, which keeps it amenable to property testing.
 fn allocate_columns_with_priority(
     metadata: &LayoutMetadata,
     skip_tasks: &HashSet<TaskKind>,
     max_path_width: usize,
     commit_width: usize,
     terminal_width: usize,
-    main_worktree_path: PathBuf,
-) -> LayoutConfig {
+    limits: &WidthLimits,
+) -> ColumnAllocation {
     let spacing = 2;
-    let mut remaining = terminal_width;
+    // Without truncation every column fits; lines overflow instead
+    let mut remaining = if limits.no_truncate {
+        usize::MAX / 2
+    } else {
+        terminal_width
+    };
 
     // Build candidates with priorities
     // Filter out columns whose required task is being skipped
@@ -642,6 +776,7 @@ fn allocate_columns_with_priority(
             spec.requires_task
                 .is_none_or(|task| !skip_tasks.contains(&task))
         })
+        .filter(|spec| !limits.tuning.is_hidden(spec.kind))
         .map(|spec| ColumnCandidate {
             spec,
             priority: if spec.kind.has_data(&metadata.data_flags) {
@@ -661,7 +796,9 @@ fn allocate_columns_with_priority(
         .collect();
 
     const MIN_MESSAGE: usize = 10;
-    const MAX_MESSAGE: usize = 100;
+    let max_message = limits
+        .tuned_width(ColumnKind::Message)
+        .unwrap_or_else(|| limits.cap(100, limits.max_widths.message, ""));
 
     let mut pending: Vec<PendingColumn> = Vec::new();
 
@@ -696,7 +833,7 @@ fn allocate_columns_with_priority(
             // bring it up to preferred/max width after empty columns have a chance
             // to be allocated.
             if available >= MIN_MESSAGE {
-                message_width = MIN_MESSAGE.min(metadata.widths.message);
+                message_width = MIN_MESSAGE.min(metadata.widths.message).min(max_message);
             }
 
             if message_width > 0 {
@@ -712,15 +849,30 @@ fn allocate_columns_with_priority(
         }
 
         // For non-message columns
-        let Some((ideal_width, format)) =
+        let Some((mut ideal_width, format)) =
             spec.kind
                 .ideal(&metadata.widths, max_path_width, commit_width)
         else {
             continue;
         };
+        // Diff columns align digits to their computed width, so only text is tuned
+        if let (ColumnFormat::Text, Some(tuned)) = (format, limits.tuned_width(spec.kind)) {
+            ideal_width = tuned;
+        }
 
         let skip_spacing = !needs_spacing(&pending);
-        let allocated = try_allocate(&mut remaining, ideal_width, spacing, skip_spacing);
+        let mut allocated = try_allocate(&mut remaining, ideal_width, spacing, skip_spacing);
+        // The branch identifies the row, so on terminals too narrow for it,
+        // it takes what's left and is truncated rather than hidden
+        if allocated == 0 && spec.kind == ColumnKind::Branch {
+            let spacing_cost = if skip_spacing { 0 } else { spacing };
+            allocated = try_allocate(
+                &mut remaining,
+                remaining.saturating_sub(spacing_cost),
+                spacing,
+                skip_spacing,
+            );
+        }
         if allocated > 0 {
             pending.push(PendingColumn {
                 spec,
@@ -736,11 +888,16 @@ fn allocate_columns_with_priority(
         .iter_mut()
         .find(|col| col.spec.kind == ColumnKind::Message)
     {
-        if message_col.width < MAX_MESSAGE && remaining > 0 {
-            let expansion = remaining.min(MAX_MESSAGE - message_col.width);
+        if message_col.width < max_message && remaining > 0 {
+            let expansion = remaining.min(max_message - message_col.width);
             message_col.width += expansion;
         }
-        max_message_len = message_col.width;
+        // Message is the last column, so with --no-truncate it runs to its end
+        max_message_len = if limits.no_truncate {
+            usize::MAX
+        } else {
+            message_col.width
+        };
     }
 
     // Sort by display order to maintain correct visual order
@@ -786,12 +943,10 @@ fn allocate_columns_with_priority(
         .filter(|(kind, _has_data)| !allocated_kinds.contains(kind))
         .count();
 
-    LayoutConfig {
+    ColumnAllocation {
         columns,
-        main_worktree_path,
         max_message_len,
         hidden_column_count,
-        status_position_mask: metadata.status_position_mask,
     }
 }
 
@@ -811,15 +966,20 @@ fn allocate_columns_with_priority(
 /// - Ahead/behind: 7 chars ("↑99 ↓99")
 /// - Branch diff: 9 chars ("+999 -999")
 /// - Upstream: 7 chars ("↑99 ↓99")
-/// - Age: 4 chars ("11mo" short format)
+/// - Age: 4 chars ("11mo" short format), or the width of a formatted date
 /// - CI: 1 char (indicator symbol)
+/// - Size: 5 chars ("1023M")
 /// - Message: flexible (20-100 chars)
 /// - URL: estimated from template + longest branch
+/// - Graph: 11 chars (five commits either side of the merge-base), only with `--graph`
 pub fn calculate_layout_from_basics(
     items: &[super::model::ListItem],
     skip_tasks: &HashSet<TaskKind>,
     main_worktree_path: &Path,
     url_template: Option<&str>,
+    branch_prefix: Option<&str>,
+    limits: &WidthLimits,
+    display: &DisplayOptions,
 ) -> LayoutConfig {
     calculate_layout_with_width(
         items,
@@ -827,37 +987,60 @@ pub fn calculate_layout_from_basics(
         get_terminal_width(),
         main_worktree_path,
         url_template,
+        branch_prefix,
+        limits,
+        display,
     )
 }
 
 /// Calculate layout with explicit width (for contexts like skim where available width differs)
+#[allow(clippy::too_many_arguments)]
 pub fn calculate_layout_with_width(
     items: &[super::model::ListItem],
     skip_tasks: &HashSet<TaskKind>,
     terminal_width: usize,
     main_worktree_path: &Path,
     url_template: Option<&str>,
+    branch_prefix: Option<&str>,
+    limits: &WidthLimits,
+    display: &DisplayOptions,
 ) -> LayoutConfig {
     // Calculate actual widths for things we know
     // Include branch names from both worktrees and standalone branches
-    let longest_branch = items
+    // Detached worktrees show `(detached at v1.2.3~2)` there instead
+    let max_branch = items
         .iter()
         .filter_map(|item| item.branch.as_deref())
-        .max_by_key(|b| b.width());
-
-    let max_branch = longest_branch.map(|b| b.width()).unwrap_or(0);
+        .map(|branch| display_branch(branch, branch_prefix).width())
+        .chain(
+            items
+                .iter()
+                .filter_map(|item| Some(item.detached_label()?.width())),
+        )
+        .max()
+        .unwrap_or(0);
     let max_branch = fit_header(ColumnKind::Branch.header(), max_branch);
+    let max_branch = limits.cap(
+        max_branch,
+        limits.max_widths.branch,
+        ColumnKind::Branch.header(),
+    );
 
     let path_data_width = items
         .iter()
         .filter_map(|item| item.worktree_path())
         .map(|path| {
             use crate::display::shorten_path;
-            shorten_path(path.as_path(), main_worktree_path).width()
+            sanitize_for_display(&shorten_path(path.as_path(), main_worktree_path)).width()
         })
         .max()
         .unwrap_or(0);
     let max_path_width = fit_header(ColumnKind::Path.header(), path_data_width);
+    let max_path_width = limits.cap(
+        max_path_width,
+        limits.max_widths.path,
+        ColumnKind::Path.header(),
+    );
 
     // Check if any worktree has a branch-worktree mismatch.
     // Path column is only useful when there's a mismatch; otherwise it's redundant with branch.
@@ -868,6 +1051,22 @@ pub fn calculate_layout_with_width(
 
     // Estimate URL width from template (heuristic, no expansion needed)
     let url_width = estimate_url_width(url_template, supports_hyperlinks(Stream::Stdout));
+    let url_width = limits.cap(url_width, limits.max_widths.url, ColumnKind::Url.header());
+
+    // Issue keys are known up front (extracted from branch names before layout)
+    let issue_width = items
+        .iter()
+        .filter_map(|item| item.issue.as_ref())
+        .map(|issue| issue.key.width())
+        .max()
+        .unwrap_or(0);
+
+    // Row numbers are assigned in collect() when `--index` is set
+    let index_width = items
+        .iter()
+        .filter_map(|item| item.index)
+        .max()
+        .map_or(0, |max| max.to_string().len());
 
     // Build pre-allocated width estimates (same as buffered mode)
     let metadata = build_estimated_widths(
@@ -875,18 +1074,31 @@ pub fn calculate_layout_with_width(
         skip_tasks,
         has_branch_worktree_mismatch,
         url_width,
+        issue_width,
+        index_width,
+        display,
     );
 
     let commit_width = fit_header(ColumnKind::Commit.header(), COMMIT_HASH_WIDTH);
 
-    allocate_columns_with_priority(
+    let allocation = allocate_columns_with_priority(
         &metadata,
         skip_tasks,
         max_path_width,
         commit_width,
         terminal_width,
-        main_worktree_path.to_path_buf(),
-    )
+        limits,
+    );
+
+    LayoutConfig {
+        columns: allocation.columns,
+        main_worktree_path: main_worktree_path.to_path_buf(),
+        max_message_len: allocation.max_message_len,
+        hidden_column_count: allocation.hidden_column_count,
+        status_position_mask: metadata.status_position_mask,
+        branch_prefix: branch_prefix.map(str::to_string),
+        time_format: display.time.clone(),
+    }
 }
 
 #[cfg(test)]
@@ -944,7 +1156,12 @@ mod tests {
             branch_diff: true,
             upstream: true,
             url: true,
+            issue: true,
+            index: true,
             ci_status: true,
+            disk_usage: true,
+            owner: true,
+            graph: true,
             path: true,
         };
         let all_false = ColumnDataFlags {
@@ -954,7 +1171,12 @@ mod tests {
             branch_diff: false,
             upstream: false,
             url: false,
+            issue: false,
+            index: false,
             ci_status: false,
+            disk_usage: false,
+            owner: false,
+            graph: false,
             path: false,
         };
 
@@ -978,8 +1200,18 @@ mod tests {
         assert!(!ColumnKind::Upstream.has_data(&all_false));
         assert!(ColumnKind::Url.has_data(&all_true));
         assert!(!ColumnKind::Url.has_data(&all_false));
+        assert!(ColumnKind::Issue.has_data(&all_true));
+        assert!(!ColumnKind::Issue.has_data(&all_false));
+        assert!(ColumnKind::Index.has_data(&all_true));
+        assert!(!ColumnKind::Index.has_data(&all_false));
         assert!(ColumnKind::CiStatus.has_data(&all_true));
         assert!(!ColumnKind::CiStatus.has_data(&all_false));
+        assert!(ColumnKind::DiskUsage.has_data(&all_true));
+        assert!(!ColumnKind::DiskUsage.has_data(&all_false));
+        assert!(ColumnKind::Owner.has_data(&all_true));
+        assert!(!ColumnKind::Owner.has_data(&all_false));
+        assert!(ColumnKind::Graph.has_data(&all_true));
+        assert!(!ColumnKind::Graph.has_data(&all_false));
         assert!(ColumnKind::Path.has_data(&all_true));
         assert!(!ColumnKind::Path.has_data(&all_false));
     }
@@ -1019,7 +1251,12 @@ mod tests {
             status: 8,
             time: 4,
             url: 0,
+            issue: 0,
+            index: 0,
             ci_status: 2,
+            disk_usage: 5,
+            owner: 0,
+            graph: 0,
             message: 50,
             ahead_behind: DiffWidths {
                 total: 7,
@@ -1086,7 +1323,12 @@ mod tests {
             status: 0,
             time: 0,
             url: 0,
+            issue: 0,
+            index: 0,
             ci_status: 0,
+            disk_usage: 0,
+            owner: 0,
+            graph: 0,
             message: 0,
             ahead_behind: DiffWidths {
                 total: 0,
@@ -1118,8 +1360,17 @@ mod tests {
         // Test that build_estimated_widths() returns correct pre-allocated estimates
         // Empty skip set means all tasks are computed (equivalent to --full)
         // has_branch_worktree_mismatch=true to test the path flag is passed through
-        // url_width=0 since we're not testing URL column here
-        let metadata = build_estimated_widths(20, &HashSet::new(), true, 0);
+        // url_width, issue_width and index_width are 0 and the graph is off since we're not
+        // testing those columns here
+        let metadata = build_estimated_widths(
+            20,
+            &HashSet::new(),
+            true,
+            0,
+            0,
+            0,
+            &DisplayOptions::default(),
+        );
         let widths = metadata.widths;
 
         // Line diffs (Signs variant: +/-) allocate 3 digits for 100-999 range
@@ -1181,6 +1432,18 @@ mod tests {
             widths.upstream.negative_digits, 2,
             "Pre-allocated for 2-digit negative count"
         );
+
+        // Graph is opt-in: no space unless `--graph` asks for it
+        assert_eq!(widths.graph, 0);
+        let graph = DisplayOptions {
+            graph: true,
+            ..Default::default()
+        };
+        let with_graph = build_estimated_widths(20, &HashSet::new(), true, 0, 0, 0, &graph);
+        assert_eq!(
+            with_graph.widths.graph, 11,
+            "Graph should pre-allocate five commits either side of the merge-base"
+        );
     }
 
     #[test]
@@ -1214,15 +1477,22 @@ mod tests {
                 remote: Some("origin".to_string()),
                 ahead: 4,
                 behind: 2,
+                unpushed: false,
+                gone: false,
             }),
             pr_status: None,
             url: None,
             url_active: None,
+            description: None,
+            owner: None,
+            issue: None,
+            index: None,
             status_symbols: Some(StatusSymbols::default()),
             display: DisplayFields::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test/path"),
                 detached: false,
+                detached_at: None,
                 locked: None,
                 prunable: None,
                 working_tree_diff: Some(LineDiff::from((100, 50))),
@@ -1232,6 +1502,7 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 working_diff_display: None,
+                disk_usage: None,
             })),
         };
 
@@ -1240,7 +1511,15 @@ mod tests {
             .into_iter()
             .collect();
         let main_worktree_path = PathBuf::from("/test");
-        let layout = calculate_layout_from_basics(&items, &skip_tasks, &main_worktree_path, None);
+        let layout = calculate_layout_from_basics(
+            &items,
+            &skip_tasks,
+            &main_worktree_path,
+            None,
+            None,
+            &WidthLimits::default(),
+            &DisplayOptions::default(),
+        );
 
         assert!(
             !layout.columns.is_empty(),
@@ -1285,6 +1564,108 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_width_limits() {
+        use crate::commands::list::model::ListItem;
+
+        let items = vec![ListItem::new_branch(
+            "abc12345".to_string(),
+            "feature/a-rather-long-branch-name".to_string(),
+        )];
+        let layout_at = |width: usize, limits: WidthLimits| {
+            calculate_layout_with_width(
+                &items,
+                &HashSet::new(),
+                width,
+                Path::new("/repo"),
+                None,
+                None,
+                &limits,
+                &DisplayOptions::default(),
+            )
+        };
+        let branch_width = |layout: &LayoutConfig| {
+            layout
+                .columns
+                .iter()
+                .find(|col| col.kind == ColumnKind::Branch)
+                .map(|col| col.width)
+        };
+
+        // Too narrow for the branch: it's truncated, not hidden
+        let narrow = layout_at(20, WidthLimits::default());
+        assert_eq!(branch_width(&narrow), Some(18));
+
+        // Configured caps apply, but never below the header
+        let capped = WidthLimits {
+            max_widths: ColumnMaxWidths {
+                branch: Some(12),
+                message: Some(30),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let layout = layout_at(200, capped.clone());
+        assert_eq!(branch_width(&layout), Some(12));
+        assert_eq!(layout.max_message_len, 30);
+
+        // --no-truncate ignores both the terminal width and the caps
+        let no_truncate = WidthLimits {
+            no_truncate: true,
+            ..capped
+        };
+        let layout = layout_at(20, no_truncate);
+        assert_eq!(branch_width(&layout), Some(33));
+        assert_eq!(layout.max_message_len, usize::MAX);
+    }
+
+    #[test]
+    fn test_tuned_columns() {
+        use crate::commands::list::model::ListItem;
+
+        let items = vec![ListItem::new_branch(
+            "abc12345".to_string(),
+            "feature".to_string(),
+        )];
+        let layout_with = |tuning: ColumnTuning, no_truncate: bool| {
+            calculate_layout_with_width(
+                &items,
+                &HashSet::new(),
+                200,
+                Path::new("/repo"),
+                None,
+                None,
+                &WidthLimits {
+                    no_truncate,
+                    tuning,
+                    ..Default::default()
+                },
+                &DisplayOptions::default(),
+            )
+        };
+        let width_of = |layout: &LayoutConfig, kind: ColumnKind| {
+            layout
+                .columns
+                .iter()
+                .find(|col| col.kind == kind)
+                .map(|col| col.width)
+        };
+
+        let mut tuning = ColumnTuning::default();
+        tuning.widths.insert(ColumnKind::Branch, 30);
+        tuning.widths.insert(ColumnKind::Message, 20);
+        tuning.hidden.insert(ColumnKind::Commit);
+        let layout = layout_with(tuning.clone(), false);
+        assert_eq!(width_of(&layout, ColumnKind::Branch), Some(30));
+        assert_eq!(layout.max_message_len, 20);
+        assert_eq!(width_of(&layout, ColumnKind::Commit), None);
+
+        // --no-truncate ignores tuned widths but keeps columns hidden
+        let layout = layout_with(tuning, true);
+        assert_eq!(width_of(&layout, ColumnKind::Branch), Some(7));
+        assert_eq!(width_of(&layout, ColumnKind::Commit), None);
+    }
+
     #[test]
     fn test_column_positions_with_empty_columns() {
         use crate::commands::list::model::{
@@ -1316,11 +1697,16 @@ mod tests {
             pr_status: None,
             url: None,
             url_active: None,
+            description: None,
+            owner: None,
+            issue: None,
+            index: None,
             status_symbols: Some(StatusSymbols::default()),
             display: DisplayFields::default(),
             kind: ItemKind::Worktree(Box::new(WorktreeData {
                 path: PathBuf::from("/test"),
                 detached: false,
+                detached_at: None,
                 locked: None,
                 prunable: None,
                 working_tree_diff: Some(LineDiff::default()),
@@ -1330,6 +1716,7 @@ mod tests {
                 is_previous: false,
                 branch_worktree_mismatch: false,
                 working_diff_display: None,
+                disk_usage: None,
             })),
         };
 
@@ -1338,7 +1725,15 @@ mod tests {
             .into_iter()
             .collect();
         let main_worktree_path = PathBuf::from("/home/user/project");
-        let layout = calculate_layout_from_basics(&items, &skip_tasks, &main_worktree_path, None);
+        let layout = calculate_layout_from_basics(
+            &items,
+            &skip_tasks,
+            &main_worktree_path,
+            None,
+            None,
+            &WidthLimits::default(),
+            &DisplayOptions::default(),
+        );
 
         assert!(
             layout
@@ -1405,4 +1800,259 @@ mod tests {
         // With hyperlinks: has ":{{" pattern, compact display = 6
         assert_eq!(estimate_url_width(Some(template), true), 6);
     }
+
+    mod properties {
+        use super::*;
+        use proptest::prelude::*;
+
+        const MIN_MESSAGE: usize = 10;
+
+        fn diff_widths() -> impl Strategy<Value = DiffWidths> {
+            (0usize..=12, 0usize..=4, 0usize..=4).prop_map(
+                |(total, positive_digits, negative_digits)| DiffWidths {
+                    total,
+                    positive_digits,
+                    negative_digits,
+                },
+            )
+        }
+
+        fn column_widths() -> impl Strategy<Value = ColumnWidths> {
+            (
+                (
+                    0usize..=4,
+                    0usize..=60,
+                    0usize..=12,
+                    0usize..=6,
+                    0usize..=40,
+                ),
+                (
+                    0usize..=12,
+                    0usize..=3,
+                    0usize..=8,
+                    0usize..=16,
+                    0usize..=120,
+                ),
+                (diff_widths(), diff_widths(), diff_widths(), diff_widths()),
+            )
+                .prop_map(
+                    |(
+                        (index, branch, status, time, url),
+                        (issue, ci_status, disk_usage, owner, message),
+                        (ahead_behind, working_diff, branch_diff, upstream),
+                    )| ColumnWidths {
+                        index,
+                        branch,
+                        status,
+                        time,
+                        url,
+                        issue,
+                        ci_status,
+                        disk_usage,
+                        owner,
+                        message,
+                        ahead_behind,
+                        working_diff,
+                        branch_diff,
+                        upstream,
+                    },
+                )
+        }
+
+        fn data_flags() -> impl Strategy<Value = ColumnDataFlags> {
+            proptest::array::uniform12(any::<bool>()).prop_map(|f| ColumnDataFlags {
+                status: f[0],
+                working_diff: f[1],
+                ahead_behind: f[2],
+                branch_diff: f[3],
+                upstream: f[4],
+                url: f[5],
+                issue: f[6],
+                index: f[7],
+                ci_status: f[8],
+                disk_usage: f[9],
+                owner: f[10],
+                path: f[11],
+            })
+        }
+
+        fn skip_tasks() -> impl Strategy<Value = HashSet<TaskKind>> {
+            let optional = vec![
+                TaskKind::BranchDiff,
+                TaskKind::UrlStatus,
+                TaskKind::CiStatus,
+                TaskKind::DiskUsage,
+                TaskKind::Owner,
+            ];
+            proptest::sample::subsequence(optional, 0..=5)
+                .prop_map(|tasks| tasks.into_iter().collect())
+        }
+
+        /// Width a column needs (excluding the gap) for allocation to accept it.
+        fn required_width(
+            kind: ColumnKind,
+            metadata: &LayoutMetadata,
+            max_path_width: usize,
+            commit_width: usize,
+        ) -> Option<usize> {
+            if kind == ColumnKind::Message {
+                return (metadata.widths.message > 0).then_some(MIN_MESSAGE);
+            }
+            kind.ideal(&metadata.widths, max_path_width, commit_width)
+                .map(|(width, _)| width)
+        }
+
+        proptest! {
+            #[test]
+            fn allocation_invariants(
+                widths in column_widths(),
+                data_flags in data_flags(),
+                skip_tasks in skip_tasks(),
+                max_path_width in 0usize..=60,
+                terminal_width in 0usize..=250,
+                no_truncate in any::<bool>(),
+            ) {
+                let metadata = LayoutMetadata {
+                    widths,
+                    data_flags,
+                    status_position_mask: crate::commands::list::model::PositionMask::FULL,
+                };
+                let limits = WidthLimits {
+                    no_truncate,
+                    ..WidthLimits::default()
+                };
+                let commit_width = fit_header(ColumnKind::Commit.header(), COMMIT_HASH_WIDTH);
+                let allocation = allocate_columns_with_priority(
+                    &metadata,
+                    &skip_tasks,
+                    max_path_width,
+                    commit_width,
+                    terminal_width,
+                    &limits,
+                );
+                let columns = &allocation.columns;
+
+                // Display order, no overlap, gap of 2 except directly after the gutter
+                let mut end = 0;
+                for (i, col) in columns.iter().enumerate() {
+                    prop_assert!(col.width > 0, "{:?} allocated with zero width", col.kind);
+                    match i.checked_sub(1).map(|j| &columns[j]) {
+                        None => prop_assert_eq!(col.start, 0),
+                        Some(prev) => {
+                            let gap = if prev.kind == ColumnKind::Gutter { 0 } else { 2 };
+                            prop_assert_eq!(col.start, prev.start + prev.width + gap);
+                            prop_assert!(
+                                column_display_index(prev.kind) < column_display_index(col.kind)
+                            );
+                        }
+                    }
+                    end = col.start + col.width;
+                }
+
+                // Total width fits the terminal
+                if !no_truncate {
+                    prop_assert!(end <= terminal_width, "{} > {}", end, terminal_width);
+                }
+
+                // Gutter comes first whenever it fits
+                if terminal_width >= 2 || no_truncate {
+                    prop_assert_eq!(columns.first().map(|c| c.kind), Some(ColumnKind::Gutter));
+                }
+
+                // Every candidate is either shown or counted as hidden
+                let candidates = COLUMN_SPECS
+                    .iter()
+                    .filter(|spec| spec.requires_task.is_none_or(|t| !skip_tasks.contains(&t)))
+                    .count();
+                prop_assert_eq!(columns.len() + allocation.hidden_column_count, candidates);
+
+                // Message stays within its bounds and reports its own width
+                if let Some(message) = columns.iter().find(|c| c.kind == ColumnKind::Message) {
+                    prop_assert!(message.width <= 100);
+                    if !no_truncate {
+                        prop_assert_eq!(allocation.max_message_len, message.width);
+                    }
+                }
+
+                // Data columns are preferred over empty ones: an empty column is only
+                // shown if every hidden data column needed more room than it took
+                for spec in COLUMN_SPECS {
+                    let kind = spec.kind;
+                    if matches!(kind, ColumnKind::Gutter | ColumnKind::Branch)
+                        || !kind.has_data(&metadata.data_flags)
+                        || spec.requires_task.is_some_and(|t| skip_tasks.contains(&t))
+                        || columns.iter().any(|c| c.kind == kind)
+                    {
+                        continue;
+                    }
+                    let Some(required) =
+                        required_width(kind, &metadata, max_path_width, commit_width)
+                    else {
+                        continue;
+                    };
+                    for empty in columns
+                        .iter()
+                        .filter(|c| !c.kind.has_data(&metadata.data_flags))
+                    {
+                        prop_assert!(
+                            empty.width < required,
+                            "empty {:?} ({}) shown while {:?} ({}) with data is hidden",
+                            empty.kind,
+                            empty.width,
+                            kind,
+                            required
+                        );
+                    }
+                }
+            }
+
+            #[test]
+            fn estimated_columns_fit_headers(
+                max_branch in 0usize..=60,
+                skip_tasks in skip_tasks(),
+                has_mismatch in any::<bool>(),
+                url_width in 0usize..=40,
+                issue_width in 0usize..=12,
+                index_width in 0usize..=3,
+                show_graph in any::<bool>(),
+                path_width in 0usize..=60,
+                terminal_width in 0usize..=250,
+            ) {
+                let metadata = build_estimated_widths(
+                    fit_header(ColumnKind::Branch.header(), max_branch),
+                    &skip_tasks,
+                    has_mismatch,
+                    url_width,
+                    issue_width,
+                    index_width,
+                    &DisplayOptions {
+                        graph: show_graph,
+                        ..Default::default()
+                    },
+                );
+                let allocation = allocate_columns_with_priority(
+                    &metadata,
+                    &skip_tasks,
+                    fit_header(ColumnKind::Path.header(), path_width),
+                    fit_header(ColumnKind::Commit.header(), COMMIT_HASH_WIDTH),
+                    terminal_width,
+                    &WidthLimits::default(),
+                );
+
+                // Branch may be truncated on narrow terminals; Message has its own minimum
+                for col in &allocation.columns {
+                    if matches!(col.kind, ColumnKind::Branch | ColumnKind::Message) {
+                        continue;
+                    }
+                    prop_assert!(
+                        col.width >= col.header.width(),
+                        "{:?} header {:?} doesn't fit in {}",
+                        col.kind,
+                        col.header,
+                        col.width
+                    );
+                }
+            }
+        }
+    }
 }
@@ -3,7 +3,8 @@
 //! # Performance
 //!
 //! `wt list` runs multiple git commands per worktree in parallel using Rayon. Performance
-//! depends heavily on git's internal caches, not worktrunk-specific caching.
+//! depends heavily on git's internal caches. The one worktrunk-specific cache is opt-in
+//! (`[list] cache`) and only serves `--format=json`; see `cache.rs`.
 //!
 //! ## Time to First Information
 //!
@@ -118,63 +119,150 @@
 //! - Run `git gc` periodically to consolidate objects into pack files
 //! - Minimize uncommitted changes across worktrees (each dirty worktree adds diff overhead)
 
+mod cache;
 pub mod ci_status;
 pub(crate) mod collect;
 pub(crate) mod columns;
-mod json_output;
+pub(crate) mod disk_usage;
+pub(crate) mod json_output;
 pub(crate) mod layout;
 pub mod model;
 pub mod progressive;
 mod progressive_table;
 pub(crate) mod render;
+pub(crate) mod tune;
 
 #[cfg(test)]
 mod spacing_test;
 
 // Layout is calculated in collect.rs
 use anyhow::Context;
-use model::{ListData, ListItem};
+use model::{ListData, ListItem, MainState};
 use progressive::RenderMode;
+use worktrunk::config::SummaryPart;
 use worktrunk::git::Repository;
+use worktrunk::styling::{info_message, success_message};
 
 // Re-export for statusline and other consumers
 pub use collect::{CollectOptions, build_worktree_item, populate_item};
 pub use model::StatuslineSegment;
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_list(
     format: crate::OutputFormat,
+    layout: crate::ListLayout,
     show_branches: bool,
     show_remotes: bool,
     show_full: bool,
+    show_disk_usage: bool,
+    show_owner: bool,
+    mine: bool,
+    show_index: bool,
+    show_graph: bool,
+    absolute_time: bool,
+    show_summary: bool,
+    no_truncate: bool,
+    tune: bool,
     render_mode: RenderMode,
+    refresh_cache: bool,
     config: &worktrunk::config::WorktrunkConfig,
 ) -> anyhow::Result<()> {
     use collect::TaskKind;
 
     let repo = Repository::current()?;
 
+    if tune {
+        tune::ensure_interactive()?;
+    }
+
+    // `[remove] auto-integrated`; JSON output is for scripts, which shouldn't remove anything
+    if matches!(format, crate::OutputFormat::Table) {
+        crate::commands::worktree::remove_integrated_worktrees(&repo, config, None);
+    }
+
+    // JSON for prompt integrations can come from the cache (`[list] cache`)
+    let use_cache = refresh_cache
+        || (matches!(format, crate::OutputFormat::Json)
+            && config.list.as_ref().and_then(|l| l.cache).unwrap_or(false));
+    let cache = if use_cache {
+        let variant = format!(
+            "{:?}",
+            (
+                worktrunk::git::base_dir()?,
+                show_branches,
+                show_remotes,
+                show_full,
+                show_disk_usage,
+                show_owner,
+                mine,
+                show_index,
+            )
+        );
+        Some(cache::ListCache::new(&repo, &variant)?)
+    } else {
+        None
+    };
+    if let Some(cache) = &cache
+        && !refresh_cache
+        && let Some((json, stale)) = cache.read()
+    {
+        if stale {
+            cache.refresh_in_background(&repo)?;
+        }
+        crate::output::stdout(json)?;
+        return Ok(());
+    }
+
     // Build skip set based on flags
     // Without --full: skip expensive operations (BranchDiff, CiStatus, WorkingTreeConflicts)
-    let skip_tasks: std::collections::HashSet<TaskKind> = if show_full {
+    // and branch descriptions, which replace the commit subject in the Message column
+    let mut skip_tasks: std::collections::HashSet<TaskKind> = if show_full {
         std::collections::HashSet::new() // Compute everything
     } else {
         [
             TaskKind::BranchDiff,
             TaskKind::CiStatus,
             TaskKind::WorkingTreeConflicts,
+            TaskKind::BranchDescription,
         ]
         .into_iter()
         .collect()
     };
+    // Disk usage walks every file, so it's opt-in even with --full
+    if !show_disk_usage {
+        skip_tasks.insert(TaskKind::DiskUsage);
+    }
+    if !show_owner {
+        skip_tasks.insert(TaskKind::Owner);
+    }
+
+    // Narrow terminals get a record per item rather than a table missing most columns
+    let record_layout = match layout {
+        crate::ListLayout::Record => true,
+        crate::ListLayout::Table => false,
+        crate::ListLayout::Auto => {
+            let record_below = config
+                .list
+                .as_ref()
+                .and_then(|l| l.record_below)
+                .unwrap_or(DEFAULT_RECORD_BELOW);
+            crate::display::get_terminal_width() < record_below
+        }
+    };
 
-    // Progressive rendering only for table format with Progressive mode
+    // Progressive rendering only for table format with Progressive mode.
+    // It redraws rows in place, which needs each row on one terminal line.
     let show_progress = match format {
-        crate::OutputFormat::Table => render_mode == RenderMode::Progressive,
+        crate::OutputFormat::Table => {
+            render_mode == RenderMode::Progressive && !no_truncate && !record_layout
+        }
         crate::OutputFormat::Json => false, // JSON never shows progress
     };
 
-    // Render table in collect() for all table modes (progressive + buffered)
-    let render_table = matches!(format, crate::OutputFormat::Table);
+    // Render table in collect() for all table modes (progressive + buffered);
+    // the tuner draws its own
+    let render_table = matches!(format, crate::OutputFormat::Table) && !tune;
+    let show_progress = show_progress && !tune;
 
     // For testing: allow enabling skip_expensive_for_stale via env var
     let skip_expensive_for_stale = std::env::var("WORKTRUNK_TEST_SKIP_EXPENSIVE_THRESHOLD").is_ok();
@@ -191,6 +279,11 @@ pub fn handle_list(
             .map(std::time::Duration::from_millis)
     };
 
+    let display = layout::DisplayOptions {
+        graph: show_graph,
+        time: crate::display::TimeFormat::from_config(config.list.as_ref(), absolute_time),
+    };
+
     let list_data = collect::collect(
         &repo,
         show_branches,
@@ -201,29 +294,87 @@ pub fn handle_list(
         config,
         command_timeout,
         skip_expensive_for_stale,
+        show_index,
+        show_summary,
+        no_truncate,
+        record_layout,
+        mine,
+        &display,
     )?;
 
-    let Some(ListData { items, .. }) = list_data else {
+    let Some(ListData {
+        items,
+        main_worktree_path,
+    }) = list_data
+    else {
         return Ok(());
     };
 
+    if tune {
+        let limits = layout::WidthLimits {
+            max_widths: config
+                .list
+                .as_ref()
+                .and_then(|l| l.max_width)
+                .unwrap_or_default(),
+            tuning: tune::ColumnTuning::load(&repo),
+            ..Default::default()
+        };
+        let saved = tune::run(
+            &repo,
+            &items,
+            &main_worktree_path,
+            &skip_tasks,
+            limits,
+            &display,
+        )?;
+        let message = if saved {
+            success_message("Saved column layout for this repository")
+        } else {
+            info_message("Column layout unchanged")
+        };
+        return crate::output::print(message);
+    }
+
     match format {
         crate::OutputFormat::Json => {
             // Convert to new JSON structure
             let json_items = json_output::to_json_items(&items);
             let json =
                 serde_json::to_string_pretty(&json_items).context("Failed to serialize to JSON")?;
-            crate::output::stdout(json)?;
+            // The cache only speeds up later calls; failing to write it isn't
+            // worth failing this one
+            if let Some(cache) = &cache
+                && let Err(e) = cache.write(&json)
+            {
+                log::warn!("Failed to write list cache: {e:#}");
+            }
+            if !refresh_cache {
+                crate::output::stdout(json)?;
+            }
         }
         crate::OutputFormat::Table => {
-            // Table and summary already rendered in collect() for all modes
-            // Nothing to do here - collect() handles the complete table rendering
+            // Table and summary already rendered in collect() for all modes.
+            // Remember the numbered rows so `wt switch N` resolves against them.
+            if show_index {
+                let rows: Vec<Option<String>> =
+                    items.iter().map(|item| item.branch.clone()).collect();
+                repo.record_list_index(&rows)?;
+            }
         }
     }
 
     Ok(())
 }
 
+/// Terminal width below which `--layout auto` renders records instead of a table.
+const DEFAULT_RECORD_BELOW: usize = 60;
+
+/// Commits behind the default branch beyond which a branch counts as stale.
+///
+/// Also the default threshold for skipping expensive tasks on stale branches.
+pub(super) const STALE_BEHIND_THRESHOLD: usize = 50;
+
 #[derive(Default)]
 pub(super) struct SummaryMetrics {
     worktrees: usize,
@@ -231,6 +382,10 @@ pub(super) struct SummaryMetrics {
     remote_branches: usize,
     dirty_worktrees: usize,
     ahead_items: usize,
+    stale_items: usize,
+    integrated_items: usize,
+    /// Total of the computed disk usages; `None` without `--du`
+    disk_usage: Option<u64>,
 }
 
 impl SummaryMetrics {
@@ -243,8 +398,11 @@ impl SummaryMetrics {
     }
 
     fn update(&mut self, item: &ListItem) {
-        if let Some(_data) = item.worktree_data() {
+        if let Some(data) = item.worktree_data() {
             self.worktrees += 1;
+            if let Some(bytes) = data.disk_usage {
+                *self.disk_usage.get_or_insert(0) += bytes;
+            }
             // Use status_symbols.working_tree which includes untracked files,
             // not just working_tree_diff which only has tracked changes
             if item
@@ -267,12 +425,28 @@ impl SummaryMetrics {
         if item.counts.is_some_and(|c| c.ahead > 0) {
             self.ahead_items += 1;
         }
+
+        if item
+            .counts
+            .is_some_and(|c| c.behind > STALE_BEHIND_THRESHOLD)
+        {
+            self.stale_items += 1;
+        }
+
+        if item
+            .status_symbols
+            .as_ref()
+            .is_some_and(|s| matches!(s.main_state, MainState::Integrated(_)))
+        {
+            self.integrated_items += 1;
+        }
     }
 
     pub(super) fn summary_parts(
         &self,
         include_branches: bool,
         hidden_columns: usize,
+        metrics: &[SummaryPart],
     ) -> Vec<String> {
         let mut parts = Vec::new();
 
@@ -289,12 +463,24 @@ impl SummaryMetrics {
             parts.push(format!("{} worktree{}", self.worktrees, plural));
         }
 
-        if self.dirty_worktrees > 0 {
-            parts.push(format!("{} with changes", self.dirty_worktrees));
-        }
-
-        if self.ahead_items > 0 {
-            parts.push(format!("{} ahead", self.ahead_items));
+        // Zero counts are left out; the configured order is kept
+        for metric in metrics {
+            let part = match metric {
+                SummaryPart::Dirty => (self.dirty_worktrees > 0)
+                    .then(|| format!("{} with changes", self.dirty_worktrees)),
+                SummaryPart::Ahead => {
+                    (self.ahead_items > 0).then(|| format!("{} ahead", self.ahead_items))
+                }
+                SummaryPart::Stale => {
+                    (self.stale_items > 0).then(|| format!("{} stale", self.stale_items))
+                }
+                SummaryPart::Integrated => (self.integrated_items > 0)
+                    .then(|| format!("{} integrated", self.integrated_items)),
+                SummaryPart::Disk => self
+                    .disk_usage
+                    .map(|bytes| format!("{} on disk", disk_usage::format_size(bytes))),
+            };
+            parts.extend(part);
         }
 
         if hidden_columns > 0 {
@@ -315,6 +501,7 @@ pub(crate) fn format_summary_message(
     items: &[ListItem],
     show_branches: bool,
     hidden_column_count: usize,
+    metrics: &[SummaryPart],
     error_count: usize,
     timed_out_count: usize,
 ) -> String {
@@ -324,7 +511,7 @@ pub(crate) fn format_summary_message(
     let metrics = SummaryMetrics::from_items(items);
     let dim = Style::new().dimmed();
     let summary = metrics
-        .summary_parts(show_branches, hidden_column_count)
+        .summary_parts(show_branches, hidden_column_count, metrics)
         .join(", ");
 
     if error_count > 0 {
@@ -369,8 +556,9 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 0,
+            ..Default::default()
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, 0, SummaryPart::DEFAULT);
         assert_eq!(parts, vec!["1 worktree"]);
     }
 
@@ -382,8 +570,9 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 0,
+            ..Default::default()
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, 0, SummaryPart::DEFAULT);
         assert_eq!(parts, vec!["3 worktrees"]);
     }
 
@@ -395,8 +584,9 @@ mod tests {
             remote_branches: 10,
             dirty_worktrees: 0,
             ahead_items: 0,
+            ..Default::default()
         };
-        let parts = metrics.summary_parts(true, 0);
+        let parts = metrics.summary_parts(true, 0, SummaryPart::DEFAULT);
         assert_eq!(
             parts,
             vec!["2 worktrees", "5 branches", "10 remote branches"]
@@ -411,8 +601,9 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 2,
             ahead_items: 0,
+            ..Default::default()
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, 0, SummaryPart::DEFAULT);
         assert_eq!(parts, vec!["3 worktrees", "2 with changes"]);
     }
 
@@ -424,8 +615,9 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 1,
+            ..Default::default()
         };
-        let parts = metrics.summary_parts(false, 0);
+        let parts = metrics.summary_parts(false, 0, SummaryPart::DEFAULT);
         assert_eq!(parts, vec!["2 worktrees", "1 ahead"]);
     }
 
@@ -437,11 +629,12 @@ mod tests {
             remote_branches: 0,
             dirty_worktrees: 0,
             ahead_items: 0,
+            ..Default::default()
         };
-        let parts = metrics.summary_parts(false, 1);
+        let parts = metrics.summary_parts(false, 1, SummaryPart::DEFAULT);
         assert_eq!(parts, vec!["1 worktree", "1 column hidden"]);
 
-        let parts = metrics.summary_parts(false, 3);
+        let parts = metrics.summary_parts(false, 3, SummaryPart::DEFAULT);
         assert_eq!(parts, vec!["1 worktree", "3 columns hidden"]);
     }
 
@@ -453,8 +646,9 @@ mod tests {
             remote_branches: 5,
             dirty_worktrees: 0,
             ahead_items: 0,
+            ..Default::default()
         };
-        let parts = metrics.summary_parts(true, 0);
+        let parts = metrics.summary_parts(true, 0, SummaryPart::DEFAULT);
         assert_eq!(parts, vec!["2 worktrees", "5 remote branches"]);
     }
 
@@ -466,8 +660,9 @@ mod tests {
             remote_branches: 8,
             dirty_worktrees: 2,
             ahead_items: 4,
+            ..Default::default()
         };
-        let parts = metrics.summary_parts(true, 2);
+        let parts = metrics.summary_parts(true, 2, SummaryPart::DEFAULT);
         assert_eq!(
             parts,
             vec![
@@ -481,9 +676,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_summary_metrics_summary_parts_configured() {
+        let metrics = SummaryMetrics {
+            worktrees: 4,
+            dirty_worktrees: 1,
+            ahead_items: 2,
+            stale_items: 3,
+            integrated_items: 1,
+            disk_usage: Some(12 * 1024),
+            ..Default::default()
+        };
+        let parts = metrics.summary_parts(
+            false,
+            0,
+            &[
+                SummaryPart::Disk,
+                SummaryPart::Stale,
+                SummaryPart::Integrated,
+            ],
+        );
+        assert_eq!(
+            parts,
+            vec!["4 worktrees", "12K on disk", "3 stale", "1 integrated"]
+        );
+
+        // An empty list leaves only the counts
+        assert_eq!(metrics.summary_parts(false, 0, &[]), vec!["4 worktrees"]);
+    }
+
     #[test]
     fn test_format_summary_message_no_errors() {
-        let msg = format_summary_message(&[], false, 0, 0, 0);
+        let msg = format_summary_message(&[], false, 0, SummaryPart::DEFAULT, 0, 0);
         assert!(msg.contains("Showing 0 worktrees"));
         assert!(!msg.contains("failed"));
         assert!(!msg.contains("timed out"));
@@ -492,7 +716,7 @@ mod tests {
     #[test]
     fn test_format_summary_message_all_timeouts() {
         // 3 errors, all timeouts
-        let msg = format_summary_message(&[], false, 0, 3, 3);
+        let msg = format_summary_message(&[], false, 0, SummaryPart::DEFAULT, 3, 3);
         assert!(msg.contains("3 tasks timed out"));
         assert!(!msg.contains("failed"));
     }
@@ -500,27 +724,27 @@ mod tests {
     #[test]
     fn test_format_summary_message_mixed_errors() {
         // 5 errors, 3 are timeouts
-        let msg = format_summary_message(&[], false, 0, 5, 3);
+        let msg = format_summary_message(&[], false, 0, SummaryPart::DEFAULT, 5, 3);
         assert!(msg.contains("5 tasks failed (3 timed out)"));
     }
 
     #[test]
     fn test_format_summary_message_no_timeouts() {
         // 2 errors, none are timeouts
-        let msg = format_summary_message(&[], false, 0, 2, 0);
+        let msg = format_summary_message(&[], false, 0, SummaryPart::DEFAULT, 2, 0);
         assert!(msg.contains("2 tasks failed"));
         assert!(!msg.contains("timed out"));
     }
 
     #[test]
     fn test_format_summary_message_single_error() {
-        let msg = format_summary_message(&[], false, 0, 1, 0);
+        let msg = format_summary_message(&[], false, 0, SummaryPart::DEFAULT, 1, 0);
         assert!(msg.contains("1 task failed"));
     }
 
     #[test]
     fn test_format_summary_message_single_timeout() {
-        let msg = format_summary_message(&[], false, 0, 1, 1);
+        let msg = format_summary_message(&[], false, 0, SummaryPart::DEFAULT, 1, 1);
         assert!(msg.contains("1 task timed out"));
     }
 }
@@ -5,6 +5,7 @@
 
 use std::path::PathBuf;
 
+use worktrunk::config::IssueRef;
 use worktrunk::git::{IntegrationReason, IntegrationSignals, LineDiff, check_integration};
 
 use super::state::{Divergence, GitOperationState, MainState, OperationState, WorktreeState};
@@ -72,6 +73,9 @@ impl DisplayFields {
 pub struct WorktreeData {
     pub path: PathBuf,
     pub detached: bool,
+    /// Nearest tag containing a detached HEAD, e.g. `v1.2.3~2`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detached_at: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub locked: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -94,6 +98,9 @@ pub struct WorktreeData {
     pub branch_worktree_mismatch: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub working_diff_display: Option<String>,
+    /// Disk usage in bytes, excluding `.git` (only computed with `--du`)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disk_usage: Option<u64>,
 }
 
 impl WorktreeData {
@@ -194,6 +201,18 @@ pub struct ListItem {
     /// Whether the URL's port is actively listening
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url_active: Option<bool>,
+    /// Branch description from `branch.<name>.description` (--full only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// `WT_OWNER` marker or last commit author (--owner only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    /// Issue reference extracted from the branch name via `[integrations.issues]`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub issue: Option<IssueRef>,
+    /// Row number shown with `wt list --index`, addressable as `wt switch N`
+    #[serde(skip)]
+    pub index: Option<usize>,
 
     /// Git status symbols - None until all dependencies are ready.
     /// Note: This field is not serialized directly. JSON output converts to JsonItem first.
@@ -213,7 +232,6 @@ pub struct ListItem {
 pub struct ListData {
     pub items: Vec<ListItem>,
     /// Path to the main worktree, used for computing relative paths in display.
-    #[cfg_attr(windows, allow(dead_code))] // Used only by select module (unix-only)
     pub main_worktree_path: std::path::PathBuf,
 }
 
@@ -235,6 +253,10 @@ impl ListItem {
             pr_status: None,
             url: None,
             url_active: None,
+            description: None,
+            owner: None,
+            issue: None,
+            index: None,
             status_symbols: None,
             display: DisplayFields::default(),
             kind: ItemKind::Branch,
@@ -245,6 +267,13 @@ impl ListItem {
         self.branch.as_deref().unwrap_or("(detached)")
     }
 
+    /// Branch column text for a detached worktree whose HEAD has a description:
+    /// `(detached at v1.2.3~2)`
+    pub fn detached_label(&self) -> Option<String> {
+        let at = self.worktree_data()?.detached_at.as_deref()?;
+        Some(format!("(detached at {at})"))
+    }
+
     pub fn is_main(&self) -> bool {
         matches!(&self.kind, ItemKind::Worktree(data) if data.is_main)
     }
@@ -458,6 +487,8 @@ impl ListItem {
         let counts = self.counts.as_ref().unwrap_or(&default_counts);
         let upstream = self.upstream.as_ref().unwrap_or(&default_upstream);
         let upstream_divergence = match upstream.active() {
+            None if upstream.unpushed => Divergence::Unpushed,
+            None if upstream.gone => Divergence::Gone,
             None => Divergence::None,
             Some(active) => Divergence::from_counts_with_remote(active.ahead, active.behind),
         };
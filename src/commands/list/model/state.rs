@@ -17,6 +17,8 @@ use worktrunk::git::IntegrationReason;
 /// | Ahead     | `⇡`    - has unpushed commits   |
 /// | Behind    | `⇣`    - missing remote commits |
 /// | Diverged  | `⇅`    - both ahead and behind  |
+/// | Unpushed  | `⇈`    - remote branch not pushed yet |
+/// | Gone      | `⊘`    - remote branch deleted |
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Divergence {
     /// No remote tracking branch configured
@@ -30,6 +32,10 @@ pub enum Divergence {
     Behind,
     /// Both ahead and behind the remote
     Diverged,
+    /// Tracks a remote branch that hasn't been pushed yet
+    Unpushed,
+    /// Tracks a remote branch that was deleted on the remote
+    Gone,
 }
 
 impl Divergence {
@@ -54,6 +60,8 @@ impl Divergence {
             Self::Ahead => "⇡",
             Self::Behind => "⇣",
             Self::Diverged => "⇅",
+            Self::Unpushed => "⇈",
+            Self::Gone => "⊘",
         }
     }
 
@@ -35,6 +35,12 @@ pub struct UpstreamStatus {
     pub(crate) ahead: usize,
     #[serde(rename = "upstream_behind")]
     pub(crate) behind: usize,
+    /// Tracking is configured, but the remote branch doesn't exist yet
+    #[serde(skip)]
+    pub(crate) unpushed: bool,
+    /// Tracking is configured, but the remote branch was deleted (`[gone]`)
+    #[serde(skip)]
+    pub(crate) gone: bool,
 }
 
 /// Active upstream tracking information (when a remote is configured).
@@ -45,8 +51,11 @@ pub struct ActiveUpstream<'a> {
 }
 
 impl UpstreamStatus {
-    /// Returns active upstream info if a remote tracking branch is configured.
+    /// Returns active upstream info if a remote tracking branch exists.
     pub fn active(&self) -> Option<ActiveUpstream<'_>> {
+        if self.unpushed || self.gone {
+            return None;
+        }
         self.remote.as_deref().map(|remote| ActiveUpstream {
             remote,
             ahead: self.ahead,
@@ -85,6 +94,8 @@ mod tests {
             remote: Some("origin".to_string()),
             ahead: 3,
             behind: 2,
+            unpushed: false,
+            gone: false,
         };
         let active = status.active().unwrap();
         assert_eq!(active.remote, "origin");
@@ -98,6 +109,28 @@ mod tests {
             remote: None,
             ahead: 0,
             behind: 0,
+            unpushed: false,
+            gone: false,
+        };
+        assert!(status.active().is_none());
+    }
+
+    #[test]
+    fn test_upstream_status_unpushed_not_active() {
+        let status = UpstreamStatus {
+            remote: Some("origin".to_string()),
+            unpushed: true,
+            ..Default::default()
+        };
+        assert!(status.active().is_none());
+    }
+
+    #[test]
+    fn test_upstream_status_gone_not_active() {
+        let status = UpstreamStatus {
+            remote: Some("origin".to_string()),
+            gone: true,
+            ..Default::default()
         };
         assert!(status.active().is_none());
     }
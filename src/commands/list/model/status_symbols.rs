@@ -149,6 +149,8 @@ impl WorkingTreeStatus {
 /// - ⇅: Diverged from remote
 /// - ⇡: Ahead of remote
 /// - ⇣: Behind remote
+/// - ⇈: Tracks a remote branch not pushed yet
+/// - ⊘: Tracks a remote branch deleted on the remote
 ///
 /// **NOT mutually exclusive (can co-occur):**
 /// - Working tree symbols (+!?): Can have multiple types of changes
@@ -228,6 +228,20 @@ impl ProgressiveTable {
         self.flush()
     }
 
+    /// Finalize without a summary: flush, then erase the spacer and footer.
+    pub fn finalize_without_footer(&mut self) -> std::io::Result<()> {
+        self.flush()?;
+        if self.rendered {
+            // The cursor sits below the footer; the spacer is two lines up
+            let mut stdout = stdout();
+            stdout.execute(MoveUp(2))?;
+            stdout.execute(MoveToColumn(0))?;
+            stdout.execute(Clear(ClearType::FromCursorDown))?;
+            stdout.flush()?;
+        }
+        Ok(())
+    }
+
     /// Check if output is going to a TTY.
     pub fn is_tty(&self) -> bool {
         self.is_tty
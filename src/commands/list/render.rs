@@ -1,12 +1,17 @@
-use crate::display::{format_relative_time_short, shorten_path, truncate_to_width};
+use crate::display::{TimeFormat, pad_to_width, shorten_path, truncate_to_width, truncate_visible};
 use anstyle::Style;
+use std::borrow::Cow;
 use std::path::Path;
 use unicode_width::UnicodeWidthStr;
-use worktrunk::styling::{Stream, StyledLine, hyperlink_stdout, supports_hyperlinks};
+use worktrunk::styling::{
+    ADDITION, DELETION, Stream, StyledLine, hyperlink_stdout, sanitize_for_display,
+    supports_hyperlinks,
+};
 
 use super::collect::parse_port_from_url;
 use super::columns::{ColumnKind, DiffVariant};
-use super::layout::{ColumnFormat, ColumnLayout, DiffColumnConfig, LayoutConfig};
+use super::disk_usage::format_size;
+use super::layout::{ColumnFormat, ColumnLayout, DiffColumnConfig, LayoutConfig, display_branch};
 use super::model::{ListItem, PositionMask};
 
 impl DiffColumnConfig {
@@ -243,16 +248,65 @@ impl LayoutConfig {
                 &self.status_position_mask,
                 &self.main_worktree_path,
                 self.max_message_len,
+                self.branch_prefix.as_deref(),
+                &self.time_format,
             )
         })
     }
 
+    /// Render an item as a record: the gutter and branch, then one labeled
+    /// line per non-empty field (`wt list --layout record`).
+    ///
+    /// Lines are truncated to `max_width`.
+    pub fn format_record(&self, item: &ListItem, max_width: usize) -> Vec<String> {
+        let dim = Style::new().dimmed();
+        let render = |column: &ColumnLayout| {
+            column.render_cell(
+                item,
+                &self.status_position_mask,
+                &self.main_worktree_path,
+                self.max_message_len,
+                self.branch_prefix.as_deref(),
+                &self.time_format,
+            )
+        };
+        let label_width = self
+            .columns
+            .iter()
+            .map(|column| column.header.width())
+            .max()
+            .unwrap_or(0);
+
+        let mut title = String::new();
+        let mut fields = Vec::new();
+        for column in &self.columns {
+            let cell = render(column);
+            match column.kind {
+                ColumnKind::Gutter | ColumnKind::Branch => title.push_str(&cell.render()),
+                _ => {
+                    if cell.plain_text().trim().is_empty() {
+                        continue;
+                    }
+                    let label = pad_to_width(column.header, label_width);
+                    let value = cell.render();
+                    fields.push(format!("  {dim}{label}{dim:#}  {}", value.trim()));
+                }
+            }
+        }
+
+        std::iter::once(title.trim_end().to_string())
+            .chain(fields)
+            .map(|line| truncate_visible(&line, max_width))
+            .collect()
+    }
+
     /// Render a skeleton row showing known data (branch, path) with placeholders for other columns.
     ///
     /// Used for both worktrees and branch-only items; branch-only rows render an empty path
     /// and a blank gutter placeholder.
     pub fn render_skeleton_row(&self, item: &ListItem) -> StyledLine {
         let branch = item.branch_name();
+        let detached_label = item.detached_label();
         let wt_data = item.worktree_data();
         let shortened_path = item
             .worktree_path()
@@ -276,14 +330,29 @@ impl LayoutConfig {
                     };
                     cell.push_styled(symbol, dim);
                 }
+                ColumnKind::Index => {
+                    // Row numbers are assigned before the skeleton renders
+                    return col.render_cell(
+                        item,
+                        &self.status_position_mask,
+                        &self.main_worktree_path,
+                        self.max_message_len,
+                        self.branch_prefix.as_deref(),
+                        &self.time_format,
+                    );
+                }
                 ColumnKind::Branch => {
                     // Show actual branch name (no dim - start normal, gray out later if removable)
-                    cell.push_raw(branch.to_string());
+                    let branch = match &detached_label {
+                        Some(label) => Cow::Borrowed(label.as_str()),
+                        None => display_branch(branch, self.branch_prefix.as_deref()),
+                    };
+                    cell = col.render_text_cell(&branch, None);
                     cell.pad_to(col.width);
                 }
                 ColumnKind::Path => {
                     // Show actual path (no dim - start normal, gray out later if removable)
-                    cell.push_raw(&shortened_path);
+                    cell = col.render_text_cell(&shortened_path, None);
                     cell.pad_to(col.width);
                 }
                 ColumnKind::Commit => {
@@ -318,11 +387,12 @@ impl ColumnLayout {
 
     /// Render a text cell with optional style, truncated to column width.
     fn render_text_cell(&self, text: &str, style: Option<Style>) -> StyledLine {
+        let text = sanitize_for_display(text).into_owned();
         let mut cell = StyledLine::new();
         if let Some(s) = style {
-            cell.push_styled(text.to_string(), s);
+            cell.push_styled(text, s);
         } else {
-            cell.push_raw(text.to_string());
+            cell.push_raw(text);
         }
         cell.truncate_to_width(self.width)
     }
@@ -337,12 +407,38 @@ impl ColumnLayout {
         config.render_segment(positive, negative)
     }
 
+    /// Draw main's commits (`○`), the merge-base (`◆`), then the branch's own
+    /// commits (`●`). The merge-base sits mid-column so it lines up across rows;
+    /// `…` marks a side with more commits than fit.
+    fn render_graph_cell(&self, ahead: usize, behind: usize) -> StyledLine {
+        let depth = self.width.saturating_sub(1) / 2;
+        let shown = |count: usize| count.min(depth);
+        let overflow = |count: usize| count > depth;
+
+        let mut cell = StyledLine::new();
+        cell.push_raw(" ".repeat(depth - shown(behind)));
+        if overflow(behind) {
+            cell.push_styled("…", DELETION.dimmed());
+        }
+        let behind_dots = shown(behind) - usize::from(overflow(behind));
+        cell.push_styled("○".repeat(behind_dots), DELETION.dimmed());
+        cell.push_styled("◆", Style::new().dimmed());
+        let ahead_dots = shown(ahead) - usize::from(overflow(ahead));
+        cell.push_styled("●".repeat(ahead_dots), ADDITION);
+        if overflow(ahead) {
+            cell.push_styled("…", ADDITION);
+        }
+        cell
+    }
+
     fn render_cell(
         &self,
         item: &ListItem,
         status_mask: &PositionMask,
         main_worktree_path: &Path,
         max_message_len: usize,
+        branch_prefix: Option<&str>,
+        time_format: &TimeFormat,
     ) -> StyledLine {
         // Compute derived values inline (avoids separate context struct)
         let worktree_data = item.worktree_data();
@@ -368,9 +464,25 @@ impl ColumnLayout {
                 cell.push_raw(symbol.to_string());
                 cell
             }
+            ColumnKind::Index => {
+                let Some(index) = item.index else {
+                    return StyledLine::new();
+                };
+                // Right-align so single and double digits line up
+                let index = index.to_string();
+                let mut cell = StyledLine::new();
+                cell.push_raw(" ".repeat(self.width.saturating_sub(index.width())));
+                cell.push_styled(index, Style::new().dimmed());
+                cell
+            }
             ColumnKind::Branch => {
-                let text = item.branch.as_deref().unwrap_or("-");
-                self.render_text_cell(text, text_style)
+                let detached = item.detached_label();
+                let text = match (&item.branch, &detached) {
+                    (Some(branch), _) => display_branch(branch, branch_prefix),
+                    (None, Some(label)) => Cow::Borrowed(label.as_str()),
+                    (None, None) => Cow::Borrowed("-"),
+                };
+                self.render_text_cell(&text, text_style)
             }
             ColumnKind::Status => {
                 let Some(ref status_symbols) = item.status_symbols else {
@@ -399,6 +511,15 @@ impl ColumnLayout {
                     None => self.placeholder_cell("⋯"), // Not loaded yet
                 }
             }
+            ColumnKind::Graph => {
+                if item.is_main() {
+                    return StyledLine::new();
+                }
+                match item.counts {
+                    Some(counts) => self.render_graph_cell(counts.ahead, counts.behind),
+                    None => self.placeholder_cell("⋯"), // Not loaded yet
+                }
+            }
             ColumnKind::BranchDiff => {
                 if item.is_main() {
                     return StyledLine::new();
@@ -417,19 +538,30 @@ impl ColumnLayout {
             }
             ColumnKind::Upstream => {
                 let upstream = item.upstream();
+                let centered = |symbol: &str| {
+                    let mut cell = StyledLine::new();
+                    // Center the symbol in the column width
+                    let padding_left = (self.width.saturating_sub(1)) / 2;
+                    cell.push_raw(" ".repeat(padding_left));
+                    cell.push_styled(symbol, Style::new().dimmed());
+                    cell
+                };
                 let Some(active) = upstream.active() else {
+                    // Tracking set up, nothing pushed yet: distinct from no upstream at all
+                    if upstream.unpushed {
+                        return centered("⇈");
+                    }
+                    // Tracked remote branch deleted (e.g. after its PR merged)
+                    if upstream.gone {
+                        return centered("⊘");
+                    }
                     return StyledLine::new();
                 };
                 // Show centered | when in sync instead of ⇡0  ⇣0
                 // Note: This duplicates the InSync check from Divergence::Special, but
                 // checking counts directly is simpler than threading the enum through.
                 if active.ahead == 0 && active.behind == 0 {
-                    let mut cell = StyledLine::new();
-                    // Center the symbol in the column width
-                    let padding_left = (self.width.saturating_sub(1)) / 2;
-                    cell.push_raw(" ".repeat(padding_left));
-                    cell.push_styled("|", Style::new().dimmed());
-                    return cell;
+                    return centered("|");
                 }
                 self.render_diff_cell(active.ahead, active.behind)
             }
@@ -438,10 +570,7 @@ impl ColumnLayout {
                     return self.placeholder_cell("⋯");
                 };
                 let mut cell = StyledLine::new();
-                cell.push_styled(
-                    format_relative_time_short(commit.timestamp),
-                    Style::new().dimmed(),
-                );
+                cell.push_styled(time_format.format(commit.timestamp), Style::new().dimmed());
                 cell
             }
             ColumnKind::Url => {
@@ -462,6 +591,20 @@ impl ColumnLayout {
                 }
                 cell.truncate_to_width(self.width)
             }
+            ColumnKind::Issue => {
+                // Issue key from branch name, linked to the tracker when a URL is configured
+                let Some(issue) = &item.issue else {
+                    return StyledLine::new();
+                };
+                let mut cell = StyledLine::new();
+                match &issue.url {
+                    Some(url) if supports_hyperlinks(Stream::Stdout) => {
+                        cell.push_raw(hyperlink_stdout(url, &issue.key));
+                    }
+                    _ => cell.push_raw(issue.key.clone()),
+                }
+                cell.truncate_to_width(self.width)
+            }
             ColumnKind::CiStatus => {
                 // Check display field first for pending indicators during progressive rendering
                 // (works for both worktrees and branches)
@@ -488,17 +631,50 @@ impl ColumnLayout {
                     }
                 }
             }
+            ColumnKind::DiskUsage => {
+                let Some(data) = worktree_data else {
+                    return StyledLine::new();
+                };
+                let Some(bytes) = data.disk_usage else {
+                    return self.placeholder_cell("⋯");
+                };
+                // Right-align so magnitudes line up like `du -h`
+                let size = format_size(bytes);
+                let mut cell = StyledLine::new();
+                cell.push_raw(" ".repeat(self.width.saturating_sub(size.width())));
+                cell.push_styled(size, Style::new().dimmed());
+                cell
+            }
+            ColumnKind::Owner => match &item.owner {
+                Some(owner) => self.render_text_cell(owner, None),
+                None => self.placeholder_cell("⋯"),
+            },
             ColumnKind::Commit => {
                 let head = item.head();
                 let short_head = &head[..8.min(head.len())];
                 self.render_text_cell(short_head, Some(Style::new().dimmed()))
             }
             ColumnKind::Message => {
+                // Branch descriptions (--full) stand in for the commit subject,
+                // undimmed so they read as human-written context. Like a commit
+                // message, only the first line fits; JSON keeps the full text.
+                if let Some(ref description) = item.description {
+                    let first_line = description.lines().next().unwrap_or_default();
+                    let mut cell = StyledLine::new();
+                    cell.push_raw(truncate_to_width(
+                        &sanitize_for_display(first_line),
+                        max_message_len,
+                    ));
+                    return cell;
+                }
                 let Some(ref commit) = item.commit else {
                     return self.placeholder_cell("⋯");
                 };
                 let mut cell = StyledLine::new();
-                let msg = truncate_to_width(&commit.commit_message, max_message_len);
+                let msg = truncate_to_width(
+                    &sanitize_for_display(&commit.commit_message),
+                    max_message_len,
+                );
                 cell.push_styled(msg, Style::new().dimmed());
                 cell
             }
@@ -1271,4 +1447,27 @@ mod tests {
         assert!(arrow_rendered2.contains("50"));
         assert!(arrow_rendered2.contains("↓1") && arrow_rendered2.contains('K'));
     }
+    #[test]
+    fn test_graph_cell_aligns_merge_base() {
+        let column = ColumnLayout {
+            kind: ColumnKind::Graph,
+            header: "Graph",
+            start: 0,
+            width: 11,
+            format: ColumnFormat::Text,
+        };
+        let graph = |ahead, behind| {
+            column
+                .render_graph_cell(ahead, behind)
+                .render()
+                .ansi_strip()
+                .into_owned()
+        };
+
+        assert_eq!(graph(2, 3), "  ○○○◆●●");
+        assert_eq!(graph(0, 0), "     ◆");
+        assert_eq!(graph(5, 5), "○○○○○◆●●●●●");
+        // Sides longer than the column end in an ellipsis
+        assert_eq!(graph(12, 40), "…○○○○◆●●●●…");
+    }
 }
@@ -0,0 +1,293 @@
+//! `wt list --tune`: adjust column widths interactively and keep them per repo.
+//!
+//! The table is redrawn on stderr after every key press. Saved choices are
+//! stored as JSON in `worktrunk.list-columns` (repo-local git config), e.g.
+//! `{"widths": {"branch": 24}, "hidden": ["path"]}`. The layout allocator
+//! applies them in place of its own estimates; `--no-truncate` ignores the
+//! widths but still hides columns.
+
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+use std::io::{IsTerminal, Write, stderr};
+use std::path::Path;
+
+use anstyle::{AnsiColor, Color, Style};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::{cursor, execute, queue, terminal};
+use serde::{Deserialize, Serialize};
+use unicode_width::UnicodeWidthStr;
+use worktrunk::git::{GitError, Repository};
+
+use super::collect::TaskKind;
+use super::columns::{ColumnKind, column_display_index};
+use super::layout::{
+    ColumnFormat, DisplayOptions, LayoutConfig, WidthLimits, calculate_layout_from_basics,
+};
+use super::model::ListItem;
+use crate::display::{get_terminal_width, truncate_visible};
+
+/// Repo-local git config key holding the tuned layout.
+const CONFIG_KEY: &str = "worktrunk.list-columns";
+
+/// Column widths and visibility chosen with `wt list --tune`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnTuning {
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub widths: BTreeMap<ColumnKind, usize>,
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub hidden: BTreeSet<ColumnKind>,
+}
+
+impl ColumnTuning {
+    /// Load the saved tuning; unreadable values count as untuned.
+    pub fn load(repo: &Repository) -> Self {
+        repo.get_config(CONFIG_KEY)
+            .ok()
+            .flatten()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, repo: &Repository) -> anyhow::Result<()> {
+        if self == &Self::default() {
+            // Nothing tuned: drop the key rather than storing `{}`
+            let _ = repo.run_command(&["config", "--unset", CONFIG_KEY]);
+            return Ok(());
+        }
+        repo.set_config(CONFIG_KEY, &serde_json::to_string(self)?)
+    }
+
+    pub fn width(&self, kind: ColumnKind) -> Option<usize> {
+        self.widths.get(&kind).copied()
+    }
+
+    pub fn is_hidden(&self, kind: ColumnKind) -> bool {
+        self.hidden.contains(&kind)
+    }
+}
+
+/// Whether a column's width can be tuned. Diff columns align their numbers to
+/// a computed digit width, so they can only be hidden.
+fn resizable(format: ColumnFormat) -> bool {
+    matches!(format, ColumnFormat::Text)
+}
+
+/// Whether a column can be hidden. The gutter and branch identify the row.
+fn hideable(kind: ColumnKind) -> bool {
+    !matches!(kind, ColumnKind::Gutter | ColumnKind::Branch)
+}
+
+/// Leaves raw mode and the alternate screen however the tuner exits.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    fn enter() -> anyhow::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(stderr(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(Self)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        let _ = execute!(stderr(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Fail unless there's a terminal to draw the tuner in.
+///
+/// Checked before collecting, which can take a while.
+pub fn ensure_interactive() -> anyhow::Result<()> {
+    if !stderr().is_terminal() {
+        return Err(GitError::NeedsTerminal {
+            command: "wt list --tune",
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Run the interactive tuner over already-collected items.
+///
+/// Returns whether the tuning was saved.
+pub fn run(
+    repo: &Repository,
+    items: &[ListItem],
+    main_worktree_path: &Path,
+    skip_tasks: &HashSet<TaskKind>,
+    limits: WidthLimits,
+    display: &DisplayOptions,
+) -> anyhow::Result<bool> {
+    let url_template = repo.url_template();
+    let mut skip_tasks = skip_tasks.clone();
+    if url_template.is_none() {
+        skip_tasks.insert(TaskKind::UrlStatus);
+    }
+    let branch_prefix = repo.branch_display_prefix();
+    let mut limits = WidthLimits {
+        no_truncate: false,
+        ..limits
+    };
+    let mut selected = ColumnKind::Branch;
+
+    let _guard = TerminalGuard::enter()?;
+    loop {
+        let layout = calculate_layout_from_basics(
+            items,
+            &skip_tasks,
+            main_worktree_path,
+            url_template.as_deref(),
+            branch_prefix.as_deref(),
+            &limits,
+            display,
+        );
+        // Shown columns plus hidden ones, so hidden columns can be brought back
+        let mut choices: Vec<ColumnKind> = layout
+            .columns
+            .iter()
+            .map(|col| col.kind)
+            .chain(limits.tuning.hidden.iter().copied())
+            .filter(|kind| *kind != ColumnKind::Gutter)
+            .collect();
+        choices.sort_by_key(|kind| column_display_index(*kind));
+        choices.dedup();
+        if !choices.contains(&selected) {
+            selected = ColumnKind::Branch;
+        }
+        draw(&layout, items, &limits.tuning, selected)?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        let position = choices.iter().position(|kind| *kind == selected);
+        let shown = layout.columns.iter().find(|col| col.kind == selected);
+        let tuning = &mut limits.tuning;
+        match key.code {
+            KeyCode::Left => {
+                if let Some(i) = position.filter(|i| *i > 0) {
+                    selected = choices[i - 1];
+                }
+            }
+            KeyCode::Right => {
+                if let Some(i) = position.filter(|i| i + 1 < choices.len()) {
+                    selected = choices[i + 1];
+                }
+            }
+            KeyCode::Up | KeyCode::Char('+') | KeyCode::Down | KeyCode::Char('-') => {
+                let Some(col) = shown.filter(|col| resizable(col.format)) else {
+                    continue;
+                };
+                let current = if selected == ColumnKind::Message {
+                    layout.max_message_len
+                } else {
+                    col.width
+                };
+                let width = if matches!(key.code, KeyCode::Up | KeyCode::Char('+')) {
+                    current + 1
+                } else {
+                    current.saturating_sub(1).max(col.header.width()).max(1)
+                };
+                tuning.widths.insert(selected, width);
+            }
+            KeyCode::Char(' ') if hideable(selected) => {
+                if !tuning.hidden.remove(&selected) {
+                    tuning.hidden.insert(selected);
+                }
+            }
+            KeyCode::Char('r') => {
+                tuning.widths.remove(&selected);
+                tuning.hidden.remove(&selected);
+            }
+            KeyCode::Char('R') => *tuning = ColumnTuning::default(),
+            KeyCode::Enter => {
+                tuning.save(repo)?;
+                return Ok(true);
+            }
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(false),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                return Ok(false);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Redraw the table with the selected column marked under its header.
+fn draw(
+    layout: &LayoutConfig,
+    items: &[ListItem],
+    tuning: &ColumnTuning,
+    selected: ColumnKind,
+) -> anyhow::Result<()> {
+    let width = get_terminal_width();
+    let height = terminal::size().map_or(24, |(_, rows)| rows as usize);
+    let accent = Style::new().fg_color(Some(Color::Ansi(AnsiColor::Cyan)));
+    let dim = Style::new().dimmed();
+
+    let mut lines = vec![layout.render_header_line().render()];
+    let marker = match layout.columns.iter().find(|col| col.kind == selected) {
+        Some(col) => format!(
+            "{}{accent}{}{accent:#}",
+            " ".repeat(col.start),
+            "▔".repeat(col.width)
+        ),
+        None => String::new(),
+    };
+    lines.push(marker);
+    // Leave room for the marker, the status lines and a spacer
+    let rows = height.saturating_sub(5);
+    lines.extend(
+        items
+            .iter()
+            .take(rows)
+            .map(|item| layout.render_list_item_line(item).render()),
+    );
+    lines.push(String::new());
+
+    let header = selected.header();
+    let state = if tuning.is_hidden(selected) {
+        "hidden".to_string()
+    } else {
+        match tuning.width(selected) {
+            Some(width) => format!("width {width}"),
+            None => "automatic".to_string(),
+        }
+    };
+    lines.push(format!("{accent}{header}{accent:#} {state}"));
+    lines.push(format!(
+        "{dim}←/→ column  ↑/↓ width  space hide/show  r reset  R reset all  enter save  esc cancel{dim:#}"
+    ));
+
+    let mut err = stderr();
+    queue!(
+        err,
+        cursor::MoveTo(0, 0),
+        terminal::Clear(terminal::ClearType::All)
+    )?;
+    for line in lines {
+        // Raw mode: newlines don't return the cursor
+        write!(err, "{}\r\n", truncate_visible(&line, width))?;
+    }
+    err.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_tuning_roundtrip() {
+        let mut tuning = ColumnTuning::default();
+        assert_eq!(serde_json::to_string(&tuning).unwrap(), "{}");
+
+        tuning.widths.insert(ColumnKind::Branch, 24);
+        tuning.hidden.insert(ColumnKind::CiStatus);
+        let json = serde_json::to_string(&tuning).unwrap();
+        assert_eq!(json, r#"{"widths":{"branch":24},"hidden":["ci-status"]}"#);
+        assert_eq!(serde_json::from_str::<ColumnTuning>(&json).unwrap(), tuning);
+    }
+}
@@ -1,31 +1,50 @@
 use std::path::Path;
 
+use color_print::cformat;
 use worktrunk::HookType;
-use worktrunk::config::ProjectConfig;
-use worktrunk::git::Repository;
-use worktrunk::styling::info_message;
+use worktrunk::config::{MergeStep, ProjectConfig};
+use worktrunk::git::{ActivityKind, GitError, MergeProgress, Repository};
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{hint_message, info_message, success_message};
 
+use super::OperationLock;
 use super::command_approval::approve_command_batch;
 use super::command_executor::CommandContext;
 use super::commit::CommitOptions;
 use super::context::CommandEnv;
 use super::hooks::{HookFailureStrategy, run_hook_with_filter};
-use super::project_config::{HookCommand, collect_commands_for_hooks};
+use super::project_config::{HookCommand, collect_commands_for_hooks, collect_profile_commands};
 use super::repository_ext::RepositoryCliExt;
 use super::worktree::{
     BranchDeletionMode, MergeOperations, RemoveResult, get_path_mismatch, handle_push,
+    merge_push_remote, push_target_to_remote,
 };
 
 /// Options for the merge command
+///
+/// `None` for a step leaves it to the project's `[merge] steps` (on when the
+/// project doesn't configure a pipeline).
 pub struct MergeOptions<'a> {
     pub target: Option<&'a str>,
-    pub squash: bool,
-    pub commit: bool,
-    pub rebase: bool,
-    pub remove: bool,
+    pub squash: Option<bool>,
+    pub commit: Option<bool>,
+    pub rebase: Option<bool>,
+    pub remove: Option<bool>,
     pub verify: bool,
+    /// Push the updated target branch to its remote
+    pub push: bool,
+    /// Push the branch and wait for its CI to pass before updating the target
+    pub wait_ci: bool,
     pub yes: bool,
     pub stage_mode: super::commit::StageMode,
+    /// Fold `fixup!`/`squash!` commits before squashing or rebasing
+    pub autosquash: bool,
+    /// Resume an interrupted merge (`--continue`)
+    pub resume: bool,
+    /// Merge despite a git rebase or merge in progress
+    pub force: bool,
+    /// Block on the operation lock instead of failing (`--wait`)
+    pub wait: bool,
 }
 
 /// Collect all commands that will be executed during merge.
@@ -35,7 +54,9 @@ fn collect_merge_commands(
     repo: &Repository,
     commit: bool,
     verify: bool,
+    pre_merge: bool,
     will_remove: bool,
+    profiles: &[&str],
 ) -> anyhow::Result<(Vec<HookCommand>, String)> {
     let mut all_commands = Vec::new();
     let project_config = match repo.load_project_config()? {
@@ -51,7 +72,9 @@ fn collect_merge_commands(
     }
 
     if verify {
-        hooks.push(HookType::PreMerge);
+        if pre_merge {
+            hooks.push(HookType::PreMerge);
+        }
         hooks.push(HookType::PostMerge);
         if will_remove {
             hooks.push(HookType::PreRemove);
@@ -60,12 +83,33 @@ fn collect_merge_commands(
     }
 
     all_commands.extend(collect_commands_for_hooks(&project_config, &hooks));
+    if verify && !profiles.is_empty() {
+        all_commands.extend(collect_profile_commands(&project_config, Some(profiles)));
+    }
 
     let project_id = repo.project_identifier()?.to_string();
     Ok((all_commands, project_id))
 }
 
+/// Run `wt merge`, recording the outcome in the activity log for `wt history`.
 pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
+    // Opened first: removing the worktree after merging takes the cwd with it
+    let repo = Repository::current()?;
+    let branch = repo.current_worktree().branch().ok().flatten();
+    let path = repo.current_worktree().root().ok();
+    let result = merge(opts);
+    if let Err(e) = repo.record_activity(
+        ActivityKind::Merge,
+        branch.as_deref(),
+        path.as_deref(),
+        result.is_ok(),
+    ) {
+        log::debug!("Failed to record merge: {e:#}");
+    }
+    result
+}
+
+fn merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let MergeOptions {
         target,
         squash,
@@ -73,15 +117,79 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         rebase,
         remove,
         verify,
+        push,
+        wait_ci,
         yes,
         stage_mode,
+        autosquash,
+        resume,
+        force,
+        wait,
     } = opts;
 
-    let env = CommandEnv::for_action("merge")?;
+    // Branchless so an interrupted rebase (detached HEAD) can be detected and finished
+    let env = CommandEnv::for_action_branchless()?;
+    let current_branch = merge_branch(&env)?;
+
+    // An interrupted merge has to be resumed or rolled back before starting another
+    let target = match env.repo.merge_progress(&current_branch) {
+        Some(progress) if resume => {
+            finish_interrupted_rebase(&env.repo, &progress.target)?;
+            Some(progress.target)
+        }
+        Some(progress) if !is_rewritten(&env.repo, &progress)? => {
+            // Stopped before touching the branch (e.g. a pre-merge hook failed on
+            // an already-rebased branch); nothing to recover
+            env.repo.clear_merge_progress(&current_branch);
+            target.map(str::to_string)
+        }
+        Some(progress) => {
+            return Err(GitError::MergeInterrupted {
+                branch: current_branch,
+                target_branch: progress.target,
+            }
+            .into());
+        }
+        None if resume => return Err(no_interrupted_merge(&current_branch)),
+        None => target.map(str::to_string),
+    };
+    let target = target.as_deref();
+
+    // A rebase or merge started outside `wt merge` has to be settled first
+    if !force && let Some(operation) = env.repo.current_worktree().pending_operation()? {
+        return Err(GitError::GitOperationPending {
+            operation,
+            action: "merge".into(),
+            branch: Some(current_branch),
+        }
+        .into());
+    }
+
+    // Reload if a finished rebase just put HEAD back on the branch
+    let env = match env.branch {
+        Some(_) => env,
+        None => CommandEnv::for_action("merge")?,
+    };
     let repo = &env.repo;
     let config = &env.config;
-    // Merge requires being on a branch (can't merge from detached HEAD)
-    let current_branch = env.require_branch("merge")?.to_string();
+
+    // Steps the flags and user config leave open follow the project's pipeline
+    let project_config = repo.load_project_config()?.unwrap_or_default();
+    let steps = project_config.merge_steps()?;
+    let in_pipeline = |step: MergeStep| steps.as_ref().is_none_or(|steps| steps.contains(&step));
+    let commit = commit.unwrap_or_else(|| in_pipeline(MergeStep::Commit));
+    let squash = squash.unwrap_or_else(|| in_pipeline(MergeStep::Squash));
+    let rebase = rebase.unwrap_or_else(|| in_pipeline(MergeStep::Rebase));
+    let remove = remove.unwrap_or_else(|| in_pipeline(MergeStep::Cleanup));
+    let pre_merge = in_pipeline(MergeStep::Hooks);
+    let steps = steps.unwrap_or_default();
+    let profiles: Vec<&str> = steps
+        .iter()
+        .filter_map(|step| match step {
+            MergeStep::Profile(name) => Some(name.as_str()),
+            _ => None,
+        })
+        .collect();
 
     // Validate --no-commit: requires clean working tree
     if !commit && repo.current_worktree().is_dirty()? {
@@ -100,6 +208,13 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     let target_branch = repo.require_target_branch(target)?;
     // Worktree for target is optional: if present we use it for safety checks and as destination.
     let target_worktree_path = repo.worktree_for_branch(&target_branch)?;
+    // Check the remote before rewriting anything, so a push that would be rejected
+    // doesn't leave the local target updated
+    let push_remote = if push {
+        Some(merge_push_remote(repo, &target_branch)?)
+    } else {
+        None
+    };
 
     // When current == target or we're in the main worktree, disable remove (can't remove it)
     let in_main = !repo.current_worktree().is_linked().unwrap_or(false);
@@ -108,7 +223,7 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
 
     // Collect and approve all commands upfront for batch permission request
     let (all_commands, project_id) =
-        collect_merge_commands(repo, commit, verify, remove_effective)?;
+        collect_merge_commands(repo, commit, verify, pre_merge, remove_effective, &profiles)?;
 
     // Approve all commands in a single batch (shows templates, not expanded values)
     let approved = approve_command_batch(&all_commands, &project_id, config, yes, false)?;
@@ -122,6 +237,39 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
         verify
     };
 
+    // The starting point an interruption rolls back to, recorded just before a
+    // step changes the branch. A resumed merge keeps its original record.
+    let mut progress = match repo.merge_progress(&current_branch) {
+        Some(progress) => progress,
+        None => MergeProgress {
+            target: target_branch.clone(),
+            head: repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string(),
+            tip: None,
+            set_at: worktrunk::utils::get_now(),
+        },
+    };
+    let progress_guard = MergeProgressGuard {
+        repo,
+        branch: &current_branch,
+        start: progress.clone(),
+        armed: true,
+    };
+
+    // `[profiles]` steps run right after the built-in step they follow in
+    // `[merge] steps`; like hooks, they're skipped with --no-verify
+    let run_profile_steps = |after: Option<MergeStep>| -> anyhow::Result<()> {
+        if !verify {
+            return Ok(());
+        }
+        let ctx = env.context(yes);
+        for name in profile_steps_after(&steps, after) {
+            let template = &project_config.profile(name).expect("validated").command;
+            super::run::run_profile(&ctx, name, template, &[("target", target_branch.as_str())])?;
+        }
+        Ok(())
+    };
+    run_profile_steps(None)?;
+
     // Handle uncommitted changes (skip if --no-commit) - track whether commit occurred
     let committed = if commit && repo.current_worktree().is_dirty()? {
         if squash_enabled {
@@ -135,27 +283,46 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
             options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
             options.show_no_squash_note = true;
 
+            repo.set_merge_progress(&current_branch, &progress)?;
             options.commit()?;
             true // Committed directly
         }
     } else {
         false // No dirty changes or --no-commit
     };
+    run_profile_steps(Some(MergeStep::Commit))?;
 
     // Squash commits if enabled - track whether squashing occurred
+    if squash_enabled || autosquash {
+        repo.set_merge_progress(&current_branch, &progress)?;
+    }
     let squashed = if squash_enabled {
         matches!(
             super::step_commands::handle_squash(
                 Some(&target_branch),
                 yes,
                 !verify, // skip_pre_commit when !verify
-                stage_mode
+                stage_mode,
+                autosquash,
             )?,
             super::step_commands::SquashResult::Squashed
         )
     } else {
+        // Without squashing, fixups still fold into the commits they name
+        if autosquash {
+            super::step_commands::handle_autosquash(&target_branch)?;
+        }
         false
     };
+    run_profile_steps(Some(MergeStep::Squash))?;
+
+    // Where commit/squash left the branch, for rolling back a stopped rebase
+    if progress.tip.is_none() {
+        progress.tip = Some(repo.run_command(&["rev-parse", "HEAD"])?.trim().to_string());
+    }
+    if rebase || is_rewritten(repo, &progress)? {
+        repo.set_merge_progress(&current_branch, &progress)?;
+    }
 
     // Rebase onto target - track whether rebasing occurred
     let rebased = if rebase {
@@ -167,23 +334,32 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     } else {
         // --no-rebase: verify already rebased, fail if not
         if !repo.is_rebased_onto(&target_branch)? {
-            return Err(worktrunk::git::GitError::NotRebased {
+            return Err(GitError::NotRebased {
                 target_branch: target_branch.clone(),
             }
             .into());
         }
         false // Already rebased, no rebase occurred
     };
+    run_profile_steps(Some(MergeStep::Rebase))?;
 
-    // Run pre-merge checks unless --no-verify was specified
+    // Run pre-merge checks unless --no-verify was specified (or the pipeline drops them)
     // Do this after commit/squash/rebase to validate the final state that will be pushed
-    if verify {
+    if verify && pre_merge {
         let ctx = env.context(yes);
-        let project_config = repo.load_project_config()?.unwrap_or_default();
         run_pre_merge_commands(&project_config, &ctx, &target_branch, None, &[])?;
     }
+    run_profile_steps(Some(MergeStep::Hooks))?;
 
-    // Fast-forward push to target branch with commit/squash/rebase info for consolidated message
+    // The target only moves once the branch's CI passes on what will land
+    if wait_ci && !on_target {
+        super::ci_gate::push_and_wait_for_ci(repo, &current_branch, config)?;
+    }
+
+    // Fast-forward push to target branch with commit/squash/rebase info for consolidated message.
+    // Locked so a concurrent `wt` can't remove or create worktrees mid-update;
+    // released before the removal below, which takes the lock itself
+    let lock = OperationLock::acquire(repo, wait)?;
     handle_push(
         Some(&target_branch),
         "Merged to",
@@ -192,7 +368,16 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
             squashed,
             rebased,
         }),
+        false,
     )?;
+    drop(lock);
+    // Target now has the commits; what follows doesn't rewrite the branch
+    progress_guard.finish();
+
+    if let Some(remote) = &push_remote {
+        push_target_to_remote(repo, &target_branch, remote)?;
+    }
+    run_profile_steps(Some(MergeStep::Push))?;
 
     // Destination: prefer the target branch's worktree; fall back to home path.
     let destination_path = match target_worktree_path {
@@ -228,10 +413,11 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
             // commit, removal will fail and user can run `wt remove --force`
             force_worktree: false,
             expected_path,
+            unlock: false,
         };
         // Run hooks during merge removal (pass through verify flag)
         // Approval was handled at the gate (collect_merge_commands)
-        crate::output::handle_remove_output(&remove_result, true, verify)?;
+        crate::output::handle_remove_output(&remove_result, true, verify, wait)?;
     } else {
         // Worktree preserved - show reason (priority: main worktree > on target > --no-remove flag)
         let message = if in_main {
@@ -271,6 +457,139 @@ pub fn handle_merge(opts: MergeOptions<'_>) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Names of the `[profiles]` steps between `after` (the start, with `None`)
+/// and the next built-in step.
+fn profile_steps_after(steps: &[MergeStep], after: Option<MergeStep>) -> Vec<&str> {
+    let start = match after {
+        Some(after) => match steps.iter().position(|step| *step == after) {
+            Some(index) => index + 1,
+            None => return Vec::new(),
+        },
+        None => 0,
+    };
+    steps[start..]
+        .iter()
+        .map_while(|step| match step {
+            MergeStep::Profile(name) => Some(name.as_str()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Handle `wt merge --abort`: roll the branch back to where an interrupted merge started.
+///
+/// Aborts any rebase in progress, restores the pre-rebase commits, then resets to
+/// the original HEAD, leaving changes the merge committed or squashed staged.
+pub fn handle_merge_abort() -> anyhow::Result<()> {
+    let env = CommandEnv::for_action_branchless()?;
+    let repo = &env.repo;
+    let current_branch = merge_branch(&env)?;
+    let Some(progress) = repo.merge_progress(&current_branch) else {
+        return Err(no_interrupted_merge(&current_branch));
+    };
+    let worktree = repo.current_worktree();
+
+    if worktree.is_rebasing()? {
+        worktree.run_command(&["rebase", "--abort"])?;
+    }
+    if let Some(tip) = &progress.tip {
+        // A hard reset would discard edits made since the interruption
+        worktree.ensure_clean("abort merge", Some(&current_branch), false)?;
+        worktree.run_command(&["reset", "--hard", tip])?;
+    }
+    worktree.run_command(&["reset", "--soft", &progress.head])?;
+    repo.clear_merge_progress(&current_branch);
+
+    crate::output::print(success_message(cformat!(
+        "Rolled back merge of <bold>{current_branch}</> into <bold>{}</>",
+        progress.target
+    )))?;
+    if worktree.is_dirty()? {
+        crate::output::print(hint_message(cformat!(
+            "Changes the merge committed are staged; to review, run <bright-black>git diff --cached</>"
+        )))?;
+    }
+    Ok(())
+}
+
+/// The branch being merged, looking through the detached HEAD of a rebase in progress.
+fn merge_branch(env: &CommandEnv) -> anyhow::Result<String> {
+    if let Some(branch) = env.repo.current_worktree().rebasing_branch() {
+        return Ok(branch);
+    }
+    // Merge requires being on a branch (can't merge from detached HEAD)
+    Ok(env.require_branch("merge")?.to_string())
+}
+
+/// Clears the merge record when the merge stops with the branch unchanged.
+///
+/// A step that fails and restores the branch (or fails before touching it)
+/// then leaves nothing behind to block the next `wt merge`.
+struct MergeProgressGuard<'a> {
+    repo: &'a Repository,
+    branch: &'a str,
+    start: MergeProgress,
+    armed: bool,
+}
+
+impl MergeProgressGuard<'_> {
+    /// The target was updated: clear the record for good.
+    fn finish(mut self) {
+        self.repo.clear_merge_progress(self.branch);
+        self.armed = false;
+    }
+}
+
+impl Drop for MergeProgressGuard<'_> {
+    fn drop(&mut self) {
+        if self.armed && matches!(is_rewritten(self.repo, &self.start), Ok(false)) {
+            self.repo.clear_merge_progress(self.branch);
+        }
+    }
+}
+
+/// Whether an interrupted merge changed the branch (or left a rebase in progress).
+fn is_rewritten(repo: &Repository, progress: &MergeProgress) -> anyhow::Result<bool> {
+    let worktree = repo.current_worktree();
+    if worktree.is_rebasing()? {
+        return Ok(true);
+    }
+    let head = worktree.run_command(&["rev-parse", "HEAD"])?;
+    Ok(head.trim() != progress.head)
+}
+
+/// Complete a rebase the interrupted merge left behind, once conflicts are resolved.
+fn finish_interrupted_rebase(repo: &Repository, target_branch: &str) -> anyhow::Result<()> {
+    let worktree = repo.current_worktree();
+    if !worktree.is_rebasing()? {
+        return Ok(());
+    }
+
+    let output = Cmd::new("git")
+        .args(["rebase", "--continue"])
+        // Keep the original commit messages rather than opening an editor
+        .env("GIT_EDITOR", "true")
+        .current_dir(worktree.root()?)
+        .context("merge")
+        .run()?;
+    if !output.status.success() || worktree.is_rebasing()? {
+        let git_output = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(GitError::RebaseConflict {
+            target_branch: target_branch.to_string(),
+            git_output,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+fn no_interrupted_merge(branch: &str) -> anyhow::Error {
+    GitError::Other {
+        message: cformat!("No interrupted merge for <bold>{branch}</>"),
+    }
+    .into()
+}
+
 /// Run pre-merge commands sequentially (blocking, fail-fast)
 ///
 /// Runs user hooks first, then project hooks.
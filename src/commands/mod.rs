@@ -1,49 +1,100 @@
-pub(crate) mod branch_deletion;
+mod alias;
+mod cherry;
+mod ci_gate;
+mod clean;
+pub(crate) mod clone;
 pub(crate) mod command_approval;
 pub(crate) mod command_executor;
 pub(crate) mod commit;
+mod commit_review;
+mod commit_split;
 pub(crate) mod config;
 pub(crate) mod configure_shell;
 pub(crate) mod context;
+pub(crate) mod describe;
+mod doctor;
 mod for_each;
+pub(crate) mod forge;
+mod history;
 mod hook_commands;
 mod hook_filter;
 mod hooks;
 pub(crate) mod init;
 pub(crate) mod list;
 pub(crate) mod merge;
+mod navigate;
+pub(crate) mod operation_lock;
 pub(crate) mod process;
 pub(crate) mod project_config;
+pub(crate) mod ps;
+mod report;
 pub(crate) mod repository_ext;
+pub(crate) mod review;
+mod run;
 #[cfg(unix)]
 pub(crate) mod select;
+#[cfg(feature = "serve")]
+pub(crate) mod serve;
+pub(crate) mod setup;
 pub(crate) mod statusline;
+pub(crate) mod statusline_format;
 pub(crate) mod step_commands;
+mod tag;
+pub(crate) mod trash;
+mod update;
+#[cfg(feature = "web")]
+pub(crate) mod web;
 pub(crate) mod worktree;
 
-pub(crate) use command_approval::approve_hooks;
+pub(crate) use alias::{expand_aliases, handle_alias_list};
+pub(crate) use cherry::handle_cherry;
+pub(crate) use clean::handle_clean;
+pub(crate) use clone::handle_clone;
+pub(crate) use command_approval::{approve_hooks, approve_hooks_for_branches};
 pub(crate) use config::{
-    handle_config_create, handle_config_show, handle_hints_clear, handle_hints_get,
-    handle_state_clear, handle_state_clear_all, handle_state_get, handle_state_set,
-    handle_state_show,
+    handle_config_create, handle_config_diff, handle_config_migrate, handle_config_show,
+    handle_hints_clear, handle_hints_get, handle_state_clear, handle_state_clear_all,
+    handle_state_get, handle_state_set, handle_state_show,
 };
 pub(crate) use configure_shell::{
     handle_configure_shell, handle_show_theme, handle_unconfigure_shell,
 };
+pub(crate) use describe::handle_describe;
+pub(crate) use doctor::handle_doctor;
 pub(crate) use for_each::step_for_each;
-pub(crate) use hook_commands::{add_approvals, clear_approvals, handle_hook_show, run_hook};
-pub(crate) use init::handle_init;
+pub(crate) use history::handle_history;
+pub(crate) use hook_commands::{
+    add_approvals, clear_approvals, handle_hook_show, list_approvals, revoke_approvals, run_hook,
+};
+pub(crate) use hooks::set_skipped_hooks;
+pub(crate) use init::{handle_completions, handle_init};
 pub(crate) use list::handle_list;
-pub(crate) use merge::{MergeOptions, execute_pre_remove_commands, handle_merge};
+pub(crate) use merge::{
+    MergeOptions, execute_pre_remove_commands, handle_merge, handle_merge_abort,
+};
+pub(crate) use navigate::{handle_main, handle_root};
+pub(crate) use operation_lock::OperationLock;
+pub(crate) use ps::handle_ps;
+pub(crate) use report::handle_report;
+pub(crate) use review::{handle_review, handle_review_release};
+pub(crate) use run::handle_run;
 #[cfg(unix)]
 pub(crate) use select::handle_select;
+#[cfg(feature = "serve")]
+pub(crate) use serve::handle_serve;
+pub(crate) use setup::{handle_setup, offer_setup};
 pub(crate) use step_commands::{
     RebaseResult, SquashResult, handle_rebase, handle_squash, step_commit, step_copy_ignored,
-    step_show_squash_prompt,
+    step_prune, step_show_squash_prompt,
 };
+pub(crate) use tag::{TagOptions, handle_tag};
+pub(crate) use update::handle_update;
+#[cfg(feature = "web")]
+pub(crate) use web::handle_web;
 pub(crate) use worktree::{
-    ResolutionContext, execute_switch, handle_remove, handle_remove_current,
-    is_worktree_at_expected_path, plan_switch, resolve_worktree_arg, worktree_display_name,
+    ResolutionContext, execute_switch, handle_path, handle_push_branch, handle_remove,
+    handle_remove_current, is_worktree_at_expected_path, plan_switch, resolve_worktree_arg,
+    select_from_history, worktree_display_name,
 };
 
 // Re-export Shell from the canonical location
@@ -0,0 +1,40 @@
+//! `wt main` and `wt root`: jump to the repository's fixed locations.
+//!
+//! Both print the path on stdout, so `$(wt main)` works in scripts. With shell
+//! integration the shell also changes to it, like `wt switch`.
+
+use std::path::Path;
+
+use worktrunk::git::{GitError, Repository};
+
+use crate::output;
+
+/// Handle `wt main`: the primary worktree.
+///
+/// The main worktree for normal repositories; in bare repositories, the
+/// default branch's worktree.
+pub fn handle_main() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let Some(path) = repo.primary_worktree()? else {
+        return Err(match repo.default_branch() {
+            Some(branch) => GitError::NoWorktreeFound { branch },
+            None => GitError::Other {
+                message: "Cannot determine the default branch".into(),
+            },
+        }
+        .into());
+    };
+    go_to(&path)
+}
+
+/// Handle `wt root`: the common git directory, shared by all worktrees.
+pub fn handle_root() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    go_to(repo.git_common_dir())
+}
+
+fn go_to(path: &Path) -> anyhow::Result<()> {
+    output::change_directory(path)?;
+    output::stdout(path.display().to_string())?;
+    Ok(())
+}
@@ -0,0 +1,108 @@
+//! Advisory lock serializing mutating `wt` operations on one repository.
+//!
+//! Worktree creation and removal touch shared state in the common git dir
+//! (`worktrees/`, branch refs, worktrunk's own config keys). Two `wt`
+//! processes — say, an agent and a human — running these at once can race,
+//! so each takes `wt.lock` in the common git dir for the duration of the
+//! mutation. The lock file records the holder's pid and command line so a
+//! blocked invocation can say who it's waiting on.
+//!
+//! The lock is an OS file lock, released when the holder exits even if it
+//! crashes; the file itself is left in place. A background removal takes the
+//! lock over with [`OperationLock::into_stdin`], so it stays held until the
+//! detached process finishes.
+
+use std::fs::{File, OpenOptions, TryLockError};
+use std::io::{Read, Seek, Write};
+use std::process::Stdio;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::progress_message;
+
+use crate::output;
+
+/// Held lock; released on drop.
+pub struct OperationLock {
+    file: File,
+}
+
+impl OperationLock {
+    /// Take the repository's operation lock.
+    ///
+    /// Fails with [`GitError::OperationInProgress`] when another process
+    /// holds it, unless `wait` (`--wait`) is set.
+    ///
+    /// Locks aren't reentrant: the lock is taken around individual mutations,
+    /// never around code that itself takes it.
+    pub fn acquire(repo: &Repository, wait: bool) -> anyhow::Result<Self> {
+        let path = repo.git_common_dir().join("wt.lock");
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .context("Failed to open wt.lock")?;
+
+        match file.try_lock() {
+            Ok(()) => {}
+            Err(TryLockError::WouldBlock) => {
+                let (pid, command) = read_holder(&mut file);
+                if !wait {
+                    return Err(GitError::OperationInProgress { pid, command }.into());
+                }
+                let holder = match (pid, command) {
+                    (Some(pid), Some(command)) => cformat!(" (pid {pid}: <bold>{command}</>)"),
+                    (Some(pid), None) => format!(" (pid {pid})"),
+                    _ => String::new(),
+                };
+                output::print(progress_message(format!(
+                    "Waiting for another wt operation{holder}..."
+                )))?;
+                output::flush()?;
+                file.lock().context("Failed to lock wt.lock")?;
+            }
+            Err(TryLockError::Error(e)) => {
+                return Err(e).context("Failed to lock wt.lock");
+            }
+        }
+
+        // Record the holder; failure only degrades the contention message
+        let command_line = std::iter::once(crate::binary_name())
+            .chain(std::env::args().skip(1))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let _ = file
+            .set_len(0)
+            .and_then(|()| file.rewind())
+            .and_then(|()| writeln!(file, "{}\n{command_line}", std::process::id()));
+
+        Ok(Self { file })
+    }
+
+    /// The lock as a child's stdin, so the child keeps it after we exit.
+    ///
+    /// The lock belongs to the open file, which the child shares; it's
+    /// released once every process holding the file has closed it.
+    pub fn into_stdin(self) -> Stdio {
+        Stdio::from(self.file)
+    }
+}
+
+/// Parse the pid and command line written by the current holder.
+fn read_holder(file: &mut File) -> (Option<u32>, Option<String>) {
+    let mut content = String::new();
+    if file.read_to_string(&mut content).is_err() {
+        return (None, None);
+    }
+    let mut lines = content.lines();
+    let pid = lines.next().and_then(|line| line.trim().parse().ok());
+    let command = lines
+        .next()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(str::to_string);
+    (pid, command)
+}
@@ -7,6 +7,8 @@ use std::process::Stdio;
 use worktrunk::git::Repository;
 use worktrunk::path::{format_path_for_display, sanitize_for_filename};
 
+use super::OperationLock;
+
 /// Get the separator needed before closing brace in POSIX shell command grouping.
 /// Returns empty string if command already ends with newline or semicolon.
 fn posix_command_separator(command: &str) -> &'static str {
@@ -32,6 +34,7 @@ fn posix_command_separator(command: &str) -> &'static str {
 /// * `branch` - Branch name for log organization
 /// * `name` - Operation identifier (e.g., "post-start-npm", "remove")
 /// * `context_json` - Optional JSON context to pipe to command's stdin
+/// * `env` - Extra environment variables for the command
 ///
 /// # Returns
 /// Path to the log file where output is being written
@@ -42,6 +45,52 @@ pub fn spawn_detached(
     branch: &str,
     name: &str,
     context_json: Option<&str>,
+    env: &[(String, String)],
+) -> anyhow::Result<std::path::PathBuf> {
+    spawn_detached_impl(
+        repo,
+        worktree_path,
+        command,
+        branch,
+        name,
+        context_json,
+        env,
+        None,
+    )
+}
+
+/// Like [`spawn_detached`], handing `lock` to the detached process so it
+/// stays held until the command finishes.
+pub fn spawn_detached_with_lock(
+    repo: &Repository,
+    worktree_path: &Path,
+    command: &str,
+    branch: &str,
+    name: &str,
+    lock: OperationLock,
+) -> anyhow::Result<std::path::PathBuf> {
+    spawn_detached_impl(
+        repo,
+        worktree_path,
+        command,
+        branch,
+        name,
+        None,
+        &[],
+        Some(lock),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn spawn_detached_impl(
+    repo: &Repository,
+    worktree_path: &Path,
+    command: &str,
+    branch: &str,
+    name: &str,
+    context_json: Option<&str>,
+    env: &[(String, String)],
+    lock: Option<OperationLock>,
 ) -> anyhow::Result<std::path::PathBuf> {
     // Create log directory in the common git directory
     let log_dir = repo.wt_logs_dir();
@@ -75,12 +124,12 @@ pub fn spawn_detached(
 
     #[cfg(unix)]
     {
-        spawn_detached_unix(worktree_path, command, log_file, context_json)?;
+        spawn_detached_unix(worktree_path, command, log_file, context_json, env, lock)?;
     }
 
     #[cfg(windows)]
     {
-        spawn_detached_windows(worktree_path, command, log_file, context_json)?;
+        spawn_detached_windows(worktree_path, command, log_file, context_json, env, lock)?;
     }
 
     Ok(log_path)
@@ -92,6 +141,8 @@ fn spawn_detached_unix(
     command: &str,
     log_file: fs::File,
     context_json: Option<&str>,
+    env: &[(String, String)],
+    lock: Option<OperationLock>,
 ) -> anyhow::Result<()> {
     use std::os::unix::process::CommandExt;
 
@@ -111,7 +162,12 @@ fn spawn_detached_unix(
         None => command.to_string(),
     };
 
-    let shell_cmd = format!("{} &", full_command);
+    // An asynchronous command gets /dev/null as stdin, so the lock passed in
+    // on stdin is moved to fd 9 first, which the background command keeps
+    let (shell_cmd, stdin) = match lock {
+        Some(lock) => (format!("exec 9<&0; {} &", full_command), lock.into_stdin()),
+        None => (format!("{} &", full_command), Stdio::null()),
+    };
 
     // Detachment via process_group(0): puts the spawned shell in its own process group.
     // When the controlling PTY closes, SIGHUP is sent to the foreground process group.
@@ -120,13 +176,14 @@ fn spawn_detached_unix(
         .arg("-c")
         .arg(&shell_cmd)
         .current_dir(worktree_path)
-        .stdin(Stdio::null())
+        .stdin(stdin)
         .stdout(Stdio::from(
             log_file
                 .try_clone()
                 .context("Failed to clone log file handle")?,
         ))
         .stderr(Stdio::from(log_file))
+        .envs(env.iter().map(|(k, v)| (k, v)))
         // Prevent hooks from writing to the directive file
         .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
         .process_group(0) // New process group, not in PTY's foreground group
@@ -147,6 +204,8 @@ fn spawn_detached_windows(
     command: &str,
     log_file: fs::File,
     context_json: Option<&str>,
+    env: &[(String, String)],
+    lock: Option<OperationLock>,
 ) -> anyhow::Result<()> {
     use std::os::windows::process::CommandExt;
     use worktrunk::shell_exec::ShellConfig;
@@ -192,14 +251,17 @@ fn spawn_detached_windows(
         shell.command(&full_command)
     };
 
+    // The detached process holds the lock through the handle it inherits
+    let stdin = lock.map_or_else(Stdio::null, OperationLock::into_stdin);
     cmd.current_dir(worktree_path)
-        .stdin(Stdio::null())
+        .stdin(stdin)
         .stdout(Stdio::from(
             log_file
                 .try_clone()
                 .context("Failed to clone log file handle")?,
         ))
         .stderr(Stdio::from(log_file))
+        .envs(env.iter().map(|(k, v)| (k, v)))
         // Prevent hooks from writing to the directive file
         .env_remove(worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR)
         .creation_flags(CREATE_NEW_PROCESS_GROUP | DETACHED_PROCESS)
@@ -266,6 +328,46 @@ pub fn build_remove_command(
     }
 }
 
+/// Build shell command that finishes a removal staged in the trash directory
+///
+/// The worktree has already been moved to `trash_path` and unregistered from git
+/// (see `trash::stage_worktree_removal`), so only the branch deletion and the
+/// slow file deletion remain. The branch is deleted first so it disappears from
+/// `wt list` right away; the files are deleted even if that fails.
+///
+/// With `wt_exe` (`[remove] use-trash`), the files are handed to
+/// `wt step empty-trash`, which decides between the OS trash and deleting;
+/// otherwise they're deleted with `rm -rf`.
+pub fn build_trash_delete_command(
+    trash_path: &Path,
+    branch_to_delete: Option<&str>,
+    wt_exe: Option<&Path>,
+) -> String {
+    use shell_escape::escape;
+
+    let trash_path_str = trash_path.to_string_lossy();
+    let trash_escaped = escape(trash_path_str.as_ref().into());
+
+    // Same shell-cd race as `build_remove_command`: the shell may still be
+    // inside the (renamed) directory until it processes the cd directive.
+    let delete = match wt_exe {
+        Some(exe) => format!(
+            "sleep 1 && {} step empty-trash {}",
+            escape(exe.to_string_lossy()),
+            trash_escaped
+        ),
+        None => format!("sleep 1 && rm -rf {}", trash_escaped),
+    };
+
+    match branch_to_delete {
+        Some(branch_name) => {
+            let branch_escaped = escape(branch_name.into());
+            format!("git branch -D {}; {}", branch_escaped, delete)
+        }
+        None => delete,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,4 +474,29 @@ mod tests {
         let cmd = build_remove_command(&special_path, Some("feature/branch"), false);
         assert!(cmd.contains("worktree remove"));
     }
+
+    #[test]
+    fn test_build_trash_delete_command() {
+        use std::path::PathBuf;
+
+        let path = PathBuf::from("/repo/.git/wt-trash/1700000000-feature");
+
+        let cmd = build_trash_delete_command(&path, None);
+        assert_eq!(
+            cmd,
+            "sleep 1 && rm -rf /repo/.git/wt-trash/1700000000-feature"
+        );
+
+        // Branch deletion runs first and doesn't gate the file deletion
+        let cmd = build_trash_delete_command(&path, Some("feature"));
+        assert_eq!(
+            cmd,
+            "git branch -D feature; sleep 1 && rm -rf /repo/.git/wt-trash/1700000000-feature"
+        );
+
+        // Shell escaping for special characters
+        let special_path = PathBuf::from("/tmp/wt trash/1700000000-x");
+        let cmd = build_trash_delete_command(&special_path, Some("feature/branch"));
+        assert!(cmd.contains("'/tmp/wt trash/1700000000-x'"));
+    }
 }
@@ -1,19 +1,60 @@
 use worktrunk::config::{Command, ProjectConfig};
 use worktrunk::git::HookType;
 
+use super::hooks::is_hook_skipped;
+
+/// Where a project command runs: a lifecycle hook or `wt run`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommandPhase {
+    Hook(HookType),
+    /// A `[profiles.<name>]` command; the profile name is the command name.
+    Run,
+}
+
+impl std::fmt::Display for CommandPhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hook(hook_type) => hook_type.fmt(f),
+            Self::Run => f.write_str("run"),
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct HookCommand {
-    pub hook_type: HookType,
+    pub phase: CommandPhase,
     pub command: Command,
 }
 
+impl HookCommand {
+    /// Identifies the command across edits to its text: `phase:name`, or
+    /// just the phase for an unnamed command.
+    pub fn approval_key(&self) -> String {
+        match &self.command.name {
+            Some(name) => format!("{}:{name}", self.phase),
+            None => self.phase.to_string(),
+        }
+    }
+}
+
 /// Collect commands for the given hook types, preserving order of the provided hooks.
+///
+/// Hook types skipped with `--skip-hook` contribute nothing.
 pub fn collect_commands_for_hooks(
     project_config: &ProjectConfig,
     hooks: &[HookType],
 ) -> Vec<HookCommand> {
     let mut commands = Vec::new();
-    for hook in hooks {
+    for hook in hooks.iter().filter(|hook| !is_hook_skipped(**hook)) {
+        // The `[integrations] nix` command runs as part of post-create
+        if *hook == HookType::PostCreate
+            && let Some(command) = project_config.nix_command()
+        {
+            commands.push(HookCommand {
+                phase: CommandPhase::Hook(*hook),
+                command,
+            });
+        }
         if let Some(config) = project_config.hooks.get(*hook) {
             commands.extend(
                 config
@@ -21,7 +62,7 @@ pub fn collect_commands_for_hooks(
                     .iter()
                     .cloned()
                     .map(|command| HookCommand {
-                        hook_type: *hook,
+                        phase: CommandPhase::Hook(*hook),
                         command,
                     }),
             );
@@ -30,6 +71,48 @@ pub fn collect_commands_for_hooks(
     commands
 }
 
+/// Collect the extra hook commands of the branch's `[[rules]]` entry.
+pub fn collect_rule_commands(
+    project_config: &ProjectConfig,
+    branch: &str,
+    hooks: &[HookType],
+) -> Vec<HookCommand> {
+    let Some(rule) = project_config.rule_for(branch) else {
+        return Vec::new();
+    };
+    hooks
+        .iter()
+        .filter(|hook| !is_hook_skipped(**hook))
+        .filter_map(|hook| rule.hook(*hook).map(|config| (*hook, config)))
+        .flat_map(|(hook, config)| {
+            config
+                .commands()
+                .iter()
+                .cloned()
+                .map(move |command| HookCommand {
+                    phase: CommandPhase::Hook(hook),
+                    command,
+                })
+        })
+        .collect()
+}
+
+/// Collect the commands of the given profiles, or of every profile with `None`.
+pub fn collect_profile_commands(
+    project_config: &ProjectConfig,
+    names: Option<&[&str]>,
+) -> Vec<HookCommand> {
+    project_config
+        .profiles
+        .iter()
+        .filter(|(name, _)| names.is_none_or(|names| names.contains(&name.as_str())))
+        .map(|(name, profile)| HookCommand {
+            phase: CommandPhase::Run,
+            command: Command::new(Some(name.clone()), profile.command.clone()),
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,6 +200,26 @@ build = "npm run build"
         let config = make_project_config_with_hooks();
         let commands = collect_commands_for_hooks(&config, &[HookType::PostCreate]);
         assert_eq!(commands.len(), 1);
-        assert_eq!(commands[0].hook_type, HookType::PostCreate);
+        assert_eq!(commands[0].phase, CommandPhase::Hook(HookType::PostCreate));
+    }
+
+    #[test]
+    fn test_collect_profile_commands() {
+        let toml_content = r#"
+[profiles.test]
+command = "cargo test"
+
+[profiles.lint]
+command = "cargo clippy"
+"#;
+        let config: ProjectConfig = toml::from_str(toml_content).unwrap();
+        let commands = collect_profile_commands(&config, None);
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].approval_key(), "run:test");
+
+        let commands = collect_profile_commands(&config, Some(&["lint"]));
+        assert_eq!(commands.len(), 1);
+        assert_eq!(commands[0].command.template, "cargo clippy");
+        assert_eq!(commands[0].phase, CommandPhase::Run);
     }
 }
@@ -0,0 +1,43 @@
+//! Background removal status.
+//!
+//! `wt remove` moves worktrees into `.git/wt-trash/` and deletes their files
+//! afterwards (see `trash`). `wt ps` lists the deletions that haven't finished.
+
+use color_print::cformat;
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{hint_message, info_message, progress_message};
+
+use super::list::disk_usage::{dir_size, format_size};
+use super::trash::pending_removals;
+use crate::display::format_relative_time_short;
+use crate::output;
+
+/// Handle `wt ps`: show worktree removals still deleting files.
+pub fn handle_ps() -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let pending = pending_removals(&repo);
+
+    if pending.is_empty() {
+        output::print(info_message("No background removals in progress"))?;
+        return Ok(());
+    }
+
+    for entry in &pending {
+        let name = &entry.name;
+        let remaining = format_size(dir_size(&entry.path));
+        let started = match format_relative_time_short(entry.started as i64).as_str() {
+            "now" => "just now".to_string(),
+            age => format!("{age} ago"),
+        };
+        output::print(progress_message(cformat!(
+            "Deleting <bold>{name}</> <bright-black>({remaining} left, started {started})</>"
+        )))?;
+    }
+
+    let logs_dir = format_path_for_display(&repo.wt_logs_dir());
+    output::print(hint_message(cformat!(
+        "Removal logs are in <bright-black>{logs_dir}</>"
+    )))?;
+    Ok(())
+}
@@ -0,0 +1,235 @@
+//! `wt report`: a cleanup summary for login messages and cron jobs.
+//!
+//! Collects four things worth acting on: worktrees without recent commits,
+//! worktrees whose branch is already integrated into the default branch,
+//! branches far behind it, and disk usage per worktree (largest first).
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Context;
+use color_print::cformat;
+use serde::Serialize;
+use worktrunk::git::{IntegrationReason, Repository, WorktreeInfo};
+use worktrunk::path::format_path_for_display;
+use worktrunk::utils::get_now;
+
+use super::list::disk_usage::{format_size, worktree_disk_usage};
+use crate::OutputFormat;
+use crate::display::format_relative_time_short;
+use crate::output;
+
+#[derive(Debug, Serialize)]
+struct Report {
+    default_branch: Option<String>,
+    /// Worktrees whose last commit is older than `--stale-after`
+    stale: Vec<StaleWorktree>,
+    /// Worktrees whose branch is already in the default branch
+    integrated: Vec<IntegratedWorktree>,
+    /// Branches more than `--behind` commits behind the default branch
+    behind: Vec<BehindBranch>,
+    /// Disk usage per worktree, largest first
+    disk: Vec<WorktreeDisk>,
+}
+
+#[derive(Debug, Serialize)]
+struct StaleWorktree {
+    branch: Option<String>,
+    path: PathBuf,
+    /// Unix timestamp of the last commit
+    last_commit: i64,
+}
+
+#[derive(Debug, Serialize)]
+struct IntegratedWorktree {
+    branch: String,
+    path: PathBuf,
+    reason: IntegrationReason,
+}
+
+#[derive(Debug, Serialize)]
+struct BehindBranch {
+    branch: String,
+    behind: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct WorktreeDisk {
+    branch: Option<String>,
+    path: PathBuf,
+    bytes: u64,
+}
+
+/// Handle `wt report`.
+pub fn handle_report(
+    stale_after: Duration,
+    behind_threshold: usize,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let report = build_report(&repo, stale_after, behind_threshold)?;
+
+    match format {
+        OutputFormat::Json => {
+            let json =
+                serde_json::to_string_pretty(&report).context("Failed to serialize to JSON")?;
+            output::stdout(json)?;
+        }
+        OutputFormat::Table => {
+            output::stdout(render(&report, stale_after, behind_threshold))?;
+        }
+    }
+    Ok(())
+}
+
+fn build_report(
+    repo: &Repository,
+    stale_after: Duration,
+    behind_threshold: usize,
+) -> anyhow::Result<Report> {
+    let default_branch = repo.default_branch();
+    let worktrees: Vec<WorktreeInfo> = repo
+        .list_worktrees()?
+        .into_iter()
+        .filter(|wt| !wt.bare && !wt.is_prunable())
+        .collect();
+    let is_default = |branch: Option<&str>| branch.is_some() && branch == default_branch.as_deref();
+
+    let cutoff = get_now().saturating_sub(stale_after.as_secs()) as i64;
+    let heads: Vec<&str> = worktrees.iter().map(|wt| wt.head.as_str()).collect();
+    let timestamps = repo.commit_timestamps(&heads)?;
+    let stale = worktrees
+        .iter()
+        .filter(|wt| !is_default(wt.branch.as_deref()))
+        .filter_map(|wt| {
+            let last_commit = *timestamps.get(&wt.head)?;
+            (last_commit < cutoff).then(|| StaleWorktree {
+                branch: wt.branch.clone(),
+                path: wt.path.clone(),
+                last_commit,
+            })
+        })
+        .collect();
+
+    let mut integrated = Vec::new();
+    let mut behind = Vec::new();
+    if let Some(base) = &default_branch {
+        for wt in &worktrees {
+            let Some(branch) = wt.branch.as_deref().filter(|b| !is_default(Some(*b))) else {
+                continue;
+            };
+            // Like `wt list`, a branch with no commits of its own isn't "integrated"
+            if let (_, Some(reason)) = repo.integration_reason(branch, base)?
+                && reason != IntegrationReason::SameCommit
+            {
+                integrated.push(IntegratedWorktree {
+                    branch: branch.to_string(),
+                    path: wt.path.clone(),
+                    reason,
+                });
+            }
+        }
+
+        behind = repo
+            .batch_ahead_behind(base)
+            .into_iter()
+            .filter(|(branch, (_, count))| *count > behind_threshold && branch != base)
+            .map(|(branch, (_, count))| BehindBranch {
+                branch,
+                behind: count,
+            })
+            .collect();
+        behind.sort_by(|a, b| b.behind.cmp(&a.behind).then(a.branch.cmp(&b.branch)));
+    }
+
+    let mut disk: Vec<WorktreeDisk> = worktrees
+        .iter()
+        .map(|wt| {
+            Ok(WorktreeDisk {
+                branch: wt.branch.clone(),
+                path: wt.path.clone(),
+                bytes: worktree_disk_usage(repo, &wt.path)?,
+            })
+        })
+        .collect::<anyhow::Result<_>>()?;
+    disk.sort_by(|a, b| b.bytes.cmp(&a.bytes));
+
+    Ok(Report {
+        default_branch,
+        stale,
+        integrated,
+        behind,
+        disk,
+    })
+}
+
+/// Render the report as a text block, one heading per section.
+fn render(report: &Report, stale_after: Duration, behind_threshold: usize) -> String {
+    let base = report.default_branch.as_deref().unwrap_or("default branch");
+    let name = |branch: &Option<String>| branch.clone().unwrap_or_else(|| "HEAD".to_string());
+    let mut lines = Vec::new();
+
+    let hours = stale_after.as_secs() / (60 * 60);
+    let period = match hours {
+        h if h >= 24 => format!("{} days", h / 24),
+        h => format!("{h} hours"),
+    };
+    lines.push(heading(
+        &format!("No commits in {period}"),
+        report.stale.len(),
+    ));
+    for wt in &report.stale {
+        let age = format_relative_time_short(wt.last_commit);
+        lines.push(cformat!(
+            "  <bold>{}</> <bright-black>{age} · {}</>",
+            name(&wt.branch),
+            format_path_for_display(&wt.path)
+        ));
+    }
+
+    lines.push(heading(
+        &format!("Integrated into {base}"),
+        report.integrated.len(),
+    ));
+    for wt in &report.integrated {
+        lines.push(cformat!(
+            "  <bold>{}</> <bright-black>{} {base} · {}</>",
+            wt.branch,
+            wt.reason.description(),
+            format_path_for_display(&wt.path)
+        ));
+    }
+
+    lines.push(heading(
+        &format!("More than {behind_threshold} commits behind {base}"),
+        report.behind.len(),
+    ));
+    for branch in &report.behind {
+        lines.push(cformat!(
+            "  <bold>{}</> <bright-black>{} behind</>",
+            branch.branch,
+            branch.behind
+        ));
+    }
+
+    let total: u64 = report.disk.iter().map(|wt| wt.bytes).sum();
+    lines.push(cformat!("<bold>Disk usage</> {}", format_size(total)));
+    for wt in &report.disk {
+        lines.push(cformat!(
+            "  {:>6}  <bold>{}</> <bright-black>{}</>",
+            format_size(wt.bytes),
+            name(&wt.branch),
+            format_path_for_display(&wt.path)
+        ));
+    }
+
+    lines.join("\n")
+}
+
+fn heading(title: &str, count: usize) -> String {
+    if count == 0 {
+        cformat!("<bold>{title}</> <green>none</>")
+    } else {
+        cformat!("<bold>{title}</> <yellow>{count}</>")
+    }
+}
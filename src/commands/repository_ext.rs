@@ -8,6 +8,7 @@ use color_print::cformat;
 use worktrunk::config::WorktrunkConfig;
 use worktrunk::git::{
     GitError, IntegrationReason, Repository, parse_porcelain_z, parse_untracked_files,
+    path_dir_name,
 };
 use worktrunk::path::format_path_for_display;
 use worktrunk::styling::{format_with_gutter, progress_message, warning_message};
@@ -34,11 +35,16 @@ pub trait RepositoryCliExt {
     ///
     /// The `config` parameter is used to compute the expected worktree path
     /// for path mismatch detection.
+    ///
+    /// Locked worktrees are refused, after all other checks, unless `unlock` is
+    /// set. The lock itself is released by the output handler, just before the
+    /// worktree is removed.
     fn prepare_worktree_removal(
         &self,
         target: RemoveTarget,
         deletion_mode: BranchDeletionMode,
         force_worktree: bool,
+        unlock: bool,
         config: &WorktrunkConfig,
     ) -> anyhow::Result<RemoveResult>;
 
@@ -74,6 +80,7 @@ impl RepositoryCliExt for Repository {
         target: RemoveTarget,
         deletion_mode: BranchDeletionMode,
         force_worktree: bool,
+        unlock: bool,
         config: &WorktrunkConfig,
     ) -> anyhow::Result<RemoveResult> {
         let current_path = self.current_worktree().root()?.to_path_buf();
@@ -83,7 +90,7 @@ impl RepositoryCliExt for Repository {
         let home_worktree_path = self.home_path()?;
 
         // Resolve target to worktree path and branch
-        let (worktree_path, branch_name, is_current) = match target {
+        let (worktree_path, branch_name, is_current, lock_reason) = match target {
             RemoveTarget::Branch(branch) => {
                 match worktrees
                     .iter()
@@ -96,20 +103,20 @@ impl RepositoryCliExt for Repository {
                             }
                             .into());
                         }
-                        if wt.locked.is_some() {
-                            return Err(GitError::WorktreeLocked {
-                                branch: branch.into(),
-                                path: wt.path.clone(),
-                                reason: wt.locked.clone(),
-                            }
-                            .into());
-                        }
                         let is_current = current_path == wt.path;
-                        (wt.path.clone(), Some(branch.to_string()), is_current)
+                        (
+                            wt.path.clone(),
+                            Some(branch.to_string()),
+                            is_current,
+                            wt.locked.clone(),
+                        )
                     }
                     None => {
                         // No worktree found - check if the branch exists locally
                         if self.local_branch_exists(branch)? {
+                            if !deletion_mode.should_keep() {
+                                self.ensure_not_protected(branch, "delete branch", None)?;
+                            }
                             return Ok(RemoveResult::BranchOnly {
                                 branch_name: branch.to_string(),
                                 deletion_mode,
@@ -138,20 +145,7 @@ impl RepositoryCliExt for Repository {
                     .ok_or_else(|| {
                         anyhow::anyhow!("Current worktree not found in worktree list")
                     })?;
-                if wt.locked.is_some() {
-                    // Use branch name if available, otherwise use directory name
-                    let name = wt
-                        .branch
-                        .clone()
-                        .unwrap_or_else(|| wt.dir_name().to_string());
-                    return Err(GitError::WorktreeLocked {
-                        branch: name,
-                        path: wt.path.clone(),
-                        reason: wt.locked.clone(),
-                    }
-                    .into());
-                }
-                (wt.path.clone(), wt.branch.clone(), true)
+                (wt.path.clone(), wt.branch.clone(), true, wt.locked.clone())
             }
         };
 
@@ -161,6 +155,13 @@ impl RepositoryCliExt for Repository {
             return Err(GitError::CannotRemoveMainWorktree.into());
         }
 
+        // Protected branches are never deleted; the worktree can still go with --no-delete-branch
+        if let Some(branch) = branch_name.as_deref()
+            && !deletion_mode.should_keep()
+        {
+            self.ensure_not_protected(branch, "delete branch", Some("--no-delete-branch"))?;
+        }
+
         // Check working tree cleanliness (unless --force, which passes through to git)
         if !force_worktree {
             target_wt.ensure_clean("remove worktree", branch_name.as_deref(), true)?;
@@ -196,6 +197,19 @@ impl RepositoryCliExt for Repository {
             .as_ref()
             .and_then(|branch| get_path_mismatch(self, branch, &worktree_path, config));
 
+        // Checked last, so `wt remove` only offers to unlock a removal that
+        // would otherwise go ahead
+        if lock_reason.is_some() && !unlock {
+            return Err(GitError::WorktreeLocked {
+                branch: branch_name
+                    .clone()
+                    .unwrap_or_else(|| path_dir_name(&worktree_path).to_string()),
+                path: worktree_path,
+                reason: lock_reason,
+            }
+            .into());
+        }
+
         Ok(RemoveResult::RemovedWorktree {
             main_path,
             worktree_path,
@@ -206,6 +220,7 @@ impl RepositoryCliExt for Repository {
             integration_reason,
             force_worktree,
             expected_path,
+            unlock: lock_reason.is_some(),
         })
     }
 
@@ -0,0 +1,197 @@
+//! `wt review`: worktrees for reading someone else's branch.
+//!
+//! A review checkout is an ordinary worktree with a record in
+//! `worktrunk.state.<branch>.review`. Commands that write to the branch
+//! (`wt merge`, `wt step commit`, `wt step squash`) refuse to run there, and
+//! `wt list` shows it with [`REVIEW_MARKER`] unless the branch has its own
+//! marker. Each `wt review` first removes review worktrees whose branch has
+//! since been merged into the default branch.
+
+use std::path::Path;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{GitError, IntegrationReason, Repository, ReviewState};
+use worktrunk::styling::{info_message, success_message, warning_message};
+use worktrunk::utils::get_now;
+
+use super::command_approval::approve_hooks;
+use super::command_executor::CommandContext;
+use super::worktree::{execute_switch, plan_switch};
+use crate::output;
+
+/// Status marker for review worktrees in `wt list`.
+pub(crate) const REVIEW_MARKER: &str = "👀";
+
+/// Handle `wt review <branch>`.
+///
+/// `branch` accepts anything `wt switch` does, including `pr:<number>`.
+pub fn handle_review(branch: &str, read_only: bool, yes: bool, verify: bool) -> anyhow::Result<()> {
+    let repo = Repository::current().context("Failed to create review worktree")?;
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+
+    prune_merged_reviews(&repo)?;
+
+    let plan = plan_switch(&repo, branch, false, None, false, None, &config)?;
+    if plan.branch().is_none() {
+        return Err(GitError::Other {
+            message: cformat!("<bold>{branch}</> is a detached worktree; wt review needs a branch"),
+        }
+        .into());
+    }
+    let approved = if verify && plan.is_create() {
+        let repo_root = repo.repo_path()?;
+        let ctx = CommandContext::new(
+            &repo,
+            &config,
+            plan.branch(),
+            plan.worktree_path(),
+            &repo_root,
+            yes,
+        );
+        approve_hooks(&ctx, &[HookType::PostCreate])?
+    } else {
+        true
+    };
+    if !approved {
+        output::print(info_message(
+            "Commands declined, continuing worktree creation",
+        ))?;
+    }
+
+    let (result, branch_info) =
+        execute_switch(&repo, plan, &config, yes, !verify || !approved, false)?;
+    let branch = branch_info.branch.as_deref().unwrap_or(branch);
+    repo.set_review_state(
+        branch,
+        &ReviewState {
+            read_only,
+            set_at: get_now(),
+        },
+    )?;
+    if read_only {
+        set_tracked_files_writable(&repo, result.path(), false)?;
+    }
+
+    output::handle_switch_output(&result, &branch_info, None)?;
+    output::print(info_message(cformat!(
+        "Checked out <bold>{branch}</> for review; to work on it, run <bright-black>wt review --release {branch}</>"
+    )))?;
+    Ok(())
+}
+
+/// Handle `wt review --release <branch>`: make a review worktree an ordinary one.
+pub fn handle_review_release(branch: &str) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let Some(state) = repo.review_state(branch) else {
+        output::print(info_message(cformat!(
+            "<bold>{branch}</> isn't checked out for review"
+        )))?;
+        return Ok(());
+    };
+
+    if state.read_only
+        && let Some(path) = repo.worktree_for_branch(branch)?
+    {
+        set_tracked_files_writable(&repo, &path, true)?;
+    }
+    repo.clear_review_state(branch);
+    output::print(success_message(cformat!(
+        "Released <bold>{branch}</> from review"
+    )))?;
+    Ok(())
+}
+
+/// Remove review worktrees whose branch is now part of the default branch.
+///
+/// Worktrees with uncommitted changes, and the current worktree, are kept. A
+/// review that can't be removed is reported and left for the next run.
+fn prune_merged_reviews(repo: &Repository) -> anyhow::Result<()> {
+    let branches = repo.review_branches();
+    if branches.is_empty() {
+        return Ok(());
+    }
+    let Some(base) = repo.default_branch() else {
+        return Ok(());
+    };
+    let current = repo.current_worktree().root().ok();
+
+    for branch in branches {
+        if let Err(e) = prune_review(repo, &branch, &base, current.as_deref()) {
+            output::print(warning_message(cformat!(
+                "Failed to remove review worktree for <bold>{branch}</>: {e:#}"
+            )))?;
+        }
+    }
+    Ok(())
+}
+
+/// Remove one review worktree and its branch if the branch has been merged.
+fn prune_review(
+    repo: &Repository,
+    branch: &str,
+    base: &str,
+    current: Option<&Path>,
+) -> anyhow::Result<()> {
+    if !repo.branch_exists(branch)? {
+        repo.clear_review_state(branch);
+        return Ok(());
+    }
+    // Like `wt list`, a branch with no commits of its own isn't "integrated"
+    let (_, reason) = repo.integration_reason(branch, base)?;
+    if reason.is_none_or(|reason| reason == IntegrationReason::SameCommit) {
+        return Ok(());
+    }
+    if let Some(path) = repo.worktree_for_branch(branch)? {
+        if current == dunce::canonicalize(&path).ok().as_deref()
+            || repo.worktree_at(&path).is_dirty()?
+        {
+            return Ok(());
+        }
+        repo.remove_worktree(&path, false)?;
+    }
+    repo.run_command(&["branch", "-D", branch])?;
+    repo.clear_review_state(branch);
+    output::print(success_message(cformat!(
+        "Removed review worktree for <bold>{branch}</> (merged into <bold>{base}</>)"
+    )))?;
+    Ok(())
+}
+
+/// Add or remove write permission on the worktree's tracked files.
+fn set_tracked_files_writable(
+    repo: &Repository,
+    path: &Path,
+    writable: bool,
+) -> anyhow::Result<()> {
+    let files = repo.worktree_at(path).run_command(&["ls-files", "-z"])?;
+    for file in files.split('\0').filter(|file| !file.is_empty()) {
+        let file = path.join(file);
+        // Deleted files and submodules have nothing to change
+        let Ok(metadata) = std::fs::symlink_metadata(&file) else {
+            continue;
+        };
+        if !metadata.is_file() {
+            continue;
+        }
+        let mut permissions = metadata.permissions();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = permissions.mode();
+            permissions.set_mode(if writable {
+                mode | 0o200
+            } else {
+                mode & !0o222
+            });
+        }
+        #[cfg(not(unix))]
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(!writable);
+        std::fs::set_permissions(&file, permissions)
+            .with_context(|| format!("Failed to change permissions of {}", file.display()))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,99 @@
+//! `wt run` — run a named command profile from the project config.
+//!
+//! Profiles are declared as `[profiles.<name>] command = "..."` in
+//! `.config/wt.toml`. They take the same template variables as hooks and need
+//! the same approval as any other project command, keyed as `run:<name>`.
+
+use std::collections::HashMap;
+
+use worktrunk::config::{WorktrunkConfig, expand_template};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{format_bash_with_gutter, info_message, progress_message};
+
+use super::command_approval::approve_command_batch;
+use super::command_executor::{CommandContext, build_hook_context};
+use super::context::CommandEnv;
+use super::format_command_label;
+use super::project_config::collect_profile_commands;
+use crate::output::execute_command_in_worktree;
+
+/// Run profile `name` in the current worktree.
+pub fn handle_run(name: &str, yes: bool, custom_vars: &[(String, String)]) -> anyhow::Result<()> {
+    let env = CommandEnv::for_action_branchless()?;
+    let ctx = env.context(yes);
+
+    let Some(template) = approve_profile(ctx.repo, ctx.config, name, yes)? else {
+        crate::output::print(info_message("Commands declined"))?;
+        return Ok(());
+    };
+
+    let extra_vars: Vec<(&str, &str)> = custom_vars
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    run_profile(&ctx, name, &template, &extra_vars)
+}
+
+/// Expand an approved profile command and run it in the context's worktree.
+///
+/// Also used for `[profiles]` steps of `wt merge`.
+pub(crate) fn run_profile(
+    ctx: &CommandContext<'_>,
+    name: &str,
+    template: &str,
+    extra_vars: &[(&str, &str)],
+) -> anyhow::Result<()> {
+    let context = build_hook_context(ctx, extra_vars);
+    let vars: HashMap<&str, &str> = context
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+    let command = expand_template(template, &vars, true, ctx.repo)
+        .map_err(|e| anyhow::anyhow!("Failed to expand command template '{template}': {e}"))?;
+    let context_json = serde_json::to_string(&context)
+        .expect("HashMap<String, String> serialization should never fail");
+
+    crate::output::print(progress_message(format!(
+        "{}:",
+        format_command_label("profile", Some(name))
+    )))?;
+    crate::output::print(format_bash_with_gutter(&command))?;
+    execute_command_in_worktree(
+        ctx.worktree_path,
+        &command,
+        Some(&context_json),
+        &ctx.hook_env(),
+    )
+}
+
+/// Look up profile `name` and approve its command.
+///
+/// Returns the command template, or `None` if the user declined. Errors if the
+/// project doesn't define the profile.
+pub(crate) fn approve_profile(
+    repo: &Repository,
+    config: &WorktrunkConfig,
+    name: &str,
+    yes: bool,
+) -> anyhow::Result<Option<String>> {
+    let project_config = repo.load_project_config()?.unwrap_or_default();
+    let Some(profile) = project_config.profile(name) else {
+        let available: Vec<&str> = project_config.profiles.keys().map(String::as_str).collect();
+        let message = if available.is_empty() {
+            format!("No profile named {name}; .config/wt.toml defines no [profiles]")
+        } else {
+            format!(
+                "No profile named {name}; available: {}",
+                available.join(", ")
+            )
+        };
+        return Err(GitError::Other { message }.into());
+    };
+
+    let commands = collect_profile_commands(&project_config, Some(&[name]));
+    let project_id = repo.project_identifier()?;
+    if !approve_command_batch(&commands, &project_id, config, yes, false)? {
+        return Ok(None);
+    }
+    Ok(Some(profile.command.clone()))
+}
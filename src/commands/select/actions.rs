@@ -0,0 +1,161 @@
+//! Actions applied to the entries picked in `wt select`.
+//!
+//! Enter switches to the highlighted (or first marked) entry. Action keys
+//! record their action in a per-process state file, then accept the
+//! selection — the same mechanism the preview tabs use to talk to skim.
+
+use std::fs;
+use std::path::PathBuf;
+
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::Repository;
+use worktrunk::styling::{info_message, success_message, warning_message};
+
+use super::super::for_each::report_failures;
+use super::super::list::model::ListItem;
+use super::super::worktree::{handle_remove, handle_remove_current};
+use crate::output::handle_remove_output;
+use crate::output::prompt::Confirm;
+
+/// What to do with the selected entries when skim exits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SelectAction {
+    /// Switch to the first selected entry (Enter)
+    Switch,
+    /// Remove every marked entry
+    Remove,
+    /// Rebase every marked worktree onto the default branch
+    Rebase,
+}
+
+impl SelectAction {
+    /// Key, state-file word, and action for the non-default actions.
+    pub(super) const BINDINGS: &[(&str, &str, SelectAction)] = &[
+        ("alt-x", "remove", SelectAction::Remove),
+        ("alt-r", "rebase", SelectAction::Rebase),
+    ];
+
+    fn from_word(word: &str) -> Self {
+        Self::BINDINGS
+            .iter()
+            .find(|(_, w, _)| *w == word)
+            .map_or(Self::Switch, |(_, _, action)| *action)
+    }
+}
+
+/// RAII wrapper for the action state file; empty means Enter (switch).
+pub(super) struct ActionState {
+    pub(super) path: PathBuf,
+}
+
+impl ActionState {
+    pub(super) fn new() -> Self {
+        // Per-process, like the preview state file
+        let path = std::env::temp_dir().join(format!("wt-select-action-{}", std::process::id()));
+        let _ = fs::remove_file(&path);
+        Self { path }
+    }
+
+    /// The action chosen when skim exited.
+    pub(super) fn read(&self) -> SelectAction {
+        fs::read_to_string(&self.path)
+            .map(|s| SelectAction::from_word(s.trim()))
+            .unwrap_or(SelectAction::Switch)
+    }
+}
+
+impl Drop for ActionState {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Remove each selected entry, continuing past failures.
+///
+/// Asks once first: a stray Alt-x shouldn't delete anything. Runs like
+/// `wt remove --no-verify`: hooks are skipped, as for switches from the
+/// selector. The current worktree goes last so the others are removed before
+/// the shell leaves it.
+pub(super) fn remove_all(items: &[&ListItem], config: &WorktrunkConfig) -> anyhow::Result<()> {
+    let count = items.len();
+    let noun = if count == 1 { "worktree" } else { "worktrees" };
+    if !Confirm::new(format!("Remove {count} {noun}?"), false).ask(false)? {
+        return crate::output::print(info_message("Removal cancelled"));
+    }
+
+    let (current, others): (Vec<_>, Vec<_>) = items
+        .iter()
+        .partition(|item| item.worktree_data().is_some_and(|data| data.is_current));
+
+    let mut failed = Vec::new();
+    for item in others.iter().chain(&current) {
+        let branch = item.branch_name();
+        let result = if item.worktree_data().is_some_and(|data| data.is_current) {
+            handle_remove_current(false, false, false, false, false, config)
+        } else {
+            handle_remove(branch, false, false, false, false, false, config)
+        };
+        if let Err(e) = result.and_then(|result| handle_remove_output(&result, true, false, false))
+        {
+            crate::output::print(e.to_string())?;
+            failed.push(branch.to_string());
+        }
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        report_failures(&failed, count)
+    }
+}
+
+/// Rebase each selected worktree onto the default branch.
+///
+/// Worktrees with uncommitted changes are skipped, and a rebase that hits
+/// conflicts is aborted, leaving that branch as it was.
+pub(super) fn rebase_all(repo: &Repository, items: &[&ListItem]) -> anyhow::Result<()> {
+    let target = repo.require_target_ref(None)?;
+
+    for item in items {
+        let branch = item.branch_name();
+        let Some(data) = item.worktree_data() else {
+            crate::output::print(info_message(cformat!(
+                "Skipping <bold>{branch}</>: no worktree"
+            )))?;
+            continue;
+        };
+        if branch == target {
+            continue;
+        }
+        let wt = repo.worktree_at(&data.path);
+        if wt.is_dirty()? {
+            crate::output::print(warning_message(cformat!(
+                "Skipping <bold>{branch}</>: uncommitted changes"
+            )))?;
+            continue;
+        }
+        if wt.run_command(&["rebase", &target]).is_err() {
+            let _ = wt.run_command(&["rebase", "--abort"]);
+            crate::output::print(warning_message(cformat!(
+                "Rebasing <bold>{branch}</> onto <bold>{target}</> conflicts; left unchanged"
+            )))?;
+            continue;
+        }
+        crate::output::print(success_message(cformat!(
+            "Rebased <bold>{branch}</> onto <bold>{target}</>"
+        )))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_action_from_word() {
+        assert_eq!(SelectAction::from_word("remove"), SelectAction::Remove);
+        assert_eq!(SelectAction::from_word("rebase"), SelectAction::Rebase);
+        assert_eq!(SelectAction::from_word(""), SelectAction::Switch);
+    }
+}
@@ -16,6 +16,7 @@ use super::log_formatter::{
 };
 use super::pager::{get_diff_pager, run_git_diff_with_pager};
 use super::preview::{PreviewMode, PreviewStateData};
+use super::preview_cache::{PreviewCache, PreviewKey};
 
 /// Header item for column names (non-selectable)
 pub(super) struct HeaderSkimItem {
@@ -38,11 +39,13 @@ impl SkimItem for HeaderSkimItem {
 }
 
 /// Wrapper to implement SkimItem for ListItem
+#[derive(Clone)]
 pub(super) struct WorktreeSkimItem {
     pub display_text: String,
     pub display_text_with_ansi: String,
     pub branch_name: String,
     pub item: Arc<ListItem>,
+    pub preview_cache: Arc<PreviewCache>,
 }
 
 impl SkimItem for WorktreeSkimItem {
@@ -63,7 +66,22 @@ impl SkimItem for WorktreeSkimItem {
 
         // Build preview: tabs header + content
         let mut result = Self::render_preview_tabs(mode);
-        result.push_str(&self.preview_for_mode(mode, context.width, context.height));
+        let (width, height) = (context.width, context.height);
+        let key = PreviewKey {
+            branch: self.branch_name.clone(),
+            head: self.item.head().to_string(),
+            mode,
+            width,
+            height,
+        };
+        let item = self.clone();
+        match self
+            .preview_cache
+            .get_or_compute(key, move || item.preview_for_mode(mode, width, height))
+        {
+            Some(content) => result.push_str(&content),
+            None => result.push_str(&cformat!("<dim>Loading…</>\n")),
+        }
 
         ItemPreview::AnsiText(result)
     }
@@ -94,10 +112,12 @@ impl WorktreeSkimItem {
         let controls = cformat!(
             "<dim,yellow>Enter: switch | Esc: cancel | ctrl-u/d: scroll | alt-p: toggle</>"
         );
+        let marking =
+            cformat!("<dim,yellow>Tab: mark | alt-x: remove marked | alt-r: rebase marked</>");
 
         format!(
-            "{} | {} | {} | {}\n{}\n\n",
-            tab1, tab2, tab3, tab4, controls
+            "{} | {} | {} | {}\n{}\n{}\n\n",
+            tab1, tab2, tab3, tab4, controls, marking
         )
     }
 
@@ -265,8 +285,8 @@ impl WorktreeSkimItem {
         // Minimum preview width to show timestamps (adds ~7 chars: space + 4-char time + space)
         // Note: preview is typically 50% of terminal width, so 50 = 100-col terminal
         const TIMESTAMP_WIDTH_THRESHOLD: usize = 50;
-        // Tab header takes 3 lines (tabs + controls + blank)
-        const HEADER_LINES: usize = 3;
+        // Tab header takes 4 lines (tabs + controls + marking + blank)
+        const HEADER_LINES: usize = 4;
 
         let mut output = String::new();
         let show_timestamps = width >= TIMESTAMP_WIDTH_THRESHOLD;
@@ -386,7 +406,8 @@ mod tests {
         assert!(output.contains("3: main…±"));
         assert!(output.contains("4: remote⇅"));
         assert!(output.contains("Enter: switch"));
-        // Verify structure: tabs on first line, controls on second
+        assert!(output.contains("alt-x: remove marked"));
+        // Verify structure: tabs on first line, controls below
         assert!(output.contains(" | "));
         assert!(output.ends_with("\n\n"));
     }
@@ -2,10 +2,12 @@
 //!
 //! A skim-based TUI for selecting and switching between worktrees.
 
+mod actions;
 mod items;
 mod log_formatter;
 mod pager;
 mod preview;
+mod preview_cache;
 
 use std::sync::Arc;
 
@@ -15,11 +17,14 @@ use worktrunk::config::WorktrunkConfig;
 use worktrunk::git::Repository;
 
 use super::list::collect;
+use super::list::model::ListItem;
 use super::worktree::{execute_switch, plan_switch};
 use crate::output::handle_switch_output;
 
+use actions::{ActionState, SelectAction};
 use items::{HeaderSkimItem, WorktreeSkimItem};
 use preview::{PreviewLayout, PreviewState};
+use preview_cache::PreviewCache;
 
 pub fn handle_select(
     show_branches: bool,
@@ -35,8 +40,9 @@ pub fn handle_select(
 
     let repo = Repository::current()?;
 
-    // Initialize preview mode state file (auto-cleanup on drop)
+    // Initialize preview mode and action state files (auto-cleanup on drop)
     let state = PreviewState::new();
+    let action_state = ActionState::new();
 
     // Gather list data using simplified collection (buffered mode)
     // Skip expensive operations not needed for select UI
@@ -44,6 +50,9 @@ pub fn handle_select(
         collect::TaskKind::BranchDiff,
         collect::TaskKind::CiStatus,
         collect::TaskKind::MergeTreeConflicts,
+        collect::TaskKind::DiskUsage,
+        collect::TaskKind::BranchDescription,
+        collect::TaskKind::Owner,
     ]
     .into_iter()
     .collect();
@@ -54,6 +63,12 @@ pub fn handle_select(
     // Operations that timeout fail silently (data not shown), but TUI stays responsive.
     let command_timeout = Some(std::time::Duration::from_millis(500));
 
+    // Graph column not shown in select; times follow the `[list]` settings
+    let display = super::list::layout::DisplayOptions {
+        graph: false,
+        time: crate::display::TimeFormat::from_config(config.list.as_ref(), false),
+    };
+
     let Some(list_data) = collect::collect(
         &repo,
         show_branches,
@@ -63,7 +78,13 @@ pub fn handle_select(
         false, // render_table (select renders its own UI)
         config,
         command_timeout,
-        true, // skip_expensive_for_stale (faster for repos with many stale branches)
+        true,  // skip_expensive_for_stale (faster for repos with many stale branches)
+        false, // show_index (select has its own navigation)
+        false, // show_summary (no table is rendered)
+        false, // no_truncate (select lays out its own rows)
+        false, // record_layout
+        false, // mine
+        &display,
     )?
     else {
         return Ok(());
@@ -84,6 +105,17 @@ pub fn handle_select(
         skim_list_width,
         &list_data.main_worktree_path,
         None, // URL column not shown in select
+        repo.branch_display_prefix().as_deref(),
+        &super::list::layout::WidthLimits {
+            no_truncate: false,
+            max_widths: config
+                .list
+                .as_ref()
+                .and_then(|l| l.max_width)
+                .unwrap_or_default(),
+            ..Default::default()
+        },
+        &display,
     );
 
     // Render header using layout system (need both plain and styled text for skim)
@@ -92,6 +124,9 @@ pub fn handle_select(
     let header_plain_text = header_line.plain_text();
 
     // Convert to skim items using the layout system for rendering
+    let preview_cache = Arc::new(PreviewCache::default());
+    let mut list_items: std::collections::HashMap<String, Arc<ListItem>> =
+        std::collections::HashMap::new();
     let mut items: Vec<Arc<dyn SkimItem>> = list_data
         .items
         .into_iter()
@@ -103,11 +138,15 @@ pub fn handle_select(
             let display_text_with_ansi = rendered_line.render();
             let display_text = rendered_line.plain_text();
 
+            let item = Arc::new(item);
+            list_items.insert(branch_name.clone(), Arc::clone(&item));
+
             Arc::new(WorktreeSkimItem {
                 display_text,
                 display_text_with_ansi,
                 branch_name,
-                item: Arc::new(item),
+                item,
+                preview_cache: Arc::clone(&preview_cache),
             }) as Arc<dyn SkimItem>
         })
         .collect();
@@ -126,6 +165,10 @@ pub fn handle_select(
     let state_path_str = shlex::try_quote(&state_path_display)
         .map(|s| s.into_owned())
         .unwrap_or(state_path_display);
+    let action_path_display = action_state.path.display().to_string();
+    let action_path_str = shlex::try_quote(&action_path_display)
+        .map(|s| s.into_owned())
+        .unwrap_or(action_path_display);
 
     // Calculate half-page scroll: skim uses 90% of terminal height, half of that = 45%
     let half_page = terminal_size::terminal_size()
@@ -142,7 +185,7 @@ pub fn handle_select(
         .height("90%".to_string())
         .layout("reverse".to_string())
         .header_lines(1) // Make first line (header) non-selectable
-        .multi(false)
+        .multi(true) // Tab marks entries for the remove/rebase actions
         .no_info(true) // Hide info line (matched/total counter)
         .preview(Some("".to_string())) // Enable preview (empty string means use SkimItem::preview())
         .preview_window(preview_window_spec)
@@ -163,31 +206,39 @@ pub fn handle_select(
             "fg:-1,bg:-1,header:-1,matched:108,current:237,current_bg:251,current_match:108"
                 .to_string(),
         ))
-        .bind(vec![
-            // Mode switching (1/2/3/4 keys change preview content)
-            format!(
-                "1:execute-silent(echo 1 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "2:execute-silent(echo 2 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "3:execute-silent(echo 3 > {0})+refresh-preview",
-                state_path_str
-            ),
-            format!(
-                "4:execute-silent(echo 4 > {0})+refresh-preview",
-                state_path_str
-            ),
-            // Preview toggle (alt-p shows/hides preview)
-            // Note: skim doesn't support change-preview-window like fzf, only toggle
-            "alt-p:toggle-preview".to_string(),
-            // Preview scrolling (half-page based on terminal height)
-            format!("ctrl-u:preview-up({half_page})"),
-            format!("ctrl-d:preview-down({half_page})"),
-        ])
+        .bind(
+            [
+                // Mode switching (1/2/3/4 keys change preview content)
+                format!(
+                    "1:execute-silent(echo 1 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "2:execute-silent(echo 2 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "3:execute-silent(echo 3 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                format!(
+                    "4:execute-silent(echo 4 > {0})+refresh-preview",
+                    state_path_str
+                ),
+                // Preview toggle (alt-p shows/hides preview)
+                // Note: skim doesn't support change-preview-window like fzf, only toggle
+                "alt-p:toggle-preview".to_string(),
+                // Preview scrolling (half-page based on terminal height)
+                format!("ctrl-u:preview-up({half_page})"),
+                format!("ctrl-d:preview-down({half_page})"),
+            ]
+            .into_iter()
+            // Actions on marked entries: record the action, then accept
+            .chain(SelectAction::BINDINGS.iter().map(|(key, word, _)| {
+                format!("{key}:execute-silent(echo {word} > {action_path_str})+accept")
+            }))
+            .collect(),
+        )
         // Legend/controls moved to preview window tabs (render_preview_tabs)
         .no_clear(true) // Prevent skim from clearing screen, we'll do it manually
         .build()
@@ -205,33 +256,54 @@ pub fn handle_select(
     let output = Skim::run_with(&options, Some(rx));
 
     // Handle selection
-    if let Some(out) = output
-        && !out.is_abort
-        && let Some(selected) = out.selected_items.first()
-    {
-        // Get branch name or worktree path from selected item
-        // (output() returns the worktree path for existing worktrees, branch name otherwise)
-        let identifier = selected.output().to_string();
-
-        // Load config
-        let config = WorktrunkConfig::load().context("Failed to load config")?;
-        let repo = Repository::current().context("Failed to switch worktree")?;
-
-        // Switch to the selected worktree (no creation, no approval prompts)
-        let plan = plan_switch(&repo, &identifier, false, None, false, &config)?;
-        let (result, branch_info) = execute_switch(&repo, plan, &config, false, true)?;
-
-        // Clear the terminal screen after skim exits to prevent artifacts
-        // Use stderr for terminal control - stdout is reserved for data output
-        use crossterm::{execute, terminal};
-        use std::io::stderr;
-        execute!(stderr(), terminal::Clear(terminal::ClearType::All))?;
-        execute!(stderr(), crossterm::cursor::MoveTo(0, 0))?;
-
-        // Show success message; emit cd directive if shell integration is active
-        handle_switch_output(&result, &branch_info, None)?;
+    let Some(out) = output.filter(|out| !out.is_abort) else {
+        return Ok(());
+    };
+    let config = WorktrunkConfig::load().context("Failed to load config")?;
+    let repo = Repository::current().context("Failed to switch worktree")?;
+
+    // Marked entries (or the highlighted one if none are marked)
+    let selected: Vec<&ListItem> = out
+        .selected_items
+        .iter()
+        .filter_map(|selected| list_items.get(selected.output().as_ref()))
+        .map(Arc::as_ref)
+        .collect();
+
+    match action_state.read() {
+        SelectAction::Switch => {
+            let Some(item) = selected.first() else {
+                return Ok(());
+            };
+
+            // Switch to the selected worktree (no creation, no approval prompts)
+            let plan = plan_switch(&repo, item.branch_name(), false, None, false, None, &config)?;
+            let (result, branch_info) = execute_switch(&repo, plan, &config, false, true, false)?;
+
+            clear_screen()?;
+
+            // Show success message; emit cd directive if shell integration is active
+            handle_switch_output(&result, &branch_info, None)
+        }
+        SelectAction::Remove => {
+            clear_screen()?;
+            actions::remove_all(&selected, &config)
+        }
+        SelectAction::Rebase => {
+            clear_screen()?;
+            actions::rebase_all(&repo, &selected)
+        }
     }
+}
 
+/// Clear the terminal screen after skim exits to prevent artifacts.
+///
+/// Uses stderr for terminal control - stdout is reserved for data output.
+fn clear_screen() -> anyhow::Result<()> {
+    use crossterm::{execute, terminal};
+    use std::io::stderr;
+    execute!(stderr(), terminal::Clear(terminal::ClearType::All))?;
+    execute!(stderr(), crossterm::cursor::MoveTo(0, 0))?;
     Ok(())
 }
 
@@ -18,7 +18,7 @@ use std::path::PathBuf;
 /// - Tab 2 shows commits (related to "main↕" counts)
 /// - Tab 3 corresponds to "main…± (--full)" column
 /// - Tab 4 corresponds to "Remote⇅" column
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub(super) enum PreviewMode {
     WorkingTree = 1,
     Log = 2,
@@ -0,0 +1,132 @@
+//! Background computation and caching of preview content.
+//!
+//! Skim calls `SkimItem::preview()` on every cursor move, and a branch diff on
+//! a large branch takes long enough to stall navigation. Previews are instead
+//! computed on a background thread and cached by branch HEAD, mode, and
+//! preview size, so revisiting a branch is instant. A preview that isn't ready
+//! within a short budget shows a loading placeholder; moving back to the item
+//! picks up the finished result.
+
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::Duration;
+
+use dashmap::DashMap;
+
+use super::preview::PreviewMode;
+
+/// How long `preview()` waits for a computation before showing the placeholder.
+///
+/// Long enough that fast previews (clean worktrees, short logs) never flash
+/// the placeholder, short enough that holding an arrow key stays fluid.
+const WAIT_BUDGET: Duration = Duration::from_millis(80);
+
+/// Identifies a rendered preview. HEAD pins the content; size changes the layout.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(super) struct PreviewKey {
+    pub branch: String,
+    pub head: String,
+    pub mode: PreviewMode,
+    pub width: usize,
+    pub height: usize,
+}
+
+/// A preview that is either still computing (`None`) or finished.
+#[derive(Default)]
+struct Slot {
+    content: Mutex<Option<String>>,
+    ready: Condvar,
+}
+
+/// Previews shared by all items in one `wt select` session.
+#[derive(Default)]
+pub(super) struct PreviewCache {
+    slots: DashMap<PreviewKey, Arc<Slot>>,
+}
+
+impl PreviewCache {
+    /// Return the preview for `key`, computing it in the background on first request.
+    ///
+    /// Returns `None` if the computation hasn't finished within [`WAIT_BUDGET`].
+    pub(super) fn get_or_compute(
+        &self,
+        key: PreviewKey,
+        compute: impl FnOnce() -> String + Send + 'static,
+    ) -> Option<String> {
+        let mut spawn = false;
+        let slot = self
+            .slots
+            .entry(key)
+            .or_insert_with(|| {
+                spawn = true;
+                Arc::default()
+            })
+            .clone();
+
+        if spawn {
+            let slot = Arc::clone(&slot);
+            std::thread::spawn(move || {
+                let content = compute();
+                *slot.content.lock().unwrap() = Some(content);
+                slot.ready.notify_all();
+            });
+        }
+
+        let content = slot.content.lock().unwrap();
+        let (content, _) = slot
+            .ready
+            .wait_timeout_while(content, WAIT_BUDGET, |content| content.is_none())
+            .unwrap();
+        content.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(head: &str) -> PreviewKey {
+        PreviewKey {
+            branch: "feature".into(),
+            head: head.into(),
+            mode: PreviewMode::Log,
+            width: 80,
+            height: 24,
+        }
+    }
+
+    #[test]
+    fn test_preview_cache_reuses_result() {
+        let cache = PreviewCache::default();
+        assert_eq!(
+            cache.get_or_compute(key("abc"), || "first".into()),
+            Some("first".into())
+        );
+        // Cached: the second computation never runs
+        assert_eq!(
+            cache.get_or_compute(key("abc"), || "second".into()),
+            Some("first".into())
+        );
+        // A new HEAD is a new preview
+        assert_eq!(
+            cache.get_or_compute(key("def"), || "second".into()),
+            Some("second".into())
+        );
+    }
+
+    #[test]
+    fn test_preview_cache_slow_preview() {
+        let cache = PreviewCache::default();
+        let (release, wait) = std::sync::mpsc::channel::<()>();
+        let slow = move || {
+            wait.recv().unwrap();
+            "done".to_string()
+        };
+        assert_eq!(cache.get_or_compute(key("abc"), slow), None);
+
+        release.send(()).unwrap();
+        assert_eq!(
+            cache.get_or_compute(key("abc"), || unreachable!()),
+            Some("done".into())
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! `wt serve --stdio`: a JSON-RPC 2.0 server for editor integrations.
+//!
+//! Requests and responses are newline-delimited JSON on stdin/stdout, one
+//! message per line. Methods are thin wrappers over [`worktrunk::api`], so
+//! nothing runs hooks or prompts. After `subscribe`, a background thread polls
+//! the worktree list and pushes a `changed` notification whenever it differs
+//! from the last one sent. The server exits when stdin closes.
+
+use std::io::{BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use anyhow::Context;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use worktrunk::api::{ListItem, ListOptions, RemoveOptions, Worktrunk};
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::Repository;
+use worktrunk::styling::plain_error_message;
+
+/// How often subscribers' worktree lists are recomputed.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+const PARSE_ERROR: i64 = -32700;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const SERVER_ERROR: i64 = -32000;
+
+#[derive(Deserialize)]
+struct Request {
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+struct ListParams {
+    branches: bool,
+}
+
+#[derive(Deserialize)]
+struct SwitchParams {
+    branch: String,
+    #[serde(default)]
+    create: bool,
+}
+
+#[derive(Deserialize)]
+struct RemoveParams {
+    branch: String,
+    #[serde(default)]
+    force: bool,
+    #[serde(default = "default_true")]
+    delete_branch: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// A JSON-RPC error object.
+#[derive(Debug)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<anyhow::Error> for RpcError {
+    fn from(e: anyhow::Error) -> Self {
+        // Editors show `message` as is: no colors, symbols or CLI hints
+        Self::new(SERVER_ERROR, plain_error_message(&e))
+    }
+}
+
+pub fn handle_serve(config: WorktrunkConfig) -> anyhow::Result<()> {
+    let root = Repository::current()?.current_worktree().root()?;
+    let wt = Worktrunk::with_config(&root, config)?;
+    let subscribed = AtomicBool::new(false);
+
+    for line in std::io::stdin().lock().lines() {
+        let line = line.context("Failed to read request")?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        if let Some(response) = handle_line(&wt, &root, &subscribed, &line) {
+            match send(&response) {
+                Ok(()) => {}
+                // The client closed its end, so there's no one left to answer
+                Err(e) if e.kind() == std::io::ErrorKind::BrokenPipe => return Ok(()),
+                Err(e) => return Err(anyhow::Error::from(e).context("Failed to write response")),
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Handle one request line, returning the response (none for notifications).
+fn handle_line(wt: &Worktrunk, root: &Path, subscribed: &AtomicBool, line: &str) -> Option<Value> {
+    let request: Request = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => {
+            return Some(error_response(
+                Value::Null,
+                RpcError::new(PARSE_ERROR, e.to_string()),
+            ));
+        }
+    };
+    let id = request.id?;
+    Some(
+        match dispatch(wt, root, subscribed, &request.method, request.params) {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(e) => error_response(id, e),
+        },
+    )
+}
+
+fn dispatch(
+    wt: &Worktrunk,
+    root: &Path,
+    subscribed: &AtomicBool,
+    method: &str,
+    params: Value,
+) -> Result<Value, RpcError> {
+    match method {
+        "list" => {
+            let params: ListParams = parse_params(params)?;
+            Ok(json!(wt.list(ListOptions {
+                branches: params.branches
+            })?))
+        }
+        "switch" => {
+            let params: SwitchParams = parse_params(params)?;
+            let path = wt.switch(&params.branch, params.create)?;
+            Ok(json!({ "path": path }))
+        }
+        "remove" => {
+            let params: RemoveParams = parse_params(params)?;
+            wt.remove(
+                &params.branch,
+                RemoveOptions {
+                    force: params.force,
+                    delete_branch: params.delete_branch,
+                },
+            )?;
+            Ok(Value::Null)
+        }
+        "subscribe" => {
+            let params: ListParams = parse_params(params)?;
+            let options = ListOptions {
+                branches: params.branches,
+            };
+            let items = wt.list(options.clone())?;
+            if !subscribed.swap(true, Ordering::SeqCst) {
+                let root = root.to_path_buf();
+                let last = items.clone();
+                std::thread::spawn(move || watch(root, options, last));
+            }
+            Ok(json!(items))
+        }
+        _ => Err(RpcError::new(
+            METHOD_NOT_FOUND,
+            format!("Unknown method: {method}"),
+        )),
+    }
+}
+
+/// Poll the worktree list, sending `changed` notifications until stdout closes.
+fn watch(root: PathBuf, options: ListOptions, mut last: Vec<ListItem>) {
+    // Listing doesn't read the user config
+    let Ok(wt) = Worktrunk::with_config(&root, WorktrunkConfig::default()) else {
+        return;
+    };
+    loop {
+        std::thread::sleep(POLL_INTERVAL);
+        let items = match wt.list(options.clone()) {
+            Ok(items) => items,
+            Err(e) => {
+                log::debug!("wt serve poll failed: {e:#}");
+                continue;
+            }
+        };
+        if items != last {
+            let notification = json!({
+                "jsonrpc": "2.0",
+                "method": "changed",
+                "params": { "items": items },
+            });
+            if send(&notification).is_err() {
+                return;
+            }
+            last = items;
+        }
+    }
+}
+
+fn parse_params<T: serde::de::DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    // Omitted params mean "all defaults"
+    let params = if params.is_null() { json!({}) } else { params };
+    serde_json::from_value(params).map_err(|e| RpcError::new(INVALID_PARAMS, e.to_string()))
+}
+
+fn error_response(id: Value, error: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": error.code, "message": error.message },
+    })
+}
+
+/// Write a message as one line of stdout.
+///
+/// Holds the stdout lock for the whole line, so a notification from the poll
+/// thread never lands in the middle of a response.
+fn send(message: &Value) -> std::io::Result<()> {
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "{message}")?;
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_params() {
+        let params: ListParams = parse_params(Value::Null).unwrap();
+        assert!(!params.branches);
+
+        let params: RemoveParams = parse_params(json!({ "branch": "feature" })).unwrap();
+        assert!(params.delete_branch);
+
+        let err = parse_params::<SwitchParams>(json!({})).unwrap_err();
+        assert_eq!(err.code, INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_error_response() {
+        let response = error_response(
+            json!(1),
+            RpcError::new(METHOD_NOT_FOUND, "Unknown method: x"),
+        );
+        assert_eq!(
+            response,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "error": { "code": -32601, "message": "Unknown method: x" },
+            })
+        );
+    }
+
+    #[test]
+    fn test_error_message_is_plain() {
+        let err = RpcError::from(anyhow::Error::from(
+            worktrunk::git::GitError::UserEmailNotSet,
+        ));
+        assert_eq!(err.code, SERVER_ERROR);
+        assert_eq!(err.message, "wt list --mine needs user.email in git config");
+    }
+}
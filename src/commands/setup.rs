@@ -0,0 +1,288 @@
+//! Guided first-run setup.
+//!
+//! Walks through the pieces `wt config shell install` and `wt config create`
+//! cover separately: shell integration for the detected shell, the worktree
+//! path template, and an LLM command for commit messages. Answers are written
+//! into the user config; a new file starts from the commented example so each
+//! choice sits beside its documentation.
+
+use std::io::{self, IsTerminal};
+use std::path::Path;
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::{WorktrunkConfig, get_config_path};
+use worktrunk::git::Repository;
+use worktrunk::path::format_path_for_display;
+use worktrunk::shell::current_shell;
+use worktrunk::shell_exec::Cmd;
+use worktrunk::styling::{eprintln, hint_message, info_message, success_message};
+
+use super::config::user_config_template;
+use super::configure_shell::{
+    ConfigAction, handle_configure_shell, prompt_for_install, scan_shell_configs,
+};
+use crate::output;
+use crate::output::prompt::{Input, Response};
+use crate::output::shell_integration::shell_integration_hint;
+
+/// Worktree path templates offered by the wizard, with the layout each creates.
+const WORKTREE_PATH_CHOICES: &[(&str, &str)] = &[
+    (
+        "../{{ repo }}.{{ branch | sanitize }}",
+        "siblings: ~/code/myproject.feature-auth",
+    ),
+    (
+        ".worktrees/{{ branch | sanitize }}",
+        "inside the repo: ~/code/myproject/.worktrees/feature-auth",
+    ),
+    (
+        "../{{ branch | sanitize }}",
+        "nested: ~/code/myproject/feature-auth (pairs with wt clone)",
+    ),
+];
+
+/// LLM tools the wizard can configure, with the args from the config docs.
+const LLM_CHOICES: &[(&str, &[&str])] = &[
+    ("llm", &["-m", "claude-haiku-4.5"]),
+    ("aichat", &["-m", "claude:claude-haiku-4.5"]),
+];
+
+/// Commit-generation settings chosen in the wizard.
+struct LlmChoice {
+    command: &'static str,
+    args: &'static [&'static str],
+}
+
+/// Handle `wt setup`: interactive shell, worktree path, and LLM configuration.
+pub fn handle_setup(binary_name: &str) -> anyhow::Result<()> {
+    if !io::stdin().is_terminal() || !io::stderr().is_terminal() {
+        anyhow::bail!(
+            "wt setup is interactive; for scripted setup use wt config create and wt config shell install"
+        );
+    }
+
+    let config_path = get_config_path().context(
+        "Cannot determine config directory. Set $HOME or $XDG_CONFIG_HOME environment variable",
+    )?;
+
+    setup_shell(binary_name)?;
+    let worktree_path = prompt_worktree_path()?;
+    // `[llm] enabled = false` opts out of LLM tools; don't offer one
+    let llm_disabled = WorktrunkConfig::load().is_ok_and(|c| !c.llm_enabled());
+    let llm = if llm_disabled { None } else { prompt_llm()? };
+
+    write_user_config(&config_path, worktree_path, llm.as_ref())?;
+
+    output::print(success_message(cformat!(
+        "Wrote <bold>{}</>",
+        format_path_for_display(&config_path)
+    )))?;
+    output::print(hint_message(cformat!(
+        "To review, run <bright-black>wt config show</>"
+    )))?;
+    Ok(())
+}
+
+/// Point first-time users at `wt setup`.
+///
+/// Shown once per repository, only on a terminal, and only while no user
+/// config file exists. The terminal and config file checks come first, so
+/// the usual case costs no git call.
+pub fn offer_setup() -> anyhow::Result<()> {
+    if !io::stdin().is_terminal() || !io::stderr().is_terminal() {
+        return Ok(());
+    }
+    if get_config_path().is_none_or(|path| path.exists()) {
+        return Ok(());
+    }
+    let Ok(repo) = Repository::current() else {
+        return Ok(());
+    };
+    if repo.has_shown_hint("setup") {
+        return Ok(());
+    }
+    output::print(hint_message(cformat!(
+        "New to worktrunk? To configure shell integration and worktree paths, run <bright-black>wt setup</>"
+    )))?;
+    let _ = repo.mark_hint_shown("setup");
+    Ok(())
+}
+
+/// Offer shell integration for the shell in `$SHELL`.
+fn setup_shell(binary_name: &str) -> anyhow::Result<()> {
+    let Some(shell) = current_shell() else {
+        output::print(hint_message(shell_integration_hint()))?;
+        return Ok(());
+    };
+    output::print(info_message(cformat!("Detected shell: <bold>{shell}</>")))?;
+
+    let scan = scan_shell_configs(Some(shell), true, binary_name)
+        .map_err(|e| anyhow::anyhow!("Failed to scan shell configs: {e}"))?;
+    if scan.configured.is_empty() {
+        output::print(hint_message(shell_integration_hint()))?;
+        return Ok(());
+    }
+    if scan
+        .configured
+        .iter()
+        .all(|r| matches!(r.action, ConfigAction::AlreadyExists))
+    {
+        output::print(info_message("Shell integration already installed"))?;
+        return Ok(());
+    }
+
+    let confirmed = prompt_for_install(
+        &scan.configured,
+        &scan.completion_results,
+        binary_name,
+        "Install shell integration?",
+    )
+    .map_err(|e| anyhow::anyhow!("{e}"))?;
+    if !confirmed {
+        output::print(hint_message(shell_integration_hint()))?;
+        return Ok(());
+    }
+
+    let result = handle_configure_shell(Some(shell), true, false, binary_name.to_string())
+        .map_err(|e| anyhow::anyhow!("Failed to configure shell integration: {e}"))?;
+    output::print_shell_install_result(&result)?;
+    Ok(())
+}
+
+/// Ask where worktrees should go. `None` keeps the current setting.
+fn prompt_worktree_path() -> anyhow::Result<Option<String>> {
+    output::print(info_message("Where should new worktrees go?"))?;
+    let labels: Vec<String> = WORKTREE_PATH_CHOICES
+        .iter()
+        .map(|(template, layout)| cformat!("{template} <bright-black>{layout}</>"))
+        .collect();
+    Ok(choose(&labels, "Template")?.map(|i| WORKTREE_PATH_CHOICES[i].0.to_string()))
+}
+
+/// Offer LLM commit messages using whichever supported tools are installed.
+fn prompt_llm() -> anyhow::Result<Option<LlmChoice>> {
+    let available: Vec<_> = LLM_CHOICES
+        .iter()
+        .filter(|(command, _)| {
+            Cmd::new(*command)
+                .arg("--version")
+                .run()
+                .is_ok_and(|o| o.status.success())
+        })
+        .collect();
+    if available.is_empty() {
+        output::print(hint_message(cformat!(
+            "For LLM commit messages, install <bright-black>llm</> or <bright-black>aichat</>, then see https://worktrunk.dev/llm-commits/"
+        )))?;
+        return Ok(None);
+    }
+
+    output::print(info_message("Generate commit messages with an LLM?"))?;
+    let labels: Vec<String> = available
+        .iter()
+        .map(|(command, args)| format!("{command} {}", args.join(" ")))
+        .collect();
+    Ok(choose(&labels, "Tool")?.map(|i| LlmChoice {
+        command: available[i].0,
+        args: available[i].1,
+    }))
+}
+
+/// Numbered chooser; Enter skips.
+///
+/// `--yes` takes the first choice; `--no` skips.
+fn choose(labels: &[String], noun: &str) -> anyhow::Result<Option<usize>> {
+    output::flush()?;
+    for (i, label) in labels.iter().enumerate() {
+        eprintln!("{}", cformat!("  <bold>{}</> {label}", i + 1));
+    }
+    let question = cformat!("{noun} <bold>[1-{}]</>, or Enter to skip:", labels.len());
+
+    Ok(match Input::new(question).ask(false)? {
+        Response::Text(text) => text
+            .parse::<usize>()
+            .ok()
+            .and_then(|n| n.checked_sub(1))
+            .filter(|&i| i < labels.len()),
+        Response::Yes => Some(0),
+        Response::No | Response::NotInteractive => None,
+    })
+}
+
+/// Write the chosen settings, preserving any existing config and its comments.
+fn write_user_config(
+    path: &Path,
+    worktree_path: Option<String>,
+    llm: Option<&LlmChoice>,
+) -> anyhow::Result<()> {
+    let is_new = !path.exists();
+    let content = if is_new {
+        user_config_template()
+    } else {
+        std::fs::read_to_string(path).context("Failed to read config file")?
+    };
+    let mut doc = apply_setup_choices(&content, worktree_path, llm)?;
+    // The example's comments become trailing decor; separate them from the settings
+    if is_new && !doc.as_table().is_empty() {
+        let trailing = doc.trailing().as_str().unwrap_or_default().to_string();
+        doc.set_trailing(format!("\n{trailing}"));
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent).context("Failed to create config directory")?;
+    }
+    std::fs::write(path, doc.to_string()).context("Failed to write config file")?;
+    Ok(())
+}
+
+/// Set the chosen keys in `content`, leaving everything else as written.
+fn apply_setup_choices(
+    content: &str,
+    worktree_path: Option<String>,
+    llm: Option<&LlmChoice>,
+) -> anyhow::Result<toml_edit::DocumentMut> {
+    let mut doc: toml_edit::DocumentMut = content.parse().context("Failed to parse config")?;
+    if let Some(template) = worktree_path {
+        doc["worktree-path"] = toml_edit::value(template);
+    }
+    if let Some(llm) = llm {
+        let table = doc
+            .entry("commit-generation")
+            .or_insert_with(toml_edit::table);
+        table["command"] = toml_edit::value(llm.command);
+        table["args"] = toml_edit::value(llm.args.iter().copied().collect::<toml_edit::Array>());
+    }
+    Ok(doc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_setup_choices_to_template() {
+        let doc = apply_setup_choices(
+            &user_config_template(),
+            Some(".worktrees/{{ branch | sanitize }}".to_string()),
+            Some(&LlmChoice {
+                command: "llm",
+                args: &["-m", "claude-haiku-4.5"],
+            }),
+        )
+        .unwrap();
+        let config: worktrunk::config::WorktrunkConfig = toml::from_str(&doc.to_string()).unwrap();
+        assert_eq!(config.worktree_path(), ".worktrees/{{ branch | sanitize }}");
+        assert_eq!(config.commit_generation.command.as_deref(), Some("llm"));
+        assert_eq!(config.commit_generation.args, ["-m", "claude-haiku-4.5"]);
+        // Documentation comments survive
+        assert!(doc.to_string().contains("## Worktree path template"));
+    }
+
+    #[test]
+    fn test_apply_setup_choices_preserves_existing() {
+        let existing = "# my settings\nskip-shell-integration-prompt = true\n";
+        let doc = apply_setup_choices(existing, None, None).unwrap();
+        assert_eq!(doc.to_string(), existing);
+    }
+}
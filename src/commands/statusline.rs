@@ -5,13 +5,20 @@
 //!
 //! This command reuses the data collection infrastructure from `wt list`,
 //! avoiding duplication of git operations.
+//!
+//! Multiplexer formats (`--format=tmux|zellij|wezterm`) are polled every few
+//! seconds by the status bar, so they print the status cached in the
+//! worktree's git dir and refresh it in a detached process when stale.
 
+use crate::cli::StatuslineFormat;
 use crate::output;
 use anyhow::{Context, Result};
 use std::env;
+use std::fs;
 use std::io::{self, Read};
 use std::path::Path;
-use worktrunk::git::{Repository, WorktreeInfo};
+use std::time::{Duration, SystemTime};
+use worktrunk::git::{Repository, WorktreeInfo, base_dir};
 use worktrunk::styling::{get_terminal_width, truncate_visible};
 
 use super::list::{self, CollectOptions, StatuslineSegment};
@@ -130,25 +137,29 @@ const PRIORITY_DIRECTORY: u8 = 0;
 /// Same as Branch - model identity is important.
 const PRIORITY_MODEL: u8 = 1;
 
+/// Cached multiplexer status older than this is refreshed in the background.
+const REFRESH_AFTER: Duration = Duration::from_secs(5);
+
 /// Run the statusline command.
 ///
 /// Output uses `output::stdout()` for raw stdout (bypasses anstream color detection).
 /// Shell prompts (PS1) and Claude Code always expect ANSI codes.
-pub fn run(claude_code: bool) -> Result<()> {
+pub fn run(claude_code: bool, format: StatuslineFormat, refresh_cache: bool) -> Result<()> {
+    if format != StatuslineFormat::Ansi || refresh_cache {
+        return run_cached(format, refresh_cache);
+    }
+
     // Get context - either from stdin (claude-code mode) or current directory
     let (cwd, model_name) = if claude_code {
         let ctx = ClaudeCodeContext::from_stdin();
         let current_dir = ctx
             .as_ref()
             .map(|c| c.current_dir.clone())
-            .unwrap_or_else(|| env::current_dir().unwrap_or_default().display().to_string());
+            .unwrap_or_else(|| base_dir().unwrap_or_default().display().to_string());
         let model = ctx.and_then(|c| c.model_name);
         (Path::new(&current_dir).to_path_buf(), model)
     } else {
-        (
-            env::current_dir().context("Failed to get current directory")?,
-            None,
-        )
+        (base_dir().context("Failed to get current directory")?, None)
     };
 
     // Build segments with priorities
@@ -214,6 +225,74 @@ pub fn run(claude_code: bool) -> Result<()> {
     Ok(())
 }
 
+/// Print the cached status in a multiplexer format, refreshing it when stale.
+///
+/// The cache holds the ANSI rendering; conversion happens on output so all
+/// formats share it. With `refresh_cache`, recompute and store without printing
+/// (the detached refresh process).
+fn run_cached(format: StatuslineFormat, refresh_cache: bool) -> Result<()> {
+    let cwd = base_dir().context("Failed to get current directory")?;
+    let Ok(repo) = Repository::current() else {
+        return Ok(());
+    };
+    let Ok(git_dir) = repo.worktree_at(&cwd).git_dir() else {
+        return Ok(());
+    };
+    let cache_path = git_dir.join("wt-statusline");
+
+    let cached = fs::metadata(&cache_path)
+        .and_then(|meta| meta.modified())
+        .ok()
+        .zip(fs::read_to_string(&cache_path).ok());
+
+    let status = match cached {
+        Some((modified, status)) if !refresh_cache => {
+            let stale = SystemTime::now()
+                .duration_since(modified)
+                .is_ok_and(|age| age > REFRESH_AFTER);
+            if stale {
+                // Bump the mtime first so polls during the refresh don't spawn more
+                let _ = fs::File::options()
+                    .write(true)
+                    .open(&cache_path)
+                    .and_then(|file| file.set_modified(SystemTime::now()));
+                let exe = env::current_exe().context("Failed to locate wt executable")?;
+                let command = format!(
+                    "{} list statusline --refresh-cache",
+                    shell_escape::escape(exe.to_string_lossy())
+                );
+                super::process::spawn_detached(
+                    &repo,
+                    &cwd,
+                    &command,
+                    "wt",
+                    "statusline",
+                    None,
+                    &[],
+                )?;
+            }
+            status
+        }
+        _ => {
+            let segments = get_git_status_segments(&repo, &cwd, false)?;
+            let status =
+                worktrunk::styling::fix_dim_after_color_reset(&StatuslineSegment::join(&segments));
+            // Write-then-rename so a concurrent reader never sees a partial status
+            let tmp_path = git_dir.join(format!("wt-statusline.{}", std::process::id()));
+            fs::write(&tmp_path, &status)
+                .and_then(|()| fs::rename(&tmp_path, &cache_path))
+                .context("Failed to write statusline cache")?;
+            status
+        }
+    };
+
+    if refresh_cache {
+        return Ok(());
+    }
+    output::stdout(super::statusline_format::convert(&status, format))?;
+    Ok(())
+}
+
 /// Filter out branch segment if directory already shows it via worktrunk template.
 fn filter_redundant_branch(segments: Vec<StatuslineSegment>, dir: &str) -> Vec<StatuslineSegment> {
     use super::list::columns::ColumnKind;
@@ -291,12 +370,20 @@ fn get_git_status_segments(
 
     // Build collect options with URL template
     let options = CollectOptions {
+        skip_tasks: [
+            list::collect::TaskKind::DiskUsage,
+            list::collect::TaskKind::BranchDescription,
+            list::collect::TaskKind::Owner,
+        ]
+        .into_iter()
+        .collect(),
         url_template,
+        clone_kind: repo.clone_kind(),
         ..Default::default()
     };
 
     // Populate computed fields (parallel git operations)
-    // Compute everything (same as --full) for complete status symbols
+    // Compute everything except disk usage, descriptions, and owner for complete status symbols
     list::populate_item(repo, &mut item, options)?;
 
     // Get prioritized segments
@@ -0,0 +1,190 @@
+//! Conversion of the ANSI statusline to terminal multiplexer status bar syntax.
+//!
+//! The statusline is rendered with ANSI SGR escapes. Multiplexer status bars
+//! take their own inline style syntax instead:
+//!
+//! - tmux: `#[fg=green,bold]text#[default]` in `status-left`/`status-right`
+//! - zellij: the same `#[...]` syntax, as read by the zjstatus plugin
+//! - wezterm: `window:set_right_status()` accepts SGR escapes directly, so
+//!   only hyperlinks (which it would print literally) are stripped
+
+use crate::cli::StatuslineFormat;
+
+/// Convert ANSI-styled statusline text to `format`'s native syntax.
+pub fn convert(text: &str, format: StatuslineFormat) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(esc) = rest.find('\x1b') {
+        push_text(&mut out, &rest[..esc], format);
+        rest = &rest[esc..];
+
+        if let Some(body) = rest.strip_prefix("\x1b[") {
+            // CSI: only SGR (`m`) sequences are emitted by the statusline
+            let end = body
+                .find(|c: char| c.is_ascii_alphabetic())
+                .unwrap_or(body.len());
+            if body[end..].starts_with('m') {
+                push_sgr(&mut out, &body[..end], format);
+            }
+            rest = body.get(end + 1..).unwrap_or("");
+        } else if let Some(body) = rest.strip_prefix("\x1b]") {
+            // OSC (hyperlinks): dropped, terminated by ST or BEL
+            rest = match (body.find("\x1b\\"), body.find('\x07')) {
+                (Some(st), Some(bel)) if bel < st => &body[bel + 1..],
+                (Some(st), _) => &body[st + 2..],
+                (None, Some(bel)) => &body[bel + 1..],
+                (None, None) => "",
+            };
+        } else {
+            rest = &rest[1..];
+        }
+    }
+    push_text(&mut out, rest, format);
+    out
+}
+
+fn push_text(out: &mut String, text: &str, format: StatuslineFormat) {
+    match format {
+        // `#` starts a format sequence in tmux and zjstatus
+        StatuslineFormat::Tmux | StatuslineFormat::Zellij => {
+            out.push_str(&text.replace('#', "##"));
+        }
+        StatuslineFormat::Ansi | StatuslineFormat::Wezterm => out.push_str(text),
+    }
+}
+
+fn push_sgr(out: &mut String, params: &str, format: StatuslineFormat) {
+    if matches!(format, StatuslineFormat::Ansi | StatuslineFormat::Wezterm) {
+        out.push_str("\x1b[");
+        out.push_str(params);
+        out.push('m');
+        return;
+    }
+
+    let codes: Vec<u16> = params.split(';').map(|p| p.parse().unwrap_or(0)).collect();
+    let mut styles: Vec<String> = Vec::new();
+    let mut codes = codes.into_iter();
+
+    while let Some(code) = codes.next() {
+        let style = match code {
+            0 => {
+                // Reset is emitted on its own so it can't swallow later styles
+                flush(out, &mut styles);
+                out.push_str("#[default]");
+                continue;
+            }
+            1 => "bold".to_string(),
+            2 => "dim".to_string(),
+            3 => attr_name("italics", format).to_string(),
+            4 => attr_name("underscore", format).to_string(),
+            7 => "reverse".to_string(),
+            9 => "strikethrough".to_string(),
+            22 => "nobold,nodim".to_string(),
+            23 => format!("no{}", attr_name("italics", format)),
+            24 => format!("no{}", attr_name("underscore", format)),
+            27 => "noreverse".to_string(),
+            29 => "nostrikethrough".to_string(),
+            30..=37 => format!("fg={}", color_name(code - 30, format)),
+            90..=97 => format!("fg={}", color_name(code - 90 + 8, format)),
+            40..=47 => format!("bg={}", color_name(code - 40, format)),
+            100..=107 => format!("bg={}", color_name(code - 100 + 8, format)),
+            39 => "fg=default".to_string(),
+            49 => "bg=default".to_string(),
+            38 | 48 => {
+                let target = if code == 38 { "fg" } else { "bg" };
+                match codes.next() {
+                    Some(5) => {
+                        let n = codes.next().unwrap_or(0);
+                        format!("{target}={}", color_name(n, format))
+                    }
+                    Some(2) => {
+                        let mut rgb = [0u16; 3];
+                        for c in &mut rgb {
+                            *c = codes.next().unwrap_or(0);
+                        }
+                        format!("{target}=#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2])
+                    }
+                    _ => continue,
+                }
+            }
+            _ => continue,
+        };
+        styles.push(style);
+    }
+    flush(out, &mut styles);
+}
+
+fn flush(out: &mut String, styles: &mut Vec<String>) {
+    if !styles.is_empty() {
+        out.push_str(&format!("#[{}]", styles.join(",")));
+        styles.clear();
+    }
+}
+
+/// zjstatus spells two attributes differently from tmux.
+fn attr_name(tmux_name: &'static str, format: StatuslineFormat) -> &'static str {
+    match (format, tmux_name) {
+        (StatuslineFormat::Zellij, "italics") => "italic",
+        (StatuslineFormat::Zellij, "underscore") => "underline",
+        _ => tmux_name,
+    }
+}
+
+/// Name of palette color `n`: tmux names the 16 base colors, zjstatus takes indices.
+fn color_name(n: u16, format: StatuslineFormat) -> String {
+    const NAMES: [&str; 8] = [
+        "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    ];
+    match format {
+        StatuslineFormat::Zellij => n.to_string(),
+        _ => match n {
+            0..=7 => NAMES[n as usize].to_string(),
+            8..=15 => format!("bright{}", NAMES[n as usize - 8]),
+            _ => format!("colour{n}"),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STYLED: &str = "\x1b[0m main  \x1b[36m?\x1b[39m\x1b[2m^\x1b[22m  \x1b[32m+3\x1b[0m #1";
+
+    #[test]
+    fn test_convert_tmux() {
+        assert_eq!(
+            convert(STYLED, StatuslineFormat::Tmux),
+            "#[default] main  #[fg=cyan]?#[fg=default]#[dim]^#[nobold,nodim]  #[fg=green]+3#[default] ##1"
+        );
+        assert_eq!(
+            convert(
+                "\x1b[1;38;5;208mx\x1b[48;2;255;0;16my",
+                StatuslineFormat::Tmux
+            ),
+            "#[bold,fg=colour208]x#[bg=#ff0010]y"
+        );
+    }
+
+    #[test]
+    fn test_convert_zellij() {
+        assert_eq!(
+            convert("\x1b[91;4mx\x1b[0m", StatuslineFormat::Zellij),
+            "#[fg=9,underline]x#[default]"
+        );
+    }
+
+    #[test]
+    fn test_convert_strips_hyperlinks() {
+        let linked = "\x1b]8;;https://example.com\x1b\\\x1b[32m●\x1b[39m\x1b]8;;\x1b\\ done";
+        assert_eq!(
+            convert(linked, StatuslineFormat::Wezterm),
+            "\x1b[32m●\x1b[39m done"
+        );
+        assert_eq!(
+            convert(linked, StatuslineFormat::Tmux),
+            "#[fg=green]●#[fg=default] done"
+        );
+    }
+}
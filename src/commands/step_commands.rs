@@ -6,6 +6,7 @@
 //! - `step_show_squash_prompt` - Show squash prompt without executing
 //! - `handle_rebase` - Rebase onto target branch
 //! - `step_copy_ignored` - Copy gitignored files matching .worktreeinclude
+//! - `step_prune` - Clean up stale worktree metadata and orphaned directories
 
 use std::path::{Path, PathBuf};
 
@@ -16,6 +17,7 @@ use worktrunk::config::WorktrunkConfig;
 use worktrunk::git::Repository;
 use worktrunk::styling::{
     format_with_gutter, hint_message, info_message, progress_message, success_message,
+    warning_message,
 };
 
 use super::commit::{CommitGenerator, CommitOptions};
@@ -29,6 +31,8 @@ pub fn step_commit(
     no_verify: bool,
     stage_mode: super::commit::StageMode,
     show_prompt: bool,
+    interactive: bool,
+    llm_groups: bool,
 ) -> anyhow::Result<()> {
     use super::command_approval::approve_hooks;
 
@@ -63,6 +67,8 @@ pub fn step_commit(
     options.no_verify = no_verify;
     options.stage_mode = stage_mode;
     options.show_no_squash_note = false;
+    options.interactive = interactive;
+    options.llm_groups = llm_groups;
     // Only warn about untracked if we're staging all
     options.warn_about_untracked = stage_mode == super::commit::StageMode::All;
 
@@ -87,11 +93,13 @@ pub enum SquashResult {
 /// # Arguments
 /// * `skip_pre_commit` - If true, skip all pre-commit hooks (both user and project)
 /// * `stage_mode` - What to stage before committing (All or Tracked; None not supported for squash)
+/// * `autosquash` - Fold `fixup!`/`squash!` commits first, then squash what remains
 pub fn handle_squash(
     target: Option<&str>,
     yes: bool,
     skip_pre_commit: bool,
     stage_mode: super::commit::StageMode,
+    autosquash: bool,
 ) -> anyhow::Result<SquashResult> {
     use super::commit::StageMode;
 
@@ -100,11 +108,19 @@ pub fn handle_squash(
     // Squash requires being on a branch (can't squash in detached HEAD)
     let current_branch = env.require_branch("squash")?.to_string();
     let ctx = env.context(yes);
-    let generator = CommitGenerator::new(&env.config.commit_generation);
+    let generator = CommitGenerator::new(&env.config.commit_generation)
+        .with_signoff(env.config.commit_signoff())
+        .with_git_hooks(!skip_pre_commit && env.config.commit_git_hooks())
+        .with_review(env.config.commit_review() && !yes);
 
     // Get and validate target ref (any commit-ish for merge-base calculation)
     let target_branch = repo.require_target_ref(target)?;
 
+    // Before staging: the rebase stashes uncommitted changes around itself
+    if autosquash {
+        handle_autosquash(&target_branch)?;
+    }
+
     // Auto-stage changes before running pre-commit hooks so both beta and merge paths behave identically
     match stage_mode {
         StageMode::All => {
@@ -129,6 +145,11 @@ pub fn handle_squash(
         .unwrap_or(false);
     let has_user_pre_commit = ctx.config.hooks.pre_commit.is_some();
     let has_any_pre_commit = has_project_pre_commit || has_user_pre_commit;
+    let generator = generator.with_trailers(super::commit::expand_trailers(
+        &ctx,
+        project_config.as_ref(),
+        &[("target", target_branch.as_str())],
+    )?);
 
     if skip_pre_commit && has_any_pre_commit {
         crate::output::print(info_message("Skipping pre-commit hooks (--no-verify)"))?;
@@ -244,18 +265,17 @@ pub fn handle_squash(
         .and_then(|n| n.to_str())
         .unwrap_or("repo");
 
-    let commit_message = crate::llm::generate_squash_message(
-        &target_branch,
-        &merge_base,
-        &subjects,
-        &current_branch,
-        repo_name,
-        &env.config.commit_generation,
-    )?;
-
-    // Display the generated commit message
-    let formatted_message = generator.format_message_for_display(&commit_message);
-    crate::output::print(format_with_gutter(&formatted_message, None))?;
+    // Display the generated commit message (and offer it for review)
+    let commit_message = generator.generate_message(repo, |config| {
+        crate::llm::generate_squash_message(
+            &target_branch,
+            &merge_base,
+            &subjects,
+            &current_branch,
+            repo_name,
+            config,
+        )
+    })?;
 
     // Reset to merge base (soft reset stages all changes, including any already-staged uncommitted changes)
     repo.run_command(&["reset", "--soft", &merge_base])
@@ -270,7 +290,8 @@ pub fn handle_squash(
     }
 
     // Commit with the generated message
-    repo.run_command(&["commit", "-m", &commit_message])
+    generator
+        .commit(repo, &commit_message)
         .context("Failed to create squash commit")?;
 
     // Get commit hash for display
@@ -415,6 +436,58 @@ pub fn handle_rebase(target: Option<&str>) -> anyhow::Result<RebaseResult> {
     Ok(RebaseResult::Rebased)
 }
 
+/// Fold `fixup!`/`squash!`/`amend!` commits since branching from `target` into
+/// the commits they name.
+///
+/// Returns the number of fixup commits folded; zero means nothing was rewritten.
+/// A conflicting autosquash is aborted, leaving the branch as it was.
+pub fn handle_autosquash(target: &str) -> anyhow::Result<usize> {
+    let repo = Repository::current()?;
+    let merge_base = repo
+        .merge_base("HEAD", target)?
+        .context("Cannot autosquash: no common ancestor with target branch")?;
+
+    let range = format!("{merge_base}..HEAD");
+    let fixups = repo
+        .run_command(&["log", "--format=%s", &range])?
+        .lines()
+        .filter(|subject| is_fixup_subject(subject))
+        .count();
+    if fixups == 0 {
+        return Ok(0);
+    }
+
+    let plural = if fixups == 1 { "" } else { "s" };
+    crate::output::print(progress_message(format!(
+        "Autosquashing {fixups} fixup commit{plural}..."
+    )))?;
+
+    if let Err(e) = repo.current_worktree().autosquash(&merge_base) {
+        if repo
+            .worktree_state()?
+            .is_some_and(|state| state.starts_with("REBASING"))
+        {
+            let _ = repo.run_command(&["rebase", "--abort"]);
+        }
+        return Err(worktrunk::git::GitError::Other {
+            message: format!("Failed to autosquash fixup commits (rebase aborted): {e}"),
+        }
+        .into());
+    }
+
+    crate::output::print(success_message(format!(
+        "Autosquashed {fixups} fixup commit{plural}"
+    )))?;
+    Ok(fixups)
+}
+
+/// Whether a commit subject marks a commit for `git rebase --autosquash`.
+fn is_fixup_subject(subject: &str) -> bool {
+    ["fixup! ", "squash! ", "amend! "]
+        .iter()
+        .any(|prefix| subject.starts_with(prefix))
+}
+
 /// Handle `wt step copy-ignored` command
 ///
 /// Copies gitignored files from a source worktree to a destination worktree.
@@ -582,6 +655,230 @@ pub fn step_copy_ignored(
     Ok(())
 }
 
+/// Handle `wt step prune` command
+///
+/// Prunes worktree metadata whose directory is gone (`git worktree prune`) and,
+/// with `orphans`, removes directories at templated worktree paths that git no
+/// longer tracks. Both kinds are listed and confirmed together before anything
+/// is deleted. Matching directories that were never worktrees of this
+/// repository are asked about separately, and `--yes` doesn't cover them.
+pub fn step_prune(
+    orphans: bool,
+    yes: bool,
+    dry_run: bool,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    use crate::output::prompt::{self, Confirm};
+    use worktrunk::path::format_path_for_display;
+
+    let repo = Repository::current()?;
+    let worktrees = repo.list_worktrees()?;
+
+    let stale: Vec<_> = worktrees.iter().filter(|wt| wt.is_prunable()).collect();
+    let (orphaned, unverified) = if orphans {
+        find_orphaned_directories(&repo, &worktrees, config)?
+    } else {
+        (Vec::new(), Vec::new())
+    };
+
+    if stale.is_empty() && orphaned.is_empty() && unverified.is_empty() {
+        crate::output::print(info_message("Nothing to prune"))?;
+        return Ok(());
+    }
+
+    let items: Vec<String> = stale
+        .iter()
+        .map(|wt| format!("{} (stale metadata)", format_path_for_display(&wt.path)))
+        .chain(
+            orphaned
+                .iter()
+                .map(|path| format!("{} (orphaned directory)", format_path_for_display(path))),
+        )
+        .collect();
+    let count = items.len();
+    let entry_word = if count == 1 { "entry" } else { "entries" };
+    let listing = format_with_gutter(&items.join("\n"), None);
+    let unverified_listing = format_with_gutter(
+        &unverified
+            .iter()
+            .map(|path| format_path_for_display(path))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        None,
+    );
+    let unverified_count = unverified.len();
+    let directory_word = if unverified_count == 1 {
+        "directory"
+    } else {
+        "directories"
+    };
+
+    if dry_run {
+        if count > 0 {
+            crate::output::print(info_message(format!(
+                "Would prune {count} {entry_word}:\n{listing}"
+            )))?;
+        }
+        if unverified_count > 0 {
+            crate::output::print(info_message(format!(
+                "Would ask before deleting {unverified_count} {directory_word} at worktree paths that were never worktrees of this repository:\n{unverified_listing}"
+            )))?;
+        }
+        return Ok(());
+    }
+
+    if count > 0 {
+        crate::output::print(info_message(format!(
+            "Found {count} {entry_word} to prune:\n{listing}"
+        )))?;
+
+        if Confirm::new("Prune?", false).ask(yes)? {
+            if !stale.is_empty() {
+                repo.run_command(&["worktree", "prune"])
+                    .context("Failed to prune worktree metadata")?;
+            }
+            for path in &orphaned {
+                std::fs::remove_dir_all(path).with_context(|| {
+                    format!("Failed to remove {}", format_path_for_display(path))
+                })?;
+            }
+            crate::output::print(success_message(format!("Pruned {count} {entry_word}")))?;
+        } else if prompt::assumed_answer().is_none() && !prompt::is_interactive() {
+            crate::output::print(hint_message(cformat!(
+                "To prune without prompting, add <bright-black>--yes</>"
+            )))?;
+        } else {
+            crate::output::print(info_message("Prune cancelled"))?;
+        }
+    }
+
+    if unverified_count > 0 {
+        crate::output::print(warning_message(format!(
+            "Found {unverified_count} {directory_word} at worktree paths that were never worktrees of this repository:\n{unverified_listing}"
+        )))?;
+        // Deliberately not covered by `--yes` or `WORKTRUNK_ASSUME_YES`: these
+        // may be the user's own files
+        if Confirm::new(
+            format!("Delete {unverified_count} {directory_word}?"),
+            false,
+        )
+        .always_ask()
+        .ask(yes)?
+        {
+            for path in &unverified {
+                std::fs::remove_dir_all(path).with_context(|| {
+                    format!("Failed to remove {}", format_path_for_display(path))
+                })?;
+            }
+            crate::output::print(success_message(format!(
+                "Removed {unverified_count} {directory_word}"
+            )))?;
+        } else {
+            crate::output::print(info_message(format!(
+                "Kept {unverified_count} {directory_word}"
+            )))?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Find directories at templated worktree paths that git doesn't track.
+///
+/// Renders the worktree-path template with a sentinel branch to learn the parent
+/// directory and the name pattern (e.g. `repo.*`), then lists matching siblings.
+/// Directories containing a `.git` directory are standalone repositories and
+/// never count as orphans.
+///
+/// Returns the directories that were worktrees of this repository (see
+/// [`was_worktree_of`]) and, separately, the ones that only match by name.
+fn find_orphaned_directories(
+    repo: &Repository,
+    worktrees: &[worktrunk::git::WorktreeInfo],
+    config: &WorktrunkConfig,
+) -> anyhow::Result<(Vec<PathBuf>, Vec<PathBuf>)> {
+    const SENTINEL: &str = "wt-prune-sentinel";
+
+    let template_path = super::worktree::compute_worktree_path(repo, SENTINEL, config)?;
+    let (Some(parent), Some(name)) = (
+        template_path.parent(),
+        template_path.file_name().and_then(|n| n.to_str()),
+    ) else {
+        return Ok((Vec::new(), Vec::new()));
+    };
+    // Templates that put the branch in a parent component can't be matched by name
+    let Some((prefix, suffix)) = name.split_once(SENTINEL) else {
+        return Ok((Vec::new(), Vec::new()));
+    };
+    if !parent.is_dir() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+
+    let tracked: Vec<PathBuf> = worktrees
+        .iter()
+        .map(|wt| dunce::canonicalize(&wt.path).unwrap_or_else(|_| wt.path.clone()))
+        .collect();
+    let worktrees_dir = dunce::canonicalize(repo.git_common_dir().join("worktrees"))
+        .unwrap_or_else(|_| repo.git_common_dir().join("worktrees"));
+
+    let mut orphaned = Vec::new();
+    let mut unverified = Vec::new();
+    for entry in std::fs::read_dir(parent)? {
+        let entry = entry?;
+        let path = entry.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if name.len() <= prefix.len() + suffix.len()
+            || !name.starts_with(prefix)
+            || !name.ends_with(suffix)
+        {
+            continue;
+        }
+        if !entry.file_type()?.is_dir() || path.join(".git").is_dir() {
+            continue;
+        }
+        // Skip tracked worktrees and directories that contain them
+        let canonical = dunce::canonicalize(&path).unwrap_or_else(|_| path.clone());
+        if tracked
+            .iter()
+            .any(|wt_path| wt_path.starts_with(&canonical))
+        {
+            continue;
+        }
+        if was_worktree_of(&canonical, &worktrees_dir) {
+            orphaned.push(path);
+        } else {
+            unverified.push(path);
+        }
+    }
+    orphaned.sort();
+    unverified.sort();
+
+    Ok((orphaned, unverified))
+}
+
+/// Whether `dir` was a linked worktree of the repository whose worktree
+/// metadata lives in `worktrees_dir` (`.git/worktrees`): its `.git` file
+/// points there.
+///
+/// Directories git still records in `worktrees_dir` are listed by
+/// `git worktree list` and never get this far.
+fn was_worktree_of(dir: &Path, worktrees_dir: &Path) -> bool {
+    let Ok(contents) = std::fs::read_to_string(dir.join(".git")) else {
+        return false;
+    };
+    let Some(target) = contents.trim().strip_prefix("gitdir:") else {
+        return false;
+    };
+    let target = dir.join(target.trim());
+    let target = target
+        .parent()
+        .and_then(|parent| dunce::canonicalize(parent).ok())
+        .unwrap_or(target);
+    target.starts_with(worktrees_dir)
+}
+
 /// List ignored entries using git ls-files
 ///
 /// Uses `git ls-files --ignored --exclude-standard -o --directory` which:
@@ -649,7 +946,7 @@ fn list_ignored_entries(
 ///
 /// Apple recommends `copyfile()` with `COPYFILE_CLONE` for directories, which
 /// internally walks the tree and clones per-file — equivalent to what we do here.
-fn copy_dir_recursive(src: &Path, dest: &Path) -> anyhow::Result<()> {
+pub(crate) fn copy_dir_recursive(src: &Path, dest: &Path) -> anyhow::Result<()> {
     copy_dir_recursive_fallback(src, dest)
 }
 
@@ -732,6 +1029,15 @@ mod tests {
         assert!(matches!(cloned, SquashResult::NoCommitsAhead(ref s) if s == "develop"));
     }
 
+    #[test]
+    fn test_is_fixup_subject() {
+        assert!(is_fixup_subject("fixup! Add parser"));
+        assert!(is_fixup_subject("squash! Add parser"));
+        assert!(is_fixup_subject("amend! Add parser"));
+        assert!(!is_fixup_subject("Add fixup! handling"));
+        assert!(!is_fixup_subject("fixup!Add parser"));
+    }
+
     #[test]
     fn test_rebase_result_variants() {
         // RebaseResult doesn't derive Debug/Clone by default, just test matching
@@ -0,0 +1,162 @@
+//! `wt tag`: tag a release and clean up after it.
+//!
+//! Run from the worktree of a release branch that has been merged into the
+//! target, or from the target's own worktree. Tags the commit that merged the
+//! branch (or the target's tip), pushes the tag, and removes the merged worktree.
+
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{info_message, progress_message, success_message};
+
+use super::approve_hooks;
+use super::context::CommandEnv;
+use super::worktree::handle_remove_current;
+use crate::output;
+
+/// Options for `wt tag`
+pub struct TagOptions<'a> {
+    pub version: &'a str,
+    /// Tag message (default: the version)
+    pub message: Option<&'a str>,
+    pub sign: bool,
+    /// Branch to tag (default: the default branch)
+    pub target: Option<&'a str>,
+    pub push: bool,
+    pub remove: bool,
+    pub verify: bool,
+    pub yes: bool,
+}
+
+/// Handle `wt tag`.
+pub fn handle_tag(opts: TagOptions<'_>, config: &WorktrunkConfig) -> anyhow::Result<()> {
+    let TagOptions {
+        version,
+        message,
+        sign,
+        target,
+        push,
+        remove,
+        verify,
+        yes,
+    } = opts;
+
+    let repo = Repository::current()?;
+    // Validate before the merge-commit search. `check-ref-format` accepts a
+    // leading `-`, which `git tag` would read as an option.
+    if version.starts_with('-')
+        || !repo.run_command_check(&["check-ref-format", &format!("refs/tags/{version}")])?
+    {
+        return Err(GitError::InvalidTagName {
+            tag: version.to_string(),
+        }
+        .into());
+    }
+    let branch = repo.require_current_branch("tag")?;
+    let target = repo.require_target_branch(target)?;
+    let on_target = branch == target;
+    let commit = if on_target {
+        repo.run_command(&["rev-parse", &target])?
+            .trim()
+            .to_string()
+    } else {
+        let (effective_target, reason) = repo.integration_reason(&branch, &target)?;
+        if reason.is_none() {
+            return Err(GitError::TagBranchNotMerged { branch, target }.into());
+        }
+        merged_commit(&repo, &branch, &effective_target)?
+    };
+    if repo.ref_exists(&format!("refs/tags/{version}"))? {
+        return Err(GitError::TagExists {
+            tag: version.to_string(),
+        }
+        .into());
+    }
+
+    // Annotated either way; `git tag -a` also signs when `tag.gpgSign` is set
+    let message = message.unwrap_or(version);
+    let kind = if sign { "-s" } else { "-a" };
+    repo.run_command(&["tag", kind, version, "-m", message, &commit])
+        .map_err(|e| GitError::Other {
+            message: format!("Failed to create tag {version}: {e}"),
+        })?;
+    let short = repo.run_command(&["rev-parse", "--short", &commit])?;
+    output::print(success_message(cformat!(
+        "Tagged <bold>{target}</> @ <dim>{}</> as <bold>{version}</>",
+        short.trim()
+    )))?;
+
+    if push {
+        let remote = match repo.upstream_remote() {
+            Some(remote) => remote,
+            None => repo.primary_remote()?,
+        };
+        output::print(progress_message(cformat!(
+            "Pushing <bold>{version}</> to <bold>{remote}</>..."
+        )))?;
+        if let Err(e) = repo.run_command(&["push", &remote, &format!("refs/tags/{version}")]) {
+            // Drop the local tag so a rerun doesn't stop at `TagExists`
+            let _ = repo.run_command(&["tag", "-d", version]);
+            return Err(GitError::RemotePushFailed {
+                remote,
+                target_branch: version.to_string(),
+                error: e.to_string(),
+            }
+            .into());
+        }
+        output::print(success_message(cformat!(
+            "Pushed <bold>{version}</> to <bold>{remote}</>"
+        )))?;
+    }
+
+    let in_main = !repo.current_worktree().is_linked()?;
+    if on_target || in_main {
+        return Ok(());
+    }
+    if !remove {
+        return output::print(info_message("Worktree preserved (--no-remove)"));
+    }
+
+    let result = handle_remove_current(false, false, false, false, false, config)?;
+    let run_hooks = verify && {
+        let env = CommandEnv::for_action_branchless()?;
+        approve_hooks(
+            &env.context(yes),
+            &[HookType::PreRemove, HookType::PostSwitch],
+        )?
+    };
+    output::handle_remove_output(&result, true, run_hooks, false)
+}
+
+/// The commit on `target` that brought `branch` in: the first one along the
+/// target's first-parent history that `branch` is integrated into.
+///
+/// That's the branch tip after a fast-forward, the merge commit after a merge,
+/// and the new commit after a squash or rebase — never later commits that
+/// happen to sit on the target.
+fn merged_commit(repo: &Repository, branch: &str, target: &str) -> anyhow::Result<String> {
+    let Some(base) = repo.merge_base(branch, target)? else {
+        return Ok(repo.run_command(&["rev-parse", target])?.trim().to_string());
+    };
+    let range = format!("{base}..{target}");
+    let mut commits = vec![base];
+    commits.extend(
+        repo.run_command(&["rev-list", "--first-parent", "--reverse", &range])?
+            .lines()
+            .map(str::to_string),
+    );
+
+    // Once merged, the branch stays integrated, so bisect for where that starts.
+    // The target's tip (the last commit) is known to be integrated.
+    let (mut low, mut high) = (0, commits.len() - 1);
+    while low < high {
+        let mid = (low + high) / 2;
+        if repo.integration_reason(branch, &commits[mid])?.1.is_some() {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+    Ok(commits.swap_remove(high))
+}
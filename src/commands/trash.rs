@@ -0,0 +1,376 @@
+//! Staged deletion of removed worktrees.
+//!
+//! Deleting a worktree with a large `node_modules/` or `target/` can take many
+//! seconds. Removal first renames the worktree into `.git/wt-trash/` — a
+//! metadata-only operation on the same filesystem — and drops its git
+//! metadata, so the worktree is gone from `git worktree list` and from its path
+//! right away. The files are then deleted from the trash directory: by a
+//! detached worker in background mode, or in parallel with a spinner in
+//! foreground mode. `wt ps` lists deletions still in progress.
+//!
+//! Entries are named `<unix-timestamp>-<name>`, so `wt ps` can report what each
+//! one was and how long it has been deleting without a separate state file.
+//!
+//! With `[remove] use-trash = true`, staged worktrees under `trash-max-mb` go
+//! to the OS trash instead of being deleted (via the `trash` crate), so they
+//! can be restored from there.
+
+use std::fs;
+use std::io::{self, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::Duration;
+
+use anyhow::Context;
+use color_print::cformat;
+use rayon::prelude::*;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::{format_path_for_display, sanitize_for_filename};
+use worktrunk::styling::eprint;
+use worktrunk::utils::get_now;
+
+/// A removed worktree whose files are still being deleted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrashEntry {
+    /// Branch (or directory) name of the removed worktree
+    pub name: String,
+    /// Location inside `.git/wt-trash/`
+    pub path: PathBuf,
+    /// Unix timestamp when the worktree was moved to the trash
+    pub started: u64,
+}
+
+/// Where a worktree's files went when its removal was staged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StagedRemoval {
+    /// Moved into `.git/wt-trash/`; the files still need deleting
+    Staged(PathBuf),
+    /// Couldn't be moved; the caller falls back to `git worktree remove`
+    InPlace,
+}
+
+/// What became of a staged worktree's files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Disposal {
+    /// Moved to the OS trash (`[remove] use-trash`)
+    OsTrash,
+    /// Deleted
+    Deleted,
+}
+
+/// Move a worktree into `.git/wt-trash/` and drop its git metadata.
+///
+/// When the move isn't possible — the trash directory is on another
+/// filesystem, or (on Windows) a process holds the directory open — returns
+/// `InPlace`.
+///
+/// Moving the directory first means git never sees the files, so without
+/// `force_worktree` the checks `git worktree remove` would make — no
+/// uncommitted or untracked changes, no submodules — run here beforehand.
+///
+/// If git refuses to drop the metadata, the worktree is moved back.
+pub fn stage_worktree_removal(
+    repo: &Repository,
+    worktree_path: &Path,
+    name: &str,
+    force_worktree: bool,
+) -> anyhow::Result<StagedRemoval> {
+    if !force_worktree {
+        ensure_removable(repo, worktree_path, name)?;
+    }
+
+    // Stop fsmonitor daemon first (best effort - ignore errors)
+    // This prevents zombie daemons from accumulating when using builtin fsmonitor
+    let _ = repo
+        .worktree_at(worktree_path)
+        .run_command(&["fsmonitor--daemon", "stop"]);
+
+    let trash_dir = repo.wt_trash_dir();
+    if let Err(e) = fs::create_dir_all(&trash_dir) {
+        log::debug!("Failed to create trash directory: {}", e);
+        return Ok(StagedRemoval::InPlace);
+    }
+
+    let staged = unique_entry_path(&trash_dir, name);
+    if let Err(e) = fs::rename(worktree_path, &staged) {
+        log::debug!(
+            "Cannot move {} to trash, deleting in place: {}",
+            worktree_path.display(),
+            e
+        );
+        return Ok(StagedRemoval::InPlace);
+    }
+
+    // With the directory gone, `git worktree remove` only drops the metadata
+    if let Err(err) = repo.remove_worktree(worktree_path, false) {
+        if let Err(e) = fs::rename(&staged, worktree_path) {
+            log::warn!(
+                "Failed to restore {} from {}: {}",
+                worktree_path.display(),
+                staged.display(),
+                e
+            );
+        }
+        return Err(err);
+    }
+
+    Ok(StagedRemoval::Staged(staged))
+}
+
+/// Get rid of a staged worktree's files.
+///
+/// `os_trash_limit` is the size limit from `[remove] use-trash`
+/// (see `WorktrunkConfig::remove_trash_limit`): entries under it go to the OS
+/// trash, everything else is deleted, with a spinner when stderr is a
+/// terminal. The worktree is already gone by now, so the size walk never
+/// delays the removal itself; background removals run this in the detached
+/// process (`wt step empty-trash`).
+pub fn dispose_staged(path: &Path, os_trash_limit: Option<u64>) -> io::Result<Disposal> {
+    if let Some(limit) = os_trash_limit {
+        if exceeds_size(path, limit) {
+            log::debug!(
+                "{} exceeds the trash size limit, deleting directly",
+                path.display()
+            );
+        } else {
+            match trash::delete(path) {
+                Ok(()) => return Ok(Disposal::OsTrash),
+                Err(e) => log::debug!(
+                    "Cannot move {} to the OS trash, deleting directly: {}",
+                    path.display(),
+                    e
+                ),
+            }
+        }
+    }
+    delete_with_spinner(path).map(|()| Disposal::Deleted)
+}
+
+/// Handle `wt step empty-trash`, which finishes a background removal.
+///
+/// Only accepts entries of the current repository's trash directory.
+pub fn handle_empty_trash(path: &Path, os_trash_limit: Option<u64>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let in_trash = match (
+        path.parent().and_then(|dir| dunce::canonicalize(dir).ok()),
+        dunce::canonicalize(repo.wt_trash_dir()),
+    ) {
+        (Some(parent), Ok(trash_dir)) => parent == trash_dir,
+        _ => false,
+    };
+    if !in_trash {
+        return Err(GitError::Other {
+            message: cformat!(
+                "<bold>{}</> is not in the trash directory",
+                format_path_for_display(path)
+            ),
+        }
+        .into());
+    }
+    dispose_staged(path, os_trash_limit)
+        .with_context(|| format!("Failed to delete {}", format_path_for_display(path)))?;
+    Ok(())
+}
+
+/// Refuse to remove a worktree that `git worktree remove` would refuse.
+fn ensure_removable(repo: &Repository, worktree_path: &Path, name: &str) -> anyhow::Result<()> {
+    let wt = repo.worktree_at(worktree_path);
+    wt.ensure_clean("remove worktree", Some(name), true)?;
+    if wt.has_submodules()? {
+        // Same wording git uses when it refuses
+        return Err(GitError::WorktreeRemovalFailed {
+            branch: name.into(),
+            path: worktree_path.to_path_buf(),
+            error: "working trees containing submodules cannot be moved or removed".into(),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Whether a directory's apparent size is over `limit` bytes.
+///
+/// Stops walking as soon as the limit is passed, so checking a huge
+/// `node_modules/` against a small limit stays cheap.
+fn exceeds_size(path: &Path, limit: u64) -> bool {
+    fn walk(path: &Path, total: &mut u64, limit: u64) -> bool {
+        let Ok(entries) = fs::read_dir(path) else {
+            return false;
+        };
+        for entry in entries.flatten() {
+            let Ok(metadata) = entry.path().symlink_metadata() else {
+                continue;
+            };
+            *total += metadata.len();
+            if *total > limit || (metadata.is_dir() && walk(&entry.path(), total, limit)) {
+                return true;
+            }
+        }
+        false
+    }
+    walk(path, &mut 0, limit)
+}
+
+/// Pick an unused `<timestamp>-<name>` path in the trash directory.
+fn unique_entry_path(trash_dir: &Path, name: &str) -> PathBuf {
+    let safe_name = sanitize_for_filename(name);
+    let mut started = get_now();
+    loop {
+        let candidate = trash_dir.join(format!("{started}-{safe_name}"));
+        if !candidate.exists() {
+            return candidate;
+        }
+        started += 1;
+    }
+}
+
+/// Parse a trash entry's file name into its name and start timestamp.
+fn parse_entry_name(file_name: &str) -> Option<(String, u64)> {
+    let (started, name) = file_name.split_once('-')?;
+    let started = started.parse().ok()?;
+    (!name.is_empty()).then(|| (name.to_string(), started))
+}
+
+/// List removed worktrees that haven't finished deleting, oldest first.
+pub fn pending_removals(repo: &Repository) -> Vec<TrashEntry> {
+    let Ok(entries) = fs::read_dir(repo.wt_trash_dir()) else {
+        return Vec::new();
+    };
+    let mut pending: Vec<_> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_name = entry.file_name();
+            let (name, started) = parse_entry_name(&file_name.to_string_lossy())?;
+            Some(TrashEntry {
+                name,
+                path: entry.path(),
+                started,
+            })
+        })
+        .collect();
+    pending.sort_by(|a, b| a.started.cmp(&b.started).then(a.name.cmp(&b.name)));
+    pending
+}
+
+/// Delete a staged worktree, showing a spinner on stderr when it's a terminal.
+pub fn delete_with_spinner(path: &Path) -> io::Result<()> {
+    let removed = AtomicUsize::new(0);
+    let done = AtomicBool::new(false);
+
+    std::thread::scope(|s| {
+        if io::stderr().is_terminal() {
+            s.spawn(|| spin(&removed, &done));
+        }
+        let result = delete_tree(path, &removed);
+        done.store(true, Ordering::Relaxed);
+        result
+    })
+}
+
+/// Redraw a one-line spinner until `done` is set, then clear it.
+fn spin(removed: &AtomicUsize, done: &AtomicBool) {
+    const FRAMES: [char; 10] = ['⠋', '⠙', '⠹', '⠸', '⠼', '⠴', '⠦', '⠧', '⠇', '⠏'];
+
+    for frame in FRAMES.iter().cycle() {
+        if done.load(Ordering::Relaxed) {
+            break;
+        }
+        let count = removed.load(Ordering::Relaxed);
+        eprint!(
+            "\r{}",
+            cformat!("<cyan>{frame}</> Deleting files <dim>({count} removed)</>")
+        );
+        let _ = io::stderr().flush();
+        std::thread::sleep(Duration::from_millis(80));
+    }
+    eprint!("\r\x1b[2K");
+    let _ = io::stderr().flush();
+}
+
+/// Delete a directory tree, removing sibling entries in parallel.
+///
+/// Falls back to `fs::remove_dir_all` if the parallel walk fails partway
+/// (e.g. read-only files on Windows), so callers see the platform's error.
+fn delete_tree(path: &Path, removed: &AtomicUsize) -> io::Result<()> {
+    delete_tree_parallel(path, removed).or_else(|e| {
+        log::debug!("Parallel delete of {} failed: {}", path.display(), e);
+        fs::remove_dir_all(path)
+    })
+}
+
+fn delete_tree_parallel(path: &Path, removed: &AtomicUsize) -> io::Result<()> {
+    let entries = fs::read_dir(path)?.collect::<io::Result<Vec<_>>>()?;
+    entries.par_iter().try_for_each(|entry| {
+        let entry_path = entry.path();
+        // `DirEntry::file_type` doesn't follow symlinks, so linked directories
+        // are unlinked rather than descended into
+        if entry.file_type()?.is_dir() {
+            delete_tree_parallel(&entry_path, removed)
+        } else {
+            // Directory symlinks and junctions on Windows need `remove_dir`
+            fs::remove_file(&entry_path).or_else(|_| fs::remove_dir(&entry_path))?;
+            removed.fetch_add(1, Ordering::Relaxed);
+            Ok(())
+        }
+    })?;
+    fs::remove_dir(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entry_name() {
+        assert_eq!(
+            parse_entry_name("1700000000-feature"),
+            Some(("feature".to_string(), 1700000000))
+        );
+        // Names may contain dashes themselves
+        assert_eq!(
+            parse_entry_name("1700000000-fix-login-bug"),
+            Some(("fix-login-bug".to_string(), 1700000000))
+        );
+        assert_eq!(parse_entry_name("feature"), None);
+        assert_eq!(parse_entry_name("abc-feature"), None);
+        assert_eq!(parse_entry_name("1700000000-"), None);
+    }
+
+    #[test]
+    fn test_unique_entry_path_avoids_collisions() {
+        let dir = tempfile::tempdir().unwrap();
+        let first = unique_entry_path(dir.path(), "feature/x");
+        fs::create_dir(&first).unwrap();
+        let second = unique_entry_path(dir.path(), "feature/x");
+        assert_ne!(first, second);
+        let name = second.file_name().unwrap().to_string_lossy().into_owned();
+        assert_eq!(parse_entry_name(&name).unwrap().0, "feature-x");
+    }
+
+    #[test]
+    fn test_exceeds_size() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("node_modules/pkg")).unwrap();
+        fs::write(dir.path().join("node_modules/pkg/index.js"), vec![0; 600]).unwrap();
+        fs::write(dir.path().join("README.md"), vec![0; 600]).unwrap();
+
+        // Directory entries add their own metadata size, so compare loosely
+        assert!(!exceeds_size(dir.path(), 1 << 20));
+        assert!(exceeds_size(dir.path(), 1000));
+    }
+
+    #[test]
+    fn test_delete_tree_counts_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("wt");
+        fs::create_dir_all(root.join("node_modules/a/b")).unwrap();
+        fs::write(root.join("file.txt"), "x").unwrap();
+        fs::write(root.join("node_modules/a/index.js"), "x").unwrap();
+        fs::write(root.join("node_modules/a/b/index.js"), "x").unwrap();
+
+        let removed = AtomicUsize::new(0);
+        delete_tree(&root, &removed).unwrap();
+        assert!(!root.exists());
+        assert_eq!(removed.load(Ordering::Relaxed), 3);
+    }
+}
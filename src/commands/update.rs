@@ -0,0 +1,296 @@
+//! `wt update`: bring a worktree's branch up to date with the default branch.
+//!
+//! Fetches the remote, then rebases onto (or merges) its copy of the default
+//! branch, falling back to the local default branch when there's no
+//! remote-tracking ref. Dirty worktrees go through git's `--autostash`, so
+//! uncommitted changes come back afterwards or stay safe in `git stash`.
+//!
+//! With `[fetch] fast-forward-clean`, the fetch also moves every other clean
+//! worktree that is strictly behind the default branch. That runs on its own
+//! thread alongside the update, and what moved is listed once both finish.
+
+use std::path::Path;
+
+use color_print::cformat;
+use worktrunk::config::{UpdateStrategy, WorktrunkConfig};
+use worktrunk::git::{GitError, Repository, ResolvedWorktree};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{
+    FormattedMessage, info_message, progress_message, success_message, warning_message,
+};
+
+use crate::output;
+
+/// Handle `wt update`.
+pub fn handle_update(branch: Option<&str>, strategy: Option<UpdateStrategy>) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let config = WorktrunkConfig::load()?;
+    let strategy = strategy.unwrap_or_else(|| config.update_strategy());
+
+    let (path, branch) = match branch {
+        None => (
+            repo.current_worktree().root()?,
+            repo.require_current_branch("update")?,
+        ),
+        Some(name) => match repo.resolve_worktree(name)? {
+            ResolvedWorktree::Worktree {
+                path,
+                branch: Some(branch),
+            } => (path, branch),
+            ResolvedWorktree::Worktree { branch: None, .. } => {
+                return Err(GitError::DetachedHead {
+                    action: Some("update".into()),
+                }
+                .into());
+            }
+            ResolvedWorktree::BranchOnly { branch } => {
+                return Err(GitError::NoWorktreeFound { branch }.into());
+            }
+        },
+    };
+    let wt = repo.worktree_at(&path);
+
+    if let Some(operation) = wt.pending_operation()? {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Cannot update <bold>{branch}</>: a {operation} is in progress; finish it with <bright-black>git {operation} --continue</> or undo it with <bright-black>git {operation} --abort</>"
+            ),
+        }
+        .into());
+    }
+
+    let default_branch = repo.resolve_target_branch(None)?;
+    let base = fetch_base(&repo, &default_branch)?;
+
+    std::thread::scope(|scope| {
+        let fast_forward =
+            (base != default_branch && config.fetch_fast_forward_clean()).then(|| {
+                scope.spawn(|| fast_forward_clean_worktrees(&repo, &base, &default_branch, &branch))
+            });
+        let result = update_branch(&repo, &path, &branch, &base, strategy);
+        if let Some(handle) = fast_forward {
+            let messages = handle
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            for message in messages {
+                output::print(message)?;
+            }
+        }
+        result
+    })
+}
+
+/// Rebase `branch` (checked out at `path`) onto `base`, or merge `base` into it.
+fn update_branch(
+    repo: &Repository,
+    path: &Path,
+    branch: &str,
+    base: &str,
+    strategy: UpdateStrategy,
+) -> anyhow::Result<()> {
+    let wt = repo.worktree_at(path);
+    if repo.is_ancestor(base, branch)? {
+        let (ahead, behind) = ahead_behind(repo, base, branch)?;
+        output::print(info_message(cformat!(
+            "<bold>{branch}</> is up to date with <bold>{base}</> ({})",
+            format_counts(ahead, behind)
+        )))?;
+        return Ok(());
+    }
+
+    let (verb, done, preposition) = match strategy {
+        UpdateStrategy::Rebase => ("Rebasing", "Rebased", "onto"),
+        UpdateStrategy::Merge => ("Merging", "Merged", "with"),
+    };
+    // Only tracked changes are autostashed; untracked files stay in place
+    let dirty = !wt
+        .run_command(&["status", "--porcelain", "--untracked-files=no"])?
+        .trim()
+        .is_empty();
+    let stash_before = stash_tip(repo);
+    if dirty {
+        output::print(progress_message(cformat!(
+            "Stashing changes in <bold>{}</>...",
+            format_path_for_display(path)
+        )))?;
+    }
+    output::print(progress_message(cformat!(
+        "{verb} <bold>{branch}</> {preposition} <bold>{base}</>..."
+    )))?;
+
+    let args = match strategy {
+        UpdateStrategy::Rebase => vec!["rebase", "--autostash", base],
+        UpdateStrategy::Merge => vec!["merge", "--autostash", "--no-edit", base],
+    };
+    if let Err(e) = wt.run_command(&args) {
+        let git_output = e.to_string();
+        return Err(match (strategy, wt.pending_operation()?) {
+            (UpdateStrategy::Rebase, Some(_)) => GitError::RebaseConflict {
+                target_branch: base.to_string(),
+                git_output,
+            }
+            .into(),
+            (UpdateStrategy::Merge, Some(_)) => GitError::MergeConflict {
+                target_branch: base.to_string(),
+                git_output,
+            }
+            .into(),
+            (_, None) => GitError::Other {
+                message: cformat!("Failed to update <bold>{branch}</>: {git_output}"),
+            }
+            .into(),
+        });
+    }
+
+    // git keeps the stash when reapplying it conflicts
+    if dirty && stash_tip(repo) != stash_before {
+        output::print(warning_message(
+            "Stashed changes didn't apply cleanly; they're kept in git stash",
+        ))?;
+    }
+
+    let (ahead, behind) = ahead_behind(repo, base, branch)?;
+    output::print(success_message(cformat!(
+        "{done} <bold>{branch}</> {preposition} <bold>{base}</> ({})",
+        format_counts(ahead, behind)
+    )))?;
+    Ok(())
+}
+
+/// Fetch the default branch's remote and return the ref to update onto.
+///
+/// The remote is `[repo] upstream-remote` if set, else the default branch's
+/// upstream, else the primary remote. Repositories without a remote, or
+/// without a fetched copy of the default branch, use the local branch.
+fn fetch_base(repo: &Repository, default_branch: &str) -> anyhow::Result<String> {
+    let remote = match repo.upstream_remote() {
+        Some(remote) => Some(remote),
+        None => match repo.configured_upstream_remote(default_branch)? {
+            Some(remote) => Some(remote),
+            None => repo.primary_remote().ok(),
+        },
+    };
+    let Some(remote) = remote else {
+        return Ok(default_branch.to_string());
+    };
+
+    output::print(progress_message(cformat!("Fetching <bold>{remote}</>...")))?;
+    repo.run_command(&["fetch", &remote])
+        .map_err(|e| GitError::Other {
+            message: cformat!("Failed to fetch <bold>{remote}</>: {e}"),
+        })?;
+
+    let remote_ref = format!("{remote}/{default_branch}");
+    Ok(if repo.ref_exists(&format!("refs/remotes/{remote_ref}"))? {
+        remote_ref
+    } else {
+        default_branch.to_string()
+    })
+}
+
+/// `[fetch] fast-forward-clean`: move clean worktrees whose branch has no
+/// commits of its own up to the freshly fetched `base`, returning what to report.
+///
+/// `skip` is the branch `wt update` is updating itself, and the default branch
+/// is never moved. Worktrees with changes, local commits, a rebase or merge in
+/// progress, or an upstream other than the default branch (whose next push
+/// would publish the default branch's commits) are left alone. A worktree that
+/// can't be inspected gets a warning and doesn't stop the others.
+fn fast_forward_clean_worktrees(
+    repo: &Repository,
+    base: &str,
+    default_branch: &str,
+    skip: &str,
+) -> Vec<FormattedMessage> {
+    let worktrees = match repo.list_worktrees() {
+        Ok(worktrees) => worktrees,
+        Err(e) => {
+            return vec![warning_message(format!(
+                "Failed to list worktrees to fast-forward: {e:#}"
+            ))];
+        }
+    };
+    worktrees
+        .iter()
+        .filter(|info| !info.is_prunable())
+        .filter_map(|info| {
+            let branch = info.branch.as_deref()?;
+            if branch == skip || branch == default_branch {
+                return None;
+            }
+            fast_forward_worktree(repo, &info.path, branch, base, default_branch).unwrap_or_else(
+                |e| {
+                    Some(warning_message(cformat!(
+                        "Failed to fast-forward <bold>{branch}</>: {e:#}"
+                    )))
+                },
+            )
+        })
+        .collect()
+}
+
+/// Fast-forward one worktree if it qualifies; `None` when it's left alone.
+fn fast_forward_worktree(
+    repo: &Repository,
+    path: &Path,
+    branch: &str,
+    base: &str,
+    default_branch: &str,
+) -> anyhow::Result<Option<FormattedMessage>> {
+    if repo
+        .upstream_branch(branch)?
+        .is_some_and(|upstream| upstream != base && upstream != default_branch)
+    {
+        return Ok(None);
+    }
+    // Strictly behind: nothing of its own to lose, and something to gain
+    if !repo.is_ancestor(branch, base)? || repo.same_commit(branch, base)? {
+        return Ok(None);
+    }
+    let wt = repo.worktree_at(path);
+    if wt.is_dirty()? || wt.pending_operation()?.is_some() {
+        return Ok(None);
+    }
+
+    let count = repo
+        .run_command(&["rev-list", "--count", &format!("{branch}..{base}")])?
+        .trim()
+        .to_string();
+    wt.run_command(&["merge", "--ff-only", "--quiet", base])?;
+    let commits = if count == "1" { "commit" } else { "commits" };
+    Ok(Some(success_message(cformat!(
+        "Fast-forwarded <bold>{branch}</> to <bold>{base}</> ({count} {commits})"
+    ))))
+}
+
+/// The newest `git stash` entry, to tell whether the update left one behind.
+fn stash_tip(repo: &Repository) -> Option<String> {
+    repo.run_command(&["rev-parse", "--quiet", "--verify", "refs/stash"])
+        .ok()
+        .map(|sha| sha.trim().to_string())
+}
+
+/// Commits `branch` has that `base` lacks, and the reverse.
+///
+/// Counted directly rather than through the cached merge-base, which still
+/// describes the branch from before the update.
+fn ahead_behind(repo: &Repository, base: &str, branch: &str) -> anyhow::Result<(usize, usize)> {
+    let output = repo.run_command(&[
+        "rev-list",
+        "--left-right",
+        "--count",
+        &format!("{base}...{branch}"),
+    ])?;
+    let mut counts = output.split_whitespace().map(str::parse::<usize>);
+    match (counts.next(), counts.next()) {
+        (Some(Ok(behind)), Some(Ok(ahead))) => Ok((ahead, behind)),
+        _ => Err(GitError::Other {
+            message: format!("Unexpected git rev-list output: {output}"),
+        }
+        .into()),
+    }
+}
+
+fn format_counts(ahead: usize, behind: usize) -> String {
+    format!("{ahead} ahead, {behind} behind")
+}
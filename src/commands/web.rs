@@ -0,0 +1,217 @@
+//! `wt web`: a local dashboard of the worktree list.
+//!
+//! A minimal HTTP server on `127.0.0.1`. `GET /` serves a static page, which
+//! polls `GET /api/list` for the same JSON `wt list --format=json` prints.
+//! Requests are handled one at a time — the only client is a browser tab.
+//! Requests must name the server in their `Host` header, so a page on another
+//! site can't reach it by rebinding its own domain to `127.0.0.1`.
+//!
+//! The page never mutates the repository: its buttons copy `wt` commands to
+//! the clipboard for the user to run.
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+
+use anyhow::Context;
+use color_print::cformat;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::Repository;
+use worktrunk::styling::{hint_message, info_message};
+
+use super::list::{collect, json_output};
+
+/// Dashboard page; renders `/api/list` client-side.
+const PAGE: &str = include_str!("web.html");
+
+/// What a request asks for.
+#[derive(Debug, PartialEq, Eq)]
+enum Route {
+    Page,
+    List,
+    NotFound,
+    MethodNotAllowed,
+    /// The `Host` header names some other server
+    Forbidden,
+}
+
+/// Route an HTTP request line such as `GET /api/list?t=1 HTTP/1.1`.
+fn route(request_line: &str) -> Route {
+    let mut parts = request_line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Route::NotFound;
+    };
+    if method != "GET" {
+        return Route::MethodNotAllowed;
+    }
+    let path = target.split(['?', '#']).next().unwrap_or_default();
+    match path {
+        "/" | "/index.html" => Route::Page,
+        "/api/list" => Route::List,
+        _ => Route::NotFound,
+    }
+}
+
+/// Whether a `Host` header value names this server: `127.0.0.1:<port>` or
+/// `localhost:<port>`.
+fn is_local_host(host: Option<&str>, port: u16) -> bool {
+    let Some((name, host_port)) = host.and_then(|host| host.rsplit_once(':')) else {
+        return false;
+    };
+    (name == "127.0.0.1" || name.eq_ignore_ascii_case("localhost")) && host_port.parse() == Ok(port)
+}
+
+pub fn handle_web(port: u16, show_branches: bool, config: &WorktrunkConfig) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+
+    let listener = TcpListener::bind(("127.0.0.1", port))
+        .with_context(|| format!("Failed to listen on port {port}"))?;
+
+    crate::output::print(info_message(cformat!(
+        "Serving worktree dashboard at <bold>http://127.0.0.1:{port}</>"
+    )))?;
+    crate::output::print(hint_message("Press Ctrl-C to stop"))?;
+    crate::output::flush()?;
+
+    for stream in listener.incoming() {
+        let Ok(stream) = stream else { continue };
+        // A dropped connection only affects that request
+        if let Err(e) = serve(stream, port, &repo, show_branches, config) {
+            log::debug!("wt web request failed: {e:#}");
+        }
+    }
+    Ok(())
+}
+
+fn serve(
+    mut stream: TcpStream,
+    port: u16,
+    repo: &Repository,
+    show_branches: bool,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<()> {
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Drain headers so closing the socket doesn't reset the connection
+    let mut host = None;
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 2 {
+        if let Some((name, value)) = header.split_once(':')
+            && name.eq_ignore_ascii_case("host")
+        {
+            host = Some(value.trim().to_string());
+        }
+        header.clear();
+    }
+
+    let route = if is_local_host(host.as_deref(), port) {
+        route(&request_line)
+    } else {
+        Route::Forbidden
+    };
+    let (status, content_type, body) = match route {
+        Route::Page => ("200 OK", "text/html; charset=utf-8", PAGE.to_string()),
+        Route::List => match list_json(repo, show_branches, config) {
+            Ok(json) => ("200 OK", "application/json", json),
+            Err(e) => (
+                "500 Internal Server Error",
+                "text/plain; charset=utf-8",
+                format!("{e:#}"),
+            ),
+        },
+        Route::NotFound => (
+            "404 Not Found",
+            "text/plain; charset=utf-8",
+            "Not found".into(),
+        ),
+        Route::MethodNotAllowed => (
+            "405 Method Not Allowed",
+            "text/plain; charset=utf-8",
+            "Method not allowed".into(),
+        ),
+        Route::Forbidden => (
+            "403 Forbidden",
+            "text/plain; charset=utf-8",
+            "Forbidden".into(),
+        ),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Collect the list as `wt list --format=json` would, minus the slow tasks.
+fn list_json(
+    repo: &Repository,
+    show_branches: bool,
+    config: &WorktrunkConfig,
+) -> anyhow::Result<String> {
+    // Same cuts as `wt select`: the page refreshes every few seconds
+    let skip_tasks = [
+        collect::TaskKind::BranchDiff,
+        collect::TaskKind::CiStatus,
+        collect::TaskKind::MergeTreeConflicts,
+        collect::TaskKind::DiskUsage,
+        collect::TaskKind::BranchDescription,
+        collect::TaskKind::Owner,
+    ]
+    .into_iter()
+    .collect();
+
+    let items = collect::collect(
+        repo,
+        show_branches,
+        false, // show_remotes
+        &skip_tasks,
+        false, // show_progress
+        false, // render_table (the page renders its own)
+        config,
+        Some(std::time::Duration::from_millis(500)),
+        true,  // skip_expensive_for_stale
+        false, // show_index
+        false, // show_summary
+        false, // no_truncate
+        false, // record_layout
+        false, // mine
+        // Display options only affect the table
+        &Default::default(),
+    )?
+    .map(|data| data.items)
+    .unwrap_or_default();
+
+    serde_json::to_string(&json_output::to_json_items(&items))
+        .context("Failed to serialize to JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route() {
+        assert_eq!(route("GET / HTTP/1.1\r\n"), Route::Page);
+        assert_eq!(route("GET /api/list?t=123 HTTP/1.1\r\n"), Route::List);
+        assert_eq!(route("GET /favicon.ico HTTP/1.1\r\n"), Route::NotFound);
+        assert_eq!(
+            route("POST /api/list HTTP/1.1\r\n"),
+            Route::MethodNotAllowed
+        );
+        assert_eq!(route(""), Route::NotFound);
+    }
+
+    #[test]
+    fn test_is_local_host() {
+        assert!(is_local_host(Some("127.0.0.1:8080"), 8080));
+        assert!(is_local_host(Some("localhost:8080"), 8080));
+        assert!(is_local_host(Some("LocalHost:8080"), 8080));
+        assert!(!is_local_host(Some("127.0.0.1:9090"), 8080));
+        assert!(!is_local_host(Some("evil.example.com:8080"), 8080));
+        assert!(!is_local_host(Some("localhost"), 8080));
+        assert!(!is_local_host(None, 8080));
+    }
+}
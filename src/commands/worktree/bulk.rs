@@ -0,0 +1,209 @@
+//! `wt switch --create-all`: worktrees for every branch matching a pattern.
+//!
+//! `git worktree add` runs one branch at a time under the operation lock;
+//! post-create hooks, the slow part, then run for all new worktrees in
+//! parallel. Every git command they start still goes through the shared
+//! command semaphore, which bounds the total concurrency. Each worktree's hook
+//! output goes to its log file and is printed as one block afterwards.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use color_print::cformat;
+use rayon::prelude::*;
+use worktrunk::HookType;
+use worktrunk::config::{WorktrunkConfig, wildcard_match};
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::{format_path_for_display, sanitize_for_filename};
+use worktrunk::styling::{info_message, sanitize_for_display, visual_width};
+
+use super::switch::{execute_switch, plan_switch};
+use super::types::SwitchResult;
+use crate::commands::approve_hooks_for_branches;
+use crate::commands::command_executor::CommandContext;
+use crate::display::pad_to_width;
+use crate::output;
+
+/// What happened to one matching branch.
+enum Outcome {
+    Created(PathBuf),
+    Exists(PathBuf),
+    Failed(String),
+}
+
+/// Handle `wt switch --create-all <pattern>`.
+///
+/// Matches local branches, and remote branches without a local one (by their
+/// name without the remote). Branches that already have a worktree are listed
+/// but left alone.
+pub fn handle_create_all(
+    repo: &Repository,
+    pattern: &str,
+    config: &WorktrunkConfig,
+    yes: bool,
+    verify: bool,
+    wait: bool,
+) -> anyhow::Result<()> {
+    let branches = matching_branches(repo, pattern)?;
+    if branches.is_empty() {
+        return Err(GitError::Other {
+            message: cformat!("No branches match <bold>{pattern}</>"),
+        }
+        .into());
+    }
+
+    let repo_root = repo.repo_path()?;
+    let approved = if verify {
+        let ctx = CommandContext::new(repo, config, None, &repo_root, &repo_root, yes);
+        approve_hooks_for_branches(&ctx, &[HookType::PostCreate], &branches)?
+    } else {
+        true
+    };
+    if !approved {
+        output::print(info_message(
+            "Commands declined, continuing worktree creation",
+        ))?;
+    }
+
+    let mut outcomes: Vec<(String, Outcome)> = branches
+        .into_iter()
+        .map(|branch| {
+            let outcome = match create_worktree(repo, &branch, config, wait) {
+                Ok(outcome) => outcome,
+                Err(e) => Outcome::Failed(format!("{e:#}")),
+            };
+            (branch, outcome)
+        })
+        .collect();
+
+    if verify && approved {
+        // Hook output is collected per worktree and shown once all are done,
+        // so lines from different worktrees don't interleave
+        let hook_output: Vec<Option<String>> = outcomes
+            .par_iter_mut()
+            .map(|(branch, outcome)| {
+                let branch = branch.as_str();
+                let Outcome::Created(path) = outcome else {
+                    return None;
+                };
+                let ctx = CommandContext::new(repo, config, Some(branch), path, &repo_root, yes);
+                match run_post_create(&ctx, branch) {
+                    Ok(text) => Some(text),
+                    Err(e) => {
+                        *outcome = Outcome::Failed(format!("{e:#}"));
+                        None
+                    }
+                }
+            })
+            .collect();
+        for text in hook_output.into_iter().flatten() {
+            if !text.trim().is_empty() {
+                output::print(text.trim_end())?;
+            }
+        }
+    }
+
+    output::stdout(render(&outcomes))?;
+
+    let failed = outcomes
+        .iter()
+        .filter(|(_, outcome)| matches!(outcome, Outcome::Failed(_)))
+        .count();
+    if failed > 0 {
+        return Err(GitError::Other {
+            message: format!("Failed to create {failed} of {} worktrees", outcomes.len()),
+        }
+        .into());
+    }
+    Ok(())
+}
+
+/// Run the worktree's post-create hooks, returning what they printed.
+///
+/// The output is written to `{branch}-post-create.log` in the log directory,
+/// where it stays after the command.
+fn run_post_create(ctx: &CommandContext, branch: &str) -> anyhow::Result<String> {
+    let log_dir = ctx.repo.wt_logs_dir();
+    fs::create_dir_all(&log_dir).with_context(|| {
+        format!(
+            "Failed to create log directory {}",
+            format_path_for_display(&log_dir)
+        )
+    })?;
+    let log_path = log_dir.join(format!("{}-post-create.log", sanitize_for_filename(branch)));
+    let mut log = fs::File::create(&log_path).with_context(|| {
+        format!(
+            "Failed to create log file {}",
+            format_path_for_display(&log_path)
+        )
+    })?;
+    ctx.execute_post_create_commands_to_log(&mut log)?;
+    Ok(fs::read_to_string(&log_path)?)
+}
+
+/// Local and remote-only branch names matching `pattern`, sorted.
+fn matching_branches(repo: &Repository, pattern: &str) -> anyhow::Result<Vec<String>> {
+    let local = repo
+        .list_local_branches()?
+        .into_iter()
+        .map(|(name, _)| name);
+    let remote = repo
+        .list_untracked_remote_branches()?
+        .into_iter()
+        .filter_map(|(name, _)| name.split_once('/').map(|(_, branch)| branch.to_string()));
+    let branches: BTreeSet<String> = local
+        .chain(remote)
+        .filter(|branch| wildcard_match(pattern, branch))
+        .collect();
+    Ok(branches.into_iter().collect())
+}
+
+/// Create the worktree for `branch` without running hooks.
+fn create_worktree(
+    repo: &Repository,
+    branch: &str,
+    config: &WorktrunkConfig,
+    wait: bool,
+) -> anyhow::Result<Outcome> {
+    let plan = plan_switch(repo, branch, false, None, false, None, config)?;
+    if !plan.is_create() {
+        return Ok(Outcome::Exists(plan.worktree_path().to_path_buf()));
+    }
+    let (result, _) = execute_switch(repo, plan, config, false, true, wait)?;
+    Ok(match result {
+        SwitchResult::Created { path, .. } => Outcome::Created(path),
+        SwitchResult::Existing(path) | SwitchResult::AlreadyAt(path) => Outcome::Exists(path),
+    })
+}
+
+/// One row per branch: status, branch, and the worktree path or error.
+fn render(outcomes: &[(String, Outcome)]) -> String {
+    let width = outcomes
+        .iter()
+        .map(|(branch, _)| visual_width(&sanitize_for_display(branch)))
+        .max()
+        .unwrap_or(0);
+    outcomes
+        .iter()
+        .map(|(branch, outcome)| {
+            let branch = pad_to_width(&sanitize_for_display(branch), width);
+            match outcome {
+                Outcome::Created(path) => cformat!(
+                    "<green>created</>  <bold>{branch}</>  <bright-black>{}</>",
+                    format_path_for_display(path)
+                ),
+                Outcome::Exists(path) => cformat!(
+                    "<dim>exists</>   <bold>{branch}</>  <bright-black>{}</>",
+                    format_path_for_display(path)
+                ),
+                Outcome::Failed(error) => {
+                    let error = error.lines().next().unwrap_or_default();
+                    cformat!("<red>failed</>   <bold>{branch}</>  {error}")
+                }
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
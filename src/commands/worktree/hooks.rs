@@ -6,25 +6,27 @@ use worktrunk::HookType;
 
 use crate::commands::command_executor::CommandContext;
 use crate::commands::hooks::{
-    HookFailureStrategy, prepare_hook_commands, spawn_hook_commands_background,
+    HookFailureStrategy, prepare_hook_commands, run_hook_to_log, spawn_hook_commands_background,
 };
 
 impl<'a> CommandContext<'a> {
     /// Execute post-create commands sequentially (blocking)
     ///
-    /// Runs user hooks first, then project hooks.
+    /// Runs user hooks first, then project hooks (led by the `[integrations] nix`
+    /// command, if configured, and followed by the branch's `[[rules]]` entry).
     /// Shows path in hook announcements when shell integration isn't active (user's shell
     /// won't cd to the new worktree, so they need to know where hooks ran).
     ///
     /// `extra_vars`: Additional template variables (e.g., `base`, `base_worktree_path`).
     pub fn execute_post_create_commands(&self, extra_vars: &[(&str, &str)]) -> anyhow::Result<()> {
-        let project_config = self.repo.load_project_config()?;
+        let project_commands = self
+            .repo
+            .load_project_config()?
+            .and_then(|c| c.post_create_commands(self.branch));
         crate::commands::hooks::run_hook_with_filter(
             self,
             self.config.hooks.post_create.as_ref(),
-            project_config
-                .as_ref()
-                .and_then(|c| c.hooks.post_create.as_ref()),
+            project_commands.as_ref(),
             HookType::PostCreate,
             extra_vars,
             HookFailureStrategy::Warn,
@@ -33,8 +35,34 @@ impl<'a> CommandContext<'a> {
         )
     }
 
+    /// Execute post-create commands like [`Self::execute_post_create_commands`],
+    /// writing their announcements and output to `log`.
+    ///
+    /// For worktrees created together, whose hooks run in parallel. The shell
+    /// doesn't cd to any of them, so announcements always name the worktree.
+    pub fn execute_post_create_commands_to_log(
+        &self,
+        log: &mut std::fs::File,
+    ) -> anyhow::Result<()> {
+        let project_commands = self
+            .repo
+            .load_project_config()?
+            .and_then(|c| c.post_create_commands(self.branch));
+        run_hook_to_log(
+            self,
+            self.config.hooks.post_create.as_ref(),
+            project_commands.as_ref(),
+            HookType::PostCreate,
+            &[],
+            crate::output::pre_hook_display_path(self.worktree_path),
+            log,
+        )
+    }
+
     /// Spawn post-start commands in parallel as background processes (non-blocking)
     ///
+    /// Project commands include the branch's `[[rules]]` entry.
+    ///
     /// `extra_vars`: Additional template variables (e.g., `base`, `base_worktree_path`).
     /// `display_path`: When `Some`, shows the path in hook announcements. Pass this when
     /// the user's shell won't be in the worktree (shell integration not active).
@@ -43,14 +71,15 @@ impl<'a> CommandContext<'a> {
         extra_vars: &[(&str, &str)],
         display_path: Option<&std::path::Path>,
     ) -> anyhow::Result<()> {
-        let project_config = self.repo.load_project_config()?;
+        let project_commands = self
+            .repo
+            .load_project_config()?
+            .and_then(|c| c.hook_commands(HookType::PostStart, self.branch));
 
         let commands = prepare_hook_commands(
             self,
             self.config.hooks.post_start.as_ref(),
-            project_config
-                .as_ref()
-                .and_then(|c| c.hooks.post_start.as_ref()),
+            project_commands.as_ref(),
             HookType::PostStart,
             extra_vars,
             None,
@@ -0,0 +1,117 @@
+//! `[remove] auto-integrated`: clean up worktrees of merged branches.
+//!
+//! Runs at the start of `wt list` and `wt switch`. A branch counts as merged
+//! once it's integrated into the default branch (or its upstream, after a
+//! fetch), the same check `wt remove` uses to delete branches safely.
+
+use color_print::cformat;
+use rayon::prelude::*;
+use worktrunk::config::{AutoIntegratedMode, WorktrunkConfig};
+use worktrunk::git::{IntegrationReason, Repository};
+use worktrunk::styling::warning_message;
+
+use super::remove::handle_remove;
+use crate::output::handle_remove_output;
+use crate::output::prompt::Confirm;
+
+/// Remove or offer to remove worktrees whose branch is integrated, per config.
+///
+/// Skips the current and primary worktrees, locked or dirty ones, and `keep`
+/// (the branch being switched to). The integration checks run in parallel
+/// against the last fetched state; nothing fetches here. Failures are
+/// reported as warnings and never stop the command that triggered the cleanup.
+pub fn remove_integrated_worktrees(
+    repo: &Repository,
+    config: &WorktrunkConfig,
+    keep: Option<&str>,
+) {
+    let mode = config.auto_integrated_mode();
+    if mode == AutoIntegratedMode::Never {
+        return;
+    }
+    if let Err(e) = remove_integrated(repo, config, mode, keep) {
+        warn(cformat!("Failed to check for merged worktrees: {e:#}"));
+    }
+}
+
+fn remove_integrated(
+    repo: &Repository,
+    config: &WorktrunkConfig,
+    mode: AutoIntegratedMode,
+    keep: Option<&str>,
+) -> anyhow::Result<()> {
+    let Some(base) = repo.default_branch() else {
+        return Ok(());
+    };
+    let current = repo
+        .current_worktree()
+        .root()
+        .ok()
+        .and_then(|p| dunce::canonicalize(p).ok());
+    let primary = repo
+        .primary_worktree()?
+        .and_then(|p| dunce::canonicalize(p).ok());
+
+    let candidates: Vec<_> = repo
+        .list_worktrees()?
+        .into_iter()
+        .filter(|wt| {
+            let path = dunce::canonicalize(&wt.path).ok();
+            wt.branch.as_deref().is_some_and(|branch| branch != base)
+                && wt.branch.as_deref() != keep
+                && wt.locked.is_none()
+                && !wt.is_prunable()
+                && path.is_some()
+                && path != current
+                && path != primary
+        })
+        .collect();
+
+    let integrated: Vec<(String, IntegrationReason)> = candidates
+        .par_iter()
+        .filter_map(|wt| {
+            let branch = wt.branch.as_deref()?;
+            let check = || -> anyhow::Result<Option<IntegrationReason>> {
+                // Like `wt list`, a branch with no commits of its own isn't "integrated"
+                let (_, reason) = repo.integration_reason(branch, &base)?;
+                match reason {
+                    Some(IntegrationReason::SameCommit) | None => Ok(None),
+                    Some(_) if repo.worktree_at(&wt.path).is_dirty()? => Ok(None),
+                    Some(reason) => Ok(Some(reason)),
+                }
+            };
+            match check() {
+                Ok(reason) => reason.map(|reason| (branch.to_string(), reason)),
+                Err(e) => {
+                    warn(cformat!("Failed to check <bold>{branch}</>: {e:#}"));
+                    None
+                }
+            }
+        })
+        .collect();
+
+    for (branch, reason) in integrated {
+        if mode == AutoIntegratedMode::Prompt {
+            let question = cformat!(
+                "Remove worktree for <bold>{branch}</>? ({} <bold>{base}</>)",
+                reason.description()
+            );
+            if !Confirm::new(question, false).ask(false)? {
+                continue;
+            }
+        }
+
+        let removed = handle_remove(&branch, false, false, false, false, false, config)
+            .and_then(|result| handle_remove_output(&result, false, false, false));
+        if let Err(e) = removed {
+            warn(cformat!(
+                "Failed to remove merged worktree for <bold>{branch}</>: {e:#}"
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn warn(message: String) {
+    let _ = crate::output::print(warning_message(message));
+}
@@ -78,7 +78,10 @@
 //!
 //! The shell wrapper is generated by `wt config shell init <shell>` from templates in `templates/`.
 
+mod bulk;
 mod hooks;
+mod integrated;
+mod path;
 mod push;
 mod remove;
 mod resolve;
@@ -86,12 +89,20 @@ mod switch;
 mod types;
 
 // Re-export public types and functions
-pub use push::handle_push;
+pub use bulk::handle_create_all;
+pub use integrated::remove_integrated_worktrees;
+pub use path::handle_path;
+pub use push::{
+    handle_push, handle_push_branch, merge_push_remote, push_branch, push_target_to_remote,
+};
 pub use remove::{handle_remove, handle_remove_current};
 pub use resolve::{
-    get_path_mismatch, is_worktree_at_expected_path, resolve_worktree_arg, worktree_display_name,
+    compute_worktree_path, get_path_mismatch, is_worktree_at_expected_path, resolve_worktree_arg,
+    worktree_display_name,
+};
+pub use switch::{
+    ensure_no_pending_operation, execute_switch, handle_reattach, plan_switch, select_from_history,
 };
-pub use switch::{execute_switch, plan_switch};
 pub use types::{
     BranchDeletionMode, MergeOperations, RemoveResult, ResolutionContext, SwitchBranchInfo,
     SwitchResult,
@@ -0,0 +1,88 @@
+//! `wt path`: print a branch's worktree path for scripts and editors.
+//!
+//! Resolves the branch the way `wt switch` does, without changing directory.
+//! Only the path goes to stdout, so `cd "$(wt path feature)"` works in any shell.
+
+use color_print::cformat;
+use worktrunk::HookType;
+use worktrunk::config::WorktrunkConfig;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::path::format_path_for_display;
+use worktrunk::styling::{info_message, success_message};
+
+use super::switch::{execute_switch, plan_switch};
+use crate::commands::approve_hooks;
+use crate::commands::command_executor::CommandContext;
+use crate::output;
+
+/// Handle `wt path`.
+///
+/// Without a branch, prints the current worktree's path. With `create`, a
+/// branch without a worktree gets one, running `post-create` and `post-start`
+/// hooks as `wt switch` would.
+pub fn handle_path(
+    branch: Option<&str>,
+    create: bool,
+    yes: bool,
+    verify: bool,
+) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let Some(branch) = branch else {
+        output::stdout(repo.current_worktree().root()?.display().to_string())?;
+        return Ok(());
+    };
+
+    let config = WorktrunkConfig::load()?;
+    let plan = plan_switch(&repo, branch, false, None, false, None, &config)?;
+    if !plan.is_create() {
+        output::stdout(plan.worktree_path().display().to_string())?;
+        return Ok(());
+    }
+    if !create {
+        return Err(GitError::NoWorktreeFound {
+            branch: plan.branch().unwrap_or(branch).to_string(),
+        }
+        .into());
+    }
+
+    let repo_root = repo.repo_path()?;
+    let approved = verify && {
+        let ctx = CommandContext::new(
+            &repo,
+            &config,
+            plan.branch(),
+            plan.worktree_path(),
+            &repo_root,
+            yes,
+        );
+        let approved = approve_hooks(&ctx, &[HookType::PostCreate, HookType::PostStart])?;
+        if !approved {
+            output::print(info_message(
+                "Commands declined, continuing worktree creation",
+            ))?;
+        }
+        approved
+    };
+
+    let (result, branch_info) = execute_switch(&repo, plan, &config, yes, !approved, false)?;
+    let path = result.path();
+    let name = branch_info.branch.as_deref().unwrap_or(branch);
+    output::print(success_message(cformat!(
+        "Created worktree for <bold>{name}</> @ <bold>{}</>",
+        format_path_for_display(path)
+    )))?;
+    if approved {
+        let ctx = CommandContext::new(
+            &repo,
+            &config,
+            branch_info.branch.as_deref(),
+            path,
+            &repo_root,
+            yes,
+        );
+        ctx.spawn_post_start_commands(&[], Some(path))?;
+    }
+
+    output::stdout(path.display().to_string())?;
+    Ok(())
+}
@@ -1,12 +1,14 @@
 //! Worktree push operations.
 //!
-//! Push changes to target branch with safety checks.
+//! Push changes to target branch with safety checks, push the merged target to
+//! its remote, and push the current branch to its upstream (`wt push`).
 
 use color_print::cformat;
 use worktrunk::git::{GitError, Repository};
 use worktrunk::styling::{format_with_gutter, info_message, progress_message, success_message};
 
 use super::types::MergeOperations;
+use crate::commands::list::ci_status::CachedCiStatus;
 use crate::commands::repository_ext::RepositoryCliExt;
 
 /// Push changes to target branch
@@ -14,6 +16,9 @@ use crate::commands::repository_ext::RepositoryCliExt;
 /// The `operations` parameter indicates which merge operations occurred (commit, squash, rebase).
 /// Pass `None` for standalone push operations where these concepts don't apply.
 ///
+/// Standalone pushes to a protected branch (`[safety] protected-branches`) require
+/// `force`; `wt merge` is an explicit request to update the target, so it skips the check.
+///
 /// During the push stage we temporarily `git stash` non-overlapping changes in the
 /// target worktree (if present) so that concurrent edits there do not block the
 /// fast-forward. The stash is restored afterward and we bail out early if any file
@@ -22,12 +27,17 @@ pub fn handle_push(
     target: Option<&str>,
     verb: &str,
     operations: Option<MergeOperations>,
+    force: bool,
 ) -> anyhow::Result<()> {
     let repo = Repository::current()?;
 
     // Get and validate target branch (must be a branch since we're updating it)
     let target_branch = repo.require_target_branch(target)?;
 
+    if operations.is_none() && !force {
+        repo.ensure_not_protected(&target_branch, "push", Some("--force"))?;
+    }
+
     // A worktree for the target branch is optional for push:
     // - If present, we use it to check for overlapping dirty files.
     // - If absent, we skip that safety step but still allow the push (git itself is fine).
@@ -198,3 +208,190 @@ pub fn handle_push(
 
     Ok(())
 }
+
+/// Pick the remote `wt merge --push` sends the target branch to
+///
+/// Fork workflows route merge targets to `[repo] upstream-remote` when this clone
+/// has it; otherwise the primary remote. Fails early if the remote's copy of the
+/// target has commits the local target lacks, so the merge doesn't update the
+/// local branch and then have its push rejected.
+pub fn merge_push_remote(repo: &Repository, target_branch: &str) -> anyhow::Result<String> {
+    let remote = match repo.upstream_remote() {
+        Some(remote) => remote,
+        None => repo.primary_remote()?,
+    };
+
+    let remote_ref = format!("{remote}/{target_branch}");
+    if repo.ref_exists(&format!("refs/remotes/{remote_ref}"))?
+        && !repo.is_ancestor(&remote_ref, target_branch)?
+    {
+        let commits_formatted = repo
+            .run_command(&[
+                "log",
+                "--color=always",
+                "--graph",
+                "--oneline",
+                &format!("{target_branch}..{remote_ref}"),
+            ])?
+            .trim()
+            .to_string();
+
+        return Err(GitError::RemoteNotFastForward {
+            remote,
+            target_branch: target_branch.to_string(),
+            commits_formatted,
+        }
+        .into());
+    }
+
+    Ok(remote)
+}
+
+/// Push the (already fast-forwarded) local target branch to `remote`
+pub fn push_target_to_remote(
+    repo: &Repository,
+    target_branch: &str,
+    remote: &str,
+) -> anyhow::Result<()> {
+    push_to_remote(repo, target_branch, remote, target_branch, &[], remote)?;
+    crate::output::print(success_message(cformat!(
+        "Pushed <bold>{target_branch}</> to <bold>{remote}</>"
+    )))?;
+    Ok(())
+}
+
+/// Where `wt push` sends a branch, and whether that has to be recorded with `-u`.
+struct PushTarget {
+    remote: String,
+    /// Branch name on the remote
+    remote_branch: String,
+    set_upstream: bool,
+}
+
+/// Handle `wt push`: push the current branch to its upstream.
+pub fn handle_push_branch(force_with_lease: bool) -> anyhow::Result<()> {
+    let repo = Repository::current()?;
+    let branch = repo.require_current_branch("push")?;
+    push_branch(&repo, &branch, force_with_lease)
+}
+
+/// Push `branch` to its upstream, creating it on the first push.
+///
+/// Refuses to overwrite commits on the remote unless `force_with_lease`, and
+/// never force-pushes a protected branch (`[safety] protected-branches`), by
+/// either its local name or the upstream name it pushes to.
+pub fn push_branch(repo: &Repository, branch: &str, force_with_lease: bool) -> anyhow::Result<()> {
+    let PushTarget {
+        remote,
+        remote_branch,
+        set_upstream,
+    } = push_target(repo, branch)?;
+
+    if force_with_lease {
+        repo.ensure_not_protected(branch, "force-push", None)?;
+        repo.ensure_not_protected(&remote_branch, "force-push", None)?;
+    }
+
+    let upstream = format!("{remote}/{remote_branch}");
+    if repo.ref_exists(&format!("refs/remotes/{upstream}"))? {
+        if repo.same_commit(&upstream, branch)? {
+            crate::output::print(info_message(cformat!(
+                "<bold>{branch}</> is up to date with <bold>{upstream}</>"
+            )))?;
+            return Ok(());
+        }
+        if !force_with_lease && !repo.is_ancestor(&upstream, branch)? {
+            let commits_formatted = repo
+                .run_command(&[
+                    "log",
+                    "--color=always",
+                    "--graph",
+                    "--oneline",
+                    &format!("{branch}..{upstream}"),
+                ])?
+                .trim()
+                .to_string();
+            return Err(GitError::PushNotFastForward {
+                branch: branch.to_string(),
+                upstream,
+                commits_formatted,
+            }
+            .into());
+        }
+    }
+
+    let mut options = Vec::new();
+    if set_upstream {
+        options.push("--set-upstream");
+    }
+    if force_with_lease {
+        options.push("--force-with-lease");
+    }
+    push_to_remote(repo, branch, &remote, &remote_branch, &options, &upstream)?;
+    let note = if set_upstream {
+        " (now tracking it)"
+    } else {
+        ""
+    };
+    crate::output::print(success_message(cformat!(
+        "Pushed <bold>{branch}</> to <bold>{upstream}</>{note}"
+    )))?;
+
+    // The cached status describes the remote before this push
+    CachedCiStatus::remove(repo, branch);
+    Ok(())
+}
+
+/// The configured upstream, else a same-named branch on the primary remote.
+fn push_target(repo: &Repository, branch: &str) -> anyhow::Result<PushTarget> {
+    if let Some(remote) = repo.configured_upstream_remote(branch)? {
+        // An empty `branch.<b>.merge` names no remote branch; push to the same name
+        let remote_branch = repo
+            .get_config(&format!("branch.{branch}.merge"))?
+            .map(|merge| {
+                merge
+                    .strip_prefix("refs/heads/")
+                    .unwrap_or(&merge)
+                    .to_string()
+            })
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| branch.to_string());
+        return Ok(PushTarget {
+            remote,
+            remote_branch,
+            set_upstream: false,
+        });
+    }
+    Ok(PushTarget {
+        remote: repo.primary_remote()?,
+        remote_branch: branch.to_string(),
+        set_upstream: true,
+    })
+}
+
+/// `git push <options> <remote> <branch>:<remote_branch>`, with a progress
+/// message naming `destination`.
+fn push_to_remote(
+    repo: &Repository,
+    branch: &str,
+    remote: &str,
+    remote_branch: &str,
+    options: &[&str],
+    destination: &str,
+) -> anyhow::Result<()> {
+    crate::output::print(progress_message(cformat!(
+        "Pushing <bold>{branch}</> to <bold>{destination}</>..."
+    )))?;
+
+    let refspec = format!("{branch}:{remote_branch}");
+    let mut args = vec!["push"];
+    args.extend_from_slice(options);
+    args.extend([remote, refspec.as_str()]);
+    repo.run_command(&args)
+        .map_err(|e| GitError::RemotePushFailed {
+            remote: remote.to_string(),
+            target_branch: branch.to_string(),
+            error: e.to_string(),
+        })?;
+    Ok(())
+}
@@ -1,10 +1,14 @@
 //! Worktree remove operations.
 
+use color_print::cformat;
 use worktrunk::config::WorktrunkConfig;
-use worktrunk::git::Repository;
+use worktrunk::git::{GitError, Repository};
+use worktrunk::styling::{hint_message, info_message, warning_message};
 
 use super::types::{BranchDeletionMode, RemoveResult};
 use crate::commands::repository_ext::{RemoveTarget, RepositoryCliExt};
+use crate::output;
+use crate::output::prompt::{self, Confirm};
 
 /// Remove a worktree by branch name.
 pub fn handle_remove(
@@ -12,17 +16,30 @@ pub fn handle_remove(
     no_delete_branch: bool,
     force_delete: bool,
     force_worktree: bool,
+    force_dirty: bool,
+    unlock: bool,
     config: &WorktrunkConfig,
 ) -> anyhow::Result<RemoveResult> {
     let repo = Repository::current()?;
 
     // Progress message is shown in handle_removed_worktree_output() after pre-remove hooks run
-    repo.prepare_worktree_removal(
-        RemoveTarget::Branch(worktree_name),
-        BranchDeletionMode::from_flags(no_delete_branch, force_delete),
-        force_worktree,
-        config,
-    )
+    let prepare = |unlock| {
+        repo.prepare_worktree_removal(
+            RemoveTarget::Branch(worktree_name),
+            BranchDeletionMode::from_flags(no_delete_branch, force_delete),
+            force_worktree || force_dirty,
+            unlock,
+            config,
+        )
+    };
+    let result = match prepare(unlock) {
+        Err(e) if confirm_unlock(&e)? => prepare(true)?,
+        result => result?,
+    };
+    if force_dirty {
+        backup_dirty_worktree(&repo, &result)?;
+    }
+    Ok(result)
 }
 
 /// Handle removing the current worktree (supports detached HEAD state).
@@ -33,15 +50,86 @@ pub fn handle_remove_current(
     no_delete_branch: bool,
     force_delete: bool,
     force_worktree: bool,
+    force_dirty: bool,
+    unlock: bool,
     config: &WorktrunkConfig,
 ) -> anyhow::Result<RemoveResult> {
     let repo = Repository::current()?;
 
     // Progress message is shown in handle_removed_worktree_output() after pre-remove hooks run
-    repo.prepare_worktree_removal(
-        RemoveTarget::Current,
-        BranchDeletionMode::from_flags(no_delete_branch, force_delete),
-        force_worktree,
-        config,
-    )
+    let prepare = |unlock| {
+        repo.prepare_worktree_removal(
+            RemoveTarget::Current,
+            BranchDeletionMode::from_flags(no_delete_branch, force_delete),
+            force_worktree || force_dirty,
+            unlock,
+            config,
+        )
+    };
+    let result = match prepare(unlock) {
+        Err(e) if confirm_unlock(&e)? => prepare(true)?,
+        result => result?,
+    };
+    if force_dirty {
+        backup_dirty_worktree(&repo, &result)?;
+    }
+    Ok(result)
+}
+
+/// Offer to unlock when planning refused a removal only because the worktree
+/// is locked.
+///
+/// Planning checks the lock after everything else, so the question is only
+/// asked for a removal that would otherwise go ahead. Returns false without
+/// prompting for any other error, or when the answer can't be asked or
+/// assumed, so non-interactive removals keep refusing locked worktrees unless
+/// `WORKTRUNK_ASSUME_YES` is set.
+fn confirm_unlock(err: &anyhow::Error) -> anyhow::Result<bool> {
+    let Some(GitError::WorktreeLocked { branch, reason, .. }) = err.downcast_ref::<GitError>()
+    else {
+        return Ok(false);
+    };
+    if prompt::assumed_answer().is_none() && !prompt::is_interactive() {
+        return Ok(false);
+    }
+
+    let reason_text = match reason {
+        Some(r) if !r.is_empty() => format!(" ({r})"),
+        _ => String::new(),
+    };
+    output::flush()?;
+    output::print(warning_message(cformat!(
+        "Worktree for <bold>{branch}</> is locked{reason_text}"
+    )))?;
+    Confirm::new("Unlock and remove?", false).ask(false)
+}
+
+/// Save uncommitted changes to a backup ref before `--force-dirty` removal.
+///
+/// Runs after validation so nothing is saved for removals that would fail anyway.
+/// Clean worktrees are left alone.
+fn backup_dirty_worktree(repo: &Repository, result: &RemoveResult) -> anyhow::Result<()> {
+    let RemoveResult::RemovedWorktree {
+        worktree_path,
+        branch_name,
+        ..
+    } = result
+    else {
+        return Ok(());
+    };
+
+    let wt = repo.worktree_at(worktree_path);
+    if !wt.is_dirty()? {
+        return Ok(());
+    }
+
+    let (ref_name, sha) =
+        wt.backup_uncommitted_changes(branch_name.as_deref().unwrap_or("HEAD"))?;
+    output::print(info_message(cformat!(
+        "Backed up uncommitted changes to <bold>{sha}</> ({ref_name})"
+    )))?;
+    output::print(hint_message(cformat!(
+        "To restore, run <bright-black>git checkout {sha} -- .</> in any worktree"
+    )))?;
+    Ok(())
 }
@@ -5,7 +5,6 @@
 use std::path::{Path, PathBuf};
 
 use color_print::cformat;
-use normalize_path::NormalizePath;
 use worktrunk::config::WorktrunkConfig;
 use worktrunk::git::{GitError, Repository, ResolvedWorktree};
 
@@ -67,44 +66,7 @@ pub fn resolve_worktree_arg(
     Ok(ResolvedWorktree::BranchOnly { branch })
 }
 
-/// Compute the expected worktree path for a branch name.
-///
-/// For the default branch, returns the repo root (main worktree location).
-/// For other branches, applies the `worktree-path` template from config.
-///
-/// Uses cached values from Repository for `default_branch` and `is_bare`.
-pub fn compute_worktree_path(
-    repo: &Repository,
-    branch: &str,
-    config: &WorktrunkConfig,
-) -> anyhow::Result<PathBuf> {
-    let repo_root = repo.repo_path()?;
-    let default_branch = repo.default_branch().unwrap_or_default();
-    let is_bare = repo.is_bare()?;
-
-    // Default branch lives at repo root (main worktree), not a templated path.
-    // Exception: bare repos have no main worktree, so all branches use templated paths.
-    if !is_bare && branch == default_branch {
-        return Ok(repo_root);
-    }
-
-    let repo_name = repo_root
-        .file_name()
-        .ok_or_else(|| anyhow::anyhow!("Repository path has no filename: {}", repo_root.display()))?
-        .to_str()
-        .ok_or_else(|| {
-            anyhow::anyhow!(
-                "Repository path contains invalid UTF-8: {}",
-                repo_root.display()
-            )
-        })?;
-
-    let relative_path = config
-        .format_path(repo_name, branch, repo)
-        .map_err(|e| anyhow::anyhow!("Failed to format worktree path: {e}"))?;
-
-    Ok(repo_root.join(relative_path).normalize())
-}
+pub use worktrunk::api::compute_worktree_path;
 
 /// Check if a worktree is at its expected path based on config template.
 ///
@@ -246,10 +208,125 @@ pub(super) fn compute_clobber_backup(
     }
 }
 
+/// Maximum number of suggestions offered for an unknown branch name.
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Known branches resembling a name that didn't match exactly.
+#[derive(Debug, PartialEq, Eq)]
+pub(super) enum SimilarBranches {
+    /// Candidates containing the name (case-insensitive), shortest first
+    Containing(Vec<String>),
+    /// Candidates within a small edit distance (likely typos), closest first
+    Typos(Vec<String>),
+}
+
+/// Find branches resembling `name`, for `wt switch` did-you-mean handling.
+///
+/// Substring matches take precedence; typo matches are only considered when
+/// nothing contains the name. Returns `None` when nothing is similar.
+pub(super) fn similar_branches(name: &str, candidates: &[String]) -> Option<SimilarBranches> {
+    let needle = name.to_lowercase();
+    if needle.is_empty() {
+        return None;
+    }
+
+    let mut containing: Vec<&String> = candidates
+        .iter()
+        .filter(|c| c.to_lowercase().contains(&needle))
+        .collect();
+    if !containing.is_empty() {
+        containing.sort_by_key(|c| (c.len(), c.as_str()));
+        return Some(SimilarBranches::Containing(
+            containing
+                .into_iter()
+                .take(MAX_SUGGESTIONS)
+                .cloned()
+                .collect(),
+        ));
+    }
+
+    let max_distance = (needle.chars().count() / 3).max(1);
+    let mut typos: Vec<(usize, &String)> = candidates
+        .iter()
+        .map(|c| (edit_distance(&needle, &c.to_lowercase()), c))
+        .filter(|(d, _)| *d <= max_distance)
+        .collect();
+    if typos.is_empty() {
+        return None;
+    }
+    typos.sort();
+    Some(SimilarBranches::Typos(
+        typos
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, c)| c.clone())
+            .collect(),
+    ))
+}
+
+/// Levenshtein distance between two strings, by characters.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != *cb);
+            curr[j + 1] = substitution.min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+    prev[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_similar_branches_unique_substring() {
+        let candidates = names(&["main", "feature-login", "bugfix"]);
+        assert_eq!(
+            similar_branches("LOGIN", &candidates),
+            Some(SimilarBranches::Containing(names(&["feature-login"])))
+        );
+    }
+
+    #[test]
+    fn test_similar_branches_ambiguous_substring() {
+        let candidates = names(&["main", "feature-login-page", "feature-login"]);
+        assert_eq!(
+            similar_branches("login", &candidates),
+            Some(SimilarBranches::Containing(names(&[
+                "feature-login",
+                "feature-login-page"
+            ])))
+        );
+    }
+
+    #[test]
+    fn test_similar_branches_typos() {
+        let candidates = names(&["main", "feature", "develop"]);
+        assert_eq!(
+            similar_branches("feautre", &candidates),
+            Some(SimilarBranches::Typos(names(&["feature"])))
+        );
+        assert_eq!(similar_branches("unrelated", &candidates), None);
+        assert_eq!(similar_branches("", &candidates), None);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("main", "main"), 0);
+        assert_eq!(edit_distance("mian", "main"), 2);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
     #[test]
     fn test_generate_backup_path_with_extension() {
         // Paths with extensions: file.txt -> file.txt.bak.TIMESTAMP
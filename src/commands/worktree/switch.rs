@@ -2,20 +2,27 @@
 //!
 //! Functions for planning and executing worktree switches.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
 use color_print::cformat;
 use dunce::canonicalize;
 use worktrunk::config::WorktrunkConfig;
-use worktrunk::git::{GitError, Repository};
+use worktrunk::git::{ActivityKind, GitError, Repository};
 use worktrunk::styling::{
-    hint_message, info_message, progress_message, suggest_command, warning_message,
+    eprintln, hint_message, info_message, progress_message, success_message, suggest_command,
+    warning_message,
 };
 
-use super::resolve::{compute_clobber_backup, compute_worktree_path, paths_match};
-use super::types::{CreationMethod, SwitchBranchInfo, SwitchPlan, SwitchResult};
+use super::resolve::{
+    SimilarBranches, compute_clobber_backup, compute_worktree_path, paths_match, similar_branches,
+};
+use super::types::{CreationMethod, ScaffoldTemplate, SwitchBranchInfo, SwitchPlan, SwitchResult};
+use crate::commands::OperationLock;
 use crate::commands::command_executor::CommandContext;
+use crate::commands::forge::GitHub;
+use crate::commands::step_commands::copy_dir_recursive;
+use crate::output::prompt::{Input, Response, is_interactive};
 
 /// Result of resolving the switch target.
 struct ResolvedTarget {
@@ -35,7 +42,7 @@ fn resolve_switch_target(
     create: bool,
     base: Option<&str>,
 ) -> anyhow::Result<ResolvedTarget> {
-    use worktrunk::git::pr_ref::{fetch_pr_info, fork_remote_url, local_branch_name};
+    use worktrunk::git::pr_ref::{fork_remote_url, local_branch_name};
 
     // Handle pr:<number> syntax
     if let Some(pr_number) = worktrunk::git::pr_ref::parse_pr_ref(branch) {
@@ -54,7 +61,7 @@ fn resolve_switch_target(
         )))?;
 
         let repo_root = repo.repo_path()?;
-        let pr_info = fetch_pr_info(pr_number, &repo_root)?;
+        let pr_info = GitHub.pr_info(pr_number, &repo_root)?;
 
         if pr_info.is_cross_repository {
             // Fork PR: use owner/branch naming, will need fetch + config
@@ -112,11 +119,28 @@ fn resolve_switch_target(
         }
     }
 
-    // Regular branch switch
-    let resolved_branch = repo
+    // Regular branch switch; a bare number may name a row of `wt list --index`
+    let branch = if create || base.is_some() {
+        branch.to_string()
+    } else {
+        repo.resolve_list_index(branch)?
+    };
+    let branch = branch.as_str();
+    let mut resolved_branch = repo
         .resolve_worktree_name(branch)
         .context("Failed to resolve branch name")?;
 
+    // New branches go under the project's `[branch] prefix` (symbols like `^`
+    // resolve to existing branches and are left alone)
+    if create && resolved_branch == branch {
+        resolved_branch = repo.with_branch_prefix(&resolved_branch);
+    }
+
+    // Unknown branch without --create: fall back to a similarly named branch
+    if !create && !repo.branch_exists(&resolved_branch)? {
+        resolved_branch = resolve_similar_branch(repo, &resolved_branch)?;
+    }
+
     // Resolve and validate base
     let resolved_base = if let Some(base_str) = base {
         let resolved = repo.resolve_worktree_name(base_str)?;
@@ -131,6 +155,7 @@ fn resolve_switch_target(
             }
             .into());
         } else {
+            warn_if_unprotected_base(repo, &resolved)?;
             Some(resolved)
         }
     } else {
@@ -161,6 +186,12 @@ fn resolve_switch_target(
         }
     }
 
+    // Without --base, the branch's `[[rules]]` entry can name one
+    let resolved_base = match resolved_base {
+        None if create => rule_base(repo, &resolved_branch)?,
+        base => base,
+    };
+
     // Compute base branch for creation
     let base_branch = if create {
         resolved_base.or_else(|| {
@@ -190,6 +221,154 @@ fn resolve_switch_target(
     })
 }
 
+/// Resolve a branch name that doesn't exist to a similarly named branch.
+///
+/// A single branch containing the name is used directly. Otherwise similar
+/// branches are offered in a numbered chooser when stdin is a terminal, or
+/// listed as suggestions in the error.
+fn resolve_similar_branch(repo: &Repository, name: &str) -> anyhow::Result<String> {
+    let mut candidates: Vec<String> = repo
+        .list_local_branches()?
+        .into_iter()
+        .map(|(branch, _)| branch)
+        .collect();
+    if let Ok(remote) = repo.primary_remote() {
+        let prefix = format!("{remote}/");
+        for (remote_branch, _) in repo.list_remote_branches()? {
+            if let Some(branch) = remote_branch.strip_prefix(&prefix)
+                && !candidates.iter().any(|c| c == branch)
+            {
+                candidates.push(branch.to_string());
+            }
+        }
+    }
+
+    let not_found = || GitError::InvalidReference {
+        reference: name.to_string(),
+    };
+    let suggestions = match similar_branches(name, &candidates) {
+        None => return Err(not_found().into()),
+        Some(SimilarBranches::Containing(matches)) if matches.len() == 1 => {
+            let branch = matches.into_iter().next().unwrap();
+            crate::output::print(info_message(cformat!(
+                "Branch <bold>{name}</> not found; switching to <bold>{branch}</>"
+            )))?;
+            return Ok(branch);
+        }
+        Some(SimilarBranches::Containing(matches) | SimilarBranches::Typos(matches)) => matches,
+    };
+
+    if !is_interactive() {
+        return Err(GitError::BranchNotFoundSuggestions {
+            reference: name.to_string(),
+            suggestions,
+        }
+        .into());
+    }
+
+    crate::output::print(info_message(cformat!(
+        "Branch <bold>{name}</> not found; similar branches:"
+    )))?;
+    choose_branch(suggestions)?.ok_or_else(|| not_found().into())
+}
+
+/// Pick a branch from `wt switch --history`.
+///
+/// Offers a numbered chooser when stdin is a terminal. Otherwise prints the
+/// history, most recent first, and returns `None`.
+pub fn select_from_history(repo: &Repository) -> anyhow::Result<Option<String>> {
+    let current = repo.current_worktree().branch().ok().flatten();
+    let history: Vec<String> = repo
+        .switch_history()
+        .into_iter()
+        .filter(|branch| Some(branch) != current.as_ref())
+        .collect();
+    if history.is_empty() {
+        return Err(GitError::Other {
+            message: cformat!(
+                "No previous branch found in history. Run <bright-black>wt list</> to see available worktrees."
+            ),
+        }
+        .into());
+    }
+
+    if !is_interactive() {
+        let lines: Vec<String> = history
+            .iter()
+            .enumerate()
+            .map(|(i, branch)| format!("-{}\t{branch}", i + 1))
+            .collect();
+        crate::output::stdout(lines.join("\n"))?;
+        return Ok(None);
+    }
+
+    crate::output::print(info_message("Recently visited branches:"))?;
+    choose_branch(history)
+}
+
+/// Prompt for one of `choices` by number. Returns `None` if the user cancels.
+///
+/// `--yes` picks the first (most recent) choice; `--no` cancels.
+fn choose_branch(choices: Vec<String>) -> anyhow::Result<Option<String>> {
+    crate::output::flush()?;
+    for (i, choice) in choices.iter().enumerate() {
+        eprintln!("{}", cformat!("  <bold>{}</> {choice}", i + 1));
+    }
+    let question = cformat!(
+        "Switch to <bold>[1-{}]</>, or Enter to cancel:",
+        choices.len()
+    );
+    let index = match Input::new(question).ask(false)? {
+        Response::Text(text) => text.parse::<usize>().ok().and_then(|n| n.checked_sub(1)),
+        Response::Yes => Some(0),
+        Response::No | Response::NotInteractive => None,
+    };
+    Ok(index.and_then(|i| choices.into_iter().nth(i)))
+}
+
+/// The base from the first `[[rules]]` entry matching a new branch, if any.
+fn rule_base(repo: &Repository, branch: &str) -> anyhow::Result<Option<String>> {
+    let Some(base) = repo
+        .load_project_config()?
+        .and_then(|config| config.rule_for(branch)?.base.clone())
+    else {
+        return Ok(None);
+    };
+    let resolved = repo.resolve_worktree_name(&base)?;
+    if !repo.ref_exists(&resolved)? {
+        return Err(GitError::InvalidReference {
+            reference: resolved,
+        }
+        .into());
+    }
+    crate::output::print(info_message(cformat!(
+        "Creating from <bold>{resolved}</>, the <bright-black>[[rules]]</> base for <bold>{branch}</>"
+    )))?;
+    Ok(Some(resolved))
+}
+
+/// Warn when `--base` is neither the default branch nor a protected branch.
+///
+/// Only active when the project configures `[safety] protected-branches` — those
+/// projects branch from a known set of bases, so anything else (often `--base=@`
+/// from the wrong worktree) is worth flagging.
+fn warn_if_unprotected_base(repo: &Repository, base: &str) -> anyhow::Result<()> {
+    let Some(safety) = repo.load_project_config()?.and_then(|c| c.safety) else {
+        return Ok(());
+    };
+    if safety.protected_branches.is_empty()
+        || safety.is_protected(base)
+        || repo.default_branch().as_deref() == Some(base)
+    {
+        return Ok(());
+    }
+
+    crate::output::print(warning_message(cformat!(
+        "Creating from <bold>{base}</>, which is neither the default branch nor a protected branch"
+    )))?;
+    Ok(())
+}
+
 /// Check if branch already has a worktree.
 ///
 /// Returns `Some(Existing)` if worktree exists and is valid.
@@ -204,7 +383,7 @@ fn check_existing_worktree(
     match repo.worktree_for_branch(branch)? {
         Some(existing_path) if existing_path.exists() => Ok(Some(SwitchPlan::Existing {
             path: canonicalize(&existing_path).unwrap_or(existing_path),
-            branch: branch.to_string(),
+            branch: Some(branch.to_string()),
             expected_path: expected_path.to_path_buf(),
             new_previous,
         })),
@@ -216,6 +395,20 @@ fn check_existing_worktree(
     }
 }
 
+/// The detached worktree whose directory is named `name`.
+///
+/// Branches come first: returns `None` when `name` is also a local branch.
+fn detached_worktree_named(repo: &Repository, name: &str) -> anyhow::Result<Option<PathBuf>> {
+    if repo.local_branch_exists(name)? {
+        return Ok(None);
+    }
+    Ok(repo
+        .list_worktrees()?
+        .into_iter()
+        .find(|wt| wt.detached && wt.path.file_name().is_some_and(|dir| dir == name))
+        .map(|wt| canonicalize(&wt.path).unwrap_or(wt.path)))
+}
+
 /// Validate that we can create a worktree at the given path.
 ///
 /// Checks:
@@ -320,6 +513,78 @@ fn setup_fork_pr_branch(
     Ok(())
 }
 
+/// Look up `[templates.<name>]` for `--from-template`.
+///
+/// The template path is relative to the current worktree's root, where the
+/// project config was read from.
+fn resolve_template(repo: &Repository, name: &str) -> anyhow::Result<ScaffoldTemplate> {
+    let project_config = repo.load_project_config()?.unwrap_or_default();
+    let Some(template) = project_config.template(name) else {
+        let available: Vec<&str> = project_config
+            .templates
+            .keys()
+            .map(String::as_str)
+            .collect();
+        let message = if available.is_empty() {
+            format!("No template named {name}; .config/wt.toml defines no [templates]")
+        } else {
+            format!(
+                "No template named {name}; available: {}",
+                available.join(", ")
+            )
+        };
+        return Err(GitError::Other { message }.into());
+    };
+
+    let dir = repo.current_worktree().root()?.join(&template.path);
+    if !dir.is_dir() {
+        return Err(GitError::Other {
+            message: format!(
+                "Template {name} directory not found: {}",
+                worktrunk::path::format_path_for_display(&dir)
+            ),
+        }
+        .into());
+    }
+
+    Ok(ScaffoldTemplate {
+        name: name.to_string(),
+        dir,
+        message: template
+            .message
+            .clone()
+            .unwrap_or_else(|| format!("Scaffold from template {name}")),
+    })
+}
+
+/// Copy a template into a new worktree and commit it as the branch's first commit.
+///
+/// Files the branch already has are left alone; if the template adds nothing,
+/// no commit is made.
+fn apply_template(
+    repo: &Repository,
+    worktree_path: &Path,
+    template: &ScaffoldTemplate,
+) -> anyhow::Result<()> {
+    copy_dir_recursive(&template.dir, worktree_path)
+        .with_context(|| format!("Failed to copy template {}", template.name))?;
+
+    let worktree = repo.worktree_at(worktree_path);
+    worktree.run_command(&["add", "-A"])?;
+    let name = &template.name;
+    if !worktree.has_staged_changes()? {
+        crate::output::print(info_message(cformat!(
+            "Template <bold>{name}</> adds no new files; nothing to commit"
+        )))?;
+        return Ok(());
+    }
+    worktree.commit(&template.message, false, false)?;
+    crate::output::print(success_message(cformat!(
+        "Committed scaffold from template <bold>{name}</>"
+    )))?;
+    Ok(())
+}
+
 /// Validate and plan a switch operation.
 ///
 /// This performs all validation upfront, returning a `SwitchPlan` that can be
@@ -328,17 +593,30 @@ fn setup_fork_pr_branch(
 ///
 /// Warnings (remote branch shadow, --base without --create, invalid default branch)
 /// are printed during planning since they're informational, not blocking.
+///
+/// `template` names a `[templates.<name>]` entry to scaffold a new branch from.
 pub fn plan_switch(
     repo: &Repository,
     branch: &str,
     create: bool,
     base: Option<&str>,
     clobber: bool,
+    template: Option<&str>,
     config: &WorktrunkConfig,
 ) -> anyhow::Result<SwitchPlan> {
     // Record current branch for `wt switch -` support
     let new_previous = repo.current_worktree().branch().ok().flatten();
 
+    // A detached worktree has no branch to find it by, so it's reached by directory name
+    if !create && let Some(path) = detached_worktree_named(repo, branch)? {
+        return Ok(SwitchPlan::Existing {
+            expected_path: path.clone(),
+            path,
+            branch: None,
+            new_previous,
+        });
+    }
+
     // Phase 1: Resolve target (handles pr:, validates --create/--base, may do network)
     let target = resolve_switch_target(repo, branch, create, base)?;
 
@@ -361,13 +639,19 @@ pub fn plan_switch(
         &target.method,
     )?;
 
-    // Phase 5: Return the plan
+    // Phase 5: Resolve the scaffold template, if any
+    let template = template
+        .map(|name| resolve_template(repo, name))
+        .transpose()?;
+
+    // Phase 6: Return the plan
     Ok(SwitchPlan::Create {
         branch: target.branch,
         worktree_path: expected_path,
         method: target.method,
         clobber_backup,
         new_previous,
+        template,
     })
 }
 
@@ -376,12 +660,38 @@ pub fn plan_switch(
 /// Takes a `SwitchPlan` from `plan_switch()` and executes it.
 /// For `SwitchPlan::Existing`, just records history.
 /// For `SwitchPlan::Create`, creates the worktree and runs hooks.
+/// Either way the outcome goes to the activity log for `wt history`.
+/// `wait` (`--wait`) blocks on the operation lock instead of failing.
 pub fn execute_switch(
     repo: &Repository,
     plan: SwitchPlan,
     config: &WorktrunkConfig,
     force: bool,
     no_verify: bool,
+    wait: bool,
+) -> anyhow::Result<(SwitchResult, SwitchBranchInfo)> {
+    let operation = if plan.is_create() {
+        ActivityKind::Create
+    } else {
+        ActivityKind::Switch
+    };
+    let branch = plan.branch().map(str::to_string);
+    let path = plan.worktree_path().to_path_buf();
+    let result = execute_switch_plan(repo, plan, config, force, no_verify, wait);
+    if let Err(e) = repo.record_activity(operation, branch.as_deref(), Some(&path), result.is_ok())
+    {
+        log::debug!("Failed to record {operation}: {e:#}");
+    }
+    result
+}
+
+fn execute_switch_plan(
+    repo: &Repository,
+    plan: SwitchPlan,
+    config: &WorktrunkConfig,
+    force: bool,
+    no_verify: bool,
+    wait: bool,
 ) -> anyhow::Result<(SwitchResult, SwitchBranchInfo)> {
     match plan {
         SwitchPlan::Existing {
@@ -392,7 +702,7 @@ pub fn execute_switch(
         } => {
             let _ = repo.record_switch_previous(new_previous.as_deref());
 
-            let current_dir = std::env::current_dir()
+            let current_dir = worktrunk::git::base_dir()
                 .ok()
                 .and_then(|p| canonicalize(&p).ok());
             let already_at_worktree = current_dir
@@ -427,7 +737,11 @@ pub fn execute_switch(
             method,
             clobber_backup,
             new_previous,
+            template,
         } => {
+            // Held through `git worktree add`; post-create hooks run unlocked
+            let lock = OperationLock::acquire(repo, wait)?;
+
             // Handle --clobber backup if needed (shared for all creation methods)
             if let Some(backup_path) = &clobber_backup {
                 let path_display = worktrunk::path::format_path_for_display(&worktree_path);
@@ -474,6 +788,16 @@ pub fn execute_switch(
                         .into());
                     }
 
+                    // `[switch] set-upstream`: track the remote branch before it exists,
+                    // unless git already set up tracking (e.g. a remote base branch)
+                    if *create_branch
+                        && config.switch_set_upstream()
+                        && repo.configured_upstream_remote(&branch)?.is_none()
+                        && let Ok(remote) = repo.primary_remote()
+                    {
+                        repo.set_upstream(&branch, &remote)?;
+                    }
+
                     // Report tracking info only if git's DWIM created the branch from a remote
                     let from_remote = if !create_branch && !local_branch_existed {
                         repo.upstream_branch(&branch)?
@@ -525,6 +849,20 @@ pub fn execute_switch(
                     (false, None, Some(format!("PR #{}", pr_number)))
                 }
             };
+            drop(lock);
+
+            // `[switch] exclude`: keep files that hooks generate out of `git status`.
+            // The worktree already exists, so a failure here isn't worth failing the switch.
+            if let Err(e) = repo.add_excludes(config.switch_excludes()) {
+                crate::output::print(warning_message(cformat!(
+                    "Failed to add <bright-black>[switch] exclude</> patterns: {e:#}"
+                )))?;
+            }
+
+            // Scaffold before post-create hooks, so they see the template's files
+            if let Some(template) = &template {
+                apply_template(repo, &worktree_path, template)?;
+            }
 
             // Compute base worktree path for hooks and result
             let base_worktree_path = base_branch
@@ -580,10 +918,73 @@ pub fn execute_switch(
                     from_remote,
                 },
                 SwitchBranchInfo {
-                    branch,
+                    branch: Some(branch),
                     expected_path: None,
                 },
             ))
         }
     }
 }
+
+/// Handle `wt switch --reattach <branch>`: put the current detached worktree on `branch`.
+///
+/// Creates `branch` at HEAD when it doesn't exist, and fast-forwards it when HEAD
+/// is ahead, so commits made while detached end up on the branch. Refuses when
+/// they've diverged, since switching would leave HEAD's commits unreachable.
+pub fn handle_reattach(repo: &Repository, branch: &str) -> anyhow::Result<()> {
+    let worktree = repo.current_worktree();
+    if let Some(current) = worktree.branch()? {
+        return Err(GitError::Other {
+            message: cformat!(
+                "Worktree is on <bold>{current}</>, not a detached HEAD; to move it to <bold>{branch}</>, run <bright-black>git switch {branch}</>"
+            ),
+        }
+        .into());
+    }
+
+    let message = if !repo.local_branch_exists(branch)? {
+        worktree.run_command(&["switch", "--create", branch])?;
+        cformat!("Created branch <bold>{branch}</> at HEAD")
+    } else if repo.is_ancestor("HEAD", branch)? {
+        worktree.run_command(&["switch", branch])?;
+        cformat!("Reattached to <bold>{branch}</>")
+    } else if repo.is_ancestor(branch, "HEAD")? {
+        // `git switch -C` resets the branch, which is safe here: it only moves forward
+        worktree.run_command(&["switch", "--force-create", branch])?;
+        cformat!("Reattached to <bold>{branch}</>, fast-forwarded to HEAD")
+    } else {
+        return Err(GitError::Other {
+            message: cformat!(
+                "HEAD and <bold>{branch}</> have diverged; reattaching would leave HEAD's commits behind. To keep them on a new branch, run <bright-black>wt switch --reattach <<new-branch>></>"
+            ),
+        }
+        .into());
+    };
+    crate::output::print(success_message(message))?;
+    Ok(())
+}
+
+/// Refuse to leave the current worktree while a git rebase or merge is in progress there.
+///
+/// Switching to `target` would leave the operation half-done out of sight. Staying
+/// in the same worktree is fine.
+pub fn ensure_no_pending_operation(repo: &Repository, target: &Path) -> anyhow::Result<()> {
+    let worktree = repo.current_worktree();
+    let Ok(root) = worktree.root() else {
+        return Ok(());
+    };
+    if paths_match(&root, target) {
+        return Ok(());
+    }
+    let Some(operation) = worktree.pending_operation()? else {
+        return Ok(());
+    };
+    Err(GitError::GitOperationPending {
+        operation,
+        action: "switch away".into(),
+        branch: worktree
+            .rebasing_branch()
+            .or_else(|| worktree.branch().ok().flatten()),
+    }
+    .into())
+}
@@ -47,8 +47,8 @@ impl SwitchResult {
 /// Branch state for a switch operation.
 #[derive(Debug, Clone)]
 pub struct SwitchBranchInfo {
-    /// The branch being switched to
-    pub branch: String,
+    /// The branch being switched to (None for a detached worktree)
+    pub branch: Option<String>,
     /// Expected path when there's a branch-worktree mismatch (None = path matches template)
     pub expected_path: Option<PathBuf>,
 }
@@ -71,6 +71,16 @@ pub enum CreationMethod {
     },
 }
 
+/// A `[templates.<name>]` entry resolved for `--from-template`.
+#[derive(Debug)]
+pub struct ScaffoldTemplate {
+    pub name: String,
+    /// Absolute path to the template directory (validated to exist)
+    pub dir: PathBuf,
+    /// Message for the scaffold commit
+    pub message: String,
+}
+
 /// Validated plan for a switch operation.
 ///
 /// Created by `plan_switch()`, consumed by `execute_switch()`.
@@ -81,7 +91,8 @@ pub enum SwitchPlan {
     /// Branch already has a worktree - just switch to it (no git commands needed)
     Existing {
         path: PathBuf,
-        branch: String,
+        /// None for a detached worktree, reached by its directory name
+        branch: Option<String>,
         /// Expected path for mismatch detection
         expected_path: PathBuf,
         /// Branch to record as "previous" for `wt switch -`
@@ -97,6 +108,8 @@ pub enum SwitchPlan {
         clobber_backup: Option<PathBuf>,
         /// Branch to record as "previous" for `wt switch -`
         new_previous: Option<String>,
+        /// Scaffold to commit into the new branch (`--from-template`)
+        template: Option<ScaffoldTemplate>,
     },
 }
 
@@ -109,10 +122,11 @@ impl SwitchPlan {
         }
     }
 
-    /// Get the branch name for this plan.
-    pub fn branch(&self) -> &str {
+    /// Get the branch name for this plan (None for a detached worktree).
+    pub fn branch(&self) -> Option<&str> {
         match self {
-            SwitchPlan::Existing { branch, .. } | SwitchPlan::Create { branch, .. } => branch,
+            SwitchPlan::Existing { branch, .. } => branch.as_deref(),
+            SwitchPlan::Create { branch, .. } => Some(branch),
         }
     }
 
@@ -182,6 +196,9 @@ pub enum RemoveResult {
         /// Expected path based on config template. `Some` when actual path differs
         /// from expected (path mismatch), `None` when path matches template.
         expected_path: Option<PathBuf>,
+        /// The worktree is locked and removal was confirmed (`--unlock`);
+        /// unlocked just before it's removed, so a failed removal keeps the lock.
+        unlock: bool,
     },
     /// Branch exists but has no worktree - attempt branch deletion only
     BranchOnly {
@@ -310,6 +327,7 @@ mod tests {
             integration_reason: Some(worktrunk::git::IntegrationReason::SameCommit),
             force_worktree: false,
             expected_path: None,
+            unlock: false,
         };
         match result {
             RemoveResult::RemovedWorktree {
@@ -322,6 +340,7 @@ mod tests {
                 integration_reason,
                 force_worktree,
                 expected_path,
+                unlock,
             } => {
                 assert_eq!(main_path.to_str().unwrap(), "/main");
                 assert_eq!(worktree_path.to_str().unwrap(), "/worktree");
@@ -333,6 +352,7 @@ mod tests {
                 assert!(integration_reason.is_some());
                 assert!(!force_worktree);
                 assert!(expected_path.is_none());
+                assert!(!unlock);
             }
             _ => panic!("Expected RemovedWorktree variant"),
         }
@@ -369,6 +389,7 @@ mod tests {
             integration_reason: None, // Force delete skips integration check
             force_worktree: true,
             expected_path: None,
+            unlock: false,
         };
         match result {
             RemoveResult::RemovedWorktree {
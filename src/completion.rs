@@ -5,11 +5,12 @@ use std::io::Write;
 use clap::Command;
 use clap_complete::engine::{ArgValueCompleter, CompletionCandidate, ValueCompleter};
 use clap_complete::env::CompleteEnv;
+use strum::IntoEnumIterator;
 
 use crate::cli;
 use crate::display::format_relative_time_short;
 use worktrunk::config::{ProjectConfig, WorktrunkConfig};
-use worktrunk::git::{BranchCategory, HookType, Repository};
+use worktrunk::git::{BranchCategory, CompletionBranch, HookType, Repository};
 
 /// Deprecated args that should never appear in completions.
 /// These are hidden from help AND completions, unlike other hidden args
@@ -38,7 +39,7 @@ pub(crate) fn maybe_handle_env_completion() -> bool {
         .unwrap_or(args.len());
     args.drain(0..escape_index);
 
-    let current_dir = std::env::current_dir().ok();
+    let current_dir = worktrunk::git::base_dir().ok();
 
     // If no args after `--`, output the shell registration script
     if args.is_empty() {
@@ -188,24 +189,37 @@ pub(crate) fn worktree_only_completer() -> ArgValueCompleter {
     })
 }
 
-/// Hook command name completion for `wt step <hook-type> <name>`.
+/// Hook command name completion for `wt hook <hook-type> <name>`.
 /// Completes with command names from the project config for the hook type being invoked.
 pub(crate) fn hook_command_name_completer() -> ArgValueCompleter {
-    ArgValueCompleter::new(HookCommandCompleter)
+    ArgValueCompleter::new(SourceCompleter(complete_hook_commands))
 }
 
+/// Hint name completion for `wt config state hints clear <name>`.
+/// Completes with hints already shown in this repository.
+pub(crate) fn hint_name_completer() -> ArgValueCompleter {
+    ArgValueCompleter::new(SourceCompleter(complete_hint_names))
+}
+
+/// Marker completion for `wt config state marker set <value>`.
+/// Completes with markers already set on other branches, so they're easy to reuse.
+pub(crate) fn marker_completer() -> ArgValueCompleter {
+    ArgValueCompleter::new(SourceCompleter(complete_markers))
+}
+
+/// Completer for arguments whose candidates come from a single source function.
 #[derive(Clone, Copy)]
-struct HookCommandCompleter;
+struct SourceCompleter(fn() -> Vec<CompletionCandidate>);
 
-impl ValueCompleter for HookCommandCompleter {
+impl ValueCompleter for SourceCompleter {
     fn complete(&self, current: &OsStr) -> Vec<CompletionCandidate> {
-        // If user is typing an option (starts with -), don't suggest command names
+        // If user is typing an option (starts with -), don't suggest values
         if current.to_str().is_some_and(|s| s.starts_with('-')) {
             return Vec::new();
         }
 
         let prefix = current.to_string_lossy();
-        complete_hook_commands()
+        (self.0)()
             .into_iter()
             .filter(|candidate| {
                 candidate
@@ -217,31 +231,36 @@ impl ValueCompleter for HookCommandCompleter {
     }
 }
 
+fn complete_hint_names() -> Vec<CompletionCandidate> {
+    let Ok(repo) = Repository::current() else {
+        return Vec::new();
+    };
+    repo.list_shown_hints()
+        .into_iter()
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+fn complete_markers() -> Vec<CompletionCandidate> {
+    let Ok(repo) = Repository::current() else {
+        return Vec::new();
+    };
+    repo.markers()
+        .into_iter()
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
 fn complete_hook_commands() -> Vec<CompletionCandidate> {
     // Get the hook type from the command line context
     let hook_type = CONTEXT.with(|ctx| {
         ctx.borrow().as_ref().and_then(|ctx| {
             // Look for the hook subcommand in the args
-            for hook in &[
-                "post-create",
-                "post-start",
-                "pre-commit",
-                "pre-merge",
-                "post-merge",
-                "pre-remove",
-            ] {
-                if ctx.contains(hook) {
-                    return Some(*hook);
-                }
-            }
-            None
+            HookType::iter().find(|hook| ctx.contains(&hook.to_string()))
         })
     });
 
-    let Some(hook_type_str) = hook_type else {
-        return Vec::new();
-    };
-    let Ok(hook_type) = hook_type_str.parse::<HookType>() else {
+    let Some(hook_type) = hook_type else {
         return Vec::new();
     };
 
@@ -292,8 +311,14 @@ impl ValueCompleter for BranchCompleter {
             return Vec::new();
         }
 
-        // Filter branches by prefix - clap doesn't filter ArgValueCompleter results
+        // Filter branches by prefix - clap doesn't filter ArgValueCompleter results.
+        // The limit applies after filtering, so older branches are still reachable
+        // by typing more of their name.
         let prefix = current.to_string_lossy();
+        let limit = WorktrunkConfig::load()
+            .unwrap_or_default()
+            .completion_limit()
+            .unwrap_or(usize::MAX);
         complete_branches(
             self.suppress_with_create,
             self.exclude_remote_only,
@@ -306,6 +331,7 @@ impl ValueCompleter for BranchCompleter {
                 .to_string_lossy()
                 .starts_with(&*prefix)
         })
+        .take(limit)
         .collect()
     }
 }
@@ -319,7 +345,10 @@ fn complete_branches(
         return Vec::new();
     }
 
-    let branches = match Repository::current().and_then(|repo| repo.branches_for_completion()) {
+    let Ok(repo) = Repository::current() else {
+        return Vec::new();
+    };
+    let branches = match repo.branches_for_completion() {
         Ok(b) => b,
         Err(_) => return Vec::new(),
     };
@@ -328,6 +357,23 @@ fn complete_branches(
         return Vec::new();
     }
 
+    // Offer prefixed branches by their short name; `resolve_worktree_name` maps it back.
+    // Remote-only branches and names that would collide keep the full name.
+    let prefix = repo.branch_display_prefix();
+    let names: std::collections::HashSet<String> =
+        branches.iter().map(|branch| branch.name.clone()).collect();
+    let display_name = |branch: &CompletionBranch| -> String {
+        if matches!(branch.category, BranchCategory::Remote(_)) {
+            return branch.name.clone();
+        }
+        prefix
+            .as_deref()
+            .and_then(|prefix| branch.name.strip_prefix(prefix))
+            .filter(|short| !short.is_empty() && !names.contains(*short))
+            .unwrap_or(branch.name.as_str())
+            .to_string()
+    };
+
     branches
         .into_iter()
         .filter(|branch| {
@@ -340,13 +386,14 @@ fn complete_branches(
             }
         })
         .map(|branch| {
+            let name = display_name(&branch);
             let time_str = format_relative_time_short(branch.timestamp);
             let help = match branch.category {
                 BranchCategory::Worktree => format!("+ {}", time_str),
                 BranchCategory::Local => format!("/ {}", time_str),
                 BranchCategory::Remote(remote) => format!("⇣ {} {}", time_str, remote),
             };
-            CompletionCandidate::new(branch.name).help(Some(help.into()))
+            CompletionCandidate::new(name).help(Some(help.into()))
         })
         .collect()
 }
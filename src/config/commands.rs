@@ -6,6 +6,8 @@
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
+use super::project::wildcard_match;
+
 /// Represents a command with its template and optionally expanded form
 #[derive(Debug, Clone, PartialEq)]
 pub struct Command {
@@ -15,6 +17,9 @@ pub struct Command {
     pub template: String,
     /// Expanded command with variables substituted (same as template if not expanded yet)
     pub expanded: String,
+    /// Path patterns; when non-empty, the command only runs if the branch changes
+    /// a matching file. `*` matches any characters, including `/`.
+    pub paths: Vec<String>,
 }
 
 impl Command {
@@ -24,6 +29,7 @@ impl Command {
             name,
             expanded: template.clone(),
             template,
+            paths: Vec::new(),
         }
     }
 
@@ -33,8 +39,28 @@ impl Command {
             name,
             template,
             expanded,
+            paths: Vec::new(),
         }
     }
+
+    /// Restrict the command to branches that change files matching `paths`.
+    pub fn with_paths(mut self, paths: Vec<String>) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// Whether the `paths` filter lets the command run, given the files the
+    /// branch changes (`None` when they can't be determined, which runs it).
+    pub fn matches_changed_files(&self, changed: Option<&[String]>) -> bool {
+        let Some(changed) = changed.filter(|_| !self.paths.is_empty()) else {
+            return true;
+        };
+        changed.iter().any(|file| {
+            self.paths
+                .iter()
+                .any(|pattern| wildcard_match(pattern, file))
+        })
+    }
 }
 
 /// Configuration for commands - canonical representation
@@ -44,6 +70,9 @@ impl Command {
 /// - Single string: `post-create = "npm install"`
 /// - Named table: `[post-create]` followed by `install = "npm install"`
 ///
+/// A named command can also be a table with a `paths` filter:
+/// `install = { command = "npm install", paths = ["web/*"] }`.
+///
 /// **Order preservation:** Named commands preserve TOML insertion order (requires
 /// `preserve_order` feature on toml crate and IndexMap for deserialization). This
 /// allows users to control execution order explicitly.
@@ -55,6 +84,11 @@ pub struct CommandConfig {
 }
 
 impl CommandConfig {
+    /// Build a config from already-parsed commands
+    pub fn from_commands(commands: Vec<Command>) -> Self {
+        Self { commands }
+    }
+
     /// Returns the commands as a slice
     pub fn commands(&self) -> &[Command] {
         &self.commands
@@ -71,7 +105,18 @@ impl<'de> Deserialize<'de> for CommandConfig {
         #[serde(untagged)]
         enum CommandConfigToml {
             Single(String),
-            Named(IndexMap<String, String>),
+            Named(IndexMap<String, NamedCommandToml>),
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum NamedCommandToml {
+            Template(String),
+            Filtered {
+                command: String,
+                #[serde(default)]
+                paths: Vec<String>,
+            },
         }
 
         let toml = CommandConfigToml::deserialize(deserializer)?;
@@ -83,7 +128,12 @@ impl<'de> Deserialize<'de> for CommandConfig {
             CommandConfigToml::Named(map) => {
                 // IndexMap preserves insertion order from TOML
                 map.into_iter()
-                    .map(|(name, template)| Command::new(Some(name), template))
+                    .map(|(name, command)| match command {
+                        NamedCommandToml::Template(template) => Command::new(Some(name), template),
+                        NamedCommandToml::Filtered { command, paths } => {
+                            Command::new(Some(name), command).with_paths(paths)
+                        }
+                    })
                     .collect()
             }
         };
@@ -105,10 +155,24 @@ impl Serialize for CommandConfig {
         }
 
         // Serialize as named map (all commands from Named format have names)
+        #[derive(Serialize)]
+        struct FilteredCommand<'a> {
+            command: &'a str,
+            paths: &'a [String],
+        }
+
         let mut map = serializer.serialize_map(Some(self.commands.len()))?;
         for cmd in &self.commands {
             let key = cmd.name.as_ref().unwrap();
-            map.serialize_entry(key, &cmd.template)?;
+            if cmd.paths.is_empty() {
+                map.serialize_entry(key, &cmd.template)?;
+            } else {
+                let filtered = FilteredCommand {
+                    command: &cmd.template,
+                    paths: &cmd.paths,
+                };
+                map.serialize_entry(key, &filtered)?;
+            }
         }
         map.end()
     }
@@ -214,6 +278,45 @@ third = "echo 3"
         assert_eq!(commands[2].name, Some("third".to_string()));
     }
 
+    #[test]
+    fn test_deserialize_paths_filter() {
+        let toml_str = r#"
+[command]
+install = { command = "npm install", paths = ["web/*", "package.json"] }
+build = "cargo build"
+"#;
+
+        #[derive(Serialize, Deserialize)]
+        struct Wrapper {
+            command: CommandConfig,
+        }
+
+        let wrapper: Wrapper = toml::from_str(toml_str).unwrap();
+        let commands = wrapper.command.commands();
+        assert_eq!(commands[0].template, "npm install");
+        assert_eq!(commands[0].paths, vec!["web/*", "package.json"]);
+        assert!(commands[1].paths.is_empty());
+
+        let roundtrip: Wrapper = toml::from_str(&toml::to_string(&wrapper).unwrap()).unwrap();
+        assert_eq!(roundtrip.command, wrapper.command);
+    }
+
+    #[test]
+    fn test_matches_changed_files() {
+        let cmd = Command::new(Some("install".to_string()), "npm install".to_string())
+            .with_paths(vec!["web/*".to_string(), "*.lock".to_string()]);
+        let changed = |files: &[&str]| files.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+
+        assert!(cmd.matches_changed_files(Some(&changed(&["web/src/app.ts"]))));
+        assert!(cmd.matches_changed_files(Some(&changed(&["api/Cargo.lock"]))));
+        assert!(!cmd.matches_changed_files(Some(&changed(&["api/main.rs"]))));
+        assert!(!cmd.matches_changed_files(Some(&[])));
+        // Unknown changes, or no filter, always run
+        assert!(cmd.matches_changed_files(None));
+        let unfiltered = Command::new(None, "make".to_string());
+        assert!(unfiltered.matches_changed_files(Some(&[])));
+    }
+
     // ============================================================================
     // CommandConfig Serialization Tests
     // ============================================================================
@@ -1,7 +1,16 @@
-//! Deprecated template variable detection and migration
+//! Config schema versions, deprecated template variables, and migration
 //!
-//! Scans config file content for deprecated template variables and generates
-//! a migration file with replacements.
+//! A config file records its schema in the top-level `config-version` key; a
+//! file without one predates versioning and counts as version 0. Each
+//! [`Migration`] brings a file up to its version by rewriting the text, so
+//! comments and formatting survive. Two paths apply them:
+//! - Loading a config with deprecated template variables warns and writes the
+//!   migrated content to a `.new` file beside it ([`check_and_migrate`])
+//! - `wt config migrate` rewrites the file in place after backing it up
+//!   ([`write_migrated`])
+//!
+//! To rename a key or reshape a section, add a migration with the next version
+//! and bump [`CONFIG_VERSION`]; older files keep loading until they're migrated.
 //!
 //! Migration files are only written once per config file. The hint system tracks
 //! whether a migration file has been written:
@@ -11,7 +20,9 @@
 //! To regenerate a project config migration file, run `wt config state hints clear deprecated-project-config`.
 //! To regenerate a user config migration file, delete the existing `.new` file.
 
+use crate::git::GitError;
 use crate::styling::{eprintln, hint_message, warning_message};
+use anyhow::Context;
 use color_print::cformat;
 use minijinja::Environment;
 use shell_escape::escape;
@@ -140,6 +151,128 @@ pub fn replace_deprecated_vars(content: &str) -> String {
     result
 }
 
+/// Key holding a config file's schema version
+pub const CONFIG_VERSION_KEY: &str = "config-version";
+
+/// The schema version this build writes
+pub const CONFIG_VERSION: u32 = 1;
+
+/// One schema change.
+struct Migration {
+    /// The version a file is at once this has run
+    version: u32,
+    /// What changes, shown before migrating
+    description: &'static str,
+    /// Rewrites config text; returns it unchanged when there's nothing to do
+    apply: fn(&str) -> String,
+}
+
+/// Every migration, oldest first
+const MIGRATIONS: &[Migration] = &[Migration {
+    version: 1,
+    description: "Rename deprecated template variables (repo_root, worktree, main_worktree, main_worktree_path)",
+    apply: replace_deprecated_vars,
+}];
+
+/// The result of migrating a config file's content
+#[derive(Debug)]
+pub struct Migrated {
+    /// The file's version before migrating
+    pub from: u32,
+    /// Descriptions of the migrations that changed something
+    pub applied: Vec<&'static str>,
+    /// The migrated content, stamped with [`CONFIG_VERSION`]
+    pub content: String,
+}
+
+/// The `config-version` of `content`, 0 when missing or unreadable
+pub fn config_version(content: &str) -> u32 {
+    content
+        .parse::<toml::Table>()
+        .ok()
+        .and_then(|table| table.get(CONFIG_VERSION_KEY)?.as_integer())
+        .and_then(|version| u32::try_from(version).ok())
+        .unwrap_or(0)
+}
+
+/// Apply the migrations newer than the content's version.
+///
+/// Returns `Ok(None)` when none of them change anything; a file isn't
+/// rewritten just to record the version. Fails on invalid TOML, or when the
+/// file comes from a newer worktrunk than this one.
+pub fn migrate(content: &str) -> anyhow::Result<Option<Migrated>> {
+    content
+        .parse::<toml_edit::DocumentMut>()
+        .context("Failed to parse config")?;
+    let from = config_version(content);
+    if from > CONFIG_VERSION {
+        return Err(GitError::Other {
+            message: cformat!(
+                "<bold>{CONFIG_VERSION_KEY} = {from}</> is newer than this version of worktrunk supports ({CONFIG_VERSION}); upgrade worktrunk instead"
+            ),
+        }
+        .into());
+    }
+
+    let mut migrated = content.to_string();
+    let mut applied = Vec::new();
+    for migration in MIGRATIONS.iter().filter(|m| m.version > from) {
+        let next = (migration.apply)(&migrated);
+        if next != migrated {
+            applied.push(migration.description);
+            migrated = next;
+        }
+    }
+    if applied.is_empty() {
+        return Ok(None);
+    }
+
+    let mut doc: toml_edit::DocumentMut = migrated
+        .parse()
+        .context("Migration produced invalid TOML")?;
+    doc[CONFIG_VERSION_KEY] = toml_edit::value(i64::from(CONFIG_VERSION));
+    Ok(Some(Migrated {
+        from,
+        applied,
+        content: doc.to_string(),
+    }))
+}
+
+/// Where [`write_migrated`] backs up `path`: `config.toml` -> `config.toml.bak`
+pub fn backup_path(path: &Path) -> PathBuf {
+    path.with_extension(format!(
+        "{}.bak",
+        path.extension().unwrap_or_default().to_string_lossy()
+    ))
+}
+
+/// Save the original to its [`backup_path`], then replace `path` with the
+/// migrated content.
+///
+/// Both files are written to a temporary file and renamed into place, so an
+/// interrupted migration leaves either the old file or the new one, never a
+/// truncated config or backup. Returns the backup path.
+pub fn write_migrated(path: &Path, original: &str, migrated: &Migrated) -> anyhow::Result<PathBuf> {
+    let backup = backup_path(path);
+    write_atomic(&backup, original)
+        .with_context(|| format!("Failed to back up config to {}", backup.display()))?;
+    write_atomic(path, &migrated.content).context("Failed to write migrated config")?;
+    Ok(backup)
+}
+
+/// Write `content` beside `path`, then rename it over `path`.
+fn write_atomic(path: &Path, content: &str) -> std::io::Result<()> {
+    let temp = path.with_extension(format!(
+        "{}.tmp",
+        path.extension().unwrap_or_default().to_string_lossy()
+    ));
+    std::fs::write(&temp, content)
+        .and_then(|()| std::fs::rename(&temp, path))
+        .inspect_err(|_| {
+            let _ = std::fs::remove_file(&temp);
+        })
+}
+
 /// Check config content for deprecated variables and optionally create migration file
 ///
 /// If deprecated variables are found and `warn_and_migrate` is true:
@@ -223,8 +356,11 @@ pub fn check_and_migrate(
             ))
         );
     } else {
-        // Write migration file
-        let new_content = replace_deprecated_vars(content);
+        // Write migration file: the same content `wt config migrate` would write
+        let new_content = match migrate(content) {
+            Ok(Some(migrated)) => migrated.content,
+            _ => replace_deprecated_vars(content),
+        };
 
         match std::fs::write(&new_path, &new_content) {
             Ok(()) => {
@@ -564,4 +700,89 @@ approved-commands = [
         assert!(result.is_ok());
         assert!(result.unwrap());
     }
+
+    #[test]
+    fn test_config_version() {
+        assert_eq!(config_version(""), 0);
+        assert_eq!(config_version("config-version = 1\n"), 1);
+        assert_eq!(config_version("config-version = -1\n"), 0);
+        assert_eq!(config_version("not toml ["), 0);
+    }
+
+    #[test]
+    fn test_migrate_unversioned_config() {
+        let content = r#"# My settings
+worktree-path = "../{{ main_worktree }}.{{ branch | sanitize }}"
+
+[commit-generation]
+command = "llm"  # keep this comment
+"#;
+        let migrated = migrate(content).unwrap().unwrap();
+        assert_eq!(migrated.from, 0);
+        assert_eq!(migrated.applied.len(), 1);
+        assert_eq!(
+            migrated.content,
+            r#"# My settings
+worktree-path = "../{{ repo }}.{{ branch | sanitize }}"
+config-version = 1
+
+[commit-generation]
+command = "llm"  # keep this comment
+"#
+        );
+        assert_eq!(config_version(&migrated.content), CONFIG_VERSION);
+    }
+
+    #[test]
+    fn test_migrate_nothing_to_do() {
+        let content = "worktree-path = \"../{{ repo }}.{{ branch }}\"\n";
+        assert!(migrate(content).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_migrate_skips_applied_versions() {
+        // Version 1 already ran on this file, so it isn't repeated
+        let content = "config-version = 1\npost-create = \"echo {{ worktree }}\"\n";
+        assert!(migrate(content).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_migrate_newer_version() {
+        let content = format!("config-version = {}\n", CONFIG_VERSION + 1);
+        let err = migrate(&content).unwrap_err();
+        assert!(err.to_string().contains("upgrade worktrunk"));
+    }
+
+    #[test]
+    fn test_migrate_invalid_toml() {
+        assert!(migrate("[unclosed").is_err());
+    }
+
+    #[test]
+    fn test_backup_path() {
+        assert_eq!(
+            backup_path(Path::new("/a/config.toml")),
+            PathBuf::from("/a/config.toml.bak")
+        );
+        assert_eq!(
+            backup_path(Path::new(".config/wt.toml")),
+            PathBuf::from(".config/wt.toml.bak")
+        );
+    }
+
+    #[test]
+    fn test_write_migrated_keeps_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        let original = "worktree-path = \"../{{ main_worktree }}.{{ branch }}\"\n";
+        std::fs::write(&path, original).unwrap();
+
+        let migrated = migrate(original).unwrap().unwrap();
+        let backup = write_migrated(&path, original, &migrated).unwrap();
+
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), original);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), migrated.content);
+        // No temporary files left behind
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 2);
+    }
 }
@@ -11,7 +11,7 @@
 use minijinja::{Environment, Value};
 
 use crate::git::Repository;
-use crate::path::to_posix_path;
+use crate::path::{replace_invalid_filename_chars, to_posix_path};
 
 /// Known template variables available in hook commands.
 ///
@@ -32,6 +32,8 @@ pub const TEMPLATE_VARS: &[&str] = &[
     "remote",
     "remote_url",
     "upstream",
+    "compose_project",
+    "flake",
     "target",             // Added by merge/rebase hooks via extra_vars
     "base",               // Added by creation hooks via extra_vars
     "base_worktree_path", // Added by creation hooks via extra_vars
@@ -55,16 +57,29 @@ use std::collections::HashMap;
 use std::hash::{Hash, Hasher};
 
 /// Hash a string to a port in range 10000-19999.
-fn string_to_port(s: &str) -> u16 {
+pub fn string_to_port(s: &str) -> u16 {
     let mut h = std::collections::hash_map::DefaultHasher::new();
     s.hash(&mut h);
     10000 + (h.finish() % 10000) as u16
 }
 
+/// Longest name [`sanitize_branch_name`] returns, in bytes.
+///
+/// Filesystems cap a path component at 255 bytes, which a CJK branch name
+/// reaches at 85 characters; this leaves room for the rest of the component
+/// (e.g. `{{ repo }}.` in the default worktree path).
+const MAX_SANITIZED_BRANCH_BYTES: usize = 200;
+
 /// Sanitize a branch name for use in filesystem paths.
 ///
 /// Replaces path separators (`/` and `\`) with dashes to prevent directory traversal
-/// and ensure the branch name is a single path component.
+/// and ensure the branch name is a single path component. Characters that are invalid
+/// in filenames on some platform (`<>:"|?*`), control characters and invisible
+/// formatting characters (bidi overrides, zero-width spaces) become dashes too.
+/// Other Unicode, including CJK, emoji and right-to-left scripts, is kept.
+///
+/// Names longer than 200 bytes are cut at a character boundary and given a short
+/// hash suffix, so long branches that share a prefix still get distinct paths.
 ///
 /// # Examples
 /// ```
@@ -73,9 +88,24 @@ fn string_to_port(s: &str) -> u16 {
 /// assert_eq!(sanitize_branch_name("feature/foo"), "feature-foo");
 /// assert_eq!(sanitize_branch_name("user\\task"), "user-task");
 /// assert_eq!(sanitize_branch_name("simple-branch"), "simple-branch");
+/// assert_eq!(sanitize_branch_name("fix/<script>"), "fix--script-");
+/// assert_eq!(sanitize_branch_name("機能/ログイン"), "機能-ログイン");
 /// ```
 pub fn sanitize_branch_name(branch: &str) -> String {
-    branch.replace(['/', '\\'], "-")
+    let mut result = replace_invalid_filename_chars(branch);
+
+    if result.len() > MAX_SANITIZED_BRANCH_BYTES {
+        // Leave room for `-` and the 3-character hash
+        let mut cut = MAX_SANITIZED_BRANCH_BYTES - 4;
+        while !result.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        result.truncate(cut);
+        result.push('-');
+        result.push_str(&short_hash(branch));
+    }
+
+    result
 }
 
 /// Sanitize a string for use as a database identifier.
@@ -150,6 +180,32 @@ pub fn sanitize_db(s: &str) -> String {
     result
 }
 
+/// Docker Compose project name for a worktree: `{repo}-{branch}`, reduced to
+/// what Compose accepts.
+///
+/// Lowercase letters, digits, `-` and `_` are kept; anything else becomes `-`,
+/// and leading separators are dropped so the name starts with a letter or digit.
+///
+/// # Examples
+/// ```
+/// use worktrunk::config::compose_project_name;
+///
+/// assert_eq!(compose_project_name("MyApp", "feature/auth"), "myapp-feature-auth");
+/// assert_eq!(compose_project_name(".dotfiles", "main"), "dotfiles-main");
+/// ```
+pub fn compose_project_name(repo: &str, branch: &str) -> String {
+    let mut result = String::with_capacity(repo.len() + branch.len() + 1);
+    for c in format!("{repo}-{branch}").chars() {
+        let c = c.to_ascii_lowercase();
+        if c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' {
+            result.push(c);
+        } else if !result.is_empty() && !result.ends_with('-') {
+            result.push('-');
+        }
+    }
+    result.trim_end_matches('-').to_string()
+}
+
 /// Generate a 3-character hash suffix from a string.
 ///
 /// Uses base36 (0-9, a-z) for a compact representation with 46,656 unique values.
@@ -176,7 +232,7 @@ fn short_hash(s: &str) -> String {
 /// * `repo` - Repository for looking up worktree paths
 ///
 /// # Filters
-/// - `sanitize` — Replace `/` and `\` with `-` for filesystem-safe paths
+/// - `sanitize` — Replace `/`, `\` and other characters invalid in filenames with `-`
 /// - `sanitize_db` — Transform to database-safe identifier (`[a-z0-9_]`, max 63 chars)
 /// - `hash_port` — Hash to deterministic port number (10000-19999)
 ///
@@ -277,10 +333,26 @@ mod tests {
             ("///", "---"),
             ("/feature", "-feature"),
             ("feature/", "feature-"),
+            // Invalid on Windows, or invisible
+            ("fix:a|b?", "fix-a-b-"),
+            ("\"quoted\"", "-quoted-"),
+            ("tab\there", "tab-here"),
+            ("rtl\u{202E}txt", "rtl-txt"),
+            // Wide and right-to-left names are kept
+            ("機能/ログイン", "機能-ログイン"),
+            ("fix-🐛", "fix-🐛"),
+            ("תיקון/באג", "תיקון-באג"),
         ];
         for (input, expected) in cases {
             assert_eq!(sanitize_branch_name(input), expected, "input: {input}");
         }
+
+        // Long names stay under the component limit, cut on a character boundary
+        let long = "機".repeat(100);
+        let sanitized = sanitize_branch_name(&long);
+        assert!(sanitized.len() <= MAX_SANITIZED_BRANCH_BYTES, "{sanitized}");
+        assert!(sanitized.starts_with("機機機"));
+        assert_ne!(sanitized, sanitize_branch_name(&"機".repeat(101)));
     }
 
     #[test]
@@ -27,18 +27,28 @@ mod user;
 // Re-export public types
 pub use commands::{Command, CommandConfig};
 pub use deprecation::check_and_migrate as check_deprecated_vars;
-pub use deprecation::normalize_template_vars;
+pub use deprecation::{
+    CONFIG_VERSION, CONFIG_VERSION_KEY, Migrated, config_version, migrate as migrate_config,
+    normalize_template_vars, write_migrated,
+};
 pub use expansion::{
-    DEPRECATED_TEMPLATE_VARS, TEMPLATE_VARS, expand_template, sanitize_branch_name, sanitize_db,
+    DEPRECATED_TEMPLATE_VARS, TEMPLATE_VARS, compose_project_name, expand_template,
+    sanitize_branch_name, sanitize_db, string_to_port,
 };
 pub use hooks::HooksConfig;
 pub use project::{
-    ProjectCiConfig, ProjectConfig, ProjectListConfig,
-    find_unknown_keys as find_unknown_project_keys,
+    IssueMatcher, IssueRef, IssueTrackerConfig, MergeStep, NixIntegration, ProfileConfig,
+    ProjectBranchConfig, ProjectCiConfig, ProjectCommitConfig, ProjectConfig,
+    ProjectIntegrationsConfig, ProjectListConfig, ProjectMergeConfig, ProjectRepoConfig,
+    ProjectSafetyConfig, RuleConfig, TemplateConfig,
+    find_unknown_keys as find_unknown_project_keys, wildcard_match,
 };
 pub use user::{
-    CommitGenerationConfig, StageMode, UserProjectConfig, WorktrunkConfig,
-    find_unknown_keys as find_unknown_user_keys, get_config_path, set_config_path,
+    AutoIntegratedMode, ColumnMaxWidths, CommitGenerationConfig, DiffTruncation, DirenvMode,
+    GiteaConfig, ListConfig, LlmConfig, StageMode, SummaryPart, SwitchConfig, TimeUnits,
+    UpdateStrategy, UserProjectConfig, WorktrunkConfig, config_path_override,
+    find_unknown_keys as find_unknown_user_keys, format_worktree_path, get_config_path,
+    set_config_path,
 };
 
 #[cfg(test)]
@@ -388,12 +398,15 @@ task2 = "echo 'Task 2 running' > task2.txt"
     fn test_user_project_config_equality() {
         let config1 = UserProjectConfig {
             approved_commands: vec!["npm install".to_string()],
+            ..Default::default()
         };
         let config2 = UserProjectConfig {
             approved_commands: vec!["npm install".to_string()],
+            ..Default::default()
         };
         let config3 = UserProjectConfig {
             approved_commands: vec!["npm test".to_string()],
+            ..Default::default()
         };
         assert_eq!(config1, config2);
         assert_ne!(config1, config3);
@@ -406,6 +419,7 @@ task2 = "echo 'Task 2 running' > task2.txt"
             "github.com/user/repo".to_string(),
             UserProjectConfig {
                 approved_commands: vec!["npm install".to_string()],
+                ..Default::default()
             },
         );
 
@@ -716,9 +730,7 @@ squash-template-file = "~/file.txt"
             command: Some("llm".to_string()),
             args: vec!["-m".to_string(), "model".to_string()],
             template: Some("template content".to_string()),
-            template_file: None,
-            squash_template: None,
-            squash_template_file: None,
+            ..Default::default()
         };
 
         let toml = toml::to_string(&config).unwrap();
@@ -3,9 +3,11 @@
 //! Configuration that is checked into the repository and shared across all developers.
 
 use config::ConfigError;
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 
-use super::HooksConfig;
+use super::{Command, CommandConfig, HooksConfig};
+use crate::git::HookType;
 
 /// Project-level configuration for `wt list` output.
 ///
@@ -41,7 +43,7 @@ pub struct ProjectListConfig {
 ///
 /// ```toml
 /// [ci]
-/// platform = "github"  # or "gitlab"
+/// platform = "github"  # or "gitlab", "gitea", "bitbucket"
 /// ```
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct ProjectCiConfig {
@@ -52,6 +54,369 @@ pub struct ProjectCiConfig {
     pub platform: Option<String>,
 }
 
+/// Project-level remote configuration.
+///
+/// # Example
+///
+/// ```toml
+/// [repo]
+/// upstream-remote = "upstream"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProjectRepoConfig {
+    /// Remote holding the canonical repository in a fork workflow.
+    ///
+    /// The default branch is detected from this remote and `wt list` counts
+    /// ahead/behind against its copy of the default branch. Ignored in clones
+    /// without a remote by this name.
+    #[serde(default, rename = "upstream-remote")]
+    pub upstream_remote: Option<String>,
+}
+
+/// Project-level branch naming configuration.
+///
+/// # Example
+///
+/// ```toml
+/// [branch]
+/// prefix = "{user}/"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProjectBranchConfig {
+    /// Namespace prepended to branches created by `wt switch --create`.
+    ///
+    /// `{user}` is replaced with the local part of `git config user.email`
+    /// (falling back to the login name). A name that already starts with the
+    /// prefix is left alone.
+    #[serde(default)]
+    pub prefix: Option<String>,
+
+    /// Strip the prefix from branch names in completions and `wt list` (default: true)
+    #[serde(default, rename = "strip-prefix")]
+    pub strip_prefix: Option<bool>,
+}
+
+/// Project-level safety configuration.
+///
+/// Guard rails for branches that shouldn't be deleted or pushed to casually.
+///
+/// # Example
+///
+/// ```toml
+/// [safety]
+/// protected-branches = ["main", "release/*"]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProjectSafetyConfig {
+    /// Branch names or glob patterns (`*` matches any characters) that are protected.
+    ///
+    /// `wt remove` won't delete protected branches, and `wt step push` requires
+    /// `--force` to update one.
+    #[serde(default, rename = "protected-branches")]
+    pub protected_branches: Vec<String>,
+}
+
+impl ProjectSafetyConfig {
+    /// Returns true if the branch matches any protected pattern.
+    pub fn is_protected(&self, branch: &str) -> bool {
+        self.protected_branches
+            .iter()
+            .any(|pattern| wildcard_match(pattern, branch))
+    }
+}
+
+/// Project-level commit configuration.
+///
+/// # Example
+///
+/// ```toml
+/// [commit]
+/// trailers = ["Refs: {{ issue }}", "Co-authored-by: Pair Bot <bot@example.com>"]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProjectCommitConfig {
+    /// Trailers appended to generated commit messages (commit, squash, merge).
+    ///
+    /// Templates take the hook variables plus `{{ issue }}`, `{{ issue_id }}`,
+    /// and `{{ issue_url }}` from `[integrations.issues]`. A trailer whose
+    /// value expands to nothing is left out.
+    #[serde(default)]
+    pub trailers: Vec<String>,
+}
+
+/// Project-level `wt merge` configuration.
+///
+/// # Example
+///
+/// ```toml
+/// [merge]
+/// steps = ["commit", "rebase", "lint", "hooks", "push", "cleanup"]
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProjectMergeConfig {
+    /// Merge pipeline: built-in steps and `[profiles]` names, in order.
+    ///
+    /// Built-in steps left out are off by default (the CLI flags still turn
+    /// them on); profiles run where they're listed. See [`MergeStep`].
+    #[serde(default)]
+    pub steps: Option<Vec<String>>,
+}
+
+/// A step of the `wt merge` pipeline, from `[merge] steps`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeStep {
+    /// Commit uncommitted changes
+    Commit,
+    /// Squash the branch into one commit
+    Squash,
+    /// Rebase onto the target
+    Rebase,
+    /// Run the `pre-merge` hook
+    Hooks,
+    /// Fast-forward the target to the branch
+    Push,
+    /// Remove the worktree
+    Cleanup,
+    /// Run the `[profiles.<name>]` command
+    Profile(String),
+}
+
+impl MergeStep {
+    /// Built-in steps, in the order they run.
+    pub const BUILTIN: [MergeStep; 6] = [
+        Self::Commit,
+        Self::Squash,
+        Self::Rebase,
+        Self::Hooks,
+        Self::Push,
+        Self::Cleanup,
+    ];
+
+    fn builtin_index(&self) -> Option<usize> {
+        Self::BUILTIN.iter().position(|step| step == self)
+    }
+}
+
+impl std::fmt::Display for MergeStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Commit => "commit",
+            Self::Squash => "squash",
+            Self::Rebase => "rebase",
+            Self::Hooks => "hooks",
+            Self::Push => "push",
+            Self::Cleanup => "cleanup",
+            Self::Profile(name) => name,
+        })
+    }
+}
+
+/// A named command run on demand with `wt run <name>`.
+///
+/// # Example
+///
+/// ```toml
+/// [profiles.test]
+/// command = "cargo nextest run"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProfileConfig {
+    /// Command template, with the same variables as hooks.
+    pub command: String,
+}
+
+/// Scaffold content for new branches, used by `wt switch --create --from-template <name>`.
+///
+/// # Example
+///
+/// ```toml
+/// [templates.docs]
+/// path = ".config/templates/docs"
+/// message = "Scaffold docs branch"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct TemplateConfig {
+    /// Directory copied into the new worktree, relative to the repository root.
+    pub path: String,
+
+    /// Message for the scaffold commit (default: "Scaffold from template <name>").
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Policies for branches matching a pattern, from `[[rules]]`.
+///
+/// The first rule whose pattern matches the branch applies. Patterns match the
+/// full branch name, including any `[branch] prefix`.
+///
+/// # Example
+///
+/// ```toml
+/// [[rules]]
+/// pattern = "hotfix/*"
+/// base = "release"
+/// worktree-path = "../hotfixes/{{ branch | sanitize }}"
+/// post-create = "make check-release"
+/// ci = true
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct RuleConfig {
+    /// Branch name or glob pattern (`*` matches any characters).
+    pub pattern: String,
+
+    /// Base for new branches when `wt switch --create` gets no `--base`.
+    #[serde(default)]
+    pub base: Option<String>,
+
+    /// Worktree path template, in place of the user's `worktree-path`; must stay
+    /// in the directory that template puts worktrees in.
+    #[serde(default, rename = "worktree-path")]
+    pub worktree_path: Option<String>,
+
+    /// Commands run after the project's `post-create` hook.
+    #[serde(default, rename = "post-create")]
+    pub post_create: Option<CommandConfig>,
+
+    /// Commands run after the project's `post-start` hook.
+    #[serde(default, rename = "post-start")]
+    pub post_start: Option<CommandConfig>,
+
+    /// Fetch CI status in `wt list` for matching branches, as `--full` does.
+    #[serde(default)]
+    pub ci: bool,
+}
+
+impl RuleConfig {
+    /// The rule's commands for a hook; only creation hooks can be extended.
+    pub fn hook(&self, hook: HookType) -> Option<&CommandConfig> {
+        match hook {
+            HookType::PostCreate => self.post_create.as_ref(),
+            HookType::PostStart => self.post_start.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Project-level integrations with external services.
+///
+/// # Example
+///
+/// ```toml
+/// [integrations.issues]
+/// pattern = "PROJ-(\\d+)"
+/// url = "https://example.atlassian.net/browse/PROJ-{id}"
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct ProjectIntegrationsConfig {
+    /// Issue tracker linking from branch names
+    #[serde(default)]
+    pub issues: Option<IssueTrackerConfig>,
+
+    /// Set `COMPOSE_PROJECT_NAME` to `{{ compose_project }}` for hook commands,
+    /// so each worktree's `docker compose` gets its own containers and volumes
+    #[serde(default)]
+    pub compose: Option<bool>,
+
+    /// Warm the Nix environment of new worktrees with a built-in post-create command
+    #[serde(default)]
+    pub nix: Option<NixIntegration>,
+}
+
+/// Built-in post-create command for Nix projects, from `[integrations] nix`.
+///
+/// It's a fixed command, so unlike project hooks it doesn't need approval.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum NixIntegration {
+    /// `nix develop --command true`, building the flake's dev shell
+    Develop,
+    /// `devenv shell true`, building the devenv shell
+    Devenv,
+}
+
+impl NixIntegration {
+    /// The post-create command
+    pub fn command(self) -> &'static str {
+        match self {
+            Self::Develop => "nix develop --command true",
+            Self::Devenv => "devenv shell true",
+        }
+    }
+}
+
+/// Extracts issue keys from branch names and links them to a tracker.
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct IssueTrackerConfig {
+    /// Regex matched against branch names. The first capture group is the
+    /// issue `{id}`; without a capture group, the whole match is used.
+    pub pattern: String,
+
+    /// Issue URL with `{id}` replaced by the extracted ID.
+    #[serde(default)]
+    pub url: Option<String>,
+}
+
+/// An issue reference extracted from a branch name.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct IssueRef {
+    /// Matched text from the branch name (e.g., "PROJ-123")
+    pub key: String,
+    /// Extracted ID substituted into the URL (e.g., "123")
+    pub id: String,
+    /// Link to the issue, if a URL template is configured
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// Compiled form of [`IssueTrackerConfig`], built once per command.
+#[derive(Debug, Clone)]
+pub struct IssueMatcher {
+    regex: regex::Regex,
+    url: Option<String>,
+}
+
+impl IssueMatcher {
+    /// Compile the configured pattern.
+    pub fn new(config: &IssueTrackerConfig) -> Result<Self, regex::Error> {
+        Ok(Self {
+            regex: regex::Regex::new(&config.pattern)?,
+            url: config.url.clone(),
+        })
+    }
+
+    /// Extract the first issue reference from a branch name.
+    pub fn issue_for_branch(&self, branch: &str) -> Option<IssueRef> {
+        let captures = self.regex.captures(branch)?;
+        let whole = captures.get(0)?;
+        let id = captures.get(1).unwrap_or(whole).as_str();
+        Some(IssueRef {
+            key: whole.as_str().to_string(),
+            id: id.to_string(),
+            url: self.url.as_ref().map(|url| url.replace("{id}", id)),
+        })
+    }
+}
+
+/// Match `text` against a pattern where `*` matches any run of characters.
+pub fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let Some((prefix, rest)) = pattern.split_once('*') else {
+        return pattern == text;
+    };
+    let Some(mut remaining) = text.strip_prefix(prefix) else {
+        return false;
+    };
+
+    let mut parts: Vec<&str> = rest.split('*').collect();
+    let suffix = parts.pop().unwrap_or_default();
+    for part in parts {
+        match remaining.find(part) {
+            Some(idx) => remaining = &remaining[idx + part.len()..],
+            None => return false,
+        }
+    }
+    remaining.len() >= suffix.len() && remaining.ends_with(suffix)
+}
+
 impl ProjectListConfig {
     /// Returns true if any list configuration is set.
     pub fn is_configured(&self) -> bool {
@@ -64,6 +429,172 @@ impl ProjectConfig {
     pub fn ci_platform(&self) -> Option<&str> {
         self.ci.as_ref().and_then(|ci| ci.platform.as_deref())
     }
+
+    /// Upstream remote from `[repo] upstream-remote`, if configured.
+    pub fn upstream_remote(&self) -> Option<&str> {
+        self.repo
+            .as_ref()
+            .and_then(|repo| repo.upstream_remote.as_deref())
+    }
+
+    /// Branch prefix template from `[branch] prefix`, if configured.
+    pub fn branch_prefix(&self) -> Option<&str> {
+        self.branch
+            .as_ref()
+            .and_then(|branch| branch.prefix.as_deref())
+            .filter(|prefix| !prefix.is_empty())
+    }
+
+    /// Returns true if displayed branch names should omit the prefix.
+    pub fn strip_branch_prefix(&self) -> bool {
+        self.branch
+            .as_ref()
+            .and_then(|branch| branch.strip_prefix)
+            .unwrap_or(true)
+    }
+
+    /// Get the issue tracker config from `[integrations.issues]`, if configured.
+    pub fn issue_tracker(&self) -> Option<&IssueTrackerConfig> {
+        self.integrations
+            .as_ref()
+            .and_then(|integrations| integrations.issues.as_ref())
+    }
+
+    /// Whether `[integrations] compose` sets `COMPOSE_PROJECT_NAME` for hooks.
+    pub fn compose_integration(&self) -> bool {
+        self.integrations
+            .as_ref()
+            .and_then(|integrations| integrations.compose)
+            .unwrap_or(false)
+    }
+
+    /// The `[integrations] nix` command, run before the `post-create` hook.
+    pub fn nix_command(&self) -> Option<Command> {
+        let nix = self
+            .integrations
+            .as_ref()
+            .and_then(|integrations| integrations.nix)?;
+        Some(Command::new(
+            Some("nix".to_string()),
+            nix.command().to_string(),
+        ))
+    }
+
+    /// Post-create commands: the `[integrations] nix` command, if any, followed
+    /// by the `post-create` hook and the branch's rule.
+    pub fn post_create_commands(&self, branch: Option<&str>) -> Option<CommandConfig> {
+        let builtin = self.nix_command();
+        let hooks = self.hook_commands(HookType::PostCreate, branch);
+        if builtin.is_none() {
+            return hooks;
+        }
+        Some(CommandConfig::from_commands(
+            builtin
+                .into_iter()
+                .chain(hooks.iter().flat_map(|c| c.commands().iter().cloned()))
+                .collect(),
+        ))
+    }
+
+    /// The project's commands for a hook, followed by those of the branch's rule.
+    pub fn hook_commands(&self, hook: HookType, branch: Option<&str>) -> Option<CommandConfig> {
+        let rule = branch
+            .and_then(|branch| self.rule_for(branch))
+            .and_then(|rule| rule.hook(hook));
+        let Some(rule) = rule else {
+            return self.hooks.get(hook).cloned();
+        };
+        Some(CommandConfig::from_commands(
+            self.hooks
+                .get(hook)
+                .into_iter()
+                .chain(std::iter::once(rule))
+                .flat_map(|c| c.commands().iter().cloned())
+                .collect(),
+        ))
+    }
+
+    /// The command profile `name` from `[profiles.<name>]`, if defined.
+    pub fn profile(&self, name: &str) -> Option<&ProfileConfig> {
+        self.profiles.get(name)
+    }
+
+    /// The scaffold template `name` from `[templates.<name>]`, if defined.
+    pub fn template(&self, name: &str) -> Option<&TemplateConfig> {
+        self.templates.get(name)
+    }
+
+    /// The first `[[rules]]` entry whose pattern matches the branch.
+    pub fn rule_for(&self, branch: &str) -> Option<&RuleConfig> {
+        self.rules
+            .iter()
+            .find(|rule| wildcard_match(&rule.pattern, branch))
+    }
+
+    /// Trailer templates from `[commit] trailers`.
+    pub fn commit_trailers(&self) -> &[String] {
+        self.commit
+            .as_ref()
+            .map_or(&[], |commit| commit.trailers.as_slice())
+    }
+
+    /// The `[merge] steps` pipeline, validated; `None` when not configured.
+    ///
+    /// Names that aren't built-in steps must be `[profiles]`. Built-in steps
+    /// keep their relative order, `push` is required, `squash` needs `commit`,
+    /// and nothing can follow `cleanup`, which removes the worktree.
+    pub fn merge_steps(&self) -> Result<Option<Vec<MergeStep>>, ConfigError> {
+        let Some(names) = self.merge.as_ref().and_then(|merge| merge.steps.as_ref()) else {
+            return Ok(None);
+        };
+        let invalid = |message: String| ConfigError::Message(format!("[merge] steps: {message}"));
+
+        let mut steps: Vec<MergeStep> = Vec::with_capacity(names.len());
+        for name in names {
+            let step = MergeStep::BUILTIN
+                .into_iter()
+                .find(|step| step.to_string() == *name)
+                .unwrap_or_else(|| MergeStep::Profile(name.clone()));
+            if let MergeStep::Profile(name) = &step
+                && self.profile(name).is_none()
+            {
+                return Err(invalid(format!(
+                    "{name} is neither a built-in step (commit, squash, rebase, hooks, push, cleanup) nor a [profiles] name"
+                )));
+            }
+            if steps.contains(&step) {
+                return Err(invalid(format!("{name} is listed twice")));
+            }
+            if steps.contains(&MergeStep::Cleanup) {
+                return Err(invalid(format!(
+                    "{name} can't follow cleanup, which removes the worktree"
+                )));
+            }
+            if let Some(index) = step.builtin_index()
+                && let Some(later) = steps
+                    .iter()
+                    .find(|prev| prev.builtin_index().is_some_and(|i| i > index))
+            {
+                return Err(invalid(format!("{name} must come before {later}")));
+            }
+            steps.push(step);
+        }
+
+        if !steps.contains(&MergeStep::Push) {
+            return Err(invalid("push is required".to_string()));
+        }
+        if steps.contains(&MergeStep::Squash) && !steps.contains(&MergeStep::Commit) {
+            return Err(invalid("squash needs commit".to_string()));
+        }
+        Ok(Some(steps))
+    }
+
+    /// Returns true if the branch is listed in `[safety] protected-branches`.
+    pub fn is_protected_branch(&self, branch: &str) -> bool {
+        self.safety
+            .as_ref()
+            .is_some_and(|safety| safety.is_protected(branch))
+    }
 }
 
 /// Project-specific configuration with hooks.
@@ -92,10 +623,14 @@ impl ProjectConfig {
 ///
 /// # Filters
 ///
-/// - `{{ branch | sanitize }}` - Replace `/` and `\` with `-` (e.g., "feature-auth")
+/// - `{{ branch | sanitize }}` - Replace `/`, `\` and characters invalid in filenames with `-` (e.g., "feature-auth")
 /// - `{{ branch | hash_port }}` - Hash string to deterministic port (10000-19999)
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct ProjectConfig {
+    /// Schema version, for `wt config migrate`
+    #[serde(default, rename = "config-version")]
+    pub config_version: Option<u32>,
+
     /// Project hooks (same keys as user hooks, flattened at top level)
     #[serde(flatten, default)]
     pub hooks: HooksConfig,
@@ -108,6 +643,42 @@ pub struct ProjectConfig {
     #[serde(default)]
     pub ci: Option<ProjectCiConfig>,
 
+    /// Remote settings (fork workflows)
+    #[serde(default)]
+    pub repo: Option<ProjectRepoConfig>,
+
+    /// Branch naming (namespace prefix)
+    #[serde(default)]
+    pub branch: Option<ProjectBranchConfig>,
+
+    /// Safety guard rails (protected branches)
+    #[serde(default)]
+    pub safety: Option<ProjectSafetyConfig>,
+
+    /// Integrations with external services (issue trackers)
+    #[serde(default)]
+    pub integrations: Option<ProjectIntegrationsConfig>,
+
+    /// Commit message settings (trailers)
+    #[serde(default)]
+    pub commit: Option<ProjectCommitConfig>,
+
+    /// `wt merge` pipeline
+    #[serde(default)]
+    pub merge: Option<ProjectMergeConfig>,
+
+    /// Named command profiles for `wt run` (in declaration order)
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub profiles: IndexMap<String, ProfileConfig>,
+
+    /// Scaffold templates for `wt switch --create --from-template`
+    #[serde(default, skip_serializing_if = "IndexMap::is_empty")]
+    pub templates: IndexMap<String, TemplateConfig>,
+
+    /// Per-branch policies (in declaration order; the first match applies)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<RuleConfig>,
+
     /// Captures unknown fields for validation warnings
     #[serde(flatten, default, skip_serializing)]
     unknown: std::collections::HashMap<String, toml::Value>,
@@ -354,6 +925,253 @@ platform = "gitlab"
         assert!(config.platform.is_none());
     }
 
+    // ============================================================================
+    // SafetyConfig Tests
+    // ============================================================================
+
+    #[test]
+    fn test_deserialize_upstream_remote() {
+        let config: ProjectConfig =
+            toml::from_str("[repo]\nupstream-remote = \"upstream\"\n").unwrap();
+        assert_eq!(config.upstream_remote(), Some("upstream"));
+        assert_eq!(ProjectConfig::default().upstream_remote(), None);
+    }
+
+    #[test]
+    fn test_deserialize_branch_prefix() {
+        let config: ProjectConfig = toml::from_str("[branch]\nprefix = \"{user}/\"\n").unwrap();
+        assert_eq!(config.branch_prefix(), Some("{user}/"));
+        assert!(config.strip_branch_prefix());
+
+        let config: ProjectConfig =
+            toml::from_str("[branch]\nprefix = \"\"\nstrip-prefix = false\n").unwrap();
+        assert_eq!(config.branch_prefix(), None);
+        assert!(!config.strip_branch_prefix());
+    }
+
+    #[test]
+    fn test_deserialize_protected_branches() {
+        let contents = r#"
+[safety]
+protected-branches = ["main", "release/*"]
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert!(config.is_protected_branch("main"));
+        assert!(config.is_protected_branch("release/1.0"));
+        assert!(!config.is_protected_branch("feature"));
+        assert!(!config.is_protected_branch("release"));
+    }
+
+    #[test]
+    fn test_no_safety_config_protects_nothing() {
+        let config = ProjectConfig::default();
+        assert!(!config.is_protected_branch("main"));
+    }
+
+    // ============================================================================
+    // IntegrationsConfig Tests
+    // ============================================================================
+
+    #[test]
+    fn test_deserialize_issue_tracker() {
+        let contents = r#"
+[integrations.issues]
+pattern = "PROJ-(\\d+)"
+url = "https://tracker.example.com/browse/PROJ-{id}"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        let matcher = IssueMatcher::new(config.issue_tracker().unwrap()).unwrap();
+
+        let issue = matcher.issue_for_branch("feature/PROJ-123-login").unwrap();
+        assert_eq!(issue.key, "PROJ-123");
+        assert_eq!(issue.id, "123");
+        assert_eq!(
+            issue.url.as_deref(),
+            Some("https://tracker.example.com/browse/PROJ-123")
+        );
+        assert!(matcher.issue_for_branch("feature/login").is_none());
+    }
+
+    #[test]
+    fn test_deserialize_compose_integration() {
+        let config: ProjectConfig = toml::from_str("[integrations]\ncompose = true\n").unwrap();
+        assert!(config.compose_integration());
+        assert!(!ProjectConfig::default().compose_integration());
+    }
+
+    #[test]
+    fn test_post_create_commands_with_nix() {
+        let config: ProjectConfig =
+            toml::from_str("post-create = \"npm ci\"\n\n[integrations]\nnix = \"develop\"\n")
+                .unwrap();
+        let commands = config.post_create_commands(None).unwrap();
+        let templates: Vec<_> = commands.commands().iter().map(|c| &c.template).collect();
+        assert_eq!(templates, ["nix develop --command true", "npm ci"]);
+
+        let config: ProjectConfig = toml::from_str("post-create = \"npm ci\"\n").unwrap();
+        assert_eq!(config.post_create_commands(None), config.hooks.post_create);
+    }
+
+    #[test]
+    fn test_rules() {
+        let contents = r#"
+post-create = "npm ci"
+
+[[rules]]
+pattern = "hotfix/*"
+base = "release"
+worktree-path = "../hotfixes/{{ branch | sanitize }}"
+post-create = "make check-release"
+ci = true
+
+[[rules]]
+pattern = "*"
+base = "develop"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert!(find_unknown_keys(contents).is_empty());
+
+        let hotfix = config.rule_for("hotfix/login").unwrap();
+        assert_eq!(hotfix.base.as_deref(), Some("release"));
+        assert!(hotfix.ci);
+        assert_eq!(
+            config.rule_for("feature").unwrap().base.as_deref(),
+            Some("develop")
+        );
+
+        let templates = |branch| {
+            let commands = config.post_create_commands(branch).unwrap();
+            commands
+                .commands()
+                .iter()
+                .map(|c| c.template.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            templates(Some("hotfix/login")),
+            ["npm ci", "make check-release"]
+        );
+        assert_eq!(templates(Some("feature")), ["npm ci"]);
+        assert_eq!(templates(None), ["npm ci"]);
+        assert!(
+            config
+                .hook_commands(HookType::PostStart, Some("hotfix/login"))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_merge_steps() {
+        let steps = |list: &str| {
+            let contents =
+                format!("[profiles.lint]\ncommand = \"make lint\"\n\n[merge]\nsteps = {list}\n");
+            toml::from_str::<ProjectConfig>(&contents)
+                .unwrap()
+                .merge_steps()
+                .map(|steps| {
+                    steps
+                        .unwrap()
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect::<Vec<_>>()
+                })
+                .map_err(|e| e.to_string())
+        };
+
+        assert!(ProjectConfig::default().merge_steps().unwrap().is_none());
+        assert_eq!(
+            steps(r#"["commit", "rebase", "lint", "hooks", "push"]"#).unwrap(),
+            ["commit", "rebase", "lint", "hooks", "push"]
+        );
+
+        for (list, error) in [
+            (r#"["commit", "docs", "push"]"#, "docs is neither"),
+            (r#"["lint", "lint", "push"]"#, "lint is listed twice"),
+            (r#"["push", "rebase"]"#, "rebase must come before push"),
+            (
+                r#"["push", "cleanup", "lint"]"#,
+                "lint can't follow cleanup",
+            ),
+            (r#"["commit", "rebase"]"#, "push is required"),
+            (r#"["squash", "push"]"#, "squash needs commit"),
+        ] {
+            let message = steps(list).unwrap_err();
+            assert!(message.contains(error), "{list}: {message}");
+        }
+    }
+
+    #[test]
+    fn test_deserialize_profiles() {
+        let contents = r#"
+[profiles.test]
+command = "cargo nextest run"
+
+[profiles.lint]
+command = "cargo clippy"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert_eq!(config.profiles.keys().collect::<Vec<_>>(), ["test", "lint"]);
+        assert_eq!(config.profile("test").unwrap().command, "cargo nextest run");
+        assert!(config.profile("bench").is_none());
+    }
+
+    #[test]
+    fn test_deserialize_templates() {
+        let contents = r#"
+[templates.docs]
+path = ".config/templates/docs"
+message = "Start docs branch"
+
+[templates.experiment]
+path = "templates/experiment"
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        let docs = config.template("docs").unwrap();
+        assert_eq!(docs.path, ".config/templates/docs");
+        assert_eq!(docs.message.as_deref(), Some("Start docs branch"));
+        assert!(config.template("experiment").unwrap().message.is_none());
+        assert!(config.template("missing").is_none());
+    }
+
+    #[test]
+    fn test_deserialize_commit_trailers() {
+        let contents = r#"
+[commit]
+trailers = ["Refs: {{ issue }}", "Reviewed-by: Team <team@example.com>"]
+"#;
+        let config: ProjectConfig = toml::from_str(contents).unwrap();
+        assert_eq!(
+            config.commit_trailers(),
+            ["Refs: {{ issue }}", "Reviewed-by: Team <team@example.com>"]
+        );
+        assert!(ProjectConfig::default().commit_trailers().is_empty());
+    }
+
+    #[test]
+    fn test_issue_matcher_without_capture_group() {
+        let matcher = IssueMatcher::new(&IssueTrackerConfig {
+            pattern: "#[0-9]+".to_string(),
+            url: None,
+        })
+        .unwrap();
+        let issue = matcher.issue_for_branch("fix-#42").unwrap();
+        assert_eq!(issue.key, "#42");
+        assert_eq!(issue.id, "#42");
+        assert!(issue.url.is_none());
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("main", "main"));
+        assert!(!wildcard_match("main", "mainline"));
+        assert!(wildcard_match("*", "anything"));
+        assert!(wildcard_match("release/*", "release/"));
+        assert!(wildcard_match("*-stable", "1.0-stable"));
+        assert!(wildcard_match("hotfix/*/prod", "hotfix/x/y/prod"));
+        assert!(!wildcard_match("hotfix/*/prod", "hotfix/x/staging"));
+        assert!(!wildcard_match("a*a", "a"));
+    }
+
     // ============================================================================
     // find_unknown_keys Tests
     // ============================================================================
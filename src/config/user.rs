@@ -6,6 +6,7 @@ use config::{Case, Config, ConfigError, File};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::path::PathBuf;
 use std::sync::OnceLock;
+use std::time::Duration;
 
 use super::HooksConfig;
 
@@ -84,6 +85,27 @@ pub enum StageMode {
     None,
 }
 
+/// How `wt update` brings a branch up to date with the default branch
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    clap::ValueEnum,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum UpdateStrategy {
+    /// Replay the branch's commits on top (`git rebase`)
+    #[default]
+    Rebase,
+    /// Merge the default branch in (`git merge`)
+    Merge,
+}
+
 /// User-level configuration for worktree path formatting and LLM integration.
 ///
 /// This config is stored at `~/.config/worktrunk/config.toml` (or platform equivalent)
@@ -115,6 +137,9 @@ pub enum StageMode {
 /// # Per-project configuration
 /// [projects."github.com/user/repo"]
 /// approved-commands = ["npm install", "npm test"]
+///
+/// [projects."github.com/user/repo".approved-hashes]
+/// "post-create:install" = "a5f8e2c4b1d09e37"
 /// ```
 ///
 /// Config file location:
@@ -126,6 +151,14 @@ pub enum StageMode {
 /// `__` separator for nested fields (e.g., `WORKTRUNK_COMMIT_GENERATION__COMMAND`).
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct WorktrunkConfig {
+    /// Schema version, for `wt config migrate`
+    #[serde(
+        rename = "config-version",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub config_version: Option<u32>,
+
     #[serde(
         rename = "worktree-path",
         default,
@@ -136,6 +169,10 @@ pub struct WorktrunkConfig {
     #[serde(default, rename = "commit-generation")]
     pub commit_generation: CommitGenerationConfig,
 
+    /// Master switch for LLM features
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llm: Option<LlmConfig>,
+
     /// Per-project configuration (approved commands, etc.)
     /// Uses BTreeMap for deterministic serialization order and better diff readability
     #[serde(default)]
@@ -153,10 +190,38 @@ pub struct WorktrunkConfig {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub merge: Option<MergeConfig>,
 
+    /// Configuration for the `wt update` command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub update: Option<UpdateConfig>,
+
+    /// What happens after worktrunk fetches the remote
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fetch: Option<FetchConfig>,
+
     /// Configuration for the `wt select` command
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub select: Option<SelectConfig>,
 
+    /// Configuration for `wt remove` (also used by `wt merge`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remove: Option<RemoveConfig>,
+
+    /// Configuration for the `wt switch` command
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub switch: Option<SwitchConfig>,
+
+    /// Integrations with other tools on the user's machine
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub integrations: Option<IntegrationsConfig>,
+
+    /// A self-hosted Gitea or Forgejo instance for PR and CI status
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gitea: Option<GiteaConfig>,
+
+    /// Command aliases: `ls = "list --branches --full"` makes `wt ls` run the expansion
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub alias: std::collections::BTreeMap<String, String>,
+
     // =========================================================================
     // User-level hooks (same syntax as project hooks, run before project hooks)
     // =========================================================================
@@ -207,15 +272,37 @@ pub struct CommitGenerationConfig {
     /// Supports tilde expansion (e.g., "~/.config/worktrunk/squash-template.txt")
     #[serde(default, rename = "squash-template-file")]
     pub squash_template_file: Option<String>,
+
+    /// Set from `[llm] enabled = false`; the command is then never run
+    #[serde(skip)]
+    pub disabled: bool,
+
+    /// Set from `[llm] max-diff-bytes`
+    #[serde(skip)]
+    pub max_diff_bytes: Option<usize>,
+
+    /// Set from `[llm] diff-truncation`
+    #[serde(skip)]
+    pub diff_truncation: DiffTruncation,
+
+    /// Set from `[llm] redact`
+    #[serde(skip)]
+    pub redact: Vec<String>,
+
+    /// Appended to the prompt when regenerating a reviewed message
+    #[serde(skip)]
+    pub feedback: Option<String>,
 }
 
 impl CommitGenerationConfig {
-    /// Returns true if an LLM command is configured
+    /// Returns true if an LLM command is configured and LLM features are enabled
     pub fn is_configured(&self) -> bool {
-        self.command
-            .as_ref()
-            .map(|s| !s.trim().is_empty())
-            .unwrap_or(false)
+        !self.disabled
+            && self
+                .command
+                .as_ref()
+                .map(|s| !s.trim().is_empty())
+                .unwrap_or(false)
     }
 }
 
@@ -244,6 +331,68 @@ pub struct UserProjectConfig {
         skip_serializing_if = "Vec::is_empty"
     )]
     pub approved_commands: Vec<String>,
+
+    /// Hash of the approved text for each command, keyed by `hook-type:name`
+    ///
+    /// Detects when a previously-approved command's text changes, so the
+    /// approval prompt can say so and the superseded text can be dropped.
+    #[serde(
+        default,
+        rename = "approved-hashes",
+        skip_serializing_if = "std::collections::BTreeMap::is_empty"
+    )]
+    pub approved_hashes: std::collections::BTreeMap<String, String>,
+}
+
+impl UserProjectConfig {
+    /// Record an approval for the command identified by `key`.
+    ///
+    /// If `key` was approved with different text, the old text is removed
+    /// unless another command still uses it.
+    pub fn record_approval(&mut self, key: &str, command: &str) {
+        let hash = command_hash(command);
+        if let Some(old) = self.approved_hashes.insert(key.to_string(), hash.clone())
+            && old != hash
+            && !self.approved_hashes.values().any(|h| *h == old)
+        {
+            self.approved_commands.retain(|c| command_hash(c) != old);
+        }
+        if !self
+            .approved_commands
+            .iter()
+            .any(|c| command_hash(c) == hash)
+        {
+            self.approved_commands.push(command.to_string());
+        }
+    }
+
+    /// Approved text whose recorded name matches `name` (`test` or `pre-merge:test`)
+    pub fn commands_named(&self, name: &str) -> Vec<&String> {
+        let hashes: Vec<&String> = self
+            .approved_hashes
+            .iter()
+            .filter(|(key, _)| {
+                key.as_str() == name || key.split_once(':').is_some_and(|(_, n)| n == name)
+            })
+            .map(|(_, hash)| hash)
+            .collect();
+        self.approved_commands
+            .iter()
+            .filter(|c| hashes.contains(&&command_hash(c)))
+            .collect()
+    }
+}
+
+/// Stable hash of a command template, after normalizing deprecated variable names.
+///
+/// FNV-1a rather than `DefaultHasher`, whose output may change between Rust
+/// releases — these hashes are persisted in user config.
+pub fn command_hash(command: &str) -> String {
+    let normalized = super::deprecation::normalize_template_vars(command);
+    let hash = normalized.bytes().fold(0xcbf29ce484222325_u64, |h, b| {
+        (h ^ u64::from(b)).wrapping_mul(0x100000001b3)
+    });
+    format!("{hash:016x}")
 }
 
 /// Configuration for the `wt list` command
@@ -261,12 +410,105 @@ pub struct ListConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub remotes: Option<bool>,
 
+    /// Number rows for `wt switch N` by default
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<bool>,
+
     /// (Experimental) Per-task timeout in milliseconds.
     /// When set to a positive value, git operations that exceed this timeout are terminated.
     /// Timed-out tasks show defaults in the table. Set to 0 to explicitly disable timeout
     /// (useful to override a global setting). Disabled when --full is used.
     #[serde(rename = "timeout-ms", skip_serializing_if = "Option::is_none")]
     pub timeout_ms: Option<u64>,
+
+    /// Metrics shown in the summary line after the worktree count
+    /// (default: dirty, ahead). An empty list shows only the counts.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<Vec<SummaryPart>>,
+
+    /// Maximum column widths; longer values are truncated with `…`
+    #[serde(rename = "max-width", skip_serializing_if = "Option::is_none")]
+    pub max_width: Option<ColumnMaxWidths>,
+
+    /// Terminal width below which `--layout auto` shows records (default: 60)
+    #[serde(rename = "record-below", skip_serializing_if = "Option::is_none")]
+    pub record_below: Option<usize>,
+
+    /// Serve repeated `--format=json` calls from a cache refreshed in the background (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache: Option<bool>,
+
+    /// Show commit dates instead of relative ages in the Age column
+    #[serde(rename = "absolute-time", skip_serializing_if = "Option::is_none")]
+    pub absolute_time: Option<bool>,
+
+    /// strftime pattern for absolute dates (default: `%Y-%m-%d`)
+    #[serde(rename = "time-format", skip_serializing_if = "Option::is_none")]
+    pub time_format: Option<String>,
+
+    /// Labels for relative ages, e.g. to match another language
+    #[serde(rename = "time-units", skip_serializing_if = "Option::is_none")]
+    pub time_units: Option<TimeUnits>,
+}
+
+/// Labels for relative ages in `wt list` (`[list.time-units]`)
+///
+/// Each replaces the English abbreviation after the number (`3d`); unset
+/// units keep theirs.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct TimeUnits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub now: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub future: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minute: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hour: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub week: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub month: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub year: Option<String>,
+}
+
+/// Per-column width caps for `wt list` (`[list.max-width]`)
+///
+/// Only the columns whose values vary in length can be capped.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub struct ColumnMaxWidths {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<usize>,
+}
+
+/// A metric in the `wt list` summary line
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SummaryPart {
+    /// Worktrees with uncommitted changes
+    Dirty,
+    /// Items with commits ahead of the default branch
+    Ahead,
+    /// Items far behind the default branch
+    Stale,
+    /// Items whose content is already in the default branch
+    Integrated,
+    /// Total disk usage (with `--du`)
+    Disk,
+}
+
+impl SummaryPart {
+    /// Parts shown when `summary` isn't configured
+    pub const DEFAULT: &[SummaryPart] = &[SummaryPart::Dirty, SummaryPart::Ahead];
 }
 
 /// Configuration for the `wt step commit` command
@@ -278,6 +520,23 @@ pub struct CommitConfig {
     /// Values: "all", "tracked", "none"
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stage: Option<StageMode>,
+
+    /// Add a `Signed-off-by` trailer to generated commits (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signoff: Option<bool>,
+
+    /// Run native git hooks (`core.hooksPath`) on generated commits (default: true)
+    ///
+    /// `--no-verify` skips them along with Worktrunk's own pre-commit hooks.
+    #[serde(rename = "git-hooks", skip_serializing_if = "Option::is_none")]
+    pub git_hooks: Option<bool>,
+
+    /// Offer generated messages for review before committing (default: false)
+    ///
+    /// On a terminal, each LLM message can be accepted, edited in `$EDITOR`,
+    /// or regenerated with optional guidance.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub review: Option<bool>,
 }
 
 /// Configuration for the `wt merge` command
@@ -306,6 +565,39 @@ pub struct MergeConfig {
     /// Run project hooks (default: true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub verify: Option<bool>,
+
+    /// Push the target branch to its remote after merging (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub push: Option<bool>,
+
+    /// Push the branch and wait for its CI to pass before merging (default: false)
+    #[serde(rename = "wait-ci", skip_serializing_if = "Option::is_none")]
+    pub wait_ci: Option<bool>,
+
+    /// Seconds to wait for CI before giving up (default: 1800)
+    #[serde(rename = "ci-timeout", skip_serializing_if = "Option::is_none")]
+    pub ci_timeout: Option<u64>,
+
+    /// Seconds between CI status checks (default: 15)
+    #[serde(rename = "ci-interval", skip_serializing_if = "Option::is_none")]
+    pub ci_interval: Option<u64>,
+}
+
+/// Configuration for the `wt update` command
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct UpdateConfig {
+    /// Rebase onto the default branch or merge it in (default: rebase)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strategy: Option<UpdateStrategy>,
+}
+
+/// What happens after worktrunk fetches the remote (currently in `wt update`)
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct FetchConfig {
+    /// Fast-forward clean worktrees whose branch is strictly behind the
+    /// default branch (default: false)
+    #[serde(rename = "fast-forward-clean", skip_serializing_if = "Option::is_none")]
+    pub fast_forward_clean: Option<bool>,
 }
 
 /// Configuration for the `wt select` command
@@ -321,6 +613,199 @@ pub struct SelectConfig {
     pub pager: Option<String>,
 }
 
+/// Configuration for `wt remove`
+///
+/// Also applies to the removal at the end of `wt merge`.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct RemoveConfig {
+    /// Move removed worktrees to the OS trash instead of deleting them (default: false)
+    #[serde(rename = "use-trash", skip_serializing_if = "Option::is_none")]
+    pub use_trash: Option<bool>,
+
+    /// Worktrees larger than this are deleted directly, even with `use-trash` (default: 1024)
+    ///
+    /// Moving a large tree to the trash can mean copying it on some platforms,
+    /// and fills the trash with build artifacts nobody restores.
+    #[serde(rename = "trash-max-mb", skip_serializing_if = "Option::is_none")]
+    pub trash_max_mb: Option<u64>,
+
+    /// Remove worktrees of merged branches during `wt list` and `wt switch` (default: never)
+    #[serde(rename = "auto-integrated", skip_serializing_if = "Option::is_none")]
+    pub auto_integrated: Option<AutoIntegratedMode>,
+}
+
+/// Configuration for the `wt switch` command
+///
+/// # TOML Format
+/// ```toml
+/// [switch]
+/// set-upstream = true
+/// exclude = ["*.log"]
+/// completion-limit = 100
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct SwitchConfig {
+    /// Track a same-named branch on the primary remote when `--create` makes a branch (default: false)
+    ///
+    /// The remote branch doesn't need to exist: `git push` then needs no `-u`,
+    /// and `wt list` shows the branch as not yet pushed.
+    #[serde(rename = "set-upstream", skip_serializing_if = "Option::is_none")]
+    pub set_upstream: Option<bool>,
+
+    /// Ignore patterns added to `.git/info/exclude` when a worktree is created
+    ///
+    /// For files that hooks generate in every worktree (logs, local state), so
+    /// they don't show as untracked. The file is shared by all worktrees.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exclude: Vec<String>,
+
+    /// Most branches offered when completing a branch name; 0 for no limit (default: 100)
+    ///
+    /// Worktrees come first, then local and remote-only branches by recency.
+    #[serde(rename = "completion-limit", skip_serializing_if = "Option::is_none")]
+    pub completion_limit: Option<usize>,
+}
+
+/// What `wt list` and `wt switch` do with worktrees whose branch has been
+/// merged into the default branch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AutoIntegratedMode {
+    /// Ask before removing each one
+    Prompt,
+    /// Remove them without asking
+    Auto,
+    /// Leave them for `wt remove`
+    #[default]
+    Never,
+}
+
+/// LLM features as a whole
+///
+/// With `enabled = false`, no diff or commit content is passed to an external
+/// command, even if `[commit-generation]` is configured.
+///
+/// # TOML Format
+/// ```toml
+/// [llm]
+/// enabled = false
+/// max-diff-bytes = 100000
+/// diff-truncation = "file-list"
+/// redact = ['internal\.example\.com']
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct LlmConfig {
+    /// Allow LLM-generated commit messages (default: true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+
+    /// Largest diff to put in a prompt, in bytes (default: 400000)
+    #[serde(rename = "max-diff-bytes", skip_serializing_if = "Option::is_none")]
+    pub max_diff_bytes: Option<usize>,
+
+    /// How diffs over `max-diff-bytes` are shrunk (default: per-file)
+    #[serde(rename = "diff-truncation", skip_serializing_if = "Option::is_none")]
+    pub diff_truncation: Option<DiffTruncation>,
+
+    /// Regexes to redact from diffs, on top of the built-in secret patterns
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redact: Vec<String>,
+}
+
+/// How a diff over `[llm] max-diff-bytes` is cut down for the prompt
+///
+/// Lock files go first either way. Whatever is left out is named at the end of
+/// the diff, and the diffstat still lists every file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiffTruncation {
+    /// Cap each file at 50 lines and the diff at 50 files
+    #[default]
+    PerFile,
+    /// Keep whole files while they fit; list the rest by name
+    FileList,
+}
+
+/// Integrations with other tools on the user's machine
+///
+/// # TOML Format
+/// ```toml
+/// [integrations]
+/// zoxide = true
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct IntegrationsConfig {
+    /// Register created worktrees with zoxide and unregister removed ones (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zoxide: Option<bool>,
+
+    /// What to do when a created worktree has an `.envrc` (default: warn)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direnv: Option<DirenvMode>,
+}
+
+/// Handling of `.envrc` files in worktrees created by `wt switch --create`
+///
+/// direnv blocks an `.envrc` until it's allowed, and allowances are per path,
+/// so every new worktree starts out blocked.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DirenvMode {
+    /// Run `direnv allow` in the new worktree
+    Allow,
+    /// Suggest running `direnv allow`
+    #[default]
+    Warn,
+    /// Do nothing
+    Ignore,
+}
+
+/// A Gitea or Forgejo instance
+///
+/// # TOML Format
+/// ```toml
+/// [gitea]
+/// host = "git.example.com"
+/// token = "..."
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub struct GiteaConfig {
+    /// Host whose remotes use the Gitea API, with `https://` unless a scheme is given
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
+
+    /// API token, needed for private repositories; only sent to `host`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+/// Default size limit for moving removed worktrees to the OS trash
+const DEFAULT_TRASH_MAX_MB: u64 = 1024;
+
+/// Default number of branches offered by shell completion
+const DEFAULT_COMPLETION_LIMIT: usize = 100;
+
+/// Default time `wt merge --wait-ci` waits for CI, in seconds
+const DEFAULT_CI_TIMEOUT_SECS: u64 = 30 * 60;
+
+/// Default time between CI status checks in `wt merge --wait-ci`, in seconds
+const DEFAULT_CI_INTERVAL_SECS: u64 = 15;
+
+/// Expand a `worktree-path` template for a branch (see [`WorktrunkConfig::format_path`]).
+pub fn format_worktree_path(
+    template: &str,
+    main_worktree: &str,
+    branch: &str,
+    repo: &crate::git::Repository,
+) -> Result<String, String> {
+    use std::collections::HashMap;
+    let mut vars = HashMap::new();
+    vars.insert("main_worktree", main_worktree);
+    vars.insert("repo", main_worktree);
+    vars.insert("branch", branch);
+    expand_template(template, &vars, false, repo)
+}
+
 /// Default worktree path template
 fn default_worktree_path() -> String {
     "../{{ repo }}.{{ branch | sanitize }}".to_string()
@@ -334,6 +819,124 @@ impl WorktrunkConfig {
             .unwrap_or_else(default_worktree_path)
     }
 
+    /// Returns the size limit in bytes for moving removed worktrees to the OS
+    /// trash, or `None` when `[remove] use-trash` is off.
+    pub fn remove_trash_limit(&self) -> Option<u64> {
+        let remove = self.remove.as_ref()?;
+        if !remove.use_trash.unwrap_or(false) {
+            return None;
+        }
+        let max_mb = remove.trash_max_mb.unwrap_or(DEFAULT_TRASH_MAX_MB);
+        Some(max_mb.saturating_mul(1024 * 1024))
+    }
+
+    /// Returns what to do with worktrees of merged branches (`[remove] auto-integrated`).
+    pub fn auto_integrated_mode(&self) -> AutoIntegratedMode {
+        self.remove
+            .as_ref()
+            .and_then(|r| r.auto_integrated)
+            .unwrap_or_default()
+    }
+
+    /// Returns true if branches made by `wt switch --create` should get an upstream.
+    pub fn switch_set_upstream(&self) -> bool {
+        self.switch
+            .as_ref()
+            .and_then(|s| s.set_upstream)
+            .unwrap_or(false)
+    }
+
+    /// Ignore patterns to add to `.git/info/exclude` for new worktrees.
+    pub fn switch_excludes(&self) -> &[String] {
+        self.switch
+            .as_ref()
+            .map(|s| s.exclude.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// How long `wt merge --wait-ci` waits for CI, and how often it checks
+    /// (`[merge] ci-timeout` and `ci-interval`).
+    pub fn merge_ci_timing(&self) -> (Duration, Duration) {
+        let merge = self.merge.as_ref();
+        let timeout = merge
+            .and_then(|m| m.ci_timeout)
+            .unwrap_or(DEFAULT_CI_TIMEOUT_SECS);
+        let interval = merge
+            .and_then(|m| m.ci_interval)
+            .unwrap_or(DEFAULT_CI_INTERVAL_SECS)
+            .max(1);
+        (Duration::from_secs(timeout), Duration::from_secs(interval))
+    }
+
+    /// Returns whether clean worktrees behind the default branch are
+    /// fast-forwarded after a fetch (`[fetch] fast-forward-clean`).
+    pub fn fetch_fast_forward_clean(&self) -> bool {
+        self.fetch
+            .as_ref()
+            .and_then(|f| f.fast_forward_clean)
+            .unwrap_or(false)
+    }
+
+    /// Returns how `wt update` brings branches up to date (`[update] strategy`).
+    pub fn update_strategy(&self) -> UpdateStrategy {
+        self.update
+            .as_ref()
+            .and_then(|u| u.strategy)
+            .unwrap_or_default()
+    }
+
+    /// Most branches to offer when completing a branch name, or `None` for all.
+    pub fn completion_limit(&self) -> Option<usize> {
+        let limit = self
+            .switch
+            .as_ref()
+            .and_then(|s| s.completion_limit)
+            .unwrap_or(DEFAULT_COMPLETION_LIMIT);
+        (limit > 0).then_some(limit)
+    }
+
+    /// Returns false if `[llm] enabled = false` turns off LLM features.
+    pub fn llm_enabled(&self) -> bool {
+        self.llm.as_ref().and_then(|l| l.enabled).unwrap_or(true)
+    }
+
+    /// Returns true if worktrees should be added to and removed from zoxide.
+    pub fn zoxide_enabled(&self) -> bool {
+        self.integrations
+            .as_ref()
+            .and_then(|i| i.zoxide)
+            .unwrap_or(false)
+    }
+
+    /// Returns how `.envrc` files in newly created worktrees are handled.
+    pub fn direnv_mode(&self) -> DirenvMode {
+        self.integrations
+            .as_ref()
+            .and_then(|i| i.direnv)
+            .unwrap_or_default()
+    }
+
+    /// Returns true if generated commits should carry a `Signed-off-by` trailer.
+    pub fn commit_signoff(&self) -> bool {
+        self.commit
+            .as_ref()
+            .and_then(|c| c.signoff)
+            .unwrap_or(false)
+    }
+
+    /// Returns true if generated commits should run native git hooks.
+    pub fn commit_git_hooks(&self) -> bool {
+        self.commit
+            .as_ref()
+            .and_then(|c| c.git_hooks)
+            .unwrap_or(true)
+    }
+
+    /// Returns true if generated messages should be offered for review.
+    pub fn commit_review(&self) -> bool {
+        self.commit.as_ref().and_then(|c| c.review).unwrap_or(false)
+    }
+
     /// Returns true if the user has explicitly set a custom worktree-path.
     pub fn has_custom_worktree_path(&self) -> bool {
         self.worktree_path.is_some()
@@ -390,7 +993,13 @@ impl WorktrunkConfig {
                 .convert_case(Case::Kebab),
         );
 
-        let config: Self = builder.build()?.try_deserialize()?;
+        let mut config: Self = builder.build()?.try_deserialize()?;
+        config.commit_generation.disabled = !config.llm_enabled();
+        if let Some(llm) = &config.llm {
+            config.commit_generation.max_diff_bytes = llm.max_diff_bytes;
+            config.commit_generation.diff_truncation = llm.diff_truncation.unwrap_or_default();
+            config.commit_generation.redact = llm.redact.clone();
+        }
 
         // Validate worktree path (only if explicitly set - default is always valid)
         if let Some(ref path) = config.worktree_path {
@@ -421,6 +1030,14 @@ impl WorktrunkConfig {
             ));
         }
 
+        for pattern in &config.commit_generation.redact {
+            if let Err(e) = regex::Regex::new(pattern) {
+                return Err(ConfigError::Message(format!(
+                    "llm.redact pattern {pattern:?} is invalid: {e}"
+                )));
+            }
+        }
+
         Ok(config)
     }
 
@@ -436,12 +1053,7 @@ impl WorktrunkConfig {
         branch: &str,
         repo: &crate::git::Repository,
     ) -> Result<String, String> {
-        use std::collections::HashMap;
-        let mut vars = HashMap::new();
-        vars.insert("main_worktree", main_worktree);
-        vars.insert("repo", main_worktree);
-        vars.insert("branch", branch);
-        expand_template(&self.worktree_path(), &vars, false, repo)
+        format_worktree_path(&self.worktree_path(), main_worktree, branch, repo)
     }
 
     /// Check if a command is approved for the given project.
@@ -462,6 +1074,14 @@ impl WorktrunkConfig {
             .unwrap_or(false)
     }
 
+    /// Whether the command recorded under `key` was approved with different text.
+    pub fn approval_changed(&self, project: &str, key: &str, command: &str) -> bool {
+        self.projects
+            .get(project)
+            .and_then(|p| p.approved_hashes.get(key))
+            .is_some_and(|hash| *hash != command_hash(command))
+    }
+
     /// Add an approved command and save to config file.
     ///
     /// Reloads from disk before modifying to reduce race conditions from concurrent processes.
@@ -549,6 +1169,10 @@ impl WorktrunkConfig {
             let len_before = project_config.approved_commands.len();
             project_config.approved_commands.retain(|c| c != command);
             let changed = len_before != project_config.approved_commands.len();
+            if changed {
+                let hash = command_hash(command);
+                project_config.approved_hashes.retain(|_, h| *h != hash);
+            }
 
             if project_config.approved_commands.is_empty() {
                 self.projects.remove(project);
@@ -629,6 +1253,15 @@ impl WorktrunkConfig {
         array
     }
 
+    /// Format approval hashes as a `[projects."…".approved-hashes]` table
+    fn hashes_table(hashes: &std::collections::BTreeMap<String, String>) -> toml_edit::Item {
+        let mut table = toml_edit::Table::new();
+        for (key, hash) in hashes {
+            table[key.as_str()] = toml_edit::value(hash);
+        }
+        toml_edit::Item::Table(table)
+    }
+
     /// Save the current configuration to a specific file path
     ///
     /// Use this in tests to save to a temporary location instead of the user's config.
@@ -682,6 +1315,14 @@ impl WorktrunkConfig {
                     let commands =
                         Self::format_multiline_array(project_config.approved_commands.iter());
                     projects[project_id]["approved-commands"] = toml_edit::value(commands);
+                    if project_config.approved_hashes.is_empty() {
+                        if let Some(table) = projects[project_id].as_table_mut() {
+                            table.remove("approved-hashes");
+                        }
+                    } else {
+                        projects[project_id]["approved-hashes"] =
+                            Self::hashes_table(&project_config.approved_hashes);
+                    }
                 }
             }
 
@@ -717,6 +1358,10 @@ impl WorktrunkConfig {
                     let commands =
                         Self::format_multiline_array(project_config.approved_commands.iter());
                     table["approved-commands"] = toml_edit::value(commands);
+                    if !project_config.approved_hashes.is_empty() {
+                        table["approved-hashes"] =
+                            Self::hashes_table(&project_config.approved_hashes);
+                    }
                     projects_table[project_id] = toml_edit::Item::Table(table);
                 }
                 doc["projects"] = toml_edit::Item::Table(projects_table);
@@ -732,6 +1377,18 @@ impl WorktrunkConfig {
     }
 }
 
+/// What moved the user config away from its standard location: `--config`,
+/// `WORKTRUNK_CONFIG_PATH`, or nothing
+pub fn config_path_override() -> Option<&'static str> {
+    if CONFIG_PATH.get().is_some() {
+        Some("--config")
+    } else if std::env::var_os("WORKTRUNK_CONFIG_PATH").is_some() {
+        Some("WORKTRUNK_CONFIG_PATH")
+    } else {
+        None
+    }
+}
+
 pub fn get_config_path() -> Option<PathBuf> {
     // Priority 1: CLI --config flag
     if let Some(path) = CONFIG_PATH.get() {
@@ -931,6 +1588,8 @@ rename-tab = "echo 'switched'"
             branches: Some(false),
             remotes: None,
             timeout_ms: Some(500),
+            summary: Some(vec![SummaryPart::Stale, SummaryPart::Disk]),
+            ..Default::default()
         };
         let json = serde_json::to_string(&config).unwrap();
         let parsed: ListConfig = serde_json::from_str(&json).unwrap();
@@ -938,6 +1597,28 @@ rename-tab = "echo 'switched'"
         assert_eq!(parsed.branches, Some(false));
         assert_eq!(parsed.remotes, None);
         assert_eq!(parsed.timeout_ms, Some(500));
+        assert_eq!(
+            parsed.summary,
+            Some(vec![SummaryPart::Stale, SummaryPart::Disk])
+        );
+    }
+
+    #[test]
+    fn test_list_config_time_settings() {
+        let config: ListConfig = toml::from_str(
+            r#"
+absolute-time = true
+time-format = "%d.%m.%Y"
+time-units = { day = " Tg.", now = "jetzt" }
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.absolute_time, Some(true));
+        assert_eq!(config.time_format.as_deref(), Some("%d.%m.%Y"));
+        let units = config.time_units.unwrap();
+        assert_eq!(units.day.as_deref(), Some(" Tg."));
+        assert_eq!(units.now.as_deref(), Some("jetzt"));
+        assert_eq!(units.week, None);
     }
 
     #[test]
@@ -976,6 +1657,7 @@ rename-tab = "echo 'switched'"
             "github.com/user/repo".to_string(),
             UserProjectConfig {
                 approved_commands: vec!["npm install".to_string(), "npm test".to_string()],
+                ..Default::default()
             },
         );
         assert!(config.is_command_approved("github.com/user/repo", "npm install"));
@@ -984,6 +1666,29 @@ rename-tab = "echo 'switched'"
         assert!(!config.is_command_approved("other/project", "npm install"));
     }
 
+    #[test]
+    fn test_record_approval_replaces_changed_text() {
+        let mut project = UserProjectConfig::default();
+        project.record_approval("pre-merge:test", "cargo test");
+        project.record_approval("post-create", "npm ci");
+        assert_eq!(project.approved_commands, vec!["cargo test", "npm ci"]);
+
+        let mut config = WorktrunkConfig::default();
+        config
+            .projects
+            .insert("github.com/user/repo".to_string(), project.clone());
+        assert!(!config.approval_changed("github.com/user/repo", "pre-merge:test", "cargo test"));
+        assert!(config.approval_changed("github.com/user/repo", "pre-merge:test", "cargo nextest"));
+        assert!(!config.approval_changed("github.com/user/repo", "pre-merge:lint", "cargo clippy"));
+
+        // Re-approving under the same name drops the superseded text
+        project.record_approval("pre-merge:test", "cargo nextest");
+        assert_eq!(project.approved_commands, vec!["npm ci", "cargo nextest"]);
+        assert_eq!(project.commands_named("test"), vec!["cargo nextest"]);
+        assert_eq!(project.commands_named("post-create"), vec!["npm ci"]);
+        assert!(project.commands_named("lint").is_empty());
+    }
+
     #[test]
     fn test_is_command_approved_normalizes_deprecated_vars() {
         // Approval saved with deprecated variable should match command with new variable
@@ -994,6 +1699,7 @@ rename-tab = "echo 'switched'"
                 approved_commands: vec![
                     "ln -sf {{ repo_root }}/node_modules".to_string(), // old var
                 ],
+                ..Default::default()
             },
         );
 
@@ -1020,6 +1726,7 @@ rename-tab = "echo 'switched'"
                 approved_commands: vec![
                     "cd {{ worktree_path }} && npm install".to_string(), // new var
                 ],
+                ..Default::default()
             },
         );
 
@@ -1039,6 +1746,7 @@ rename-tab = "echo 'switched'"
                 approved_commands: vec![
                     "ln -sf {{ repo_root }}/modules {{ worktree }}/modules".to_string(),
                 ],
+                ..Default::default()
             },
         );
 
@@ -1113,6 +1821,10 @@ rename-tab = "echo 'switched'"
             rebase: Some(false),
             remove: Some(true),
             verify: Some(true),
+            push: None,
+            wait_ci: None,
+            ci_timeout: None,
+            ci_interval: None,
         };
         let json = serde_json::to_string(&config).unwrap();
         let parsed: MergeConfig = serde_json::from_str(&json).unwrap();
@@ -1167,4 +1879,114 @@ worktree-path = "../{{ main_worktree }}.{{ branch }}"
         let config: WorktrunkConfig = toml::from_str(content).unwrap();
         assert!(!config.skip_shell_integration_prompt);
     }
+
+    #[test]
+    fn test_llm_enabled() {
+        assert!(WorktrunkConfig::default().llm_enabled());
+
+        let config: WorktrunkConfig =
+            toml::from_str("[commit-generation]\ncommand = \"llm\"\n\n[llm]\nenabled = false\n")
+                .unwrap();
+        assert!(!config.llm_enabled());
+        assert!(config.commit_generation.is_configured());
+
+        // `load` copies the setting into the commit generation config
+        let commit_generation = CommitGenerationConfig {
+            disabled: true,
+            ..config.commit_generation
+        };
+        assert!(!commit_generation.is_configured());
+    }
+
+    #[test]
+    fn test_llm_diff_limits() {
+        let config: WorktrunkConfig =
+            toml::from_str("[llm]\nmax-diff-bytes = 1000\ndiff-truncation = \"file-list\"\n")
+                .unwrap();
+        let llm = config.llm.unwrap();
+        assert_eq!(llm.max_diff_bytes, Some(1000));
+        assert_eq!(llm.diff_truncation, Some(DiffTruncation::FileList));
+
+        assert!(toml::from_str::<WorktrunkConfig>("[llm]\ndiff-truncation = \"all\"\n").is_err());
+    }
+
+    #[test]
+    fn test_llm_redact() {
+        assert!(WorktrunkConfig::default().llm.is_none());
+
+        let config: WorktrunkConfig = toml::from_str("[llm]\nredact = ['corp-[0-9]+']\n").unwrap();
+        assert_eq!(config.llm.unwrap().redact, ["corp-[0-9]+"]);
+    }
+
+    #[test]
+    fn test_zoxide_enabled() {
+        assert!(!WorktrunkConfig::default().zoxide_enabled());
+
+        let config: WorktrunkConfig = toml::from_str("[integrations]\nzoxide = true\n").unwrap();
+        assert!(config.zoxide_enabled());
+    }
+
+    #[test]
+    fn test_direnv_mode() {
+        assert_eq!(WorktrunkConfig::default().direnv_mode(), DirenvMode::Warn);
+
+        let config: WorktrunkConfig =
+            toml::from_str("[integrations]\ndirenv = \"allow\"\n").unwrap();
+        assert_eq!(config.direnv_mode(), DirenvMode::Allow);
+    }
+
+    #[test]
+    fn test_auto_integrated_mode() {
+        assert_eq!(
+            WorktrunkConfig::default().auto_integrated_mode(),
+            AutoIntegratedMode::Never
+        );
+
+        let config: WorktrunkConfig =
+            toml::from_str("[remove]\nauto-integrated = \"prompt\"\n").unwrap();
+        assert_eq!(config.auto_integrated_mode(), AutoIntegratedMode::Prompt);
+    }
+
+    #[test]
+    fn test_switch_set_upstream() {
+        assert!(!WorktrunkConfig::default().switch_set_upstream());
+
+        let config: WorktrunkConfig = toml::from_str("[switch]\nset-upstream = true\n").unwrap();
+        assert!(config.switch_set_upstream());
+        assert!(config.switch_excludes().is_empty());
+    }
+
+    #[test]
+    fn test_switch_excludes() {
+        let config: WorktrunkConfig =
+            toml::from_str("[switch]\nexclude = [\"*.log\", \".wt-state\"]\n").unwrap();
+        assert_eq!(config.switch_excludes(), ["*.log", ".wt-state"]);
+    }
+
+    #[test]
+    fn test_completion_limit() {
+        let config = WorktrunkConfig::default();
+        assert_eq!(config.completion_limit(), Some(100));
+        let config: WorktrunkConfig = toml::from_str("[switch]\ncompletion-limit = 50\n").unwrap();
+        assert_eq!(config.completion_limit(), Some(50));
+        let config: WorktrunkConfig = toml::from_str("[switch]\ncompletion-limit = 0\n").unwrap();
+        assert_eq!(config.completion_limit(), None);
+    }
+
+    #[test]
+    fn test_remove_trash_limit() {
+        let config = WorktrunkConfig::default();
+        assert_eq!(config.remove_trash_limit(), None);
+
+        let config: WorktrunkConfig = toml::from_str("[remove]\nuse-trash = true\n").unwrap();
+        assert_eq!(config.remove_trash_limit(), Some(1024 * 1024 * 1024));
+
+        let config: WorktrunkConfig =
+            toml::from_str("[remove]\nuse-trash = true\ntrash-max-mb = 50\n").unwrap();
+        assert_eq!(config.remove_trash_limit(), Some(50 * 1024 * 1024));
+
+        // The limit alone doesn't enable the trash
+        let config: WorktrunkConfig = toml::from_str("[remove]\ntrash-max-mb = 50\n").unwrap();
+        assert_eq!(config.remove_trash_limit(), None);
+    }
 }
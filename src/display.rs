@@ -1,12 +1,13 @@
 //! Display utilities for terminal output.
 //!
 //! This module provides utility functions for:
-//! - Relative time formatting
+//! - Relative and absolute time formatting
 //! - Path manipulation and shortening
-//! - Text truncation with word boundaries
+//! - Text truncation and padding by display width
 //! - Terminal width detection
 
 use std::path::Path;
+use worktrunk::config::{ListConfig, TimeUnits};
 use worktrunk::path::format_path_for_display;
 use worktrunk::utils::get_now;
 
@@ -17,6 +18,11 @@ pub(crate) fn format_relative_time_short(timestamp: i64) -> String {
 }
 
 fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
+    format_relative_time_with(timestamp, now, &TimeUnits::default())
+}
+
+/// Relative age with the configured unit labels in place of the English ones.
+fn format_relative_time_with(timestamp: i64, now: i64, units: &TimeUnits) -> String {
     const MINUTE: i64 = 60;
     const HOUR: i64 = MINUTE * 60;
     const DAY: i64 = HOUR * 24;
@@ -24,33 +30,122 @@ fn format_relative_time_impl(timestamp: i64, now: i64) -> String {
     const MONTH: i64 = DAY * 30;
     const YEAR: i64 = DAY * 365;
 
+    let label = |custom: &Option<String>, default: &'static str| -> String {
+        custom.clone().unwrap_or_else(|| default.to_string())
+    };
+
     let seconds_ago = now - timestamp;
 
     if seconds_ago < 0 {
-        return "future".to_string();
+        return label(&units.future, "future");
     }
 
     if seconds_ago < MINUTE {
-        return "now".to_string();
+        return label(&units.now, "now");
     }
 
-    const UNITS: &[(i64, &str)] = &[
-        (YEAR, "y"),
-        (MONTH, "mo"),
-        (WEEK, "w"),
-        (DAY, "d"),
-        (HOUR, "h"),
-        (MINUTE, "m"),
+    let unit_list = [
+        (YEAR, &units.year, "y"),
+        (MONTH, &units.month, "mo"),
+        (WEEK, &units.week, "w"),
+        (DAY, &units.day, "d"),
+        (HOUR, &units.hour, "h"),
+        (MINUTE, &units.minute, "m"),
     ];
 
-    for &(unit_seconds, abbrev) in UNITS {
+    for (unit_seconds, custom, abbrev) in unit_list {
         let value = seconds_ago / unit_seconds;
         if value > 0 {
-            return format!("{}{}", value, abbrev);
+            return format!("{}{}", value, label(custom, abbrev));
+        }
+    }
+
+    label(&units.now, "now")
+}
+
+/// How `wt list` shows commit times: relative ages or formatted dates.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct TimeFormat {
+    /// strftime pattern for absolute dates; `None` shows relative ages
+    absolute: Option<String>,
+    units: TimeUnits,
+}
+
+impl TimeFormat {
+    /// Default pattern for `--absolute-time`
+    const DEFAULT_PATTERN: &'static str = "%Y-%m-%d";
+
+    /// Build from `[list]` config; `absolute` is the `--absolute-time` flag.
+    pub(crate) fn from_config(list: Option<&ListConfig>, absolute: bool) -> Self {
+        let absolute = absolute || list.and_then(|l| l.absolute_time).unwrap_or(false);
+        Self {
+            absolute: absolute.then(|| {
+                list.and_then(|l| l.time_format.clone())
+                    .unwrap_or_else(|| Self::DEFAULT_PATTERN.to_string())
+            }),
+            units: list.and_then(|l| l.time_units.clone()).unwrap_or_default(),
+        }
+    }
+
+    pub(crate) fn format(&self, timestamp: i64) -> String {
+        self.format_at(timestamp, get_now() as i64)
+    }
+
+    fn format_at(&self, timestamp: i64, now: i64) -> String {
+        match &self.absolute {
+            Some(pattern) => format_local_time(timestamp, pattern),
+            None => format_relative_time_with(timestamp, now, &self.units),
         }
     }
 
-    "now".to_string()
+    /// Column width to reserve before the times are known.
+    pub(crate) fn width_estimate(&self) -> usize {
+        use unicode_width::UnicodeWidthStr;
+        match &self.absolute {
+            // A two-digit day and month give the widest rendering of most patterns
+            Some(pattern) => format_local_time(1_766_620_800, pattern).width(),
+            None => {
+                let u = &self.units;
+                let label = |custom: &Option<String>, default: &str| {
+                    custom.as_deref().unwrap_or(default).width()
+                };
+                // Two digits cover everything below the next unit up; "future"
+                // is rare enough to truncate
+                [
+                    label(&u.year, "y"),
+                    label(&u.month, "mo"),
+                    label(&u.week, "w"),
+                    label(&u.day, "d"),
+                    label(&u.hour, "h"),
+                    label(&u.minute, "m"),
+                ]
+                .into_iter()
+                .map(|unit| unit + 2)
+                .chain([label(&u.now, "now"), 4])
+                .max()
+                .unwrap_or(4)
+            }
+        }
+    }
+}
+
+/// Format a Unix timestamp in local time with a strftime pattern.
+///
+/// Invalid patterns fall back to the default date rather than panicking.
+fn format_local_time(timestamp: i64, pattern: &str) -> String {
+    use chrono::format::{Item, StrftimeItems};
+    use chrono::{Local, TimeZone};
+
+    let Some(time) = Local.timestamp_opt(timestamp, 0).single() else {
+        return String::new();
+    };
+    let items: Vec<Item> = StrftimeItems::new(pattern).collect();
+    let items = if items.contains(&Item::Error) {
+        StrftimeItems::new(TimeFormat::DEFAULT_PATTERN).collect()
+    } else {
+        items
+    };
+    time.format_with_items(items.into_iter()).to_string()
 }
 
 /// Shorten a path relative to the main worktree.
@@ -114,6 +209,17 @@ pub(crate) fn truncate_to_width(text: &str, max_width: usize) -> String {
     format!("{}…", truncated)
 }
 
+/// Pad text with trailing spaces to a display width.
+///
+/// Unlike `format!("{:width$}")`, which counts characters, this accounts for
+/// wide characters (CJK, emoji) taking two columns.
+pub(crate) fn pad_to_width(text: &str, width: usize) -> String {
+    use worktrunk::styling::visual_width;
+
+    let padding = width.saturating_sub(visual_width(text));
+    format!("{text}{}", " ".repeat(padding))
+}
+
 // Re-export from styling for convenience
 pub(crate) use worktrunk::styling::{get_terminal_width, truncate_visible};
 
@@ -199,6 +305,30 @@ mod tests {
         assert!(result.ends_with('…'));
     }
 
+    #[test]
+    fn test_pad_to_width_counts_display_columns() {
+        use unicode_width::UnicodeWidthStr;
+
+        for name in ["main", "機能", "fix-🐛", "תיקון"] {
+            let padded = pad_to_width(name, 8);
+            assert_eq!(padded.width(), 8, "{name:?} padded to {padded:?}");
+        }
+        // Already wider: left alone
+        assert_eq!(pad_to_width("日本語の名前", 4), "日本語の名前");
+    }
+
+    #[test]
+    fn test_truncate_to_width_wide_characters() {
+        use unicode_width::UnicodeWidthStr;
+
+        // Never splits a wide character across the limit
+        let result = truncate_to_width("機能追加ブランチ", 7);
+        assert_eq!(result, "機能追…");
+        assert!(result.width() <= 7);
+        let result = truncate_to_width("🐛🐛🐛🐛", 6);
+        assert_eq!(result, "🐛🐛…");
+    }
+
     #[test]
     fn test_format_relative_time_short() {
         let now: i64 = 1700000000; // Fixed timestamp for testing
@@ -233,6 +363,50 @@ mod tests {
         assert_eq!(format_relative_time_impl(now + 1000, now), "future");
     }
 
+    #[test]
+    fn test_time_format() {
+        let now: i64 = 1700000000;
+
+        // Defaults match the short relative format
+        let default = TimeFormat::default();
+        assert_eq!(default.format_at(now - 7200, now), "2h");
+        assert_eq!(default.width_estimate(), 4);
+
+        // Custom labels replace the English abbreviations
+        let list = ListConfig {
+            time_units: Some(TimeUnits {
+                now: Some("jetzt".into()),
+                day: Some("T".into()),
+                month: Some("Mon".into()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let localized = TimeFormat::from_config(Some(&list), false);
+        assert_eq!(localized.format_at(now - 10, now), "jetzt");
+        assert_eq!(localized.format_at(now - 172800, now), "2T");
+        assert_eq!(localized.format_at(now - 3600, now), "1h");
+        assert_eq!(localized.width_estimate(), 5);
+
+        // Absolute dates, from the flag or config, with the configured pattern
+        let absolute = TimeFormat::from_config(None, true);
+        let date = absolute.format(now);
+        assert_eq!(date.len(), 10, "Expected YYYY-MM-DD, got: {date}");
+        assert_eq!(absolute.width_estimate(), 10);
+        let list = ListConfig {
+            absolute_time: Some(true),
+            time_format: Some("%Y".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            TimeFormat::from_config(Some(&list), false).format(now),
+            "2023"
+        );
+
+        // An invalid pattern falls back to the default date
+        assert_eq!(format_local_time(now, "%Q").len(), 10);
+    }
+
     #[test]
     #[cfg(unix)] // Uses Unix-style paths
     fn test_shorten_path() {
@@ -0,0 +1,171 @@
+//! C ABI over [`crate::api`], for editor plugins that want worktree data
+//! without spawning `wt` (Neovim via LuaJIT FFI, VS Code via a Node addon).
+//!
+//! Enabled with the `worktrunk-ffi` feature and built as a shared library with
+//! `cargo rustc --lib --features worktrunk-ffi --crate-type cdylib`:
+//!
+//! ```c
+//! char *wt_list_json(const char *repo_path, bool branches);
+//! char *wt_switch(const char *repo_path, const char *branch, bool create);
+//! const char *wt_last_error(void);
+//! void wt_string_free(char *s);
+//! ```
+//!
+//! Strings are UTF-8 and NUL-terminated. Returned strings are owned by the
+//! caller and must be released with `wt_string_free`. On failure a function
+//! returns NULL and `wt_last_error` describes the error, as one line of plain
+//! text, until the next call on the same thread. Panics never cross into the
+//! host: they're caught at each entry point and reported as errors.
+//!
+//! Git still runs as a subprocess underneath, so hosts must be able to spawn
+//! `git` — this rules out sandboxed `wasm32` targets.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char};
+use std::panic::{AssertUnwindSafe, catch_unwind};
+
+use crate::api::{ListOptions, Worktrunk};
+use crate::styling::plain_error_message;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// List worktrees (and with `branches`, branches without one) as a JSON array
+/// of [`crate::api::ListItem`].
+///
+/// # Safety
+///
+/// `repo_path` must be a valid NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_list_json(repo_path: *const c_char, branches: bool) -> *mut c_char {
+    ffi_result(|| {
+        // SAFETY: the caller guarantees a valid C string
+        let repo_path = unsafe { str_arg(repo_path) };
+        let items = Worktrunk::open(repo_path?)?.list(ListOptions { branches })?;
+        Ok(serde_json::to_string(&items)?)
+    })
+}
+
+/// Return the worktree path for `branch`, creating the worktree (and with
+/// `create`, the branch) if needed. No hooks run.
+///
+/// # Safety
+///
+/// `repo_path` and `branch` must be valid NUL-terminated strings.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_switch(
+    repo_path: *const c_char,
+    branch: *const c_char,
+    create: bool,
+) -> *mut c_char {
+    ffi_result(|| {
+        // SAFETY: the caller guarantees valid C strings
+        let (repo_path, branch) = unsafe { (str_arg(repo_path), str_arg(branch)) };
+        let path = Worktrunk::open(repo_path?)?.switch(branch?, create)?;
+        Ok(path.to_string_lossy().into_owned())
+    })
+}
+
+/// The error from the last failed call on this thread, or NULL.
+///
+/// The pointer stays valid until the next `wt_*` call on the same thread.
+#[unsafe(no_mangle)]
+pub extern "C" fn wt_last_error() -> *const c_char {
+    catch_unwind(|| {
+        LAST_ERROR.with_borrow(|error| error.as_ref().map_or(std::ptr::null(), |e| e.as_ptr()))
+    })
+    .unwrap_or(std::ptr::null())
+}
+
+/// Free a string returned by `wt_list_json` or `wt_switch`.
+///
+/// # Safety
+///
+/// `s` must be NULL or a pointer returned by this library, not yet freed.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn wt_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        // SAFETY: the caller guarantees `s` came from `CString::into_raw`
+        let _ = catch_unwind(|| drop(unsafe { CString::from_raw(s) }));
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must be NULL or a valid NUL-terminated string that outlives `'a`.
+unsafe fn str_arg<'a>(ptr: *const c_char) -> anyhow::Result<&'a str> {
+    anyhow::ensure!(!ptr.is_null(), "Unexpected NULL argument");
+    // SAFETY: non-null, and the caller guarantees it's a valid C string
+    Ok(unsafe { CStr::from_ptr(ptr) }.to_str()?)
+}
+
+/// Run an entry point's body, turning errors and panics into NULL plus
+/// `wt_last_error`, which holds the error as one line of plain text.
+fn ffi_result(f: impl FnOnce() -> anyhow::Result<String>) -> *mut c_char {
+    // Nothing observes state `f` leaves behind after a panic
+    let result = catch_unwind(AssertUnwindSafe(f))
+        .unwrap_or_else(|panic| {
+            Err(anyhow::anyhow!(
+                "Internal error: {}",
+                panic_message(&*panic)
+            ))
+        })
+        .and_then(|s| Ok(CString::new(s)?));
+    LAST_ERROR.with_borrow_mut(|last| match result {
+        Ok(s) => {
+            *last = None;
+            s.into_raw()
+        }
+        Err(e) => {
+            *last = CString::new(plain_error_message(&e)).ok();
+            std::ptr::null_mut()
+        }
+    })
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("panic")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_reported_through_last_error() {
+        let path = CString::new("/nonexistent/worktrunk-ffi").unwrap();
+        let json = unsafe { wt_list_json(path.as_ptr(), false) };
+        assert!(json.is_null());
+        let error = unsafe { CStr::from_ptr(wt_last_error()) };
+        assert!(!error.to_bytes().is_empty());
+
+        let json = unsafe { wt_list_json(std::ptr::null(), false) };
+        assert!(json.is_null());
+        let error = unsafe { CStr::from_ptr(wt_last_error()) };
+        assert_eq!(error.to_str().unwrap(), "Unexpected NULL argument");
+    }
+
+    #[test]
+    fn test_last_error_is_plain_text() {
+        let result = ffi_result(|| Err(crate::git::GitError::UserEmailNotSet.into()));
+        assert!(result.is_null());
+        let error = unsafe { CStr::from_ptr(wt_last_error()) }.to_str().unwrap();
+        assert!(!error.contains('\x1b'), "error: {error:?}");
+        assert!(!error.contains('\n'), "error: {error:?}");
+        assert_eq!(error, "wt list --mine needs user.email in git config");
+    }
+
+    #[test]
+    fn test_panic_reported_through_last_error() {
+        let result = ffi_result(|| panic!("boom"));
+        assert!(result.is_null());
+        let error = unsafe { CStr::from_ptr(wt_last_error()) };
+        assert_eq!(error.to_str().unwrap(), "Internal error: boom");
+    }
+}
@@ -4,7 +4,7 @@
 //! deleted after its worktree is removed. It checks if the branch's content has
 //! been integrated into the target branch.
 
-use worktrunk::git::{IntegrationReason, Repository};
+use super::{IntegrationReason, Repository};
 
 /// Outcome of a branch deletion attempt.
 pub enum BranchDeletionOutcome {
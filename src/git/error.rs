@@ -58,6 +58,11 @@ pub enum GitError {
     InvalidReference {
         reference: String,
     },
+    /// Branch not found, but similarly named branches exist
+    BranchNotFoundSuggestions {
+        reference: String,
+        suggestions: Vec<String>,
+    },
 
     // Worktree errors
     NotInWorktree {
@@ -95,11 +100,23 @@ pub enum GitError {
         error: String,
     },
     CannotRemoveMainWorktree,
+    /// Branch matches `[safety] protected-branches` in project config
+    ProtectedBranch {
+        branch: String,
+        action: String,
+        /// Flag that lets the operation proceed, if any
+        override_flag: Option<String>,
+    },
     WorktreeLocked {
         branch: String,
         path: PathBuf,
         reason: Option<String>,
     },
+    /// Branch is checked out with `wt review`
+    ReviewWorktree {
+        branch: String,
+        action: String,
+    },
 
     // Merge/push errors
     ConflictingChanges {
@@ -116,20 +133,111 @@ pub enum GitError {
         target_branch: String,
         git_output: String,
     },
+    /// A merge of `target_branch` stopped on conflicts
+    MergeConflict {
+        target_branch: String,
+        git_output: String,
+    },
+    /// `wt cherry` stopped on a commit that doesn't apply cleanly
+    CherryPickConflict {
+        source_branch: String,
+        /// Short hash and subject of the commit that stopped
+        commit: String,
+        files: Vec<String>,
+    },
     NotRebased {
         target_branch: String,
     },
+    /// A previous `wt merge` stopped after rewriting the branch but before pushing
+    MergeInterrupted {
+        branch: String,
+        target_branch: String,
+    },
+    /// A git rebase or merge is in progress in the worktree
+    GitOperationPending {
+        /// "rebase" or "merge"
+        operation: &'static str,
+        /// What was refused, e.g. "switch away" or "merge"
+        action: String,
+        branch: Option<String>,
+    },
     PushFailed {
         target_branch: String,
         error: String,
     },
+    /// `wt merge --push`: the remote copy of the target has commits the local target lacks
+    RemoteNotFastForward {
+        remote: String,
+        target_branch: String,
+        commits_formatted: String,
+    },
+    /// `wt tag`: the current branch isn't the target and hasn't been merged into it
+    TagBranchNotMerged {
+        branch: String,
+        target: String,
+    },
+    TagExists {
+        tag: String,
+    },
+    /// `wt tag`: the version isn't a valid tag name
+    InvalidTagName {
+        tag: String,
+    },
+    /// `wt push`: the branch's upstream has commits the branch lacks
+    PushNotFastForward {
+        branch: String,
+        /// e.g. `origin/feature`
+        upstream: String,
+        commits_formatted: String,
+    },
+    /// `wt merge --push`: pushing the target to its remote failed
+    RemotePushFailed {
+        remote: String,
+        target_branch: String,
+        error: String,
+    },
+    /// `git commit` failed while `commit.gpgsign` is enabled
+    CommitSigningFailed {
+        error: String,
+    },
+    /// The user quit the `[commit] review` prompt, or saved an empty message
+    CommitAborted {
+        empty: bool,
+    },
+    /// `wt clone`: no directory given, and none can be derived from the URL
+    CloneDirectoryUnknown {
+        url: String,
+    },
+    /// `wt clone`: the directory to clone into already has files in it
+    CloneTargetNotEmpty {
+        path: PathBuf,
+    },
 
     // Validation/other errors
     NotInteractive,
+    /// A command that only works interactively (`wt list --tune`,
+    /// `wt step commit --interactive`) without a terminal
+    NeedsTerminal {
+        command: &'static str,
+    },
+    /// No staged changes to commit
+    NothingToCommit,
+    /// `wt list --mine` without `user.email` to compare committers against
+    UserEmailNotSet,
+    /// Another `wt` process holds the repository's operation lock
+    OperationInProgress {
+        pid: Option<u32>,
+        command: Option<String>,
+    },
     HookCommandNotFound {
         name: String,
         available: Vec<String>,
     },
+    /// `wt hook approvals revoke` named a command the project has no approval for
+    ApprovalNotFound {
+        project: String,
+        command: String,
+    },
     ParseError {
         message: String,
     },
@@ -241,6 +349,34 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::BranchNotFoundSuggestions {
+                reference,
+                suggestions,
+            } => {
+                let hint = match suggestions.as_slice() {
+                    [only] => {
+                        let switch_cmd = suggest_command("switch", &[only], &[]);
+                        cformat!(
+                            "Did you mean <bold>{only}</>? To switch, run <bright-black>{switch_cmd}</>"
+                        )
+                    }
+                    _ => {
+                        let list = suggestions
+                            .iter()
+                            .map(|s| cformat!("<bold>{s}</>"))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        format!("Did you mean one of: {list}?")
+                    }
+                };
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!("Branch <bold>{reference}</> not found")),
+                    hint_message(hint)
+                )
+            }
+
             GitError::NotInWorktree { action } => {
                 let message = match action {
                     Some(action) => format!("Cannot {action}: not in a worktree"),
@@ -373,6 +509,25 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::ProtectedBranch {
+                branch,
+                action,
+                override_flag,
+            } => {
+                let message = error_message(cformat!(
+                    "Cannot {action}: <bold>{branch}</> is a protected branch"
+                ));
+                let hint = match override_flag {
+                    Some(flag) => cformat!(
+                        "Protected by <bright-black>[safety] protected-branches</> in project config; to proceed, add <bright-black>{flag}</>"
+                    ),
+                    None => cformat!(
+                        "Protected by <bright-black>[safety] protected-branches</> in project config"
+                    ),
+                };
+                write!(f, "{}\n{}", message, hint_message(hint))
+            }
+
             GitError::WorktreeLocked {
                 branch,
                 path,
@@ -390,11 +545,22 @@ impl std::fmt::Display for GitError {
                         "Cannot remove <bold>{branch}</>, worktree is locked{reason_text}"
                     )),
                     hint_message(cformat!(
-                        "To unlock, run <bright-black>git worktree unlock {path_display}</>"
+                        "To remove anyway, add <bright-black>--unlock</>; to only unlock, run <bright-black>git worktree unlock {path_display}</>"
                     ))
                 )
             }
 
+            GitError::ReviewWorktree { branch, action } => write!(
+                f,
+                "{}\n{}",
+                error_message(cformat!(
+                    "Cannot {action}: <bold>{branch}</> is checked out for review"
+                )),
+                hint_message(cformat!(
+                    "To work on it, run <bright-black>wt review --release {branch}</>"
+                ))
+            ),
+
             GitError::ConflictingChanges {
                 target_branch,
                 files,
@@ -483,6 +649,110 @@ impl std::fmt::Display for GitError {
                 }
             }
 
+            GitError::MergeConflict {
+                target_branch,
+                git_output,
+            } => {
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!("Merge of <bold>{target_branch}</> incomplete"))
+                )?;
+                if !git_output.is_empty() {
+                    write!(f, "\n{}", format_with_gutter(git_output, None))?;
+                }
+                write!(
+                    f,
+                    "\n{}\n{}",
+                    hint_message(cformat!(
+                        "To continue after resolving conflicts, run <bright-black>git merge --continue</>"
+                    )),
+                    hint_message(cformat!("To abort, run <bright-black>git merge --abort</>"))
+                )
+            }
+
+            GitError::CherryPickConflict {
+                source_branch,
+                commit,
+                files,
+            } => {
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!(
+                        "Cherry-pick from <bold>{source_branch}</> stopped at conflicts in {commit}"
+                    ))
+                )?;
+                if !files.is_empty() {
+                    write!(f, "\n{}", format_with_gutter(&files.join("\n"), None))?;
+                }
+                write!(
+                    f,
+                    "\n{}\n{}",
+                    hint_message(cformat!(
+                        "To continue after resolving conflicts, run <bright-black>git cherry-pick --continue</>"
+                    )),
+                    hint_message(cformat!(
+                        "To abort, run <bright-black>git cherry-pick --abort</>"
+                    ))
+                )
+            }
+
+            GitError::MergeInterrupted {
+                branch,
+                target_branch,
+            } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Merge of <bold>{branch}</> into <bold>{target_branch}</> was interrupted"
+                    )),
+                    hint_message(cformat!(
+                        "To resume, run <bright-black>wt merge --continue</>; to roll back, run <bright-black>wt merge --abort</>"
+                    ))
+                )
+            }
+
+            GitError::GitOperationPending {
+                operation,
+                action,
+                branch,
+            } => {
+                let location = match branch {
+                    Some(branch) => cformat!("on <bold>{branch}</>"),
+                    None => "in this worktree".to_string(),
+                };
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Cannot {action}: a {operation} is in progress {location}"
+                    )),
+                    hint_message(cformat!(
+                        "To finish it, run <bright-black>git {operation} --continue</>; to undo it, run <bright-black>git {operation} --abort</>; to {action} anyway, add <bright-black>--force</>"
+                    ))
+                )
+            }
+
+            GitError::OperationInProgress { pid, command } => {
+                let holder = match (pid, command) {
+                    (Some(pid), Some(command)) => cformat!(" (pid {pid}: <bold>{command}</>)"),
+                    (Some(pid), None) => format!(" (pid {pid})"),
+                    _ => String::new(),
+                };
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(format!(
+                        "Another wt operation is in progress in this repository{holder}"
+                    )),
+                    hint_message(cformat!(
+                        "To wait for it to finish, rerun with <bright-black>--wait</>"
+                    ))
+                )
+            }
+
             GitError::NotRebased { target_branch } => {
                 let rebase_cmd = suggest_command("step", &["rebase", target_branch], &[]);
                 write!(
@@ -505,6 +775,167 @@ impl std::fmt::Display for GitError {
                 write!(f, "{}", format_error_block(header, error))
             }
 
+            GitError::RemoteNotFastForward {
+                remote,
+                target_branch,
+                commits_formatted,
+            } => {
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!(
+                        "Can't push to <bold>{remote}/{target_branch}</>: it has newer commits"
+                    ))
+                )?;
+                if !commits_formatted.is_empty() {
+                    write!(f, "\n{}\n", format_with_gutter(commits_formatted, None))?;
+                }
+                let merge_cmd = suggest_command("merge", &[target_branch], &["--push"]);
+                write!(
+                    f,
+                    "\n{}",
+                    hint_message(cformat!(
+                        "Run <bright-black>git fetch {remote}</>, update <bold>{target_branch}</>, then run <bright-black>{merge_cmd}</> again"
+                    ))
+                )
+            }
+
+            GitError::TagBranchNotMerged { branch, target } => {
+                let merge_cmd = suggest_command("merge", &[target], &[]);
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Can't tag from <bold>{branch}</>: it isn't merged into <bold>{target}</>"
+                    )),
+                    hint_message(cformat!(
+                        "Merge it first with <bright-black>{merge_cmd}</>, or run from <bold>{target}</>'s worktree"
+                    ))
+                )
+            }
+
+            GitError::TagExists { tag } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!("Tag <bold>{tag}</> already exists")),
+                    hint_message(cformat!(
+                        "To move it, delete it first with <bright-black>git tag -d {tag}</>"
+                    ))
+                )
+            }
+
+            GitError::InvalidTagName { tag } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!("<bold>{tag}</> isn't a valid tag name")),
+                    hint_message(cformat!(
+                        "Tag names follow git's ref name rules; check with <bright-black>git check-ref-format refs/tags/{tag}</>"
+                    ))
+                )
+            }
+
+            GitError::PushNotFastForward {
+                branch,
+                upstream,
+                commits_formatted,
+            } => {
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!(
+                        "Can't push <bold>{branch}</>: <bold>{upstream}</> has commits it doesn't"
+                    ))
+                )?;
+                if !commits_formatted.is_empty() {
+                    write!(f, "\n{}\n", format_with_gutter(commits_formatted, None))?;
+                }
+                let force_cmd = suggest_command("push", &[], &["--force-with-lease"]);
+                write!(
+                    f,
+                    "\n{}",
+                    hint_message(cformat!(
+                        "Bring them in with <bright-black>git pull --rebase</>, or replace them with <bright-black>{force_cmd}</>"
+                    ))
+                )
+            }
+
+            GitError::RemotePushFailed {
+                remote,
+                target_branch,
+                error,
+            } => {
+                let header = error_message(cformat!(
+                    "Can't push <bold>{target_branch}</> to <bold>{remote}</>"
+                ));
+                write!(f, "{}", format_error_block(header, error))
+            }
+
+            GitError::CommitSigningFailed { error } => {
+                let header = error_message("Failed to sign commit");
+                write!(
+                    f,
+                    "{}\n{}",
+                    format_error_block(header, error),
+                    hint_message(cformat!(
+                        "GPG can't prompt for a passphrase here; unlock the key (or set <bright-black>GPG_TTY=$(tty)</>) and retry, or disable with <bright-black>git config commit.gpgsign false</>"
+                    ))
+                )
+            }
+
+            GitError::CommitAborted { empty } => {
+                let reason = if *empty {
+                    "Commit aborted: empty commit message"
+                } else {
+                    "Commit aborted"
+                };
+                write!(f, "{}", error_message(reason))
+            }
+
+            GitError::CloneDirectoryUnknown { url } => {
+                write!(
+                    f,
+                    "{}\n{}",
+                    error_message(cformat!(
+                        "Can't derive a directory name from <bold>{url}</>"
+                    )),
+                    hint_message(cformat!(
+                        "To choose one, run <bright-black>wt clone {url} DIRECTORY</>"
+                    ))
+                )
+            }
+
+            GitError::CloneTargetNotEmpty { path } => {
+                let path_display = format_path_for_display(path);
+                write!(
+                    f,
+                    "{}",
+                    error_message(cformat!(
+                        "Can't clone into <bold>{path_display}</>: directory is not empty"
+                    ))
+                )
+            }
+
+            GitError::NeedsTerminal { command } => write!(
+                f,
+                "{}",
+                error_message(cformat!("<bold>{command}</> needs an interactive terminal"))
+            ),
+
+            GitError::NothingToCommit => write!(f, "{}", error_message("Nothing to commit")),
+
+            GitError::UserEmailNotSet => write!(
+                f,
+                "{}\n{}",
+                error_message(cformat!(
+                    "<bold>wt list --mine</> needs <bold>user.email</> in git config"
+                )),
+                hint_message(cformat!(
+                    "To set it, run <bright-black>git config --global user.email EMAIL</>"
+                ))
+            ),
+
             GitError::NotInteractive => {
                 let approvals_cmd = suggest_command("hook", &["approvals", "add"], &[]);
                 write!(
@@ -517,6 +948,17 @@ impl std::fmt::Display for GitError {
                 )
             }
 
+            GitError::ApprovalNotFound { project, command } => write!(
+                f,
+                "{}\n{}",
+                error_message(cformat!(
+                    "No approved command matching <bold>{command}</> for <bold>{project}</>"
+                )),
+                hint_message(cformat!(
+                    "To see saved approvals, run <bright-black>wt hook approvals list</>"
+                ))
+            ),
+
             GitError::HookCommandNotFound { name, available } => {
                 if available.is_empty() {
                     write!(
@@ -1018,6 +1460,27 @@ mod tests {
         assert!(display.contains("feature"));
     }
 
+    #[test]
+    fn test_git_error_branch_not_found_suggestions() {
+        let err = GitError::BranchNotFoundSuggestions {
+            reference: "feat".into(),
+            suggestions: vec!["feature".into()],
+        };
+        let display = err.to_string();
+        assert!(display.contains("not found"));
+        assert!(display.contains("Did you mean"));
+        assert!(display.contains("wt switch feature"));
+
+        let err = GitError::BranchNotFoundSuggestions {
+            reference: "feat".into(),
+            suggestions: vec!["feature-a".into(), "feature-b".into()],
+        };
+        let display = err.to_string();
+        assert!(display.contains("Did you mean one of:"));
+        assert!(display.contains("feature-a"));
+        assert!(display.contains("feature-b"));
+    }
+
     #[test]
     fn test_git_error_worktree_locked_with_reason() {
         let err = GitError::WorktreeLocked {
@@ -1030,6 +1493,7 @@ mod tests {
         assert!(display.contains("feature"));
         assert!(display.contains(", worktree is locked"));
         assert!(display.contains("(Testing lock)"));
+        assert!(display.contains("--unlock"));
         assert!(display.contains("git worktree unlock /tmp/repo.feature"));
     }
 
@@ -1062,6 +1526,31 @@ mod tests {
         assert!(display.contains("not rebased"));
     }
 
+    #[test]
+    fn test_git_error_merge_interrupted() {
+        let err = GitError::MergeInterrupted {
+            branch: "feature".into(),
+            target_branch: "main".into(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("was interrupted"));
+        assert!(display.contains("wt merge --continue"));
+        assert!(display.contains("wt merge --abort"));
+    }
+
+    #[test]
+    fn test_git_error_operation_in_progress() {
+        let err = GitError::OperationInProgress {
+            pid: Some(4242),
+            command: Some("wt remove feature".into()),
+        };
+        let display = err.to_string();
+        assert!(display.contains("Another wt operation is in progress"));
+        assert!(display.contains("pid 4242"));
+        assert!(display.contains("wt remove feature"));
+        assert!(display.contains("--wait"));
+    }
+
     #[test]
     fn test_git_error_hook_command_not_found() {
         // With available commands
@@ -1104,6 +1593,52 @@ mod tests {
         assert!(display.contains("llm --model gpt-4"));
     }
 
+    #[test]
+    fn test_git_error_git_operation_pending() {
+        let err = GitError::GitOperationPending {
+            operation: "rebase",
+            action: "switch away".into(),
+            branch: Some("feature".into()),
+        };
+        let display = err.to_string();
+        assert!(display.contains("Cannot switch away"));
+        assert!(display.contains("git rebase --continue"));
+        assert!(display.contains("git rebase --abort"));
+        assert!(display.contains("--force"));
+    }
+
+    #[test]
+    fn test_git_error_tag_branch_not_merged() {
+        let err = GitError::TagBranchNotMerged {
+            branch: "release".into(),
+            target: "main".into(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("isn't merged into"));
+        assert!(display.contains("wt merge main"));
+    }
+
+    #[test]
+    fn test_git_error_invalid_tag_name() {
+        let err = GitError::InvalidTagName { tag: "-v1".into() };
+        let display = err.to_string();
+        assert!(display.contains("isn't a valid tag name"));
+        assert!(display.contains("git check-ref-format"));
+    }
+
+    #[test]
+    fn test_git_error_push_not_fast_forward() {
+        let err = GitError::PushNotFastForward {
+            branch: "feature".into(),
+            upstream: "origin/feature".into(),
+            commits_formatted: String::new(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("origin/feature"));
+        assert!(display.contains("git pull --rebase"));
+        assert!(display.contains("--force-with-lease"));
+    }
+
     #[test]
     fn test_git_error_uncommitted_changes_variants() {
         // Action only
@@ -1265,4 +1800,51 @@ mod tests {
         assert!(display.contains("main"));
         // Empty output shouldn't cause issues
     }
+
+    #[test]
+    fn test_merge_conflict_hints() {
+        let err = GitError::MergeConflict {
+            target_branch: "origin/main".into(),
+            git_output: "CONFLICT (content): Merge conflict in a.txt".into(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("origin/main"));
+        assert!(display.contains("Merge conflict in a.txt"));
+        assert!(display.contains("git merge --continue"));
+        assert!(display.contains("git merge --abort"));
+    }
+
+    #[test]
+    fn test_user_email_not_set_hint() {
+        let display = GitError::UserEmailNotSet.to_string();
+        assert!(display.contains("user.email"));
+        assert!(display.contains("git config --global user.email"));
+    }
+
+    #[test]
+    fn test_cherry_pick_conflict_lists_files() {
+        let err = GitError::CherryPickConflict {
+            source_branch: "feature".into(),
+            commit: "abc1234 Fix parser".into(),
+            files: vec!["src/parser.rs".into()],
+        };
+        let display = err.to_string();
+        assert!(display.contains("feature"));
+        assert!(display.contains("abc1234 Fix parser"));
+        assert!(display.contains("src/parser.rs"));
+        assert!(display.contains("git cherry-pick --continue"));
+        assert!(display.contains("git cherry-pick --abort"));
+    }
+
+    #[test]
+    fn test_review_worktree_suggests_release() {
+        let err = GitError::ReviewWorktree {
+            branch: "feature".into(),
+            action: "commit".into(),
+        };
+        let display = err.to_string();
+        assert!(display.contains("Cannot commit"));
+        assert!(display.contains("checked out for review"));
+        assert!(display.contains("wt review --release feature"));
+    }
 }
@@ -3,6 +3,7 @@
 use std::path::PathBuf;
 
 // Submodules
+mod branch_deletion;
 mod diff;
 mod error;
 mod parse;
@@ -30,6 +31,7 @@ use std::sync::LazyLock;
 static HEAVY_OPS_SEMAPHORE: LazyLock<Semaphore> = LazyLock::new(|| Semaphore::new(4));
 
 // Re-exports from submodules
+pub use branch_deletion::{BranchDeletionOutcome, BranchDeletionResult, delete_branch_if_safe};
 pub(crate) use diff::DiffStats;
 pub use diff::{LineDiff, parse_numstat_line};
 pub use error::{
@@ -43,9 +45,12 @@ pub use error::{
     exit_code,
 };
 pub use parse::{parse_porcelain_z, parse_untracked_files};
-pub use repository::{Repository, ResolvedWorktree, WorkingTree, set_base_path};
+pub use repository::{
+    ActivityEntry, ActivityKind, CloneKind, MergeProgress, Repository, ResolvedWorktree,
+    ReviewState, WorkingTree, base_dir, set_base_path,
+};
 pub(crate) use url::GitRemoteUrl;
-pub use url::{parse_owner_repo, parse_remote_owner};
+pub use url::{parse_owner_repo, parse_remote_host, parse_remote_owner};
 /// Why branch content is considered integrated into the target branch.
 ///
 /// Used by both `wt list` (for status symbols) and `wt remove` (for messages).
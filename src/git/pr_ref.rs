@@ -230,6 +230,9 @@
 //!
 //! ## Required gh Fields
 //!
+//! The lookup itself is the GitHub forge backend's `GitHub::pr_info`, next to
+//! the rest of worktrunk's `gh` parsing.
+//!
 //! ```bash
 //! gh pr view <number> --json \
 //!   headRefName,headRepository,headRepositoryOwner,isCrossRepository,url
@@ -276,11 +279,6 @@
 //! - Interaction with `wt merge`
 //! - Multiple fork PRs with same branch name
 
-use anyhow::{Context, bail};
-use serde::Deserialize;
-
-use crate::shell_exec::Cmd;
-
 /// Information about a PR retrieved from GitHub.
 #[derive(Debug, Clone)]
 pub struct PrInfo {
@@ -298,27 +296,6 @@ pub struct PrInfo {
     pub url: String,
 }
 
-/// Raw JSON response from `gh pr view`.
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct GhPrResponse {
-    head_ref_name: String,
-    head_repository: GhRepository,
-    head_repository_owner: GhOwner,
-    is_cross_repository: bool,
-    url: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct GhRepository {
-    name: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct GhOwner {
-    login: String,
-}
-
 /// Parse a `pr:<number>` reference, returning the PR number if valid.
 ///
 /// Returns `None` if the input doesn't match the `pr:<number>` pattern.
@@ -327,104 +304,6 @@ pub fn parse_pr_ref(input: &str) -> Option<u32> {
     suffix.parse().ok()
 }
 
-/// Fetch PR information from GitHub using the `gh` CLI.
-///
-/// # Errors
-///
-/// Returns an error if:
-/// - `gh` is not installed or not authenticated
-/// - The PR doesn't exist
-/// - The JSON response is malformed
-pub fn fetch_pr_info(pr_number: u32, repo_root: &std::path::Path) -> anyhow::Result<PrInfo> {
-    let output = match Cmd::new("gh")
-        .args([
-            "pr",
-            "view",
-            &pr_number.to_string(),
-            "--json",
-            "headRefName,headRepository,headRepositoryOwner,isCrossRepository,url",
-        ])
-        .current_dir(repo_root)
-        .env("GH_PROMPT_DISABLED", "1")
-        .run()
-    {
-        Ok(output) => output,
-        Err(e) => {
-            // Check if gh is not installed (OS error for command not found)
-            let error_str = e.to_string();
-            if error_str.contains("No such file")
-                || error_str.contains("not found")
-                || error_str.contains("cannot find")
-            {
-                bail!("GitHub CLI (gh) not installed; install from https://cli.github.com/");
-            }
-            return Err(anyhow::Error::from(e).context("Failed to run gh pr view"));
-        }
-    };
-
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        let stderr_lower = stderr.to_lowercase();
-
-        // PR not found (various phrasings across gh versions)
-        if stderr_lower.contains("could not resolve")
-            || stderr_lower.contains("not found")
-            || stderr_lower.contains("no pull request")
-        {
-            bail!("PR #{} not found", pr_number);
-        }
-
-        // Authentication errors
-        if stderr_lower.contains("authentication")
-            || stderr_lower.contains("logged in")
-            || stderr_lower.contains("auth login")
-            || stderr_lower.contains("not logged")
-        {
-            bail!("GitHub CLI not authenticated; run gh auth login");
-        }
-
-        // Rate limiting
-        if stderr_lower.contains("rate limit") || stderr_lower.contains("api rate") {
-            bail!("GitHub API rate limit exceeded; wait a few minutes and retry");
-        }
-
-        // Network errors
-        if stderr_lower.contains("network")
-            || stderr_lower.contains("connection")
-            || stderr_lower.contains("timeout")
-        {
-            bail!("Network error connecting to GitHub; check your internet connection");
-        }
-
-        bail!("gh pr view failed: {}", stderr.trim());
-    }
-
-    let response: GhPrResponse = serde_json::from_slice(&output.stdout).with_context(|| {
-        format!(
-            "Failed to parse gh pr view JSON for PR #{}. \
-             This may indicate a gh version incompatibility or GitHub API change.",
-            pr_number
-        )
-    })?;
-
-    // Validate required fields are not empty
-    if response.head_ref_name.is_empty() {
-        bail!(
-            "PR #{} has empty branch name; the PR may be in an invalid state",
-            pr_number
-        );
-    }
-
-    Ok(PrInfo {
-        number: pr_number,
-        head_ref_name: response.head_ref_name,
-        head_owner: response.head_repository_owner.login,
-        head_repo: response.head_repository.name,
-        is_cross_repository: response.is_cross_repository,
-        url: response.url,
-    })
-}
-
 /// Generate the local branch name for a PR.
 ///
 /// - Same-repo PRs: use `headRefName` directly
@@ -0,0 +1,89 @@
+//! Append-only activity log for `wt history`.
+//!
+//! Every switch, create, remove and merge appends one JSON line to
+//! `.git/wt-history.jsonl` in the common git dir, so entries from all
+//! worktrees land in the same file. Lines are small and written with a single
+//! `O_APPEND` write, so concurrent `wt` processes don't interleave them.
+
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use super::Repository;
+
+/// An operation recorded in the activity log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, strum::Display)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum ActivityKind {
+    Switch,
+    Create,
+    Remove,
+    Merge,
+}
+
+/// One line of the activity log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEntry {
+    /// Unix timestamp (seconds)
+    pub timestamp: u64,
+    pub operation: ActivityKind,
+    /// Branch operated on, or `None` for a detached HEAD
+    pub branch: Option<String>,
+    /// Worktree path, when the operation has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Whether the operation succeeded
+    pub success: bool,
+}
+
+impl Repository {
+    /// Path of the activity log: `.git/wt-history.jsonl`.
+    pub fn wt_history_path(&self) -> PathBuf {
+        self.git_common_dir().join("wt-history.jsonl")
+    }
+
+    /// Append an entry to the activity log, timestamped now.
+    pub fn record_activity(
+        &self,
+        operation: ActivityKind,
+        branch: Option<&str>,
+        path: Option<&Path>,
+        success: bool,
+    ) -> anyhow::Result<()> {
+        let entry = ActivityEntry {
+            timestamp: crate::utils::get_now(),
+            operation,
+            branch: branch.map(str::to_string),
+            path: path.map(Path::to_path_buf),
+            success,
+        };
+        let mut line = serde_json::to_string(&entry)?;
+        line.push('\n');
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(self.wt_history_path())
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .context("Failed to write activity log")
+    }
+
+    /// Activity log entries at or after `since` (Unix seconds), oldest first.
+    ///
+    /// Lines that don't parse (e.g. from a newer version) are skipped.
+    pub fn activity_log(&self, since: u64) -> anyhow::Result<Vec<ActivityEntry>> {
+        let contents = match fs::read_to_string(self.wt_history_path()) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e).context("Failed to read activity log"),
+        };
+        Ok(contents
+            .lines()
+            .filter_map(|line| serde_json::from_str::<ActivityEntry>(line).ok())
+            .filter(|entry| entry.timestamp >= since)
+            .collect())
+    }
+}
@@ -3,8 +3,36 @@
 use std::collections::HashSet;
 
 use super::{BranchCategory, CompletionBranch, Repository};
+use crate::git::GitError;
 
 impl Repository {
+    /// Check if a branch matches `[safety] protected-branches` in project config.
+    pub fn is_protected_branch(&self, branch: &str) -> anyhow::Result<bool> {
+        Ok(self
+            .load_project_config()?
+            .is_some_and(|config| config.is_protected_branch(branch)))
+    }
+
+    /// Fail with [`GitError::ProtectedBranch`] if the branch is protected.
+    ///
+    /// `override_flag` names the flag that lets the operation proceed (shown as a hint).
+    pub fn ensure_not_protected(
+        &self,
+        branch: &str,
+        action: &str,
+        override_flag: Option<&str>,
+    ) -> anyhow::Result<()> {
+        if self.is_protected_branch(branch)? {
+            return Err(GitError::ProtectedBranch {
+                branch: branch.into(),
+                action: action.into(),
+                override_flag: override_flag.map(String::from),
+            }
+            .into());
+        }
+        Ok(())
+    }
+
     /// Check if a local git branch exists.
     pub fn local_branch_exists(&self, branch: &str) -> anyhow::Result<bool> {
         Ok(self
@@ -32,6 +60,78 @@ impl Repository {
             .is_ok())
     }
 
+    /// Get the branch namespace from the project's `[branch] prefix`.
+    ///
+    /// `{user}` expands to the local part of `git config user.email`, falling
+    /// back to the login name. Returns `None` if no prefix is configured or
+    /// `{user}` can't be determined.
+    ///
+    /// Result is cached in the shared repo cache (shared across all worktrees).
+    pub fn branch_prefix(&self) -> Option<String> {
+        self.cache
+            .branch_prefix
+            .get_or_init(|| {
+                let config = self.load_project_config().ok().flatten()?;
+                let template = config.branch_prefix()?;
+                if !template.contains("{user}") {
+                    return Some(template.to_string());
+                }
+                let Some(user) = self.branch_prefix_user() else {
+                    log::debug!(
+                        "Can't determine {{user}} for branch prefix '{template}', ignoring"
+                    );
+                    return None;
+                };
+                Some(template.replace("{user}", &user))
+            })
+            .clone()
+    }
+
+    /// The prefix to hide when showing branch names, if `[branch] strip-prefix` allows.
+    pub fn branch_display_prefix(&self) -> Option<String> {
+        let strip = self
+            .load_project_config()
+            .ok()
+            .flatten()
+            .is_none_or(|config| config.strip_branch_prefix());
+        self.branch_prefix().filter(|_| strip)
+    }
+
+    /// Apply the branch prefix to a new branch name, unless it already has it.
+    pub fn with_branch_prefix(&self, name: &str) -> String {
+        match self.branch_prefix() {
+            Some(prefix) if !name.starts_with(&prefix) => format!("{prefix}{name}"),
+            _ => name.to_string(),
+        }
+    }
+
+    /// Resolve a name typed without the branch prefix to the prefixed branch.
+    ///
+    /// Returns `name` unchanged when a branch has that name, or when no local
+    /// branch exists with the prefix applied.
+    pub fn resolve_branch_prefix(&self, name: &str) -> anyhow::Result<String> {
+        let prefixed = self.with_branch_prefix(name);
+        if prefixed != name && !self.branch_exists(name)? && self.local_branch_exists(&prefixed)? {
+            return Ok(prefixed);
+        }
+        Ok(name.to_string())
+    }
+
+    fn branch_prefix_user(&self) -> Option<String> {
+        let from_email = self
+            .run_command(&["config", "user.email"])
+            .ok()
+            .and_then(|email| email.trim().split('@').next().map(str::to_string));
+        from_email
+            .into_iter()
+            .chain(
+                ["USER", "USERNAME"]
+                    .into_iter()
+                    .filter_map(|var| std::env::var(var).ok()),
+            )
+            .find(|user| !user.is_empty())
+    }
+
     /// Check if a git reference exists (branch, tag, commit SHA, HEAD, etc.).
     ///
     /// Accepts any valid commit-ish: branch names, tags, HEAD, commit SHAs,
@@ -162,17 +262,28 @@ impl Repository {
         Ok(upstreams)
     }
 
-    /// List remote branches that aren't tracked by any local branch.
+    /// List remote-only branches: those without a local counterpart.
     ///
-    /// Returns (branch_name, commit_sha) pairs for remote branches that have no
-    /// corresponding local tracking branch.
+    /// Returns (branch_name, commit_sha) pairs for remote branches that no local
+    /// branch tracks or shares a name with. `origin/feature` is left out when a
+    /// local `feature` exists, even if it was pushed without `-u`.
     pub fn list_untracked_remote_branches(&self) -> anyhow::Result<Vec<(String, String)>> {
         let all_remote_branches = self.list_remote_branches()?;
         let tracked_upstreams = self.list_tracked_upstreams()?;
+        let local_branches: HashSet<String> = self
+            .list_local_branches()?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
 
         let remote_branches: Vec<_> = all_remote_branches
             .into_iter()
-            .filter(|(remote_branch_name, _)| !tracked_upstreams.contains(remote_branch_name))
+            .filter(|(remote_branch_name, _)| {
+                !tracked_upstreams.contains(remote_branch_name)
+                    && !remote_branch_name
+                        .split_once('/')
+                        .is_some_and(|(_, branch)| local_branches.contains(branch))
+            })
             .collect();
 
         Ok(remote_branches)
@@ -195,6 +306,31 @@ impl Repository {
         }
     }
 
+    /// Remote that `branch` is configured to track, from `branch.<name>.remote`.
+    ///
+    /// Unlike [`upstream_branch`](Self::upstream_branch), this doesn't need the
+    /// remote-tracking ref, so it also covers a branch that hasn't been pushed yet.
+    pub fn configured_upstream_remote(&self, branch: &str) -> anyhow::Result<Option<String>> {
+        let remote = self.get_config(&format!("branch.{branch}.remote"))?;
+        let merge = self.get_config(&format!("branch.{branch}.merge"))?;
+        // `.` tracks a local branch, which isn't an upstream on a remote
+        Ok(remote.filter(|r| r != "." && merge.is_some()))
+    }
+
+    /// Make `branch` track a same-named branch on `remote`, which may not exist yet.
+    ///
+    /// Writes the config directly: `git branch --set-upstream-to` refuses a
+    /// remote branch it hasn't seen. Also records the branch as not pushed yet
+    /// (see [`tracks_unpushed`](Self::tracks_unpushed)).
+    pub fn set_upstream(&self, branch: &str, remote: &str) -> anyhow::Result<()> {
+        self.set_config(&format!("branch.{branch}.remote"), remote)?;
+        self.set_config(
+            &format!("branch.{branch}.merge"),
+            &format!("refs/heads/{branch}"),
+        )?;
+        self.set_config(&format!("worktrunk.state.{branch}.unpushed"), "true")
+    }
+
     /// Get branches that don't have worktrees (available for switch).
     pub fn available_branches(&self) -> anyhow::Result<Vec<String>> {
         let all_branches = self.all_branches()?;
@@ -215,11 +351,13 @@ impl Repository {
 
     /// Get branches with metadata for shell completions.
     ///
-    /// Returns branches in completion order: worktrees first, then local branches,
-    /// then remote-only branches. Each category is sorted by recency.
+    /// Returns branches in completion order: worktrees first (by recency), then
+    /// local branches and remote-only branches merged by recency.
     ///
-    /// For remote branches, returns the local name (e.g., "fix" not "origin/fix")
-    /// since `git worktree add path fix` auto-creates a tracking branch.
+    /// Remote branches come from the primary remote, the one `wt switch` resolves
+    /// them against, and are offered by their local name (e.g., "fix" not
+    /// "origin/fix"), since `git worktree add path fix` auto-creates a tracking
+    /// branch.
     pub fn branches_for_completion(&self) -> anyhow::Result<Vec<CompletionBranch>> {
         // Get worktree branches
         let worktrees = self.list_worktrees()?;
@@ -228,105 +366,77 @@ impl Repository {
             .filter_map(|wt| wt.branch.clone())
             .collect();
 
-        // Get local branches with timestamps
-        let local_output = self.run_command(&[
-            "for-each-ref",
-            "--sort=-committerdate",
-            "--format=%(refname:lstrip=2)\t%(committerdate:unix)",
-            "refs/heads/",
-        ])?;
-
-        let local_branches: Vec<(String, i64)> = local_output
-            .lines()
-            .filter_map(|line| {
-                let parts: Vec<&str> = line.split('\t').collect();
-                if parts.len() == 2 {
-                    let timestamp = parts[1].parse().unwrap_or(0);
-                    Some((parts[0].to_string(), timestamp))
-                } else {
-                    None
-                }
-            })
-            .collect();
-
-        let local_branch_names: HashSet<String> =
-            local_branches.iter().map(|(n, _)| n.clone()).collect();
-
-        // Get remote branches with timestamps (if remotes exist)
-        let remote_branches: Vec<(String, String, i64)> = if let Ok(remote) = self.primary_remote()
-        {
-            let remote_ref_path = format!("refs/remotes/{}/", remote);
-            let remote_prefix = format!("{}/", remote);
-
-            let remote_output = self.run_command(&[
-                "for-each-ref",
-                "--sort=-committerdate",
-                "--format=%(refname:lstrip=2)\t%(committerdate:unix)",
-                &remote_ref_path,
-            ])?;
-
-            let remote_head = format!("{}/HEAD", remote);
-            remote_output
-                .lines()
-                .filter_map(|line| {
-                    let parts: Vec<&str> = line.split('\t').collect();
-                    if parts.len() == 2 {
-                        let full_name = parts[0];
-                        // Skip <remote>/HEAD
-                        if full_name == remote_head {
-                            return None;
-                        }
-                        // Strip remote prefix to get local name
+        let local_branches = self.refs_by_recency("refs/heads/")?;
+        let local_branch_names: HashSet<&str> =
+            local_branches.iter().map(|(n, _)| n.as_str()).collect();
+
+        // Remote-only branches from the primary remote, which is where
+        // `wt switch` looks them up
+        let remote_branches: Vec<CompletionBranch> = match self.primary_remote() {
+            Ok(remote) => {
+                let remote_prefix = format!("{remote}/");
+                self.refs_by_recency(&format!("refs/remotes/{remote}/"))?
+                    .into_iter()
+                    .filter_map(|(full_name, timestamp)| {
                         let local_name = full_name.strip_prefix(&remote_prefix)?;
-                        // Skip if local branch exists (user should use local)
-                        if local_branch_names.contains(local_name) {
+                        // Skip <remote>/HEAD, and branches that exist locally (user should use local)
+                        if local_name == "HEAD" || local_branch_names.contains(local_name) {
                             return None;
                         }
-                        let timestamp = parts[1].parse().unwrap_or(0);
-                        Some((local_name.to_string(), remote.to_string(), timestamp))
-                    } else {
-                        None
-                    }
-                })
-                .collect()
-        } else {
-            Vec::new()
+                        Some(CompletionBranch {
+                            name: local_name.to_string(),
+                            timestamp,
+                            category: BranchCategory::Remote(remote.clone()),
+                        })
+                    })
+                    .collect()
+            }
+            Err(_) => Vec::new(),
         };
 
-        // Build result: worktrees first, then local, then remote
-        let mut result = Vec::new();
-
         // Worktree branches (sorted by recency from local_branches order)
-        for (name, timestamp) in &local_branches {
-            if worktree_branches.contains(name) {
-                result.push(CompletionBranch {
-                    name: name.clone(),
-                    timestamp: *timestamp,
-                    category: BranchCategory::Worktree,
-                });
-            }
-        }
-
-        // Local branches without worktrees
-        for (name, timestamp) in &local_branches {
-            if !worktree_branches.contains(name) {
-                result.push(CompletionBranch {
-                    name: name.clone(),
-                    timestamp: *timestamp,
-                    category: BranchCategory::Local,
-                });
-            }
-        }
+        let mut result: Vec<CompletionBranch> = local_branches
+            .iter()
+            .filter(|(name, _)| worktree_branches.contains(name))
+            .map(|(name, timestamp)| CompletionBranch {
+                name: name.clone(),
+                timestamp: *timestamp,
+                category: BranchCategory::Worktree,
+            })
+            .collect();
 
-        // Remote-only branches
-        for (local_name, remote_name, timestamp) in remote_branches {
-            result.push(CompletionBranch {
-                name: local_name,
+        // Local branches without worktrees and remote-only branches, most recent first
+        let mut others: Vec<CompletionBranch> = local_branches
+            .into_iter()
+            .filter(|(name, _)| !worktree_branches.contains(name))
+            .map(|(name, timestamp)| CompletionBranch {
+                name,
                 timestamp,
-                category: BranchCategory::Remote(remote_name),
-            });
-        }
+                category: BranchCategory::Local,
+            })
+            .chain(remote_branches)
+            .collect();
+        // Stable, so a local branch stays ahead of a remote one with the same time
+        others.sort_by_key(|branch| std::cmp::Reverse(branch.timestamp));
+        result.extend(others);
 
         Ok(result)
     }
+
+    /// Short ref names under `prefix` with their commit times, most recent first.
+    fn refs_by_recency(&self, prefix: &str) -> anyhow::Result<Vec<(String, i64)>> {
+        let output = self.run_command(&[
+            "for-each-ref",
+            "--sort=-committerdate",
+            "--format=%(refname:lstrip=2)\t%(committerdate:unix)",
+            prefix,
+        ])?;
+        Ok(output
+            .lines()
+            .filter_map(|line| {
+                let (name, timestamp) = line.split_once('\t')?;
+                Some((name.to_string(), timestamp.parse().unwrap_or(0)))
+            })
+            .collect())
+    }
 }
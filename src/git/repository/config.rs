@@ -7,6 +7,40 @@ use crate::config::ProjectConfig;
 
 use super::{DefaultBranchName, GitError, Repository};
 
+/// Number of branches kept in worktrunk.history for `wt switch -N`.
+const SWITCH_HISTORY_LIMIT: usize = 10;
+
+/// How long a `wt list --index` listing can be addressed by row number.
+const LIST_INDEX_TTL_SECS: u64 = 60 * 60;
+
+/// A `wt merge` that has started rewriting the branch but hasn't pushed.
+///
+/// Stored as JSON in `worktrunk.state.<branch>.merge` so the next `wt merge`
+/// can resume (`--continue`) or roll back (`--abort`) after an interruption.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct MergeProgress {
+    /// Branch being merged into
+    pub target: String,
+    /// HEAD before the merge committed, squashed, or rebased
+    pub head: String,
+    /// HEAD once commit/squash finished, before rebasing
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tip: Option<String>,
+    pub set_at: u64,
+}
+
+/// A branch checked out with `wt review`.
+///
+/// Stored as JSON in `worktrunk.state.<branch>.review`. Commands that write
+/// to the branch refuse to run until it's released.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ReviewState {
+    /// Tracked files were made read-only (`--read-only`)
+    #[serde(default)]
+    pub read_only: bool,
+    pub set_at: u64,
+}
+
 impl Repository {
     /// Get a git config value. Returns None if the key doesn't exist.
     pub fn get_config(&self, key: &str) -> anyhow::Result<Option<String>> {
@@ -47,14 +81,83 @@ impl Repository {
         branch.and_then(|branch| self.branch_keyed_marker(branch))
     }
 
+    /// Distinct marker texts set on any branch, sorted.
+    pub fn markers(&self) -> Vec<String> {
+        #[derive(serde::Deserialize)]
+        struct MarkerValue {
+            marker: Option<String>,
+        }
+
+        let output = self
+            .run_command(&["config", "--get-regexp", r"^worktrunk\.state\..+\.marker$"])
+            .unwrap_or_default();
+        let markers: std::collections::BTreeSet<String> = output
+            .lines()
+            // Format: "worktrunk.state.feature.marker {"marker":"🚧 WIP","set_at":...}"
+            .filter_map(|line| line.split_once(' '))
+            .filter_map(|(_, raw)| serde_json::from_str::<MarkerValue>(raw).ok()?.marker)
+            .collect();
+        markers.into_iter().collect()
+    }
+
+    /// Read a branch's description from `branch.<name>.description` in git config.
+    ///
+    /// This is the same key `git branch --edit-description` writes.
+    pub fn branch_description(&self, branch: &str) -> Option<String> {
+        let config_key = format!("branch.{branch}.description");
+        self.run_command(&["config", "--get", &config_key])
+            .ok()
+            .map(|output| output.trim().to_string())
+            .filter(|s| !s.is_empty())
+    }
+
+    /// Set a branch's description, or clear it when `description` is `None`.
+    pub fn set_branch_description(
+        &self,
+        branch: &str,
+        description: Option<&str>,
+    ) -> anyhow::Result<()> {
+        let config_key = format!("branch.{branch}.description");
+        match description {
+            Some(text) => {
+                self.run_command(&["config", &config_key, text])?;
+            }
+            None => {
+                // Exit code 5 means the key wasn't set; clearing it is still a success
+                let output = self.run_command_output(&["config", "--unset", &config_key])?;
+                if !output.status.success() && output.status.code() != Some(5) {
+                    let stderr = String::from_utf8_lossy(&output.stderr);
+                    anyhow::bail!("git config --unset {config_key} failed: {}", stderr.trim());
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Record the previous branch in worktrunk.history for `wt switch -` support.
     ///
     /// Stores the branch we're switching FROM, so `wt switch -` can return to it.
+    /// Earlier branches stay in the history (most recent first, without
+    /// duplicates) for `wt switch -N` and `wt switch --history`. The whole
+    /// history is one space-separated value — branch names can't contain
+    /// spaces — so recording costs one read and one write.
     pub fn record_switch_previous(&self, previous: Option<&str>) -> anyhow::Result<()> {
-        if let Some(prev) = previous {
-            self.run_command(&["config", "worktrunk.history", prev])?;
-        }
         // If previous is None (detached HEAD), don't update history
+        let Some(prev) = previous else {
+            return Ok(());
+        };
+
+        let mut history = self.switch_history();
+        history.retain(|branch| branch != prev);
+        history.insert(0, prev.to_string());
+        history.truncate(SWITCH_HISTORY_LIMIT);
+
+        self.run_command(&[
+            "config",
+            "--replace-all",
+            "worktrunk.history",
+            &history.join(" "),
+        ])?;
         Ok(())
     }
 
@@ -62,10 +165,129 @@ impl Repository {
     ///
     /// Returns the branch we came from, enabling ping-pong switching.
     pub fn get_switch_previous(&self) -> Option<String> {
+        self.switch_history().into_iter().next()
+    }
+
+    /// Get the switch history from worktrunk.history, most recent first.
+    pub fn switch_history(&self) -> Vec<String> {
         self.run_command(&["config", "--get", "worktrunk.history"])
-            .ok()
-            .map(|s| s.trim().to_string())
-            .filter(|s| !s.is_empty())
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(String::from)
+            .collect()
+    }
+
+    /// Clear the switch history. Returns whether there was any history to clear.
+    pub fn clear_switch_history(&self) -> bool {
+        self.run_command(&["config", "--unset-all", "worktrunk.history"])
+            .is_ok()
+    }
+
+    /// Get the in-progress merge record for `branch`, if any.
+    pub fn merge_progress(&self, branch: &str) -> Option<MergeProgress> {
+        let config_key = format!("worktrunk.state.{branch}.merge");
+        let raw = self.run_command(&["config", "--get", &config_key]).ok()?;
+        serde_json::from_str(raw.trim()).ok()
+    }
+
+    /// Record an in-progress merge for `branch`.
+    pub fn set_merge_progress(&self, branch: &str, progress: &MergeProgress) -> anyhow::Result<()> {
+        let config_key = format!("worktrunk.state.{branch}.merge");
+        self.run_command(&["config", &config_key, &serde_json::to_string(progress)?])?;
+        Ok(())
+    }
+
+    /// Clear the in-progress merge record for `branch`.
+    ///
+    /// Returns true if a record was present.
+    pub fn clear_merge_progress(&self, branch: &str) -> bool {
+        let config_key = format!("worktrunk.state.{branch}.merge");
+        self.run_command(&["config", "--unset", &config_key])
+            .is_ok()
+    }
+
+    /// Whether `branch` was set to track a remote branch before its first push.
+    ///
+    /// Git can't tell that apart from an upstream deleted on the remote: both
+    /// leave tracking config without a remote-tracking ref.
+    pub fn tracks_unpushed(&self, branch: &str) -> bool {
+        let config_key = format!("worktrunk.state.{branch}.unpushed");
+        self.run_command(&["config", "--get", &config_key]).is_ok()
+    }
+
+    /// Get the review record for `branch`, if it's checked out with `wt review`.
+    pub fn review_state(&self, branch: &str) -> Option<ReviewState> {
+        let config_key = format!("worktrunk.state.{branch}.review");
+        let raw = self.run_command(&["config", "--get", &config_key]).ok()?;
+        serde_json::from_str(raw.trim()).ok()
+    }
+
+    /// Record `branch` as checked out for review.
+    pub fn set_review_state(&self, branch: &str, state: &ReviewState) -> anyhow::Result<()> {
+        let config_key = format!("worktrunk.state.{branch}.review");
+        self.run_command(&["config", &config_key, &serde_json::to_string(state)?])?;
+        Ok(())
+    }
+
+    /// Clear the review record for `branch`.
+    ///
+    /// Returns true if a record was present.
+    pub fn clear_review_state(&self, branch: &str) -> bool {
+        let config_key = format!("worktrunk.state.{branch}.review");
+        self.run_command(&["config", "--unset", &config_key])
+            .is_ok()
+    }
+
+    /// Branches with a review record, sorted.
+    pub fn review_branches(&self) -> Vec<String> {
+        let output = self
+            .run_command(&["config", "--get-regexp", r"^worktrunk\.state\..+\.review$"])
+            .unwrap_or_default();
+        let mut branches: Vec<String> = output
+            .lines()
+            // Format: "worktrunk.state.feature.review {"read_only":false,"set_at":...}"
+            .filter_map(|line| line.split_once(' '))
+            .filter_map(|(key, _)| {
+                key.strip_prefix("worktrunk.state.")?
+                    .strip_suffix(".review")
+                    .map(str::to_string)
+            })
+            .collect();
+        branches.sort();
+        branches
+    }
+
+    /// Record the rows of an indexed `wt list` for numeric addressing.
+    ///
+    /// Stored as JSON in `worktrunk.list-index`:
+    /// `{"branches": ["main", null, ...], "set_at": unix_timestamp}`, where
+    /// `null` marks a row without a branch (detached HEAD).
+    pub fn record_list_index(&self, branches: &[Option<String>]) -> anyhow::Result<()> {
+        let value = serde_json::json!({
+            "branches": branches,
+            "set_at": crate::utils::get_now(),
+        });
+        self.run_command(&["config", "worktrunk.list-index", &value.to_string()])?;
+        Ok(())
+    }
+
+    /// Get the rows of the last indexed `wt list`, if it was recorded recently.
+    ///
+    /// Listings expire after [`LIST_INDEX_TTL_SECS`], since worktrees come and go
+    /// and stale numbers would silently address the wrong row.
+    pub fn list_index(&self) -> Option<Vec<Option<String>>> {
+        #[derive(serde::Deserialize)]
+        struct ListIndexValue {
+            branches: Vec<Option<String>>,
+            set_at: u64,
+        }
+
+        let raw = self
+            .run_command(&["config", "--get", "worktrunk.list-index"])
+            .ok()?;
+        let parsed: ListIndexValue = serde_json::from_str(raw.trim()).ok()?;
+        let age = crate::utils::get_now().saturating_sub(parsed.set_at);
+        (age <= LIST_INDEX_TTL_SECS).then_some(parsed.branches)
     }
 
     /// Check if a hint has been shown in this repo.
@@ -205,8 +427,13 @@ impl Repository {
     }
 
     /// Try to detect default branch from remote.
+    ///
+    /// Prefers the upstream remote in fork workflows, since a fork's default
+    /// branch may lag behind or differ from the original repository's.
     fn detect_from_remote(&self) -> Option<String> {
-        let remote = self.primary_remote().ok()?;
+        let remote = self
+            .upstream_remote()
+            .or_else(|| self.primary_remote().ok())?;
 
         // Try git's local cache for this remote (e.g., origin/HEAD)
         if let Ok(branch) = self.get_local_default_branch(&remote) {
@@ -217,6 +444,34 @@ impl Repository {
         self.query_remote_default_branch(&remote).ok()
     }
 
+    /// Get the ref that ahead/behind stats compare against.
+    ///
+    /// Normally the default branch itself. With `[repo] upstream-remote`, it's the
+    /// upstream remote's copy (e.g. `upstream/main`), so `wt list` shows how far
+    /// branches are from the canonical repository while pushes still go to `origin`.
+    /// Falls back to the default branch if that remote-tracking ref hasn't been
+    /// fetched yet.
+    ///
+    /// Returns `None` if the default branch cannot be determined.
+    ///
+    /// Result is cached in the shared repo cache (shared across all worktrees).
+    pub fn ahead_behind_base(&self) -> Option<String> {
+        self.cache
+            .ahead_behind_base
+            .get_or_init(|| {
+                let default_branch = self.default_branch()?;
+                let upstream_ref = self
+                    .upstream_remote()
+                    .map(|remote| format!("{remote}/{default_branch}"))
+                    .filter(|r| {
+                        self.ref_exists(&format!("refs/remotes/{r}"))
+                            .unwrap_or(false)
+                    });
+                Some(upstream_ref.unwrap_or(default_branch))
+            })
+            .clone()
+    }
+
     /// Resolve a target branch from an optional override
     ///
     /// If target is Some, expands special symbols ("@", "-", "^") via `resolve_worktree_name`.
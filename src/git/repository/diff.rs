@@ -85,6 +85,34 @@ impl Repository {
         Ok(result)
     }
 
+    /// Get committer emails for multiple commits in a single git command.
+    ///
+    /// Returns a map from commit SHA to committer email, as `%ce` prints it.
+    pub fn commit_committer_emails(
+        &self,
+        commits: &[&str],
+    ) -> anyhow::Result<HashMap<String, String>> {
+        if commits.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut args = vec!["show", "-s", "--format=%H %ce"];
+        args.extend(commits);
+
+        let stdout = self.run_command(&args)?;
+        Ok(stdout
+            .lines()
+            .filter_map(|line| line.split_once(' '))
+            .map(|(sha, email)| (sha.to_string(), email.trim().to_string()))
+            .collect())
+    }
+
+    /// Get the author name of a commit.
+    pub fn commit_author(&self, commit: &str) -> anyhow::Result<String> {
+        let stdout = self.run_command(&["show", "-s", "--format=%an", commit])?;
+        Ok(stdout.trim().to_owned())
+    }
+
     /// Get commit message (subject line) for a commit.
     pub fn commit_message(&self, commit: &str) -> anyhow::Result<String> {
         let stdout = self.run_command(&["show", "-s", "--format=%s", commit])?;
@@ -34,6 +34,7 @@ use super::{DefaultBranchName, GitError, LineDiff, WorktreeInfo};
 pub(super) use super::{BranchCategory, CompletionBranch, DiffStats, GitRemoteUrl};
 
 // Submodules with impl blocks
+mod activity;
 mod branches;
 mod config;
 mod diff;
@@ -43,6 +44,9 @@ mod working_tree;
 mod worktrees;
 
 // Re-export WorkingTree
+pub use activity::{ActivityEntry, ActivityKind};
+pub use config::{MergeProgress, ReviewState};
+pub use remotes::CloneKind;
 pub use working_tree::WorkingTree;
 pub(super) use working_tree::path_to_logging_context;
 
@@ -72,6 +76,14 @@ pub(super) struct RepoCache {
     pub(super) integration_target: OnceCell<Option<String>>,
     /// Primary remote name (None if no remotes configured)
     pub(super) primary_remote: OnceCell<Option<String>>,
+    /// Upstream remote from `[repo] upstream-remote` (None if unset or missing)
+    pub(super) upstream_remote: OnceCell<Option<String>>,
+    /// Expanded `[branch] prefix` (None if unset)
+    pub(super) branch_prefix: OnceCell<Option<String>>,
+    /// Ref that ahead/behind stats compare against (default branch or its upstream copy)
+    pub(super) ahead_behind_base: OnceCell<Option<String>>,
+    /// Shallow/partial clone detection
+    pub(super) clone_kind: OnceCell<CloneKind>,
     /// Primary remote URL (None if no remotes configured or no URL)
     pub(super) primary_remote_url: OnceCell<Option<String>>,
     /// Project identifier derived from remote URL
@@ -125,6 +137,19 @@ pub fn set_base_path(path: PathBuf) {
     BASE_PATH.set(path).ok();
 }
 
+/// The directory a command acts in: the process's working directory, moved by
+/// the -C flag when given.
+///
+/// Use this instead of `std::env::current_dir()` for anything about the
+/// repository the command targets, such as where hooks run.
+pub fn base_dir() -> std::io::Result<PathBuf> {
+    let cwd = std::env::current_dir()?;
+    Ok(match BASE_PATH.get() {
+        Some(path) => cwd.join(path),
+        None => cwd,
+    })
+}
+
 /// Get the base path for repository operations.
 fn base_path() -> &'static PathBuf {
     static DEFAULT: OnceLock<PathBuf> = OnceLock::new();
@@ -299,6 +324,67 @@ impl Repository {
         self.git_common_dir().join("wt-logs")
     }
 
+    /// Get the directory where removed worktrees wait to be deleted.
+    ///
+    /// Removal renames a worktree into `.git/wt-trash/` (cheap on the same
+    /// filesystem) and deletes it from there, so the worktree disappears
+    /// immediately even when deleting its files takes a while.
+    pub fn wt_trash_dir(&self) -> PathBuf {
+        self.git_common_dir().join("wt-trash")
+    }
+
+    /// Get the directory holding cached `wt list --format=json` output.
+    pub fn wt_list_cache_dir(&self) -> PathBuf {
+        self.git_common_dir().join("wt-list-cache")
+    }
+
+    /// Append ignore patterns to `.git/info/exclude`, skipping ones already there.
+    ///
+    /// The file lives in the common dir, so the patterns apply to every worktree.
+    /// Returns the patterns that were added.
+    pub fn add_excludes(&self, patterns: &[String]) -> anyhow::Result<Vec<String>> {
+        let path = self.git_common_dir().join("info").join("exclude");
+        let existing = match std::fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+        let present: Vec<&str> = existing.lines().map(str::trim).collect();
+        let mut added: Vec<String> = Vec::new();
+        for pattern in patterns {
+            let pattern = pattern.trim();
+            if !pattern.is_empty()
+                && !present.contains(&pattern)
+                && !added.iter().any(|p| p == pattern)
+            {
+                added.push(pattern.to_string());
+            }
+        }
+        if added.is_empty() {
+            return Ok(added);
+        }
+
+        let mut content = existing;
+        if !content.is_empty() && !content.ends_with('\n') {
+            content.push('\n');
+        }
+        const HEADER: &str = "# Added by worktrunk ([switch] exclude)";
+        if !present.contains(&HEADER) {
+            content.push_str(HEADER);
+            content.push('\n');
+        }
+        for pattern in &added {
+            content.push_str(pattern);
+            content.push('\n');
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(added)
+    }
+
     /// The repository root path.
     ///
     /// For normal repositories: the main worktree directory (parent of .git).
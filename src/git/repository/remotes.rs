@@ -4,7 +4,68 @@ use anyhow::Context;
 
 use super::{GitRemoteUrl, Repository};
 
+/// How much of the remote's history and content a clone holds.
+///
+/// Shallow clones (`--depth`) stop history at a boundary, so merge-bases past
+/// it are missing and ahead/behind counts only cover fetched commits. Partial
+/// clones (`--filter=blob:none`) fetch missing objects from the remote on
+/// demand, so every diff can turn into a series of network round trips.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CloneKind {
+    /// History ends at a shallow boundary.
+    pub shallow: bool,
+    /// Remote that missing objects are fetched from, with its object filter.
+    pub partial: Option<(String, String)>,
+}
+
+impl CloneKind {
+    /// Whether the clone holds full history and all objects.
+    pub fn is_complete(&self) -> bool {
+        !self.shallow && self.partial.is_none()
+    }
+
+    /// Short description for indicators, e.g. "Shallow clone".
+    pub fn label(&self) -> Option<&'static str> {
+        match (self.shallow, self.partial.is_some()) {
+            (false, false) => None,
+            (true, false) => Some("Shallow clone"),
+            (false, true) => Some("Partial clone"),
+            (true, true) => Some("Shallow partial clone"),
+        }
+    }
+}
+
 impl Repository {
+    /// Detect whether this is a shallow and/or partial clone.
+    ///
+    /// Result is cached in the shared repo cache (shared across all worktrees).
+    pub fn clone_kind(&self) -> CloneKind {
+        self.cache
+            .clone_kind
+            .get_or_init(|| {
+                // git writes `shallow` in the common dir while history is truncated
+                let shallow = self.git_common_dir().join("shallow").exists();
+                // "remote.<name>.partialclonefilter <filter>"
+                let partial = self
+                    .run_command(&[
+                        "config",
+                        "--get-regexp",
+                        r"^remote\..+\.partialclonefilter$",
+                    ])
+                    .unwrap_or_default()
+                    .lines()
+                    .find_map(|line| {
+                        let (key, filter) = line.split_once(' ')?;
+                        let remote = key
+                            .strip_prefix("remote.")?
+                            .strip_suffix(".partialclonefilter")?;
+                        Some((remote.to_string(), filter.trim().to_string()))
+                    });
+                CloneKind { shallow, partial }
+            })
+            .clone()
+    }
+
     /// Get the primary remote name for this repository.
     ///
     /// Returns a consistent value across all worktrees (not branch-specific).
@@ -50,6 +111,29 @@ impl Repository {
             .ok_or_else(|| anyhow::anyhow!("No remotes configured"))
     }
 
+    /// Get the upstream remote for fork workflows.
+    ///
+    /// Returns the remote named by the project's `[repo] upstream-remote` setting,
+    /// or `None` if it's unset or this clone has no such remote (e.g. the
+    /// maintainer's clone, where `origin` is already the canonical repository).
+    ///
+    /// Result is cached in the shared repo cache (shared across all worktrees).
+    pub fn upstream_remote(&self) -> Option<String> {
+        self.cache
+            .upstream_remote
+            .get_or_init(|| {
+                let config = self.load_project_config().ok().flatten()?;
+                let remote = config.upstream_remote()?;
+                if self.remote_has_url(remote) {
+                    Some(remote.to_string())
+                } else {
+                    log::debug!("Configured upstream remote '{remote}' has no URL, ignoring");
+                    None
+                }
+            })
+            .clone()
+    }
+
     /// Check if a remote has a URL configured.
     fn remote_has_url(&self, remote: &str) -> bool {
         self.run_command(&["config", &format!("remote.{}.url", remote)])
@@ -134,4 +218,14 @@ impl Repository {
             .and_then(|config| config.list)
             .and_then(|list| list.url)
     }
+
+    /// Get the issue tracker config from project config, if configured.
+    ///
+    /// Extracts `[integrations.issues]`; returns `None` if no config exists.
+    pub fn issue_tracker(&self) -> Option<crate::config::IssueTrackerConfig> {
+        self.load_project_config()
+            .ok()
+            .flatten()
+            .and_then(|config| config.issue_tracker().cloned())
+    }
 }
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use super::super::{DefaultBranchName, WorktreeInfo, finalize_worktree};
+use super::super::{CloneKind, DefaultBranchName, WorktreeInfo, finalize_worktree};
 
 #[test]
 fn test_parse_worktree_list() {
@@ -404,3 +404,22 @@ fn test_default_branch_name_display() {
         assert_eq!(branch, expected);
     }
 }
+
+#[test]
+fn test_clone_kind_label() {
+    assert_eq!(CloneKind::default().label(), None);
+    assert!(CloneKind::default().is_complete());
+
+    let shallow = CloneKind {
+        shallow: true,
+        partial: None,
+    };
+    assert_eq!(shallow.label(), Some("Shallow clone"));
+
+    let partial = CloneKind {
+        shallow: false,
+        partial: Some(("origin".into(), "blob:none".into())),
+    };
+    assert_eq!(partial.label(), Some("Partial clone"));
+    assert!(!partial.is_complete());
+}
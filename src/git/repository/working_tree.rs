@@ -54,10 +54,19 @@ pub struct WorkingTree<'a> {
 impl<'a> WorkingTree<'a> {
     /// Run a git command in this worktree and return stdout.
     pub fn run_command(&self, args: &[&str]) -> anyhow::Result<String> {
-        let output = Cmd::new("git")
+        self.run_command_with_env(args, &[])
+    }
+
+    /// Run a git command in this worktree with extra environment variables.
+    fn run_command_with_env(&self, args: &[&str], env: &[(&str, &str)]) -> anyhow::Result<String> {
+        let mut cmd = Cmd::new("git")
             .args(args.iter().copied())
             .current_dir(&self.path)
-            .context(path_to_logging_context(&self.path))
+            .context(path_to_logging_context(&self.path));
+        for (key, value) in env {
+            cmd = cmd.env(*key, *value);
+        }
+        let output = cmd
             .run()
             .with_context(|| format!("Failed to execute: git {}", args.join(" ")))?;
 
@@ -147,18 +156,53 @@ impl<'a> WorkingTree<'a> {
         }
     }
 
+    /// Owner recorded in the worktree's `WT_OWNER` marker file, if any.
+    ///
+    /// The marker lives in the worktree's git dir, next to git's own
+    /// per-worktree files, so it never shows up as an untracked change.
+    pub fn owner_marker(&self) -> Option<String> {
+        let path = self.git_dir().ok()?.join("WT_OWNER");
+        std::fs::read_to_string(path)
+            .ok()
+            .map(|content| content.trim().to_string())
+            .filter(|owner| !owner.is_empty())
+    }
+
     /// Check if a rebase is in progress.
     pub fn is_rebasing(&self) -> anyhow::Result<bool> {
         let git_dir = self.git_dir()?;
         Ok(git_dir.join("rebase-merge").exists() || git_dir.join("rebase-apply").exists())
     }
 
+    /// Branch being rebased, while a rebase has HEAD detached.
+    pub fn rebasing_branch(&self) -> Option<String> {
+        let git_dir = self.git_dir().ok()?;
+        ["rebase-merge", "rebase-apply"].iter().find_map(|dir| {
+            let head_name = std::fs::read_to_string(git_dir.join(dir).join("head-name")).ok()?;
+            head_name
+                .trim()
+                .strip_prefix("refs/heads/")
+                .map(str::to_string)
+        })
+    }
+
     /// Check if a merge is in progress.
     pub fn is_merging(&self) -> anyhow::Result<bool> {
         let git_dir = self.git_dir()?;
         Ok(git_dir.join("MERGE_HEAD").exists())
     }
 
+    /// The git operation left in progress, as its git command: `"rebase"` or `"merge"`.
+    pub fn pending_operation(&self) -> anyhow::Result<Option<&'static str>> {
+        Ok(if self.is_rebasing()? {
+            Some("rebase")
+        } else if self.is_merging()? {
+            Some("merge")
+        } else {
+            None
+        })
+    }
+
     /// Check if this is a linked worktree (vs the main worktree).
     ///
     /// Returns `true` for linked worktrees (created via `git worktree add`),
@@ -175,6 +219,23 @@ impl<'a> WorkingTree<'a> {
         Ok(git_dir != common_dir)
     }
 
+    /// Check if this worktree has initialized submodules.
+    ///
+    /// Mirrors the check `git worktree remove` makes before refusing to remove
+    /// a worktree without `--force`: a `modules/` directory in the worktree's
+    /// git dir, or a gitlink in the index whose directory has been checked out.
+    pub fn has_submodules(&self) -> anyhow::Result<bool> {
+        if self.git_dir()?.join("modules").is_dir() {
+            return Ok(true);
+        }
+        let stdout = self.run_command(&["ls-files", "--stage"])?;
+        Ok(stdout
+            .lines()
+            .filter(|line| line.starts_with("160000 "))
+            .filter_map(|line| line.split_once('\t').map(|(_, path)| path))
+            .any(|path| self.path.join(path).join(".git").exists()))
+    }
+
     /// Ensure this worktree is clean (no uncommitted changes).
     ///
     /// Returns an error if there are uncommitted changes.
@@ -210,6 +271,29 @@ impl<'a> WorkingTree<'a> {
         LineDiff::from_numstat(&stdout)
     }
 
+    /// Files changed in this worktree since it diverged from `base`.
+    ///
+    /// Diffs the merge-base against the working tree, so committed, staged and
+    /// unstaged changes to tracked files all count. Returns `None` when there's
+    /// no common ancestor, since every file is then new relative to `base`.
+    pub fn changed_files_since(&self, base: &str) -> anyhow::Result<Option<Vec<String>>> {
+        let Some(merge_base) = self
+            .run_command(&["merge-base", base, "HEAD"])
+            .ok()
+            .map(|sha| sha.trim().to_string())
+        else {
+            return Ok(None);
+        };
+        let stdout = self.run_command(&["diff", "--name-only", "-z", &merge_base])?;
+        Ok(Some(
+            stdout
+                .split('\0')
+                .filter(|path| !path.is_empty())
+                .map(str::to_string)
+                .collect(),
+        ))
+    }
+
     /// Determine whether there are staged changes in the index.
     ///
     /// Returns `Ok(true)` when staged changes are present, `Ok(false)` otherwise.
@@ -225,6 +309,154 @@ impl<'a> WorkingTree<'a> {
             .is_err())
     }
 
+    /// Commit staged changes with the given message.
+    ///
+    /// Git applies `commit.gpgsign` itself; `signoff` adds a `Signed-off-by` trailer.
+    /// Signing failures are reported as [`GitError::CommitSigningFailed`], which carries
+    /// a hint — GPG can't prompt for a passphrase when run without a terminal.
+    ///
+    /// With `verify`, git runs its native hooks (`pre-commit`, `commit-msg`) from
+    /// [`Self::hooks_path`]; without it, the commit is made with `--no-verify`.
+    pub fn commit(&self, message: &str, signoff: bool, verify: bool) -> anyhow::Result<()> {
+        let hooks_override = if verify {
+            self.hooks_path()?
+                .map(|path| format!("core.hooksPath={}", path.display()))
+        } else {
+            None
+        };
+
+        let mut args = Vec::new();
+        if let Some(hooks_override) = &hooks_override {
+            args.extend(["-c", hooks_override.as_str()]);
+        }
+        args.extend(["commit", "-m", message]);
+        if signoff {
+            args.push("--signoff");
+        }
+        if !verify {
+            args.push("--no-verify");
+        }
+
+        let Err(err) = self.run_command(&args) else {
+            return Ok(());
+        };
+
+        // Git has no structured signal for signing failures. Both messages have been
+        // stable across git versions; match on them only when signing is enabled so
+        // unrelated failures (e.g., native git hooks) keep their original error.
+        let error = err.to_string();
+        let signing_failed =
+            error.contains("failed to sign") || error.contains("failed to write commit object");
+        if signing_failed && self.is_commit_signing_enabled() {
+            return Err(GitError::CommitSigningFailed { error }.into());
+        }
+        Err(err)
+    }
+
+    /// Append trailers to a commit message with `git interpret-trailers`.
+    ///
+    /// Trailers join an existing trailer block rather than starting a new one,
+    /// and one already present with the same value isn't repeated.
+    pub fn append_trailers(&self, message: &str, trailers: &[String]) -> anyhow::Result<String> {
+        let mut args = vec!["interpret-trailers", "--if-exists", "addIfDifferent"];
+        for trailer in trailers {
+            args.extend(["--trailer", trailer.as_str()]);
+        }
+        let output = Cmd::new("git")
+            .args(args.iter().copied())
+            .current_dir(&self.path)
+            .context(path_to_logging_context(&self.path))
+            .stdin_bytes(message)
+            .run()
+            .context("Failed to execute: git interpret-trailers")?;
+        if !output.status.success() {
+            bail!(
+                "git interpret-trailers failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout)
+            .trim_end()
+            .to_string())
+    }
+
+    /// Run `text` through `git stripspace` with `args`, e.g. `--strip-comments`.
+    ///
+    /// Comments use the configured `core.commentChar`, as in git's own editor
+    /// messages.
+    pub fn stripspace(&self, text: &str, args: &[&str]) -> anyhow::Result<String> {
+        let output = Cmd::new("git")
+            .arg("stripspace")
+            .args(args.iter().copied())
+            .current_dir(&self.path)
+            .context(path_to_logging_context(&self.path))
+            .stdin_bytes(text)
+            .run()
+            .context("Failed to execute: git stripspace")?;
+        if !output.status.success() {
+            bail!(
+                "git stripspace failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    }
+
+    /// Resolve `core.hooksPath` for this worktree, if configured.
+    ///
+    /// Git resolves a relative `core.hooksPath` against the worktree root. Hook
+    /// managers like Husky and lefthook install into a gitignored directory there,
+    /// which a freshly created worktree doesn't have — git would then silently skip
+    /// hooks. A relative path missing here falls back to the primary worktree's copy.
+    pub fn hooks_path(&self) -> anyhow::Result<Option<PathBuf>> {
+        // `--path` expands `~`, as git does when running hooks
+        let Ok(value) = self.run_command(&["config", "--path", "core.hooksPath"]) else {
+            return Ok(None);
+        };
+        let value = PathBuf::from(value.trim());
+        if value.as_os_str().is_empty() {
+            return Ok(None);
+        }
+        if value.is_absolute() {
+            return Ok(Some(value));
+        }
+
+        let local = self.root()?.join(&value);
+        if !local.is_dir()
+            && let Some(primary) = self.repo.primary_worktree()?
+            && primary.join(&value).is_dir()
+        {
+            return Ok(Some(primary.join(&value)));
+        }
+        Ok(Some(local))
+    }
+
+    /// Fold `fixup!`, `squash!`, and `amend!` commits after `base` into their targets.
+    ///
+    /// Runs `git rebase --interactive --autosquash` without opening an editor:
+    /// the todo list is taken as generated, and `squash!` messages are combined
+    /// as git proposes. Uncommitted changes are stashed around the rebase.
+    pub fn autosquash(&self, base: &str) -> anyhow::Result<()> {
+        self.run_command_with_env(
+            &[
+                "rebase",
+                "--interactive",
+                "--autosquash",
+                "--autostash",
+                base,
+            ],
+            &[("GIT_SEQUENCE_EDITOR", ":"), ("GIT_EDITOR", ":")],
+        )?;
+        Ok(())
+    }
+
+    /// Check whether `commit.gpgsign` is enabled for this worktree.
+    pub fn is_commit_signing_enabled(&self) -> bool {
+        self.run_command(&["config", "--bool", "commit.gpgsign"])
+            .map(|v| v.trim() == "true")
+            .unwrap_or(false)
+    }
+
     /// Create a safety backup of current working tree state without affecting the working tree.
     ///
     /// This creates a backup commit containing all changes (staged, unstaged, and untracked files)
@@ -266,6 +498,16 @@ impl<'a> WorkingTree<'a> {
             .trim()
             .to_string();
 
+        self.update_backup_ref(&branch, &backup_sha, message)?;
+
+        Ok(backup_sha[..7].to_string())
+    }
+
+    /// Point `refs/wt-backup/<branch>` at a backup commit, returning the ref name.
+    ///
+    /// Each backup is a new reflog entry, so earlier backups of the same branch stay
+    /// reachable through `git reflog show refs/wt-backup/<branch>`.
+    fn update_backup_ref(&self, branch: &str, sha: &str, message: &str) -> anyhow::Result<String> {
         // Sanitize branch name for use in ref path (replace / with -)
         let safe_branch = branch.replace('/', "-");
 
@@ -279,10 +521,44 @@ impl<'a> WorkingTree<'a> {
             "-m",
             message,
             &ref_name,
-            &backup_sha,
+            sha,
         ])
         .context("Failed to create backup ref")?;
 
-        Ok(backup_sha[..7].to_string())
+        Ok(ref_name)
+    }
+
+    /// Snapshot all uncommitted changes into `refs/wt-backup/<branch>`.
+    ///
+    /// Unlike [`Self::create_safety_backup`], the snapshot is built in a temporary
+    /// index, so neither the working tree nor the real index is touched; untracked
+    /// files are included and gitignored ones excluded. The backup commit's parent
+    /// is HEAD, so `git diff HEAD <commit>` shows exactly what was saved. Both
+    /// backups share the ref, and its reflog keeps earlier ones.
+    ///
+    /// Restore into any worktree with `git checkout <commit> -- .`.
+    ///
+    /// Returns the ref name and the short SHA of the backup commit.
+    pub fn backup_uncommitted_changes(&self, branch: &str) -> anyhow::Result<(String, String)> {
+        let index_path = self.git_dir()?.join("wt-backup-index");
+        let index = index_path.to_string_lossy();
+        let env = [("GIT_INDEX_FILE", index.as_ref())];
+
+        let tree = self
+            .run_command_with_env(&["read-tree", "HEAD"], &env)
+            .and_then(|_| self.run_command_with_env(&["add", "-A"], &env))
+            .and_then(|_| self.run_command_with_env(&["write-tree"], &env));
+        let _ = std::fs::remove_file(&index_path);
+        let tree = tree.context("Failed to snapshot uncommitted changes")?;
+
+        let message = format!("wt remove --force-dirty: {branch}");
+        let sha = self
+            .run_command(&["commit-tree", tree.trim(), "-p", "HEAD", "-m", &message])?
+            .trim()
+            .to_string();
+
+        let ref_name = self.update_backup_ref(branch, &sha, &message)?;
+
+        Ok((ref_name, sha[..7].to_string()))
     }
 }
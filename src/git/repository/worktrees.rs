@@ -24,6 +24,18 @@ impl Repository {
         Ok(raw_worktrees.into_iter().filter(|wt| !wt.bare).collect())
     }
 
+    /// Describe a detached HEAD by the nearest tag containing it, e.g. `v1.2.3~2`.
+    ///
+    /// Returns `None` when no tag contains the commit.
+    pub fn describe_detached(&self, sha: &str) -> Option<String> {
+        let name = self
+            .run_command(&["describe", "--contains", "--tags", sha])
+            .ok()?;
+        // Annotated tags name the tagged commit itself `v1.2.3^0`
+        let name = name.trim();
+        Some(name.strip_suffix("^0").unwrap_or(name).to_string())
+    }
+
     /// Get the WorktreeInfo struct for the current worktree, if we're inside one.
     ///
     /// Returns `None` if not in a worktree (e.g., in bare repo directory).
@@ -118,7 +130,10 @@ impl Repository {
     /// * `name` - The worktree name to resolve:
     ///   - "@" for current HEAD
     ///   - "-" for previous branch (via worktrunk.history)
+    ///   - "-N" for the Nth most recent branch in worktrunk.history
     ///   - "^" for default branch
+    ///   - a name typed without the `[branch] prefix`, when only the prefixed
+    ///     branch exists
     ///   - any other string is returned as-is
     ///
     /// # Returns
@@ -155,7 +170,71 @@ impl Repository {
                 }
                 .into()
             }),
-            _ => Ok(name.to_string()),
+            _ if name.starts_with('-') => self.resolve_switch_history(name),
+            _ => self.resolve_branch_prefix(name),
+        }
+    }
+
+    /// Resolve "-N" to the Nth most recent branch in worktrunk.history.
+    ///
+    /// The current branch is skipped, so "-1" matches "-" and "-2" goes one
+    /// step further back. Returns `name` unchanged when it isn't "-N".
+    fn resolve_switch_history(&self, name: &str) -> anyhow::Result<String> {
+        let Some(n) = name
+            .strip_prefix('-')
+            .filter(|n| n.bytes().all(|b| b.is_ascii_digit()))
+            .and_then(|n| n.parse::<usize>().ok())
+            .filter(|&n| n > 0)
+        else {
+            return Ok(name.to_string());
+        };
+
+        let current = self.current_worktree().branch().ok().flatten();
+        let history: Vec<String> = self
+            .switch_history()
+            .into_iter()
+            .filter(|branch| Some(branch) != current.as_ref())
+            .collect();
+        history.get(n - 1).cloned().ok_or_else(|| {
+            GitError::Other {
+                message: cformat!(
+                    "No entry <bold>{n}</> in switch history. To see it, run <bright-black>wt switch --history</>"
+                ),
+            }
+            .into()
+        })
+    }
+
+    /// Resolve a row number from the last `wt list --index` to its branch.
+    ///
+    /// Only for `wt switch`'s positional argument (not with `--create` or
+    /// `--base`) and `wt remove`'s. Returns `name` unchanged when it isn't a
+    /// number, when a local or remote branch has that name, or when there's no
+    /// recent listing.
+    pub fn resolve_list_index(&self, name: &str) -> anyhow::Result<String> {
+        let Ok(index) = name.parse::<usize>() else {
+            return Ok(name.to_string());
+        };
+        if !name.bytes().all(|b| b.is_ascii_digit()) || self.branch_exists(name)? {
+            return Ok(name.to_string());
+        }
+        let Some(rows) = self.list_index() else {
+            return Ok(name.to_string());
+        };
+
+        match index.checked_sub(1).and_then(|i| rows.get(i)) {
+            Some(Some(branch)) => Ok(branch.clone()),
+            Some(None) => Err(GitError::Other {
+                message: cformat!("Row <bold>{index}</> of the last listing has no branch"),
+            }
+            .into()),
+            None => Err(GitError::Other {
+                message: cformat!(
+                    "No row <bold>{index}</> in the last listing ({} rows). Run <bright-black>wt list --index</> to renumber.",
+                    rows.len()
+                ),
+            }
+            .into()),
         }
     }
 
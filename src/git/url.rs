@@ -83,6 +83,11 @@ impl GitRemoteUrl {
         })
     }
 
+    /// The host without scheme or user (e.g., "github.com").
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
     /// The repository owner or organization (e.g., "owner", "company-org").
     pub fn owner(&self) -> &str {
         &self.owner
@@ -129,6 +134,11 @@ pub fn parse_owner_repo(url: &str) -> Option<(String, String)> {
     GitRemoteUrl::parse(url).map(|u| (u.owner().to_string(), u.repo().to_string()))
 }
 
+/// Extract the host from a git remote URL.
+pub fn parse_remote_host(url: &str) -> Option<String> {
+    GitRemoteUrl::parse(url).map(|u| u.host().to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -139,6 +149,7 @@ mod tests {
         assert_eq!(url.owner(), "owner");
         assert_eq!(url.repo(), "repo");
         assert_eq!(url.project_identifier(), "github.com/owner/repo");
+        assert_eq!(url.host(), "github.com");
 
         // Without .git suffix
         let url = GitRemoteUrl::parse("https://github.com/owner/repo").unwrap();
@@ -356,6 +356,11 @@ fn colorize_ci_status_for_html(text: &str) -> String {
         .replace("`●` yellow", "<span style='color:#a60'>●</span> yellow")
         .replace("`⚠` yellow", "<span style='color:#a60'>⚠</span> yellow")
         .replace("`●` gray", "<span style='color:#888'>●</span> gray")
+        // PR/MR review states
+        .replace("`✓` green", "<span style='color:#0a0'>✓</span> green")
+        .replace("`✎` red", "<span style='color:#a00'>✎</span> red")
+        .replace("`○` yellow", "<span style='color:#a60'>○</span> yellow")
+        .replace("`◌` dim", "<span class=d>◌</span> dim")
         // Convert plain URL references to markdown links for web docs
         // CLI shows: "Open an issue at https://github.com/max-sixty/worktrunk."
         // Web shows: "[Open an issue](https://github.com/max-sixty/worktrunk/issues)."
@@ -1,4 +1,7 @@
+pub mod api;
 pub mod config;
+#[cfg(feature = "worktrunk-ffi")]
+pub mod ffi;
 pub mod git;
 pub mod path;
 pub mod shell;
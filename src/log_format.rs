@@ -0,0 +1,175 @@
+//! Debug log formatting.
+//!
+//! Log records (from `-v` or `RUST_LOG`) go to stderr as thread-prefixed text by
+//! default. With `--log-format=json` (or `WORKTRUNK_LOG_FORMAT=json`), each record
+//! is one JSON object per line, for ingestion by log tooling:
+//!
+//! ```text
+//! {"ts_us":1735776000123456,"level":"debug","target":"worktrunk::shell_exec","thread":"a","event":"command","command":"git status --porcelain","context":"feature"}
+//! {"ts_us":1735776000135789,"level":"debug","target":"worktrunk::shell_exec","thread":"a","event":"command_finished","command":"git status --porcelain","context":"feature","duration_us":12300,"ok":true}
+//! ```
+//!
+//! `wt-trace` lines are parsed into fields with [`worktrunk::trace`], so
+//! consumers get durations and results without re-parsing the text.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{Map, Value, json};
+use worktrunk::trace::{TraceEntryKind, TraceResult, parse_lines};
+
+use crate::cli::LogFormat;
+
+/// Resolve the log format: `--log-format`, then `WORKTRUNK_LOG_FORMAT`, then text.
+///
+/// An unrecognized environment value falls back to text rather than failing
+/// the command over a logging preference.
+pub(crate) fn resolve(flag: Option<LogFormat>) -> LogFormat {
+    flag.or_else(|| {
+        let value = std::env::var("WORKTRUNK_LOG_FORMAT").ok()?;
+        clap::ValueEnum::from_str(&value, true).ok()
+    })
+    .unwrap_or(LogFormat::Text)
+}
+
+/// Map the current thread ID to a single character (`0`, then a-z, then A-Z).
+pub(crate) fn thread_label() -> char {
+    let thread_id = format!("{:?}", std::thread::current().id());
+    thread_id
+        .strip_prefix("ThreadId(")
+        .and_then(|s| s.strip_suffix(")"))
+        .and_then(|s| s.parse::<usize>().ok())
+        .map(|n| {
+            if n == 0 {
+                '0'
+            } else if n <= 26 {
+                char::from(b'a' + (n - 1) as u8)
+            } else if n <= 52 {
+                char::from(b'A' + (n - 27) as u8)
+            } else {
+                '?'
+            }
+        })
+        .unwrap_or('?')
+}
+
+/// Render a log record as a single JSON line.
+pub(crate) fn json_line(record: &log::Record<'_>, thread: char) -> String {
+    let ts_us = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_micros() as u64)
+        .unwrap_or(0);
+    let mut fields = Map::new();
+    fields.insert("ts_us".into(), json!(ts_us));
+    fields.insert(
+        "level".into(),
+        json!(record.level().as_str().to_ascii_lowercase()),
+    );
+    fields.insert("target".into(), json!(record.target()));
+    fields.insert("thread".into(), json!(thread.to_string()));
+    fields.extend(message_fields(&record.args().to_string()));
+    Value::Object(fields).to_string()
+}
+
+/// Structured fields for a log message, by the shape `shell_exec` logs it in.
+fn message_fields(msg: &str) -> Map<String, Value> {
+    let mut fields = Map::new();
+
+    if let Some(entry) = parse_lines(msg).into_iter().next() {
+        match entry.kind {
+            TraceEntryKind::Command {
+                command,
+                duration,
+                result,
+            } => {
+                fields.insert("event".into(), json!("command_finished"));
+                fields.insert("command".into(), json!(command));
+                if let Some(context) = entry.context {
+                    fields.insert("context".into(), json!(context));
+                }
+                fields.insert("duration_us".into(), json!(duration.as_micros() as u64));
+                match result {
+                    TraceResult::Completed { success } => {
+                        fields.insert("ok".into(), json!(success));
+                    }
+                    TraceResult::Error { message } => {
+                        fields.insert("ok".into(), json!(false));
+                        fields.insert("error".into(), json!(message));
+                    }
+                }
+            }
+            TraceEntryKind::Instant { name } => {
+                fields.insert("event".into(), json!("milestone"));
+                fields.insert("name".into(), json!(name));
+            }
+        }
+    } else if let Some(rest) = msg.strip_prefix("$ ") {
+        // "git status [worktree]", optionally followed by " (streaming, …)"
+        fields.insert("event".into(), json!("command"));
+        match rest.split_once(" [") {
+            Some((command, tail)) => {
+                fields.insert("command".into(), json!(command));
+                if let Some((context, _)) = tail.split_once(']') {
+                    fields.insert("context".into(), json!(context));
+                }
+            }
+            None => {
+                let command = rest.split_once(" (").map_or(rest, |(command, _)| command);
+                fields.insert("command".into(), json!(command));
+            }
+        }
+    } else if let Some(line) = msg.strip_prefix("  ! ") {
+        fields.insert("event".into(), json!("stderr"));
+        fields.insert("line".into(), json!(line));
+    } else if let Some(line) = msg.strip_prefix("  ") {
+        fields.insert("event".into(), json!("stdout"));
+        fields.insert("line".into(), json!(line));
+    } else {
+        fields.insert("msg".into(), json!(msg));
+    }
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_fields() {
+        assert_eq!(
+            Value::Object(message_fields("$ git status --porcelain [feature]")),
+            json!({"event": "command", "command": "git status --porcelain", "context": "feature"})
+        );
+        assert_eq!(
+            Value::Object(message_fields("$ cargo test (streaming, direct)")),
+            json!({"event": "command", "command": "cargo test"})
+        );
+        assert_eq!(
+            Value::Object(message_fields(
+                r#"[wt-trace] ts=10 tid=2 context=main cmd="git merge-base" dur_us=1500 err="fatal: bad""#
+            )),
+            json!({
+                "event": "command_finished",
+                "command": "git merge-base",
+                "context": "main",
+                "duration_us": 1500,
+                "ok": false,
+                "error": "fatal: bad",
+            })
+        );
+        assert_eq!(
+            Value::Object(message_fields(
+                r#"[wt-trace] ts=10 tid=2 event="Showed skeleton""#
+            )),
+            json!({"event": "milestone", "name": "Showed skeleton"})
+        );
+        assert_eq!(
+            Value::Object(message_fields("  ! fatal: not a git repository")),
+            json!({"event": "stderr", "line": "fatal: not a git repository"})
+        );
+        assert_eq!(
+            Value::Object(message_fields("Loaded config")),
+            json!({"msg": "Loaded config"})
+        );
+    }
+}
@@ -19,9 +19,11 @@ mod help;
 pub(crate) mod help_pager;
 mod invocation;
 mod llm;
+mod log_format;
 mod md_help;
 mod output;
 mod pager;
+mod timings;
 mod verbose_log;
 
 // Re-export invocation utilities at crate level for use by other modules
@@ -29,27 +31,36 @@ pub(crate) use invocation::{
     binary_name, invocation_path, is_git_subcommand, was_invoked_with_explicit_path,
 };
 
-pub(crate) use crate::cli::OutputFormat;
+pub(crate) use crate::cli::{ListLayout, OutputFormat};
 
 use commands::command_executor::{CommandContext, build_hook_context};
 #[cfg(unix)]
 use commands::handle_select;
-use commands::worktree::{SwitchResult, handle_push};
+use commands::worktree::{
+    SwitchResult, ensure_no_pending_operation, handle_create_all, handle_push, handle_reattach,
+    remove_integrated_worktrees,
+};
 use commands::{
-    MergeOptions, RebaseResult, ResolutionContext, SquashResult, add_approvals, approve_hooks,
-    clear_approvals, execute_switch, handle_config_create, handle_config_show,
-    handle_configure_shell, handle_hints_clear, handle_hints_get, handle_hook_show, handle_init,
-    handle_list, handle_merge, handle_rebase, handle_remove, handle_remove_current,
+    MergeOptions, RebaseResult, ResolutionContext, SquashResult, TagOptions, add_approvals,
+    approve_hooks, clear_approvals, execute_switch, handle_alias_list, handle_cherry, handle_clean,
+    handle_clone, handle_completions, handle_config_create, handle_config_diff,
+    handle_config_migrate, handle_config_show, handle_configure_shell, handle_describe,
+    handle_doctor, handle_hints_clear, handle_hints_get, handle_history, handle_hook_show,
+    handle_init, handle_list, handle_main, handle_merge, handle_merge_abort, handle_path,
+    handle_ps, handle_push_branch, handle_rebase, handle_remove, handle_remove_current,
+    handle_report, handle_review, handle_review_release, handle_root, handle_run, handle_setup,
     handle_show_theme, handle_squash, handle_state_clear, handle_state_clear_all, handle_state_get,
-    handle_state_set, handle_state_show, handle_unconfigure_shell, plan_switch,
-    resolve_worktree_arg, run_hook, step_commit, step_copy_ignored, step_for_each,
+    handle_state_set, handle_state_show, handle_tag, handle_unconfigure_shell, handle_update,
+    list_approvals, offer_setup, plan_switch, resolve_worktree_arg, revoke_approvals, run_hook,
+    select_from_history, set_skipped_hooks, step_commit, step_copy_ignored, step_for_each,
+    step_prune,
 };
 use output::{execute_user_command, handle_remove_output, handle_switch_output};
 
 use cli::{
-    ApprovalsCommand, CiStatusAction, Cli, Commands, ConfigCommand, ConfigShellCommand,
-    DefaultBranchAction, HintsAction, HookCommand, ListSubcommand, LogsAction, MarkerAction,
-    PreviousBranchAction, StateCommand, StepCommand,
+    AliasCommand, ApprovalsCommand, CiStatusAction, Cli, Commands, ConfigCommand,
+    ConfigShellCommand, DefaultBranchAction, HintsAction, HookCommand, ListSubcommand, LogFormat,
+    LogsAction, MarkerAction, PreviousBranchAction, StateCommand, StepCommand,
 };
 use worktrunk::HookType;
 
@@ -95,6 +106,8 @@ fn enhance_and_exit_error(err: clap::Error) -> ! {
 }
 
 fn main() {
+    let started = std::time::Instant::now();
+
     // Configure Rayon's global thread pool for mixed I/O workloads.
     // The `wt list` command runs git operations (CPU + disk I/O) and network
     // requests (CI status, URL health checks) in parallel. Using 2x CPU cores
@@ -129,15 +142,25 @@ fn main() {
     // Clap doesn't support this natively yet - see https://github.com/clap-rs/clap/issues/3320
     // When available, use built-in setting. Until then, could use try_parse() to intercept
     // MissingRequiredArgument errors and print custom messages with ValueEnum::value_variants().
+    let args = commands::expand_aliases(std::env::args_os().collect()).unwrap_or_else(|e| {
+        // Alias errors are GitErrors, which style themselves via Display
+        let _ = output::print(e.to_string());
+        process::exit(2);
+    });
     let cmd = cli::build_command();
-    let matches = cmd.try_get_matches().unwrap_or_else(|e| {
+    let matches = cmd.try_get_matches_from(args).unwrap_or_else(|e| {
         enhance_and_exit_error(e);
     });
     let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
-    // Initialize base path from -C flag if provided
-    if let Some(path) = cli.directory {
-        set_base_path(path);
+    // Initialize base path from -C flags if provided. Like git, each -C is
+    // relative to the previous one, and an absolute path starts over.
+    if !cli.directory.is_empty() {
+        set_base_path(
+            cli.directory
+                .iter()
+                .fold(std::path::PathBuf::new(), |base, dir| base.join(dir)),
+        );
     }
 
     // Initialize config path from --config flag if provided
@@ -145,6 +168,14 @@ fn main() {
         set_config_path(path);
     }
 
+    // --no declines and --yes accepts every prompt (see output::prompt)
+    if cli.no {
+        output::prompt::set_assume_no();
+    }
+    if cli.yes {
+        output::prompt::set_assume_yes();
+    }
+
     // Configure logging based on --verbose flag or RUST_LOG env var
     // When --verbose is set, also write logs to .git/wt-logs/verbose.log
     if cli.verbose >= 1 {
@@ -153,6 +184,7 @@ fn main() {
 
     // Capture verbose level and command line before cli is partially consumed
     let verbose_level = cli.verbose;
+    let show_timings = cli.timings;
     let command_line = std::env::args().collect::<Vec<_>>().join(" ");
 
     // --verbose takes precedence over RUST_LOG: use Builder::new() to ignore env var
@@ -165,34 +197,21 @@ fn main() {
         env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("off"))
     };
 
+    let json_logs = log_format::resolve(cli.log_format) == LogFormat::Json;
     builder
-        .format(|buf, record| {
+        .format(move |buf, record| {
             use std::io::Write;
 
             let msg = record.args().to_string();
-
-            // Map thread ID to a single character (a-z, then A-Z)
-            let thread_id = format!("{:?}", std::thread::current().id());
-            let thread_num = thread_id
-                .strip_prefix("ThreadId(")
-                .and_then(|s| s.strip_suffix(")"))
-                .and_then(|s| s.parse::<usize>().ok())
-                .map(|n| {
-                    if n == 0 {
-                        '0'
-                    } else if n <= 26 {
-                        char::from(b'a' + (n - 1) as u8)
-                    } else if n <= 52 {
-                        char::from(b'A' + (n - 27) as u8)
-                    } else {
-                        '?'
-                    }
-                })
-                .unwrap_or('?');
+            let thread_num = log_format::thread_label();
 
             // Write plain text to log file (no ANSI codes)
             verbose_log::write_line(&format!("[{thread_num}] {msg}"));
 
+            if json_logs {
+                return writeln!(buf, "{}", log_format::json_line(record, thread_num));
+            }
+
             // Commands start with $, make only the command bold (not $ or [worktree])
             if let Some(rest) = msg.strip_prefix("$ ") {
                 // Split: "git command [worktree]" -> ("git command", " [worktree]")
@@ -229,6 +248,25 @@ fn main() {
         return;
     };
 
+    // Best-effort: never fail a command over the first-run hint. Only the
+    // commands a new user starts with; scripts and prompts don't need it.
+    if matches!(
+        command,
+        Commands::Switch { .. } | Commands::List { .. } | Commands::Select { .. }
+    ) {
+        let _ = offer_setup();
+    }
+
+    // --skip-hook applies wherever the command ends up running hooks
+    if let Commands::Switch { skip_hook, .. }
+    | Commands::Merge { skip_hook, .. }
+    | Commands::Step {
+        action: StepCommand::Commit { skip_hook, .. },
+    } = &command
+    {
+        set_skipped_hooks(skip_hook.clone());
+    }
+
     let result = match command {
         Commands::Config { action } => match action {
             ConfigCommand::Shell { action } => {
@@ -238,6 +276,15 @@ fn main() {
                         let cmd = cmd.unwrap_or_else(binary_name);
                         handle_init(shell, cmd).map_err(|e| anyhow::anyhow!("{}", e))
                     }
+                    ConfigShellCommand::Completions {
+                        shell,
+                        install,
+                        cmd,
+                    } => {
+                        let cmd = cmd.unwrap_or_else(binary_name);
+                        handle_completions(shell, install, cmd)
+                            .map_err(|e| anyhow::anyhow!("{}", e))
+                    }
                     ConfigShellCommand::Install {
                         shell,
                         yes,
@@ -412,6 +459,12 @@ fn main() {
             }
             ConfigCommand::Create { project } => handle_config_create(project),
             ConfigCommand::Show { full } => handle_config_show(full),
+            ConfigCommand::Diff => handle_config_diff(),
+            ConfigCommand::Migrate {
+                project,
+                yes,
+                dry_run,
+            } => handle_config_migrate(project, yes, dry_run),
             ConfigCommand::State { action } => match action {
                 StateCommand::DefaultBranch { action } => match action {
                     Some(DefaultBranchAction::Get) | None => {
@@ -464,19 +517,117 @@ fn main() {
                 StateCommand::Clear => handle_state_clear_all(),
             },
         },
+        Commands::Describe {
+            branch,
+            text,
+            clear,
+        } => handle_describe(&branch, text, clear),
+        Commands::Main => handle_main(),
+        Commands::Root => handle_root(),
+        Commands::Path {
+            branch,
+            create,
+            yes,
+            verify,
+        } => handle_path(branch.as_deref(), create, yes, verify),
+        Commands::Ps => handle_ps(),
+        Commands::History { since, format } => handle_history(since, format),
+        Commands::Report {
+            stale_after,
+            behind,
+            format,
+        } => handle_report(stale_after, behind, format),
+        Commands::Clean {
+            branches,
+            force,
+            untracked,
+            yes,
+        } => handle_clean(&branches, force, untracked, yes),
+        Commands::Update { branch, strategy } => handle_update(branch.as_deref(), strategy),
+        Commands::Push { force_with_lease } => handle_push_branch(force_with_lease),
+        Commands::Tag {
+            version,
+            message,
+            sign,
+            target,
+            no_push,
+            no_remove,
+            verify,
+            yes,
+        } => WorktrunkConfig::load()
+            .context("Failed to load config")
+            .and_then(|config| {
+                handle_tag(
+                    TagOptions {
+                        version: &version,
+                        message: message.as_deref(),
+                        sign,
+                        target: target.as_deref(),
+                        push: !no_push,
+                        remove: !no_remove,
+                        verify,
+                        yes,
+                    },
+                    &config,
+                )
+            }),
+        Commands::Cherry {
+            branch,
+            commits,
+            all,
+        } => handle_cherry(&branch, &commits, all),
+        Commands::Review {
+            branch,
+            read_only,
+            release,
+            yes,
+            verify,
+        } => {
+            if release {
+                handle_review_release(&branch)
+            } else {
+                handle_review(&branch, read_only, yes, verify)
+            }
+        }
+        Commands::Setup => handle_setup(&binary_name()),
+        Commands::Doctor => handle_doctor(),
+        Commands::Clone {
+            url,
+            directory,
+            switch,
+        } => WorktrunkConfig::load()
+            .context("Failed to load config")
+            .and_then(|mut config| {
+                handle_clone(&url, directory.as_deref(), switch.as_deref(), &config)?;
+                // Best-effort: a new clone is a natural moment to offer shell integration
+                if !output::is_shell_integration_active() {
+                    let _ = output::prompt_shell_integration(&mut config, &binary_name(), false);
+                }
+                Ok(())
+            }),
         Commands::Step { action } => match action {
             StepCommand::Commit {
                 yes,
                 verify,
                 stage,
                 show_prompt,
+                interactive,
+                llm_groups,
+                skip_hook: _,
             } => WorktrunkConfig::load()
                 .context("Failed to load config")
                 .and_then(|config| {
                     let stage_final = stage
                         .or_else(|| config.commit.and_then(|c| c.stage))
                         .unwrap_or_default();
-                    step_commit(yes, !verify, stage_final, show_prompt)
+                    step_commit(
+                        yes,
+                        !verify,
+                        stage_final,
+                        show_prompt,
+                        interactive,
+                        llm_groups,
+                    )
                 }),
             StepCommand::Squash {
                 target,
@@ -484,6 +635,7 @@ fn main() {
                 verify,
                 stage,
                 show_prompt,
+                autosquash,
             } => WorktrunkConfig::load()
                 .context("Failed to load config")
                 .and_then(|config| {
@@ -517,7 +669,7 @@ fn main() {
                         false
                     };
 
-                    match handle_squash(target.as_deref(), yes, !verify, stage_final)? {
+                    match handle_squash(target.as_deref(), yes, !verify, stage_final, autosquash)? {
                         SquashResult::Squashed | SquashResult::NoNetChanges => {}
                         SquashResult::NoCommitsAhead(branch) => {
                             crate::output::print(info_message(format!(
@@ -532,7 +684,9 @@ fn main() {
                     }
                     Ok(())
                 }),
-            StepCommand::Push { target } => handle_push(target.as_deref(), "Pushed to", None),
+            StepCommand::Push { target, force } => {
+                handle_push(target.as_deref(), "Pushed to", None, force)
+            }
             StepCommand::Rebase { target } => {
                 handle_rebase(target.as_deref()).and_then(|result| match result {
                     RebaseResult::Rebased => Ok(()),
@@ -547,7 +701,27 @@ fn main() {
             StepCommand::CopyIgnored { from, to, dry_run } => {
                 step_copy_ignored(from.as_deref(), to.as_deref(), dry_run)
             }
-            StepCommand::ForEach { args } => step_for_each(args),
+            StepCommand::Prune {
+                orphans,
+                yes,
+                dry_run,
+            } => WorktrunkConfig::load()
+                .context("Failed to load config")
+                .and_then(|config| step_prune(orphans, yes, dry_run, &config)),
+            StepCommand::ForEach { args, profile, yes } => {
+                step_for_each(args, profile.as_deref(), yes)
+            }
+            StepCommand::EmptyTrash { path } => WorktrunkConfig::load()
+                .context("Failed to load config")
+                .and_then(|config| {
+                    commands::trash::handle_empty_trash(&path, config.remove_trash_limit())
+                }),
+        },
+        Commands::Run { profile, yes, vars } => handle_run(&profile, yes, &vars),
+        Commands::Alias { action } => match action {
+            AliasCommand::List => WorktrunkConfig::load()
+                .context("Failed to load config")
+                .and_then(|config| handle_alias_list(&config)),
         },
         Commands::Hook { action } => match action {
             HookCommand::Show {
@@ -611,6 +785,10 @@ fn main() {
             }
             HookCommand::Approvals { action } => match action {
                 ApprovalsCommand::Add { all } => add_approvals(all),
+                ApprovalsCommand::List => list_approvals(),
+                ApprovalsCommand::Revoke { project, command } => {
+                    revoke_approvals(&project, command.as_deref())
+                }
                 ApprovalsCommand::Clear { global } => clear_approvals(global),
             },
         },
@@ -633,6 +811,14 @@ fn main() {
                     handle_select(show_branches, show_remotes, &config)
                 })
         }
+        #[cfg(feature = "serve")]
+        Commands::Serve { stdio: _ } => WorktrunkConfig::load()
+            .context("Failed to load config")
+            .and_then(commands::handle_serve),
+        #[cfg(feature = "web")]
+        Commands::Web { port, branches } => WorktrunkConfig::load()
+            .context("Failed to load config")
+            .and_then(|config| commands::handle_web(port, branches, &config)),
         #[cfg(not(unix))]
         Commands::Select { .. } => {
             let _ = output::print(error_message("wt select is not available on Windows"));
@@ -644,15 +830,28 @@ fn main() {
         Commands::List {
             subcommand,
             format,
+            layout,
             branches,
             remotes,
             full,
+            du,
+            owner,
+            mine,
+            index,
+            graph,
+            absolute_time,
+            no_summary,
+            no_truncate,
+            tune,
             progressive,
             no_progressive,
+            refresh_cache: refresh_list_cache,
         } => match subcommand {
-            Some(ListSubcommand::Statusline { claude_code }) => {
-                commands::statusline::run(claude_code)
-            }
+            Some(ListSubcommand::Statusline {
+                claude_code,
+                format,
+                refresh_cache,
+            }) => commands::statusline::run(claude_code, format, refresh_cache),
             None => {
                 use commands::list::progressive::RenderMode;
 
@@ -661,7 +860,12 @@ fn main() {
                     .context("Failed to load config")
                     .and_then(|config| {
                         // Get config values from global list config
-                        let (show_branches_config, show_remotes_config, show_full_config) = config
+                        let (
+                            show_branches_config,
+                            show_remotes_config,
+                            show_full_config,
+                            show_index_config,
+                        ) = config
                             .list
                             .as_ref()
                             .map(|l| {
@@ -669,14 +873,16 @@ fn main() {
                                     l.branches.unwrap_or(false),
                                     l.remotes.unwrap_or(false),
                                     l.full.unwrap_or(false),
+                                    l.index.unwrap_or(false),
                                 )
                             })
-                            .unwrap_or((false, false, false));
+                            .unwrap_or((false, false, false, false));
 
                         // CLI flags override config
                         let show_branches = branches || show_branches_config;
                         let show_remotes = remotes || show_remotes_config;
                         let show_full = full || show_full_config;
+                        let show_index = index || show_index_config;
 
                         // Convert two bools to Option<bool>: Some(true), Some(false), or None
                         let progressive_opt = match (progressive, no_progressive) {
@@ -687,10 +893,21 @@ fn main() {
                         let render_mode = RenderMode::detect(progressive_opt);
                         handle_list(
                             format,
+                            layout,
                             show_branches,
                             show_remotes,
                             show_full,
+                            du,
+                            owner,
+                            mine,
+                            show_index,
+                            graph,
+                            absolute_time,
+                            !no_summary,
+                            no_truncate,
+                            tune,
                             render_mode,
+                            refresh_list_cache,
                             &config,
                         )
                     })
@@ -700,18 +917,59 @@ fn main() {
             branch,
             create,
             base,
+            from_template,
             execute,
             execute_args,
             yes,
             clobber,
+            history: _,
+            create_all,
+            reattach,
+            force,
             verify,
+            skip_hook: _,
+            wait,
         } => WorktrunkConfig::load()
             .context("Failed to load config")
             .and_then(|mut config| {
                 let repo = Repository::current().context("Failed to switch worktree")?;
 
+                if let Some(pattern) = create_all {
+                    return handle_create_all(&repo, &pattern, &config, yes, verify, wait);
+                }
+
+                // --history picks the branch; without a terminal it just lists the history
+                // (clap requires BRANCH unless --history)
+                let branch = match branch {
+                    Some(branch) => branch,
+                    None => match select_from_history(&repo)? {
+                        Some(branch) => branch,
+                        None => return Ok(()),
+                    },
+                };
+
+                if reattach {
+                    return handle_reattach(&repo, &branch);
+                }
+
                 // Validate FIRST (before approval) - fails fast if branch doesn't exist, etc.
-                let plan = plan_switch(&repo, &branch, create, base.as_deref(), clobber, &config)?;
+                let plan = plan_switch(
+                    &repo,
+                    &branch,
+                    create,
+                    base.as_deref(),
+                    clobber,
+                    from_template.as_deref(),
+                    &config,
+                )?;
+
+                // Leaving a worktree mid-rebase buries the operation
+                if !force {
+                    ensure_no_pending_operation(&repo, plan.worktree_path())?;
+                }
+
+                // `[remove] auto-integrated`: clean up merged worktrees, except the target
+                remove_integrated_worktrees(&repo, &config, plan.branch());
 
                 // "Approve at the Gate": collect and approve hooks upfront
                 // This ensures approval happens once at the command entry point
@@ -721,7 +979,7 @@ fn main() {
                     let ctx = CommandContext::new(
                         &repo,
                         &config,
-                        Some(plan.branch()),
+                        plan.branch(),
                         plan.worktree_path(),
                         &repo_root,
                         yes,
@@ -757,7 +1015,8 @@ fn main() {
                 }
 
                 // Execute the validated plan
-                let (result, branch_info) = execute_switch(&repo, plan, &config, yes, skip_hooks)?;
+                let (result, branch_info) =
+                    execute_switch(&repo, plan, &config, yes, skip_hooks, wait)?;
 
                 // Show success message (temporal locality: immediately after worktree operation)
                 // Returns path to display in hooks when user's shell won't be in the worktree
@@ -804,7 +1063,7 @@ fn main() {
                     let ctx = CommandContext::new(
                         &repo,
                         &config,
-                        Some(&branch_info.branch),
+                        branch_info.branch.as_deref(),
                         result.path(),
                         &repo_root,
                         yes,
@@ -828,7 +1087,7 @@ fn main() {
                     let ctx = CommandContext::new(
                         &repo,
                         &config,
-                        Some(&branch_info.branch),
+                        branch_info.branch.as_deref(),
                         result.path(),
                         &repo_root,
                         yes,
@@ -877,6 +1136,9 @@ fn main() {
             verify,
             yes,
             force,
+            force_dirty,
+            unlock,
+            wait,
         } => WorktrunkConfig::load()
             .context("Failed to load config")
             .and_then(|config| {
@@ -916,14 +1178,20 @@ fn main() {
 
                 if branches.is_empty() {
                     // Single worktree removal: validate FIRST, then approve, then execute
-                    let result =
-                        handle_remove_current(!delete_branch, force_delete, force, &config)
-                            .context("Failed to remove worktree")?;
+                    let result = handle_remove_current(
+                        !delete_branch,
+                        force_delete,
+                        force,
+                        force_dirty,
+                        unlock,
+                        &config,
+                    )
+                    .context("Failed to remove worktree")?;
 
                     // "Approve at the Gate": approval happens AFTER validation passes
                     let run_hooks = verify && approve_remove(yes)?;
 
-                    handle_remove_output(&result, background, run_hooks)
+                    handle_remove_output(&result, background, run_hooks, wait)
                 } else {
                     use commands::worktree::RemoveResult;
                     use worktrunk::git::ResolvedWorktree;
@@ -960,10 +1228,19 @@ fn main() {
                     };
 
                     for branch_name in &branches {
+                        // A bare number may name a row of `wt list --index`
+                        let branch_name = match repo.resolve_list_index(branch_name) {
+                            Ok(name) => name,
+                            Err(e) => {
+                                record_error(e)?;
+                                continue;
+                            }
+                        };
+
                         // Resolve the target
                         let resolved = match resolve_worktree_arg(
                             &repo,
-                            branch_name,
+                            &branch_name,
                             &config,
                             ResolutionContext::Remove,
                         ) {
@@ -986,6 +1263,8 @@ fn main() {
                                         !delete_branch,
                                         force_delete,
                                         force,
+                                        force_dirty,
+                                        unlock,
                                         &config,
                                     ) {
                                         Ok(result) => plan_current = Some(result),
@@ -1004,6 +1283,8 @@ fn main() {
                                     !delete_branch,
                                     force_delete,
                                     force,
+                                    force_dirty,
+                                    unlock,
                                     &config,
                                 ) {
                                     Ok(result) => plans_others.push(result),
@@ -1016,6 +1297,8 @@ fn main() {
                                     !delete_branch,
                                     force_delete,
                                     force,
+                                    force_dirty,
+                                    unlock,
                                     &config,
                                 ) {
                                     Ok(result) => plans_branch_only.push(result),
@@ -1041,17 +1324,17 @@ fn main() {
                     // Phase 3: Execute all validated plans
                     // Remove other worktrees first
                     for result in plans_others {
-                        handle_remove_output(&result, background, run_hooks)?;
+                        handle_remove_output(&result, background, run_hooks, wait)?;
                     }
 
                     // Handle branch-only cases
                     for result in plans_branch_only {
-                        handle_remove_output(&result, background, run_hooks)?;
+                        handle_remove_output(&result, background, run_hooks, wait)?;
                     }
 
                     // Remove current worktree last (if it was in the list)
                     if let Some(result) = plan_current {
-                        handle_remove_output(&result, background, run_hooks)?;
+                        handle_remove_output(&result, background, run_hooks, wait)?;
                     }
 
                     // Exit with failure if any validation errors occurred
@@ -1074,8 +1357,18 @@ fn main() {
             no_remove,
             verify,
             no_verify,
+            skip_hook: _,
+            push,
+            no_push,
+            wait_ci,
+            no_wait_ci,
             yes,
             stage,
+            autosquash,
+            resume,
+            abort: false,
+            force,
+            wait,
         } => WorktrunkConfig::load()
             .context("Failed to load config")
             .and_then(|config| {
@@ -1088,20 +1381,25 @@ fn main() {
                     }
                 }
 
-                // Get config defaults (positive form: true = do it)
+                // Get config defaults (positive form: true = do it); steps the user
+                // config leaves unset follow the project's `[merge] steps`
                 let merge_config = config.merge.as_ref();
-                let squash_default = merge_config.and_then(|m| m.squash).unwrap_or(true);
-                let commit_default = merge_config.and_then(|m| m.commit).unwrap_or(true);
-                let rebase_default = merge_config.and_then(|m| m.rebase).unwrap_or(true);
-                let remove_default = merge_config.and_then(|m| m.remove).unwrap_or(true);
+                let squash_default = merge_config.and_then(|m| m.squash);
+                let commit_default = merge_config.and_then(|m| m.commit);
+                let rebase_default = merge_config.and_then(|m| m.rebase);
+                let remove_default = merge_config.and_then(|m| m.remove);
                 let verify_default = merge_config.and_then(|m| m.verify).unwrap_or(true);
+                let push_default = merge_config.and_then(|m| m.push).unwrap_or(false);
+                let wait_ci_default = merge_config.and_then(|m| m.wait_ci).unwrap_or(false);
 
                 // CLI flags override config, config overrides defaults
-                let squash_final = flag_pair(squash, no_squash).unwrap_or(squash_default);
-                let commit_final = flag_pair(commit, no_commit).unwrap_or(commit_default);
-                let rebase_final = flag_pair(rebase, no_rebase).unwrap_or(rebase_default);
-                let remove_final = flag_pair(remove, no_remove).unwrap_or(remove_default);
+                let squash_final = flag_pair(squash, no_squash).or(squash_default);
+                let commit_final = flag_pair(commit, no_commit).or(commit_default);
+                let rebase_final = flag_pair(rebase, no_rebase).or(rebase_default);
+                let remove_final = flag_pair(remove, no_remove).or(remove_default);
                 let verify_final = flag_pair(verify, no_verify).unwrap_or(verify_default);
+                let push_final = flag_pair(push, no_push).unwrap_or(push_default);
+                let wait_ci_final = flag_pair(wait_ci, no_wait_ci).unwrap_or(wait_ci_default);
 
                 // Stage defaults from [commit] config section
                 let stage_final = stage
@@ -1115,10 +1413,17 @@ fn main() {
                     rebase: rebase_final,
                     remove: remove_final,
                     verify: verify_final,
+                    push: push_final,
+                    wait_ci: wait_ci_final,
                     yes,
                     stage_mode: stage_final,
+                    autosquash,
+                    resume,
+                    force,
+                    wait,
                 })
             }),
+        Commands::Merge { abort: true, .. } => handle_merge_abort(),
     };
 
     if let Err(e) = result {
@@ -1160,6 +1465,10 @@ fn main() {
         // Preserve exit code from child processes (especially for signals like SIGINT)
         let code = exit_code(&e).unwrap_or(1);
 
+        if show_timings {
+            let _ = timings::print_summary(started);
+        }
+
         // Write diagnostic if -vv was used (error case)
         diagnostic::write_if_verbose(verbose_level, &command_line, Some(&e.to_string()));
 
@@ -1168,6 +1477,10 @@ fn main() {
         process::exit(code);
     }
 
+    if show_timings {
+        let _ = timings::print_summary(started);
+    }
+
     // Write diagnostic if -vv was used (success case)
     diagnostic::write_if_verbose(verbose_level, &command_line, None);
 
@@ -31,6 +31,7 @@ use std::path::PathBuf;
 #[cfg(unix)]
 use std::process::Stdio;
 use std::sync::{Mutex, OnceLock};
+use worktrunk::config::string_to_port;
 use worktrunk::shell_exec::DIRECTIVE_FILE_ENV_VAR;
 #[cfg(unix)]
 use worktrunk::shell_exec::ShellConfig;
@@ -54,8 +55,18 @@ struct OutputState {
     directive_file: Option<PathBuf>,
     /// Buffered target directory for execute() in interactive mode
     target_dir: Option<PathBuf>,
+    /// Whether the version header has been written to the directive file
+    wrote_header: bool,
 }
 
+/// First line of a non-empty directive file.
+///
+/// Version 1 files had no header and only contained `cd` and `--execute`
+/// commands; version 2 adds `WT_*` environment exports and messages printed
+/// to stderr. It's a comment, so wrappers that source the file as-is don't
+/// need to handle it.
+const DIRECTIVE_HEADER: &str = "# worktrunk directives v2";
+
 /// Get or lazily initialize the global output state.
 ///
 /// Reads `WORKTRUNK_DIRECTIVE_FILE` from environment on first access.
@@ -70,6 +81,7 @@ fn get_state() -> &'static Mutex<OutputState> {
         Mutex::new(OutputState {
             directive_file,
             target_dir: None,
+            wrote_header: false,
         })
     })
 }
@@ -122,9 +134,10 @@ pub fn stdout(content: impl Into<String>) -> io::Result<()> {
 /// Write a directive to the directive file (if set)
 fn write_directive(directive: &str) -> io::Result<()> {
     // Copy path out of lock to avoid holding mutex during I/O
-    let path = {
-        let guard = get_state().lock().expect("OUTPUT_STATE lock poisoned");
-        guard.directive_file.clone()
+    let (path, wrote_header) = {
+        let mut guard = get_state().lock().expect("OUTPUT_STATE lock poisoned");
+        let wrote_header = std::mem::replace(&mut guard.wrote_header, true);
+        (guard.directive_file.clone(), wrote_header)
     };
 
     let Some(path) = path else {
@@ -132,6 +145,9 @@ fn write_directive(directive: &str) -> io::Result<()> {
     };
 
     let mut file = OpenOptions::new().append(true).open(&path)?;
+    if !wrote_header {
+        writeln!(file, "{DIRECTIVE_HEADER}")?;
+    }
     writeln!(file, "{}", directive)?;
     file.flush()
 }
@@ -150,26 +166,74 @@ pub fn change_directory(path: impl AsRef<Path>) -> io::Result<()> {
     // Write to directive file if set
     if guard.directive_file.is_some() {
         drop(guard); // Release lock before I/O
+        write_directive(&format!("cd {}", quote(&path.to_string_lossy())))?;
+    }
 
-        let path_str = path.to_string_lossy();
-        // Escape based on shell type. Both shell families use single-quoted strings
-        // where contents are literal, but they escape embedded quotes differently:
-        // - PowerShell: double the quote ('it''s')
-        // - POSIX (bash/zsh/fish): end quote, escaped quote, start quote ('it'\''s')
-        let is_powershell = std::env::var("WORKTRUNK_SHELL")
-            .map(|v| v.eq_ignore_ascii_case("powershell"))
-            .unwrap_or(false);
-        let escaped = if is_powershell {
-            path_str.replace('\'', "''")
+    Ok(())
+}
+
+/// Export `WT_BRANCH` and `WT_PORT` for the worktree the shell is moving to
+///
+/// `WT_PORT` matches `{{ branch | hash_port }}` in hook templates, so shell
+/// commands can reach a dev server started by a hook. Only written with shell
+/// integration; there's no parent shell to export to otherwise.
+pub fn export_worktree_env(branch: &str) -> io::Result<()> {
+    if !has_directive_file() {
+        return Ok(());
+    }
+    let port = string_to_port(branch).to_string();
+    for (name, value) in [("WT_BRANCH", branch), ("WT_PORT", port.as_str())] {
+        let directive = if is_powershell() {
+            format!("$env:{name} = {}", quote(value))
         } else {
-            path_str.replace('\'', "'\\''")
+            // fish also provides `export` for POSIX compatibility
+            format!("export {name}={}", quote(value))
         };
-        write_directive(&format!("cd '{}'", escaped))?;
+        write_directive(&directive)?;
     }
-
     Ok(())
 }
 
+/// Print a message in order with the directives
+///
+/// With shell integration the message goes in the directive file, so the shell
+/// prints it after the `cd`, right before any `--execute` command that follows.
+/// Otherwise it's printed to stderr now.
+pub fn print_directive(message: impl Into<String>) -> io::Result<()> {
+    let message = message.into();
+    if !has_directive_file() {
+        return print(message);
+    }
+    let directive = if is_powershell() {
+        format!("[Console]::Error.WriteLine({})", quote(&message))
+    } else {
+        // fish also provides `printf`
+        format!("printf '%s\\n' {} >&2", quote(&message))
+    };
+    write_directive(&directive)
+}
+
+fn is_powershell() -> bool {
+    std::env::var("WORKTRUNK_SHELL")
+        .map(|v| v.eq_ignore_ascii_case("powershell"))
+        .unwrap_or(false)
+}
+
+/// Single-quote a value for the wrapper's shell.
+///
+/// Both shell families use single-quoted strings where contents are literal,
+/// but they escape embedded quotes differently:
+/// - PowerShell: double the quote ('it''s')
+/// - POSIX (bash/zsh/fish): end quote, escaped quote, start quote ('it'\''s')
+fn quote(value: &str) -> String {
+    let escaped = if is_powershell() {
+        value.replace('\'', "''")
+    } else {
+        value.replace('\'', "'\\''")
+    };
+    format!("'{escaped}'")
+}
+
 /// Request command execution
 ///
 /// In interactive mode (no directive file), executes the command directly (replacing process on Unix).
@@ -325,7 +389,7 @@ pub fn compute_hooks_display_path<'a>(
 /// run_hook_with_filter(..., pre_hook_display_path(ctx.worktree_path))?;
 /// ```
 pub fn pre_hook_display_path(hooks_run_at: &std::path::Path) -> Option<&std::path::Path> {
-    let cwd = match std::env::current_dir() {
+    let cwd = match worktrunk::git::base_dir() {
         Ok(cwd) => cwd,
         Err(_) => return None, // Can't determine cwd, don't show path
     };
@@ -378,7 +442,7 @@ mod tests {
     #[test]
     fn test_pre_hook_display_path_at_cwd() {
         // When hooks run at cwd, no path annotation needed
-        let cwd = std::env::current_dir().unwrap();
+        let cwd = worktrunk::git::base_dir().unwrap();
         let result = pre_hook_display_path(&cwd);
         assert!(result.is_none(), "Should return None when hooks run at cwd");
     }
@@ -403,7 +467,7 @@ mod tests {
         let result = post_hook_display_path(&elsewhere);
         // If cwd != elsewhere, should return Some
         // If cwd == elsewhere (unlikely), should return None
-        let cwd = std::env::current_dir().unwrap();
+        let cwd = worktrunk::git::base_dir().unwrap();
         if cwd == elsewhere {
             assert!(result.is_none());
         } else {
@@ -414,7 +478,7 @@ mod tests {
     #[test]
     fn test_post_hook_display_path_at_cwd_no_shell_integration() {
         // Without shell integration, if destination == cwd, no path needed
-        let cwd = std::env::current_dir().unwrap();
+        let cwd = worktrunk::git::base_dir().unwrap();
         let result = post_hook_display_path(&cwd);
         assert!(
             result.is_none(),
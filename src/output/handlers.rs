@@ -1,21 +1,26 @@
 //! Output handlers for worktree operations using the global output context
 
+use anyhow::Context;
 use color_print::cformat;
 use std::path::{Path, PathBuf};
 
-use crate::commands::branch_deletion::{
-    BranchDeletionOutcome, BranchDeletionResult, delete_branch_if_safe,
-};
+use crate::commands::OperationLock;
 use crate::commands::command_executor::CommandContext;
 use crate::commands::execute_pre_remove_commands;
-use crate::commands::process::{build_remove_command, spawn_detached};
+use crate::commands::process::{
+    build_remove_command, build_trash_delete_command, spawn_detached, spawn_detached_with_lock,
+};
+use crate::commands::trash::{Disposal, StagedRemoval, dispose_staged, stage_worktree_removal};
 use crate::commands::worktree::{BranchDeletionMode, RemoveResult, SwitchBranchInfo, SwitchResult};
-use worktrunk::config::WorktrunkConfig;
-use worktrunk::git::GitError;
-use worktrunk::git::IntegrationReason;
+use worktrunk::config::{DirenvMode, WorktrunkConfig};
 use worktrunk::git::Repository;
 use worktrunk::git::path_dir_name;
+use worktrunk::git::{ActivityKind, GitError};
+use worktrunk::git::{
+    BranchDeletionOutcome, BranchDeletionResult, IntegrationReason, delete_branch_if_safe,
+};
 use worktrunk::path::format_path_for_display;
+use worktrunk::shell_exec::Cmd;
 use worktrunk::styling::{
     FormattedMessage, error_message, format_with_gutter, hint_message, info_message,
     progress_message, success_message, suggest_command, warning_message,
@@ -295,10 +300,13 @@ pub fn handle_switch_output(
 ) -> anyhow::Result<Option<std::path::PathBuf>> {
     // Set target directory for command execution
     super::change_directory(result.path())?;
+    if let Some(branch) = &branch_info.branch {
+        super::export_worktree_env(branch)?;
+    }
 
     let path = result.path();
     let path_display = format_path_for_display(path);
-    let branch = &branch_info.branch;
+    let branch = branch_info.branch.as_deref().unwrap_or("detached HEAD");
 
     // Check if shell integration is active (directive file set)
     let is_shell_integration_active = super::is_shell_integration_active();
@@ -318,7 +326,7 @@ pub fn handle_switch_output(
     let branch_worktree_mismatch_warning = branch_info
         .expected_path
         .as_ref()
-        .map(|expected| format_path_mismatch_warning(&branch_info.branch, expected));
+        .map(|expected| format_path_mismatch_warning(branch, expected));
 
     let display_path_for_hooks = match result {
         SwitchResult::AlreadyAt(_) => {
@@ -387,12 +395,19 @@ pub fn handle_switch_output(
                 from_remote.as_deref(),
             )))?;
 
+            let config = WorktrunkConfig::load().ok();
+            update_zoxide(config.as_ref(), "add", path);
+            if path.join(".envrc").exists() {
+                let mode = config.as_ref().map(|c| c.direnv_mode()).unwrap_or_default();
+                handle_envrc(mode, path)?;
+            }
+
             // Show worktree-path config hint on first --create in this repo,
             // unless user already has a custom worktree-path config
             if *created_branch && let Ok(repo) = worktrunk::git::Repository::current() {
-                let has_custom_config = WorktrunkConfig::load()
-                    .map(|c| c.has_custom_worktree_path())
-                    .unwrap_or(false);
+                let has_custom_config = config
+                    .as_ref()
+                    .is_some_and(|c| c.has_custom_worktree_path());
                 if !has_custom_config && !repo.has_shown_hint("worktree-path") {
                     let hint = hint_message(cformat!(
                         "To customize worktree locations, run <bright-black>wt config create</>"
@@ -433,13 +448,87 @@ pub fn handle_switch_output(
     Ok(display_path_for_hooks)
 }
 
+/// Allow or flag the `.envrc` of a newly created worktree, per `[integrations] direnv`.
+///
+/// Setting `direnv = "allow"` is the user's standing approval: direnv would
+/// otherwise block the file in every new worktree.
+fn handle_envrc(mode: DirenvMode, path: &Path) -> anyhow::Result<()> {
+    match mode {
+        DirenvMode::Ignore => {}
+        DirenvMode::Warn => {
+            let installed = Cmd::new("direnv")
+                .arg("version")
+                .run()
+                .is_ok_and(|o| o.status.success());
+            if installed {
+                let path_display = format_path_for_display(path);
+                super::print(hint_message(cformat!(
+                    "Worktree has an <bold>.envrc</>; to load it, run <bright-black>direnv allow {path_display}</>"
+                )))?;
+            }
+        }
+        DirenvMode::Allow => {
+            match Cmd::new("direnv")
+                .arg("allow")
+                .arg(path.to_string_lossy())
+                .run()
+            {
+                Ok(output) if output.status.success() => {
+                    super::print(info_message(cformat!(
+                        "Allowed <bold>.envrc</> with <bright-black>direnv allow</>"
+                    )))?;
+                }
+                Ok(output) => {
+                    super::print(warning_message(cformat!(
+                        "Failed to allow <bold>.envrc</> — {}",
+                        String::from_utf8_lossy(&output.stderr).trim()
+                    )))?;
+                }
+                Err(e) => {
+                    super::print(warning_message(cformat!(
+                        "Failed to allow <bold>.envrc</> — couldn't run direnv: {e}"
+                    )))?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Run `zoxide add` or `zoxide remove` for a worktree path, when
+/// `[integrations] zoxide` is on.
+///
+/// Best-effort: a missing `zoxide` or a path it doesn't know only shows up in
+/// `-v` logs, never fails the switch or removal.
+fn update_zoxide(config: Option<&WorktrunkConfig>, action: &str, path: &Path) {
+    if !config.is_some_and(|c| c.zoxide_enabled()) {
+        return;
+    }
+    match Cmd::new("zoxide")
+        .arg(action)
+        .arg(path.to_string_lossy())
+        .run()
+    {
+        Ok(output) if output.status.success() => {}
+        Ok(output) => log::debug!(
+            "zoxide {action} failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ),
+        Err(e) => log::debug!("zoxide {action} failed: {e}"),
+    }
+}
+
 /// Execute the --execute command after hooks have run
 pub fn execute_user_command(command: &str) -> anyhow::Result<()> {
     use worktrunk::styling::format_bash_with_gutter;
 
-    // Show what command is being executed (section header + gutter content)
-    super::print(progress_message("Executing (--execute):"))?;
-    super::print(format_bash_with_gutter(command))?;
+    // Show what command is being executed (section header + gutter content).
+    // With shell integration the shell prints it just before running the command.
+    super::print_directive(format!(
+        "{}\n{}",
+        progress_message("Executing (--execute):"),
+        format_bash_with_gutter(command)
+    ))?;
 
     super::execute(command)?;
 
@@ -448,11 +537,41 @@ pub fn execute_user_command(command: &str) -> anyhow::Result<()> {
 
 /// Handle output for a remove operation
 ///
-/// Approval is handled at the gate (command entry point), not here.
+/// Approval is handled at the gate (command entry point), not here. The
+/// outcome goes to the activity log for `wt history`.
+///
+/// `wait` (`--wait`) blocks on the operation lock instead of failing when
+/// another `wt` process holds it.
 pub fn handle_remove_output(
     result: &RemoveResult,
     background: bool,
     verify: bool,
+    wait: bool,
+) -> anyhow::Result<()> {
+    // Opened first: removing the current worktree takes the cwd with it
+    let repo = Repository::current();
+    let outcome = remove_and_report(result, background, verify, wait);
+    if let Ok(repo) = repo {
+        let (branch, path) = match result {
+            RemoveResult::RemovedWorktree {
+                branch_name,
+                worktree_path,
+                ..
+            } => (branch_name.as_deref(), Some(worktree_path.as_path())),
+            RemoveResult::BranchOnly { branch_name, .. } => (Some(branch_name.as_str()), None),
+        };
+        if let Err(e) = repo.record_activity(ActivityKind::Remove, branch, path, outcome.is_ok()) {
+            log::debug!("Failed to record remove: {e:#}");
+        }
+    }
+    outcome
+}
+
+fn remove_and_report(
+    result: &RemoveResult,
+    background: bool,
+    verify: bool,
+    wait: bool,
 ) -> anyhow::Result<()> {
     match result {
         RemoveResult::RemovedWorktree {
@@ -465,6 +584,7 @@ pub fn handle_remove_output(
             integration_reason,
             force_worktree,
             expected_path,
+            unlock,
         } => handle_removed_worktree_output(
             main_path,
             worktree_path,
@@ -475,8 +595,10 @@ pub fn handle_remove_output(
             *integration_reason,
             *force_worktree,
             expected_path.as_ref(),
+            *unlock,
             background,
             verify,
+            wait,
         ),
         RemoveResult::BranchOnly {
             branch_name,
@@ -759,21 +881,25 @@ fn handle_removed_worktree_output(
     pre_computed_integration: Option<IntegrationReason>,
     force_worktree: bool,
     expected_path: Option<&PathBuf>,
+    unlock: bool,
     background: bool,
     verify: bool,
+    wait: bool,
 ) -> anyhow::Result<()> {
     // Use main_path for discovery - the worktree being removed might be cwd,
     // and git operations after removal need a valid working directory.
     let repo = worktrunk::git::Repository::at(main_path)?;
+    let config = WorktrunkConfig::load().ok();
+    let os_trash_limit = config.as_ref().and_then(|c| c.remove_trash_limit());
 
     // Execute pre-remove hooks in the worktree being removed BEFORE writing cd directive.
     // Non-zero exit aborts removal (FailFast strategy).
     // If hooks fail, we don't want the shell to cd to main_path.
     // For detached HEAD, {{ branch }} expands to "HEAD" in templates
-    if verify && let Ok(config) = WorktrunkConfig::load() {
+    if verify && let Some(config) = &config {
         let ctx = CommandContext::new(
             &repo,
-            &config,
+            config,
             branch_name,
             worktree_path,
             main_path,
@@ -788,9 +914,23 @@ fn handle_removed_worktree_output(
         execute_pre_remove_commands(&ctx, None, display_path, &[])?;
     }
 
+    // Held until the worktree is gone; a background removal hands it to the
+    // detached process
+    let lock = OperationLock::acquire(&repo, wait)?;
+    // Unlocked only now that nothing else can stop the removal
+    if unlock {
+        let path = worktree_path.to_string_lossy();
+        repo.run_command(&["worktree", "unlock", path.as_ref()])
+            .context("Failed to unlock worktree")?;
+    }
+    update_zoxide(config.as_ref(), "remove", worktree_path);
+
     // Emit cd directive only after pre-remove hooks succeed
     if changed_directory {
         super::change_directory(main_path)?;
+        if let Ok(Some(main_branch)) = repo.worktree_at(main_path).branch() {
+            super::export_worktree_env(&main_branch)?;
+        }
         super::flush()?; // Force flush to ensure shell processes the cd
     }
 
@@ -801,31 +941,28 @@ fn handle_removed_worktree_output(
             super::print(progress_message(
                 "Removing worktree in background (detached HEAD, no branch to delete)",
             ))?;
-            let remove_command = build_remove_command(worktree_path, None, force_worktree);
-            spawn_detached(
+            spawn_background_removal(
                 &repo,
                 main_path,
-                &remove_command,
+                worktree_path,
                 "detached",
-                "remove",
                 None,
+                force_worktree,
+                os_trash_limit,
+                lock,
             )?;
         } else {
             // Progress message after pre-remove hooks, before actual removal
             super::print(progress_message(
                 "Removing worktree (detached HEAD, no branch to delete)...",
             ))?;
-            let _ = repo
-                .worktree_at(worktree_path)
-                .run_command(&["fsmonitor--daemon", "stop"]);
-            if let Err(err) = repo.remove_worktree(worktree_path, force_worktree) {
-                return Err(GitError::WorktreeRemovalFailed {
-                    branch: path_dir_name(worktree_path).to_string(),
-                    path: worktree_path.to_path_buf(),
-                    error: err.to_string(),
-                }
-                .into());
-            }
+            remove_worktree_foreground(
+                &repo,
+                worktree_path,
+                path_dir_name(worktree_path),
+                force_worktree,
+                os_trash_limit,
+            )?;
             super::print(success_message(
                 "Removed worktree (detached HEAD, no branch to delete)",
             ))?;
@@ -853,21 +990,16 @@ fn handle_removed_worktree_output(
         display_info.print_hints(branch_name, deletion_mode, pre_computed_integration)?;
         print_switch_message_if_changed(changed_directory, main_path)?;
 
-        // Build command with the decision we already made
-        let remove_command = build_remove_command(
-            worktree_path,
-            display_info.branch_deleted().then_some(branch_name),
-            force_worktree,
-        );
-
-        // Spawn the removal in background - runs from main_path (where we cd'd to)
-        spawn_detached(
+        // Spawn the removal with the decision we already made
+        spawn_background_removal(
             &repo,
             main_path,
-            &remove_command,
+            worktree_path,
             branch_name,
-            "remove",
-            None,
+            display_info.branch_deleted().then_some(branch_name),
+            force_worktree,
+            os_trash_limit,
+            lock,
         )?;
 
         spawn_post_switch_after_remove(main_path, verify, changed_directory)?;
@@ -886,20 +1018,13 @@ fn handle_removed_worktree_output(
             super::print(format_path_mismatch_warning(branch_name, expected))?;
         }
 
-        // Stop fsmonitor daemon first (best effort - ignore errors)
-        // This prevents zombie daemons from accumulating when using builtin fsmonitor
-        let _ = repo
-            .worktree_at(worktree_path)
-            .run_command(&["fsmonitor--daemon", "stop"]);
-
-        if let Err(err) = repo.remove_worktree(worktree_path, force_worktree) {
-            return Err(GitError::WorktreeRemovalFailed {
-                branch: branch_name.into(),
-                path: worktree_path.to_path_buf(),
-                error: err.to_string(),
-            }
-            .into());
-        }
+        remove_worktree_foreground(
+            &repo,
+            worktree_path,
+            branch_name,
+            force_worktree,
+            os_trash_limit,
+        )?;
 
         let display_info = RemovalDisplayInfo::from_actual(
             &repo,
@@ -920,6 +1045,96 @@ fn handle_removed_worktree_output(
     }
 }
 
+/// Start a background removal in a detached process.
+///
+/// The worktree is moved into the trash directory first, so it's gone by the
+/// time `wt` exits and the detached process only deletes files (plus the
+/// branch). When the move isn't possible, the detached process falls back to
+/// `git worktree remove`. `name` labels the log file and the trash entry.
+#[allow(clippy::too_many_arguments)]
+fn spawn_background_removal(
+    repo: &Repository,
+    main_path: &Path,
+    worktree_path: &Path,
+    name: &str,
+    branch_to_delete: Option<&str>,
+    force_worktree: bool,
+    os_trash_limit: Option<u64>,
+    lock: OperationLock,
+) -> anyhow::Result<()> {
+    let staged = stage_worktree_removal(repo, worktree_path, name, force_worktree)
+        .map_err(|err| removal_error(name, worktree_path, err))?;
+    let remove_command = match staged {
+        StagedRemoval::Staged(trash_path) => {
+            // The OS trash needs wt itself; plain deletion is just `rm -rf`
+            let wt_exe = match os_trash_limit {
+                Some(_) => Some(std::env::current_exe().context("Failed to locate wt executable")?),
+                None => None,
+            };
+            build_trash_delete_command(&trash_path, branch_to_delete, wt_exe.as_deref())
+        }
+        StagedRemoval::InPlace => {
+            build_remove_command(worktree_path, branch_to_delete, force_worktree)
+        }
+    };
+
+    // Runs from main_path (where we cd'd to), holding the lock until it's done
+    spawn_detached_with_lock(repo, main_path, &remove_command, name, "remove", lock)?;
+    Ok(())
+}
+
+/// Wrap a failed removal, passing refusals from the pre-removal checks through as-is.
+fn removal_error(name: &str, worktree_path: &Path, err: anyhow::Error) -> anyhow::Error {
+    if matches!(
+        err.downcast_ref::<GitError>(),
+        Some(GitError::UncommittedChanges { .. } | GitError::WorktreeRemovalFailed { .. })
+    ) {
+        return err;
+    }
+    GitError::WorktreeRemovalFailed {
+        branch: name.into(),
+        path: worktree_path.to_path_buf(),
+        error: err.to_string(),
+    }
+    .into()
+}
+
+/// Remove a worktree and wait for its files to be deleted.
+///
+/// Files are deleted in parallel from the trash directory with a spinner on
+/// stderr. If the worktree can't be moved to the trash, falls back to
+/// `git worktree remove`.
+fn remove_worktree_foreground(
+    repo: &Repository,
+    worktree_path: &Path,
+    name: &str,
+    force_worktree: bool,
+    os_trash_limit: Option<u64>,
+) -> anyhow::Result<()> {
+    match stage_worktree_removal(repo, worktree_path, name, force_worktree)
+        .map_err(|err| removal_error(name, worktree_path, err))?
+    {
+        StagedRemoval::Staged(trash_path) => match dispose_staged(&trash_path, os_trash_limit) {
+            Ok(Disposal::OsTrash) => {
+                super::print(info_message("Moved worktree files to the trash"))?;
+            }
+            Ok(Disposal::Deleted) => {}
+            // The worktree is already unregistered, so a failed delete only
+            // leaves files behind in the trash directory
+            Err(err) => {
+                let trash_display = format_path_for_display(&trash_path);
+                super::print(warning_message(cformat!(
+                    "Failed to delete files in <bold>{trash_display}</>: {err}"
+                )))?;
+            }
+        },
+        StagedRemoval::InPlace => repo
+            .remove_worktree(worktree_path, force_worktree)
+            .map_err(|err| removal_error(name, worktree_path, err))?,
+    }
+    Ok(())
+}
+
 /// Execute a command in a worktree directory
 ///
 /// Redirects child stdout to stderr (via `.stdout(Stdio::from(std::io::stderr()))`) for
@@ -948,6 +1163,7 @@ pub fn execute_command_in_worktree(
     worktree_path: &std::path::Path,
     command: &str,
     stdin_content: Option<&str>,
+    env: &[(String, String)],
 ) -> anyhow::Result<()> {
     use std::io::Write;
     use worktrunk::shell_exec::Cmd;
@@ -973,6 +1189,9 @@ pub fn execute_command_in_worktree(
     if let Some(content) = stdin_content {
         cmd = cmd.stdin_bytes(content);
     }
+    for (key, value) in env {
+        cmd = cmd.env(key, value);
+    }
 
     cmd.stream()?;
 
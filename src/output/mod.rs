@@ -18,7 +18,7 @@
 //! ## Shell Integration
 //!
 //! When `WORKTRUNK_DIRECTIVE_FILE` env var is set (by shell wrapper):
-//! - Shell commands (cd, exec) are written to that file
+//! - Shell commands (cd, `WT_*` exports, exec) are written to that file
 //! - Shell wrapper sources the file after wt exits
 //! - This allows the parent shell to change directory
 //!
@@ -30,12 +30,14 @@
 
 mod global;
 pub(crate) mod handlers;
+pub(crate) mod prompt;
 pub(crate) mod shell_integration;
 
 // Re-export the public API
 pub(crate) use global::{
-    blank, change_directory, execute, flush, is_shell_integration_active, post_hook_display_path,
-    pre_hook_display_path, print, stdout, terminate_output,
+    blank, change_directory, execute, export_worktree_env, flush, is_shell_integration_active,
+    post_hook_display_path, pre_hook_display_path, print, print_directive, stdout,
+    terminate_output,
 };
 // Re-export output handlers
 pub(crate) use handlers::{
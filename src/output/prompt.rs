@@ -0,0 +1,233 @@
+//! Interactive prompts.
+//!
+//! Every yes/no question goes through [`Confirm`], and every other question
+//! (menus, free-form answers) through [`Input`], so automation sees the same
+//! behavior from every command. A prompt resolves, first match wins:
+//!
+//! 1. Global `--no`: decline. Declining is the safe answer, so it beats `--yes`.
+//! 2. `--yes` (the command's own or the global one), or
+//!    `WORKTRUNK_ASSUME_YES=1`: accept.
+//! 3. stdin isn't a terminal: apply the prompt's [`NonInteractive`] policy.
+//! 4. Otherwise ask on stderr; Enter picks the prompt's default.
+//!
+//! A prompt built with [`Confirm::always_ask`] skips step 2: only a typed yes
+//! accepts it.
+
+use std::io::{self, IsTerminal, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use color_print::cformat;
+use worktrunk::git::GitError;
+use worktrunk::styling::{PROMPT_SYMBOL, eprint, stderr};
+
+/// Environment variable that answers yes to every prompt, like `--yes`.
+pub const ASSUME_YES_ENV_VAR: &str = "WORKTRUNK_ASSUME_YES";
+
+/// Set by the global `--no` flag.
+static ASSUME_NO: AtomicBool = AtomicBool::new(false);
+
+/// Set by the global `--yes` flag.
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
+
+/// Decline every prompt for the rest of the process (`--no`).
+pub fn set_assume_no() {
+    ASSUME_NO.store(true, Ordering::Relaxed);
+}
+
+/// Accept every prompt for the rest of the process (global `--yes`).
+pub fn set_assume_yes() {
+    ASSUME_YES.store(true, Ordering::Relaxed);
+}
+
+/// The answer fixed by `--no`, the global `--yes` or `WORKTRUNK_ASSUME_YES`,
+/// if any.
+///
+/// Doesn't include a command's own `--yes`; pass that to [`Confirm::ask`].
+pub fn assumed_answer() -> Option<bool> {
+    if ASSUME_NO.load(Ordering::Relaxed) {
+        return Some(false);
+    }
+    if ASSUME_YES.load(Ordering::Relaxed) {
+        return Some(true);
+    }
+    std::env::var(ASSUME_YES_ENV_VAR)
+        .is_ok_and(|value| {
+            matches!(
+                value.trim().to_ascii_lowercase().as_str(),
+                "1" | "true" | "yes"
+            )
+        })
+        .then_some(true)
+}
+
+/// Whether a prompt can be shown, i.e. stdin is a terminal.
+pub fn is_interactive() -> bool {
+    io::stdin().is_terminal()
+}
+
+/// What a prompt does when stdin isn't a terminal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonInteractive {
+    /// Take the default answer
+    Default,
+    /// Fail with [`GitError::NotInteractive`], for prompts guarding something
+    /// the user has to opt into (e.g. running project commands)
+    Fail,
+}
+
+/// A yes/no question.
+///
+/// ```rust,ignore
+/// if !Confirm::new("Prune?", false).ask(yes)? {
+///     return Ok(());
+/// }
+/// ```
+pub struct Confirm {
+    question: String,
+    default: bool,
+    non_interactive: NonInteractive,
+    always_ask: bool,
+}
+
+impl Confirm {
+    /// A question with `default` as the Enter answer (`[Y/n]` or `[y/N]`).
+    ///
+    /// Without a terminal it takes the default, unless changed with
+    /// [`Confirm::non_interactive`].
+    pub fn new(question: impl Into<String>, default: bool) -> Self {
+        Self {
+            question: question.into(),
+            default,
+            non_interactive: NonInteractive::Default,
+            always_ask: false,
+        }
+    }
+
+    /// Ignore `--yes` and `WORKTRUNK_ASSUME_YES`, for prompts guarding
+    /// something automation must never do (e.g. deleting files that may be
+    /// the user's own). `--no` still declines.
+    pub fn always_ask(mut self) -> Self {
+        self.always_ask = true;
+        self
+    }
+
+    /// Set what happens when stdin isn't a terminal.
+    pub fn non_interactive(mut self, policy: NonInteractive) -> Self {
+        self.non_interactive = policy;
+        self
+    }
+
+    /// Resolve the question; `yes` is the command's `--yes` flag.
+    pub fn ask(&self, yes: bool) -> anyhow::Result<bool> {
+        match assumed_answer() {
+            Some(false) => return Ok(false),
+            _ if self.always_ask => {}
+            Some(true) => return Ok(true),
+            None if yes => return Ok(true),
+            None => {}
+        }
+
+        if !is_interactive() {
+            return match self.non_interactive {
+                NonInteractive::Default => Ok(self.default),
+                NonInteractive::Fail => Err(GitError::NotInteractive.into()),
+            };
+        }
+
+        let choices = if self.default { "[Y/n]" } else { "[y/N]" };
+        super::flush()?;
+        eprint!(
+            "{}",
+            cformat!("{PROMPT_SYMBOL} {} <bold>{choices}</> ", self.question)
+        );
+        stderr().flush()?;
+
+        let mut response = String::new();
+        io::stdin().read_line(&mut response)?;
+        super::blank()?;
+
+        Ok(parse_answer(&response, self.default))
+    }
+}
+
+/// A question answered with a line of text: a menu choice or free-form input.
+///
+/// ```rust,ignore
+/// let choice = match Input::new("Branch number:").ask(yes)? {
+///     Response::Text(text) => text,
+///     Response::Yes | Response::NotInteractive => default_choice,
+///     Response::No => return Ok(None),
+/// };
+/// ```
+pub struct Input {
+    question: String,
+}
+
+/// How an [`Input`] was resolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Response {
+    /// What the user typed, trimmed
+    Text(String),
+    /// `--yes` or `WORKTRUNK_ASSUME_YES`: take the default
+    Yes,
+    /// `--no`: decline
+    No,
+    /// stdin isn't a terminal; the caller decides
+    NotInteractive,
+}
+
+impl Input {
+    /// A question; the answer is typed after it on the same line.
+    pub fn new(question: impl Into<String>) -> Self {
+        Self {
+            question: question.into(),
+        }
+    }
+
+    /// Resolve the question; `yes` is the command's `--yes` flag.
+    pub fn ask(&self, yes: bool) -> anyhow::Result<Response> {
+        match assumed_answer() {
+            Some(false) => return Ok(Response::No),
+            Some(true) => return Ok(Response::Yes),
+            None if yes => return Ok(Response::Yes),
+            None => {}
+        }
+        if !is_interactive() {
+            return Ok(Response::NotInteractive);
+        }
+
+        super::flush()?;
+        eprint!("{}", cformat!("{PROMPT_SYMBOL} {} ", self.question));
+        stderr().flush()?;
+
+        let mut response = String::new();
+        io::stdin().read_line(&mut response)?;
+        super::blank()?;
+
+        Ok(Response::Text(response.trim().to_string()))
+    }
+}
+
+/// Interpret a typed answer: Enter takes the default, anything but yes declines.
+fn parse_answer(response: &str, default: bool) -> bool {
+    match response.trim().to_ascii_lowercase().as_str() {
+        "" => default,
+        "y" | "yes" => true,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answer() {
+        assert!(parse_answer("\n", true));
+        assert!(!parse_answer("\n", false));
+        assert!(parse_answer("Y\n", false));
+        assert!(parse_answer(" yes ", false));
+        assert!(!parse_answer("n", true));
+        assert!(!parse_answer("maybe", true));
+    }
+}
@@ -217,13 +217,13 @@ pub fn print_shell_install_result(
         );
 
         match result.action {
-            ConfigAction::Added | ConfigAction::Created => {
+            ConfigAction::Added | ConfigAction::Created | ConfigAction::Updated => {
                 super::print(success_message(message))?;
             }
             ConfigAction::AlreadyExists => {
                 super::print(info_message(message))?;
             }
-            ConfigAction::WouldAdd | ConfigAction::WouldCreate => {
+            ConfigAction::WouldAdd | ConfigAction::WouldCreate | ConfigAction::WouldUpdate => {
                 unreachable!("Preview actions handled by confirmation prompt")
             }
         }
@@ -240,13 +240,13 @@ pub fn print_shell_install_result(
                 comp_result.action.description()
             );
             match comp_result.action {
-                ConfigAction::Added | ConfigAction::Created => {
+                ConfigAction::Added | ConfigAction::Created | ConfigAction::Updated => {
                     super::print(success_message(comp_message))?;
                 }
                 ConfigAction::AlreadyExists => {
                     super::print(info_message(comp_message))?;
                 }
-                ConfigAction::WouldAdd | ConfigAction::WouldCreate => {
+                ConfigAction::WouldAdd | ConfigAction::WouldCreate | ConfigAction::WouldUpdate => {
                     unreachable!("Preview actions handled by confirmation prompt")
                 }
             }
@@ -384,8 +384,13 @@ pub fn prompt_shell_integration(
         return Ok(false);
     }
 
-    // Can't or shouldn't prompt - show install hint
-    if config.skip_shell_integration_prompt || !is_tty || skip_prompt {
+    // Can't or shouldn't prompt - show install hint. Assumed answers (`--no`,
+    // `WORKTRUNK_ASSUME_YES`) are for the command at hand, not this offer.
+    if config.skip_shell_integration_prompt
+        || !is_tty
+        || skip_prompt
+        || super::prompt::assumed_answer().is_some()
+    {
         super::print(hint_message(shell_integration_hint()))?;
         return Ok(false);
     }
@@ -1,5 +1,7 @@
 use std::path::{Path, PathBuf};
 
+use crate::styling::is_invisible_format;
+
 #[cfg(windows)]
 use crate::shell_exec::Cmd;
 
@@ -104,19 +106,10 @@ pub fn format_path_for_display(path: &Path) -> String {
 
 /// Sanitize a string for use as a filename on all platforms.
 ///
-/// Replaces invalid characters and control characters with `-`, trims trailing
+/// Replaces invalid, control and invisible formatting characters with `-`, trims trailing
 /// dots/spaces (Windows), and prefixes reserved device names with `_`.
 pub fn sanitize_for_filename(value: &str) -> String {
-    let mut result: String = value
-        .chars()
-        .map(|c| match c {
-            // Windows/Unix invalid filename characters
-            '/' | '\\' | '<' | '>' | ':' | '"' | '|' | '?' | '*' => '-',
-            // Control characters (0x00-0x1F)
-            c if c.is_control() => '-',
-            _ => c,
-        })
-        .collect();
+    let mut result = replace_invalid_filename_chars(value);
 
     // Trim trailing dots and spaces (Windows silently strips these)
     while result.ends_with('.') || result.ends_with(' ') {
@@ -140,6 +133,23 @@ pub fn sanitize_for_filename(value: &str) -> String {
     }
 }
 
+/// Replace characters that are invalid in a filename on any platform with `-`.
+///
+/// The character map behind [`sanitize_for_filename`], without its Windows name
+/// handling; the `sanitize` template filter uses it directly.
+pub(crate) fn replace_invalid_filename_chars(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            // Windows/Unix invalid filename characters
+            '/' | '\\' | '<' | '>' | ':' | '"' | '|' | '?' | '*' => '-',
+            // Control characters, and bidi overrides and zero-width spaces
+            c if c.is_control() || is_invisible_format(c) => '-',
+            _ => c,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -255,6 +265,8 @@ mod tests {
     fn test_sanitize_for_filename_replaces_invalid_chars() {
         assert_eq!(sanitize_for_filename("foo/bar"), "foo-bar");
         assert_eq!(sanitize_for_filename("name:with?chars"), "name-with-chars");
+        assert_eq!(sanitize_for_filename("bidi\u{202E}txt"), "bidi-txt");
+        assert_eq!(sanitize_for_filename("日本語-🐛"), "日本語-🐛");
     }
 
     #[test]
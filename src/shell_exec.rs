@@ -10,7 +10,7 @@
 
 use std::path::PathBuf;
 use std::process::Command;
-use std::sync::OnceLock;
+use std::sync::{Mutex, OnceLock};
 use std::time::Instant;
 
 use crate::sync::Semaphore;
@@ -29,6 +29,65 @@ fn trace_epoch() -> &'static Instant {
     TRACE_EPOCH.get_or_init(Instant::now)
 }
 
+/// Running totals of captured command time, reported by `--timings`.
+static COMMAND_TIMINGS: Mutex<CommandTimings> = Mutex::new(CommandTimings {
+    git: KindTiming::ZERO,
+    ci: KindTiming::ZERO,
+    other: KindTiming::ZERO,
+});
+
+/// Count and summed duration of one kind of external command.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KindTiming {
+    pub count: u64,
+    pub total: Duration,
+}
+
+impl KindTiming {
+    const ZERO: Self = Self {
+        count: 0,
+        total: Duration::ZERO,
+    };
+}
+
+/// Time spent in commands run via [`Cmd::run`], grouped by kind.
+///
+/// Durations are summed across threads, so with parallel commands (as in
+/// `wt list`) a total can exceed the elapsed time.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CommandTimings {
+    /// `git` invocations
+    pub git: KindTiming,
+    /// Forge CLIs (`gh`, `glab`), which make network calls
+    pub ci: KindTiming,
+    /// Everything else
+    pub other: KindTiming,
+}
+
+/// Snapshot of the command timings recorded so far in this process.
+pub fn command_timings() -> CommandTimings {
+    *COMMAND_TIMINGS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn record_command_timing(program: &str, duration: Duration) {
+    let name = std::path::Path::new(program)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or(program);
+    let mut timings = COMMAND_TIMINGS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let kind = match name {
+        "git" => &mut timings.git,
+        "gh" | "glab" => &mut timings.ci,
+        _ => &mut timings.other,
+    };
+    kind.count += 1;
+    kind.total += duration;
+}
+
 /// Default concurrent external commands. Tuned to avoid hitting OS limits
 /// (file descriptors, process limits) while maintaining good parallelism.
 const DEFAULT_CONCURRENT_COMMANDS: usize = 32;
@@ -369,6 +428,8 @@ pub struct Cmd {
     shell_wrap: bool,
     /// Stdout configuration for stream() (defaults to inherit)
     stdout_cfg: Option<std::process::Stdio>,
+    /// Stderr configuration for stream() (defaults to inherit)
+    stderr_cfg: Option<std::process::Stdio>,
     /// Stdin configuration for stream() (defaults to null, or piped if stdin_data is set)
     stdin_cfg: Option<std::process::Stdio>,
     /// If true, forward signals to child process group (for stream(), Unix only)
@@ -392,6 +453,7 @@ impl Cmd {
             env_removes: Vec::new(),
             shell_wrap: false,
             stdout_cfg: None,
+            stderr_cfg: None,
             stdin_cfg: None,
             forward_signals: false,
         }
@@ -415,6 +477,7 @@ impl Cmd {
             env_removes: Vec::new(),
             shell_wrap: true,
             stdout_cfg: None,
+            stderr_cfg: None,
             stdin_cfg: None,
             forward_signals: false,
         }
@@ -489,6 +552,17 @@ impl Cmd {
         self
     }
 
+    /// Set stderr configuration for `.stream()`.
+    ///
+    /// Defaults to `Stdio::inherit()`. Point both stdout and stderr at a file to
+    /// collect a command's output instead of showing it as it runs.
+    ///
+    /// Only affects `.stream()`. For `.run()`, output is always captured separately.
+    pub fn stderr(mut self, cfg: std::process::Stdio) -> Self {
+        self.stderr_cfg = Some(cfg);
+        self
+    }
+
     /// Set stdin configuration for `.stream()`.
     ///
     /// Defaults to `Stdio::null()`. Use `Stdio::inherit()` for interactive commands
@@ -603,7 +677,9 @@ impl Cmd {
         };
 
         // Log trace
-        let dur_us = t0.elapsed().as_micros() as u64;
+        let elapsed = t0.elapsed();
+        record_command_timing(&self.program, elapsed);
+        let dur_us = elapsed.as_micros() as u64;
         match (&result, &self.context) {
             (Ok(output), Some(ctx)) => {
                 log::debug!(
@@ -657,7 +733,8 @@ impl Cmd {
     /// Execute the command with streaming output (inherits stdio).
     ///
     /// Unlike `.run()`, this method:
-    /// - Inherits stderr to preserve TTY behavior (colors, progress bars)
+    /// - Inherits stderr to preserve TTY behavior (colors, progress bars), unless
+    ///   redirected via `.stderr()`
     /// - Optionally redirects stdout to stderr (via `.stdout(Stdio::from(io::stderr()))`)
     /// - Optionally inherits stdin for interactive commands (via `.stdin(Stdio::inherit())`)
     /// - Optionally forwards signals to child process group (via `.forward_signals()`)
@@ -719,6 +796,7 @@ impl Cmd {
 
         // Determine stdout handling (default: inherit)
         let stdout_mode = self.stdout_cfg.unwrap_or_else(std::process::Stdio::inherit);
+        let stderr_mode = self.stderr_cfg.unwrap_or_else(std::process::Stdio::inherit);
 
         // Determine stdin handling (stdin_bytes takes precedence, then stdin cfg, then null)
         let stdin_mode = if self.stdin_data.is_some() {
@@ -744,7 +822,7 @@ impl Cmd {
         cmd.current_dir(working_dir)
             .stdin(stdin_mode)
             .stdout(stdout_mode)
-            .stderr(std::process::Stdio::inherit()) // Preserve TTY for errors
+            .stderr(stderr_mode) // Inherited by default to preserve TTY for errors
             // Prevent vergen "overridden" warning in nested cargo builds
             .env_remove("VERGEN_GIT_DESCRIBE");
 
@@ -75,6 +75,64 @@ pub fn visual_width(s: &str) -> usize {
     s.ansi_strip().width()
 }
 
+/// An error as one line of plain text, for clients that don't render
+/// terminal output (`wt serve`, the C API).
+///
+/// Keeps the context chain and drops colors, message symbols and every line
+/// after the first (hints and gutter output).
+pub fn plain_error_message(error: &anyhow::Error) -> String {
+    use ansi_str::AnsiStr;
+    let text = format!("{error:#}");
+    let plain = text.ansi_strip();
+    let mut line = plain.lines().next().unwrap_or_default().to_string();
+    for symbol in [
+        ERROR_SYMBOL,
+        WARNING_SYMBOL,
+        HINT_SYMBOL,
+        INFO_SYMBOL,
+        SUCCESS_SYMBOL,
+        PROGRESS_SYMBOL,
+    ] {
+        line = line.replace(&format!("{} ", symbol.ansi_strip()), "");
+    }
+    line
+}
+
+/// Whether a character is invisible but changes how the text around it shows:
+/// bidirectional overrides, isolates and marks, zero-width spaces and BOMs.
+///
+/// Joiners (U+200C, U+200D) are left alone; emoji sequences and some scripts
+/// need them.
+pub fn is_invisible_format(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}'
+            | '\u{200B}'
+            | '\u{200E}'
+            | '\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+/// Make user-provided text, such as a branch name, safe to print in a column.
+///
+/// Control characters and [invisible formatting](is_invisible_format) would
+/// move the cursor, reorder the line, or occupy no width while still counting
+/// as text, so each becomes `�`. Wide characters (CJK, emoji) and
+/// right-to-left letters are kept; their width comes from `unicode-width`.
+pub fn sanitize_for_display(text: &str) -> std::borrow::Cow<'_, str> {
+    let unsafe_char = |c: char| c.is_control() || is_invisible_format(c);
+    if !text.chars().any(unsafe_char) {
+        return std::borrow::Cow::Borrowed(text);
+    }
+    text.chars()
+        .map(|c| if unsafe_char(c) { '\u{FFFD}' } else { c })
+        .collect::<String>()
+        .into()
+}
+
 /// Fix dim rendering for terminals that don't handle \e[2m after \e[39m.
 ///
 /// Claude Code's terminal doesn't render dim (\e[2m) correctly when it follows
@@ -92,8 +150,51 @@ pub fn fix_dim_after_color_reset(s: &str) -> String {
 mod tests {
     use super::*;
     use anstyle::Style;
+    use color_print::cformat;
     use unicode_width::UnicodeWidthStr;
 
+    #[test]
+    fn test_sanitize_for_display() {
+        // Wide and right-to-left text is kept as is
+        for name in [
+            "feature/日本語",
+            "fix-🐛-crash",
+            "👨\u{200D}👩\u{200D}👧",
+            "תיקון-באג",
+        ] {
+            assert!(
+                matches!(sanitize_for_display(name), std::borrow::Cow::Borrowed(_)),
+                "{name}"
+            );
+        }
+        // Escapes, bidi overrides and zero-width spaces can't reach the terminal
+        assert_eq!(sanitize_for_display("a\x1b[31mb"), "a\u{FFFD}[31mb");
+        assert_eq!(
+            sanitize_for_display("evil\u{202E}gpj.exe"),
+            "evil\u{FFFD}gpj.exe"
+        );
+        assert_eq!(
+            sanitize_for_display("zero\u{200B}width"),
+            "zero\u{FFFD}width"
+        );
+        // Each replacement is one column wide, so widths stay honest
+        assert_eq!(sanitize_for_display("a\u{2066}b").width(), 3);
+    }
+
+    #[test]
+    fn test_plain_error_message() {
+        let error = anyhow::Error::msg(format!(
+            "{}\n{}",
+            error_message(cformat!("Branch <bold>feature</> not found")),
+            hint_message("To create it, add --create")
+        ))
+        .context("Failed to switch");
+        assert_eq!(
+            plain_error_message(&error),
+            "Failed to switch: Branch feature not found"
+        );
+    }
+
     #[test]
     fn test_toml_formatting() {
         let toml_content = r#"worktree-path = "../{{ repo }}.{{ branch }}"
@@ -0,0 +1,81 @@
+//! `--timings`: a short breakdown of where a command's time went.
+//!
+//! Uses the same instrumentation as `wt-trace` (see [`worktrunk::shell_exec`]),
+//! summarized at exit instead of logged per command. For a full timeline, use
+//! `RUST_LOG=debug` with `wt-perf trace`.
+
+use std::time::{Duration, Instant};
+
+use worktrunk::shell_exec::{KindTiming, command_timings};
+use worktrunk::styling::{format_with_gutter, info_message};
+
+use crate::output;
+
+/// Print the timing summary for a command that started at `started`.
+pub(crate) fn print_summary(started: Instant) -> std::io::Result<()> {
+    let timings = command_timings();
+    let mut lines = vec![
+        kind_line("git", "command", timings.git),
+        kind_line("CI", "call", timings.ci),
+        kind_line("other", "command", timings.other),
+    ];
+    if let Some(cpu) = cpu_time() {
+        lines.push(format!("{:<6}{:>18}", "CPU", format_secs(cpu)));
+    }
+
+    output::print(info_message(format!(
+        "Timings: {} elapsed",
+        format_secs(started.elapsed())
+    )))?;
+    output::print(format_with_gutter(&lines.join("\n"), None))
+}
+
+fn kind_line(label: &str, noun: &str, timing: KindTiming) -> String {
+    let plural = if timing.count == 1 { "" } else { "s" };
+    let count = format!("{} {noun}{plural}", timing.count);
+    format!("{label:<6}{count:<12}{:>6}", format_secs(timing.total))
+}
+
+fn format_secs(duration: Duration) -> String {
+    format!("{:.2}s", duration.as_secs_f64())
+}
+
+/// CPU time (user + system) used by wt itself, excluding child processes.
+#[cfg(unix)]
+fn cpu_time() -> Option<Duration> {
+    use nix::sys::resource::{UsageWho, getrusage};
+    use nix::sys::time::TimeVal;
+
+    let usage = getrusage(UsageWho::RUSAGE_SELF).ok()?;
+    let to_duration = |tv: TimeVal| {
+        Duration::from_secs(tv.tv_sec() as u64) + Duration::from_micros(tv.tv_usec() as u64)
+    };
+    Some(to_duration(usage.user_time()) + to_duration(usage.system_time()))
+}
+
+#[cfg(not(unix))]
+fn cpu_time() -> Option<Duration> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kind_line() {
+        let timing = KindTiming {
+            count: 37,
+            total: Duration::from_millis(850),
+        };
+        assert_eq!(
+            kind_line("git", "command", timing),
+            "git   37 commands  0.85s"
+        );
+        let timing = KindTiming {
+            count: 1,
+            total: Duration::from_millis(20),
+        };
+        assert_eq!(kind_line("CI", "call", timing), "CI    1 call       0.02s");
+    }
+}
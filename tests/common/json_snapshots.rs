@@ -0,0 +1,211 @@
+//! Golden JSON harness for machine-readable output.
+//!
+//! `wt list --format=json` is read by scripts and editor integrations, so its
+//! shape is an API. Table snapshots don't protect it: a renamed or dropped field
+//! only shows up as a snapshot diff that's easy to accept. This harness:
+//!
+//! - parses output into [`serde_json::Value`] and normalizes volatile values
+//!   (SHAs, paths, timestamps) so outputs can be compared directly;
+//! - derives a schema — each field path with its JSON type — and checks it
+//!   against a versioned baseline in `tests/json_schema/`.
+//!
+//! Baselines are per compatibility version: `0.15` for 0.15.x (0.x minors break
+//! compatibility, as in Cargo's semver rules), `1` for 1.x. Within a version,
+//! fields may be added but never removed or retyped. Set
+//! `WORKTRUNK_UPDATE_JSON_SCHEMA=1` to record new fields in the baseline, or to
+//! start a baseline for a new version.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use serde_json::Value;
+
+use super::{TestRepo, canonicalize};
+
+/// Run `wt list --format=json` with extra args and parse the output.
+pub fn list_json(repo: &TestRepo, args: &[&str]) -> Value {
+    let mut cmd = repo.wt_command();
+    cmd.args(["list", "--format=json"]).args(args);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "wt list --format=json failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).expect("wt list --format=json should emit valid JSON")
+}
+
+/// Replace values that vary between runs and machines with placeholders.
+///
+/// - Full commit SHAs become `[SHA]`, `short_sha` values `[SHORT_SHA]`
+/// - `timestamp` values become `[TIMESTAMP]`, `time` values `[TIME]`
+/// - Paths under the test repo's directory are rewritten relative to `[REPO]`
+pub fn normalize(value: &mut Value, repo: &TestRepo) {
+    let mut roots = vec![repo.root_path().to_path_buf()];
+    if let Ok(canonical) = canonicalize(repo.root_path()) {
+        roots.push(canonical);
+    }
+    normalize_with(value, None, &roots);
+}
+
+fn normalize_with(value: &mut Value, key: Option<&str>, roots: &[PathBuf]) {
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map.iter_mut() {
+                normalize_with(v, Some(k), roots);
+            }
+        }
+        Value::Array(items) => {
+            for item in items {
+                normalize_with(item, key, roots);
+            }
+        }
+        Value::Number(_) if key == Some("timestamp") => {
+            *value = Value::from("[TIMESTAMP]");
+        }
+        Value::String(s) => {
+            if key == Some("short_sha") {
+                *value = Value::from("[SHORT_SHA]");
+            } else if key == Some("time") {
+                *value = Value::from("[TIME]");
+            } else if s.len() == 40 && s.bytes().all(|b| b.is_ascii_hexdigit()) {
+                *value = Value::from("[SHA]");
+            } else if let Some(rest) = roots.iter().find_map(|root| s.strip_prefix(root.to_str()?))
+            {
+                *value = Value::from(format!("[REPO]{}", rest.replace('\\', "/")));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Every field path in `value` with its JSON type, e.g. `[].commit.sha: string`.
+///
+/// Array elements are merged under `[]`; an empty array records `array`.
+pub fn schema(value: &Value) -> BTreeSet<String> {
+    let mut fields = BTreeSet::new();
+    collect_schema(value, "", &mut fields);
+    fields
+}
+
+fn collect_schema(value: &Value, path: &str, fields: &mut BTreeSet<String>) {
+    let leaf = |ty: &str| format!("{path}: {ty}");
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map {
+                let child = if path.is_empty() {
+                    k.clone()
+                } else {
+                    format!("{path}.{k}")
+                };
+                collect_schema(v, &child, fields);
+            }
+        }
+        Value::Array(items) if items.is_empty() => {
+            fields.insert(leaf("array"));
+        }
+        Value::Array(items) => {
+            for item in items {
+                collect_schema(item, &format!("{path}[]"), fields);
+            }
+        }
+        Value::Null => {
+            fields.insert(leaf("null"));
+        }
+        Value::Bool(_) => {
+            fields.insert(leaf("boolean"));
+        }
+        Value::Number(_) => {
+            fields.insert(leaf("number"));
+        }
+        Value::String(_) => {
+            fields.insert(leaf("string"));
+        }
+    }
+}
+
+/// Compatibility version for schema baselines: `0.<minor>` before 1.0, else `<major>`.
+fn compat_version() -> String {
+    let version = env!("CARGO_PKG_VERSION");
+    let mut parts = version.split('.');
+    match (parts.next(), parts.next()) {
+        (Some("0"), Some(minor)) => format!("0.{minor}"),
+        (Some(major), _) => major.to_string(),
+        _ => unreachable!("CARGO_PKG_VERSION is semver"),
+    }
+}
+
+fn baseline_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("json_schema")
+        .join(format!("{name}.v{}.txt", compat_version()))
+}
+
+/// Assert that `value` keeps every field of the `name` baseline for this version.
+///
+/// New fields pass; run with `WORKTRUNK_UPDATE_JSON_SCHEMA=1` to add them to
+/// the baseline so they're protected too.
+pub fn assert_schema_compatible(name: &str, value: &Value) {
+    let current = schema(value);
+    let path = baseline_path(name);
+    let update = std::env::var_os("WORKTRUNK_UPDATE_JSON_SCHEMA").is_some();
+
+    let baseline: BTreeSet<String> = match std::fs::read_to_string(&path) {
+        Ok(content) => content
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(String::from)
+            .collect(),
+        Err(_) if update => BTreeSet::new(),
+        Err(e) => panic!(
+            "No JSON schema baseline at {} ({e}); run with WORKTRUNK_UPDATE_JSON_SCHEMA=1 to create it",
+            path.display()
+        ),
+    };
+
+    let removed: Vec<&String> = baseline.difference(&current).collect();
+    assert!(
+        removed.is_empty(),
+        "JSON output for {name} dropped or retyped fields from the v{} baseline \
+         (breaking for consumers until the next major version):\n  {}",
+        compat_version(),
+        removed
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>()
+            .join("\n  ")
+    );
+
+    if update {
+        let merged: Vec<&String> = baseline.union(&current).collect();
+        let content: String = merged.iter().map(|line| format!("{line}\n")).collect();
+        std::fs::write(&path, content).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_schema_paths() {
+        let value = json!([
+            {"branch": "main", "commit": {"sha": "abc", "timestamp": 1}, "tags": []},
+            {"branch": null, "worktree": {"detached": true}},
+        ]);
+        let fields: Vec<String> = schema(&value).into_iter().collect();
+        assert_eq!(
+            fields,
+            vec![
+                "[].branch: null",
+                "[].branch: string",
+                "[].commit.sha: string",
+                "[].commit.timestamp: number",
+                "[].tags: array",
+                "[].worktree.detached: boolean",
+            ]
+        );
+    }
+}
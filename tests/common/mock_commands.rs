@@ -16,7 +16,8 @@
 use serde_json::json;
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 /// Path to the mock-stub binary, built by `cargo test`.
 fn mock_stub_binary() -> std::path::PathBuf {
@@ -47,6 +48,7 @@ pub struct MockConfig {
     name: String,
     version: Option<String>,
     commands: HashMap<String, MockResponse>,
+    rules: Vec<(Vec<String>, MockResponse)>,
 }
 
 /// How to respond to a command.
@@ -55,6 +57,8 @@ pub struct MockResponse {
     output: Option<String>,
     stderr: Option<String>,
     exit_code: i32,
+    delay_ms: u64,
+    sequence: Vec<MockResponse>,
 }
 
 impl MockResponse {
@@ -65,6 +69,8 @@ impl MockResponse {
             output: None,
             stderr: None,
             exit_code: 0,
+            delay_ms: 0,
+            sequence: Vec::new(),
         }
     }
 
@@ -75,6 +81,8 @@ impl MockResponse {
             output: Some(text.to_string()),
             stderr: None,
             exit_code: 0,
+            delay_ms: 0,
+            sequence: Vec::new(),
         }
     }
 
@@ -85,6 +93,8 @@ impl MockResponse {
             output: None,
             stderr: Some(text.to_string()),
             exit_code: 0,
+            delay_ms: 0,
+            sequence: Vec::new(),
         }
     }
 
@@ -95,9 +105,37 @@ impl MockResponse {
             output: None,
             stderr: None,
             exit_code: code,
+            delay_ms: 0,
+            sequence: Vec::new(),
         }
     }
 
+    /// Respond with each step in turn on successive calls; the last step repeats.
+    ///
+    /// ```ignore
+    /// MockResponse::sequence(vec![
+    ///     MockResponse::output(RUNNING_JSON),
+    ///     MockResponse::output(PASSED_JSON),
+    /// ])
+    /// ```
+    pub fn sequence(steps: Vec<MockResponse>) -> Self {
+        assert!(!steps.is_empty(), "mock sequence needs at least one step");
+        Self {
+            file: None,
+            output: None,
+            stderr: None,
+            exit_code: 0,
+            delay_ms: 0,
+            sequence: steps,
+        }
+    }
+
+    /// Sleep before responding (chainable).
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay_ms = delay.as_millis() as u64;
+        self
+    }
+
     /// Set exit code (chainable).
     pub fn with_exit_code(mut self, code: i32) -> Self {
         self.exit_code = code;
@@ -122,10 +160,20 @@ impl MockResponse {
             obj.insert("stderr".to_string(), json!(e));
         }
         if self.exit_code != 0
-            || (self.file.is_none() && self.output.is_none() && self.stderr.is_none())
+            || (self.file.is_none()
+                && self.output.is_none()
+                && self.stderr.is_none()
+                && self.sequence.is_empty())
         {
             obj.insert("exit_code".to_string(), json!(self.exit_code));
         }
+        if self.delay_ms > 0 {
+            obj.insert("delay_ms".to_string(), json!(self.delay_ms));
+        }
+        if !self.sequence.is_empty() {
+            let steps: Vec<_> = self.sequence.iter().map(MockResponse::to_json).collect();
+            obj.insert("sequence".to_string(), json!(steps));
+        }
         serde_json::Value::Object(obj)
     }
 }
@@ -137,6 +185,7 @@ impl MockConfig {
             name: name.to_string(),
             version: None,
             commands: HashMap::new(),
+            rules: Vec::new(),
         }
     }
 
@@ -152,6 +201,15 @@ impl MockConfig {
         self
     }
 
+    /// Add a handler matched on an argument prefix, checked before `command`s.
+    ///
+    /// `*` matches any single argument, e.g. `&["pr", "view", "*", "--json"]`.
+    pub fn rule(mut self, args: &[&str], response: MockResponse) -> Self {
+        let args = args.iter().map(|arg| arg.to_string()).collect();
+        self.rules.push((args, response));
+        self
+    }
+
     /// Write the config and copy the mock binary to bin_dir.
     pub fn write(self, bin_dir: &Path) {
         let mut config = serde_json::Map::new();
@@ -167,6 +225,19 @@ impl MockConfig {
             .collect();
         config.insert("commands".to_string(), serde_json::Value::Object(commands));
 
+        if !self.rules.is_empty() {
+            let rules: Vec<serde_json::Value> = self
+                .rules
+                .iter()
+                .map(|(args, response)| {
+                    let mut rule = response.to_json();
+                    rule["args"] = json!(args);
+                    rule
+                })
+                .collect();
+            config.insert("rules".to_string(), json!(rules));
+        }
+
         let json = serde_json::to_string_pretty(&serde_json::Value::Object(config)).unwrap();
 
         // Write config file
@@ -203,6 +274,83 @@ pub fn copy_mock_binary(bin_dir: &Path, name: &str) {
     }
 }
 
+// =============================================================================
+// Call recording
+// =============================================================================
+
+/// One recorded invocation of a mock command, read from `calls.jsonl`.
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct MockCall {
+    pub command: String,
+    pub args: Vec<String>,
+    pub cwd: Option<PathBuf>,
+    /// FNV-1a hash of stdin (`None` when stdin was a terminal).
+    pub stdin_hash: Option<String>,
+}
+
+impl MockCall {
+    /// Whether `args` appear in this call as a contiguous run, e.g. `["pr", "list"]`.
+    pub fn has_args(&self, args: &[&str]) -> bool {
+        args.is_empty()
+            || self
+                .args
+                .windows(args.len())
+                .any(|window| window.iter().zip(args).all(|(a, b)| a == b))
+    }
+}
+
+/// All calls recorded by mocks in `bin_dir`, in invocation order.
+pub fn mock_calls(bin_dir: &Path) -> Vec<MockCall> {
+    let Ok(content) = fs::read_to_string(bin_dir.join("calls.jsonl")) else {
+        return Vec::new();
+    };
+    content
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("mock: invalid calls.jsonl line"))
+        .collect()
+}
+
+/// Assert that `command` was called with `args` as a contiguous run of arguments.
+///
+/// ```ignore
+/// assert_mock_called(&bin_dir, "gh", &["pr", "list", "--json"]);
+/// ```
+pub fn assert_mock_called(bin_dir: &Path, command: &str, args: &[&str]) {
+    let calls = mock_calls(bin_dir);
+    assert!(
+        calls
+            .iter()
+            .any(|call| call.command == command && call.has_args(args)),
+        "Expected `{command} {}` to be called; recorded calls:\n{}",
+        args.join(" "),
+        format_calls(&calls)
+    );
+}
+
+/// Assert that `command` was never called with `args`.
+pub fn assert_mock_not_called(bin_dir: &Path, command: &str, args: &[&str]) {
+    let calls = mock_calls(bin_dir);
+    assert!(
+        !calls
+            .iter()
+            .any(|call| call.command == command && call.has_args(args)),
+        "Expected `{command} {}` not to be called; recorded calls:\n{}",
+        args.join(" "),
+        format_calls(&calls)
+    );
+}
+
+fn format_calls(calls: &[MockCall]) -> String {
+    if calls.is_empty() {
+        return "  (none)".to_string();
+    }
+    calls
+        .iter()
+        .map(|call| format!("  {} {}", call.command, call.args.join(" ")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 // =============================================================================
 // High-level mock helpers for common test scenarios
 // =============================================================================
@@ -388,4 +536,56 @@ mod tests {
         #[cfg(windows)]
         assert!(bin_dir.join("test-cmd.exe").exists());
     }
+
+    #[test]
+    fn test_mock_rules_and_sequences() {
+        let temp = TempDir::new().unwrap();
+        let bin_dir = temp.path();
+
+        MockConfig::new("gh")
+            .rule(
+                &["run", "view", "*", "--json"],
+                MockResponse::sequence(vec![
+                    MockResponse::output("running"),
+                    MockResponse::output("passed").with_delay(Duration::from_millis(10)),
+                ]),
+            )
+            .command("run", MockResponse::output("fallback"))
+            .write(bin_dir);
+
+        let run = |args: &[&str]| {
+            let output = std::process::Command::new(bin_dir.join("gh"))
+                .args(args)
+                .env("MOCK_CONFIG_DIR", bin_dir)
+                .output()
+                .unwrap();
+            String::from_utf8(output.stdout).unwrap()
+        };
+
+        let view = ["run", "view", "123", "--json", "status"];
+        assert_eq!(run(&view), "running");
+        assert_eq!(run(&view), "passed");
+        assert_eq!(run(&view), "passed");
+        assert_eq!(run(&["run", "view", "123"]), "fallback");
+
+        assert_eq!(mock_calls(bin_dir).len(), 4);
+        assert_mock_called(bin_dir, "gh", &["view", "123", "--json"]);
+    }
+
+    #[test]
+    fn test_mock_call_has_args() {
+        let call = MockCall {
+            command: "gh".to_string(),
+            args: ["pr", "list", "--head", "feature", "--json", "url"]
+                .map(String::from)
+                .to_vec(),
+            cwd: None,
+            stdin_hash: None,
+        };
+        assert!(call.has_args(&["pr", "list"]));
+        assert!(call.has_args(&["--head", "feature"]));
+        assert!(call.has_args(&[]));
+        assert!(!call.has_args(&["pr", "view"]));
+        assert!(!call.has_args(&["list", "pr"]));
+    }
 }
@@ -38,6 +38,7 @@
 //! git cannot handle. We use `normalize_path()` to strip these prefixes while
 //! preserving the symlink resolution behavior needed on macOS.
 
+pub mod json_snapshots;
 pub mod list_snapshots;
 // Progressive output tests use PTY and are Unix-only for now
 #[cfg(unix)]
@@ -1006,6 +1007,22 @@ impl TestRepo {
         repo
     }
 
+    /// Start a fluent scenario on top of the standard fixture.
+    ///
+    /// ```ignore
+    /// let repo = TestRepo::builder()
+    ///     .branch("feature")
+    ///     .commits(3)
+    ///     .diverge_from_main(2)
+    ///     .conflict_on("file.txt")
+    ///     .build();
+    /// ```
+    ///
+    /// Steps are shared with `wt-perf` via [`wt_perf::Scenario`].
+    pub fn builder() -> TestRepoBuilder {
+        TestRepoBuilder::default()
+    }
+
     /// Create an empty test repository (no commits, no branches).
     ///
     /// Use this for tests that specifically need to test behavior in an
@@ -1814,6 +1831,26 @@ impl TestRepo {
         self.mock_bin_path = Some(mock_bin);
     }
 
+    /// Setup mock `gh` that answers `gh api graphql` with `graphql_json`
+    ///
+    /// `gh pr list` and `gh run list` return empty arrays, so a test can tell
+    /// batched GraphQL results from per-branch ones.
+    pub fn setup_mock_gh_with_graphql_data(&mut self, graphql_json: &str) {
+        use crate::common::mock_commands::{MockConfig, MockResponse};
+
+        self.setup_mock_gh_with_ci_data("[]", "[]");
+        let mock_bin = self.mock_bin_path.clone().unwrap();
+        std::fs::write(mock_bin.join("graphql_data.json"), graphql_json).unwrap();
+
+        MockConfig::new("gh")
+            .version("gh version 2.0.0 (mock)")
+            .command("auth", MockResponse::exit(0))
+            .command("api", MockResponse::file("graphql_data.json"))
+            .command("pr", MockResponse::file("pr_data.json"))
+            .command("run", MockResponse::file("run_data.json"))
+            .write(&mock_bin);
+    }
+
     /// Setup mock `glab` that returns configurable MR/CI data for GitLab
     ///
     /// Use this for testing GitLab CI status parsing code. The mock returns JSON data
@@ -1854,6 +1891,53 @@ impl TestRepo {
         self.mock_bin_path = Some(mock_bin);
     }
 
+    /// Setup mock `curl` answering Gitea API requests for `test-owner/test-repo`
+    ///
+    /// The open PR listing returns `pr_json`; every other request (commit
+    /// statuses) returns `status_json`.
+    pub fn setup_mock_gitea_with_ci_data(&mut self, pr_json: &str, status_json: &str) {
+        use crate::common::mock_commands::{MockConfig, MockResponse};
+
+        let mock_bin = self.temp_dir.path().join("mock-bin");
+        std::fs::create_dir_all(&mock_bin).unwrap();
+        std::fs::write(mock_bin.join("pr_data.json"), pr_json).unwrap();
+        std::fs::write(mock_bin.join("status_data.json"), status_json).unwrap();
+
+        MockConfig::new("curl")
+            .command(
+                "https://gitea.example.com/api/v1/repos/test-owner/test-repo/pulls?state=open&sort=recentupdate&limit=50",
+                MockResponse::file("pr_data.json"),
+            )
+            .command("_default", MockResponse::file("status_data.json"))
+            .write(&mock_bin);
+
+        self.mock_bin_path = Some(mock_bin);
+    }
+
+    /// Setup mock `curl` answering Bitbucket Cloud API requests for
+    /// `test-owner/test-repo`
+    ///
+    /// The open PR listing returns `pr_json`; every other request (build
+    /// statuses) returns `status_json`.
+    pub fn setup_mock_bitbucket_with_ci_data(&mut self, pr_json: &str, status_json: &str) {
+        use crate::common::mock_commands::{MockConfig, MockResponse};
+
+        let mock_bin = self.temp_dir.path().join("mock-bin");
+        std::fs::create_dir_all(&mock_bin).unwrap();
+        std::fs::write(mock_bin.join("pr_data.json"), pr_json).unwrap();
+        std::fs::write(mock_bin.join("status_data.json"), status_json).unwrap();
+
+        MockConfig::new("curl")
+            .command(
+                "https://api.bitbucket.org/2.0/repositories/test-owner/test-repo/pullrequests?state=OPEN&pagelen=50&fields=%2Bvalues.participants",
+                MockResponse::file("pr_data.json"),
+            )
+            .command("_default", MockResponse::file("status_data.json"))
+            .write(&mock_bin);
+
+        self.mock_bin_path = Some(mock_bin);
+    }
+
     /// Configure a command to use mock gh/glab commands
     ///
     /// Must call `setup_mock_gh()` first. Prepends the mock bin directory to PATH
@@ -1889,6 +1973,27 @@ impl TestRepo {
         }
     }
 
+    /// Calls recorded by this repo's mock commands (see `setup_mock_gh`).
+    pub fn mock_calls(&self) -> Vec<mock_commands::MockCall> {
+        self.mock_bin_path
+            .as_deref()
+            .map(mock_commands::mock_calls)
+            .unwrap_or_default()
+    }
+
+    /// Assert a mock command was called with `args` as a contiguous run.
+    ///
+    /// ```ignore
+    /// repo.assert_mock_called("gh", &["pr", "list", "--json"]);
+    /// ```
+    pub fn assert_mock_called(&self, command: &str, args: &[&str]) {
+        let mock_bin = self
+            .mock_bin_path
+            .as_deref()
+            .expect("no mock commands set up for this repo");
+        mock_commands::assert_mock_called(mock_bin, command, args);
+    }
+
     /// Set a marker for a branch.
     ///
     /// Markers are stored as JSON with a timestamp in `worktrunk.state.<branch>.marker`.
@@ -1902,6 +2007,101 @@ impl TestRepo {
     }
 }
 
+/// Fluent builder for [`TestRepo`] scenarios; see [`TestRepo::builder`].
+///
+/// Branch methods apply to the most recent `.branch(name)`.
+#[derive(Default)]
+#[must_use]
+pub struct TestRepoBuilder {
+    scenario: wt_perf::Scenario,
+    without_fixture_worktrees: bool,
+}
+
+impl TestRepoBuilder {
+    /// Remove the fixture's feature-a/b/c worktrees and branches first.
+    pub fn without_fixture_worktrees(mut self) -> Self {
+        self.without_fixture_worktrees = true;
+        self
+    }
+
+    /// Start a new branch forked from main.
+    pub fn branch(mut self, name: &str) -> Self {
+        self.scenario = self.scenario.branch(name);
+        self
+    }
+
+    /// Add `n` commits to the branch.
+    pub fn commits(mut self, n: usize) -> Self {
+        self.scenario = self.scenario.commits(n);
+        self
+    }
+
+    /// Add `n` commits to main after the branch forks.
+    pub fn diverge_from_main(mut self, n: usize) -> Self {
+        self.scenario = self.scenario.diverge_from_main(n);
+        self
+    }
+
+    /// Change `file` differently on the branch and on main.
+    pub fn conflict_on(mut self, file: &str) -> Self {
+        self.scenario = self.scenario.conflict_on(file);
+        self
+    }
+
+    /// Keep a worktree for the branch.
+    pub fn worktree(mut self) -> Self {
+        self.scenario = self.scenario.worktree();
+        self
+    }
+
+    /// Lock the branch's worktree.
+    pub fn locked(mut self, reason: &str) -> Self {
+        self.scenario = self.scenario.locked(reason);
+        self
+    }
+
+    /// Delete the branch's worktree directory so it shows as prunable.
+    pub fn prunable(mut self) -> Self {
+        self.scenario = self.scenario.prunable();
+        self
+    }
+
+    /// Leave the branch's worktree stopped mid-rebase onto main.
+    pub fn rebase_in_progress(mut self) -> Self {
+        self.scenario = self.scenario.rebase_in_progress();
+        self
+    }
+
+    /// Leave the branch's worktree stopped mid-merge of main.
+    pub fn merge_in_progress(mut self) -> Self {
+        self.scenario = self.scenario.merge_in_progress();
+        self
+    }
+
+    /// Create the repo and apply the scenario.
+    ///
+    /// Worktrees are registered under their branch name, so `worktree_path()` works.
+    pub fn build(self) -> TestRepo {
+        let mut repo = TestRepo::new();
+        if self.without_fixture_worktrees {
+            repo.remove_fixture_worktrees();
+        }
+
+        let root = repo.root_path().to_path_buf();
+        let created = self.scenario.apply_with(&root, |dir, args| {
+            repo.git_command()
+                .args(args)
+                .current_dir(dir)
+                .output()
+                .unwrap()
+        });
+        for wt in created {
+            repo.worktrees.insert(wt.branch, wt.path);
+        }
+        repo
+    }
+}
+
 impl TestRepoBase for TestRepo {
     fn git_config_path(&self) -> &Path {
         &self.git_config_path
@@ -26,29 +26,73 @@
 //! - `file`: read and output contents of specified file (relative to config dir)
 //! - `output`: output literal string
 //! - `exit_code`: exit with specified code (default 0)
+//! - `delay_ms`: sleep before responding (for timeout tests)
+//! - `sequence`: list of responses used in call order; the last one repeats
+//!
+//! Argument rules are checked before `commands`, in order:
+//! ```json
+//! {
+//!   "rules": [
+//!     { "args": ["pr", "view", "*", "--json"], "output": "{}" },
+//!     { "args": ["run", "list"], "sequence": [{ "output": "running" }, { "output": "done" }] }
+//!   ]
+//! }
+//! ```
+//! A rule matches when its `args` are a prefix of the actual arguments;
+//! `*` matches any single argument.
+//!
+//! Call recording:
+//! Every invocation appends one JSON line to `calls.jsonl` in the config dir:
+//! `{"command": "gh", "args": [...], "cwd": "...", "stdin_hash": "..."}`.
+//! `stdin_hash` is an FNV-1a hash of stdin, or null when stdin is a terminal.
 
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::env;
-use std::fs;
-use std::io::{self, Write};
-use std::path::PathBuf;
+use std::fs::{self, OpenOptions};
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::{Path, PathBuf};
 use std::process::exit;
+use std::time::Duration;
 
 #[derive(Debug, Deserialize)]
 struct Config {
     version: Option<String>,
     #[serde(default)]
     commands: HashMap<String, CommandResponse>,
+    #[serde(default)]
+    rules: Vec<Rule>,
 }
 
 #[derive(Debug, Deserialize)]
+struct Rule {
+    args: Vec<String>,
+    #[serde(flatten)]
+    response: CommandResponse,
+}
+
+impl Rule {
+    fn matches(&self, args: &[String]) -> bool {
+        self.args.len() <= args.len()
+            && self
+                .args
+                .iter()
+                .zip(args)
+                .all(|(pattern, arg)| pattern == "*" || pattern == arg)
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
 struct CommandResponse {
     file: Option<String>,
     output: Option<String>,
     stderr: Option<String>,
     #[serde(default)]
     exit_code: i32,
+    #[serde(default)]
+    delay_ms: u64,
+    #[serde(default)]
+    sequence: Vec<CommandResponse>,
 }
 
 /// Get command name from argv\[0\].
@@ -65,6 +109,68 @@ fn config_dir() -> PathBuf {
     PathBuf::from(env::var_os("MOCK_CONFIG_DIR").expect("mock: MOCK_CONFIG_DIR not set"))
 }
 
+/// FNV-1a hash of stdin, skipped for terminals so interactive runs don't block.
+fn stdin_hash() -> Option<String> {
+    let mut stdin = io::stdin();
+    if stdin.is_terminal() {
+        return None;
+    }
+    let mut data = Vec::new();
+    stdin.read_to_end(&mut data).ok()?;
+    let hash = data.iter().fold(0xcbf2_9ce4_8422_2325_u64, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    });
+    Some(format!("{hash:016x}"))
+}
+
+/// Append this invocation to `calls.jsonl` so tests can assert how it was driven.
+fn record_call(config_dir: &Path, cmd_name: &str, args: &[String]) {
+    let record = serde_json::json!({
+        "command": cmd_name,
+        "args": args,
+        "cwd": env::current_dir().ok(),
+        "stdin_hash": stdin_hash(),
+    });
+    let path = config_dir.join("calls.jsonl");
+    let result = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| writeln!(file, "{record}"));
+    if let Err(e) = result {
+        eprintln!("mock: failed to record call in {}: {}", path.display(), e);
+        exit(1);
+    }
+}
+
+/// Claim the next call index for `key`.
+///
+/// Each call atomically creates a marker file, so concurrent invocations
+/// (e.g. parallel `gh` calls from `wt list`) each get a distinct index.
+fn next_call_index(config_dir: &Path, key: &str) -> usize {
+    let dir = config_dir.join(".mock-sequence");
+    fs::create_dir_all(&dir).unwrap_or_else(|e| {
+        eprintln!("mock: failed to create {}: {}", dir.display(), e);
+        exit(1);
+    });
+    for n in 0.. {
+        let marker = dir.join(format!("{key}.{n}"));
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&marker)
+        {
+            Ok(_) => return n,
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => {
+                eprintln!("mock: failed to create {}: {}", marker.display(), e);
+                exit(1);
+            }
+        }
+    }
+    unreachable!()
+}
+
 fn main() {
     let cmd_name = command_name();
     let config_dir = config_dir();
@@ -81,6 +187,7 @@ fn main() {
     });
 
     let args: Vec<String> = env::args().skip(1).collect();
+    record_call(&config_dir, &cmd_name, &args);
 
     // Handle --version flag
     if args.first().map(|s| s.as_str()) == Some("--version")
@@ -90,18 +197,38 @@ fn main() {
         exit(0);
     }
 
-    // Match first argument against commands, fall back to _default
+    // Match argument rules, then first argument against commands, fall back to _default
     let default_response = CommandResponse {
-        file: None,
-        output: None,
-        stderr: None,
         exit_code: 1,
+        ..Default::default()
     };
-    let response = args
-        .first()
-        .and_then(|cmd| config.commands.get(cmd))
-        .or_else(|| config.commands.get("_default"))
-        .unwrap_or(&default_response);
+    let (key, response) = config
+        .rules
+        .iter()
+        .enumerate()
+        .find(|(_, rule)| rule.matches(&args))
+        .map(|(i, rule)| (format!("{cmd_name}-rule-{i}"), &rule.response))
+        .or_else(|| {
+            let cmd = args
+                .first()
+                .filter(|cmd| config.commands.contains_key(*cmd))
+                .map_or("_default", |cmd| cmd.as_str());
+            let response = config.commands.get(cmd)?;
+            Some((format!("{cmd_name}-{cmd}"), response))
+        })
+        .unwrap_or_else(|| (String::new(), &default_response));
+
+    let response = match response.sequence.as_slice() {
+        [] => response,
+        steps => {
+            let index = next_call_index(&config_dir, &key);
+            &steps[index.min(steps.len() - 1)]
+        }
+    };
+
+    if response.delay_ms > 0 {
+        std::thread::sleep(Duration::from_millis(response.delay_ms));
+    }
 
     if let Some(file) = &response.file {
         let file_path = config_dir.join(file);
@@ -2,7 +2,10 @@
 //!
 //! This crate provides:
 //! - Benchmark repository setup (used by `benches/list.rs`)
+//! - Fluent git scenarios (diverged branches, conflicts, worktree states), shared
+//!   with the integration tests' `TestRepo::builder()`
 //! - Cache invalidation for cold benchmark runs
+//! - Time-to-milestone measurement from `wt-trace` events
 //! - Trace analysis utilities
 //!
 //! # Library Usage
@@ -31,9 +34,15 @@
 //! RUST_LOG=debug wt list 2>&1 | grep wt-trace | cargo run -p wt-perf -- trace
 //! ```
 
+mod scenario;
+
+pub use scenario::{Scenario, ScenarioWorktree};
+
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Stdio};
 use std::sync::OnceLock;
+use std::time::{Duration, Instant};
 use tempfile::TempDir;
 
 /// Lazy-initialized rust repo path.
@@ -281,6 +290,33 @@ pub fn setup_fake_remote(repo_path: &Path) {
     std::fs::write(refs_dir.join("main"), head_sha.stdout).unwrap();
 }
 
+/// Time from spawning `cmd` until it logs the `[wt-trace]` instant event `event`.
+///
+/// Runs with `RUST_LOG=debug` and scans stderr line by line, so the measurement
+/// covers process startup through the milestone (e.g. `"Skeleton rendered"`).
+/// The process is killed once the event appears. Panics if it exits first.
+pub fn time_to_trace_event(cmd: &mut Command, event: &str) -> Duration {
+    let needle = format!("event=\"{event}\"");
+    let start = Instant::now();
+    let mut child = cmd
+        .env("RUST_LOG", "debug")
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    let stderr = BufReader::new(child.stderr.take().unwrap());
+    let elapsed = stderr
+        .lines()
+        .map_while(Result::ok)
+        .find(|line| line.contains("[wt-trace]") && line.contains(&needle))
+        .map(|_| start.elapsed());
+
+    let _ = child.kill();
+    let _ = child.wait();
+    elapsed.unwrap_or_else(|| panic!("process exited without trace event {event:?}"))
+}
+
 /// Invalidate git caches for cold benchmarks.
 ///
 /// Removes:
@@ -0,0 +1,261 @@
+//! Fluent composition of git scenarios on top of an existing repository.
+//!
+//! Integration tests reach this through `TestRepo::builder()`; benchmarks and
+//! `wt-perf` can apply the same scenario to any repo created by [`create_repo_at`].
+//!
+//! [`create_repo_at`]: crate::create_repo_at
+//!
+//! ```rust,ignore
+//! use wt_perf::Scenario;
+//!
+//! Scenario::new()
+//!     .branch("feature")
+//!     .commits(3)
+//!     .diverge_from_main(2)
+//!     .conflict_on("file.txt")
+//!     .rebase_in_progress()
+//!     .branch("stale")
+//!     .prunable()
+//!     .apply(&repo_path);
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+/// What to leave behind in a branch's worktree after its commits are made.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+enum Interrupted {
+    #[default]
+    None,
+    Rebase,
+    Merge,
+}
+
+/// One branch of a [`Scenario`], configured by the builder methods that follow
+/// its [`Scenario::branch`] call.
+#[derive(Clone, Debug, Default)]
+struct BranchSpec {
+    name: String,
+    commits: usize,
+    diverge_from_main: usize,
+    conflict_on: Option<String>,
+    worktree: bool,
+    locked: Option<String>,
+    prunable: bool,
+    interrupted: Interrupted,
+}
+
+/// A sequence of branches to create, each with its own commits, divergence
+/// from main, conflicts, and worktree state.
+///
+/// Builder methods other than [`branch`](Self::branch) apply to the most
+/// recently added branch and panic if there is none.
+#[derive(Clone, Debug, Default)]
+#[must_use]
+pub struct Scenario {
+    branches: Vec<BranchSpec>,
+}
+
+/// A worktree created by [`Scenario::apply_with`], keyed by branch name.
+#[derive(Clone, Debug)]
+pub struct ScenarioWorktree {
+    pub branch: String,
+    pub path: PathBuf,
+}
+
+impl Scenario {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new branch, forked from the current tip of main.
+    pub fn branch(mut self, name: &str) -> Self {
+        self.branches.push(BranchSpec {
+            name: name.to_string(),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Add `n` commits to the branch, each touching its own file.
+    pub fn commits(mut self, n: usize) -> Self {
+        self.current().commits = n;
+        self
+    }
+
+    /// Add `n` commits to main after the branch forks, so the branch is behind.
+    pub fn diverge_from_main(mut self, n: usize) -> Self {
+        self.current().diverge_from_main = n;
+        self
+    }
+
+    /// Change `file` differently on the branch and on main.
+    ///
+    /// Adds one commit to each side, on top of any from
+    /// [`commits`](Self::commits) and [`diverge_from_main`](Self::diverge_from_main).
+    pub fn conflict_on(mut self, file: &str) -> Self {
+        self.current().conflict_on = Some(file.to_string());
+        self
+    }
+
+    /// Keep a worktree for the branch (otherwise only the branch remains).
+    pub fn worktree(mut self) -> Self {
+        self.current().worktree = true;
+        self
+    }
+
+    /// Lock the branch's worktree. Implies [`worktree`](Self::worktree).
+    pub fn locked(mut self, reason: &str) -> Self {
+        let spec = self.current();
+        spec.worktree = true;
+        spec.locked = Some(reason.to_string());
+        self
+    }
+
+    /// Delete the worktree directory so git reports it as prunable.
+    /// Implies [`worktree`](Self::worktree).
+    pub fn prunable(mut self) -> Self {
+        let spec = self.current();
+        spec.worktree = true;
+        spec.prunable = true;
+        self
+    }
+
+    /// Leave the worktree stopped mid-rebase onto main. Implies
+    /// [`worktree`](Self::worktree); combine with [`conflict_on`](Self::conflict_on)
+    /// so the rebase stops.
+    pub fn rebase_in_progress(mut self) -> Self {
+        let spec = self.current();
+        spec.worktree = true;
+        spec.interrupted = Interrupted::Rebase;
+        self
+    }
+
+    /// Leave the worktree stopped mid-merge of main. Implies
+    /// [`worktree`](Self::worktree); combine with [`conflict_on`](Self::conflict_on)
+    /// so the merge stops.
+    pub fn merge_in_progress(mut self) -> Self {
+        let spec = self.current();
+        spec.worktree = true;
+        spec.interrupted = Interrupted::Merge;
+        self
+    }
+
+    fn current(&mut self) -> &mut BranchSpec {
+        self.branches
+            .last_mut()
+            .expect("call .branch(name) before configuring a branch")
+    }
+
+    /// Apply the scenario to the repo at `repo_path` using plain `git`.
+    pub fn apply(&self, repo_path: &Path) -> Vec<ScenarioWorktree> {
+        self.apply_with(repo_path, |dir, args| {
+            Command::new("git")
+                .args(args)
+                .current_dir(dir)
+                .env("GIT_CONFIG_GLOBAL", "/dev/null")
+                .env("GIT_CONFIG_SYSTEM", "/dev/null")
+                .output()
+                .unwrap()
+        })
+    }
+
+    /// Apply the scenario, running every git command through `git`.
+    ///
+    /// `repo_path` must be the primary worktree with main checked out. Branch
+    /// worktrees are created as siblings named `<repo>.<branch>`, with slashes
+    /// in the branch replaced by dashes.
+    pub fn apply_with(
+        &self,
+        repo_path: &Path,
+        git: impl Fn(&Path, &[&str]) -> Output,
+    ) -> Vec<ScenarioWorktree> {
+        let run = |dir: &Path, args: &[&str]| {
+            let output = git(dir, args);
+            assert!(
+                output.status.success(),
+                "Git command failed: {:?}\nstderr: {}\npath: {}",
+                args,
+                String::from_utf8_lossy(&output.stderr),
+                dir.display()
+            );
+        };
+        let commit_file = |dir: &Path, file: &str, content: &str, message: &str| {
+            std::fs::write(dir.join(file), content).unwrap();
+            run(dir, &["add", file]);
+            run(dir, &["commit", "-m", message]);
+        };
+
+        let repo_name = repo_path.file_name().unwrap().to_str().unwrap();
+        let mut worktrees = Vec::new();
+
+        for spec in &self.branches {
+            let safe_name = spec.name.replace('/', "-");
+            let wt_path = repo_path.with_file_name(format!("{repo_name}.{safe_name}"));
+            let wt_str = wt_path.to_str().unwrap();
+            run(
+                repo_path,
+                &["worktree", "add", "-b", &spec.name, wt_str, "main"],
+            );
+
+            for i in 1..=spec.commits {
+                commit_file(
+                    &wt_path,
+                    &format!("{safe_name}-{i}.txt"),
+                    &format!("{} change {i}\n", spec.name),
+                    &format!("{}: commit {i}", spec.name),
+                );
+            }
+            for i in 1..=spec.diverge_from_main {
+                commit_file(
+                    repo_path,
+                    &format!("main-{safe_name}-{i}.txt"),
+                    &format!("main change {i}\n"),
+                    &format!("main: commit {i} after {}", spec.name),
+                );
+            }
+            if let Some(file) = &spec.conflict_on {
+                commit_file(
+                    &wt_path,
+                    file,
+                    &format!("{} version\n", spec.name),
+                    &format!("{}: change {file}", spec.name),
+                );
+                commit_file(
+                    repo_path,
+                    file,
+                    "main version\n",
+                    &format!("main: change {file}"),
+                );
+            }
+
+            // Expected to stop on conflicts, so the exit status is ignored
+            match spec.interrupted {
+                Interrupted::None => {}
+                Interrupted::Rebase => {
+                    git(&wt_path, &["rebase", "main"]);
+                }
+                Interrupted::Merge => {
+                    git(&wt_path, &["merge", "--no-edit", "main"]);
+                }
+            }
+
+            if !spec.worktree {
+                run(repo_path, &["worktree", "remove", wt_str]);
+                continue;
+            }
+            if let Some(reason) = &spec.locked {
+                run(repo_path, &["worktree", "lock", "--reason", reason, wt_str]);
+            }
+            if spec.prunable {
+                std::fs::remove_dir_all(&wt_path).unwrap();
+            }
+            worktrees.push(ScenarioWorktree {
+                branch: spec.name.clone(),
+                path: wt_path,
+            });
+        }
+
+        worktrees
+    }
+}
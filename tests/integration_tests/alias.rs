@@ -0,0 +1,63 @@
+//! Integration tests for `[alias]` and `wt alias`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_alias_expands_with_extra_args(repo: TestRepo) {
+    repo.write_test_config(
+        r#"
+[alias]
+ls = "list --format json"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["ls", "--branches"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.trim_start().starts_with('['), "stdout: {stdout}");
+}
+
+#[rstest]
+fn test_alias_recursion(repo: TestRepo) {
+    repo.write_test_config(
+        r#"
+[alias]
+a = "b --full"
+b = "a"
+"#,
+    );
+
+    let output = repo.wt_command().arg("a").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("expands to itself"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_alias_list(repo: TestRepo) {
+    repo.write_test_config(
+        r#"
+[alias]
+ls = "list --branches"
+list = "list --full"
+"#,
+    );
+
+    let output = repo.wt_command().args(["alias", "list"]).output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "ls  list --branches"
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("built-in command"), "stderr: {stderr}");
+}
@@ -0,0 +1,68 @@
+//! Tests for the `worktrunk::api` library facade.
+
+use worktrunk::api::{ListOptions, RemoveOptions, Worktrunk};
+use worktrunk::config::WorktrunkConfig;
+
+use crate::common::TestRepo;
+
+#[test]
+fn test_api_switch_list_remove() {
+    let repo = TestRepo::new();
+    let wt = Worktrunk::with_config(repo.root_path(), WorktrunkConfig::default()).unwrap();
+
+    let path = wt.switch("feature", true).unwrap();
+    assert!(path.exists());
+    // Switching again returns the existing worktree
+    assert_eq!(wt.switch("feature", false).unwrap(), path);
+
+    let items = wt.list(ListOptions::default()).unwrap();
+    let feature = items
+        .iter()
+        .find(|item| item.branch.as_deref() == Some("feature"))
+        .unwrap();
+    assert_eq!(feature.path.as_deref(), Some(path.as_path()));
+    assert_eq!(feature.dirty, Some(false));
+    assert!(!feature.is_main);
+    assert!(items.iter().any(|item| item.is_main));
+
+    wt.remove(
+        "feature",
+        RemoveOptions {
+            delete_branch: false,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert!(!path.exists());
+
+    // The branch survives, so it's listed without a worktree
+    let items = wt.list(ListOptions { branches: true }).unwrap();
+    let feature = items
+        .iter()
+        .find(|item| item.branch.as_deref() == Some("feature"))
+        .unwrap();
+    assert_eq!(feature.path, None);
+    assert_eq!(feature.dirty, None);
+}
+
+#[test]
+fn test_api_remove_keeps_unmerged_and_protected_branches() {
+    let repo = TestRepo::new();
+    repo.write_project_config("[safety]\nprotected-branches = [\"release/*\"]\n");
+    let wt = Worktrunk::with_config(repo.root_path(), WorktrunkConfig::default()).unwrap();
+
+    // Unmerged work survives removal, as with `wt remove`
+    let path = wt.switch("feature", true).unwrap();
+    repo.commit_in_worktree(&path, "new.txt", "content", "Unmerged work");
+    wt.remove("feature", RemoveOptions::default()).unwrap();
+    assert!(!path.exists());
+    assert!(!repo.git_output(&["branch", "--list", "feature"]).is_empty());
+
+    // Protected branches aren't deleted, so the worktree stays too
+    let path = wt.switch("release/1.0", true).unwrap();
+    let err = wt
+        .remove("release/1.0", RemoveOptions::default())
+        .unwrap_err();
+    assert!(format!("{err:#}").contains("release/1.0"), "{err:#}");
+    assert!(path.exists());
+}
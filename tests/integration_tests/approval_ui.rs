@@ -591,3 +591,44 @@ fn test_select_fails_in_non_tty(repo: TestRepo) {
         make_snapshot_cmd(&repo, "select", &[], None)
     );
 }
+
+/// `WORKTRUNK_ASSUME_YES` approves like `--yes`, without a TTY and without
+/// saving the approval.
+#[rstest]
+fn test_assume_yes_env_bypasses_tty_check(repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "echo ran > assume-yes.txt""#);
+    repo.commit("Add config");
+
+    let output = repo
+        .wt_command()
+        .args(["hook", "post-create"])
+        .env("WORKTRUNK_ASSUME_YES", "1")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(repo.root_path().join("assume-yes.txt").exists());
+
+    let config = fs::read_to_string(repo.test_config_path()).unwrap_or_default();
+    assert!(!config.contains("assume-yes.txt"));
+}
+
+/// The global `--no` declines approval, even with `--yes` or `WORKTRUNK_ASSUME_YES`.
+#[rstest]
+fn test_no_flag_declines_approval(repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "echo ran > declined.txt""#);
+    repo.commit("Add config");
+
+    let output = repo
+        .wt_command()
+        .args(["--no", "hook", "post-create", "--yes"])
+        .env("WORKTRUNK_ASSUME_YES", "1")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Commands declined"));
+    assert!(!repo.root_path().join("declined.txt").exists());
+}
@@ -1,9 +1,9 @@
-//! Integration tests for add-approvals and clear-approvals commands
+//! Integration tests for the `wt hook approvals` subcommands
 
 use crate::common::{TestRepo, make_snapshot_cmd, repo, setup_snapshot_settings};
 use insta_cmd::assert_cmd_snapshot;
 use rstest::rstest;
-use worktrunk::config::WorktrunkConfig;
+use worktrunk::config::{UserProjectConfig, WorktrunkConfig};
 
 /// Helper to snapshot add-approvals command
 fn snapshot_add_approvals(test_name: &str, repo: &TestRepo, args: &[&str]) {
@@ -223,3 +223,102 @@ worktree-path = "../project.{{ branch }}"
     // Try to add approvals - should show "no commands configured"
     snapshot_add_approvals("add_approvals_no_commands", &repo, &[]);
 }
+
+// ============================================================================
+// list and revoke tests
+// ============================================================================
+
+/// Save approvals for a project other than the test repo, recorded by name
+fn save_named_approvals(repo: &TestRepo) {
+    let mut project = UserProjectConfig::default();
+    project.record_approval("pre-merge:test", "cargo test");
+    project.record_approval("post-create", "npm ci");
+    let mut config = WorktrunkConfig::default();
+    config
+        .projects
+        .insert("github.com/user/other".to_string(), project);
+    config.save_to(repo.test_config_path()).unwrap();
+}
+
+#[rstest]
+fn test_list_approvals(repo: TestRepo) {
+    save_named_approvals(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["hook", "approvals", "list"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("github.com/user/other"), "stderr: {stderr}");
+    assert!(stderr.contains("2 approved commands"), "stderr: {stderr}");
+    assert!(stderr.contains("cargo test"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_revoke_approvals_by_name(repo: TestRepo) {
+    save_named_approvals(&repo);
+
+    let output = repo
+        .wt_command()
+        .args([
+            "hook",
+            "approvals",
+            "revoke",
+            "github.com/user/other",
+            "test",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let saved = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    assert!(!saved.contains("cargo test"), "config: {saved}");
+    assert!(!saved.contains("pre-merge:test"), "config: {saved}");
+    assert!(saved.contains("npm ci"), "config: {saved}");
+}
+
+#[rstest]
+fn test_revoke_approvals_whole_project(repo: TestRepo) {
+    save_named_approvals(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["hook", "approvals", "revoke", "github.com/user/other"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Revoked 2 approvals"), "stderr: {stderr}");
+
+    let saved = std::fs::read_to_string(repo.test_config_path()).unwrap();
+    assert!(!saved.contains("github.com/user/other"), "config: {saved}");
+}
+
+#[rstest]
+fn test_revoke_approvals_unknown_command(repo: TestRepo) {
+    save_named_approvals(&repo);
+
+    let output = repo
+        .wt_command()
+        .args([
+            "hook",
+            "approvals",
+            "revoke",
+            "github.com/user/other",
+            "lint",
+        ])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("No approved command matching"),
+        "stderr: {stderr}"
+    );
+}
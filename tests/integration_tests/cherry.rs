@@ -0,0 +1,88 @@
+//! Integration tests for `wt cherry`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn cherry(repo: &TestRepo, args: &[&str]) -> std::process::Output {
+    repo.wt_command()
+        .arg("cherry")
+        .args(args)
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap()
+}
+
+fn candidates(repo: &TestRepo) -> Vec<String> {
+    let output = cherry(repo, &["feature"]);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(|line| line.split_once('\t').unwrap().1.to_string())
+        .collect()
+}
+
+#[rstest]
+fn test_cherry_lists_and_picks(mut repo: TestRepo) {
+    let feature = repo.add_feature();
+    repo.commit_in_worktree(&feature, "fix.txt", "fix", "Fix parser");
+    assert_eq!(candidates(&repo), ["Add feature file", "Fix parser"]);
+
+    let fix = repo.git_output(&["rev-parse", "--short", "feature"]);
+    let output = cherry(&repo, &["feature", &fix]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Cherry-picked 1 commit"),
+        "stderr: {stderr}"
+    );
+    assert_eq!(repo.git_output(&["log", "-1", "--format=%s"]), "Fix parser");
+
+    // The picked commit is recognized by content and no longer offered
+    assert_eq!(candidates(&repo), ["Add feature file"]);
+
+    let output = cherry(&repo, &["feature", "--all"]);
+    assert!(output.status.success());
+    assert!(candidates(&repo).is_empty());
+}
+
+#[rstest]
+fn test_cherry_conflict(mut repo: TestRepo) {
+    repo.add_feature();
+    repo.commit_in_worktree(repo.root_path(), "feature.txt", "main content", "Diverge");
+
+    let output = cherry(&repo, &["feature", "--all"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("stopped at conflicts"), "stderr: {stderr}");
+    assert!(stderr.contains("feature.txt"), "stderr: {stderr}");
+    assert!(
+        stderr.contains("git cherry-pick --abort"),
+        "stderr: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_cherry_assumed_answers(mut repo: TestRepo) {
+    let feature = repo.add_feature();
+    repo.commit_in_worktree(&feature, "fix.txt", "fix", "Fix parser");
+
+    // `--no` declines without listing or picking anything
+    let output = cherry(&repo, &["feature", "--no"]);
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+    assert_eq!(candidates(&repo).len(), 2);
+
+    // `--yes` picks every candidate
+    let output = cherry(&repo, &["feature", "--yes"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Cherry-picked 2 commits"),
+        "stderr: {stderr}"
+    );
+    assert!(candidates(&repo).is_empty());
+}
@@ -216,6 +216,232 @@ fn test_list_full_with_no_ci_checks(mut repo: TestRepo) {
     run_ci_status_test(&mut repo, "no_ci_checks", &pr_json, "[]");
 }
 
+#[rstest]
+fn test_list_full_queries_gh_by_head_branch(mut repo: TestRepo) {
+    setup_github_repo_with_feature(&mut repo);
+    repo.setup_mock_gh_with_ci_data("[]", "[]");
+
+    let mut cmd = make_snapshot_cmd(&repo, "list", &["--full"], None);
+    repo.configure_mock_commands(&mut cmd);
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    // `gh pr list --head` rather than `gh pr view`, which misreads numeric branch names
+    repo.assert_mock_called("gh", &["pr", "list", "--head", "feature"]);
+    let pr_call = repo
+        .mock_calls()
+        .into_iter()
+        .find(|call| call.command == "gh" && call.has_args(&["--head", "feature"]))
+        .unwrap();
+    assert!(pr_call.has_args(&["--state", "open"]));
+    assert_eq!(pr_call.cwd.as_deref(), Some(repo.root_path()));
+    assert!(
+        !repo
+            .mock_calls()
+            .iter()
+            .any(|call| call.command == "gh" && call.has_args(&["pr", "view"]))
+    );
+}
+
+/// With several branches to check, `wt list` asks for all their PRs in one
+/// `gh api graphql` call instead of one `gh pr list` per branch.
+#[rstest]
+fn test_list_full_batches_pr_queries_with_graphql(mut repo: TestRepo) {
+    let head_sha = setup_github_repo_with_feature(&mut repo);
+    repo.add_worktree("feature2");
+
+    // Aliases are numbered in query order; answer every one with the same PR
+    let pr = format!(
+        r#"{{"nodes": [{{
+            "headRefOid": "{head_sha}",
+            "mergeStateStatus": "CLEAN",
+            "url": "https://github.com/test-owner/test-repo/pull/1",
+            "isDraft": false,
+            "reviewDecision": null,
+            "headRepositoryOwner": {{"login": "test-owner"}},
+            "commits": {{"nodes": [{{"commit": {{"statusCheckRollup": {{"contexts": {{"nodes": [
+                {{"status": "COMPLETED", "conclusion": "SUCCESS"}}
+            ]}}}}}}}}]}}
+        }}]}}"#
+    );
+    let fields: Vec<String> = (0..3).map(|i| format!(r#""pr{i}": {pr}"#)).collect();
+    let graphql_json = format!(r#"{{"data": {{"repository": {{{}}}}}}}"#, fields.join(", "));
+    repo.setup_mock_gh_with_graphql_data(&graphql_json);
+
+    let mut cmd = make_snapshot_cmd(&repo, "list", &["--full", "--format=json"], None);
+    repo.configure_mock_commands(&mut cmd);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "wt list failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .unwrap();
+    assert_eq!(feature["ci"]["status"].as_str(), Some("passed"));
+
+    repo.assert_mock_called("gh", &["api", "graphql"]);
+    assert!(
+        !repo
+            .mock_calls()
+            .iter()
+            .any(|call| call.command == "gh" && call.has_args(&["pr", "list"]))
+    );
+}
+
+/// Gitea and Forgejo remotes get PR status from the REST API through `curl`.
+#[rstest]
+fn test_list_full_with_gitea_pr(mut repo: TestRepo) {
+    repo.run_git(&[
+        "remote",
+        "set-url",
+        "origin",
+        "https://gitea.example.com/test-owner/test-repo.git",
+    ]);
+    repo.add_worktree("feature");
+    let head_sha = get_branch_sha(&repo, "feature");
+
+    let pr_json = format!(
+        r#"[{{
+            "title": "Add feature",
+            "html_url": "https://gitea.example.com/test-owner/test-repo/pulls/1",
+            "mergeable": true,
+            "head": {{"ref": "feature", "sha": "{head_sha}", "repo": {{"owner": {{"login": "test-owner"}}}}}}
+        }}]"#
+    );
+    repo.setup_mock_gitea_with_ci_data(&pr_json, r#"{"state": "failure", "total_count": 2}"#);
+
+    let mut cmd = make_snapshot_cmd(&repo, "list", &["--full", "--format=json"], None);
+    repo.configure_mock_commands(&mut cmd);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "wt list failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .unwrap();
+    assert_eq!(feature["ci"]["status"].as_str(), Some("failed"));
+    assert_eq!(feature["ci"]["source"].as_str(), Some("pr"));
+    assert_eq!(
+        feature["ci"]["url"].as_str(),
+        Some("https://gitea.example.com/test-owner/test-repo/pulls/1")
+    );
+    repo.assert_mock_called(
+        "curl",
+        &[&format!(
+            "https://gitea.example.com/api/v1/repos/test-owner/test-repo/commits/{head_sha}/status"
+        )],
+    );
+}
+
+/// Bitbucket Cloud remotes get PR status from the REST API through `curl`.
+#[rstest]
+fn test_list_full_with_bitbucket_pr(mut repo: TestRepo) {
+    repo.run_git(&[
+        "remote",
+        "set-url",
+        "origin",
+        "git@bitbucket.org:test-owner/test-repo.git",
+    ]);
+    repo.add_worktree("feature");
+    let head_sha = get_branch_sha(&repo, "feature");
+
+    // Bitbucket abbreviates commit hashes in PR listings
+    let pr_json = format!(
+        r#"{{"values": [{{
+            "id": 7,
+            "draft": false,
+            "links": {{"html": {{"href": "https://bitbucket.org/test-owner/test-repo/pull-requests/7"}}}},
+            "source": {{
+                "branch": {{"name": "feature"}},
+                "commit": {{"hash": "{}"}},
+                "repository": {{"full_name": "test-owner/test-repo"}}
+            }},
+            "participants": [{{"role": "REVIEWER", "approved": true, "state": "approved"}}]
+        }}]}}"#,
+        &head_sha[..12]
+    );
+    repo.setup_mock_bitbucket_with_ci_data(
+        &pr_json,
+        r#"{"values": [{"state": "SUCCESSFUL"}, {"state": "INPROGRESS"}]}"#,
+    );
+
+    let mut cmd = make_snapshot_cmd(&repo, "list", &["--full", "--format=json"], None);
+    repo.configure_mock_commands(&mut cmd);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "wt list failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .unwrap();
+    assert_eq!(feature["ci"]["status"].as_str(), Some("running"));
+    assert_eq!(feature["ci"]["source"].as_str(), Some("pr"));
+    assert_eq!(feature["ci"]["stale"].as_bool(), Some(false));
+    assert_eq!(
+        feature["ci"]["url"].as_str(),
+        Some("https://bitbucket.org/test-owner/test-repo/pull-requests/7")
+    );
+    repo.assert_mock_called(
+        "curl",
+        &["https://api.bitbucket.org/2.0/repositories/test-owner/test-repo/pullrequests/7/statuses?pagelen=100"],
+    );
+}
+
+/// `WORKTRUNK_TEST_FAULT` simulates network failures in the CI fetcher.
+/// Timeouts and rate limits surface as `error`; malformed JSON is treated as no CI.
+#[rstest]
+#[case::timeout("ci-timeout", Some("error"))]
+#[case::rate_limit("ci-rate-limit", Some("error"))]
+#[case::malformed_json("ci-malformed-json", None)]
+fn test_list_full_with_ci_fault(
+    mut repo: TestRepo,
+    #[case] fault: &str,
+    #[case] expected_status: Option<&str>,
+) {
+    setup_github_repo_with_feature(&mut repo);
+    repo.setup_mock_gh_with_ci_data("[]", "[]");
+
+    let mut cmd = make_snapshot_cmd(&repo, "list", &["--full", "--format=json"], None);
+    repo.configure_mock_commands(&mut cmd);
+    cmd.env("WORKTRUNK_TEST_FAULT", fault);
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "wt list failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let items: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = items
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .unwrap();
+    assert_eq!(feature["ci"]["status"].as_str(), expected_status);
+}
+
 #[rstest]
 fn test_list_full_filters_by_repo_owner(mut repo: TestRepo) {
     // Use different org name
@@ -0,0 +1,71 @@
+//! Integration tests for `wt clean`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn clean(repo: &TestRepo, args: &[&str]) -> String {
+    let output = repo.wt_command().arg("clean").args(args).output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[rstest]
+fn test_clean_previews_then_deletes(mut repo: TestRepo) {
+    std::fs::write(repo.root_path().join(".gitignore"), "target/\n").unwrap();
+    repo.run_git(&["add", ".gitignore"]);
+    repo.run_git(&["commit", "-m", "Ignore target"]);
+    let feature = repo.add_worktree("feature");
+    std::fs::create_dir(feature.join("target")).unwrap();
+    std::fs::write(feature.join("target/out.bin"), vec![0u8; 64 * 1024]).unwrap();
+    std::fs::write(feature.join("notes.txt"), "draft\n").unwrap();
+
+    let preview = clean(&repo, &[]);
+    assert!(preview.contains("target/"), "{preview}");
+    assert!(!preview.contains("notes.txt"), "{preview}");
+    assert!(clean(&repo, &["--untracked"]).contains("notes.txt"));
+    assert!(preview.contains("--force"), "{preview}");
+    assert!(feature.join("target").exists());
+
+    // Untracked files that aren't ignored stay without `--untracked`
+    clean(&repo, &["feature", "--force"]);
+    assert!(!feature.join("target").exists());
+    assert!(feature.join("notes.txt").exists());
+
+    clean(&repo, &["feature", "--untracked", "--force"]);
+    assert!(!feature.join("notes.txt").exists());
+    assert!(clean(&repo, &["feature"]).contains("Nothing to clean"));
+}
+
+#[rstest]
+fn test_clean_branch_without_worktree(repo: TestRepo) {
+    repo.run_git(&["branch", "orphan"]);
+    let output = repo
+        .wt_command()
+        .args(["clean", "orphan"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No worktree found"), "{stderr}");
+}
+
+#[rstest]
+fn test_clean_all_worktrees_asks_first(mut repo: TestRepo) {
+    std::fs::write(repo.root_path().join(".gitignore"), "target/\n").unwrap();
+    repo.run_git(&["add", ".gitignore"]);
+    repo.run_git(&["commit", "-m", "Ignore target"]);
+    let feature = repo.add_worktree("feature");
+    std::fs::create_dir(feature.join("target")).unwrap();
+
+    // No terminal and no `--yes`: nothing is deleted
+    let stderr = clean(&repo, &["--force"]);
+    assert!(stderr.contains("--yes"), "{stderr}");
+    assert!(feature.join("target").exists());
+
+    clean(&repo, &["--force", "--yes"]);
+    assert!(!feature.join("target").exists());
+}
@@ -0,0 +1,104 @@
+//! Integration tests for `wt clone`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_clone_default_template(repo: TestRepo) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let target = temp_dir.path().join("project");
+
+    let output = repo
+        .wt_command()
+        .args(["clone", repo.root_path().to_str().unwrap()])
+        .arg(&target)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+
+    // Default template puts worktrees beside the clone, so no nesting
+    assert!(target.join(".git").exists());
+    assert!(stderr.contains("Cloned into"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_clone_nested_template(repo: TestRepo) {
+    repo.write_test_config(r#"worktree-path = "../{{ branch | sanitize }}""#);
+    let temp_dir = tempfile::tempdir().unwrap();
+    let target = temp_dir.path().join("project");
+
+    let output = repo
+        .wt_command()
+        .args(["clone", repo.root_path().to_str().unwrap()])
+        .arg(&target)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+
+    // Main worktree lives in a subdirectory named for the default branch
+    assert!(target.join("main").join(".git").exists());
+}
+
+#[rstest]
+fn test_clone_into_non_empty_directory(repo: TestRepo) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    std::fs::write(temp_dir.path().join("existing.txt"), "").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["clone", repo.root_path().to_str().unwrap()])
+        .arg(temp_dir.path())
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not empty"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_clone_nested_template_uses_default_branch(repo: TestRepo) {
+    repo.run_git(&["branch", "-m", "main", "trunk"]);
+    repo.write_test_config(r#"worktree-path = "../{{branch}}""#);
+    let temp_dir = tempfile::tempdir().unwrap();
+    let target = temp_dir.path().join("project");
+
+    let output = repo
+        .wt_command()
+        .args(["clone", repo.root_path().to_str().unwrap()])
+        .arg(&target)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+
+    // Named after the remote's default branch, and the staging directory is gone
+    assert!(target.join("trunk").join(".git").exists());
+    assert!(!target.join(".wt-clone").exists());
+}
+
+#[rstest]
+fn test_clone_switch_creates_worktree(repo: TestRepo) {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let target = temp_dir.path().join("project");
+
+    let output = repo
+        .wt_command()
+        .args(["clone", repo.root_path().to_str().unwrap()])
+        .arg(&target)
+        .args(["--switch", "feature"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+
+    let worktree = temp_dir.path().join("project.feature");
+    assert!(worktree.join(".git").exists(), "stderr: {stderr}");
+    let branch = std::process::Command::new("git")
+        .args(["branch", "--show-current"])
+        .current_dir(&worktree)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&branch.stdout).trim(), "feature");
+}
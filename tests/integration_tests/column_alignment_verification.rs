@@ -372,6 +372,42 @@ fn test_alignment_with_unicode_content(mut repo: TestRepo) {
     }
 }
 
+#[rstest]
+fn test_alignment_with_wide_and_rtl_branch_names(mut repo: TestRepo) {
+    // CJK and emoji take two columns per character; Hebrew reads right to left;
+    // a bidi override would reorder the rest of the line if printed as is
+    repo.add_worktree("機能/ログイン");
+    repo.add_worktree("fix-🐛-crash");
+    repo.add_worktree("תיקון-באג");
+    repo.add_worktree("rtl\u{202E}override");
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    cmd.arg("list")
+        .current_dir(repo.root_path())
+        .env("COLUMNS", "150");
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    println!("=== RAW OUTPUT WITH WIDE AND RTL NAMES ===");
+    println!("{}", stdout);
+    println!("===========================================");
+
+    assert!(stdout.contains("機能/ログイン"));
+    assert!(stdout.contains("תיקון-באג"));
+    assert!(
+        !stdout.contains('\u{202E}'),
+        "bidi override reached the terminal"
+    );
+    assert!(stdout.contains("rtl\u{FFFD}override"));
+
+    match verify_table_alignment(&stdout) {
+        Ok(()) => println!("\n✓ Wide and RTL alignment verification passed"),
+        Err(e) => panic!("\n{}", e),
+    }
+}
+
 #[rstest]
 fn test_alignment_with_sparse_columns(mut repo: TestRepo) {
     // Create mix of worktrees - some with diffs, some without
@@ -379,6 +379,51 @@ fn test_complete_excludes_remote_branches(repo: TestRepo) {
     );
 }
 
+#[rstest]
+fn test_complete_remote_only_branches_from_primary_remote(repo: TestRepo) {
+    repo.commit("initial");
+
+    // Two remotes: `shared` exists on both, `fork-only` only on the second
+    let parent = repo.root_path().parent().unwrap();
+    for (name, dir) in [("origin", "origin-remote.git"), ("fork", "fork-remote.git")] {
+        let dir = parent.join(dir);
+        repo.run_git(&["init", "--bare", dir.to_str().unwrap()]);
+        if name == "origin" {
+            repo.run_git(&["remote", "set-url", "origin", dir.to_str().unwrap()]);
+        } else {
+            repo.run_git(&["remote", "add", name, dir.to_str().unwrap()]);
+        }
+        repo.run_git(&["push", name, "main:shared"]);
+    }
+    repo.run_git(&["push", "fork", "main:fork-only"]);
+    repo.run_git(&["fetch", "--all"]);
+
+    let output = repo.completion_cmd(&["wt", "switch", ""]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let values = value_suggestions(&stdout);
+    assert_eq!(
+        values.iter().filter(|v| **v == "shared").count(),
+        1,
+        "{stdout}"
+    );
+    // `wt switch fork-only` wouldn't find it, so it isn't offered
+    assert!(!values.contains(&"fork-only"), "{stdout}");
+    assert!(!stdout.contains("fork/"), "{stdout}");
+
+    // The limit applies after prefix filtering
+    repo.write_test_config("[switch]\ncompletion-limit = 1\n");
+    let output = repo.completion_cmd(&["wt", "switch", ""]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(value_suggestions(&stdout).len(), 1, "{stdout}");
+    let output = repo
+        .completion_cmd(&["wt", "switch", "sh"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(value_suggestions(&stdout), ["shared"]);
+}
+
 #[rstest]
 fn test_complete_merge_shows_branches(mut repo: TestRepo) {
     repo.commit("initial");
@@ -637,6 +682,69 @@ fn test_complete_hook_subcommands(repo: TestRepo) {
     assert!(!subcommands.contains(&"pre-merge"));
 }
 
+#[rstest]
+fn test_complete_hook_command_names(repo: TestRepo) {
+    repo.commit("initial");
+    repo.write_project_config(
+        r#"[post-switch]
+rename-tab = "echo switched"
+
+[pre-merge]
+test = "cargo test"
+"#,
+    );
+
+    let output = repo
+        .completion_cmd(&["wt", "hook", "post-switch", ""])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let names = value_suggestions(&stdout);
+    assert_eq!(names, vec!["rename-tab"]);
+
+    let output = repo
+        .completion_cmd(&["wt", "hook", "pre-merge", "t"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(value_suggestions(&stdout), vec!["test"]);
+}
+
+#[rstest]
+fn test_complete_hint_names(repo: TestRepo) {
+    repo.commit("initial");
+    repo.run_git(&["config", "worktrunk.hints.worktree-path", "true"]);
+    repo.run_git(&["config", "worktrunk.hints.setup", "true"]);
+
+    let output = repo
+        .completion_cmd(&["wt", "config", "state", "hints", "clear", "wo"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(value_suggestions(&stdout), vec!["worktree-path"]);
+}
+
+#[rstest]
+fn test_complete_marker_values(repo: TestRepo) {
+    repo.commit("initial");
+    repo.create_branch("feature");
+    repo.create_branch("bugfix");
+    repo.set_marker("feature", "wip");
+    repo.set_marker("bugfix", "wip");
+    repo.set_marker("main", "ready");
+
+    let output = repo
+        .completion_cmd(&["wt", "config", "state", "marker", "set", ""])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    // Distinct and sorted
+    assert_eq!(value_suggestions(&stdout), vec!["ready", "wip"]);
+}
+
 #[rstest]
 fn test_complete_init_shell_all_variations(repo: TestRepo) {
     repo.commit("initial");
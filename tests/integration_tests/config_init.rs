@@ -119,3 +119,40 @@ run = "echo hello"
         ");
     });
 }
+
+#[rstest]
+fn test_config_migrate_project_rewrites_with_backup(repo: TestRepo) {
+    let config_dir = repo.root_path().join(".config");
+    fs::create_dir_all(&config_dir).unwrap();
+    let original = r#"# Install dependencies
+post-create = "ln -sf {{ repo_root }}/node_modules node_modules"
+"#;
+    fs::write(config_dir.join("wt.toml"), original).unwrap();
+
+    let output = make_snapshot_cmd(&repo, "config", &["migrate", "--project", "--yes"], None)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "wt config migrate failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let migrated = fs::read_to_string(config_dir.join("wt.toml")).unwrap();
+    assert_eq!(
+        migrated,
+        r#"# Install dependencies
+post-create = "ln -sf {{ repo_path }}/node_modules node_modules"
+config-version = 1
+"#
+    );
+    let backup = fs::read_to_string(config_dir.join("wt.toml.bak")).unwrap();
+    assert_eq!(backup, original);
+
+    // A second run has nothing to do
+    let output = make_snapshot_cmd(&repo, "config", &["migrate", "--project", "--yes"], None)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("up to date"));
+}
@@ -489,6 +489,43 @@ args = ["-m", "test-model"]
     });
 }
 
+#[rstest]
+fn test_config_show_full_llm_disabled(mut repo: TestRepo, temp_home: TempDir) {
+    repo.setup_mock_ci_tools_unauthenticated();
+
+    // The command would fail if run; with LLM features off it never is
+    let global_config_dir = temp_home.path().join(".config").join("worktrunk");
+    fs::create_dir_all(&global_config_dir).unwrap();
+    let config_path = global_config_dir.join("config.toml");
+    fs::write(
+        &config_path,
+        r#"[commit-generation]
+command = "nonexistent-llm-command-12345"
+
+[llm]
+enabled = false
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    repo.configure_mock_commands(&mut cmd);
+    cmd.env("WORKTRUNK_CONFIG_PATH", &config_path);
+    cmd.args(["config", "show", "--full"])
+        .current_dir(repo.root_path());
+    set_temp_home_env(&mut cmd, temp_home.path());
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Commit generation disabled"),
+        "Expected LLM status, got:\n{stderr}"
+    );
+    assert!(!stderr.contains("Commit generation failed"), "{stderr}");
+}
+
 #[rstest]
 fn test_config_show_github_remote(mut repo: TestRepo, temp_home: TempDir) {
     // Setup mock gh/glab for deterministic BINARIES output
@@ -1144,3 +1181,37 @@ fn test_config_show_claude_available_plugin_not_installed(mut repo: TestRepo, te
         assert_cmd_snapshot!(cmd);
     });
 }
+
+#[rstest]
+fn test_config_diff_shows_sources(repo: TestRepo) {
+    repo.write_test_config(
+        r#"worktree-path = ".worktrees/{{ branch | sanitize }}"
+
+[list]
+full = true
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["config", "diff"])
+        .env("WORKTRUNK_COMMIT_GENERATION__COMMAND", "llm")
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "wt config diff failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let lines: Vec<_> = stdout.lines().collect();
+    assert_eq!(
+        lines,
+        [
+            r#"worktree-path = ".worktrees/{{ branch | sanitize }}"  # user config, via WORKTRUNK_CONFIG_PATH; default "../{{ repo }}.{{ branch | sanitize }}""#,
+            r#"commit-generation.command = "llm"  # env WORKTRUNK_COMMIT_GENERATION__COMMAND"#,
+            "list.full = true  # user config, via WORKTRUNK_CONFIG_PATH",
+        ]
+    );
+}
@@ -1530,6 +1530,84 @@ fn test_uninstall_shell_dry_run_multiple(repo: TestRepo, temp_home: TempDir) {
 }
 
 // PTY-based tests for interactive install preview
+#[rstest]
+fn test_completions_install_fish(repo: TestRepo, temp_home: TempDir) {
+    let settings = setup_home_snapshot_settings(&temp_home);
+    settings.bind(|| {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.args(["config", "shell", "completions", "fish", "--install"])
+            .current_dir(repo.root_path());
+
+        assert_cmd_snapshot!(cmd, @"
+        success: true
+        exit_code: 0
+        ----- stdout -----
+
+        ----- stderr -----
+        [32m✓[39m [32mWrote completions for [1mfish[22m @ [1m~/.config/fish/completions/wt.fish[22m[39m
+        ");
+    });
+
+    let content =
+        fs::read_to_string(temp_home.path().join(".config/fish/completions/wt.fish")).unwrap();
+    assert!(content.contains("COMPLETE=fish"));
+}
+
+#[rstest]
+fn test_completions_stdout_zsh(repo: TestRepo, temp_home: TempDir) {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    set_temp_home_env(&mut cmd, temp_home.path());
+    cmd.args(["config", "shell", "completions", "zsh"])
+        .current_dir(repo.root_path());
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("#compdef wt\n"), "{stdout}");
+    assert!(stdout.contains("COMPLETE=zsh"));
+    // Printing doesn't write anything
+    assert!(!temp_home.path().join(".zfunc").exists());
+}
+
+#[rstest]
+fn test_completions_powershell_unsupported(repo: TestRepo, temp_home: TempDir) {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    set_temp_home_env(&mut cmd, temp_home.path());
+    cmd.args(["config", "shell", "completions", "powershell"])
+        .current_dir(repo.root_path());
+
+    let output = cmd.output().unwrap();
+    assert!(!output.status.success());
+}
+
+#[rstest]
+fn test_uninstall_removes_installed_zsh_completions(repo: TestRepo, temp_home: TempDir) {
+    let zshrc_path = temp_home.path().join(".zshrc");
+    fs::write(&zshrc_path, "# Existing config\n").unwrap();
+
+    for args in [
+        &["config", "shell", "completions", "zsh", "--install"][..],
+        &["config", "shell", "uninstall", "zsh", "--yes"][..],
+    ] {
+        let mut cmd = wt_command();
+        repo.configure_wt_cmd(&mut cmd);
+        set_temp_home_env(&mut cmd, temp_home.path());
+        cmd.env("SHELL", "/bin/zsh");
+        cmd.args(args).current_dir(repo.root_path());
+        let output = cmd.output().unwrap();
+        assert!(output.status.success(), "{args:?} should succeed");
+        if args.contains(&"--install") {
+            assert!(temp_home.path().join(".zfunc/_wt").exists());
+        }
+    }
+
+    assert!(!temp_home.path().join(".zfunc/_wt").exists());
+}
+
 #[cfg(all(unix, feature = "shell-integration-tests"))]
 mod pty_tests {
     use crate::common::pty::exec_cmd_in_pty;
@@ -311,3 +311,32 @@ fn test_resolve_caret_fails_when_default_branch_unavailable(repo: TestRepo) {
         err_msg
     );
 }
+
+#[rstest]
+fn test_default_branch_prefers_upstream_remote(#[from(repo_with_remote)] mut repo: TestRepo) {
+    // The original repository uses `trunk`; the fork (origin) still has `main`
+    repo.run_git(&["branch", "trunk"]);
+    repo.setup_custom_remote("upstream", "trunk");
+    repo.write_project_config("[repo]\nupstream-remote = \"upstream\"\n");
+    let _ = repo
+        .git_command()
+        .args(["config", "--unset", "worktrunk.default-branch"])
+        .output();
+
+    let git_repo = Repository::at(repo.root_path()).unwrap();
+    assert_eq!(git_repo.upstream_remote().as_deref(), Some("upstream"));
+    assert_eq!(git_repo.default_branch().as_deref(), Some("trunk"));
+    assert_eq!(
+        git_repo.ahead_behind_base().as_deref(),
+        Some("upstream/trunk")
+    );
+}
+
+#[rstest]
+fn test_upstream_remote_ignored_when_missing(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.write_project_config("[repo]\nupstream-remote = \"upstream\"\n");
+
+    let git_repo = Repository::at(repo.root_path()).unwrap();
+    assert_eq!(git_repo.upstream_remote(), None);
+    assert_eq!(git_repo.ahead_behind_base().as_deref(), Some("main"));
+}
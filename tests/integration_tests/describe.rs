@@ -0,0 +1,131 @@
+//! Integration tests for `wt describe`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_describe_set_show_clear(repo: TestRepo) {
+    repo.create_branch("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["describe", "feature", "OAuth login"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        repo.git_output(&["config", "branch.feature.description"]),
+        "OAuth login"
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["describe", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout).trim(),
+        "OAuth login"
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["describe", "feature", "--clear"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let output = repo
+        .wt_command()
+        .args(["describe", "feature"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No description"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_describe_clear_reports_config_failure(repo: TestRepo) {
+    repo.create_branch("feature");
+    // A held config lock makes `git config --unset` fail with something other
+    // than "key not set"
+    std::fs::write(repo.root_path().join(".git/config.lock"), "").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["describe", "feature", "--clear"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("could not lock config file"),
+        "stderr: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_describe_unknown_branch(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["describe", "nonexistent", "text"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not found"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_describe_shown_in_list_full_json(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.run_git(&["config", "branch.feature.description", "Spike: new parser"]);
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--full"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = json.iter().find(|w| w["branch"] == "feature").unwrap();
+    assert_eq!(feature["description"], "Spike: new parser");
+
+    // Without --full, descriptions aren't read
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = json.iter().find(|w| w["branch"] == "feature").unwrap();
+    assert!(feature.get("description").is_none());
+}
+
+#[rstest]
+fn test_describe_multiline_shows_first_line_in_list(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.run_git(&[
+        "config",
+        "branch.feature.description",
+        "Spike: new parser\n\nKeeps the old one behind a flag.",
+    ]);
+
+    let output = repo.wt_command().args(["list", "--full"]).output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Spike: new parser"), "stdout: {stdout}");
+    assert!(!stdout.contains("behind a flag"), "stdout: {stdout}");
+    assert!(!stdout.contains('\u{FFFD}'), "stdout: {stdout}");
+
+    // JSON keeps the full description
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--full"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = json.iter().find(|w| w["branch"] == "feature").unwrap();
+    assert_eq!(
+        feature["description"],
+        "Spike: new parser\n\nKeeps the old one behind a flag."
+    );
+}
@@ -14,6 +14,7 @@
 //! - `test_diagnostic_saved_message_with_vv`: Output shows "Diagnostic saved" with -vv
 //! - `test_diagnostic_written_to_correct_location`: File in .git/wt-logs/
 //! - `test_diagnostic_gh_hint_with_vv`: Hint shows gist and issue URL when gh installed
+//! - `test_verbose_json_log_format`: `WORKTRUNK_LOG_FORMAT=json` emits JSON lines
 
 use std::fs;
 use std::path::PathBuf;
@@ -328,6 +329,47 @@ fn test_verbose_log_file_created(mut repo: TestRepo) {
     );
 }
 
+/// `WORKTRUNK_LOG_FORMAT=json` emits one JSON object per log line, with
+/// command lines and traces parsed into fields; the verbose log stays text.
+#[rstest]
+fn test_verbose_json_log_format(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["list", "-v"])
+        .env("WORKTRUNK_LOG_FORMAT", "json")
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let records: Vec<serde_json::Value> = stderr
+        .lines()
+        .filter(|line| line.starts_with('{'))
+        .map(|line| serde_json::from_str(line).expect("log line should be valid JSON"))
+        .collect();
+    assert!(
+        records
+            .iter()
+            .any(|r| r["event"] == "command" && r["command"].as_str().unwrap().starts_with("git ")),
+        "expected a git command record, got: {stderr}"
+    );
+    assert!(
+        records
+            .iter()
+            .any(|r| r["event"] == "command_finished" && r["duration_us"].is_u64()),
+        "expected a finished command record, got: {stderr}"
+    );
+
+    let verbose_log = fs::read_to_string(
+        repo.root_path()
+            .join(".git")
+            .join("wt-logs")
+            .join("verbose.log"),
+    )
+    .unwrap();
+    assert!(verbose_log.contains("[wt-trace]"));
+}
+
 // =============================================================================
 // Tests for -vv verbosity level (always write diagnostic)
 // =============================================================================
@@ -37,6 +37,21 @@ fn test_switch_directive_file(#[from(repo_with_remote)] mut repo: TestRepo) {
             "Directive file should contain cd command, got: {}",
             directives
         );
+        assert!(
+            directives.starts_with("# worktrunk directives v2\n"),
+            "Directive file should start with the version header, got: {}",
+            directives
+        );
+        assert!(
+            directives.contains("export WT_BRANCH='feature'\n"),
+            "Directive file should export WT_BRANCH, got: {}",
+            directives
+        );
+        assert!(
+            directives.contains("export WT_PORT='1"),
+            "Directive file should export WT_PORT, got: {}",
+            directives
+        );
     });
 }
 
@@ -93,6 +108,44 @@ fn test_remove_directive_file(#[from(repo_with_remote)] mut repo: TestRepo) {
     });
 }
 
+/// The `--execute` announcement is printed by the shell, right before the command.
+#[rstest]
+fn test_switch_execute_message_directive(repo: TestRepo) {
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd
+        .args([
+            "switch",
+            "--create",
+            "exec-message",
+            "--execute",
+            "echo ran",
+        ])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        !stderr.contains("Executing (--execute)"),
+        "stderr: {stderr}"
+    );
+
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    let message = directives
+        .find("printf '%s\\n' '")
+        .expect("message directive");
+    let command = directives.find("\necho ran").expect("command directive");
+    assert!(message < command, "{directives}");
+    assert!(
+        directives[message..command].contains("Executing (--execute):"),
+        "{directives}"
+    );
+}
+
 // ============================================================================
 // Non-Directive Mode Tests (no WORKTRUNK_DIRECTIVE_FILE)
 // ============================================================================
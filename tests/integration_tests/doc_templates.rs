@@ -58,7 +58,7 @@ fn test_doc_basic_variables(repo: TestRepo) {
 
 // =============================================================================
 // Sanitize Filter (docs/content/hook.md: Filters table)
-// "Replace `/` and `\` with `-`"
+// "Replace `/`, `\` and characters invalid in filenames with `-`"
 // =============================================================================
 
 #[rstest]
@@ -88,6 +88,14 @@ fn test_doc_sanitize_filter(repo: TestRepo) {
         "user-feature-task",
         "sanitize should handle multiple slashes"
     );
+
+    // Characters invalid in Windows filenames
+    vars.insert("branch", "fix/a|b");
+    assert_eq!(
+        expand_template("{{ branch | sanitize }}", &vars, false, &repository).unwrap(),
+        "fix-a-b",
+        "sanitize should replace characters invalid in filenames"
+    );
 }
 
 // =============================================================================
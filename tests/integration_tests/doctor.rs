@@ -0,0 +1,35 @@
+//! Integration tests for `wt doctor`
+
+use crate::common::{TestRepo, repo};
+use ansi_str::AnsiStr;
+use rstest::rstest;
+
+fn doctor(repo: &TestRepo) -> String {
+    let output = repo.wt_command().arg("doctor").output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr)
+        .ansi_strip()
+        .into_owned();
+    assert!(output.status.success(), "stderr: {stderr}");
+    stderr
+}
+
+#[rstest]
+fn test_doctor_llm_disabled(repo: TestRepo) {
+    // The command would be named if diffs could reach it
+    repo.write_test_config(
+        "[commit-generation]\ncommand = \"nonexistent-llm-command-12345\"\n\n[llm]\nenabled = false\n",
+    );
+    let stderr = doctor(&repo);
+    assert!(stderr.contains("LLM features disabled"), "{stderr}");
+    assert!(!stderr.contains("nonexistent-llm-command"), "{stderr}");
+}
+
+#[rstest]
+fn test_doctor_llm_configured(repo: TestRepo) {
+    repo.write_test_config("[commit-generation]\ncommand = \"nonexistent-llm-command-12345\"\n");
+    let stderr = doctor(&repo);
+    assert!(
+        stderr.contains("Diffs are sent to nonexistent-llm-command-12345"),
+        "{stderr}"
+    );
+}
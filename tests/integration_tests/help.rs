@@ -67,6 +67,8 @@ fn snapshot_help(test_name: &str, args: &[&str]) {
 #[case("help_config_state_clear", "config state clear --help")]
 #[case("help_hook_approvals", "hook approvals --help")]
 #[case("help_hook_approvals_add", "hook approvals add --help")]
+#[case("help_hook_approvals_list", "hook approvals list --help")]
+#[case("help_hook_approvals_revoke", "hook approvals revoke --help")]
 #[case("help_hook_approvals_clear", "hook approvals clear --help")]
 fn test_help(#[case] test_name: &str, #[case] args_str: &str) {
     let args: Vec<&str> = if args_str.is_empty() {
@@ -0,0 +1,117 @@
+//! Integration tests for `wt history`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn history_json(repo: &TestRepo, args: &[&str]) -> Vec<serde_json::Value> {
+    let output = repo
+        .wt_command()
+        .args(["history", "--format=json"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+#[rstest]
+fn test_history_records_create_switch_remove(repo: TestRepo) {
+    for args in [
+        &["switch", "--create", "feature"][..],
+        &["switch", "main"],
+        &["remove", "feature", "--foreground"],
+    ] {
+        let output = repo.wt_command().args(args).output().unwrap();
+        assert!(
+            output.status.success(),
+            "wt {args:?}: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let entries = history_json(&repo, &[]);
+    let summary: Vec<(&str, &str, bool)> = entries
+        .iter()
+        .map(|entry| {
+            (
+                entry["operation"].as_str().unwrap(),
+                entry["branch"].as_str().unwrap(),
+                entry["success"].as_bool().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("create", "feature", true),
+            ("switch", "main", true),
+            ("remove", "feature", true),
+        ]
+    );
+}
+
+#[rstest]
+fn test_history_records_failure(repo: TestRepo) {
+    repo.write_project_config(r#"post-create = "exit 1""#);
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "broken", "--yes"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    let entries = history_json(&repo, &[]);
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0]["branch"], "broken");
+    assert_eq!(entries[0]["success"], false);
+}
+
+#[rstest]
+fn test_history_since(repo: TestRepo) {
+    // Entries are stamped with SOURCE_DATE_EPOCH, so an old entry appended
+    // by hand is the only one filtered out
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let log = repo.root_path().join(".git").join("wt-history.jsonl");
+    let mut contents = std::fs::read_to_string(&log).unwrap();
+    contents.insert_str(
+        0,
+        "{\"timestamp\":0,\"operation\":\"switch\",\"branch\":\"old\",\"success\":true}\n",
+    );
+    std::fs::write(&log, contents).unwrap();
+
+    assert_eq!(history_json(&repo, &[]).len(), 2);
+    let recent = history_json(&repo, &["--since", "7d"]);
+    assert_eq!(recent.len(), 1);
+    assert_eq!(recent[0]["branch"], "feature");
+}
+
+#[rstest]
+fn test_history_invalid_since(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["history", "--since", "week"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("invalid age"), "stderr: {stderr}");
+
+    // Fits in a u64 as days, but not as seconds
+    let output = repo
+        .wt_command()
+        .args(["history", "--since", "999999999999999d"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("too large"), "stderr: {stderr}");
+}
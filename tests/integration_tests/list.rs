@@ -1,6 +1,7 @@
 use crate::common::{
-    DAY, HOUR, MINUTE, TestRepo, list_snapshots, repo, repo_with_remote, wt_command,
+    DAY, HOUR, MINUTE, TestRepo, json_snapshots, list_snapshots, repo, repo_with_remote, wt_command,
 };
+use ansi_str::AnsiStr;
 use insta_cmd::assert_cmd_snapshot;
 use rstest::rstest;
 
@@ -147,6 +148,18 @@ fn test_list_detached_head_in_worktree(mut repo: TestRepo) {
     assert_cmd_snapshot!(list_snapshots::command(&repo, repo.root_path()));
 }
 
+#[rstest]
+fn test_list_detached_head_describes_tag(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.run_git(&["tag", "v1.0"]);
+    repo.detach_head_in_worktree("feature");
+
+    let output = repo.wt_command().arg("list").output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("(detached at v1.0)"), "stdout: {stdout}");
+}
+
 #[rstest]
 fn test_list_locked_worktree(mut repo: TestRepo) {
     repo.add_worktree("locked-feature");
@@ -223,6 +236,25 @@ fn test_list_json_with_metadata(mut repo: TestRepo) {
     });
 }
 
+/// Guards the `--format=json` contract: fields may be added, never removed or retyped.
+///
+/// Set `WORKTRUNK_UPDATE_JSON_SCHEMA=1` to record newly added fields in the baseline.
+#[rstest]
+fn test_list_json_schema_compatible(mut repo: TestRepo) {
+    repo.add_worktree("locked-feature");
+    repo.lock_worktree("locked-feature", Some("Testing"));
+
+    let mut value = json_snapshots::list_json(&repo, &[]);
+    json_snapshots::assert_schema_compatible("list", &value);
+
+    json_snapshots::normalize(&mut value, &repo);
+    assert_eq!(value[0]["path"], "[REPO]");
+    assert_eq!(value[0]["commit"]["sha"], "[SHA]");
+    assert_eq!(value[0]["commit"]["short_sha"], "[SHORT_SHA]");
+    assert_eq!(value[0]["commit"]["timestamp"], "[TIMESTAMP]");
+    assert_eq!(value[0]["commit"]["time"], "[TIME]");
+}
+
 /// This tests the merge commit scenario where content matches main even with different commit history.
 #[rstest]
 fn test_list_json_tree_matches_main_after_merge(mut repo: TestRepo) {
@@ -397,6 +429,27 @@ fn test_list_with_remotes_filters_tracked_branches(#[from(repo_with_remote)] rep
     });
 }
 
+#[rstest]
+fn test_list_with_remotes_dedups_local_names(#[from(repo_with_remote)] repo: TestRepo) {
+    // Pushed without -u: no local branch tracks origin/shared, but one has its name
+    repo.create_branch("shared");
+    repo.push_branch("shared");
+    repo.create_branch("remote-only");
+    repo.push_branch("remote-only");
+    repo.run_git(&["branch", "-D", "remote-only"]);
+
+    let json = json_snapshots::list_json(&repo, &["--branches", "--remote"]);
+    let branches: Vec<&str> = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter_map(|item| item["branch"].as_str())
+        .collect();
+    assert!(branches.contains(&"shared"), "{branches:?}");
+    assert!(branches.contains(&"origin/remote-only"), "{branches:?}");
+    assert!(!branches.contains(&"origin/shared"), "{branches:?}");
+}
+
 #[rstest]
 fn test_list_json_with_display_fields(mut repo: TestRepo) {
     repo.commit("Initial commit on main");
@@ -647,6 +700,46 @@ fn test_list_json_with_git_operation(mut repo: TestRepo) {
     });
 }
 
+#[test]
+fn test_list_json_builder_diverged_branch() {
+    let repo = TestRepo::builder()
+        .branch("feature")
+        .commits(3)
+        .diverge_from_main(2)
+        .worktree()
+        .build();
+
+    let value = json_snapshots::list_json(&repo, &[]);
+    let feature = value
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["branch"] == "feature")
+        .expect("feature worktree listed");
+    assert_eq!(feature["main"]["ahead"], 3);
+    assert_eq!(feature["main"]["behind"], 2);
+}
+
+#[test]
+fn test_list_json_builder_rebase_in_progress() {
+    let repo = TestRepo::builder()
+        .branch("feature")
+        .conflict_on("conflict.txt")
+        .rebase_in_progress()
+        .build();
+
+    let value = json_snapshots::list_json(&repo, &[]);
+    let feature_path = repo.worktree_path("feature").to_str().unwrap();
+    let feature = value
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|item| item["path"] == feature_path)
+        .expect("feature worktree listed");
+    assert_eq!(feature["operation_state"], "conflicts");
+    assert_eq!(feature["worktree"]["detached"], true);
+}
+
 #[rstest]
 fn test_list_branch_only_with_status(repo: TestRepo) {
     // Test that branch-only entries (no worktree) can display branch-keyed status
@@ -2539,3 +2632,563 @@ fn test_list_nested_worktree_json_is_current(mut repo: TestRepo) {
         "Parent worktree 'main' should NOT be marked as current"
     );
 }
+
+/// Tests that `--du` reports per-worktree disk usage in JSON, and that it's opt-in.
+#[rstest]
+fn test_list_json_disk_usage(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    std::fs::create_dir_all(feature_path.join("node_modules")).unwrap();
+    std::fs::write(
+        feature_path.join("node_modules/big.js"),
+        vec![b'x'; 64 * 1024],
+    )
+    .unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--du"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = json.iter().find(|w| w["branch"] == "feature").unwrap();
+    let bytes = feature["worktree"]["disk_usage"].as_u64().unwrap();
+    assert!(bytes >= 64 * 1024, "expected at least 64K, got {bytes}");
+
+    let cache_dir = repo.root_path().join(".git/wt-cache/disk-usage");
+    assert!(cache_dir.exists(), "disk usage cache should be written");
+
+    // Without --du, the field is absent
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = json.iter().find(|w| w["branch"] == "feature").unwrap();
+    assert!(feature["worktree"].get("disk_usage").is_none());
+}
+
+/// Tests that worktrees sharing a directory name get separate disk usage caches.
+#[rstest]
+fn test_list_disk_usage_cache_per_worktree_path(mut repo: TestRepo) {
+    let first = repo.root_path().join(".worktrees/one/app");
+    let second = repo.root_path().join(".worktrees/two/app");
+    repo.add_worktree_at_path("one", &first);
+    repo.add_worktree_at_path("two", &second);
+    std::fs::create_dir_all(second.join("node_modules")).unwrap();
+    std::fs::write(second.join("node_modules/big.js"), vec![b'x'; 64 * 1024]).unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--du"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let size = |branch: &str| {
+        json.iter().find(|w| w["branch"] == branch).unwrap()["worktree"]["disk_usage"]
+            .as_u64()
+            .unwrap()
+    };
+    assert!(size("two") >= 64 * 1024 + size("one"));
+
+    let cache_files = std::fs::read_dir(repo.root_path().join(".git/wt-cache/disk-usage"))
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("app-"))
+        .count();
+    assert_eq!(
+        cache_files, 2,
+        "each worktree path should have its own cache"
+    );
+}
+
+/// Tests that `[integrations.issues]` extracts issue references from branch names into JSON.
+#[rstest]
+fn test_list_json_issue_from_branch_name(mut repo: TestRepo) {
+    repo.write_project_config(
+        r#"[integrations.issues]
+pattern = "PROJ-(\\d+)"
+url = "https://tracker.example.com/browse/PROJ-{id}"
+"#,
+    );
+    repo.add_worktree("feature/PROJ-123-login");
+    repo.add_worktree("feature-no-issue");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+
+    let linked = json
+        .iter()
+        .find(|w| w["branch"] == "feature/PROJ-123-login")
+        .unwrap();
+    assert_eq!(linked["issue"]["key"], "PROJ-123");
+    assert_eq!(linked["issue"]["id"], "123");
+    assert_eq!(
+        linked["issue"]["url"],
+        "https://tracker.example.com/browse/PROJ-123"
+    );
+
+    let unlinked = json
+        .iter()
+        .find(|w| w["branch"] == "feature-no-issue")
+        .unwrap();
+    assert!(unlinked.get("issue").is_none());
+}
+
+/// Tests that `--index` rows can be addressed by number in later commands.
+#[rstest]
+fn test_list_index_addressing(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--index"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let recorded = repo.git_output(&["config", "worktrunk.list-index"]);
+    let value: serde_json::Value = serde_json::from_str(&recorded).unwrap();
+    let rows = value["branches"].as_array().unwrap();
+    let row = rows.iter().position(|b| b == "feature").unwrap() + 1;
+
+    let output = repo
+        .wt_command()
+        .args(["switch", &row.to_string()])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("feature"), "stderr: {stderr}");
+
+    // Rows past the end of the listing are rejected rather than treated as names
+    let output = repo.wt_command().args(["switch", "99"]).output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("No row"), "stderr: {stderr}");
+
+    // With --create the number is a new branch name, not a row
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", &row.to_string()])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        repo.git_output(&["branch", "--list", &row.to_string()])
+            .contains(&row.to_string()),
+        "branch {row} should exist"
+    );
+}
+
+/// Tests that `wt remove` takes several row numbers, and that a branch named
+/// like a row number still means that branch.
+#[rstest]
+fn test_list_index_remove(mut repo: TestRepo) {
+    let one = repo.add_worktree("one");
+    let two = repo.add_worktree("two");
+    let keep = repo.add_worktree("keep");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--index"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+
+    let recorded = repo.git_output(&["config", "worktrunk.list-index"]);
+    let value: serde_json::Value = serde_json::from_str(&recorded).unwrap();
+    let rows = value["branches"].as_array().unwrap();
+    let row = |branch: &str| (rows.iter().position(|b| b == branch).unwrap() + 1).to_string();
+    let keep_row = row("keep");
+    repo.run_git(&["branch", &keep_row]);
+
+    let output = repo
+        .wt_command()
+        .args([
+            "remove",
+            "--foreground",
+            &row("one"),
+            &row("two"),
+            &keep_row,
+        ])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(!one.exists());
+    assert!(!two.exists());
+    // The branch literally named like keep's row was removed instead of keep
+    assert!(keep.exists());
+    assert_eq!(repo.git_output(&["branch", "--list", &keep_row]), "");
+}
+
+// file:// URLs built from Windows paths need drive-letter handling
+#[cfg(unix)]
+#[rstest]
+fn test_list_shallow_clone(repo: TestRepo) {
+    repo.commit("Second commit");
+    let temp_dir = tempfile::tempdir().unwrap();
+    let clone = temp_dir.path().join("shallow");
+    // file:// so git honors --depth for a local source
+    let url = format!("file://{}", repo.root_path().display());
+    repo.run_git(&["clone", "--depth", "1", &url, clone.to_str().unwrap()]);
+
+    let output = repo
+        .wt_command()
+        .arg("list")
+        .current_dir(&clone)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Shallow clone"), "stderr: {stderr}");
+    assert!(stderr.contains("wt config show --full"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_list_partial_clone(repo: TestRepo) {
+    repo.run_git(&[
+        "config",
+        "remote.origin.url",
+        "https://example.com/repo.git",
+    ]);
+    repo.run_git(&["config", "remote.origin.partialclonefilter", "blob:none"]);
+
+    let output = repo.wt_command().arg("list").output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Partial clone"), "stderr: {stderr}");
+
+    // Full clones get no notice
+    repo.run_git(&["config", "--unset", "remote.origin.partialclonefilter"]);
+    let output = repo.wt_command().arg("list").output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stderr.contains("Partial clone"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_list_no_truncate(repo: TestRepo) {
+    let message = format!(
+        "Subject that runs well past the message column {}",
+        "x".repeat(120)
+    );
+    repo.commit(&message);
+
+    let output = repo.wt_command().arg("list").output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains(&message), "stdout: {stdout}");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--no-truncate"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.contains(&message), "stdout: {stdout}");
+}
+
+#[rstest]
+fn test_list_record_layout(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--layout", "record"])
+        .env("COLUMNS", "150")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout)
+        .ansi_strip()
+        .into_owned();
+    assert!(output.status.success());
+    assert!(
+        stdout.lines().any(|line| line.starts_with("@ main")),
+        "stdout: {stdout}"
+    );
+    assert!(
+        stdout.lines().any(|line| line.starts_with("+ feature")),
+        "stdout: {stdout}"
+    );
+    assert!(stdout.contains("  Commit "), "stdout: {stdout}");
+    assert!(
+        !stdout.contains("Branch"),
+        "no table header expected: {stdout}"
+    );
+
+    // auto switches to records on narrow terminals
+    let output = repo
+        .wt_command()
+        .arg("list")
+        .env("COLUMNS", "40")
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout)
+        .ansi_strip()
+        .into_owned();
+    assert!(
+        stdout.lines().any(|line| line.starts_with("@ main")),
+        "stdout: {stdout}"
+    );
+}
+
+/// Tests `--owner` (marker wins over commit author) and `--mine` filtering.
+#[rstest]
+fn test_list_owner_and_mine(mut repo: TestRepo) {
+    repo.add_worktree("mine");
+    let theirs = repo.add_worktree("theirs");
+    std::fs::write(theirs.join("theirs.txt"), "theirs").unwrap();
+    repo.run_git_in(&theirs, &["add", "."]);
+    repo.run_git_in(
+        &theirs,
+        &[
+            "-c",
+            "user.name=Other Dev",
+            "-c",
+            "user.email=other@example.com",
+            "commit",
+            "-m",
+            "Their commit",
+        ],
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--owner"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let owner = |branch: &str| {
+        json.iter().find(|w| w["branch"] == branch).unwrap()["owner"]
+            .as_str()
+            .map(str::to_string)
+    };
+    assert_eq!(owner("mine").as_deref(), Some("Test User"));
+    assert_eq!(owner("theirs").as_deref(), Some("Other Dev"));
+
+    // A WT_OWNER marker in the worktree's git dir overrides the author
+    let git_dir = repo.git_output(&[
+        "-C",
+        theirs.to_str().unwrap(),
+        "rev-parse",
+        "--absolute-git-dir",
+    ]);
+    std::fs::write(std::path::Path::new(&git_dir).join("WT_OWNER"), "alex\n").unwrap();
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--owner"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let theirs_item = json.iter().find(|w| w["branch"] == "theirs").unwrap();
+    assert_eq!(theirs_item["owner"], "alex");
+
+    // --mine keeps the main worktree; owner stays absent without --owner
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json", "--mine"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let branches: Vec<_> = json.iter().filter_map(|w| w["branch"].as_str()).collect();
+    assert!(branches.contains(&"main"), "branches: {branches:?}");
+    assert!(branches.contains(&"mine"), "branches: {branches:?}");
+    assert!(!branches.contains(&"theirs"), "branches: {branches:?}");
+    assert!(json.iter().all(|w| w.get("owner").is_none()));
+}
+
+#[rstest]
+fn test_list_json_cache(repo: TestRepo) {
+    repo.write_test_config("[list]\ncache = true\n");
+    let list_json = || {
+        let output = repo
+            .wt_command()
+            .args(["list", "--format=json"])
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    };
+
+    let fresh = list_json();
+    let cache_dir = repo.root_path().join(".git").join("wt-list-cache");
+    let entries: Vec<_> = std::fs::read_dir(&cache_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    assert_eq!(entries.len(), 1);
+
+    // Same refs and index: the stored output is served as-is
+    let contents = std::fs::read_to_string(&entries[0]).unwrap();
+    let (key, json) = contents.split_once('\n').unwrap();
+    assert_eq!(json.trim(), fresh.trim());
+    std::fs::write(&entries[0], format!("{key}\n[\"cached\"]")).unwrap();
+    assert_eq!(list_json().trim(), "[\"cached\"]");
+
+    // A new commit changes the key, so the output is recomputed
+    repo.commit("Another commit");
+    assert_ne!(list_json().trim(), "[\"cached\"]");
+}
+
+/// A cache that can't be written doesn't fail the listing.
+#[rstest]
+fn test_list_json_cache_write_failure(repo: TestRepo) {
+    repo.write_test_config("[list]\ncache = true\n");
+    // A file where the cache directory should be
+    std::fs::write(repo.root_path().join(".git").join("wt-list-cache"), "").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert!(json.as_array().is_some_and(|items| !items.is_empty()));
+}
+
+/// Tests `--graph` draws behind/merge-base/ahead, and stays hidden without it.
+#[rstest]
+fn test_list_graph(mut repo: TestRepo) {
+    repo.add_worktree_with_commit("feature", "feature.txt", "feature", "Feature commit");
+    repo.commit("Main moves on");
+
+    let list = |args: &[&str]| {
+        let output = repo
+            .wt_command()
+            .arg("list")
+            .args(args)
+            .env("COLUMNS", "150")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout)
+            .ansi_strip()
+            .into_owned()
+    };
+
+    let stdout = list(&["--graph"]);
+    assert!(stdout.contains("Graph"), "stdout: {stdout}");
+    let feature = stdout
+        .lines()
+        .find(|line| line.contains("feature"))
+        .unwrap();
+    assert!(feature.contains("○◆●"), "stdout: {stdout}");
+
+    let stdout = list(&[]);
+    assert!(!stdout.contains("Graph"), "stdout: {stdout}");
+}
+
+/// Tests that a layout saved by `wt list --tune` is applied, and that tuning
+/// needs a terminal.
+#[rstest]
+fn test_list_tuned_columns(repo: TestRepo) {
+    let list = || {
+        let output = repo
+            .wt_command()
+            .arg("list")
+            .env("COLUMNS", "150")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout)
+            .ansi_strip()
+            .into_owned()
+    };
+    assert!(list().contains("Commit"));
+
+    repo.run_git(&[
+        "config",
+        "worktrunk.list-columns",
+        r#"{"widths":{"branch":20},"hidden":["commit"]}"#,
+    ]);
+    let stdout = list();
+    assert!(!stdout.contains("Commit"), "stdout: {stdout}");
+    let header = stdout.lines().next().unwrap();
+    assert!(
+        header.contains(&format!("Branch{}", " ".repeat(14))),
+        "{header}"
+    );
+
+    let output = repo.wt_command().args(["list", "--tune"]).output().unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("interactive terminal"), "stderr: {stderr}");
+
+    // The tuner only draws tables
+    let output = repo
+        .wt_command()
+        .args(["list", "--tune", "--format=json"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("cannot be used with"), "stderr: {stderr}");
+}
+
+/// Tests `--absolute-time` and `[list]` time settings in the Age column; JSON
+/// always carries both the raw timestamp and an ISO 8601 time.
+#[rstest]
+fn test_list_absolute_time(repo: TestRepo) {
+    let list = |args: &[&str]| {
+        let output = repo
+            .wt_command()
+            .arg("list")
+            .args(args)
+            .env("COLUMNS", "150")
+            .env("TZ", "UTC")
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+        String::from_utf8_lossy(&output.stdout)
+            .ansi_strip()
+            .into_owned()
+    };
+
+    assert!(!list(&[]).contains("2025-01-01"));
+    let stdout = list(&["--absolute-time"]);
+    assert!(stdout.contains("2025-01-01"), "stdout: {stdout}");
+
+    repo.write_test_config("[list]\nabsolute-time = true\ntime-format = \"%d.%m.%Y\"\n");
+    let stdout = list(&[]);
+    assert!(stdout.contains("01.01.2025"), "stdout: {stdout}");
+
+    let value = json_snapshots::list_json(&repo, &[]);
+    let commit = &value[0]["commit"];
+    assert!(commit["timestamp"].is_i64(), "{commit}");
+    assert!(
+        commit["time"].as_str().unwrap().starts_with("2025-01-01T"),
+        "{commit}"
+    );
+}
+
+#[rstest]
+fn test_list_upstream_gone(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    repo.run_git_in(&feature, &["push", "-u", "origin", "feature"]);
+    // The remote branch is deleted, e.g. after its PR merged
+    repo.run_git(&["push", "origin", "--delete", "feature"]);
+
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = json.iter().find(|w| w["branch"] == "feature").unwrap();
+    let symbols = feature["symbols"].as_str().unwrap();
+    assert!(symbols.contains('⊘'), "{feature}");
+    assert!(!symbols.contains('⇈'), "{feature}");
+}
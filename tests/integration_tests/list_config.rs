@@ -473,3 +473,46 @@ timeout-ms = 1
         stderr
     );
 }
+
+/// Test that `[list] summary` selects the summary line's metrics.
+#[rstest]
+fn test_list_config_summary_metrics(repo: TestRepo, temp_home: TempDir) {
+    fs::write(repo.root_path().join("dirty.txt"), "uncommitted").unwrap();
+
+    let global_config_dir = temp_home.path().join(".config").join("worktrunk");
+    fs::create_dir_all(&global_config_dir).unwrap();
+    fs::write(
+        global_config_dir.join("config.toml"),
+        r#"[list]
+summary = ["ahead"]
+"#,
+    )
+    .unwrap();
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    set_temp_home_env(&mut cmd, temp_home.path());
+    cmd.arg("list").current_dir(repo.root_path());
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.contains("Showing 1 worktree"), "stdout: {stdout}");
+    assert!(!stdout.contains("with changes"), "stdout: {stdout}");
+}
+
+/// Test that `--no-summary` omits the summary line.
+#[rstest]
+fn test_list_no_summary(repo: TestRepo, temp_home: TempDir) {
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    set_temp_home_env(&mut cmd, temp_home.path());
+    cmd.args(["list", "--no-summary"])
+        .current_dir(repo.root_path());
+
+    let output = cmd.output().unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert!(stdout.contains("main"), "stdout: {stdout}");
+    assert!(!stdout.contains("Showing"), "stdout: {stdout}");
+}
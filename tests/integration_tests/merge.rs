@@ -2,7 +2,8 @@ use crate::common::{
     TestRepo, make_snapshot_cmd, merge_scenario,
     mock_commands::{create_mock_cargo, create_mock_llm_auth},
     repo, repo_with_alternate_primary, repo_with_feature_worktree, repo_with_main_worktree,
-    repo_with_multi_commit_feature, setup_snapshot_settings,
+    repo_with_multi_commit_feature, repo_with_remote, repo_with_remote_and_feature,
+    setup_snapshot_settings,
 };
 use insta_cmd::assert_cmd_snapshot;
 use rstest::rstest;
@@ -414,6 +415,218 @@ fn test_merge_no_squash(repo_with_multi_commit_feature: TestRepo) {
     ));
 }
 
+#[rstest]
+fn test_merge_no_squash_autosquash(mut repo_with_main_worktree: TestRepo) {
+    let repo = &mut repo_with_main_worktree;
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "a.txt", "a", "Add a");
+    repo.commit_in_worktree(&feature_wt, "b.txt", "b", "Add b");
+    repo.commit_in_worktree(&feature_wt, "a.txt", "a fixed", "fixup! Add a");
+
+    let output = repo
+        .wt_command()
+        .args([
+            "merge",
+            "main",
+            "--no-squash",
+            "--autosquash",
+            "--no-remove",
+        ])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "wt merge failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // The fixup folded into "Add a"; the other commits stay separate
+    let subjects = repo.git_output(&["log", "--format=%s", "-2", "main"]);
+    assert_eq!(subjects, "Add b\nAdd a");
+    assert_eq!(repo.git_output(&["show", "main:a.txt"]), "a fixed");
+}
+
+#[rstest]
+fn test_merge_configured_steps(mut repo_with_main_worktree: TestRepo) {
+    let repo = &mut repo_with_main_worktree;
+    let feature_wt = repo.add_worktree("feature");
+    fs::create_dir_all(feature_wt.join(".config")).unwrap();
+    fs::write(
+        feature_wt.join(".config/wt.toml"),
+        r#"[merge]
+steps = ["commit", "rebase", "mark", "push"]
+
+[profiles.mark]
+command = "echo {{ target }} > marker.txt"
+"#,
+    )
+    .unwrap();
+    repo.run_git_in(&feature_wt, &["add", ".config"]);
+    repo.run_git_in(&feature_wt, &["commit", "-m", "Add config"]);
+    repo.commit_in_worktree(&feature_wt, "b.txt", "b", "Add b");
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "wt merge failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // No squash or cleanup in the pipeline; the profile ran before the fast-forward
+    let subjects = repo.git_output(&["log", "--format=%s", "-2", "main"]);
+    assert_eq!(subjects, "Add b\nAdd config");
+    assert!(feature_wt.exists());
+    assert_eq!(
+        fs::read_to_string(feature_wt.join("marker.txt"))
+            .unwrap()
+            .trim(),
+        "main"
+    );
+
+    // An invalid pipeline fails before anything runs
+    fs::write(
+        feature_wt.join(".config/wt.toml"),
+        "[merge]\nsteps = [\"push\", \"rebase\"]\n",
+    )
+    .unwrap();
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("rebase must come before push"),
+        "stderr: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_merge_wait_ci_without_forge(mut repo_with_main_worktree: TestRepo) {
+    let repo = &mut repo_with_main_worktree;
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "b.txt", "b", "Add b");
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--yes", "--wait-ci"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("Can't wait for CI"), "stderr: {stderr}");
+
+    // Without a CI result the target stays where it was
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+    assert!(feature_wt.exists());
+}
+
+/// A feature worktree whose CI is answered by a mock `gh` with `pr_json`.
+///
+/// The remote is a local bare repository, so `[ci] platform` picks GitHub.
+fn setup_wait_ci(repo: &mut TestRepo, pr_json: &str) -> PathBuf {
+    repo.write_project_config("[ci]\nplatform = \"github\"\n");
+    repo.commit("Add project config");
+    repo.write_test_config("[merge]\nci-interval = 1\nci-timeout = 1\n");
+    repo.setup_mock_gh_with_ci_data(pr_json, "[]");
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "b.txt", "b", "Add b");
+    feature_wt
+}
+
+/// PR status for the feature branch with a single check.
+fn pr_with_check(merge_state: &str, status: &str, conclusion: &str) -> String {
+    format!(
+        r#"[{{
+        "mergeStateStatus": "{merge_state}",
+        "statusCheckRollup": [{{"status": "{status}", "conclusion": {conclusion}}}],
+        "url": "https://github.com/test-owner/test-repo/pull/1"
+    }}]"#
+    )
+}
+
+fn merge_wait_ci(repo: &TestRepo, feature_wt: &Path) -> std::process::Output {
+    let mut cmd = repo.wt_command();
+    repo.configure_mock_commands(&mut cmd);
+    cmd.args(["merge", "main", "--yes", "--wait-ci"])
+        .current_dir(feature_wt)
+        .output()
+        .unwrap()
+}
+
+#[rstest]
+fn test_merge_wait_ci_passes(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt = setup_wait_ci(
+        &mut repo,
+        &pr_with_check("CLEAN", "COMPLETED", "\"SUCCESS\""),
+    );
+
+    let output = merge_wait_ci(&repo, &feature_wt);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("CI passed"), "stderr: {stderr}");
+
+    // The branch was pushed for CI, and the target moved to it afterwards
+    let pushed = repo.git_output(&["rev-parse", "origin/feature"]);
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), pushed);
+}
+
+#[rstest]
+fn test_merge_wait_ci_failure_keeps_target(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt = setup_wait_ci(
+        &mut repo,
+        &pr_with_check("BLOCKED", "COMPLETED", "\"FAILURE\""),
+    );
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+
+    let output = merge_wait_ci(&repo, &feature_wt);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("CI failed"), "stderr: {stderr}");
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+    assert!(feature_wt.exists());
+}
+
+#[rstest]
+fn test_merge_wait_ci_timeout_keeps_target(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt = setup_wait_ci(&mut repo, &pr_with_check("UNKNOWN", "IN_PROGRESS", "null"));
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+
+    let output = merge_wait_ci(&repo, &feature_wt);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("Timed out waiting for CI"),
+        "stderr: {stderr}"
+    );
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+}
+
+/// Without any checks, `wt merge` gives up within `ci-timeout`, not a fixed grace period.
+#[rstest]
+fn test_merge_wait_ci_no_checks(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt = setup_wait_ci(&mut repo, "[]");
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+
+    let started = std::time::Instant::now();
+    let output = merge_wait_ci(&repo, &feature_wt);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("No CI checks reported"), "stderr: {stderr}");
+    assert!(started.elapsed() < std::time::Duration::from_secs(30));
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+}
+
 #[rstest]
 fn test_merge_squash_empty_changes(mut repo_with_main_worktree: TestRepo) {
     let repo = &mut repo_with_main_worktree;
@@ -709,6 +922,39 @@ fn test_merge_post_merge_command_skipped_with_no_verify(mut repo: TestRepo) {
     );
 }
 
+#[rstest]
+fn test_merge_skip_hook_skips_only_that_type(mut repo: TestRepo) {
+    // A failing pre-merge hook would abort the merge unless skipped
+    let config_dir = repo.root_path().join(".config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("wt.toml"),
+        r#"pre-merge = "exit 1"
+post-merge = "echo 'merged {{ branch }}' > post-merge-ran.txt"
+"#,
+    )
+    .unwrap();
+
+    repo.commit("Add config");
+
+    let feature_wt = repo.add_feature();
+
+    let output = make_snapshot_cmd(
+        &repo,
+        "merge",
+        &["main", "--yes", "--skip-hook", "pre-merge"],
+        Some(&feature_wt),
+    )
+    .output()
+    .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Skipping pre-merge hooks (--skip-hook)"));
+
+    // Post-merge still runs
+    assert!(repo.root_path().join("post-merge-ran.txt").exists());
+}
+
 #[rstest]
 fn test_merge_post_merge_command_failure(mut repo: TestRepo) {
     // Create project config with failing post-merge command
@@ -1799,6 +2045,212 @@ fn test_step_commit_nothing_to_commit(repo: TestRepo) {
     });
 }
 
+#[rstest]
+fn test_step_commit_signoff_config(repo: TestRepo) {
+    repo.write_test_config("[commit]\nsignoff = true\n");
+    fs::write(repo.root_path().join("file1.txt"), "content 1").expect("Failed to write file");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit"])
+        .env("WORKTRUNK_COMMIT_GENERATION__COMMAND", "echo")
+        .env("WORKTRUNK_COMMIT_GENERATION__ARGS", "feat: add file")
+        .output()
+        .expect("Failed to run wt step commit");
+    assert!(
+        output.status.success(),
+        "wt step commit failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let message = repo.git_output(&["log", "-1", "--format=%B"]);
+    assert!(
+        message.contains("Signed-off-by: Test User <test@example.com>"),
+        "expected Signed-off-by trailer, got: {message}"
+    );
+}
+
+/// `[commit] review` only asks on a terminal: without one the generated
+/// message is used, and `--no` aborts the commit instead of hanging.
+#[rstest]
+fn test_step_commit_review_skipped_without_terminal(repo: TestRepo) {
+    repo.write_test_config("[commit]\nreview = true\n");
+    fs::write(repo.root_path().join("file1.txt"), "content 1").expect("Failed to write file");
+
+    let output = repo
+        .wt_command()
+        .args(["--no", "step", "commit"])
+        .env("WORKTRUNK_COMMIT_GENERATION__COMMAND", "echo")
+        .env("WORKTRUNK_COMMIT_GENERATION__ARGS", "feat: add file")
+        .output()
+        .expect("Failed to run wt step commit");
+    assert!(!output.status.success());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("Commit aborted"),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit"])
+        .env("WORKTRUNK_COMMIT_GENERATION__COMMAND", "echo")
+        .env("WORKTRUNK_COMMIT_GENERATION__ARGS", "feat: add file")
+        .output()
+        .expect("Failed to run wt step commit");
+    assert!(
+        output.status.success(),
+        "wt step commit failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let message = repo.git_output(&["log", "-1", "--format=%s"]);
+    assert_eq!(message.trim(), "feat: add file");
+}
+
+/// Trailers from the project `[commit]` section are expanded and appended;
+/// a trailer whose value expands to nothing is dropped.
+#[rstest]
+fn test_step_commit_project_trailers(repo: TestRepo) {
+    let config_dir = repo.root_path().join(".config");
+    fs::create_dir_all(&config_dir).unwrap();
+    fs::write(
+        config_dir.join("wt.toml"),
+        "[commit]\ntrailers = [\"Branch: {{ branch }}\", \"Refs: {{ issue }}\"]\n",
+    )
+    .unwrap();
+    repo.commit("Add config");
+    fs::write(repo.root_path().join("file1.txt"), "content 1").expect("Failed to write file");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit"])
+        .env("WORKTRUNK_COMMIT_GENERATION__COMMAND", "echo")
+        .env("WORKTRUNK_COMMIT_GENERATION__ARGS", "feat: add file")
+        .output()
+        .expect("Failed to run wt step commit");
+    assert!(
+        output.status.success(),
+        "wt step commit failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let message = repo.git_output(&["log", "-1", "--format=%B"]);
+    assert!(
+        message.contains("Branch: main"),
+        "expected Branch trailer, got: {message}"
+    );
+    assert!(
+        !message.contains("Refs:"),
+        "unexpected Refs trailer: {message}"
+    );
+}
+
+/// Committing the same staged changes again reuses the cached message rather
+/// than running the LLM command a second time.
+#[rstest]
+fn test_step_commit_reuses_cached_message(repo: TestRepo) {
+    let calls = repo.root_path().parent().unwrap().join("llm-calls");
+    repo.write_test_config(&format!(
+        r#"[commit-generation]
+command = "sh"
+args = ["-c", "cat >/dev/null; echo call >> '{}'; echo 'feat: add file'"]
+"#,
+        calls.display()
+    ));
+    fs::write(repo.root_path().join("file1.txt"), "content 1").expect("Failed to write file");
+
+    for _ in 0..2 {
+        let output = repo
+            .wt_command()
+            .args(["step", "commit"])
+            .output()
+            .expect("Failed to run wt step commit");
+        assert!(
+            output.status.success(),
+            "wt step commit failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert_eq!(
+            repo.git_output(&["log", "-1", "--format=%s"]),
+            "feat: add file"
+        );
+        // Undo the commit, keeping the same changes staged
+        repo.run_git(&["reset", "--soft", "HEAD~1"]);
+    }
+
+    let calls = fs::read_to_string(&calls).unwrap();
+    assert_eq!(calls.lines().count(), 1, "LLM ran more than once");
+}
+
+#[rstest]
+fn test_step_commit_interactive_needs_terminal(repo: TestRepo) {
+    fs::write(repo.root_path().join("file1.txt"), "content 1").expect("Failed to write file");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "commit", "--interactive"])
+        .output()
+        .expect("Failed to run wt step commit");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("needs an interactive terminal"),
+        "stderr: {stderr}"
+    );
+
+    // Nothing was committed or left staged
+    assert_eq!(repo.git_output(&["diff", "--staged", "--name-only"]), "");
+}
+
+/// A relative `core.hooksPath` whose directory is gitignored (as Husky and lefthook
+/// install it) exists only in the primary worktree; commits from other worktrees
+/// still run those hooks, and `--no-verify` skips them.
+#[rstest]
+#[cfg(unix)]
+fn test_step_commit_runs_native_hooks_from_primary_worktree(mut repo: TestRepo) {
+    use std::os::unix::fs::PermissionsExt;
+
+    let hooks_dir = repo.root_path().join(".githooks");
+    fs::create_dir_all(&hooks_dir).unwrap();
+    let hook = hooks_dir.join("commit-msg");
+    fs::write(&hook, "#!/bin/sh\necho 'Hooked: yes' >> \"$1\"\n").unwrap();
+    fs::set_permissions(&hook, fs::Permissions::from_mode(0o755)).unwrap();
+    fs::write(repo.root_path().join(".git/info/exclude"), ".githooks/\n").unwrap();
+    repo.run_git(&["config", "core.hooksPath", ".githooks"]);
+
+    let feature_wt = repo.add_worktree("feature");
+    let commit = |file: &str, extra: &[&str]| {
+        fs::write(feature_wt.join(file), file).unwrap();
+        let output = repo
+            .wt_command()
+            .args(["step", "commit"])
+            .args(extra)
+            .current_dir(&feature_wt)
+            .env("WORKTRUNK_COMMIT_GENERATION__COMMAND", "echo")
+            .env("WORKTRUNK_COMMIT_GENERATION__ARGS", "feat: add file")
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "wt step commit failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+        repo.git_output(&[
+            "-C",
+            feature_wt.to_str().unwrap(),
+            "log",
+            "-1",
+            "--format=%B",
+        ])
+    };
+
+    let message = commit("one.txt", &[]);
+    assert!(message.contains("Hooked: yes"), "got: {message}");
+
+    let message = commit("two.txt", &["--no-verify"]);
+    assert!(!message.contains("Hooked: yes"), "got: {message}");
+}
+
 // =============================================================================
 // Error message snapshot tests
 // =============================================================================
@@ -2018,3 +2470,246 @@ fn test_step_rebase_accepts_tag(mut repo: TestRepo) {
         Some(&feature_wt)
     ));
 }
+
+/// Feature worktree whose only commit conflicts with a newer commit on main.
+fn conflicting_feature(repo: &TestRepo) -> PathBuf {
+    std::fs::write(repo.root_path().join("shared.txt"), "initial content\n").unwrap();
+    repo.run_git(&["add", "shared.txt"]);
+    repo.commit("Add shared file");
+    let base_commit = repo.git_output(&["rev-parse", "HEAD"]);
+    std::fs::write(repo.root_path().join("shared.txt"), "main version\n").unwrap();
+    repo.run_git(&["add", "shared.txt"]);
+    repo.run_git(&["commit", "-m", "Update shared.txt in main"]);
+
+    let feature_wt = repo.root_path().parent().unwrap().join("repo.feature");
+    repo.run_git(&[
+        "worktree",
+        "add",
+        feature_wt.to_str().unwrap(),
+        "-b",
+        "feature",
+        &base_commit,
+    ]);
+    std::fs::write(feature_wt.join("shared.txt"), "feature version\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "shared.txt"]);
+    repo.run_git_in(
+        &feature_wt,
+        &["commit", "-m", "Update shared.txt in feature"],
+    );
+    feature_wt
+}
+
+#[rstest]
+fn test_merge_interrupted_abort(repo: TestRepo) {
+    let feature_wt = conflicting_feature(&repo);
+    let original_head = repo.git_output(&["rev-parse", "feature"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    // The next merge reports the interrupted one instead of starting over
+    let output = repo
+        .wt_command()
+        .args(["merge", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("was interrupted"), "stderr: {stderr}");
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "--abort"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Rolled back"), "stderr: {stderr}");
+    assert_eq!(repo.git_output(&["rev-parse", "feature"]), original_head);
+    assert!(
+        repo.git_command()
+            .args(["config", "--get", "worktrunk.state.feature.merge"])
+            .output()
+            .unwrap()
+            .stdout
+            .is_empty()
+    );
+}
+
+#[rstest]
+fn test_merge_failure_before_rewrite_leaves_no_record(repo: TestRepo) {
+    let feature_wt = conflicting_feature(&repo);
+
+    // Fails on the rebase check without having changed the branch
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-rebase"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        repo.git_command()
+            .args(["config", "--get", "worktrunk.state.feature.merge"])
+            .output()
+            .unwrap()
+            .stdout
+            .is_empty()
+    );
+}
+
+#[rstest]
+fn test_merge_abort_conflicts_with_other_flags(repo: TestRepo) {
+    for flag in ["--no-squash", "--wait-ci", "--force"] {
+        let output = repo
+            .wt_command()
+            .args(["merge", "--abort", flag])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "{flag} accepted");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("cannot be used with"), "stderr: {stderr}");
+    }
+}
+
+#[rstest]
+fn test_merge_continue_refuses_flags_for_steps_already_run(repo: TestRepo) {
+    for flag in ["--no-squash", "--no-commit", "--no-rebase", "--autosquash"] {
+        let output = repo
+            .wt_command()
+            .args(["merge", "--continue", flag])
+            .output()
+            .unwrap();
+        assert!(!output.status.success(), "{flag} accepted");
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(stderr.contains("cannot be used with"), "stderr: {stderr}");
+    }
+}
+
+#[rstest]
+fn test_merge_interrupted_continue(repo: TestRepo) {
+    let feature_wt = conflicting_feature(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--no-remove"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+
+    // Resolve the conflict and stage it, as git asks
+    std::fs::write(feature_wt.join("shared.txt"), "resolved\n").unwrap();
+    repo.run_git_in(&feature_wt, &["add", "shared.txt"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "--continue", "--no-remove"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert_eq!(
+        repo.git_output(&["rev-parse", "main"]),
+        repo.git_output(&["rev-parse", "feature"])
+    );
+    assert_eq!(repo.git_output(&["show", "main:shared.txt"]), "resolved");
+}
+
+#[rstest]
+fn test_merge_continue_without_interrupted_merge(merge_scenario: (TestRepo, PathBuf)) {
+    let (repo, feature_wt) = merge_scenario;
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "--continue"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No interrupted merge"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_merge_push_updates_remote(repo_with_remote_and_feature: TestRepo) {
+    let repo = repo_with_remote_and_feature;
+    let feature_wt = repo.worktree_path("feature").to_path_buf();
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--push", "--no-remove"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Pushed"), "stderr: {stderr}");
+
+    let remote_main = repo.git_output(&["ls-remote", "origin", "refs/heads/main"]);
+    assert!(
+        remote_main.starts_with(&repo.git_output(&["rev-parse", "main"])),
+        "origin main: {remote_main}"
+    );
+}
+
+/// A remote target with commits the local target lacks is rejected before the
+/// local branch is touched.
+#[rstest]
+fn test_merge_push_rejects_diverged_remote(repo_with_remote_and_feature: TestRepo) {
+    let repo = repo_with_remote_and_feature;
+    let feature_wt = repo.worktree_path("feature").to_path_buf();
+
+    // Advance origin/main, then drop the commit locally
+    repo.commit("Remote-only commit");
+    repo.run_git(&["push", "origin", "main"]);
+    repo.run_git(&["reset", "--hard", "HEAD~1"]);
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main", "--push", "--no-remove"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("origin/main"), "stderr: {stderr}");
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+}
+
+#[rstest]
+fn test_merge_refuses_pending_git_merge(mut repo: TestRepo) {
+    let feature_wt = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_wt, "shared.txt", "feature", "Feature side");
+    repo.commit_in_worktree(repo.root_path(), "shared.txt", "main", "Main side");
+    let git_merge = repo
+        .git_command()
+        .args(["merge", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        !git_merge.status.success(),
+        "merge should stop on the conflict"
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["merge", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("merge is in progress"), "stderr: {stderr}");
+    assert!(stderr.contains("git merge --abort"), "stderr: {stderr}");
+}
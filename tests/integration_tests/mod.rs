@@ -4,7 +4,9 @@
 //
 // Windows path differences are handled by snapshot filters in setup_snapshot_settings().
 
+pub mod alias;
 pub mod analyze_trace;
+pub mod api;
 // column_alignment merged into spacing_edge_cases
 pub mod approval_pty;
 
@@ -13,7 +15,10 @@ pub mod approval_ui;
 pub mod approvals;
 pub mod bare_repository;
 pub mod cache_sharing;
+pub mod cherry;
 pub mod ci_status;
+pub mod clean;
+pub mod clone;
 pub mod column_alignment_verification;
 pub mod completion;
 pub mod completion_validation;
@@ -23,14 +28,17 @@ pub mod config_show_theme;
 pub mod config_state;
 pub mod configure_shell;
 pub mod default_branch;
+pub mod describe;
 pub mod diagnostic;
 pub mod directives;
+pub mod doctor;
 pub mod doc_templates;
 pub mod e2e_shell;
 pub mod e2e_shell_post_start;
 pub mod for_each;
 pub mod git_error_display;
 pub mod help;
+pub mod history;
 pub mod hook_show;
 pub mod init;
 pub mod list;
@@ -38,20 +46,30 @@ pub mod list_column_alignment;
 pub mod list_config;
 pub mod list_progressive;
 pub mod merge;
+pub mod navigate;
 pub mod output_system_guard;
+pub mod path;
 pub mod post_start_commands;
+pub mod ps;
 pub mod push;
 pub mod readme_sync;
 pub mod remove;
+pub mod report;
 pub mod repository;
+pub mod review;
+pub mod run;
 pub mod security;
 pub mod select;
 pub mod select_config;
+pub mod setup;
 pub mod shell_integration_prompt;
 pub mod shell_integration_windows;
 pub mod shell_wrapper;
 pub mod spacing_edge_cases;
 pub mod statusline;
 pub mod step_copy_ignored;
+pub mod step_prune;
 pub mod switch;
+pub mod tag;
+pub mod update;
 pub mod user_hooks;
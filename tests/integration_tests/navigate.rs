@@ -0,0 +1,51 @@
+//! Integration tests for `wt main` and `wt root`
+
+use crate::common::{TestRepo, canonicalize, configure_directive_file, directive_file, repo};
+use rstest::rstest;
+
+fn printed_path(repo: &TestRepo, command: &str, cwd: &std::path::Path) -> std::path::PathBuf {
+    let output = repo
+        .wt_command()
+        .arg(command)
+        .current_dir(cwd)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    canonicalize(std::path::Path::new(
+        String::from_utf8_lossy(&output.stdout).trim(),
+    ))
+    .unwrap()
+}
+
+#[rstest]
+fn test_main_and_root_from_linked_worktree(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+
+    assert_eq!(
+        printed_path(&repo, "main", &feature),
+        canonicalize(repo.root_path()).unwrap()
+    );
+    assert_eq!(
+        printed_path(&repo, "root", &feature),
+        canonicalize(&repo.root_path().join(".git")).unwrap()
+    );
+}
+
+#[rstest]
+fn test_main_changes_directory_with_shell_integration(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+    let (directive_path, _guard) = directive_file();
+
+    let mut cmd = repo.wt_command();
+    cmd.arg("main").current_dir(&feature);
+    configure_directive_file(&mut cmd, &directive_path);
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+
+    let directives = std::fs::read_to_string(&directive_path).unwrap();
+    assert!(directives.contains("cd "), "{directives}");
+}
@@ -0,0 +1,54 @@
+//! Integration tests for `wt path`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn wt_path(repo: &TestRepo, args: &[&str]) -> std::process::Output {
+    repo.wt_command().arg("path").args(args).output().unwrap()
+}
+
+#[rstest]
+fn test_path_existing_and_current(mut repo: TestRepo) {
+    let feature = repo.add_worktree("feature");
+
+    let output = wt_path(&repo, &["feature"]);
+    assert!(output.status.success());
+    let printed = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        dunce::canonicalize(printed.trim()).unwrap(),
+        dunce::canonicalize(&feature).unwrap()
+    );
+
+    let output = wt_path(&repo, &[]);
+    let printed = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(
+        dunce::canonicalize(printed.trim()).unwrap(),
+        dunce::canonicalize(repo.root_path()).unwrap()
+    );
+}
+
+#[rstest]
+fn test_path_create(repo: TestRepo) {
+    repo.run_git(&["branch", "feature"]);
+
+    // Nothing is created without --create
+    let output = wt_path(&repo, &["feature"]);
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("No worktree found"), "{stderr}");
+
+    let output = wt_path(&repo, &["feature", "--create"]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let printed = String::from_utf8_lossy(&output.stdout);
+    let path = std::path::Path::new(printed.trim());
+    assert!(path.is_dir(), "{printed}");
+    assert_eq!(
+        repo.git_output(&["-C", printed.trim(), "branch", "--show-current"]),
+        "feature"
+    );
+}
@@ -0,0 +1,90 @@
+//! Integration tests for `wt ps` and trash-staged removal
+
+use crate::common::{TestRepo, repo, wait_for};
+use rstest::rstest;
+
+fn trash_entries(repo: &TestRepo) -> Vec<String> {
+    std::fs::read_dir(repo.root_path().join(".git/wt-trash"))
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|e| e.file_name().to_string_lossy().into_owned())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[rstest]
+fn test_ps_nothing_pending(repo: TestRepo) {
+    let output = repo.wt_command().arg("ps").output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("No background removals in progress"),
+        "stderr: {stderr}"
+    );
+}
+
+#[rstest]
+fn test_ps_lists_pending_removal(repo: TestRepo) {
+    let entry = repo.root_path().join(".git/wt-trash/1700000000-feature");
+    std::fs::create_dir_all(entry.join("node_modules")).unwrap();
+    std::fs::write(entry.join("node_modules/index.js"), "x".repeat(4096)).unwrap();
+
+    let output = repo.wt_command().arg("ps").output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Deleting feature"), "stderr: {stderr}");
+    assert!(stderr.contains("left, started"), "stderr: {stderr}");
+    assert!(stderr.contains("wt-logs"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_remove_foreground_empties_trash(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    std::fs::create_dir_all(worktree_path.join("node_modules/pkg")).unwrap();
+    std::fs::write(worktree_path.join("node_modules/pkg/index.js"), "x").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "--force", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+
+    assert!(!worktree_path.exists());
+    assert!(trash_entries(&repo).is_empty());
+    assert!(
+        !repo
+            .git_output(&["worktree", "list"])
+            .contains(&*worktree_path.to_string_lossy())
+    );
+}
+
+#[rstest]
+fn test_remove_background_stages_in_trash(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "feature"])
+        .output()
+        .unwrap();
+    assert!(output.status.success(), "{:?}", output);
+
+    // The worktree is unregistered before `wt remove` returns; only the file
+    // deletion is left to the detached process
+    assert!(!worktree_path.exists());
+    assert!(
+        !repo
+            .git_output(&["worktree", "list"])
+            .contains(&*worktree_path.to_string_lossy())
+    );
+
+    wait_for("trash directory emptied", || {
+        trash_entries(&repo).is_empty()
+    });
+    wait_for("branch deleted", || {
+        repo.git_output(&["branch", "--list", "feature"]).is_empty()
+    });
+}
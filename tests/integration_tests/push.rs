@@ -236,3 +236,174 @@ fn test_push_no_remote(#[from(repo_with_feature_worktree)] repo: TestRepo) {
     // Try to push without specifying target (should fail - no remote to get default branch)
     snapshot_push("push_no_remote", &repo, &[], Some(feature_wt));
 }
+
+#[rstest]
+fn test_push_protected_target_requires_force(mut repo: TestRepo) {
+    repo.write_project_config("[safety]\nprotected-branches = [\"main\"]\n");
+    repo.run_git(&["add", ".config/wt.toml"]);
+    repo.run_git(&["commit", "-m", "Protect main"]);
+
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "push", "main"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success(), "push should be refused");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("protected branch"), "stderr: {stderr}");
+
+    let output = repo
+        .wt_command()
+        .args(["step", "push", "main", "--force"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_wt_push_creates_upstream(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+
+    let output = repo
+        .wt_command()
+        .arg("push")
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        repo.git_output(&["rev-parse", "origin/feature"]),
+        repo.git_output(&["rev-parse", "feature"])
+    );
+    assert_eq!(
+        repo.git_output(&["config", "branch.feature.remote"]),
+        "origin"
+    );
+}
+
+#[rstest]
+fn test_wt_push_refuses_non_fast_forward(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+    repo.run_git_in(&feature_wt, &["push", "-u", "origin", "feature"]);
+    repo.run_git_in(&feature_wt, &["commit", "--amend", "-m", "Reworded"]);
+
+    let output = repo
+        .wt_command()
+        .arg("push")
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("origin/feature"), "stderr: {stderr}");
+    assert!(stderr.contains("--force-with-lease"), "stderr: {stderr}");
+
+    let output = repo
+        .wt_command()
+        .args(["push", "--force-with-lease"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s", "origin/feature"]),
+        "Reworded"
+    );
+}
+
+#[rstest]
+fn test_wt_push_never_force_pushes_protected_branch(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.write_project_config("[safety]\nprotected-branches = [\"main\"]\n");
+    repo.run_git(&["add", ".config/wt.toml"]);
+    repo.run_git(&["commit", "-m", "Protect main"]);
+    repo.run_git(&["push", "origin", "main"]);
+    let pushed = repo.git_output(&["rev-parse", "origin/main"]);
+    repo.run_git(&["commit", "--amend", "-m", "Rewritten"]);
+
+    let output = repo
+        .wt_command()
+        .args(["push", "--force-with-lease"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("protected branch"), "stderr: {stderr}");
+    assert_eq!(repo.git_output(&["rev-parse", "origin/main"]), pushed);
+}
+
+#[rstest]
+fn test_wt_push_never_force_pushes_protected_upstream(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.write_project_config("[safety]\nprotected-branches = [\"main\"]\n");
+    repo.run_git(&["add", ".config/wt.toml"]);
+    repo.run_git(&["commit", "-m", "Protect main"]);
+    repo.run_git(&["push", "origin", "main"]);
+    let pushed = repo.git_output(&["rev-parse", "origin/main"]);
+
+    // A differently named local branch whose upstream is the protected `main`
+    let fix_wt = repo.root_path().parent().unwrap().join("repo.fix");
+    repo.run_git(&[
+        "worktree",
+        "add",
+        "--track",
+        "-b",
+        "fix",
+        fix_wt.to_str().unwrap(),
+        "origin/main",
+    ]);
+    repo.run_git_in(&fix_wt, &["commit", "--amend", "-m", "Rewritten"]);
+
+    let output = repo
+        .wt_command()
+        .args(["push", "--force-with-lease"])
+        .current_dir(&fix_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("protected branch"), "stderr: {stderr}");
+    assert_eq!(repo.git_output(&["rev-parse", "origin/main"]), pushed);
+}
+
+#[rstest]
+fn test_wt_push_empty_merge_ref_pushes_same_name(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+    // An upstream remote with an empty merge ref names no remote branch
+    repo.run_git(&["config", "branch.feature.remote", "origin"]);
+    repo.run_git(&["config", "branch.feature.merge", ""]);
+
+    let output = repo
+        .wt_command()
+        .arg("push")
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        repo.git_output(&["rev-parse", "origin/feature"]),
+        repo.git_output(&["rev-parse", "feature"])
+    );
+}
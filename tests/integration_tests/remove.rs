@@ -1231,6 +1231,78 @@ approved-commands = ["exit 1"]
     );
 }
 
+/// Files a pre-remove hook leaves behind are checked before the worktree is
+/// moved to the trash, so a dirty worktree isn't staged for deletion.
+#[rstest]
+fn test_pre_remove_hook_dirtying_worktree_blocks_removal(mut repo: TestRepo) {
+    repo.write_project_config(r#"pre-remove = "touch scratch.txt""#);
+    repo.commit("Add config");
+    repo.write_test_config(
+        r#"[projects."../origin"]
+approved-commands = ["touch scratch.txt"]
+"#,
+    );
+    let worktree_path = repo.add_worktree("feature-dirty");
+
+    let mut cmd = wt_command();
+    repo.configure_wt_cmd(&mut cmd);
+    let output = cmd
+        .current_dir(repo.root_path())
+        .args(["remove", "--foreground", "feature-dirty"])
+        .output()
+        .unwrap();
+
+    assert!(
+        !output.status.success(),
+        "Removal of a dirty worktree should fail"
+    );
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("uncommitted changes"),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(worktree_path.join("scratch.txt").exists());
+    let trash_dir = repo.root_path().join(".git/wt-trash");
+    assert!(
+        !trash_dir.exists() || std::fs::read_dir(&trash_dir).unwrap().next().is_none(),
+        "Dirty worktree should not be staged in the trash"
+    );
+}
+
+/// The detached `wt step empty-trash` only deletes entries of the trash
+/// directory, never an arbitrary path it's handed.
+#[rstest]
+fn test_empty_trash_rejects_paths_outside_trash(repo: TestRepo) {
+    let outside = repo.root_path().join("keep-me");
+    std::fs::create_dir_all(&outside).unwrap();
+    std::fs::write(outside.join("file.txt"), "x").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "empty-trash"])
+        .arg(&outside)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(outside.join("file.txt").exists());
+
+    let entry = repo.root_path().join(".git/wt-trash/1700000000-feature");
+    std::fs::create_dir_all(entry.join("node_modules")).unwrap();
+    std::fs::write(entry.join("node_modules/index.js"), "x").unwrap();
+    let output = repo
+        .wt_command()
+        .args(["step", "empty-trash"])
+        .arg(&entry)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!entry.exists());
+}
+
 /// Pre-remove hook failure should NOT write cd directive.
 /// Bug: cd directive was written before pre-remove hooks ran, so if hooks failed,
 /// the shell would still cd to main_path even though the worktree wasn't removed.
@@ -1638,3 +1710,244 @@ fn test_remove_at_symbol_via_symlink(mut repo: TestRepo) {
         Some(&symlink_path)
     ));
 }
+
+#[rstest]
+fn test_remove_protected_branch_refuses_deletion(mut repo: TestRepo) {
+    repo.write_project_config("[safety]\nprotected-branches = [\"release/*\"]\n");
+    let worktree_path = repo.add_worktree("release/1.0");
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "release/1.0"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success(), "removal should be refused");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("protected branch"), "stderr: {stderr}");
+    assert!(worktree_path.exists(), "worktree should be untouched");
+
+    // --no-delete-branch removes only the worktree
+    let output = repo
+        .wt_command()
+        .args([
+            "remove",
+            "--no-delete-branch",
+            "--foreground",
+            "release/1.0",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!worktree_path.exists());
+    repo.run_git(&["rev-parse", "--verify", "refs/heads/release/1.0"]);
+}
+
+#[rstest]
+fn test_remove_force_dirty_backs_up_changes(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    std::fs::write(worktree_path.join("wip.txt"), "work in progress").unwrap();
+
+    // Plain removal refuses the dirty worktree
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(worktree_path.exists());
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--force-dirty", "--foreground", "feature"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("refs/wt-backup/feature"),
+        "stderr: {stderr}"
+    );
+    assert!(!worktree_path.exists());
+
+    let content = repo.git_output(&["show", "refs/wt-backup/feature:wip.txt"]);
+    assert_eq!(content, "work in progress");
+}
+
+#[rstest]
+fn test_remove_force_dirty_keeps_earlier_backups(repo: TestRepo) {
+    let worktree_path = repo.root_path().parent().unwrap().join("repo.feature");
+    for content in ["first attempt", "second attempt"] {
+        // `-B` recreates the branch whether or not the last removal deleted it
+        repo.run_git(&[
+            "worktree",
+            "add",
+            "-B",
+            "feature",
+            worktree_path.to_str().unwrap(),
+        ]);
+        std::fs::write(worktree_path.join("wip.txt"), content).unwrap();
+        let output = repo
+            .wt_command()
+            .args(["remove", "--force-dirty", "--foreground", "feature"])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "stderr: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    // The latest backup is the ref; the earlier one stays in its reflog
+    assert_eq!(
+        repo.git_output(&["show", "refs/wt-backup/feature:wip.txt"]),
+        "second attempt"
+    );
+    assert_eq!(
+        repo.git_output(&["show", "refs/wt-backup/feature@{1}:wip.txt"]),
+        "first attempt"
+    );
+}
+
+#[rstest]
+fn test_remove_locked_worktree_with_unlock(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("locked-feature");
+    repo.lock_worktree("locked-feature", Some("Testing lock"));
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--unlock", "--foreground", "locked-feature"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(!worktree_path.exists());
+}
+
+/// Validation runs before the unlock prompt, so a removal that fails anyway
+/// doesn't ask.
+#[rstest]
+fn test_remove_locked_dirty_worktree_does_not_offer_unlock(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("locked-feature");
+    repo.lock_worktree("locked-feature", Some("Testing lock"));
+    std::fs::write(worktree_path.join("wip.txt"), "work in progress").unwrap();
+    repo.run_git(&["-C", worktree_path.to_str().unwrap(), "add", "wip.txt"]);
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "locked-feature"])
+        .env("WORKTRUNK_ASSUME_YES", "1")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(!stderr.contains("is locked"), "stderr: {stderr}");
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(worktrees.contains("locked"), "worktrees: {worktrees}");
+}
+
+/// Hold `wt.lock` as if another `wt` process were mid-operation.
+fn hold_operation_lock(repo: &TestRepo) -> std::fs::File {
+    let path = repo.root_path().join(".git/wt.lock");
+    std::fs::write(&path, "4242\nwt switch --create other\n").unwrap();
+    let file = std::fs::File::open(&path).unwrap();
+    file.lock().unwrap();
+    file
+}
+
+#[rstest]
+fn test_remove_blocked_by_operation_lock(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    let _lock = hold_operation_lock(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "feature"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Another wt operation is in progress"),
+        "stderr: {stderr}"
+    );
+    assert!(stderr.contains("pid 4242"), "stderr: {stderr}");
+    assert!(stderr.contains("--wait"), "stderr: {stderr}");
+    assert!(worktree_path.exists());
+}
+
+#[rstest]
+fn test_remove_waits_for_operation_lock(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("feature");
+    let lock = hold_operation_lock(&repo);
+
+    let release = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_secs(1));
+        drop(lock);
+    });
+    let output = repo
+        .wt_command()
+        .args(["remove", "--foreground", "--wait", "feature"])
+        .output()
+        .unwrap();
+    release.join().unwrap();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(!worktree_path.exists());
+}
+
+#[rstest]
+fn test_auto_integrated_removes_merged_worktrees(mut repo: TestRepo) {
+    let merged = repo.add_worktree("merged");
+    repo.commit_in_worktree(&merged, "fix.txt", "fix", "Fix parser");
+    repo.run_git(&["merge", "--no-ff", "-m", "Merge merged", "merged"]);
+    let unmerged = repo.add_worktree("unmerged");
+    repo.commit_in_worktree(&unmerged, "wip.txt", "wip", "WIP");
+
+    // Off by default
+    let output = repo.wt_command().arg("list").output().unwrap();
+    assert!(output.status.success());
+    assert!(merged.exists());
+
+    repo.write_test_config("[remove]\nauto-integrated = \"auto\"\n");
+    let output = repo.wt_command().arg("list").output().unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(!merged.exists(), "stderr: {stderr}");
+    assert!(unmerged.exists());
+    assert!(
+        !repo
+            .git_output(&["branch", "--list", "merged"])
+            .contains("merged")
+    );
+}
+
+/// The lock is only released once the removal can't be stopped, so a refused
+/// removal leaves it in place.
+#[rstest]
+fn test_remove_unlock_keeps_lock_when_blocked(mut repo: TestRepo) {
+    let worktree_path = repo.add_worktree("locked-feature");
+    repo.lock_worktree("locked-feature", Some("Testing lock"));
+    let _lock = hold_operation_lock(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["remove", "--unlock", "--foreground", "locked-feature"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(worktree_path.exists());
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(
+        worktrees.contains("locked Testing lock"),
+        "worktrees: {worktrees}"
+    );
+}
@@ -0,0 +1,53 @@
+//! Integration tests for `wt report`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn report_json(repo: &TestRepo, args: &[&str]) -> serde_json::Value {
+    let output = repo
+        .wt_command()
+        .args(["report", "--format=json"])
+        .args(args)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    serde_json::from_slice(&output.stdout).unwrap()
+}
+
+fn branches(section: &serde_json::Value) -> Vec<&str> {
+    section
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry["branch"].as_str().unwrap())
+        .collect()
+}
+
+#[rstest]
+fn test_report_integrated_and_behind(mut repo: TestRepo) {
+    repo.add_feature();
+    repo.run_git(&["merge", "--ff-only", "feature"]);
+    repo.commit("Move main past feature");
+
+    let report = report_json(&repo, &["--behind", "0"]);
+    assert_eq!(branches(&report["integrated"]), ["feature"]);
+    assert_eq!(report["integrated"][0]["reason"], "ancestor");
+    assert_eq!(branches(&report["behind"]), ["feature"]);
+    assert_eq!(report["behind"][0]["behind"], 1);
+    assert_eq!(report["disk"].as_array().unwrap().len(), 2);
+}
+
+#[rstest]
+fn test_report_stale_after(mut repo: TestRepo) {
+    repo.add_feature();
+
+    // Commits are dated a day before SOURCE_DATE_EPOCH
+    let report = report_json(&repo, &[]);
+    assert!(report["stale"].as_array().unwrap().is_empty());
+    let report = report_json(&repo, &["--stale-after", "1h"]);
+    assert_eq!(branches(&report["stale"]), ["feature"]);
+}
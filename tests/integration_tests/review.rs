@@ -0,0 +1,112 @@
+//! Integration tests for `wt review`
+
+use std::path::PathBuf;
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+fn wt(repo: &TestRepo, dir: &std::path::Path, args: &[&str]) -> std::process::Output {
+    repo.wt_command()
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap()
+}
+
+fn worktree_for(repo: &TestRepo, branch: &str) -> Option<PathBuf> {
+    let list = repo.git_output(&["worktree", "list", "--porcelain"]);
+    let mut path = None;
+    for line in list.lines() {
+        if let Some(p) = line.strip_prefix("worktree ") {
+            path = Some(PathBuf::from(p));
+        } else if line == format!("branch refs/heads/{branch}") {
+            return path;
+        }
+    }
+    None
+}
+
+#[rstest]
+fn test_review_blocks_commits_until_released(repo: TestRepo) {
+    repo.create_branch("feature");
+
+    let output = wt(
+        &repo,
+        repo.root_path(),
+        &["review", "feature", "--read-only"],
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("for review"), "stderr: {stderr}");
+
+    let path = worktree_for(&repo, "feature").expect("review worktree");
+    let file = path.join("file.txt");
+    assert!(std::fs::metadata(&file).unwrap().permissions().readonly());
+
+    let output = wt(&repo, &path, &["step", "commit"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("checked out for review"),
+        "stderr: {stderr}"
+    );
+
+    let output = wt(&repo, &path, &["review", "--release", "feature"]);
+    assert!(output.status.success());
+    assert!(!std::fs::metadata(&file).unwrap().permissions().readonly());
+    assert!(
+        repo.git_command()
+            .args(["config", "worktrunk.state.feature.review"])
+            .output()
+            .unwrap()
+            .stdout
+            .is_empty()
+    );
+}
+
+#[rstest]
+fn test_review_prunes_merged_reviews(repo: TestRepo) {
+    repo.create_branch("merged");
+    repo.create_branch("other");
+
+    let output = wt(&repo, repo.root_path(), &["review", "merged"]);
+    assert!(output.status.success());
+    let path = worktree_for(&repo, "merged").expect("review worktree");
+    repo.commit_in_worktree(&path, "fix.txt", "fix", "Fix parser");
+    repo.run_git(&["merge", "--no-ff", "-m", "Merge merged", "merged"]);
+
+    let output = wt(&repo, repo.root_path(), &["review", "other"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Removed review worktree for merged"),
+        "stderr: {stderr}"
+    );
+    assert!(!path.exists());
+    assert!(worktree_for(&repo, "other").is_some());
+}
+
+/// A merged review that can't be removed is reported, and `wt review` goes on.
+#[rstest]
+fn test_review_prune_failure_warns(repo: TestRepo) {
+    repo.create_branch("merged");
+    repo.create_branch("other");
+
+    let output = wt(&repo, repo.root_path(), &["review", "merged"]);
+    assert!(output.status.success());
+    let path = worktree_for(&repo, "merged").expect("review worktree");
+    repo.commit_in_worktree(&path, "fix.txt", "fix", "Fix parser");
+    repo.run_git(&["merge", "--no-ff", "-m", "Merge merged", "merged"]);
+    // git refuses to remove a locked worktree without --force twice
+    repo.run_git(&["worktree", "lock", path.to_str().unwrap()]);
+
+    let output = wt(&repo, repo.root_path(), &["review", "other"]);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Failed to remove review worktree for merged"),
+        "stderr: {stderr}"
+    );
+    assert!(path.exists());
+    assert!(worktree_for(&repo, "other").is_some());
+}
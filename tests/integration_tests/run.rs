@@ -0,0 +1,115 @@
+//! Integration tests for `wt run`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_run_profile(repo: TestRepo) {
+    repo.write_project_config(
+        r#"
+[profiles.hello]
+command = "echo hello from {{ branch }} > run.txt"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["run", "hello", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let contents = std::fs::read_to_string(repo.root_path().join("run.txt")).unwrap();
+    assert_eq!(contents.trim(), "hello from main");
+}
+
+#[rstest]
+fn test_run_unknown_profile(repo: TestRepo) {
+    repo.write_project_config(
+        r#"
+[profiles.test]
+command = "true"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["run", "lint", "--yes"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("available: test"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_run_propagates_exit_code(repo: TestRepo) {
+    repo.write_project_config(
+        r#"
+[profiles.fail]
+command = "exit 3"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["run", "fail", "--yes"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(3));
+}
+
+#[rstest]
+fn test_for_each_profile(mut repo: TestRepo) {
+    repo.add_worktree("feature");
+    repo.write_project_config(
+        r#"
+[profiles.mark]
+command = "touch profile-ran"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["step", "for-each", "--profile", "mark", "--yes"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(repo.root_path().join("profile-ran").exists());
+    assert!(repo.worktrees["feature"].join("profile-ran").exists());
+}
+
+#[rstest]
+fn test_run_with_repeated_c_flag(repo: TestRepo) {
+    repo.write_project_config(
+        r#"
+[profiles.hello]
+command = "echo hello > run.txt"
+"#,
+    );
+
+    // Each -C is relative to the previous one, like git
+    let root = repo.root_path();
+    let mut cmd = repo.wt_command();
+    cmd.arg("-C")
+        .arg(root.parent().unwrap())
+        .arg("-C")
+        .arg(root.file_name().unwrap())
+        .args(["run", "hello", "--yes"])
+        .current_dir(std::env::temp_dir());
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    // The profile runs in the -C worktree, not the process's directory
+    assert!(root.join("run.txt").exists());
+}
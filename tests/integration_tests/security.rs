@@ -83,7 +83,7 @@
 //! 1. **Simpler parsing**: Just source a file, no command substitution needed
 //! 2. **Channel separation**: Messages on stderr, directives in temp file
 //! 3. **Standard escaping**: Uses well-understood POSIX single-quote escaping
-//! 4. **Smaller attack surface**: Only cd and exec commands in directive file
+//! 4. **Smaller attack surface**: Only cd, exec, and `WT_*` export commands in directive file
 //!
 //! ### Testing Limitations
 //!
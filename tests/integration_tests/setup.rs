@@ -0,0 +1,16 @@
+//! Integration tests for `wt setup`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+
+#[rstest]
+fn test_setup_requires_terminal(repo: TestRepo) {
+    let output = repo.wt_command().arg("setup").output().unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("wt setup is interactive"),
+        "stderr: {stderr}"
+    );
+    assert!(stderr.contains("wt config create"), "stderr: {stderr}");
+}
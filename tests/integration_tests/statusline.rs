@@ -121,6 +121,27 @@ fn test_statusline_commits_ahead(mut repo: TestRepo) {
     assert_snapshot!(output, @"[0m feature  [2m↑[22m  [32m↑2[0m  ^[32m+2");
 }
 
+// --- Multiplexer Format Tests ---
+
+#[rstest]
+fn test_statusline_format_tmux(repo: TestRepo) {
+    let output = run_statusline(&repo, &["--format=tmux"], None);
+    assert_snapshot!(output, @"main  #[dim]^#[nobold,nodim]#[dim]|#[nobold,nodim]");
+}
+
+#[rstest]
+fn test_statusline_format_reads_cache(repo: TestRepo) {
+    // First call computes and caches the status
+    run_statusline(&repo, &["--format=zellij"], None);
+    let cache_path = repo.root_path().join(".git/wt-statusline");
+    assert!(cache_path.exists());
+
+    // Later calls print the cached status, converted to the requested format
+    std::fs::write(&cache_path, "cached \x1b[32m+1\x1b[39m").unwrap();
+    let output = run_statusline(&repo, &["--format=tmux"], None);
+    assert_snapshot!(output, @"cached #[fg=green]+1#[fg=default]");
+}
+
 // --- Claude Code Mode Tests ---
 
 /// Create snapshot settings that normalize path output for statusline tests.
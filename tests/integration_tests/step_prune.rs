@@ -0,0 +1,75 @@
+//! Integration tests for `wt step prune`
+
+use crate::common::{TestRepo, repo};
+use rstest::rstest;
+use std::fs;
+
+#[rstest]
+fn test_prune_nothing_to_prune(repo: TestRepo) {
+    let output = repo.wt_command().args(["step", "prune"]).output().unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Nothing to prune"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_prune_stale_metadata_and_orphans(mut repo: TestRepo) {
+    // Stale metadata: directory deleted behind git's back
+    let stale_path = repo.add_worktree("stale");
+    fs::remove_dir_all(&stale_path).unwrap();
+
+    // Orphaned directory: git's record removed, directory left behind
+    let orphan_path = repo.add_worktree("orphan");
+    fs::create_dir_all(orphan_path.join("target")).unwrap();
+    fs::remove_dir_all(repo.root_path().join(".git/worktrees/repo.orphan")).unwrap();
+
+    // Matches the template but was never a worktree
+    let unrelated_path = repo.root_path().parent().unwrap().join("repo.notes");
+    fs::create_dir_all(&unrelated_path).unwrap();
+
+    // Without --yes in a non-interactive session, nothing is removed
+    let output = repo
+        .wt_command()
+        .args(["step", "prune", "--orphans"])
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("stale metadata"), "stderr: {stderr}");
+    assert!(stderr.contains("orphaned directory"), "stderr: {stderr}");
+    assert!(stderr.contains("never worktrees"), "stderr: {stderr}");
+    assert!(orphan_path.exists());
+
+    let output = repo
+        .wt_command()
+        .args(["step", "prune", "--orphans", "--yes"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Pruned 2 entries"), "stderr: {stderr}");
+    assert!(!orphan_path.exists());
+    // `--yes` doesn't extend to directories without worktree evidence
+    assert!(stderr.contains("Kept 1 directory"), "stderr: {stderr}");
+    assert!(unrelated_path.exists());
+
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(!worktrees.contains("prunable"), "worktrees: {worktrees}");
+}
+
+#[rstest]
+fn test_prune_assume_yes_keeps_unverified_directories(repo: TestRepo) {
+    let unrelated_path = repo.root_path().parent().unwrap().join("repo.notes");
+    fs::create_dir_all(&unrelated_path).unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["step", "prune", "--orphans", "--yes"])
+        .env("WORKTRUNK_ASSUME_YES", "1")
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Kept 1 directory"), "stderr: {stderr}");
+    assert!(unrelated_path.exists());
+}
@@ -3,6 +3,7 @@ use crate::common::{
     set_temp_home_env, setup_home_snapshot_settings, setup_snapshot_settings, temp_home,
     wt_command,
 };
+use ansi_str::AnsiStr;
 use insta_cmd::assert_cmd_snapshot;
 use rstest::rstest;
 use std::fs;
@@ -640,6 +641,59 @@ fn test_switch_previous_branch_no_history(repo: TestRepo) {
     snapshot_switch("switch_previous_branch_no_history", &repo, &["-"]);
 }
 
+#[rstest]
+fn test_switch_history_stack(mut repo: TestRepo) {
+    let path_a = repo.add_worktree("hist-a");
+    let path_b = repo.add_worktree("hist-b");
+
+    // main -> hist-a -> hist-b leaves [hist-a, main] behind hist-b
+    for (cwd, target) in [(repo.root_path(), "hist-a"), (path_a.as_path(), "hist-b")] {
+        let output = repo
+            .wt_command()
+            .args(["switch", target])
+            .current_dir(cwd)
+            .output()
+            .unwrap();
+        assert!(output.status.success());
+    }
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--history"])
+        .current_dir(&path_b)
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success());
+    assert_eq!(stdout.trim(), "-1\thist-a\n-2\tmain");
+    // Stored as a single value, so a switch writes it with one git call
+    assert_eq!(
+        repo.git_output(&["config", "--get-all", "worktrunk.history"]),
+        "hist-a main"
+    );
+
+    // -2 goes two worktrees back
+    let output = repo
+        .wt_command()
+        .args(["switch", "-2"])
+        .current_dir(&path_b)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("main"), "stderr: {stderr}");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "-9"])
+        .current_dir(&path_b)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("switch history"), "stderr: {stderr}");
+}
+
 #[rstest]
 fn test_switch_main_branch(repo: TestRepo) {
     // Create a feature branch (use unique name to avoid fixture conflicts)
@@ -1259,6 +1313,59 @@ fn test_switch_create_no_hint_with_custom_worktree_path(repo: TestRepo) {
     );
 }
 
+#[rstest]
+fn test_switch_unique_substring_match(repo: TestRepo) {
+    repo.run_git(&["branch", "fuzzy-login-page"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "login"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("switching to") && stderr.contains("fuzzy-login-page"),
+        "stderr: {stderr}"
+    );
+    let worktrees = repo.git_output(&["worktree", "list"]);
+    assert!(worktrees.contains("[fuzzy-login-page]"), "{worktrees}");
+}
+
+#[rstest]
+fn test_switch_ambiguous_match_suggests(repo: TestRepo) {
+    repo.run_git(&["branch", "fuzzy-login"]);
+    repo.run_git(&["branch", "fuzzy-login-page"]);
+
+    // Non-interactive: no chooser, the error lists the candidates
+    let output = repo
+        .wt_command()
+        .args(["switch", "login"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("Did you mean one of:"), "stderr: {stderr}");
+    assert!(stderr.contains("fuzzy-login-page"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_switch_typo_suggests(repo: TestRepo) {
+    repo.run_git(&["branch", "fuzzy-feature"]);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "fuzzy-feautre"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("Did you mean") && stderr.contains("wt switch fuzzy-feature"),
+        "stderr: {stderr}"
+    );
+}
+
 // ============================================================================
 // PR Syntax Tests (pr:<number>)
 // ============================================================================
@@ -1683,3 +1790,530 @@ fn test_switch_pr_empty_branch(#[from(repo_with_remote)] repo: TestRepo) {
         assert_cmd_snapshot!("switch_pr_empty_branch", cmd);
     });
 }
+
+#[rstest]
+fn test_switch_create_applies_branch_prefix(repo: TestRepo) {
+    repo.write_project_config("[branch]\nprefix = \"{user}/\"\n");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "login-fix"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("test/login-fix"), "stderr: {stderr}");
+    repo.run_git(&["rev-parse", "--verify", "refs/heads/test/login-fix"]);
+
+    // The short name resolves to the prefixed branch
+    let output = repo
+        .wt_command()
+        .args(["switch", "login-fix"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("test/login-fix"), "stderr: {stderr}");
+
+    // An already-prefixed name isn't prefixed twice
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "test/other"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    repo.run_git(&["rev-parse", "--verify", "refs/heads/test/other"]);
+}
+
+#[rstest]
+fn test_switch_create_applies_branch_rule(repo: TestRepo) {
+    repo.create_branch("release");
+    repo.commit("Advance main");
+    repo.write_project_config(
+        r#"[[rules]]
+pattern = "hotfix/*"
+base = "release"
+worktree-path = "../hotfixes/{{ branch | sanitize }}"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "hotfix/login"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert_eq!(
+        repo.git_output(&["rev-parse", "hotfix/login"]),
+        repo.git_output(&["rev-parse", "release"])
+    );
+    assert!(repo.root_path().join("../hotfixes/hotfix-login").is_dir());
+
+    // Other branches keep the default base and path
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        repo.git_output(&["rev-parse", "feature"]),
+        repo.git_output(&["rev-parse", "main"])
+    );
+}
+
+/// Project config can't place worktrees outside the user's worktree directory.
+#[rstest]
+fn test_switch_create_rejects_rule_path_outside_worktree_dir(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[[rules]]
+pattern = "hotfix/*"
+worktree-path = "../../escaped/{{ branch | sanitize }}"
+"#,
+    );
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "hotfix/login"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("[[rules]] worktree-path"),
+        "stderr: {stderr}"
+    );
+    assert!(!repo.root_path().join("../../escaped").exists());
+}
+
+#[rstest]
+fn test_switch_create_from_template(repo: TestRepo) {
+    repo.write_project_config("[templates.docs]\npath = \"templates/docs\"\n");
+    let template_dir = repo.root_path().join("templates/docs/guide");
+    std::fs::create_dir_all(&template_dir).unwrap();
+    std::fs::write(template_dir.join("index.md"), "# Guide\n").unwrap();
+
+    let output = repo
+        .wt_command()
+        .args([
+            "switch",
+            "--create",
+            "docs-guide",
+            "--from-template",
+            "docs",
+        ])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s", "docs-guide"]),
+        "Scaffold from template docs"
+    );
+    assert_eq!(
+        repo.git_output(&["show", "docs-guide:guide/index.md"]),
+        "# Guide"
+    );
+
+    // Unknown templates fail before the worktree is created
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "other", "--from-template", "blog"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(stderr.contains("available: docs"), "stderr: {stderr}");
+    let other = repo
+        .git_command()
+        .args(["rev-parse", "--verify", "refs/heads/other"])
+        .output()
+        .unwrap();
+    assert!(!other.status.success());
+}
+
+#[rstest]
+fn test_switch_create_all(repo: TestRepo) {
+    repo.write_project_config("post-create = \"echo created > marker.txt\"\n");
+    for branch in ["review/one", "review/two", "other"] {
+        repo.create_branch(branch);
+    }
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create-all", "review/*", "--yes"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.matches("created").count(), 2, "stdout: {stdout}");
+
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    let paths: Vec<&str> = worktrees
+        .lines()
+        .filter_map(|line| line.strip_prefix("worktree "))
+        .collect();
+    for branch in ["review/one", "review/two"] {
+        assert!(
+            worktrees.contains(&format!("branch refs/heads/{branch}")),
+            "{worktrees}"
+        );
+    }
+    assert!(!worktrees.contains("refs/heads/other"));
+    let markers = paths
+        .iter()
+        .filter(|path| std::path::Path::new(path).join("marker.txt").exists())
+        .count();
+    assert_eq!(markers, 2);
+
+    // Running again leaves the existing worktrees alone
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create-all", "review/*", "--yes"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(stdout.matches("exists").count(), 2, "stdout: {stdout}");
+}
+
+/// Hooks run in parallel, but each worktree's output is printed as one block.
+#[rstest]
+fn test_switch_create_all_groups_hook_output(repo: TestRepo) {
+    repo.write_project_config(
+        "post-create = \"echo start-{{ branch }}; sleep 0.2; echo end-{{ branch }}\"\n",
+    );
+    for branch in ["review/one", "review/two"] {
+        repo.create_branch(branch);
+    }
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create-all", "review/*", "--yes"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+
+    let lines: Vec<&str> = stderr
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.starts_with("start-") || line.starts_with("end-"))
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            "start-review/one",
+            "end-review/one",
+            "start-review/two",
+            "end-review/two"
+        ],
+        "stderr: {stderr}"
+    );
+    let log = repo
+        .root_path()
+        .join(".git/wt-logs/review-one-post-create.log");
+    assert!(
+        fs::read_to_string(&log).unwrap().contains("end-review/one"),
+        "{}",
+        log.display()
+    );
+}
+
+/// Each matching branch's `[[rules]]` commands need approval before they run.
+#[rstest]
+fn test_switch_create_all_approves_rule_commands(repo: TestRepo) {
+    repo.write_project_config(
+        r#"[[rules]]
+pattern = "review/*"
+post-create = "echo rule > rule-marker.txt"
+"#,
+    );
+    repo.create_branch("review/one");
+
+    // Without a terminal the approval prompt can't be answered
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create-all", "review/*"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success());
+    assert!(
+        stderr.contains("echo rule > rule-marker.txt"),
+        "stderr: {stderr}"
+    );
+    let worktrees = repo.git_output(&["worktree", "list", "--porcelain"]);
+    assert!(!worktrees.contains("refs/heads/review/one"), "{worktrees}");
+}
+
+#[rstest]
+fn test_switch_to_detached_worktree_by_dir_name(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    repo.detach_head_in_worktree("feature");
+
+    // No branch names the worktree anymore, so its directory name reaches it
+    let dir_name = feature_path.file_name().unwrap().to_str().unwrap();
+    let output = repo
+        .wt_command()
+        .args(["switch", dir_name])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("detached HEAD"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_switch_reattach_fast_forwards(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+    repo.detach_head_in_worktree("feature");
+    repo.commit_in_worktree(&feature_path, "detached.txt", "work", "Work while detached");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--reattach", "feature"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    // Back on the branch, which now includes the detached commit
+    let branch = repo
+        .git_command()
+        .args(["branch", "--show-current"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&branch.stdout).trim(), "feature");
+    let subject = repo
+        .git_command()
+        .args(["log", "-1", "--format=%s", "feature"])
+        .output()
+        .unwrap();
+    assert_eq!(
+        String::from_utf8_lossy(&subject.stdout).trim(),
+        "Work while detached"
+    );
+}
+
+#[rstest]
+fn test_switch_reattach_requires_detached(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--reattach", "main"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("not a detached HEAD"), "stderr: {stderr}");
+}
+
+/// Put `feature` mid-rebase onto `main` with a conflict
+fn start_conflicting_rebase(repo: &mut TestRepo) -> std::path::PathBuf {
+    let feature_path = repo.add_worktree("feature");
+    repo.commit_in_worktree(&feature_path, "shared.txt", "feature", "Feature side");
+    repo.commit_in_worktree(repo.root_path(), "shared.txt", "main", "Main side");
+    let rebase = repo
+        .git_command()
+        .args(["rebase", "main"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert!(
+        !rebase.status.success(),
+        "rebase should stop on the conflict"
+    );
+    feature_path
+}
+
+#[rstest]
+fn test_switch_refuses_to_leave_pending_rebase(mut repo: TestRepo) {
+    let feature_path = start_conflicting_rebase(&mut repo);
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "main"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("rebase is in progress"), "stderr: {stderr}");
+    assert!(stderr.contains("git rebase --abort"), "stderr: {stderr}");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--force", "main"])
+        .current_dir(&feature_path)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[rstest]
+fn test_switch_create_sets_upstream(#[from(repo_with_remote)] repo: TestRepo) {
+    repo.write_test_config("[switch]\nset-upstream = true\n");
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(
+        repo.git_output(&["config", "branch.feature.remote"]),
+        "origin"
+    );
+    assert_eq!(
+        repo.git_output(&["config", "branch.feature.merge"]),
+        "refs/heads/feature"
+    );
+
+    // Not pushed yet: shown apart from both "no upstream" and "in sync"
+    let output = repo
+        .wt_command()
+        .args(["list", "--format=json"])
+        .output()
+        .unwrap();
+    let json: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let feature = json.iter().find(|w| w["branch"] == "feature").unwrap();
+    assert!(
+        feature["symbols"].as_str().unwrap().contains('⇈'),
+        "{feature}"
+    );
+}
+
+/// `{{ branch | sanitize }}` keeps wide characters but drops characters that
+/// are invalid in filenames on some platform.
+#[rstest]
+fn test_switch_create_sanitizes_path(repo: TestRepo) {
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "機能/a|b"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let repo_name = repo.root_path().file_name().unwrap().to_str().unwrap();
+    let expected_path = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join(format!("{repo_name}.機能-a-b"));
+    assert!(expected_path.exists(), "{}", expected_path.display());
+}
+
+/// A `.git/info/exclude` that can't be updated is a warning: the worktree exists.
+#[rstest]
+fn test_switch_create_exclude_failure_warns(repo: TestRepo) {
+    repo.write_test_config("[switch]\nexclude = [\"*.log\"]\n");
+    let exclude = repo.root_path().join(".git/info/exclude");
+    let _ = std::fs::remove_file(&exclude);
+    std::fs::create_dir_all(&exclude).unwrap();
+
+    let output = repo
+        .wt_command()
+        .args(["switch", "--create", "feature"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "{stderr}");
+    assert!(stderr.contains("Failed to add"), "{stderr}");
+}
+
+/// `[switch] exclude` adds patterns to `.git/info/exclude` once, so files that
+/// hooks generate don't show as untracked in the new worktree.
+#[rstest]
+fn test_switch_create_adds_excludes(repo: TestRepo) {
+    repo.write_test_config("[switch]\nexclude = [\"*.log\", \".wt-state\"]\n");
+
+    for branch in ["feature", "other"] {
+        let output = repo
+            .wt_command()
+            .args(["switch", "--create", branch])
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "{}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    let exclude = std::fs::read_to_string(repo.root_path().join(".git/info/exclude")).unwrap();
+    assert_eq!(exclude.matches("*.log").count(), 1, "{exclude}");
+    assert_eq!(exclude.matches(".wt-state").count(), 1, "{exclude}");
+
+    let repo_name = repo.root_path().file_name().unwrap().to_str().unwrap();
+    let worktree = repo
+        .root_path()
+        .parent()
+        .unwrap()
+        .join(format!("{repo_name}.feature"));
+    std::fs::write(worktree.join("dev.log"), "started\n").unwrap();
+    let output = repo
+        .git_command()
+        .args(["status", "--porcelain"])
+        .current_dir(&worktree)
+        .output()
+        .unwrap();
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "");
+}
+
+/// `-C` counts as being in that directory, like git's.
+#[rstest]
+fn test_switch_already_at_with_directory_flag(mut repo: TestRepo) {
+    let feature_path = repo.add_worktree("feature");
+
+    let output = repo
+        .wt_command()
+        .arg("-C")
+        .arg(&feature_path)
+        .args(["switch", "feature"])
+        .current_dir(repo.root_path())
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr
+            .ansi_strip()
+            .contains("Already on worktree for feature"),
+        "stderr: {stderr}"
+    );
+}
@@ -0,0 +1,127 @@
+//! Integration tests for `wt tag`
+
+use crate::common::{TestRepo, repo_with_remote};
+use rstest::rstest;
+
+#[rstest]
+fn test_tag_merged_release_branch(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let release_wt = repo.add_worktree_with_commit("release", "CHANGELOG.md", "1.0", "Prepare 1.0");
+    repo.run_git(&["merge", "--ff-only", "release"]);
+
+    let output = repo
+        .wt_command()
+        .args(["tag", "v1.0", "--yes"])
+        .current_dir(&release_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    assert_eq!(
+        repo.git_output(&["rev-parse", "v1.0^{commit}"]),
+        repo.git_output(&["rev-parse", "main"])
+    );
+    assert_eq!(repo.git_output(&["cat-file", "-t", "v1.0"]), "tag");
+    assert!(
+        repo.git_output(&["ls-remote", "--tags", "origin", "v1.0"])
+            .contains("v1.0")
+    );
+    assert!(!release_wt.exists(), "release worktree should be removed");
+}
+
+#[rstest]
+fn test_tag_refuses_unmerged_branch(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let release_wt = repo.add_worktree_with_commit("release", "CHANGELOG.md", "1.0", "Prepare 1.0");
+
+    let output = repo
+        .wt_command()
+        .args(["tag", "v1.0", "--no-push"])
+        .current_dir(&release_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("isn't merged into"), "stderr: {stderr}");
+    assert!(
+        repo.git_command()
+            .args(["rev-parse", "--verify", "--quiet", "refs/tags/v1.0"])
+            .output()
+            .unwrap()
+            .stdout
+            .is_empty()
+    );
+}
+
+/// After a squash merge, the tag goes on the squash commit, not on commits that
+/// landed on the target later.
+#[rstest]
+fn test_tag_squash_merged_branch(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let release_wt = repo.add_worktree_with_commit("release", "CHANGELOG.md", "1.0", "Prepare 1.0");
+    repo.run_git(&["merge", "--squash", "release"]);
+    repo.run_git(&["commit", "-m", "Release 1.0"]);
+    let squash = repo.git_output(&["rev-parse", "HEAD"]);
+    repo.commit_in_worktree(repo.root_path(), "next.txt", "next", "Start 1.1");
+
+    let output = repo
+        .wt_command()
+        .args(["tag", "v1.0", "--no-push", "--no-remove"])
+        .current_dir(&release_wt)
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert_eq!(repo.git_output(&["rev-parse", "v1.0^{commit}"]), squash);
+}
+
+/// A failed push doesn't leave the local tag behind, so the command can be rerun.
+#[rstest]
+fn test_tag_push_failure_deletes_tag(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let release_wt = repo.add_worktree_with_commit("release", "CHANGELOG.md", "1.0", "Prepare 1.0");
+    repo.run_git(&["merge", "--ff-only", "release"]);
+    repo.run_git(&["remote", "set-url", "origin", "/nonexistent/remote.git"]);
+
+    let output = repo
+        .wt_command()
+        .args(["tag", "v1.0", "--yes"])
+        .current_dir(&release_wt)
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(
+        repo.git_command()
+            .args(["rev-parse", "--verify", "--quiet", "refs/tags/v1.0"])
+            .output()
+            .unwrap()
+            .stdout
+            .is_empty()
+    );
+    assert!(release_wt.exists(), "worktree should be kept");
+}
+
+#[rstest]
+fn test_tag_refuses_invalid_tag_name(#[from(repo_with_remote)] mut repo: TestRepo) {
+    // Unmerged, so a later check would fail too; the name is checked first
+    let release_wt = repo.add_worktree_with_commit("release", "CHANGELOG.md", "1.0", "Prepare 1.0");
+
+    for version in ["v1..0", "-v1"] {
+        let output = repo
+            .wt_command()
+            .args(["tag", "--yes", "--", version])
+            .current_dir(&release_wt)
+            .output()
+            .unwrap();
+        assert!(!output.status.success());
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        assert!(
+            stderr.contains("isn't a valid tag name"),
+            "stderr: {stderr}"
+        );
+    }
+    assert_eq!(repo.git_output(&["tag", "--list"]), "");
+}
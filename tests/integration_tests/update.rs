@@ -0,0 +1,195 @@
+use crate::common::{TestRepo, repo_with_remote};
+use rstest::rstest;
+use std::fs;
+
+/// Add a commit to the remote's `main` that neither the local `main` nor the
+/// last fetch has seen.
+fn advance_remote_main(repo: &TestRepo) {
+    advance_remote_main_with(repo, "upstream.txt", "upstream");
+}
+
+/// Like [`advance_remote_main`], writing `content` to `filename`.
+fn advance_remote_main_with(repo: &TestRepo, filename: &str, content: &str) {
+    repo.commit_in_worktree(repo.root_path(), filename, content, "Upstream change");
+    repo.run_git(&["push", "origin", "main"]);
+    repo.run_git(&["reset", "--hard", "HEAD~1"]);
+    repo.run_git(&["update-ref", "refs/remotes/origin/main", "main"]);
+}
+
+#[rstest]
+fn test_update_rebases_onto_fetched_default_branch(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+    advance_remote_main(&repo);
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+    // Uncommitted work survives the update
+    fs::write(feature_wt.join("test.txt"), "edited").unwrap();
+
+    let output = repo
+        .wt_command()
+        .arg("update")
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("1 ahead, 0 behind"), "stderr: {stderr}");
+
+    assert_eq!(
+        repo.git_output(&["rev-list", "--count", "feature..origin/main"]),
+        "0"
+    );
+    assert_eq!(
+        repo.git_output(&["log", "-1", "--format=%s", "feature"]),
+        "Add test file"
+    );
+    assert_eq!(
+        fs::read_to_string(feature_wt.join("test.txt")).unwrap(),
+        "edited"
+    );
+    // The local default branch isn't moved
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+
+    // A second run, by branch name and merging, has nothing to do
+    let output = repo
+        .wt_command()
+        .args(["update", "feature", "--strategy", "merge"])
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("up to date"), "stderr: {stderr}");
+}
+
+#[rstest]
+fn test_update_fast_forwards_clean_worktrees(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.write_test_config("[fetch]\nfast-forward-clean = true\n");
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+    let docs_wt = repo.add_worktree("docs");
+    let scratch_wt = repo.add_worktree("scratch");
+    fs::write(scratch_wt.join("notes.txt"), "wip").unwrap();
+    // Tracks its own remote branch, so its next push would publish main's commits
+    repo.add_worktree("release");
+    repo.run_git(&["push", "--set-upstream", "origin", "release"]);
+    let main_before = repo.git_output(&["rev-parse", "main"]);
+    advance_remote_main(&repo);
+
+    let output = repo
+        .wt_command()
+        .arg("update")
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Fast-forwarded"), "stderr: {stderr}");
+
+    let remote_main = repo.git_output(&["rev-parse", "origin/main"]);
+    // Clean and strictly behind: moved
+    assert_eq!(repo.git_output(&["rev-parse", "docs"]), remote_main);
+    assert!(docs_wt.join("upstream.txt").exists());
+    // Untracked files count as changes: left alone
+    assert_ne!(repo.git_output(&["rev-parse", "scratch"]), remote_main);
+    // Other upstreams and the default branch itself: left alone
+    assert_ne!(repo.git_output(&["rev-parse", "release"]), remote_main);
+    assert_eq!(repo.git_output(&["rev-parse", "main"]), main_before);
+}
+
+#[rstest]
+fn test_update_merge_strategy(#[from(repo_with_remote)] mut repo: TestRepo) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+    advance_remote_main(&repo);
+
+    let output = repo
+        .wt_command()
+        .args(["update", "--strategy", "merge"])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains("Merged"), "stderr: {stderr}");
+
+    // The branch's own commit is kept, with a merge commit on top
+    assert_eq!(
+        repo.git_output(&["rev-list", "--count", "feature..origin/main"]),
+        "0"
+    );
+    assert_eq!(
+        repo.git_output(&["rev-list", "--count", "--merges", "main..feature"]),
+        "1"
+    );
+    assert!(feature_wt.join("upstream.txt").exists());
+}
+
+#[rstest]
+#[case::rebase("rebase", "Rebase onto")]
+#[case::merge("merge", "Merge of")]
+fn test_update_conflict(
+    #[from(repo_with_remote)] mut repo: TestRepo,
+    #[case] strategy: &str,
+    #[case] expected: &str,
+) {
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "feature content", "Add test file");
+    advance_remote_main_with(&repo, "test.txt", "upstream content");
+
+    let output = repo
+        .wt_command()
+        .args(["update", "--strategy", strategy])
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!output.status.success(), "stderr: {stderr}");
+    assert!(stderr.contains(expected), "stderr: {stderr}");
+    assert!(stderr.contains("incomplete"), "stderr: {stderr}");
+
+    // The operation is left for the user to resolve
+    let git_dir = repo.git_output(&["-C", feature_wt.to_str().unwrap(), "rev-parse", "--git-dir"]);
+    let marker = match strategy {
+        "rebase" => "rebase-merge",
+        _ => "MERGE_HEAD",
+    };
+    assert!(
+        feature_wt.join(git_dir).join(marker).exists(),
+        "no {marker} after a conflicting {strategy}"
+    );
+}
+
+#[rstest]
+fn test_update_stash_does_not_reapply(#[from(repo_with_remote)] mut repo: TestRepo) {
+    repo.commit_in_worktree(
+        repo.root_path(),
+        "shared.txt",
+        "original",
+        "Add shared file",
+    );
+    let feature_wt =
+        repo.add_worktree_with_commit("feature", "test.txt", "test content", "Add test file");
+    advance_remote_main_with(&repo, "shared.txt", "upstream");
+    // Conflicts with the upstream edit when the stash is reapplied
+    fs::write(feature_wt.join("shared.txt"), "local").unwrap();
+
+    let output = repo
+        .wt_command()
+        .arg("update")
+        .current_dir(&feature_wt)
+        .output()
+        .unwrap();
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "stderr: {stderr}");
+    assert!(
+        stderr.contains("Stashed changes didn't apply cleanly"),
+        "stderr: {stderr}"
+    );
+
+    // The rebase itself went through, and the changes are in the stash
+    assert_eq!(
+        repo.git_output(&["rev-list", "--count", "feature..origin/main"]),
+        "0"
+    );
+    assert_eq!(repo.git_output(&["stash", "list"]).lines().count(), 1);
+}